@@ -1,7 +1,50 @@
+use std::{env, fs, path::Path};
+
 use substrate_build_script_utils::{generate_cargo_keys, rerun_if_git_head_changed};
 
+/// Move standard library modules built by `make stdlib`, relative to the crate root.
+const STDLIB_MODULES: &str = "assets/target/stdlib";
+
 fn main() {
     generate_cargo_keys();
 
     rerun_if_git_head_changed();
+
+    embed_stdlib();
+}
+
+/// Generates `stdlib.rs` in `OUT_DIR` including the stdlib modules bytecode in publishing order.
+/// Without built modules the list is empty, so dev/local chains start without stdlib.
+fn embed_stdlib() {
+    println!("cargo:rerun-if-changed={}", STDLIB_MODULES);
+
+    let mut modules = match fs::read_dir(STDLIB_MODULES) {
+        Ok(dir) => dir
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().map_or(false, |ext| ext == "mv"))
+            .filter_map(|path| Some((module_index(&path)?, path.canonicalize().ok()?)))
+            .collect::<Vec<_>>(),
+        Err(_) => Vec::new(),
+    };
+    modules.sort();
+
+    if modules.is_empty() {
+        println!(
+            "cargo:warning=Move stdlib modules not found in {}, run `make stdlib` to embed them",
+            STDLIB_MODULES
+        );
+    }
+
+    let includes = modules
+        .iter()
+        .map(|(_, path)| format!("    include_bytes!({:?}),\n", path))
+        .collect::<String>();
+    let out = Path::new(&env::var("OUT_DIR").expect("OUT_DIR is set by cargo")).join("stdlib.rs");
+    fs::write(&out, format!("&[\n{}]\n", includes)).expect("stdlib.rs can't be written");
+}
+
+/// Publishing order of the module built by `dove build --tree`, e.g. `3` of `3_Signer.mv`.
+fn module_index(path: &Path) -> Option<usize> {
+    let name = path.file_stem()?.to_str()?;
+    name.split('_').next()?.parse().ok()
 }
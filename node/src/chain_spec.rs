@@ -1,7 +1,8 @@
 use sp_core::{Pair, Public, sr25519};
 use mv_node_runtime::{
     AccountId, AuraConfig, BalancesConfig, GenesisConfig, GrandpaConfig, SudoConfig,
-    SystemConfig, VestingConfig, WASM_BINARY, Signature, PONT, DECIMALS,
+    SystemConfig, TreasuryConfig, VestingConfig, MvmConfig, WASM_BINARY, Signature, PONT,
+    DECIMALS,
 };
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_finality_grandpa::AuthorityId as GrandpaId;
@@ -14,6 +15,10 @@ use serde_json::json;
 /// See https://github.com/paritytech/substrate/blob/master/ss58-registry.json
 const SS58_FORMAT: u8 = 42;
 
+/// Move standard library modules built by `make stdlib` in publishing order,
+/// embedded by the build script. Empty if the node was built without them.
+const STDLIB: &[&[u8]] = include!(concat!(env!("OUT_DIR"), "/stdlib.rs"));
+
 // The URL for the telemetry server.
 // const STAGING_TELEMETRY_URL: &str = "wss://telemetry.polkadot.io/submit/";
 
@@ -42,6 +47,17 @@ pub fn authority_keys_from_seed(s: &str) -> (AuraId, GrandpaId) {
     (get_from_seed::<AuraId>(s), get_from_seed::<GrandpaId>(s))
 }
 
+/// Move standard library modules, published under `0x1` at genesis of dev/local chains.
+fn stdlib() -> Vec<Vec<u8>> {
+    if STDLIB.is_empty() {
+        log::warn!(
+            "Node is built without Move stdlib, genesis won't contain it. \
+             Build it with `make stdlib` and rebuild the node."
+        );
+    }
+    STDLIB.iter().map(|module| module.to_vec()).collect()
+}
+
 fn properties() -> Option<sc_chain_spec::Properties> {
     json!({
         "ss58Format": SS58_FORMAT,
@@ -54,6 +70,7 @@ fn properties() -> Option<sc_chain_spec::Properties> {
 
 pub fn development_config() -> Result<ChainSpec, String> {
     let wasm_binary = WASM_BINARY.ok_or_else(|| "Development wasm not available".to_string())?;
+    let stdlib = stdlib();
 
     Ok(ChainSpec::from_genesis(
         // Name
@@ -75,6 +92,7 @@ pub fn development_config() -> Result<ChainSpec, String> {
                     get_account_id_from_seed::<sr25519::Public>("Alice//stash"),
                     get_account_id_from_seed::<sr25519::Public>("Bob//stash"),
                 ],
+                stdlib.clone(),
                 true,
             )
         },
@@ -93,6 +111,7 @@ pub fn development_config() -> Result<ChainSpec, String> {
 
 pub fn local_testnet_config() -> Result<ChainSpec, String> {
     let wasm_binary = WASM_BINARY.ok_or_else(|| "Development wasm not available".to_string())?;
+    let stdlib = stdlib();
 
     Ok(ChainSpec::from_genesis(
         // Name
@@ -125,6 +144,7 @@ pub fn local_testnet_config() -> Result<ChainSpec, String> {
                     get_account_id_from_seed::<sr25519::Public>("Eve//stash"),
                     get_account_id_from_seed::<sr25519::Public>("Ferdie//stash"),
                 ],
+                stdlib.clone(),
                 true,
             )
         },
@@ -147,6 +167,7 @@ fn testnet_genesis(
     initial_authorities: Vec<(AuraId, GrandpaId)>,
     root_key: AccountId,
     endowed_accounts: Vec<AccountId>,
+    stdlib: Vec<Vec<u8>>,
     _enable_println: bool,
 ) -> GenesisConfig {
     GenesisConfig {
@@ -176,6 +197,7 @@ fn testnet_genesis(
             // Assign network admin rights.
             key: root_key,
         }),
+        pallet_treasury: Some(TreasuryConfig::default()),
         pallet_vesting: Some(VestingConfig {
             // Move 10 PONT under vesting for each account since block 100 and till block 1000.
             vesting: endowed_accounts
@@ -184,5 +206,10 @@ fn testnet_genesis(
                 .map(|k| (k, 100, 1000, 10 * PONT))
                 .collect(),
         }),
+        sp_mvm: Some(MvmConfig {
+            stdlib,
+            packages: Vec::new(),
+            storage: Vec::new(),
+        }),
     }
 }
@@ -15,6 +15,9 @@ pub use sc_rpc_api::DenyUnsafe;
 use sp_transaction_pool::TransactionPool;
 use sp_mvm_rpc_runtime::MVMApiRuntime;
 use sp_mvm_rpc::{MVMApiRpc, MVMApi};
+use sp_mvm_rpc::events::{MVMEventsApiRpc, MVMEventsApi};
+use sc_client_api::BlockchainEvents;
+use sc_rpc::SubscriptionTaskExecutor;
 
 /// Full client dependencies.
 pub struct FullDeps<C, P> {
@@ -24,6 +27,8 @@ pub struct FullDeps<C, P> {
     pub pool: Arc<P>,
     /// Whether to deny unsafe calls
     pub deny_unsafe: DenyUnsafe,
+    /// Executor of the RPC subscriptions
+    pub subscription_executor: SubscriptionTaskExecutor,
 }
 
 /// Instantiate all full RPC extensions.
@@ -31,6 +36,7 @@ pub fn create_full<C, P>(deps: FullDeps<C, P>) -> jsonrpc_core::IoHandler<sc_rpc
 where
     C: ProvideRuntimeApi<Block>,
     C: HeaderBackend<Block> + HeaderMetadata<Block, Error = BlockChainError> + 'static,
+    C: BlockchainEvents<Block>,
     C: Send + Sync + 'static,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Index>,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
@@ -46,6 +52,7 @@ where
         client,
         pool,
         deny_unsafe,
+        subscription_executor,
     } = deps;
 
     io.extend_with(SystemApi::to_delegate(FullSystem::new(
@@ -65,5 +72,8 @@ where
 
     io.extend_with(MVMApiRpc::to_delegate(MVMApi::new(client.clone())));
 
+    let events = MVMEventsApi::<_, Block, AccountId>::new(client.clone(), subscription_executor);
+    io.extend_with(MVMEventsApiRpc::to_delegate(events));
+
     io
 }
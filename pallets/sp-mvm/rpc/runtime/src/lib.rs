@@ -9,7 +9,10 @@ use frame_support::weights::Weight;
 pub mod types;
 
 // Describe Runtime API for MVM pallet.
+// Version 2 adds gas estimation of packages and std, simulation,
+// Move state and module reading, storage usage and events.
 sp_api::decl_runtime_apis! {
+    #[api_version(2)]
     pub trait MVMApiRuntime<AccountId> where
         AccountId: codec::Codec,
     {
@@ -24,5 +27,38 @@ sp_api::decl_runtime_apis! {
 
         // Estimate gas for execute script.
         fn estimate_gas_execute(account: AccountId, tx_bc: Vec<u8>, gas_limit: u64) -> Result<types::MVMApiEstimation, sp_runtime::DispatchError>;
+
+        // Estimate gas for publish package, per module up to the first failed one.
+        fn estimate_gas_publish_package(account: AccountId, package: Vec<u8>, gas_limit: u64) -> Result<Vec<types::MVMApiModuleEstimation>, sp_runtime::DispatchError>;
+
+        // Estimate gas for publish std modules, per module up to the first failed one.
+        fn estimate_gas_publish_std(modules: Vec<Vec<u8>>, gas_limit: u64) -> Result<Vec<types::MVMApiModuleEstimation>, sp_runtime::DispatchError>;
+
+        // Simulate script execution: events, write set and native balance changes.
+        fn simulate_execute(account: AccountId, tx_bc: Vec<u8>, gas_limit: u64) -> Result<types::MVMApiSimulation, sp_runtime::DispatchError>;
+
+        // Simulate module publishing: write set.
+        fn simulate_publish(account: AccountId, module_bc: Vec<u8>, gas_limit: u64) -> Result<types::MVMApiSimulation, sp_runtime::DispatchError>;
+
+        // Get resource by Move address and struct tag with hex addresses (e.g. `0x1::Store::U64`).
+        fn get_resource(address: Vec<u8>, tag: Vec<u8>) -> Result<Option<types::MVMApiResource>, Vec<u8>>;
+
+        // Get module bytecode by Move address and module name.
+        fn get_module(address: Vec<u8>, name: Vec<u8>) -> Result<Option<Vec<u8>>, Vec<u8>>;
+
+        // Get ABI of the module by Move address and module name.
+        fn get_module_abi(address: Vec<u8>, name: Vec<u8>) -> Result<Option<types::ModuleAbi>, Vec<u8>>;
+
+        // List names of all modules published under Move address.
+        fn list_modules(address: Vec<u8>) -> Result<Vec<Vec<u8>>, Vec<u8>>;
+
+        // Get number of storage keys and their total size owned by Move address.
+        fn get_storage_usage(address: Vec<u8>) -> Result<types::StorageUsage, Vec<u8>>;
+
+        // Decode event by type tag with hex addresses (e.g. `0x1::Event::U64`).
+        fn decode_event(tag: Vec<u8>, message: Vec<u8>) -> Result<types::MoveValue, Vec<u8>>;
+
+        // Get Move events of the block with decoded payloads.
+        fn get_block_events() -> Vec<types::MoveEventRecord>;
     }
 }
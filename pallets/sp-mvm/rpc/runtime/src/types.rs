@@ -1,3 +1,4 @@
+use sp_std::prelude::*;
 use codec::{Encode, Decode};
 
 #[derive(Clone, PartialEq, Debug, Encode, Decode)]
@@ -5,3 +6,35 @@ pub struct MVMApiEstimation {
     pub gas_used: u64,
     pub status_code: u64,
 }
+
+#[derive(Clone, PartialEq, Debug, Encode, Decode)]
+pub struct MVMApiModuleEstimation {
+    /// Module name, empty for undecodable module.
+    pub module: Vec<u8>,
+    pub gas_used: u64,
+    pub status_code: u64,
+}
+
+pub use sp_mvm::types::MoveValue;
+pub use sp_mvm::types::{SimulatedEvent, NativeBalanceChange};
+pub use sp_mvm::types::{ModuleAbi, FunctionAbi, StructAbi, TypeParameterKind};
+pub use sp_mvm::types::StorageUsage;
+pub use sp_mvm::types::MoveEventRecord;
+
+#[derive(Clone, PartialEq, Debug, Encode, Decode)]
+pub struct MVMApiResource {
+    /// Resource as stored, BCS encoded.
+    pub bcs: Vec<u8>,
+    /// Decoded value, if the struct layout is known.
+    pub value: Option<MoveValue>,
+}
+
+#[derive(Clone, PartialEq, Debug, Encode, Decode)]
+pub struct MVMApiSimulation {
+    pub gas_used: u64,
+    pub status_code: u64,
+    pub events: Vec<SimulatedEvent>,
+    /// Written storage keys with new values, `None` for removed ones.
+    pub write_set: Vec<(Vec<u8>, Option<Vec<u8>>)>,
+    pub balance_changes: Vec<NativeBalanceChange>,
+}
@@ -0,0 +1,150 @@
+use sp_core::crypto::{AccountId32, Ss58Codec};
+
+/// Move address length in bytes.
+pub const ADDRESS_LENGTH: usize = 32;
+
+/// Parse SS58 or hex (`0x...`) address into Move address bytes.
+pub fn parse_address(address: &str) -> Result<Vec<u8>, String> {
+    let address = address.trim();
+    if address.starts_with("0x") {
+        let bytes = sp_core::bytes::from_hex(address)
+            .map_err(|err| format!("Invalid hex address '{}': {}", address, err))?;
+        if bytes.len() > ADDRESS_LENGTH {
+            return Err(format!("Address '{}' is too long", address));
+        }
+
+        let mut result = vec![0; ADDRESS_LENGTH - bytes.len()];
+        result.extend(bytes);
+        Ok(result)
+    } else {
+        AccountId32::from_ss58check(address)
+            .map(|account| AsRef::<[u8]>::as_ref(&account).to_vec())
+            .map_err(|err| format!("Invalid SS58 address '{}': {:?}", address, err))
+    }
+}
+
+/// Replace SS58 addresses in the type tag with hex ones,
+/// e.g. `5FHneW46xGXgs5mUiveU4sbTyGBzmstUspZC92UhjJM694ty::Store::U64`.
+pub fn normalize_type_tag(tag: &str) -> Result<String, String> {
+    let mut result = String::with_capacity(tag.len());
+    let mut rest = tag;
+    // Address is a word right before `::` which isn't preceded by `::` itself.
+    let mut after_path_sep = false;
+
+    while !rest.is_empty() {
+        let len = rest
+            .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+            .unwrap_or_else(|| rest.len());
+
+        if len == 0 {
+            let sep = if rest.starts_with("::") { 2 } else { 1 };
+            let (token, tail) = rest.split_at(sep);
+            after_path_sep = sep == 2 || (after_path_sep && token.trim().is_empty());
+            result.push_str(token);
+            rest = tail;
+            continue;
+        }
+
+        let (word, tail) = rest.split_at(len);
+        if !after_path_sep && tail.starts_with("::") && !word.starts_with("0x") {
+            result.push_str("0x");
+            result.push_str(&hex_encode(&parse_address(word)?));
+        } else {
+            result.push_str(word);
+        }
+        after_path_sep = false;
+        rest = tail;
+    }
+
+    Ok(result)
+}
+
+/// Type tag comparable as string: SS58 and hex addresses are replaced
+/// with full lowercase hex ones, whitespaces are removed.
+pub fn canonical_type_tag(tag: &str) -> Result<String, String> {
+    let tag = normalize_type_tag(tag)?;
+    let mut result = String::with_capacity(tag.len());
+    let mut rest = tag.as_str();
+    let mut in_word = false;
+
+    while let Some(c) = rest.chars().next() {
+        if !in_word && rest.starts_with("0x") {
+            let len = rest[2..]
+                .find(|c: char| !c.is_ascii_hexdigit())
+                .map(|len| len + 2)
+                .unwrap_or_else(|| rest.len());
+            let (address, tail) = rest.split_at(len);
+            result.push_str("0x");
+            result.push_str(&hex_encode(&parse_address(address)?));
+            rest = tail;
+            continue;
+        }
+
+        in_word = c.is_ascii_alphanumeric() || c == '_';
+        if !c.is_whitespace() {
+            result.push(c);
+        }
+        rest = &rest[c.len_utf8()..];
+    }
+
+    Ok(result)
+}
+
+pub fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOB_SS58: &str = "5FHneW46xGXgs5mUiveU4sbTyGBzmstUspZC92UhjJM694ty";
+    const BOB_HEX: &str = "0x8eaf04151687736326c9fea17e25fc5287613693c912909cb226aa4794f26a48";
+
+    #[test]
+    fn parse_addresses() {
+        let bob = parse_address(BOB_SS58).unwrap();
+        assert_eq!(bob, parse_address(BOB_HEX).unwrap());
+
+        let mut std = vec![0; ADDRESS_LENGTH];
+        std[ADDRESS_LENGTH - 1] = 1;
+        assert_eq!(std, parse_address("0x1").unwrap());
+
+        assert!(parse_address("0xZZ").is_err());
+        assert!(parse_address("not_an_address").is_err());
+    }
+
+    #[test]
+    fn normalize_tags() {
+        assert_eq!(
+            normalize_type_tag("0x1::Account::Balance<0x1::PONT::T>").unwrap(),
+            "0x1::Account::Balance<0x1::PONT::T>"
+        );
+        assert_eq!(
+            normalize_type_tag(&format!("{}::Store::U64", BOB_SS58)).unwrap(),
+            format!("{}::Store::U64", BOB_HEX)
+        );
+        assert_eq!(
+            normalize_type_tag(&format!("0x1::Account::Balance<{}::Coin::T>", BOB_SS58)).unwrap(),
+            format!("0x1::Account::Balance<{}::Coin::T>", BOB_HEX)
+        );
+    }
+
+    #[test]
+    fn canonical_tags() {
+        let std = format!("0x{}", hex_encode(&parse_address("0x1").unwrap()));
+        assert_eq!(
+            canonical_type_tag("0x1::Account::Balance<0x1::PONT::T, u64>").unwrap(),
+            format!("{0}::Account::Balance<{0}::PONT::T,u64>", std)
+        );
+        assert_eq!(
+            canonical_type_tag(&format!("{}::Store::U64", BOB_SS58)).unwrap(),
+            canonical_type_tag(&BOB_HEX.to_uppercase().replace("0X", "0x")).unwrap()
+                + "::Store::U64"
+        );
+        assert_eq!(
+            canonical_type_tag("0x1::My0x1::T").unwrap(),
+            format!("{}::My0x1::T", std)
+        );
+    }
+}
@@ -0,0 +1,264 @@
+use std::sync::Arc;
+use codec::Codec;
+use futures::{StreamExt, TryStreamExt};
+use jsonrpc_core::futures::{Future, Sink};
+use jsonrpc_core::Result;
+use jsonrpc_derive::rpc;
+use jsonrpc_pubsub::{typed::Subscriber, SubscriptionId, manager::SubscriptionManager};
+use log::warn;
+use sc_client_api::BlockchainEvents;
+use sc_rpc::SubscriptionTaskExecutor;
+use serde::{Serialize, Deserialize};
+use sp_api::ProvideRuntimeApi;
+use sp_runtime::{
+    generic::BlockId,
+    traits::{Block as BlockT, Header as HeaderT, UniqueSaturatedInto},
+};
+use sp_mvm_rpc_runtime::MVMApiRuntime;
+use sp_mvm_rpc_runtime::types::MoveEventRecord;
+
+use crate::{addr, invalid_params, EventRecord};
+
+// Events filter with serde.
+#[derive(Serialize, Deserialize, Default, Clone)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct EventFilter {
+    /// Emitting Move address, SS58 or hex.
+    pub sender: Option<String>,
+    /// Event type tag, e.g. `0x1::Event::U64`.
+    pub type_tag: Option<String>,
+    /// Module emitted the event, e.g. `0x1::Event`.
+    pub caller: Option<String>,
+}
+
+/// Parsed `EventFilter`, the type tag is canonical.
+struct Filter {
+    sender: Option<Vec<u8>>,
+    type_tag: Option<String>,
+    caller: Option<(Vec<u8>, Vec<u8>)>,
+}
+
+impl Filter {
+    fn parse(filter: EventFilter) -> std::result::Result<Self, String> {
+        let caller = match filter.caller {
+            Some(caller) => {
+                let separator = caller
+                    .rfind("::")
+                    .ok_or_else(|| format!("Invalid module id '{}'", caller))?;
+                let address = addr::parse_address(&caller[..separator])?;
+                Some((address, caller[separator + 2..].as_bytes().to_vec()))
+            }
+            None => None,
+        };
+
+        Ok(Self {
+            sender: filter
+                .sender
+                .as_deref()
+                .map(addr::parse_address)
+                .transpose()?,
+            type_tag: filter
+                .type_tag
+                .as_deref()
+                .map(addr::canonical_type_tag)
+                .transpose()?,
+            caller,
+        })
+    }
+
+    fn matches(&self, event: &MoveEventRecord) -> bool {
+        let type_tag_matches = || {
+            let type_tag = String::from_utf8_lossy(&event.ty_tag);
+            addr::canonical_type_tag(&type_tag).ok() == self.type_tag
+        };
+
+        self.sender.iter().all(|sender| sender == &event.sender)
+            && self
+                .caller
+                .iter()
+                .all(|caller| Some(caller) == event.caller.as_ref())
+            && (self.type_tag.is_none() || type_tag_matches())
+    }
+}
+
+// Move event of the imported block with serde.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockEvent<BlockHash> {
+    pub block_number: u64,
+    pub block_hash: BlockHash,
+    #[serde(flatten)]
+    pub event: EventRecord,
+}
+
+// RPC subscriptions.
+#[rpc]
+pub trait MVMEventsApiRpc<BlockHash> {
+    /// RPC metadata
+    type Metadata;
+
+    /// Move events of the new best blocks matching the filter.
+    /// Blocks imported on forks are skipped, re-orgs may still re-send the events.
+    #[pubsub(subscription = "mvm_events", subscribe, name = "mvm_subscribeEvents")]
+    fn subscribe_events(
+        &self,
+        metadata: Self::Metadata,
+        subscriber: Subscriber<BlockEvent<BlockHash>>,
+        filter: Option<EventFilter>,
+    );
+
+    #[pubsub(
+        subscription = "mvm_events",
+        unsubscribe,
+        name = "mvm_unsubscribeEvents"
+    )]
+    fn unsubscribe_events(
+        &self,
+        metadata: Option<Self::Metadata>,
+        id: SubscriptionId,
+    ) -> Result<bool>;
+}
+
+pub struct MVMEventsApi<C, P, AccountId> {
+    client: Arc<C>,
+    manager: SubscriptionManager,
+    _marker: std::marker::PhantomData<(P, AccountId)>,
+}
+
+impl<C, P, AccountId> MVMEventsApi<C, P, AccountId> {
+    pub fn new(client: Arc<C>, executor: SubscriptionTaskExecutor) -> Self {
+        Self {
+            client,
+            manager: SubscriptionManager::new(Arc::new(executor)),
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block, AccountId> MVMEventsApiRpc<<Block as BlockT>::Hash>
+    for MVMEventsApi<C, Block, AccountId>
+where
+    Block: BlockT,
+    AccountId: 'static + Send + Sync + Codec,
+    C: 'static + Send + Sync + ProvideRuntimeApi<Block> + BlockchainEvents<Block>,
+    C::Api: MVMApiRuntime<Block, AccountId>,
+{
+    type Metadata = sc_rpc::Metadata;
+
+    fn subscribe_events(
+        &self,
+        _metadata: Self::Metadata,
+        subscriber: Subscriber<BlockEvent<<Block as BlockT>::Hash>>,
+        filter: Option<EventFilter>,
+    ) {
+        let filter = match Filter::parse(filter.unwrap_or_default()) {
+            Ok(filter) => filter,
+            Err(err) => {
+                let _ = subscriber.reject(invalid_params(err));
+                return;
+            }
+        };
+
+        let client = self.client.clone();
+        let stream = self
+            .client
+            .import_notification_stream()
+            .filter(|notification| futures::future::ready(notification.is_new_best))
+            .flat_map(move |notification| {
+                let hash = notification.hash;
+                let number = (*notification.header.number()).unique_saturated_into();
+                let events = client
+                    .runtime_api()
+                    .get_block_events(&BlockId::hash(hash))
+                    .unwrap_or_else(|err| {
+                        warn!("Move events of the block {} not read: {:?}", hash, err);
+                        Vec::new()
+                    });
+
+                let events = events
+                    .into_iter()
+                    .filter(|event| filter.matches(event))
+                    .map(move |event| {
+                        Ok::<_, ()>(Ok(BlockEvent {
+                            block_number: number,
+                            block_hash: hash,
+                            event: event.into(),
+                        }))
+                    })
+                    .collect::<Vec<_>>();
+                futures::stream::iter(events)
+            })
+            .compat();
+
+        self.manager.add(subscriber, |sink| {
+            sink.sink_map_err(|err| warn!("Error sending Move events: {:?}", err))
+                .send_all(stream)
+                // the stream is over when the client is unsubscribed
+                .map(|_| ())
+        });
+    }
+
+    fn unsubscribe_events(
+        &self,
+        _metadata: Option<Self::Metadata>,
+        id: SubscriptionId,
+    ) -> Result<bool> {
+        Ok(self.manager.cancel(id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event() -> MoveEventRecord {
+        let mut std = vec![0; addr::ADDRESS_LENGTH];
+        std[addr::ADDRESS_LENGTH - 1] = 1;
+        MoveEventRecord {
+            index: 0,
+            extrinsic: Some(1),
+            sender: std.clone(),
+            ty_tag: b"0x1::Event::U64".to_vec(),
+            message: vec![],
+            value: None,
+            caller: Some((std, b"Event".to_vec())),
+        }
+    }
+
+    fn filter(sender: Option<&str>, type_tag: Option<&str>, caller: Option<&str>) -> Filter {
+        Filter::parse(EventFilter {
+            sender: sender.map(Into::into),
+            type_tag: type_tag.map(Into::into),
+            caller: caller.map(Into::into),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn filter_events() {
+        let event = event();
+        assert!(filter(None, None, None).matches(&event));
+        assert!(filter(Some("0x01"), None, None).matches(&event));
+        assert!(filter(None, Some("0x01::Event::U64"), None).matches(&event));
+        assert!(filter(None, None, Some("0x1::Event")).matches(&event));
+
+        assert!(!filter(Some("0x2"), None, None).matches(&event));
+        assert!(!filter(None, Some("0x1::Event::U128"), None).matches(&event));
+        assert!(!filter(None, None, Some("0x1::Proxy")).matches(&event));
+        assert!(
+            !filter(Some("0x1"), Some("0x1::Event::U64"), Some("0x2::Event")).matches(&event)
+        );
+    }
+
+    #[test]
+    fn invalid_filter() {
+        let parse = |caller: &str| {
+            Filter::parse(EventFilter {
+                caller: Some(caller.into()),
+                ..Default::default()
+            })
+        };
+        assert!(parse("Event").is_err());
+        assert!(parse("0xZZ::Event").is_err());
+    }
+}
@@ -0,0 +1,79 @@
+use serde_json::{Map, Value};
+use sp_mvm_rpc_runtime::types::MoveValue;
+
+use crate::addr::hex_encode;
+
+/// Move value as JSON:
+/// - `u8` and `u64` as numbers, `u128` as string (it doesn't fit JSON numbers);
+/// - addresses and `vector<u8>` as hex strings;
+/// - structs as objects.
+pub fn move_value_to_json(value: MoveValue) -> Value {
+    match value {
+        MoveValue::Bool(v) => Value::Bool(v),
+        MoveValue::U8(v) => Value::from(v),
+        MoveValue::U64(v) => Value::from(v),
+        MoveValue::U128(v) => Value::String(v.to_string()),
+        MoveValue::Address(v) | MoveValue::Signer(v) => {
+            Value::String(format!("0x{}", hex_encode(&v)))
+        }
+        MoveValue::Vector(items) => {
+            if !items.is_empty() && items.iter().all(|v| matches!(v, MoveValue::U8(_))) {
+                let bytes = items
+                    .into_iter()
+                    .filter_map(|v| match v {
+                        MoveValue::U8(b) => Some(b),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>();
+                Value::String(format!("0x{}", hex_encode(&bytes)))
+            } else {
+                Value::Array(items.into_iter().map(move_value_to_json).collect())
+            }
+        }
+        MoveValue::Struct(fields) => Value::Object(
+            fields
+                .into_iter()
+                .map(|(name, value)| {
+                    (
+                        String::from_utf8_lossy(&name).into_owned(),
+                        move_value_to_json(value),
+                    )
+                })
+                .collect::<Map<_, _>>(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn struct_to_json() {
+        let value = MoveValue::Struct(vec![
+            (b"val".to_vec(), MoveValue::U64(42)),
+            (b"big".to_vec(), MoveValue::U128(u128::MAX)),
+            (
+                b"bytes".to_vec(),
+                MoveValue::Vector(vec![MoveValue::U8(1), MoveValue::U8(255)]),
+            ),
+            (
+                b"flags".to_vec(),
+                MoveValue::Vector(vec![MoveValue::Bool(true)]),
+            ),
+            (b"owner".to_vec(), MoveValue::Address(vec![1; 2])),
+        ]);
+
+        assert_eq!(
+            move_value_to_json(value),
+            json!({
+                "val": 42,
+                "big": u128::MAX.to_string(),
+                "bytes": "0x01ff",
+                "flags": [true],
+                "owner": "0x0101",
+            })
+        );
+    }
+}
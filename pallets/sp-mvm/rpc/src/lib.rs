@@ -9,11 +9,20 @@ use sp_runtime::{
     traits::{Block as BlockT},
 };
 use sp_api::ProvideRuntimeApi;
-use sp_mvm_rpc_runtime::{MVMApiRuntime, types::MVMApiEstimation};
+use sp_mvm_rpc_runtime::{MVMApiRuntime, types::MVMApiEstimation, types::MVMApiResource};
+use sp_mvm_rpc_runtime::types::{ModuleAbi, FunctionAbi, StructAbi, TypeParameterKind};
+use sp_mvm_rpc_runtime::types::{MVMApiSimulation, SimulatedEvent, NativeBalanceChange};
+use sp_mvm_rpc_runtime::types::MVMApiModuleEstimation;
+use sp_mvm_rpc_runtime::types::StorageUsage as MVMApiStorageUsage;
+use sp_mvm_rpc_runtime::types::MoveEventRecord;
 use frame_support::weights::Weight;
 use serde::{Serialize, Deserialize};
 use fc_rpc_core::types::Bytes;
 
+pub mod addr;
+pub mod events;
+pub mod json;
+
 // Estimation struct with serde.
 #[derive(Serialize, Deserialize)]
 pub struct Estimation {
@@ -30,6 +39,272 @@ impl From<MVMApiEstimation> for Estimation {
     }
 }
 
+// Module estimation struct with serde.
+#[derive(Serialize, Deserialize)]
+pub struct ModuleEstimation {
+    pub module: String,
+    pub gas_used: u64,
+    pub status_code: u64,
+}
+
+impl From<MVMApiModuleEstimation> for ModuleEstimation {
+    fn from(e: MVMApiModuleEstimation) -> Self {
+        Self {
+            module: String::from_utf8_lossy(&e.module).into_owned(),
+            gas_used: e.gas_used,
+            status_code: e.status_code,
+        }
+    }
+}
+
+// Simulation struct with serde.
+#[derive(Serialize, Deserialize)]
+pub struct Simulation {
+    pub gas_used: u64,
+    pub status_code: u64,
+    pub events: Vec<SimulationEvent>,
+    pub write_set: Vec<WriteSetItem>,
+    pub balance_changes: Vec<BalanceChange>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SimulationEvent {
+    pub sender: String,
+    pub type_tag: String,
+    /// BCS encoded event.
+    pub data: Bytes,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct WriteSetItem {
+    pub key: Bytes,
+    /// New value, `None` for removed key.
+    pub value: Option<Bytes>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct BalanceChange {
+    pub address: String,
+    pub ticker: String,
+    /// Native balance change: `withdraw` or `deposit`.
+    pub kind: String,
+    /// Amount as string, `u128` doesn't fit JSON numbers.
+    pub amount: String,
+}
+
+impl From<MVMApiSimulation> for Simulation {
+    fn from(s: MVMApiSimulation) -> Self {
+        Self {
+            gas_used: s.gas_used,
+            status_code: s.status_code,
+            events: s.events.into_iter().map(SimulationEvent::from).collect(),
+            write_set: s
+                .write_set
+                .into_iter()
+                .map(|(key, value)| WriteSetItem {
+                    key: key.into(),
+                    value: value.map(Bytes::from),
+                })
+                .collect(),
+            balance_changes: s
+                .balance_changes
+                .into_iter()
+                .map(BalanceChange::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<SimulatedEvent> for SimulationEvent {
+    fn from(e: SimulatedEvent) -> Self {
+        Self {
+            sender: format!("0x{}", addr::hex_encode(&e.sender)),
+            type_tag: String::from_utf8_lossy(&e.ty_tag).into_owned(),
+            data: e.message.into(),
+        }
+    }
+}
+
+impl From<NativeBalanceChange> for BalanceChange {
+    fn from(c: NativeBalanceChange) -> Self {
+        let (kind, address, ticker, amount) = match c {
+            NativeBalanceChange::Withdraw {
+                address,
+                ticker,
+                amount,
+            } => ("withdraw", address, ticker, amount),
+            NativeBalanceChange::Deposit {
+                address,
+                ticker,
+                amount,
+            } => ("deposit", address, ticker, amount),
+        };
+        Self {
+            address: format!("0x{}", addr::hex_encode(&address)),
+            ticker: String::from_utf8_lossy(&ticker).into_owned(),
+            kind: kind.to_string(),
+            amount: amount.to_string(),
+        }
+    }
+}
+
+// Resource struct with serde.
+#[derive(Serialize, Deserialize)]
+pub struct Resource {
+    /// Resource as stored, BCS encoded.
+    pub bcs: Bytes,
+    /// Decoded value, if the struct layout is known.
+    pub value: Option<serde_json::Value>,
+}
+
+impl From<MVMApiResource> for Resource {
+    fn from(r: MVMApiResource) -> Self {
+        Self {
+            bcs: r.bcs.into(),
+            value: r.value.map(json::move_value_to_json),
+        }
+    }
+}
+
+// Module ABI with serde.
+#[derive(Serialize, Deserialize)]
+pub struct Abi {
+    pub address: String,
+    pub name: String,
+    pub functions: Vec<AbiFunction>,
+    pub structs: Vec<AbiStruct>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct AbiFunction {
+    pub name: String,
+    /// Kinds of type parameters: `all`, `resource` or `copyable`.
+    pub type_parameters: Vec<String>,
+    pub parameters: Vec<String>,
+    pub returns: Vec<String>,
+    pub acquires: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct AbiStruct {
+    pub name: String,
+    pub is_resource: bool,
+    pub type_parameters: Vec<String>,
+    /// Fields, `None` for native structs.
+    pub fields: Option<Vec<AbiField>>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct AbiField {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: String,
+}
+
+// Storage usage struct with serde.
+#[derive(Serialize, Deserialize)]
+pub struct StorageUsage {
+    pub keys: u32,
+    pub bytes: u64,
+}
+
+impl From<MVMApiStorageUsage> for StorageUsage {
+    fn from(u: MVMApiStorageUsage) -> Self {
+        Self {
+            keys: u.keys,
+            bytes: u.bytes,
+        }
+    }
+}
+
+// Move event record with serde.
+#[derive(Serialize, Deserialize)]
+pub struct EventRecord {
+    /// Index of the event in the block.
+    pub index: u32,
+    /// Index of the extrinsic, `None` for events out of extrinsics.
+    pub extrinsic: Option<u32>,
+    pub sender: String,
+    pub type_tag: String,
+    /// BCS encoded event.
+    pub data: Bytes,
+    /// Decoded event, if the type layout is known.
+    pub value: Option<serde_json::Value>,
+    /// Module emitted the event, e.g. `0x1::Event`, `None` for script.
+    pub caller: Option<String>,
+}
+
+impl From<MoveEventRecord> for EventRecord {
+    fn from(e: MoveEventRecord) -> Self {
+        Self {
+            index: e.index,
+            extrinsic: e.extrinsic,
+            sender: format!("0x{}", addr::hex_encode(&e.sender)),
+            type_tag: utf8(e.ty_tag),
+            data: e.message.into(),
+            value: e.value.map(json::move_value_to_json),
+            caller: e.caller.map(|(address, module)| {
+                format!("0x{}::{}", addr::hex_encode(&address), utf8(module))
+            }),
+        }
+    }
+}
+
+fn utf8(bytes: Vec<u8>) -> String {
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+fn kind(kind: TypeParameterKind) -> String {
+    match kind {
+        TypeParameterKind::All => "all",
+        TypeParameterKind::Resource => "resource",
+        TypeParameterKind::Copyable => "copyable",
+    }
+    .to_string()
+}
+
+impl From<ModuleAbi> for Abi {
+    fn from(abi: ModuleAbi) -> Self {
+        Self {
+            address: format!("0x{}", addr::hex_encode(&abi.address)),
+            name: utf8(abi.name),
+            functions: abi.functions.into_iter().map(AbiFunction::from).collect(),
+            structs: abi.structs.into_iter().map(AbiStruct::from).collect(),
+        }
+    }
+}
+
+impl From<FunctionAbi> for AbiFunction {
+    fn from(f: FunctionAbi) -> Self {
+        Self {
+            name: utf8(f.name),
+            type_parameters: f.type_parameters.into_iter().map(kind).collect(),
+            parameters: f.parameters.into_iter().map(utf8).collect(),
+            returns: f.returns.into_iter().map(utf8).collect(),
+            acquires: f.acquires.into_iter().map(utf8).collect(),
+        }
+    }
+}
+
+impl From<StructAbi> for AbiStruct {
+    fn from(s: StructAbi) -> Self {
+        Self {
+            name: utf8(s.name),
+            is_resource: s.is_resource,
+            type_parameters: s.type_parameters.into_iter().map(kind).collect(),
+            fields: s.fields.map(|fields| {
+                fields
+                    .into_iter()
+                    .map(|(name, ty)| AbiField {
+                        name: utf8(name),
+                        ty: utf8(ty),
+                    })
+                    .collect()
+            }),
+        }
+    }
+}
+
 // RPC calls.
 #[rpc]
 pub trait MVMApiRpc<BlockHash, AccountId> {
@@ -56,6 +331,90 @@ pub trait MVMApiRpc<BlockHash, AccountId> {
         gas_limit: u64,
         at: Option<BlockHash>,
     ) -> Result<Estimation>;
+
+    #[rpc(name = "mvm_estimateGasPublishPackage")]
+    fn estimate_gas_publish_package(
+        &self,
+        account: AccountId,
+        package: Bytes,
+        gas_limit: u64,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<ModuleEstimation>>;
+
+    #[rpc(name = "mvm_estimateGasPublishStd")]
+    fn estimate_gas_publish_std(
+        &self,
+        modules: Vec<Bytes>,
+        gas_limit: u64,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<ModuleEstimation>>;
+
+    #[rpc(name = "mvm_simulateExecute")]
+    fn simulate_execute(
+        &self,
+        account: AccountId,
+        tx_bc: Bytes,
+        gas_limit: u64,
+        at: Option<BlockHash>,
+    ) -> Result<Simulation>;
+
+    #[rpc(name = "mvm_simulatePublish")]
+    fn simulate_publish(
+        &self,
+        account: AccountId,
+        module_bc: Bytes,
+        gas_limit: u64,
+        at: Option<BlockHash>,
+    ) -> Result<Simulation>;
+
+    #[rpc(name = "mvm_getResource")]
+    fn get_resource(
+        &self,
+        address: String,
+        tag: String,
+        at: Option<BlockHash>,
+    ) -> Result<Option<Resource>>;
+
+    #[rpc(name = "mvm_getModule")]
+    fn get_module(
+        &self,
+        address: String,
+        name: String,
+        at: Option<BlockHash>,
+    ) -> Result<Option<Bytes>>;
+
+    #[rpc(name = "mvm_getModuleABI")]
+    fn get_module_abi(
+        &self,
+        address: String,
+        name: String,
+        at: Option<BlockHash>,
+    ) -> Result<Option<Abi>>;
+
+    #[rpc(name = "mvm_listModules")]
+    fn list_modules(&self, address: String, at: Option<BlockHash>) -> Result<Vec<String>>;
+
+    #[rpc(name = "mvm_getStorageUsage")]
+    fn get_storage_usage(&self, address: String, at: Option<BlockHash>) -> Result<StorageUsage>;
+
+    #[rpc(name = "mvm_decodeEvent")]
+    fn decode_event(
+        &self,
+        type_tag: String,
+        data: Bytes,
+        at: Option<BlockHash>,
+    ) -> Result<serde_json::Value>;
+
+    #[rpc(name = "mvm_getBlockEvents")]
+    fn get_block_events(&self, at: Option<BlockHash>) -> Result<Vec<EventRecord>>;
+}
+
+fn invalid_params(message: String) -> RpcError {
+    RpcError {
+        code: ErrorCode::InvalidParams,
+        message,
+        data: None,
+    }
 }
 
 pub struct MVMApi<C, P> {
@@ -166,4 +525,319 @@ where
 
         Ok(Estimation::from(mvm_estimation))
     }
+
+    fn estimate_gas_publish_package(
+        &self,
+        account: AccountId,
+        package: Bytes,
+        gas_limit: u64,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<ModuleEstimation>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+        let res = api
+            .estimate_gas_publish_package(&at, account, package.into_vec(), gas_limit)
+            .map_err(|e| RpcError {
+                code: ErrorCode::ServerError(500),
+                message: "Error during requesting Runtime API".into(),
+                data: Some(format!("{:?}", e).into()),
+            })?;
+
+        let estimations = res.map_err(|e| RpcError {
+            code: ErrorCode::ServerError(500),
+            message: "Error during publishing package for estimation".into(),
+            data: Some(format!("{:?}", e).into()),
+        })?;
+
+        Ok(estimations
+            .into_iter()
+            .map(ModuleEstimation::from)
+            .collect())
+    }
+
+    fn estimate_gas_publish_std(
+        &self,
+        modules: Vec<Bytes>,
+        gas_limit: u64,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<ModuleEstimation>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+        let modules = modules.into_iter().map(Bytes::into_vec).collect();
+        let res = api
+            .estimate_gas_publish_std(&at, modules, gas_limit)
+            .map_err(|e| RpcError {
+                code: ErrorCode::ServerError(500),
+                message: "Error during requesting Runtime API".into(),
+                data: Some(format!("{:?}", e).into()),
+            })?;
+
+        let estimations = res.map_err(|e| RpcError {
+            code: ErrorCode::ServerError(500),
+            message: "Error during publishing std modules for estimation".into(),
+            data: Some(format!("{:?}", e).into()),
+        })?;
+
+        Ok(estimations
+            .into_iter()
+            .map(ModuleEstimation::from)
+            .collect())
+    }
+
+    fn simulate_execute(
+        &self,
+        account: AccountId,
+        tx_bc: Bytes,
+        gas_limit: u64,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Simulation> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+        let res = api
+            .simulate_execute(&at, account, tx_bc.into_vec(), gas_limit)
+            .map_err(|e| RpcError {
+                code: ErrorCode::ServerError(500),
+                message: "Error during requesting Runtime API".into(),
+                data: Some(format!("{:?}", e).into()),
+            })?;
+
+        let simulation = res.map_err(|e| RpcError {
+            code: ErrorCode::ServerError(500),
+            message: "Error during script execution for simulation".into(),
+            data: Some(format!("{:?}", e).into()),
+        })?;
+
+        Ok(Simulation::from(simulation))
+    }
+
+    fn simulate_publish(
+        &self,
+        account: AccountId,
+        module_bc: Bytes,
+        gas_limit: u64,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Simulation> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+        let res = api
+            .simulate_publish(&at, account, module_bc.into_vec(), gas_limit)
+            .map_err(|e| RpcError {
+                code: ErrorCode::ServerError(500),
+                message: "Error during requesting Runtime API".into(),
+                data: Some(format!("{:?}", e).into()),
+            })?;
+
+        let simulation = res.map_err(|e| RpcError {
+            code: ErrorCode::ServerError(500),
+            message: "Error during publishing module for simulation".into(),
+            data: Some(format!("{:?}", e).into()),
+        })?;
+
+        Ok(Simulation::from(simulation))
+    }
+
+    fn get_resource(
+        &self,
+        address: String,
+        tag: String,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Option<Resource>> {
+        let address = addr::parse_address(&address).map_err(invalid_params)?;
+        let tag = addr::normalize_type_tag(&tag).map_err(invalid_params)?;
+
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+        let res = api
+            .get_resource(&at, address, tag.into_bytes())
+            .map_err(|e| RpcError {
+                code: ErrorCode::ServerError(500),
+                message: "Error during requesting Runtime API".into(),
+                data: Some(format!("{:?}", e).into()),
+            })?;
+
+        let resource = res.map_err(|e| RpcError {
+            code: ErrorCode::ServerError(500),
+            message: "Error during reading resource".into(),
+            data: Some(String::from_utf8_lossy(&e).into()),
+        })?;
+
+        Ok(resource.map(Resource::from))
+    }
+
+    fn get_module(
+        &self,
+        address: String,
+        name: String,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Option<Bytes>> {
+        let address = addr::parse_address(&address).map_err(invalid_params)?;
+
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+        let res = api
+            .get_module(&at, address, name.into_bytes())
+            .map_err(|e| RpcError {
+                code: ErrorCode::ServerError(500),
+                message: "Error during requesting Runtime API".into(),
+                data: Some(format!("{:?}", e).into()),
+            })?;
+
+        let module = res.map_err(|e| RpcError {
+            code: ErrorCode::ServerError(500),
+            message: "Error during reading module".into(),
+            data: Some(String::from_utf8_lossy(&e).into()),
+        })?;
+
+        Ok(module.map(Bytes::from))
+    }
+
+    fn get_module_abi(
+        &self,
+        address: String,
+        name: String,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Option<Abi>> {
+        let address = addr::parse_address(&address).map_err(invalid_params)?;
+
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+        let res = api
+            .get_module_abi(&at, address, name.into_bytes())
+            .map_err(|e| RpcError {
+                code: ErrorCode::ServerError(500),
+                message: "Error during requesting Runtime API".into(),
+                data: Some(format!("{:?}", e).into()),
+            })?;
+
+        let abi = res.map_err(|e| RpcError {
+            code: ErrorCode::ServerError(500),
+            message: "Error during reading module ABI".into(),
+            data: Some(String::from_utf8_lossy(&e).into()),
+        })?;
+
+        Ok(abi.map(Abi::from))
+    }
+
+    fn list_modules(
+        &self,
+        address: String,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<String>> {
+        let address = addr::parse_address(&address).map_err(invalid_params)?;
+
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+        let res = api.list_modules(&at, address).map_err(|e| RpcError {
+            code: ErrorCode::ServerError(500),
+            message: "Error during requesting Runtime API".into(),
+            data: Some(format!("{:?}", e).into()),
+        })?;
+
+        let names = res.map_err(|e| RpcError {
+            code: ErrorCode::ServerError(500),
+            message: "Error during listing modules".into(),
+            data: Some(String::from_utf8_lossy(&e).into()),
+        })?;
+
+        Ok(names
+            .into_iter()
+            .map(|name| String::from_utf8_lossy(&name).into_owned())
+            .collect())
+    }
+
+    fn get_storage_usage(
+        &self,
+        address: String,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<StorageUsage> {
+        let address = addr::parse_address(&address).map_err(invalid_params)?;
+
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+        let res = api.get_storage_usage(&at, address).map_err(|e| RpcError {
+            code: ErrorCode::ServerError(500),
+            message: "Error during requesting Runtime API".into(),
+            data: Some(format!("{:?}", e).into()),
+        })?;
+
+        let usage = res.map_err(|e| RpcError {
+            code: ErrorCode::ServerError(500),
+            message: "Error during reading storage usage".into(),
+            data: Some(String::from_utf8_lossy(&e).into()),
+        })?;
+
+        Ok(usage.into())
+    }
+
+    fn decode_event(
+        &self,
+        type_tag: String,
+        data: Bytes,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<serde_json::Value> {
+        let type_tag = addr::normalize_type_tag(&type_tag).map_err(invalid_params)?;
+
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+        let res = api
+            .decode_event(&at, type_tag.into_bytes(), data.into_vec())
+            .map_err(|e| RpcError {
+                code: ErrorCode::ServerError(500),
+                message: "Error during requesting Runtime API".into(),
+                data: Some(format!("{:?}", e).into()),
+            })?;
+
+        let value = res.map_err(|e| RpcError {
+            code: ErrorCode::ServerError(500),
+            message: "Error during decoding event".into(),
+            data: Some(String::from_utf8_lossy(&e).into()),
+        })?;
+
+        Ok(json::move_value_to_json(value))
+    }
+
+    fn get_block_events(&self, at: Option<<Block as BlockT>::Hash>) -> Result<Vec<EventRecord>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+        let events = api.get_block_events(&at).map_err(|e| RpcError {
+            code: ErrorCode::ServerError(500),
+            message: "Error during requesting Runtime API".into(),
+            data: Some(format!("{:?}", e).into()),
+        })?;
+
+        Ok(events.into_iter().map(EventRecord::from).collect())
+    }
 }
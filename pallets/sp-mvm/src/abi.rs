@@ -0,0 +1,214 @@
+use sp_std::prelude::*;
+use move_core_types::account_address::AccountAddress;
+use vm::access::ModuleAccess;
+use vm::file_format::CompiledModule;
+use vm::file_format::IdentifierIndex;
+use vm::file_format::Kind;
+use vm::file_format::SignatureToken;
+use vm::file_format::StructFieldInformation;
+use vm::file_format::StructHandleIndex;
+
+use crate::types::{FunctionAbi, ModuleAbi, StructAbi, TypeParameterKind};
+
+/// Build ABI of the module bytecode: public functions and all structs.
+/// Types are rendered as Move source types, e.g. `&mut vector<0x1::PONT::T>`,
+/// type parameters as `T0`, `T1`, etc.
+pub fn module_abi(bytecode: &[u8]) -> Result<ModuleAbi, &'static str> {
+    let module = CompiledModule::deserialize(bytecode).map_err(|_| "Invalid module")?;
+    let id = module.self_id();
+
+    let functions = module
+        .function_defs()
+        .iter()
+        .filter(|def| def.is_public())
+        .map(|def| {
+            let handle = module.function_handle_at(def.function);
+            FunctionAbi {
+                name: identifier(&module, handle.name),
+                type_parameters: kinds(&handle.type_parameters),
+                parameters: signature(&module, &module.signature_at(handle.parameters).0),
+                returns: signature(&module, &module.signature_at(handle.return_).0),
+                acquires: def
+                    .acquires_global_resources
+                    .iter()
+                    .map(|idx| {
+                        let handle = module.struct_def_at(*idx).struct_handle;
+                        identifier(&module, module.struct_handle_at(handle).name)
+                    })
+                    .collect(),
+            }
+        })
+        .collect();
+
+    let structs = module
+        .struct_defs()
+        .iter()
+        .map(|def| {
+            let handle = module.struct_handle_at(def.struct_handle);
+            let fields = match &def.field_information {
+                StructFieldInformation::Native => None,
+                StructFieldInformation::Declared(fields) => Some(
+                    fields
+                        .iter()
+                        .map(|field| {
+                            let mut ty = Vec::new();
+                            write_type(&module, &field.signature.0, &mut ty);
+                            (identifier(&module, field.name), ty)
+                        })
+                        .collect(),
+                ),
+            };
+            StructAbi {
+                name: identifier(&module, handle.name),
+                is_resource: handle.is_nominal_resource,
+                type_parameters: kinds(&handle.type_parameters),
+                fields,
+            }
+        })
+        .collect();
+
+    Ok(ModuleAbi {
+        address: id.address().as_ref().to_vec(),
+        name: id.name().as_str().as_bytes().to_vec(),
+        functions,
+        structs,
+    })
+}
+
+fn identifier(module: &CompiledModule, idx: IdentifierIndex) -> Vec<u8> {
+    module.identifier_at(idx).as_str().as_bytes().to_vec()
+}
+
+fn kinds(kinds: &[Kind]) -> Vec<TypeParameterKind> {
+    kinds
+        .iter()
+        .map(|kind| match kind {
+            Kind::All => TypeParameterKind::All,
+            Kind::Resource => TypeParameterKind::Resource,
+            Kind::Copyable => TypeParameterKind::Copyable,
+        })
+        .collect()
+}
+
+fn signature(module: &CompiledModule, tokens: &[SignatureToken]) -> Vec<Vec<u8>> {
+    tokens
+        .iter()
+        .map(|token| {
+            let mut ty = Vec::new();
+            write_type(module, token, &mut ty);
+            ty
+        })
+        .collect()
+}
+
+fn write_type(module: &CompiledModule, token: &SignatureToken, out: &mut Vec<u8>) {
+    match token {
+        SignatureToken::Bool => out.extend_from_slice(b"bool"),
+        SignatureToken::U8 => out.extend_from_slice(b"u8"),
+        SignatureToken::U64 => out.extend_from_slice(b"u64"),
+        SignatureToken::U128 => out.extend_from_slice(b"u128"),
+        SignatureToken::Address => out.extend_from_slice(b"address"),
+        SignatureToken::Signer => out.extend_from_slice(b"signer"),
+        SignatureToken::Vector(token) => {
+            out.extend_from_slice(b"vector<");
+            write_type(module, token, out);
+            out.push(b'>');
+        }
+        SignatureToken::Struct(idx) => write_struct(module, *idx, &[], out),
+        SignatureToken::StructInstantiation(idx, tokens) => {
+            write_struct(module, *idx, tokens, out)
+        }
+        SignatureToken::Reference(token) => {
+            out.push(b'&');
+            write_type(module, token, out);
+        }
+        SignatureToken::MutableReference(token) => {
+            out.extend_from_slice(b"&mut ");
+            write_type(module, token, out);
+        }
+        SignatureToken::TypeParameter(idx) => write_type_param(*idx, out),
+    }
+}
+
+fn write_struct(
+    module: &CompiledModule,
+    idx: StructHandleIndex,
+    type_params: &[SignatureToken],
+    out: &mut Vec<u8>,
+) {
+    let handle = module.struct_handle_at(idx);
+    let module_handle = module.module_handle_at(handle.module);
+
+    write_address(module.address_identifier_at(module_handle.address), out);
+    out.extend_from_slice(b"::");
+    out.extend_from_slice(module.identifier_at(module_handle.name).as_str().as_bytes());
+    out.extend_from_slice(b"::");
+    out.extend_from_slice(module.identifier_at(handle.name).as_str().as_bytes());
+
+    if !type_params.is_empty() {
+        out.push(b'<');
+        for (i, token) in type_params.iter().enumerate() {
+            if i > 0 {
+                out.extend_from_slice(b", ");
+            }
+            write_type(module, token, out);
+        }
+        out.push(b'>');
+    }
+}
+
+/// Short hex address, e.g. `0x1`.
+fn write_address(address: &AccountAddress, out: &mut Vec<u8>) {
+    const HEX: &[u8; 16] = b"0123456789abcdef";
+
+    let mut digits = Vec::with_capacity(AccountAddress::LENGTH * 2);
+    for byte in address.as_ref() {
+        digits.push(HEX[(byte >> 4) as usize]);
+        digits.push(HEX[(byte & 0xf) as usize]);
+    }
+    let start = digits
+        .iter()
+        .position(|digit| *digit != b'0')
+        .unwrap_or(digits.len() - 1);
+
+    out.extend_from_slice(b"0x");
+    out.extend_from_slice(&digits[start..]);
+}
+
+fn write_type_param(idx: u16, out: &mut Vec<u8>) {
+    out.push(b'T');
+    let mut digits = Vec::new();
+    let mut idx = idx;
+    loop {
+        digits.push(b'0' + (idx % 10) as u8);
+        idx /= 10;
+        if idx == 0 {
+            break;
+        }
+    }
+    digits.reverse();
+    out.extend_from_slice(&digits);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn short_address() {
+        let mut out = Vec::new();
+        write_address(&AccountAddress::from_hex_literal("0x1").unwrap(), &mut out);
+        assert_eq!(b"0x1".to_vec(), out);
+
+        let mut out = Vec::new();
+        write_address(&AccountAddress::new([0; AccountAddress::LENGTH]), &mut out);
+        assert_eq!(b"0x0".to_vec(), out);
+    }
+
+    #[test]
+    fn type_params() {
+        let mut out = Vec::new();
+        write_type_param(12, &mut out);
+        assert_eq!(b"T12".to_vec(), out);
+    }
+}
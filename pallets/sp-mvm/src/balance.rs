@@ -1,15 +1,19 @@
 use core::convert::TryFrom;
 use core::convert::TryInto;
+use core::marker::PhantomData;
 use move_vm::data::BalanceAccess;
 use move_vm_types::natives::balance::Balance as VmBalance;
 
+use codec::{Encode, Decode};
 use crate::addr::address_to_account;
+use crate::types::NativeBalanceChange;
+use frame_support::dispatch::DispatchResult;
 use frame_support::traits::Currency;
+use frame_support::traits::Get;
 use frame_support::traits::Imbalance;
 use frame_support::traits::WithdrawReasons;
 use frame_support::traits::ExistenceRequirement;
-
-type BalanceOf<T> = <T as balances::Config>::Balance;
+use sp_runtime::traits::Zero;
 
 pub const PONT: &str = "PONT";
 /// Suppoted tickers.
@@ -19,34 +23,204 @@ pub fn is_ticker_supported(ticker: &str) -> bool {
     TICKERS.contains(&ticker)
 }
 
-pub struct BalancesAdapter<T>(core::marker::PhantomData<T>);
+/// Currencies accessible from Move natives.
+/// Same shape as `orml_traits::MultiCurrency`, see [`OrmlCurrencies`].
+pub trait MultiCurrency<AccountId> {
+    type CurrencyId: Clone;
+    type Balance: TryFrom<VmBalance> + TryInto<VmBalance> + Copy;
 
-impl<T: balances::Config> Default for BalancesAdapter<T> {
-    fn default() -> Self {
-        Self(core::marker::PhantomData)
-    }
+    /// Free balance of the account.
+    fn free_balance(currency_id: Self::CurrencyId, who: &AccountId) -> Self::Balance;
+
+    /// Add `amount` to the account balance.
+    fn deposit(
+        currency_id: Self::CurrencyId,
+        who: &AccountId,
+        amount: Self::Balance,
+    ) -> DispatchResult;
+
+    /// Remove `amount` from the account balance.
+    fn withdraw(
+        currency_id: Self::CurrencyId,
+        who: &AccountId,
+        amount: Self::Balance,
+    ) -> DispatchResult;
 }
 
-impl<T: balances::Config> BalancesAdapter<T> {
-    pub fn new() -> Self {
-        Self(core::marker::PhantomData)
-    }
+/// Maps Move ticker (e.g. `PONT`) to the on-chain currency.
+pub trait TickerToCurrency<CurrencyId> {
+    /// Returns `None` for unsupported ticker.
+    fn currency_id(ticker: &str) -> Option<CurrencyId>;
 }
 
-impl<T: balances::Config> BalanceAccess for BalancesAdapter<T>
+/// Native currency (`pallet_balances`) as the only one, for [`NativeTickers`].
+pub struct NativeCurrency<T>(PhantomData<T>);
+
+impl<T: balances::Config> MultiCurrency<T::AccountId> for NativeCurrency<T>
 where
     <T as balances::Config>::Balance: TryFrom<VmBalance>,
     <T as balances::Config>::Balance: TryInto<VmBalance>,
 {
+    type CurrencyId = ();
+    type Balance = <T as balances::Config>::Balance;
+
+    fn free_balance(_: (), who: &T::AccountId) -> Self::Balance {
+        <balances::Module<T> as Currency<T::AccountId>>::free_balance(who)
+    }
+
+    fn deposit(_: (), who: &T::AccountId, amount: Self::Balance) -> DispatchResult {
+        let imbalance =
+            <balances::Module<T> as Currency<T::AccountId>>::deposit_creating(who, amount);
+        trace!("native balance deposit imbalance: {:?}", imbalance.peek());
+        // `deposit_creating` credits nothing instead of failing:
+        if imbalance.peek() != amount {
+            let total = <balances::Module<T> as Currency<T::AccountId>>::total_balance(who);
+            return if total.is_zero() {
+                Err(balances::Error::<T>::ExistentialDeposit.into())
+            } else {
+                Err(balances::Error::<T>::Overflow.into())
+            };
+        }
+        Ok(())
+    }
+
+    fn withdraw(_: (), who: &T::AccountId, amount: Self::Balance) -> DispatchResult {
+        let imbalance = <balances::Module<T> as Currency<T::AccountId>>::withdraw(
+            who,
+            amount,
+            WithdrawReasons::RESERVE,
+            ExistenceRequirement::AllowDeath,
+        )?;
+        trace!("native balance withdraw imbalance: {:?}", imbalance.peek());
+        Ok(())
+    }
+}
+
+/// Currencies of `orml_traits::MultiCurrency` implementation, e.g. `orml_tokens`
+/// or `orml_currencies` with the native currency in it.
+pub struct OrmlCurrencies<C>(PhantomData<C>);
+
+impl<AccountId, C> MultiCurrency<AccountId> for OrmlCurrencies<C>
+where
+    C: orml_traits::MultiCurrency<AccountId>,
+    C::Balance: TryFrom<VmBalance> + TryInto<VmBalance>,
+{
+    type CurrencyId = C::CurrencyId;
+    type Balance = C::Balance;
+
+    fn free_balance(currency_id: Self::CurrencyId, who: &AccountId) -> Self::Balance {
+        C::free_balance(currency_id, who)
+    }
+
+    fn deposit(
+        currency_id: Self::CurrencyId,
+        who: &AccountId,
+        amount: Self::Balance,
+    ) -> DispatchResult {
+        C::deposit(currency_id, who, amount)
+    }
+
+    fn withdraw(
+        currency_id: Self::CurrencyId,
+        who: &AccountId,
+        amount: Self::Balance,
+    ) -> DispatchResult {
+        C::withdraw(currency_id, who, amount)
+    }
+}
+
+/// Supported [`TICKERS`] mapped to [`NativeCurrency`].
+pub struct NativeTickers;
+
+impl TickerToCurrency<()> for NativeTickers {
+    fn currency_id(ticker: &str) -> Option<()> {
+        if is_ticker_supported(ticker) {
+            Some(())
+        } else {
+            None
+        }
+    }
+}
+
+/// Tickers mapping configured by the runtime, e.g. with `parameter_types!`.
+pub struct ConstTickers<L>(PhantomData<L>);
+
+impl<C, L> TickerToCurrency<C> for ConstTickers<L>
+where
+    C: Clone + 'static,
+    L: Get<&'static [(&'static str, C)]>,
+{
+    fn currency_id(ticker: &str) -> Option<C> {
+        L::get()
+            .iter()
+            .find(|(t, _)| *t == ticker)
+            .map(|(_, currency_id)| currency_id.clone())
+    }
+}
+
+/// Native balance operation failed inside the VM.
+/// `BalanceAccess` can't return errors, so the failure is recorded
+/// and turned into the transaction error after execution.
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Debug)]
+pub enum BalanceAccessError {
+    /// Address or amount can't be converted from Move.
+    Conversion,
+    /// Native balance can't be withdrawn (Move deposit).
+    Withdraw,
+    /// Native balance can't be deposited (Move withdraw).
+    Deposit,
+}
+
+type CurrencyIdOf<T> = <<T as crate::Config>::Currencies as MultiCurrency<
+    <T as frame_system::Config>::AccountId,
+>>::CurrencyId;
+type BalanceOf<T> = <<T as crate::Config>::Currencies as MultiCurrency<
+    <T as frame_system::Config>::AccountId,
+>>::Balance;
+
+pub struct BalancesAdapter<T>(PhantomData<T>);
+
+impl<T: crate::Config> Default for BalancesAdapter<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: crate::Config> BalancesAdapter<T> {
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+
+    fn currency_id(ticker: &str) -> Option<CurrencyIdOf<T>> {
+        let currency_id = <T::Tickers as TickerToCurrency<_>>::currency_id(ticker);
+        if currency_id.is_none() {
+            trace!("native balance ticker '{}' not supported", ticker);
+        }
+        currency_id
+    }
+
+    /// Record the successful change for the simulation.
+    fn record(change: NativeBalanceChange) {
+        crate::Pallet::<T>::record_simulation(|record| record.balance_changes.push(change));
+    }
+
+    /// Record the failure, only the first one is kept.
+    fn fail(err: BalanceAccessError) {
+        crate::BalanceAccessFailure::<T>::mutate(|failure| {
+            if failure.is_none() {
+                *failure = Some(err);
+            }
+        });
+    }
+}
+
+impl<T: crate::Config> BalanceAccess for BalancesAdapter<T> {
     fn get_balance(
         &self,
         address: &move_core_types::account_address::AccountAddress,
         ticker: &str,
     ) -> Option<VmBalance> {
-        if !is_ticker_supported(ticker) {
-            trace!("native balance ticker '{}' not supported", ticker);
-            return None;
-        }
+        let currency_id = Self::currency_id(ticker)?;
 
         trace!(
             "native balance requested for address: {} (ticker: {})",
@@ -56,7 +230,7 @@ where
         address_to_account::<T::AccountId>(&address)
             .map_err(|_| error!("Can't convert address from Move to Substrate."))
             .and_then(|address| {
-                <balances::Module<T> as Currency<T::AccountId>>::free_balance(&address)
+                T::Currencies::free_balance(currency_id, &address)
                     .try_into()
                     .map_err(|_err| error!("Convert native balance to VM balance type."))
             })
@@ -69,30 +243,39 @@ where
         ticker: &str,
         amount: VmBalance,
     ) {
-        if !is_ticker_supported(ticker) {
-            return trace!("native balance ticker '{}' not supported", ticker);
-        }
+        let currency_id = match Self::currency_id(ticker) {
+            Some(currency_id) => currency_id,
+            None => return Self::fail(BalanceAccessError::Conversion),
+        };
 
         trace!("deposit resource {} requested, amount: {}", ticker, amount);
-        let imbalance = address_to_account::<T::AccountId>(&address)
-            .map_err(|_| error!("Can't convert address from Move to Substrate."))
+        let result = address_to_account::<T::AccountId>(&address)
+            .map_err(|_| {
+                error!("Can't convert address from Move to Substrate.");
+                BalanceAccessError::Conversion
+            })
             .and_then(|address| {
                 amount
                     .try_into()
-                    .map_err(|_err| error!("Can't convert VM balance to native balance type."))
+                    .map_err(|_err| {
+                        error!("Can't convert VM balance to native balance type.");
+                        BalanceAccessError::Conversion
+                    })
                     .and_then(|amount: BalanceOf<T>| {
-                        <balances::Module<T> as Currency<T::AccountId>>::withdraw(
-                            &address,
-                            amount,
-                            WithdrawReasons::RESERVE,
-                            ExistenceRequirement::AllowDeath,
-                        )
-                        .map_err(|_err| error!("Can't withdraw native balance."))
+                        T::Currencies::withdraw(currency_id, &address, amount).map_err(|_err| {
+                            error!("Can't withdraw native balance.");
+                            BalanceAccessError::Withdraw
+                        })
                     })
-                    .map(|imbalance| imbalance.peek())
-            })
-            .ok();
-        trace!("native balance withdraw imbalance: {:?}", imbalance);
+            });
+        match result {
+            Ok(()) => Self::record(NativeBalanceChange::Withdraw {
+                address: address.as_ref().to_vec(),
+                ticker: ticker.as_bytes().to_vec(),
+                amount,
+            }),
+            Err(err) => Self::fail(err),
+        }
     }
 
     fn withdraw(
@@ -101,27 +284,39 @@ where
         ticker: &str,
         amount: VmBalance,
     ) {
-        if !is_ticker_supported(ticker) {
-            return trace!("native balance ticker '{}' not supported", ticker);
-        }
+        let currency_id = match Self::currency_id(ticker) {
+            Some(currency_id) => currency_id,
+            None => return Self::fail(BalanceAccessError::Conversion),
+        };
 
         trace!("withdraw resource {} requested, amount: {}", ticker, amount);
-        let imbalance = address_to_account::<T::AccountId>(&address)
-            .map_err(|_err| error!("Can't convert address from Move to Substrate."))
+        let result = address_to_account::<T::AccountId>(&address)
+            .map_err(|_err| {
+                error!("Can't convert address from Move to Substrate.");
+                BalanceAccessError::Conversion
+            })
             .and_then(|address| {
                 amount
                     .try_into()
-                    .map_err(|_err| error!("Can't convert VM balance to native balance type."))
-                    .map(|amount: BalanceOf<T>| {
-                        <balances::Module<T> as Currency<T::AccountId>>::deposit_creating(
-                            &address, amount,
-                        )
+                    .map_err(|_err| {
+                        error!("Can't convert VM balance to native balance type.");
+                        BalanceAccessError::Conversion
                     })
-            })
-            .map(|imbalance| imbalance.peek())
-            // TODO: return result
-            .ok();
-        trace!("native balance deposit imbalance: {:?}", imbalance);
+                    .and_then(|amount: BalanceOf<T>| {
+                        T::Currencies::deposit(currency_id, &address, amount).map_err(|_err| {
+                            error!("Can't deposit native balance.");
+                            BalanceAccessError::Deposit
+                        })
+                    })
+            });
+        match result {
+            Ok(()) => Self::record(NativeBalanceChange::Deposit {
+                address: address.as_ref().to_vec(),
+                ticker: ticker.as_bytes().to_vec(),
+                amount,
+            }),
+            Err(err) => Self::fail(err),
+        }
     }
 }
 
@@ -141,7 +336,7 @@ pub mod boxed {
         f_withdraw: Box<dyn Fn(&AccountAddress, &str, VmBalance)>,
     }
 
-    impl<T: balances::Config> From<super::BalancesAdapter<T>> for BalancesBoxedAdapter {
+    impl<T: crate::Config> From<super::BalancesAdapter<T>> for BalancesBoxedAdapter {
         fn from(adapter: super::BalancesAdapter<T>) -> Self {
             Self {
                 f_get: Box::new(move |address, ticker| adapter.get_balance(address, ticker)),
@@ -157,7 +352,7 @@ pub mod boxed {
         }
     }
 
-    impl<T: balances::Config + 'static> From<&'static super::BalancesAdapter<T>>
+    impl<T: crate::Config + 'static> From<&'static super::BalancesAdapter<T>>
         for BalancesBoxedAdapter
     {
         fn from(balances: &'static super::BalancesAdapter<T>) -> Self {
@@ -3,14 +3,17 @@
 
 // How to use:
 // 1. Build node with feature `runtime-benchmarks`
-// 2. Run `./target/release/mv-node benchmark --dev -lsp_mvm=trace --pallet=sp_mvm --execution=wasm --wasm-execution=compiled --extrinsic='*' --steps=20 --repeat=10 --output=./target/sp-bench/`
+// 2. Run `./target/release/mv-node benchmark --dev -lsp_mvm=trace --pallet=sp_mvm --execution=wasm --wasm-execution=compiled --extrinsic='*' --steps=50 --repeat=20 --template=./.maintain/frame-weight-template.hbs --output=./pallets/sp-mvm/src/weights.rs`
+// 3. Or `make weights`, it renders `weights.rs` with `.maintain/frame-weight-template.hbs`
 
-use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::traits::EnsureOrigin;
 use frame_system::RawOrigin;
 use move_core_types::account_address::AccountAddress;
 use move_core_types::identifier::Identifier;
 use move_core_types::language_storage::{CORE_CODE_ADDRESS, ModuleId, StructTag};
 use move_vm::data::AccessKey;
+use sp_runtime::traits::{Hash, Zero};
 use sp_std::prelude::*;
 
 use crate::benchmarking::store::container;
@@ -20,155 +23,281 @@ use super::*;
 use super::Module as Mvm;
 
 benchmarks! {
-    publish_std {
-        let s in 0 .. 100;
-        let stdlib_modules = stdlib()
-        .into_iter()
-        .map(|(_, m)|m)
-        .collect::<Vec<_>>();
-    }: _(RawOrigin::Root, stdlib_modules, 100_000_000)
+    execute {
+        let b in 0 .. max_len(&transactions());
+        insert_store::<T>();
+
+        let caller: T::AccountId = whitelisted_caller();
+        let (_, tx) = fitting(transactions(), b);
+    }: _(RawOrigin::Signed(caller), tx, 500_000)
     verify {
-        for (name, _) in stdlib() {
-            assert!(VMStorage::<T>::contains_key(module_access_core(name)));
-        }
     }
-    publish_empty_module {
-        let s in 0 .. 100;
+    execute_multi_signer {
+        let b in 0 .. max_len(&signed_transactions());
+        insert_store::<T>();
+
         let caller: T::AccountId = whitelisted_caller();
-        let module = include_bytes!("../tests/benchmark_assets/target/modules/2_Empty.mv").to_vec();
-    }: publish_module(RawOrigin::Signed(caller), module, 100_000_000)
+        let (_, tx) = fitting(signed_transactions(), b);
+        let script_hash = T::Hashing::hash_of(&(&tx, &vec![caller.clone()]));
+    }: _(RawOrigin::Signed(caller.clone()), tx, vec![caller], 500_000)
     verify {
-        assert!(VMStorage::<T>::contains_key(module_access("Empty")));
+        assert!(!ScriptApprovals::<T>::contains_key(script_hash));
     }
-    publish_many_deps_module {
-        let s in 0 .. 100;
+    execute_function {
+        let b in 0 .. 1024;
+        let m in 0 .. max_len(&callable_modules());
         for (name, module) in stdlib() {
-            VMStorage::<T>::insert(module_access_core(name), module);
+            vm_storage_insert::<T>(module_access_core(name), module);
         }
+        let (name, module) = fitting(callable_modules(), m);
+        vm_storage_insert::<T>(module_access(name), module);
+
         let caller: T::AccountId = whitelisted_caller();
-        let module = include_bytes!("../tests/benchmark_assets/target/modules/22_StdImport.mv").to_vec();
-    }: publish_module(RawOrigin::Signed(caller), module, 100_000_000)
+        let module = types::MoveModuleId {
+            owner: addr::address_to_account::<T::AccountId>(&user_address()).unwrap(),
+            module: name.as_bytes().to_vec(),
+        };
+        let args = vec![bcs::to_bytes(&vec![0u8; b as usize]).unwrap()];
+    }: _(RawOrigin::Signed(caller), module, b"call".to_vec(), vec![], args, 500_000)
     verify {
-        assert!(VMStorage::<T>::contains_key(module_access("StdImport")));
     }
-    publish_s_module {
-        let s in 0 .. 100;
+    cancel_script_approval {
         let caller: T::AccountId = whitelisted_caller();
-        let module = include_bytes!("../tests/benchmark_assets/target/modules/6_S.mv").to_vec();
-    }: publish_module(RawOrigin::Signed(caller), module, 100_000_000)
+        let signer: T::AccountId = account("signer", 0, 0);
+        let script_hash = T::Hashing::hash_of(&transactions()[0].1);
+        let pending = types::ScriptApproval {
+            approvals: vec![(caller.clone(), Zero::zero()), (signer, Zero::zero())],
+            gas_limit: 500_000,
+            expires_at: frame_system::Pallet::<T>::block_number() + 1u32.into(),
+        };
+        ScriptApprovals::<T>::insert(script_hash, pending);
+    }: _(RawOrigin::Signed(caller), script_hash)
     verify {
-        assert!(VMStorage::<T>::contains_key(module_access("S")));
+        assert_eq!(ScriptApprovals::<T>::get(script_hash).unwrap().approvals.len(), 1);
     }
-    publish_m_module {
-        let s in 0 .. 100;
+    publish_module {
+        let b in 0 .. max_len(&modules());
+        for (name, module) in stdlib() {
+            vm_storage_insert::<T>(module_access_core(name), module);
+        }
         let caller: T::AccountId = whitelisted_caller();
-        let module = include_bytes!("../tests/benchmark_assets/target/modules/5_M.mv").to_vec();
-    }: publish_module(RawOrigin::Signed(caller), module, 100_000_000)
+        let (name, module) = fitting(modules(), b);
+    }: _(RawOrigin::Signed(caller), module, 100_000_000)
     verify {
-        assert!(VMStorage::<T>::contains_key(module_access("M")));
+        assert!(vm_storage_contains::<T>(module_access(name)));
     }
-    publish_l_module {
-        let s in 0 .. 100;
+    publish_package {
+        let b in 0 .. max_len(&modules());
+        let m in 1 .. modules().len() as u32;
+        for (name, module) in stdlib() {
+            vm_storage_insert::<T>(module_access_core(name), module);
+        }
         let caller: T::AccountId = whitelisted_caller();
-        let module = include_bytes!("../tests/benchmark_assets/target/modules/4_L.mv").to_vec();
-    }: publish_module(RawOrigin::Signed(caller), module, 100_000_000)
+        let (name, module) = fitting(modules(), b);
+        let others = modules()
+            .into_iter()
+            .filter(|(other, _)| *other != name)
+            .take(m as usize - 1)
+            .collect::<Vec<_>>();
+        let names = others.iter().map(|(name, _)| *name).chain(Some(name)).collect::<Vec<_>>();
+        let mut package = vec![module];
+        package.extend(others.into_iter().map(|(_, bc)| bc));
+        // Dove package is the BCS encoded list of the modules:
+        let package = bcs::to_bytes(&package).unwrap();
+    }: _(RawOrigin::Signed(caller), package, 100_000_000)
     verify {
-        assert!(VMStorage::<T>::contains_key(module_access("L")));
+        for name in names {
+            assert!(vm_storage_contains::<T>(module_access(name)));
+        }
     }
-    execute_many_params {
-        let s in 0 .. 100;
-        let caller: T::AccountId = whitelisted_caller();
-        let tx = include_bytes!("../tests/benchmark_assets/target/transactions/many_params.mvt").to_vec();
-    }: execute(RawOrigin::Signed(caller), tx, 500_000)
+    publish_std {
+        let b in 0 .. stdlib().iter().map(|(_, m)| m.len() as u32).sum();
+        let mut size = 0;
+        let stdlib_modules = stdlib()
+            .into_iter()
+            .take_while(|(_, m)| {
+                size += m.len() as u32;
+                size <= b
+            })
+            .collect::<Vec<_>>();
+        let names = stdlib_modules.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+        let stdlib_modules = stdlib_modules.into_iter().map(|(_, m)| m).collect::<Vec<_>>();
+    }: _<T::Origin>(T::StdPublishOrigin::successful_origin(), stdlib_modules, 100_000_000)
     verify {
-        // no-op
-    }
-    execute_store {
-        let s in 0 .. 100;
-         for (name, module) in stdlib() {
-            VMStorage::<T>::insert(module_access_core(name), module);
+        for name in names {
+            assert!(vm_storage_contains::<T>(module_access_core(name)));
         }
-        VMStorage::<T>::insert(module_access_core("Store"), include_bytes!("../tests/benchmark_assets/target/modules/1_Store.mv").to_vec());
-        let caller: T::AccountId = whitelisted_caller();
-        let tx = include_bytes!("../tests/benchmark_assets/target/transactions/store.mvt").to_vec();
-    }: execute(RawOrigin::Signed(caller), tx, 500_000)
+    }
+    add_oracle_feeder {
+        let feeder: T::AccountId = whitelisted_caller();
+    }: _(RawOrigin::Root, feeder.clone())
     verify {
-
-        let tag = StructTag {
-            address: CORE_CODE_ADDRESS,
-            module: Identifier::new("Store").unwrap(),
-            name: Identifier::new("Container").unwrap(),
-            type_params: vec![]
-         };
-
-        let ak = AccessKey::from((&AccountAddress::from_hex_literal("0xd861ea1ebf4800d4b89f4ff787ad79ee96d9a708c85b57da7eb8f9ddeda61291").unwrap(), &tag));
-
-        assert!(VMStorage::<T>::contains_key(ak.as_ref()));
+        assert!(OracleFeeders::<T>::get().contains(&feeder));
     }
-    execute_load {
-        let s in 0 .. 100;
-         for (name, module) in stdlib() {
-            VMStorage::<T>::insert(module_access_core(name), module);
-        }
-
-        let tag = StructTag {
-            address: CORE_CODE_ADDRESS,
-            module: Identifier::new("Store").unwrap(),
-            name: Identifier::new("Container").unwrap(),
-            type_params: vec![]
-         };
-
-        let ak = AccessKey::from((&CORE_CODE_ADDRESS, &tag));
-
-        VMStorage::<T>::insert(ak.as_ref().to_vec(), bcs::to_bytes(&container()).unwrap());
-
-        VMStorage::<T>::insert(module_access_core("Store"), include_bytes!("../tests/benchmark_assets/target/modules/1_Store.mv").to_vec());
-        let caller: T::AccountId = whitelisted_caller();
-        let tx = include_bytes!("../tests/benchmark_assets/target/transactions/load.mvt").to_vec();
-    }: execute(RawOrigin::Signed(caller), tx, 500_000)
+    remove_oracle_feeder {
+        let feeder: T::AccountId = whitelisted_caller();
+        OracleFeeders::<T>::put(vec![feeder.clone()]);
+        OraclePrices::<T>::insert(&feeder, b"BTC_PONT".to_vec(), (1, 0));
+    }: _(RawOrigin::Root, feeder.clone())
     verify {
+        assert!(!OracleFeeders::<T>::get().contains(&feeder));
     }
-    execute_store_event {
-        let s in 0 .. 100;
-         for (name, module) in stdlib() {
-            VMStorage::<T>::insert(module_access_core(name), module);
-        }
-        let caller: T::AccountId = whitelisted_caller();
-        let tx = include_bytes!("../tests/benchmark_assets/target/transactions/store_events.mvt").to_vec();
-    }: execute(RawOrigin::Signed(caller), tx, 500_000)
+    feed_price {
+        let feeder: T::AccountId = whitelisted_caller();
+        OracleFeeders::<T>::put(vec![feeder.clone()]);
+    }: _(RawOrigin::Signed(feeder.clone()), b"BTC_PONT".to_vec(), 1)
     verify {
+        assert!(OraclePrices::<T>::contains_key(&feeder, b"BTC_PONT".to_vec()));
     }
-    execute_vec_input {
-        let s in 0 .. 100;
+    set_upgrade_policy {
         let caller: T::AccountId = whitelisted_caller();
-        let tx = include_bytes!("../tests/benchmark_assets/target/transactions/vector_input.mvt").to_vec();
-    }: execute(RawOrigin::Signed(caller), tx, 500_000)
+    }: _(RawOrigin::Signed(caller), Some(b"Store".to_vec()), types::UpgradePolicy::Immutable)
     verify {
     }
-    execute_loop {
-        let s in 0 .. 100;
-        let caller: T::AccountId = whitelisted_caller();
-        let tx = include_bytes!("../tests/benchmark_assets/target/transactions/lp.mvt").to_vec();
-    }: execute(RawOrigin::Signed(caller), tx, 100_000_000)
+    set_min_gas_price {
+    }: _(RawOrigin::Root, 1)
     verify {
+        assert_eq!(MinGasPrice::<T>::get(), 1);
     }
 }
 
 impl_benchmark_test_suite!(Mvm, crate::mock::new_test_ext(), crate::mock::Test,);
 
+pub fn vm_storage_insert<T: Config>(key: Vec<u8>, value: Vec<u8>) {
+    let (address, path) = storage::split_key(&key);
+    VMStorage::<T>::insert(address, path, value);
+}
+
+pub fn vm_storage_contains<T: Config>(key: impl AsRef<[u8]>) -> bool {
+    let (address, path) = storage::split_key(key.as_ref());
+    VMStorage::<T>::contains_key(address, path)
+}
+
 pub fn module_access_core(name: &str) -> Vec<u8> {
     ModuleId::new(CORE_CODE_ADDRESS, Identifier::new(name).unwrap()).access_vector()
 }
 
 pub fn module_access(name: &str) -> Vec<u8> {
-    ModuleId::new(
-        AccountAddress::from_hex_literal(
-            "0xd861ea1ebf4800d4b89f4ff787ad79ee96d9a708c85b57da7eb8f9ddeda61291",
-        )
-        .unwrap(),
-        Identifier::new(name).unwrap(),
+    ModuleId::new(user_address(), Identifier::new(name).unwrap()).access_vector()
+}
+
+/// Address of the user modules in the benchmark assets.
+fn user_address() -> AccountAddress {
+    AccountAddress::from_hex_literal(
+        "0xd861ea1ebf4800d4b89f4ff787ad79ee96d9a708c85b57da7eb8f9ddeda61291",
     )
-    .access_vector()
+    .unwrap()
+}
+
+/// Stdlib, `Store` module and its `Container` resource used by the transactions.
+fn insert_store<T: Config>() {
+    for (name, module) in stdlib() {
+        vm_storage_insert::<T>(module_access_core(name), module);
+    }
+    vm_storage_insert::<T>(
+        module_access_core("Store"),
+        include_bytes!("../tests/benchmark_assets/target/modules/1_Store.mv").to_vec(),
+    );
+    let ak = AccessKey::from((&CORE_CODE_ADDRESS, &container_tag()));
+    vm_storage_insert::<T>(ak.as_ref().to_vec(), bcs::to_bytes(&container()).unwrap());
+}
+
+/// Asset of the largest size fitting into `len` bytes, the smallest one if none fits.
+fn fitting(mut assets: Vec<(&'static str, Vec<u8>)>, len: u32) -> (&'static str, Vec<u8>) {
+    assets.sort_by_key(|(_, bc)| bc.len());
+    let index = assets
+        .iter()
+        .rposition(|(_, bc)| bc.len() as u32 <= len)
+        .unwrap_or(0);
+    assets.swap_remove(index)
+}
+
+fn max_len(assets: &[(&'static str, Vec<u8>)]) -> u32 {
+    assets
+        .iter()
+        .map(|(_, bc)| bc.len() as u32)
+        .max()
+        .unwrap_or(0)
+}
+
+/// Modules published by the user, `StdImport` depends on the whole stdlib.
+pub fn modules() -> Vec<(&'static str, Vec<u8>)> {
+    vec![
+        (
+            "Empty",
+            include_bytes!("../tests/benchmark_assets/target/modules/2_Empty.mv").to_vec(),
+        ),
+        (
+            "L",
+            include_bytes!("../tests/benchmark_assets/target/modules/4_L.mv").to_vec(),
+        ),
+        (
+            "M",
+            include_bytes!("../tests/benchmark_assets/target/modules/5_M.mv").to_vec(),
+        ),
+        (
+            "S",
+            include_bytes!("../tests/benchmark_assets/target/modules/6_S.mv").to_vec(),
+        ),
+        (
+            "StdImport",
+            include_bytes!("../tests/benchmark_assets/target/modules/22_StdImport.mv").to_vec(),
+        ),
+    ]
+}
+
+/// Modules of the different sizes with the `call(vector<u8>)` function.
+fn callable_modules() -> Vec<(&'static str, Vec<u8>)> {
+    modules()
+        .into_iter()
+        .filter(|(name, _)| ["L", "M", "S"].contains(name))
+        .collect()
+}
+
+/// Transactions without heavy computations, their gas is paid separately.
+pub fn transactions() -> Vec<(&'static str, Vec<u8>)> {
+    vec![
+        (
+            "many_params",
+            include_bytes!("../tests/benchmark_assets/target/transactions/many_params.mvt")
+                .to_vec(),
+        ),
+        (
+            "store",
+            include_bytes!("../tests/benchmark_assets/target/transactions/store.mvt").to_vec(),
+        ),
+        (
+            "load",
+            include_bytes!("../tests/benchmark_assets/target/transactions/load.mvt").to_vec(),
+        ),
+        (
+            "store_events",
+            include_bytes!("../tests/benchmark_assets/target/transactions/store_events.mvt")
+                .to_vec(),
+        ),
+        (
+            "vector_input",
+            include_bytes!("../tests/benchmark_assets/target/transactions/vector_input.mvt")
+                .to_vec(),
+        ),
+    ]
+}
+
+/// Transactions with the single signer.
+fn signed_transactions() -> Vec<(&'static str, Vec<u8>)> {
+    transactions()
+        .into_iter()
+        .filter(|(name, _)| ["store", "store_events"].contains(name))
+        .collect()
+}
+
+fn container_tag() -> StructTag {
+    StructTag {
+        address: CORE_CODE_ADDRESS,
+        module: Identifier::new("Store").unwrap(),
+        name: Identifier::new("Container").unwrap(),
+        type_params: vec![],
+    }
 }
 
 pub fn stdlib() -> Vec<(&'static str, Vec<u8>)> {
@@ -301,86 +430,68 @@ mod tests {
     use frame_support::assert_ok;
 
     #[test]
-    fn publish_std() {
-        ExtBuilder::default().build().execute_with(|| {
-            assert_ok!(test_benchmark_publish_std::<Test>());
-        });
-    }
-
-    #[test]
-    fn publish_empty_module() {
+    fn execute() {
         ExtBuilder::default().build().execute_with(|| {
-            assert_ok!(test_benchmark_publish_empty_module::<Test>());
+            assert_ok!(test_benchmark_execute::<Test>());
         });
     }
 
     #[test]
-    fn publish_many_deps_module() {
+    fn execute_multi_signer() {
         ExtBuilder::default().build().execute_with(|| {
-            assert_ok!(test_benchmark_publish_many_deps_module::<Test>());
+            assert_ok!(test_benchmark_execute_multi_signer::<Test>());
         });
     }
 
     #[test]
-    fn publish_s_module() {
+    fn execute_function() {
         ExtBuilder::default().build().execute_with(|| {
-            assert_ok!(test_benchmark_publish_s_module::<Test>());
+            assert_ok!(test_benchmark_execute_function::<Test>());
         });
     }
 
     #[test]
-    fn publish_m_module() {
+    fn cancel_script_approval() {
         ExtBuilder::default().build().execute_with(|| {
-            assert_ok!(test_benchmark_publish_m_module::<Test>());
+            assert_ok!(test_benchmark_cancel_script_approval::<Test>());
         });
     }
 
     #[test]
-    fn publish_l_module() {
+    fn publish_module() {
         ExtBuilder::default().build().execute_with(|| {
-            assert_ok!(test_benchmark_publish_l_module::<Test>());
+            assert_ok!(test_benchmark_publish_module::<Test>());
         });
     }
 
     #[test]
-    fn execute_many_params() {
+    fn publish_package() {
         ExtBuilder::default().build().execute_with(|| {
-            assert_ok!(test_benchmark_execute_many_params::<Test>());
+            assert_ok!(test_benchmark_publish_package::<Test>());
         });
     }
 
     #[test]
-    fn execute_store() {
-        ExtBuilder::default().build().execute_with(|| {
-            assert_ok!(test_benchmark_execute_store::<Test>());
-        });
-    }
-
-    #[test]
-    fn execute_load() {
-        ExtBuilder::default().build().execute_with(|| {
-            assert_ok!(test_benchmark_execute_load::<Test>());
-        });
-    }
-
-    #[test]
-    fn execute_store_event() {
+    fn publish_std() {
         ExtBuilder::default().build().execute_with(|| {
-            assert_ok!(test_benchmark_execute_store_event::<Test>());
+            assert_ok!(test_benchmark_publish_std::<Test>());
         });
     }
 
     #[test]
-    fn execute_vec_input() {
+    fn oracle() {
         ExtBuilder::default().build().execute_with(|| {
-            assert_ok!(test_benchmark_execute_vec_input::<Test>());
+            assert_ok!(test_benchmark_add_oracle_feeder::<Test>());
+            assert_ok!(test_benchmark_remove_oracle_feeder::<Test>());
+            assert_ok!(test_benchmark_feed_price::<Test>());
         });
     }
 
     #[test]
-    fn execute_execute_loop() {
+    fn settings() {
         ExtBuilder::default().build().execute_with(|| {
-            assert_ok!(test_benchmark_eexecute_loop::<Test>());
+            assert_ok!(test_benchmark_set_upgrade_policy::<Test>());
+            assert_ok!(test_benchmark_set_min_gas_price::<Test>());
         });
     }
 }
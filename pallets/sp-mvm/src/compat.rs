@@ -0,0 +1,99 @@
+use crate::abi::module_abi;
+use crate::types::{FunctionAbi, ModuleAbi};
+
+/// Check that the `new` module bytecode can replace the `old` one
+/// without breaking the published resources and the dependent modules:
+/// - every struct is kept with the same kind, type parameters and fields;
+/// - every public function is kept with the same type parameters, parameters and returns.
+///
+/// New structs and functions can be added, function bodies can be changed.
+pub fn is_compatible(old: &[u8], new: &[u8]) -> Result<bool, &'static str> {
+    let old = module_abi(old)?;
+    let new = module_abi(new)?;
+    Ok(is_compatible_abi(&old, &new))
+}
+
+fn is_compatible_abi(old: &ModuleAbi, new: &ModuleAbi) -> bool {
+    old.address == new.address
+        && old.name == new.name
+        && old.structs.iter().all(|s| new.structs.contains(s))
+        && old
+            .functions
+            .iter()
+            .all(|f| new.functions.iter().any(|n| same_signature(f, n)))
+}
+
+fn same_signature(old: &FunctionAbi, new: &FunctionAbi) -> bool {
+    old.name == new.name
+        && old.type_parameters == new.type_parameters
+        && old.parameters == new.parameters
+        && old.returns == new.returns
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{StructAbi, TypeParameterKind};
+
+    fn function(name: &str, parameters: &[&str]) -> FunctionAbi {
+        FunctionAbi {
+            name: name.as_bytes().to_vec(),
+            type_parameters: vec![],
+            parameters: parameters.iter().map(|p| p.as_bytes().to_vec()).collect(),
+            returns: vec![],
+            acquires: vec![],
+        }
+    }
+
+    fn abi() -> ModuleAbi {
+        ModuleAbi {
+            address: vec![1],
+            name: b"Store".to_vec(),
+            functions: vec![function("store_u64", &["&signer", "u64"])],
+            structs: vec![StructAbi {
+                name: b"U64".to_vec(),
+                is_resource: true,
+                type_parameters: vec![],
+                fields: Some(vec![(b"val".to_vec(), b"u64".to_vec())]),
+            }],
+        }
+    }
+
+    #[test]
+    fn additions_are_compatible() {
+        let old = abi();
+        let mut new = abi();
+        new.functions
+            .push(function("store_u128", &["&signer", "u128"]));
+        new.functions[0].acquires.push(b"U64".to_vec());
+        new.structs.push(StructAbi {
+            name: b"U128".to_vec(),
+            is_resource: true,
+            type_parameters: vec![TypeParameterKind::All],
+            fields: Some(vec![]),
+        });
+        assert!(is_compatible_abi(&old, &new));
+        assert!(!is_compatible_abi(&new, &old));
+    }
+
+    #[test]
+    fn changes_are_incompatible() {
+        let old = abi();
+
+        let mut new = abi();
+        new.functions[0] = function("store_u64", &["&signer", "u128"]);
+        assert!(!is_compatible_abi(&old, &new));
+
+        let mut new = abi();
+        new.structs[0].fields = Some(vec![(b"value".to_vec(), b"u64".to_vec())]);
+        assert!(!is_compatible_abi(&old, &new));
+
+        let mut new = abi();
+        new.structs[0].is_resource = false;
+        assert!(!is_compatible_abi(&old, &new));
+
+        let mut new = abi();
+        new.name = b"Other".to_vec();
+        assert!(!is_compatible_abi(&old, &new));
+    }
+}
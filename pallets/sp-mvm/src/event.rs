@@ -47,12 +47,24 @@ impl<T: Config> TryInto<Event<T>> for MoveEventArguments {
             return Err(err);
         }
 
-        let ty_tag_enc = format!("{}", self.ty_tag).as_bytes().to_vec();
+        let ty_tag = self.ty_tag.try_into()?;
 
-        Ok(Event::Event(account, ty_tag_enc, self.message, caller))
+        Ok(Event::Event(account, ty_tag, self.message, caller))
     }
 }
 
+impl MoveEventArguments {
+    /// Type tag formatted as String, e.g. `0x1::Event::U64`.
+    pub fn ty_tag_string(&self) -> Vec<u8> {
+        type_tag_string(&self.ty_tag)
+    }
+}
+
+/// Type tag formatted as String, e.g. `0x1::Event::U64`.
+pub fn type_tag_string(ty_tag: &TypeTag) -> Vec<u8> {
+    format!("{}", ty_tag).as_bytes().to_vec()
+}
+
 impl<F: Fn(MoveEventArguments)> EventHandler for EventWriter<F> {
     #[inline]
     fn on_event(
@@ -0,0 +1,255 @@
+//! Gas price of the Move transactions.
+//!
+//! The sender sets the gas price in the [`ChargeGasPrice`] signed extension.
+//! The price must be at least `MinGasPrice`, and it's the transaction priority.
+//! The gas limit is paid upfront by the price, the unused gas is refunded after the dispatch.
+//!
+//! The weight of the Move calls includes the gas, so the transaction payment
+//! must charge the weight fee through [`ExcludeGasFee`] not to charge the gas twice.
+
+use core::marker::PhantomData;
+use codec::{Encode, Decode};
+use frame_support::dispatch::DispatchInfo;
+use frame_support::traits::{
+    Currency, ExistenceRequirement, Imbalance, IsSubType, OnUnbalanced, WithdrawReasons,
+};
+use frame_support::weights::{PostDispatchInfo, Weight, WeightToFeePolynomial};
+use sp_runtime::FixedPointNumber;
+use sp_runtime::FixedPointOperand;
+use transaction_payment::OnChargeTransaction;
+use sp_runtime::traits::{DispatchInfoOf, Dispatchable, PostDispatchInfoOf, SignedExtension};
+use sp_runtime::traits::{Saturating, UniqueSaturatedInto, Zero};
+use sp_runtime::transaction_validity::{
+    InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
+};
+use sp_runtime::DispatchResult;
+
+use crate::gas::GasWeightMapping;
+use crate::{Call, Config, MinGasPrice};
+
+/// Custom `InvalidTransaction` code of the gas price below `MinGasPrice`.
+pub const GAS_PRICE_TOO_LOW: u8 = 1;
+
+pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+    <T as frame_system::Config>::AccountId,
+>>::NegativeImbalance;
+
+/// Gas limit of the Move call, `None` for the calls without gas.
+pub fn gas_limit<T: Config>(call: &Call<T>) -> Option<u64> {
+    match call {
+        Call::execute(_, gas_limit)
+        | Call::execute_multi_signer(_, _, gas_limit)
+        | Call::execute_function(_, _, _, _, gas_limit)
+        | Call::publish_module(_, gas_limit)
+        | Call::publish_package(_, gas_limit)
+        | Call::publish_std(_, gas_limit) => Some(*gas_limit),
+        _ => None,
+    }
+}
+
+/// Fee for the gas by the price.
+pub fn gas_fee<T: Config>(gas: u64, gas_price: u64) -> crate::BalanceOf<T> {
+    (gas as u128)
+        .saturating_mul(gas_price as u128)
+        .unique_saturated_into()
+}
+
+/// Signed extension with the gas price of the Move transaction.
+#[derive(Encode, Decode, Clone, Eq, PartialEq)]
+pub struct ChargeGasPrice<T: Config + Send + Sync>(#[codec(compact)] u64, PhantomData<T>);
+
+impl<T: Config + Send + Sync> ChargeGasPrice<T> {
+    /// Gas price of the Move calls, ignored by the others.
+    pub fn from(gas_price: u64) -> Self {
+        Self(gas_price, PhantomData)
+    }
+
+    fn validate_gas(
+        &self,
+        who: &T::AccountId,
+        call: &<T as frame_system::Config>::Call,
+    ) -> Result<Option<u64>, TransactionValidityError>
+    where
+        <T as frame_system::Config>::Call: IsSubType<Call<T>>,
+    {
+        let gas_limit = match call.is_sub_type().and_then(gas_limit::<T>) {
+            Some(gas_limit) => gas_limit,
+            None => return Ok(None),
+        };
+        if self.0 < MinGasPrice::<T>::get() {
+            return Err(InvalidTransaction::Custom(GAS_PRICE_TOO_LOW).into());
+        }
+
+        let fee = gas_fee::<T>(gas_limit, self.0);
+        let new_balance = T::Currency::free_balance(who).saturating_sub(fee);
+        T::Currency::ensure_can_withdraw(who, fee, WithdrawReasons::FEE, new_balance)
+            .map_err(|_| InvalidTransaction::Payment)?;
+        Ok(Some(gas_limit))
+    }
+}
+
+impl<T: Config + Send + Sync> sp_std::fmt::Debug for ChargeGasPrice<T> {
+    #[cfg(feature = "std")]
+    fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+        write!(f, "ChargeGasPrice<{:?}>", self.0)
+    }
+    #[cfg(not(feature = "std"))]
+    fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+        Ok(())
+    }
+}
+
+impl<T: Config + Send + Sync> SignedExtension for ChargeGasPrice<T>
+where
+    <T as frame_system::Config>::Call:
+        IsSubType<Call<T>> + Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+{
+    const IDENTIFIER: &'static str = "ChargeGasPrice";
+    type AccountId = T::AccountId;
+    type Call = <T as frame_system::Config>::Call;
+    type AdditionalSigned = ();
+    /// Sender, gas price, gas limit and the paid fee
+    type Pre = Option<(T::AccountId, u64, u64, NegativeImbalanceOf<T>)>;
+
+    fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+        Ok(())
+    }
+
+    fn validate(
+        &self,
+        who: &Self::AccountId,
+        call: &Self::Call,
+        _info: &DispatchInfoOf<Self::Call>,
+        _len: usize,
+    ) -> TransactionValidity {
+        match self.validate_gas(who, call)? {
+            Some(_) => Ok(ValidTransaction {
+                priority: self.0,
+                ..Default::default()
+            }),
+            None => Ok(Default::default()),
+        }
+    }
+
+    fn pre_dispatch(
+        self,
+        who: &Self::AccountId,
+        call: &Self::Call,
+        _info: &DispatchInfoOf<Self::Call>,
+        _len: usize,
+    ) -> Result<Self::Pre, TransactionValidityError> {
+        let gas_limit = match self.validate_gas(who, call)? {
+            Some(gas_limit) => gas_limit,
+            None => return Ok(None),
+        };
+
+        let paid = T::Currency::withdraw(
+            who,
+            gas_fee::<T>(gas_limit, self.0),
+            WithdrawReasons::FEE,
+            ExistenceRequirement::KeepAlive,
+        )
+        .map_err(|_| InvalidTransaction::Payment)?;
+        Ok(Some((who.clone(), self.0, gas_limit, paid)))
+    }
+
+    fn post_dispatch(
+        pre: Self::Pre,
+        info: &DispatchInfoOf<Self::Call>,
+        post_info: &PostDispatchInfoOf<Self::Call>,
+        _len: usize,
+        _result: &DispatchResult,
+    ) -> Result<(), TransactionValidityError> {
+        let (who, gas_price, gas_limit, paid) = match pre {
+            Some(pre) => pre,
+            None => return Ok(()),
+        };
+
+        // the weight is the benchmarked base weight and the gas,
+        // calls failed before the VM run report no actual weight and use no gas
+        let base_weight = info
+            .weight
+            .saturating_sub(T::GasWeightMapping::gas_to_weight(gas_limit));
+        let gas_used = match post_info.actual_weight {
+            Some(_) => T::GasWeightMapping::weight_to_gas(
+                post_info
+                    .calc_actual_weight(info)
+                    .saturating_sub(base_weight),
+            ),
+            None => 0,
+        };
+        let unused = gas_limit.saturating_sub(gas_used);
+        let (refund, fee) = paid.split(gas_fee::<T>(unused, gas_price));
+        if !refund.peek().is_zero() {
+            T::Currency::resolve_creating(&who, refund);
+        }
+        T::GasFees::on_unbalanced(fee);
+        Ok(())
+    }
+}
+
+/// Transaction payment `OnChargeTransaction` charging the weight fee without the gas weight
+/// of the Move calls, the gas is paid by [`ChargeGasPrice`] instead.
+/// `W` is the `WeightToFee` of the transaction payment.
+pub struct ExcludeGasFee<T, OCT, W>(PhantomData<(T, OCT, W)>);
+
+impl<T, OCT, W> ExcludeGasFee<T, OCT, W>
+where
+    T: Config + transaction_payment::Config,
+    OCT: OnChargeTransaction<T>,
+    OCT::Balance: FixedPointOperand,
+    W: WeightToFeePolynomial<Balance = OCT::Balance>,
+{
+    /// Share of the `gas_weight` in the weight fee of the whole `weight`.
+    fn gas_weight_fee(weight: Weight, gas_weight: Weight) -> OCT::Balance {
+        let fee = W::calc(&weight).saturating_sub(W::calc(&weight.saturating_sub(gas_weight)));
+        transaction_payment::Module::<T>::next_fee_multiplier().saturating_mul_int(fee)
+    }
+}
+
+impl<T, OCT, W> OnChargeTransaction<T> for ExcludeGasFee<T, OCT, W>
+where
+    T: Config + transaction_payment::Config,
+    <T as frame_system::Config>::Call: IsSubType<Call<T>>,
+    OCT: OnChargeTransaction<T>,
+    OCT::Balance: FixedPointOperand,
+    W: WeightToFeePolynomial<Balance = OCT::Balance>,
+{
+    type Balance = OCT::Balance;
+    /// Liquidity info of `OCT` and the gas weight of the gas limit
+    type LiquidityInfo = (OCT::LiquidityInfo, Weight);
+
+    fn withdraw_fee(
+        who: &T::AccountId,
+        call: &<T as frame_system::Config>::Call,
+        info: &DispatchInfoOf<<T as frame_system::Config>::Call>,
+        fee: Self::Balance,
+        tip: Self::Balance,
+    ) -> Result<Self::LiquidityInfo, TransactionValidityError> {
+        let gas_weight = call
+            .is_sub_type()
+            .and_then(gas_limit::<T>)
+            .map(T::GasWeightMapping::gas_to_weight)
+            .unwrap_or_default();
+        let fee = fee.saturating_sub(Self::gas_weight_fee(info.weight, gas_weight));
+        let paid = OCT::withdraw_fee(who, call, info, fee, tip)?;
+        Ok((paid, gas_weight))
+    }
+
+    fn correct_and_deposit_fee(
+        who: &T::AccountId,
+        info: &DispatchInfoOf<<T as frame_system::Config>::Call>,
+        post_info: &PostDispatchInfoOf<<T as frame_system::Config>::Call>,
+        corrected_fee: Self::Balance,
+        tip: Self::Balance,
+        (paid, gas_weight): Self::LiquidityInfo,
+    ) -> Result<(), TransactionValidityError> {
+        // the same gas used as refunded by `ChargeGasPrice`
+        let actual_weight = post_info.calc_actual_weight(info);
+        let base_weight = info.weight.saturating_sub(gas_weight);
+        let gas_used_weight = actual_weight.saturating_sub(base_weight);
+        let corrected_fee =
+            corrected_fee.saturating_sub(Self::gas_weight_fee(actual_weight, gas_used_weight));
+        OCT::correct_and_deposit_fee(who, info, post_info, corrected_fee, tip, paid)
+    }
+}
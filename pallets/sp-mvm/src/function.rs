@@ -0,0 +1,238 @@
+use core::convert::TryFrom;
+use sp_std::prelude::*;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::identifier::IdentStr;
+use move_core_types::language_storage::ModuleId;
+use move_core_types::language_storage::TypeTag;
+use move_vm_types::values::Value;
+use vm::access::ModuleAccess;
+use vm::file_format::AddressIdentifierIndex;
+use vm::file_format::Bytecode;
+use vm::file_format::CodeUnit;
+use vm::file_format::CompiledModule;
+use vm::file_format::CompiledScriptMut;
+use vm::file_format::FunctionHandle;
+use vm::file_format::FunctionHandleIndex;
+use vm::file_format::FunctionInstantiation;
+use vm::file_format::FunctionInstantiationIndex;
+use vm::file_format::IdentifierIndex;
+use vm::file_format::LocalIndex;
+use vm::file_format::ModuleHandle;
+use vm::file_format::ModuleHandleIndex;
+use vm::file_format::Signature;
+use vm::file_format::SignatureIndex;
+use vm::file_format::SignatureToken;
+use vm::file_format::TypeParameterIndex;
+
+use crate::layout::{LayoutResult, ValueDecoder};
+use crate::types::MoveValue;
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+#[cfg(not(feature = "std"))]
+use alloc::borrow::ToOwned;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum FunctionCallError {
+    /// No function with such name in the module
+    NotFound,
+    /// Function isn't public
+    NotPublic,
+    /// Function parameters or return values can't be passed through a script
+    UnsupportedSignature,
+}
+
+/// Script calling the module function, see [`function_call`].
+pub struct FunctionCall {
+    /// Script bytecode
+    pub code: Vec<u8>,
+    /// Number of the leading `&signer` parameters
+    pub signers_count: usize,
+    /// Types of the parameters following the signers
+    pub params: Vec<TypeTag>,
+}
+
+/// Build script passing its parameters to the public function of the module as is,
+/// so the function is called the same way as from a compiled script.
+/// Function parameters must be `&signer`s followed by the primitives or `vector<u8>`,
+/// return values of these types are dropped.
+pub fn function_call(
+    module: &CompiledModule,
+    function: &IdentStr,
+) -> Result<FunctionCall, FunctionCallError> {
+    let def = module
+        .function_defs()
+        .iter()
+        .find(|def| {
+            module.identifier_at(module.function_handle_at(def.function).name) == function
+        })
+        .ok_or(FunctionCallError::NotFound)?;
+    if !def.is_public() {
+        return Err(FunctionCallError::NotPublic);
+    }
+
+    let handle = module.function_handle_at(def.function);
+    let params = module.signature_at(handle.parameters).0.clone();
+    let returns = module.signature_at(handle.return_).0.clone();
+    if params.len() > LocalIndex::MAX as usize {
+        return Err(FunctionCallError::UnsupportedSignature);
+    }
+
+    let signers_count = params
+        .iter()
+        .take_while(|token| is_signer_ref(token))
+        .count();
+    let arg_types = params[signers_count..]
+        .iter()
+        .map(arg_type_tag)
+        .collect::<Option<Vec<_>>>()
+        .ok_or(FunctionCallError::UnsupportedSignature)?;
+    if !returns.iter().all(|token| arg_type_tag(token).is_some()) {
+        return Err(FunctionCallError::UnsupportedSignature);
+    }
+
+    let mut code = (0..params.len())
+        .map(|idx| Bytecode::MoveLoc(idx as LocalIndex))
+        .collect::<Vec<_>>();
+    let mut signatures = vec![
+        Signature(params),
+        Signature(returns.clone()),
+        Signature(Vec::new()),
+    ];
+    let mut function_instantiations = Vec::new();
+    if handle.type_parameters.is_empty() {
+        code.push(Bytecode::Call(FunctionHandleIndex(0)));
+    } else {
+        // pass script type parameters to the function as is:
+        signatures.push(Signature(
+            (0..handle.type_parameters.len())
+                .map(|idx| SignatureToken::TypeParameter(idx as TypeParameterIndex))
+                .collect(),
+        ));
+        function_instantiations.push(FunctionInstantiation {
+            handle: FunctionHandleIndex(0),
+            type_parameters: SignatureIndex(3),
+        });
+        code.push(Bytecode::CallGeneric(FunctionInstantiationIndex(0)));
+    }
+    code.extend(returns.iter().map(|_| Bytecode::Pop));
+    code.push(Bytecode::Ret);
+
+    let id: ModuleId = module.self_id();
+    let script = CompiledScriptMut {
+        module_handles: vec![ModuleHandle {
+            address: AddressIdentifierIndex(0),
+            name: IdentifierIndex(0),
+        }],
+        struct_handles: Vec::new(),
+        function_handles: vec![FunctionHandle {
+            module: ModuleHandleIndex(0),
+            name: IdentifierIndex(1),
+            parameters: SignatureIndex(0),
+            return_: SignatureIndex(1),
+            type_parameters: handle.type_parameters.clone(),
+        }],
+        function_instantiations,
+        signatures,
+        identifiers: vec![id.name().to_owned(), function.to_owned()],
+        address_identifiers: vec![*id.address()],
+        constant_pool: Vec::new(),
+        type_parameters: handle.type_parameters.clone(),
+        parameters: SignatureIndex(0),
+        code: CodeUnit {
+            locals: SignatureIndex(2),
+            code,
+        },
+    };
+
+    let mut bytecode = Vec::new();
+    script
+        .serialize(&mut bytecode)
+        .map_err(|_| FunctionCallError::UnsupportedSignature)?;
+
+    Ok(FunctionCall {
+        code: bytecode,
+        signers_count,
+        params: arg_types,
+    })
+}
+
+/// Decode BCS encoded arguments of the function call.
+pub fn decode_args(params: &[TypeTag], args: &[Vec<u8>]) -> LayoutResult<Vec<Value>> {
+    if params.len() != args.len() {
+        return Err("Wrong number of arguments");
+    }
+
+    let decoder = ValueDecoder::new(|_: &ModuleId| None);
+    params
+        .iter()
+        .zip(args.iter())
+        .map(|(tag, arg)| match decoder.decode(tag, arg)? {
+            MoveValue::Bool(v) => Ok(Value::bool(v)),
+            MoveValue::U8(v) => Ok(Value::u8(v)),
+            MoveValue::U64(v) => Ok(Value::u64(v)),
+            MoveValue::U128(v) => Ok(Value::u128(v)),
+            MoveValue::Address(v) => {
+                let address = AccountAddress::try_from(&v[..]).map_err(|_| "Invalid address")?;
+                Ok(Value::address(address))
+            }
+            MoveValue::Vector(items) => Ok(Value::vector_u8(items.into_iter().filter_map(
+                |item| match item {
+                    MoveValue::U8(v) => Some(v),
+                    _ => None,
+                },
+            ))),
+            _ => Err("Unsupported argument type"),
+        })
+        .collect()
+}
+
+fn is_signer_ref(token: &SignatureToken) -> bool {
+    matches!(token, SignatureToken::Reference(inner) if **inner == SignatureToken::Signer)
+}
+
+/// Type of the script argument, only primitives and `vector<u8>` are supported.
+fn arg_type_tag(token: &SignatureToken) -> Option<TypeTag> {
+    Some(match token {
+        SignatureToken::Bool => TypeTag::Bool,
+        SignatureToken::U8 => TypeTag::U8,
+        SignatureToken::U64 => TypeTag::U64,
+        SignatureToken::U128 => TypeTag::U128,
+        SignatureToken::Address => TypeTag::Address,
+        SignatureToken::Vector(inner) if **inner == SignatureToken::U8 => {
+            TypeTag::Vector(Box::new(TypeTag::U8))
+        }
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_call_args() {
+        let params = vec![TypeTag::U64, TypeTag::Vector(Box::new(TypeTag::U8))];
+        let args = vec![42u64.to_le_bytes().to_vec(), vec![2, 1, 2]];
+        let values = decode_args(&params, &args).unwrap();
+        assert_eq!(values.len(), 2);
+        assert!(values[0].equals(&Value::u64(42)).unwrap());
+        assert!(values[1].equals(&Value::vector_u8(vec![1, 2])).unwrap());
+
+        assert!(decode_args(&params, &args[..1]).is_err());
+        assert!(decode_args(&[TypeTag::U64], &[vec![0; 7]]).is_err());
+    }
+
+    #[test]
+    fn script_arg_types() {
+        assert_eq!(Some(TypeTag::U64), arg_type_tag(&SignatureToken::U64));
+        assert_eq!(
+            None,
+            arg_type_tag(&SignatureToken::Vector(Box::new(SignatureToken::U64)))
+        );
+        assert!(is_signer_ref(&SignatureToken::Reference(Box::new(
+            SignatureToken::Signer
+        ))));
+        assert!(!is_signer_ref(&SignatureToken::Signer));
+    }
+}
@@ -0,0 +1,371 @@
+use sp_std::prelude::*;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::identifier::Identifier;
+use move_core_types::language_storage::ModuleId;
+use move_core_types::language_storage::StructTag;
+use move_core_types::language_storage::TypeTag;
+use vm::access::ModuleAccess;
+use vm::file_format::CompiledModule;
+use vm::file_format::SignatureToken;
+use vm::file_format::StructFieldInformation;
+
+use crate::types::MoveValue;
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+#[cfg(not(feature = "std"))]
+use alloc::borrow::ToOwned;
+
+/// Max depth of nested types while decoding.
+const MAX_DEPTH: usize = 64;
+
+pub type LayoutResult<T> = Result<T, &'static str>;
+
+/// Parse type tag, e.g. `vector<0x1::PONT::T>`.
+/// Addresses are hex only (`0x...`).
+pub fn parse_type_tag(s: &str) -> LayoutResult<TypeTag> {
+    let mut parser = Parser::new(s);
+    let tag = parser.type_tag(0)?;
+    parser.end()?;
+    Ok(tag)
+}
+
+/// Parse struct tag, e.g. `0x1::Account::Balance<0x1::PONT::T>`.
+/// Addresses are hex only (`0x...`).
+pub fn parse_struct_tag(s: &str) -> LayoutResult<StructTag> {
+    match parse_type_tag(s)? {
+        TypeTag::Struct(tag) => Ok(tag),
+        _ => Err("Struct tag expected"),
+    }
+}
+
+struct Parser<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    fn new(s: &'a str) -> Self {
+        Self { rest: s.trim() }
+    }
+
+    fn end(&self) -> LayoutResult<()> {
+        if self.rest.trim().is_empty() {
+            Ok(())
+        } else {
+            Err("Unexpected trailing characters in type tag")
+        }
+    }
+
+    fn skip_spaces(&mut self) {
+        self.rest = self.rest.trim_start();
+    }
+
+    fn consume(&mut self, token: &str) -> bool {
+        self.skip_spaces();
+        if self.rest.starts_with(token) {
+            self.rest = &self.rest[token.len()..];
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, token: &'static str) -> LayoutResult<()> {
+        if self.consume(token) {
+            Ok(())
+        } else {
+            Err("Unexpected token in type tag")
+        }
+    }
+
+    fn word(&mut self) -> LayoutResult<&'a str> {
+        self.skip_spaces();
+        let len = self
+            .rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or_else(|| self.rest.len());
+        if len == 0 {
+            return Err("Identifier expected in type tag");
+        }
+        let (word, rest) = self.rest.split_at(len);
+        self.rest = rest;
+        Ok(word)
+    }
+
+    fn type_tag(&mut self, depth: usize) -> LayoutResult<TypeTag> {
+        if depth > MAX_DEPTH {
+            return Err("Type tag is too deep");
+        }
+
+        let word = self.word()?;
+        Ok(match word {
+            "bool" => TypeTag::Bool,
+            "u8" => TypeTag::U8,
+            "u64" => TypeTag::U64,
+            "u128" => TypeTag::U128,
+            "address" => TypeTag::Address,
+            "signer" => TypeTag::Signer,
+            "vector" => {
+                self.expect("<")?;
+                let tag = self.type_tag(depth + 1)?;
+                self.expect(">")?;
+                TypeTag::Vector(Box::new(tag))
+            }
+            address => TypeTag::Struct(self.struct_tag(address, depth)?),
+        })
+    }
+
+    fn struct_tag(&mut self, address: &str, depth: usize) -> LayoutResult<StructTag> {
+        let address =
+            AccountAddress::from_hex_literal(address).map_err(|_| "Invalid hex address")?;
+        self.expect("::")?;
+        let module = Identifier::new(self.word()?).map_err(|_| "Invalid module name")?;
+        self.expect("::")?;
+        let name = Identifier::new(self.word()?).map_err(|_| "Invalid struct name")?;
+
+        let mut type_params = Vec::new();
+        if self.consume("<") {
+            loop {
+                type_params.push(self.type_tag(depth + 1)?);
+                if self.consume(">") {
+                    break;
+                }
+                self.expect(",")?;
+            }
+        }
+
+        Ok(StructTag {
+            address,
+            module,
+            name,
+            type_params,
+        })
+    }
+}
+
+/// Decodes BCS values by the struct layouts of the published modules.
+pub struct ValueDecoder<F> {
+    /// Returns module bytecode by the id.
+    get_module: F,
+}
+
+impl<F> ValueDecoder<F>
+where
+    F: Fn(&ModuleId) -> Option<Vec<u8>>,
+{
+    pub fn new(get_module: F) -> Self {
+        Self { get_module }
+    }
+
+    /// Decode the whole `bytes` as value of the `tag` type.
+    pub fn decode(&self, tag: &TypeTag, bytes: &[u8]) -> LayoutResult<MoveValue> {
+        let mut input = bytes;
+        let value = self.value(tag, &mut input, 0)?;
+        if input.is_empty() {
+            Ok(value)
+        } else {
+            Err("Value has trailing bytes")
+        }
+    }
+
+    fn value(&self, tag: &TypeTag, input: &mut &[u8], depth: usize) -> LayoutResult<MoveValue> {
+        if depth > MAX_DEPTH {
+            return Err("Value is too deep");
+        }
+
+        Ok(match tag {
+            TypeTag::Bool => match read_bytes(input, 1)?[0] {
+                0 => MoveValue::Bool(false),
+                1 => MoveValue::Bool(true),
+                _ => return Err("Invalid bool"),
+            },
+            TypeTag::U8 => MoveValue::U8(read_bytes(input, 1)?[0]),
+            TypeTag::U64 => {
+                let mut buf = [0; 8];
+                buf.copy_from_slice(read_bytes(input, 8)?);
+                MoveValue::U64(u64::from_le_bytes(buf))
+            }
+            TypeTag::U128 => {
+                let mut buf = [0; 16];
+                buf.copy_from_slice(read_bytes(input, 16)?);
+                MoveValue::U128(u128::from_le_bytes(buf))
+            }
+            TypeTag::Address => {
+                MoveValue::Address(read_bytes(input, AccountAddress::LENGTH)?.to_vec())
+            }
+            TypeTag::Signer => {
+                MoveValue::Signer(read_bytes(input, AccountAddress::LENGTH)?.to_vec())
+            }
+            TypeTag::Vector(tag) => {
+                let len = read_uleb128(input)?;
+                let mut items = Vec::new();
+                for _ in 0..len {
+                    items.push(self.value(tag, input, depth + 1)?);
+                }
+                MoveValue::Vector(items)
+            }
+            TypeTag::Struct(tag) => {
+                let fields = self.struct_fields(tag)?;
+                let mut values = Vec::with_capacity(fields.len());
+                for (name, tag) in fields.into_iter() {
+                    let value = self.value(&tag, input, depth + 1)?;
+                    values.push((name, value));
+                }
+                MoveValue::Struct(values)
+            }
+        })
+    }
+
+    /// Struct fields names and types with substituted type parameters.
+    pub fn struct_fields(&self, tag: &StructTag) -> LayoutResult<Vec<(Vec<u8>, TypeTag)>> {
+        let id = ModuleId::new(tag.address, tag.module.clone());
+        let bytecode = (self.get_module)(&id).ok_or("Module not found")?;
+        let module = CompiledModule::deserialize(&bytecode).map_err(|_| "Invalid module")?;
+
+        let def = module
+            .struct_defs()
+            .iter()
+            .find(|def| {
+                let handle = module.struct_handle_at(def.struct_handle);
+                module.identifier_at(handle.name) == tag.name.as_ident_str()
+            })
+            .ok_or("Struct not found")?;
+
+        match &def.field_information {
+            StructFieldInformation::Native => Err("Native struct can't be decoded"),
+            StructFieldInformation::Declared(fields) => fields
+                .iter()
+                .map(|field| {
+                    let name = module
+                        .identifier_at(field.name)
+                        .as_str()
+                        .as_bytes()
+                        .to_vec();
+                    let tag =
+                        signature_to_type_tag(&module, &field.signature.0, &tag.type_params)?;
+                    Ok((name, tag))
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Type tag for the signature token of the module with substituted type parameters.
+pub fn signature_to_type_tag(
+    module: &CompiledModule,
+    token: &SignatureToken,
+    type_params: &[TypeTag],
+) -> LayoutResult<TypeTag> {
+    let struct_tag = |idx, type_params: Vec<TypeTag>| -> LayoutResult<StructTag> {
+        let handle = module.struct_handle_at(idx);
+        let module_handle = module.module_handle_at(handle.module);
+        Ok(StructTag {
+            address: *module.address_identifier_at(module_handle.address),
+            module: module.identifier_at(module_handle.name).to_owned(),
+            name: module.identifier_at(handle.name).to_owned(),
+            type_params,
+        })
+    };
+
+    Ok(match token {
+        SignatureToken::Bool => TypeTag::Bool,
+        SignatureToken::U8 => TypeTag::U8,
+        SignatureToken::U64 => TypeTag::U64,
+        SignatureToken::U128 => TypeTag::U128,
+        SignatureToken::Address => TypeTag::Address,
+        SignatureToken::Signer => TypeTag::Signer,
+        SignatureToken::Vector(token) => {
+            TypeTag::Vector(Box::new(signature_to_type_tag(module, token, type_params)?))
+        }
+        SignatureToken::Struct(idx) => TypeTag::Struct(struct_tag(*idx, Vec::new())?),
+        SignatureToken::StructInstantiation(idx, tokens) => {
+            let params = tokens
+                .iter()
+                .map(|token| signature_to_type_tag(module, token, type_params))
+                .collect::<LayoutResult<Vec<_>>>()?;
+            TypeTag::Struct(struct_tag(*idx, params)?)
+        }
+        SignatureToken::TypeParameter(idx) => type_params
+            .get(*idx as usize)
+            .cloned()
+            .ok_or("Missing type parameter")?,
+        SignatureToken::Reference(_) | SignatureToken::MutableReference(_) => {
+            return Err("References aren't storable")
+        }
+    })
+}
+
+fn read_bytes<'a>(input: &mut &'a [u8], len: usize) -> LayoutResult<&'a [u8]> {
+    if input.len() < len {
+        return Err("Unexpected end of value");
+    }
+    let (bytes, rest) = input.split_at(len);
+    *input = rest;
+    Ok(bytes)
+}
+
+fn read_uleb128(input: &mut &[u8]) -> LayoutResult<u64> {
+    let mut value: u64 = 0;
+    for shift in (0..64).step_by(7) {
+        let byte = read_bytes(input, 1)?[0];
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err("Invalid length")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_primitive_tags() {
+        assert_eq!(Ok(TypeTag::U64), parse_type_tag("u64"));
+        assert_eq!(
+            Ok(TypeTag::Vector(Box::new(TypeTag::U8))),
+            parse_type_tag(" vector< u8 >")
+        );
+        assert!(parse_type_tag("u32").is_err());
+        assert!(parse_type_tag("u64 u8").is_err());
+    }
+
+    #[test]
+    fn parse_generic_struct_tag() {
+        let tag = parse_struct_tag("0x1::Account::Balance<0x1::PONT::T>").unwrap();
+        assert_eq!(
+            tag.address,
+            AccountAddress::from_hex_literal("0x1").unwrap()
+        );
+        assert_eq!(tag.module.as_str(), "Account");
+        assert_eq!(tag.name.as_str(), "Balance");
+        assert_eq!(tag.type_params.len(), 1);
+        match &tag.type_params[0] {
+            TypeTag::Struct(param) => assert_eq!(param.name.as_str(), "T"),
+            _ => panic!("struct type param expected"),
+        }
+
+        assert!(parse_struct_tag("0x1::Account").is_err());
+        assert!(parse_struct_tag("0x1::Account::Balance<u8").is_err());
+        assert!(parse_struct_tag("u8").is_err());
+    }
+
+    #[test]
+    fn decode_primitives() {
+        let decoder = ValueDecoder::new(|_: &ModuleId| None);
+        assert_eq!(
+            Ok(MoveValue::U64(42)),
+            decoder.decode(&TypeTag::U64, &42u64.to_le_bytes())
+        );
+        assert_eq!(
+            Ok(MoveValue::Vector(vec![
+                MoveValue::Bool(true),
+                MoveValue::Bool(false)
+            ])),
+            decoder.decode(&TypeTag::Vector(Box::new(TypeTag::Bool)), &[2, 1, 0])
+        );
+        assert!(decoder.decode(&TypeTag::U64, &[0; 7]).is_err());
+        assert!(decoder.decode(&TypeTag::U8, &[0; 2]).is_err());
+    }
+}
@@ -14,15 +14,23 @@ mod benchmarking;
 /// Learn more about FRAME and the core library of Substrate FRAME pallets:
 /// <https://substrate.dev/docs/en/knowledgebase/runtime/frame>
 pub use pallet::*;
+pub mod abi;
 pub mod addr;
 pub mod balance;
+pub mod compat;
 pub mod event;
+pub mod fee;
+pub mod function;
 pub mod gas;
+pub mod layout;
+pub mod migration;
 pub mod mvm;
 pub mod oracle;
 pub mod result;
 pub mod storage;
 pub mod types;
+pub mod weights;
+pub use weights::WeightInfo;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -39,6 +47,7 @@ pub mod pallet {
     mod boxed {
         pub use crate::storage::boxed::VmStorageBoxAdapter as StorageAdapter;
         pub use crate::balance::boxed::BalancesAdapter;
+        pub use crate::oracle::boxed::OracleAdapter;
     }
 
     use core::convert::TryInto;
@@ -49,20 +58,36 @@ pub mod pallet {
     use frame_support as support;
     use support::pallet_prelude::*;
     use support::traits::UnixTime;
+    use support::traits::ReservableCurrency;
     use support::dispatch::DispatchResultWithPostInfo;
+    use support::dispatch::DispatchErrorWithPostInfo;
+    use support::storage::{with_transaction, TransactionOutcome};
+    use sp_runtime::DispatchError;
+    use support::transactional;
     use sp_runtime::traits::UniqueSaturatedInto;
+    use sp_runtime::traits::{Saturating, Zero};
+    use sp_runtime::traits::Hash as HashT;
     use codec::{FullCodec, FullEncode};
 
     use move_vm::Vm;
     use move_vm::mvm::Mvm;
     use move_vm::data::ExecutionContext;
+    use move_vm::data::State;
+    use move_vm_runtime::data_cache::RemoteCache;
     use move_vm::types::Gas;
     use move_vm::types::ModuleTx;
+    use move_vm::types::ScriptTx;
     use move_vm::types::Transaction;
     use move_vm::types::VmResult;
     use move_vm::types::ModulePackage;
     use move_core_types::account_address::AccountAddress;
+    use move_core_types::identifier::Identifier;
     use move_core_types::language_storage::CORE_CODE_ADDRESS;
+    use move_core_types::language_storage::ModuleId;
+    use move_core_types::language_storage::StructTag;
+    use move_core_types::language_storage::TypeTag;
+    use vm::access::ModuleAccess;
+    use vm::file_format::CompiledModule;
 
     /// Configure the pallet by specifying the parameters and types on which it depends.
     #[pallet::config]
@@ -73,28 +98,262 @@ pub mod pallet {
         /// Gas to weight convertion settings.
         type GasWeightMapping: gas::GasWeightMapping;
 
-        // doesn't really needed now:
-        // type Currency: Currency<Self::AccountId>;
+        /// Benchmarked weights of the extrinsics, the gas is added on top of them.
+        type WeightInfo: WeightInfo;
+
+        /// Currencies available to Move scripts through the native balances.
+        type Currencies: balance::MultiCurrency<Self::AccountId>;
+
+        /// Move ticker to the `Currencies` currency id mapping.
+        type Tickers: balance::TickerToCurrency<
+            <Self::Currencies as balance::MultiCurrency<Self::AccountId>>::CurrencyId,
+        >;
+
+        /// Prices source for Move VM, e.g. [`oracle::StorageOracle`].
+        type Oracle: move_vm::data::Oracle + Default + 'static;
+
+        /// Max age of a feeder price in milliseconds, older prices are ignored by the oracle.
+        #[pallet::constant]
+        type OraclePriceLifetime: Get<u64>;
+
+        /// Storage deposit reserved from the writer per byte of `VMStorage` key and value.
+        #[pallet::constant]
+        type StorageDepositPerByte: Get<BalanceOf<Self>>;
+
+        /// Max number of `VMStorage` keys owned by the Move address,
+        /// transactions of the address exceeding it are aborted.
+        #[pallet::constant]
+        type StorageQuotaKeys: Get<u32>;
+
+        /// Max total size of `VMStorage` keys and values owned by the Move address,
+        /// transactions of the address exceeding it are aborted.
+        #[pallet::constant]
+        type StorageQuotaBytes: Get<u64>;
+
+        /// Blocks the multi-signer script approvals live before they expire.
+        #[pallet::constant]
+        type ScriptApprovalLifetime: Get<Self::BlockNumber>;
+
+        /// Deposit reserved from every approver of the multi-signer script
+        /// until the script is executed or its approval is cancelled.
+        #[pallet::constant]
+        type ScriptApprovalDeposit: Get<BalanceOf<Self>>;
+
+        /// Origin publishing the standard library and packages under `0x1`, e.g. root.
+        type StdPublishOrigin: EnsureOrigin<Self::Origin>;
+
+        /// Origin publishing modules under its own address, e.g. any signed account.
+        type ModulePublishOrigin: EnsureOrigin<Self::Origin, Success = Self::AccountId>;
+
+        /// Currency of the gas fees paid through [`fee::ChargeGasPrice`].
+        type Currency: support::traits::Currency<Self::AccountId, Balance = BalanceOf<Self>>;
+
+        /// Handler of the gas fees left after the refund of the unused gas.
+        type GasFees: support::traits::OnUnbalanced<fee::NegativeImbalanceOf<Self>>;
     }
 
+    pub type BalanceOf<T> = <T as balances::Config>::Balance;
+
     #[pallet::pallet]
     #[pallet::generate_store(pub trait Store)]
     pub struct Pallet<T>(_);
 
     // The pallet's runtime storage items.
     // https://substrate.dev/docs/en/knowledgebase/runtime/storage
-    /// Move VM storage. Map with already encoded key-values pairs:
-    /// - Key: `AccessPath` as bytes
+    /// Move VM storage. Double map with already encoded key-values pairs,
+    /// the VM key is split by `storage::split_key`:
+    /// - Key1: owner Move address as bytes
+    /// - Key2: rest of the `AccessPath` as bytes
     /// - Value: `WriteSet` as bytes
-    // TODO: Experimentally try hasher [Identity][frame_support::Identity]
-    //                           because key are already encoded - hashes.
     #[pallet::storage]
-    pub type VMStorage<T> = StorageMap<_, Blake2_128Concat, Vec<u8>, Vec<u8>>;
+    pub type VMStorage<T> =
+        StorageDoubleMap<_, Blake2_128Concat, Vec<u8>, Blake2_128Concat, Vec<u8>, Vec<u8>>;
+
+    /// Storage layout version, see `migration::Releases`.
+    #[pallet::storage]
+    pub type StorageVersion<T> = StorageValue<_, migration::Releases, ValueQuery>;
+
+    /// Lowest gas price accepted by [`fee::ChargeGasPrice`], zero by default.
+    #[pallet::storage]
+    pub type MinGasPrice<T> = StorageValue<_, u64, ValueQuery>;
+
+    /// Index of the published modules, module code itself is in `VMStorage`.
+    /// - Key: Move address as bytes
+    /// - Value: names of the modules published under the address, in publishing order
+    #[pallet::storage]
+    pub type PublishedModules<T> =
+        StorageMap<_, Blake2_128Concat, Vec<u8>, Vec<Vec<u8>>, ValueQuery>;
+
+    /// Upgrade policy of all the modules published under the Move address.
+    /// Std modules under `0x1` are `Arbitrary` unless set by `StdPublishOrigin`.
+    #[pallet::storage]
+    pub type AccountUpgradePolicy<T> =
+        StorageMap<_, Blake2_128Concat, Vec<u8>, types::UpgradePolicy, ValueQuery>;
+
+    /// Upgrade policy of the module, overrides `AccountUpgradePolicy`.
+    /// - Keys: Move address as bytes, module name
+    #[pallet::storage]
+    pub type ModuleUpgradePolicy<T> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        Vec<u8>,
+        Blake2_128Concat,
+        Vec<u8>,
+        types::UpgradePolicy,
+    >;
+
+    /// Approvals of pending multi-signer scripts.
+    /// - Key: hash of the script bytecode and its signers
+    /// - Value: signers already approved the script, agreed gas limit and expiry
+    #[pallet::storage]
+    pub type ScriptApprovals<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::Hash,
+        types::ScriptApproval<T::AccountId, T::BlockNumber, BalanceOf<T>>,
+    >;
+
+    /// Pending multi-signer scripts by the block their approvals expire at,
+    /// deposits of the expired approvals are released at the beginning of the block.
+    /// - Key: expiry block
+    /// - Value: hashes of the scripts, see `ScriptApprovals`
+    #[pallet::storage]
+    pub type ScriptApprovalExpiries<T: Config> =
+        StorageMap<_, Twox64Concat, T::BlockNumber, Vec<T::Hash>, ValueQuery>;
+
+    /// Accounts authorized to feed oracle prices.
+    #[pallet::storage]
+    pub type OracleFeeders<T: Config> = StorageValue<_, Vec<T::AccountId>, ValueQuery>;
+
+    /// Oracle prices by feeders.
+    /// - Key: feeder account, ticker (e.g. `BTC_PONT`)
+    /// - Value: price and its update time in milliseconds
+    #[pallet::storage]
+    pub type OraclePrices<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        Vec<u8>,
+        (u128, u64),
+    >;
+
+    /// Changes recorded during the transaction simulation, empty otherwise.
+    /// Set and taken by the simulation in the rolled back storage transaction.
+    #[pallet::storage]
+    pub type Simulation<T> = StorageValue<_, types::SimulationRecord>;
+
+    /// Native balance failure during the current script execution.
+    /// Set by the VM balance adapter, taken right after the execution.
+    #[pallet::storage]
+    pub type BalanceAccessFailure<T> = StorageValue<_, balance::BalanceAccessError>;
+
+    /// Storage deposits of `VMStorage` entries.
+    /// - Key: `VMStorage` key
+    /// - Value: accounts which created or grew the entry and their reserved shares
+    #[pallet::storage]
+    pub type StorageDeposits<T: Config> =
+        StorageMap<_, Blake2_128Concat, Vec<u8>, Vec<(T::AccountId, BalanceOf<T>)>, ValueQuery>;
+
+    /// Total storage deposit reserved from the account.
+    #[pallet::storage]
+    pub type StorageDepositOf<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+    /// Account paying storage deposit of the current VM call writes.
+    /// Set right before the VM call, taken right after it.
+    #[pallet::storage]
+    pub type StorageDepositor<T: Config> = StorageValue<_, T::AccountId>;
+
+    /// Rejected VM storage write during the current VM call.
+    /// Set by the storage observer, taken right after the VM call.
+    #[pallet::storage]
+    pub type StorageWriteFailure<T> = StorageValue<_, storage::StorageWriteError>;
+
+    /// Move state owned by the address, see [`storage::key_owner`].
+    /// Raw genesis storage entries aren't accounted.
+    /// - Key: Move address as bytes
+    #[pallet::storage]
+    pub type StorageUsage<T> =
+        StorageMap<_, Blake2_128Concat, Vec<u8>, types::StorageUsage, ValueQuery>;
+
+    /// Initial Move state: standard library, packages and raw storage entries.
+    #[pallet::genesis_config]
+    pub struct GenesisConfig {
+        /// Standard library modules bytecode, published under `0x1` in the given order.
+        pub stdlib: Vec<Vec<u8>>,
+        /// Dove packages, published under `0x1` right after the standard library.
+        pub packages: Vec<Vec<u8>>,
+        /// Raw `VMStorage` entries (encoded access key, value),
+        /// inserted as is before anything is published.
+        pub storage: Vec<(Vec<u8>, Vec<u8>)>,
+    }
+
+    #[cfg(feature = "std")]
+    impl Default for GenesisConfig {
+        fn default() -> Self {
+            Self {
+                stdlib: Vec::new(),
+                packages: Vec::new(),
+                storage: Vec::new(),
+            }
+        }
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> GenesisBuild<T> for GenesisConfig {
+        fn build(&self) {
+            StorageVersion::<T>::put(migration::Releases::LATEST);
+
+            for (key, value) in self.storage.iter() {
+                let (address, path) = storage::split_key(key);
+                VMStorage::<T>::insert(address, path, value);
+            }
+
+            // Fresh VM instead of the static one, so nothing from genesis remains in its cache.
+            let vm = match Pallet::<T>::try_create_move_vm() {
+                Ok(vm) => vm,
+                Err(_) => panic!("Cannot create Move VM to build genesis"),
+            };
+            let gas = || match Pallet::<T>::get_move_gas_limit(GENESIS_GAS_LIMIT) {
+                Ok(gas) => gas,
+                Err(_) => panic!("Invalid genesis gas limit"),
+            };
+
+            for (index, module) in self.stdlib.iter().enumerate() {
+                let tx = ModuleTx::new(module.clone(), CORE_CODE_ADDRESS);
+                let res = vm.publish_module(gas(), tx, false);
+                debug!("genesis std module #{} publish result: {:?}", index, res);
+                if !result::is_ok(&res) {
+                    panic!(
+                        "Genesis std module #{} not published: {:?}",
+                        index, res.status_code
+                    );
+                }
+                Pallet::<T>::note_published_module(module);
+            }
+
+            for (index, package) in self.packages.iter().enumerate() {
+                let tx = ModulePackage::try_from(&package[..])
+                    .unwrap_or_else(|_| panic!("Invalid genesis package #{}", index))
+                    .into_tx(CORE_CODE_ADDRESS);
+                let res = vm.publish_module_package(gas(), tx, false);
+                debug!("genesis package #{} publish result: {:?}", index, res);
+                if !result::is_ok(&res) {
+                    panic!(
+                        "Genesis package #{} not published: {:?}",
+                        index, res.status_code
+                    );
+                }
+                Pallet::<T>::note_published_package(package, CORE_CODE_ADDRESS);
+            }
+        }
+    }
 
     // Pallets use events to inform users when important changes are made.
     // https://substrate.dev/docs/en/knowledgebase/runtime/events
     #[pallet::event]
-    #[pallet::metadata(T::AccountId = "AccountId")]
+    #[pallet::metadata(T::AccountId = "AccountId", T::Hash = "Hash")]
     // #[pallet::metadata(T::AccountId = "AccountId", T::Balance = "Balance")]
     #[pallet::generate_deposit(pub fn deposit_event)]
     pub enum Event<T: Config> {
@@ -102,19 +361,64 @@ pub mod pallet {
         /// Event provided by Move VM
         /// [account, type_tag, message, module]
         Event(
-            T::AccountId, /* transcoded AccountAddress */
-            Vec<u8>,      /* encoded TypeTag as String, use Text in web-UI */
-            Vec<u8>,      /* encoded String, use Text in web-UI */
+            T::AccountId,                     /* transcoded AccountAddress */
+            types::MoveTypeTag<T::AccountId>, /* event type with all type parameters */
+            Vec<u8>,                          /* encoded String, use Text in web-UI */
             Option<types::MoveModuleId<T::AccountId>>,
         ),
 
-        /// Event about successful move-module publishing
+        /// Module published, new or replacing the module with the same id
+        /// [module, bytecode_hash, is_upgrade]
+        ModulePublished(types::MoveModuleId<T::AccountId>, T::Hash, bool),
+
+        /// All modules of the package published
+        /// [package_hash, modules]
+        PackagePublished(T::Hash, Vec<types::MoveModuleId<T::AccountId>>),
+
+        /// Multi-signer script approved by one of its signers
+        /// [account, script_hash]
+        ScriptApproved(T::AccountId, T::Hash),
+
+        /// Multi-signer script approved by all signers and executed
+        /// [script_hash]
+        MultiSignerScriptExecuted(T::Hash),
+
+        /// Approval of the multi-signer script withdrawn by the signer,
+        /// or all approvals of the expired script dropped
+        /// [account, script_hash]
+        ScriptApprovalCancelled(T::AccountId, T::Hash),
+
+        /// Approvals of the multi-signer script expired, their deposits released
+        /// [script_hash]
+        ScriptApprovalsExpired(T::Hash),
+
+        /// Oracle feeder added
         /// [account]
-        ModulePublished(T::AccountId),
+        OracleFeederAdded(T::AccountId),
 
-        /// Event about successful move-module publishing
+        /// Oracle feeder removed with all its prices
         /// [account]
-        StdModulePublished,
+        OracleFeederRemoved(T::AccountId),
+
+        /// Oracle price updated by the feeder
+        /// [account, ticker, price]
+        OraclePriceFed(T::AccountId, Vec<u8>, u128),
+
+        /// Published module replaced with the new bytecode
+        /// [module, old_bytecode_hash, new_bytecode_hash]
+        ModuleUpgraded(types::MoveModuleId<T::AccountId>, T::Hash, T::Hash),
+
+        /// Upgrade policy set for the account modules or the single module
+        /// [account, module, policy]
+        UpgradePolicySet(T::AccountId, Option<Vec<u8>>, types::UpgradePolicy),
+
+        /// Lowest gas price of the Move transactions changed
+        /// [price]
+        MinGasPriceSet(u64),
+
+        /// Batch publishing rolled back by the failed module
+        /// [module_index, error]
+        BatchPublishFailed(u32, DispatchError),
     }
 
     // Dispatchable functions allows users to interact with the pallet and invoke state changes.
@@ -122,7 +426,9 @@ pub mod pallet {
     // Dispatchable functions must be annotated with a weight and must return a DispatchResult.
     #[pallet::call]
     impl<T: Config> Pallet<T> {
-        #[pallet::weight(T::GasWeightMapping::gas_to_weight(*gas_limit))]
+        #[pallet::weight(T::WeightInfo::execute(tx_bc.len() as u32)
+            .saturating_add(T::GasWeightMapping::gas_to_weight(*gas_limit)))]
+        #[transactional]
         pub fn execute(
             origin: OriginFor<T>,
             tx_bc: Vec<u8>,
@@ -131,114 +437,396 @@ pub mod pallet {
             let who = ensure_signed(origin)?;
             debug!("executing `execute` with signed {:?}", who);
 
+            let base_weight = T::WeightInfo::execute(tx_bc.len() as u32);
             let vm_result = Self::raw_execute_script(&who, tx_bc, gas_limit, false)?;
 
             // produce result with spended gas:
-            let result = result::from_vm_result::<T>(vm_result)?;
+            let result = result::from_vm_result::<T>(vm_result, base_weight)?;
+            Ok(result)
+        }
+
+        /// Approve script with several signers and execute it once all `signers` approved.
+        /// Every signer calls it with the same `tx_bc`, `signers` and `gas_limit`,
+        /// signers are passed to the script in the given order.
+        /// Every approval but the last one reserves `ScriptApprovalDeposit`
+        /// until the execution or `cancel_script_approval`.
+        /// The last approver executes the script and pays its gas and storage deposit,
+        /// the gas of the other approvals is refunded.
+        /// Approvals expire in `ScriptApprovalLifetime` blocks after the first one,
+        /// their deposits are released at the beginning of the expiry block.
+        #[pallet::weight(T::WeightInfo::execute_multi_signer(tx_bc.len() as u32)
+            .saturating_add(T::GasWeightMapping::gas_to_weight(*gas_limit)))]
+        #[transactional]
+        pub fn execute_multi_signer(
+            origin: OriginFor<T>,
+            tx_bc: Vec<u8>,
+            signers: Vec<T::AccountId>,
+            gas_limit: u64,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            debug!("executing `execute_multi_signer` with signed {:?}", who);
+
+            ensure!(signers.contains(&who), Error::<T>::NotScriptSigner);
+            for (i, signer) in signers.iter().enumerate() {
+                ensure!(
+                    !signers[..i].contains(signer),
+                    Error::<T>::DuplicateScriptSigner
+                );
+            }
+
+            let transaction = Transaction::try_from(&tx_bc[..])
+                .map_err(|_| Error::<T>::TransactionValidationError)?;
+            ensure!(
+                transaction.signers_count() as usize == signers.len(),
+                Error::<T>::TransactionSignersNumError
+            );
+
+            let script_hash = T::Hashing::hash_of(&(&tx_bc, &signers));
+            let now = frame_system::Pallet::<T>::block_number();
+            let mut pending = match ScriptApprovals::<T>::take(script_hash) {
+                Some(pending) if pending.expires_at > now => pending,
+                expired => {
+                    if let Some(expired) = expired {
+                        Self::release_script_approvals(expired);
+                    }
+                    types::ScriptApproval {
+                        approvals: Vec::new(),
+                        gas_limit,
+                        expires_at: now.saturating_add(T::ScriptApprovalLifetime::get()),
+                    }
+                }
+            };
+            ensure!(
+                pending.gas_limit == gas_limit,
+                Error::<T>::ScriptGasLimitMismatch
+            );
+            ensure!(
+                !pending.approvals.iter().any(|(signer, _)| signer == &who),
+                Error::<T>::ScriptAlreadyApproved
+            );
+
+            let base_weight = T::WeightInfo::execute_multi_signer(tx_bc.len() as u32);
+
+            if pending.approvals.len() + 1 < signers.len() {
+                let deposit = T::ScriptApprovalDeposit::get();
+                <balances::Module<T> as ReservableCurrency<T::AccountId>>::reserve(&who, deposit)
+                    .map_err(|_| Error::<T>::InsufficientApprovalDeposit)?;
+                if pending.approvals.is_empty() {
+                    ScriptApprovalExpiries::<T>::append(pending.expires_at, script_hash);
+                }
+                pending.approvals.push((who.clone(), deposit));
+                ScriptApprovals::<T>::insert(script_hash, pending);
+                Self::deposit_event(Event::ScriptApproved(who, script_hash));
+                // no gas is used by the approval:
+                return Ok(Some(base_weight).into());
+            }
+
+            let vm_result =
+                Self::raw_execute_script_with_signers(&signers, &who, tx_bc, gas_limit, false)?;
+
+            // produce result with spended gas:
+            let result = result::from_vm_result::<T>(vm_result, base_weight)?;
+
+            Self::release_script_approvals(pending);
+            Self::deposit_event(Event::ScriptApproved(who, script_hash));
+            Self::deposit_event(Event::MultiSignerScriptExecuted(script_hash));
+
+            Ok(result)
+        }
+
+        /// Withdraw the sender approval of the pending multi-signer script
+        /// and release its deposit. Approvals of the expired script are dropped
+        /// by any signed account, releasing all their deposits.
+        #[pallet::weight(T::WeightInfo::cancel_script_approval())]
+        pub fn cancel_script_approval(
+            origin: OriginFor<T>,
+            script_hash: T::Hash,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let mut pending = ScriptApprovals::<T>::get(script_hash)
+                .ok_or(Error::<T>::ScriptApprovalNotFound)?;
+            if pending.expires_at <= frame_system::Pallet::<T>::block_number() {
+                ScriptApprovals::<T>::remove(script_hash);
+                Self::release_script_approvals(pending);
+            } else {
+                let index = pending
+                    .approvals
+                    .iter()
+                    .position(|(signer, _)| signer == &who)
+                    .ok_or(Error::<T>::ScriptApprovalNotFound)?;
+                let (signer, deposit) = pending.approvals.remove(index);
+                <balances::Module<T> as ReservableCurrency<T::AccountId>>::unreserve(
+                    &signer, deposit,
+                );
+                if pending.approvals.is_empty() {
+                    ScriptApprovals::<T>::remove(script_hash);
+                } else {
+                    ScriptApprovals::<T>::insert(script_hash, pending);
+                }
+            }
+
+            Self::deposit_event(Event::ScriptApprovalCancelled(who, script_hash));
+            Ok(().into())
+        }
+
+        /// Call public module function without a script, the sender is its `&signer`.
+        /// `type_args` are type tags with hex addresses (e.g. `0x1::PONT::T`),
+        /// `args` are BCS encoded arguments following the signer.
+        #[pallet::weight(T::WeightInfo::execute_function(
+                total_len(args),
+                max_module_size::<T>(),
+            )
+            .saturating_add(T::GasWeightMapping::gas_to_weight(*gas_limit)))]
+        #[transactional]
+        pub fn execute_function(
+            origin: OriginFor<T>,
+            module: types::MoveModuleId<T::AccountId>,
+            function: Vec<u8>,
+            type_args: Vec<Vec<u8>>,
+            args: Vec<Vec<u8>>,
+            gas_limit: u64,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            debug!("executing `execute_function` with signed {:?}", who);
+
+            let base_weight =
+                T::WeightInfo::execute_function(total_len(&args), module_size::<T>(&module));
+            let vm_result = Self::raw_execute_function(
+                &who, module, function, type_args, args, gas_limit, false,
+            )?;
+
+            // produce result with spended gas:
+            let result = result::from_vm_result::<T>(vm_result, base_weight)?;
             Ok(result)
         }
 
-        #[pallet::weight(T::GasWeightMapping::gas_to_weight(*gas_limit))]
+        #[pallet::weight(T::WeightInfo::publish_module(module_bc.len() as u32)
+            .saturating_add(T::GasWeightMapping::gas_to_weight(*gas_limit)))]
+        #[transactional]
         pub fn publish_module(
             origin: OriginFor<T>,
             module_bc: Vec<u8>,
             gas_limit: u64,
         ) -> DispatchResultWithPostInfo {
-            let who = ensure_signed(origin)?;
+            let who = T::ModulePublishOrigin::ensure_origin(origin)?;
             debug!("executing `publish` with signed {:?}", who);
 
             // Publish module.
+            let base_weight = T::WeightInfo::publish_module(module_bc.len() as u32);
             let vm_result = Self::raw_publish_module(&who, module_bc, gas_limit, false)?;
 
             // produce result with spended gas:
-            let result = result::from_vm_result::<T>(vm_result)?;
-
-            // Emit an event:
-            Self::deposit_event(Event::ModulePublished(who));
+            let result = result::from_vm_result::<T>(vm_result, base_weight)?;
 
             Ok(result)
         }
 
-        /// Batch publish module-package produced by Dove compiler
-        #[pallet::weight(T::GasWeightMapping::gas_to_weight(*gas_limit))]
+        /// Batch publish module-package produced by Dove compiler.
+        /// `StdPublishOrigin` publishes it under `0x1`, `ModulePublishOrigin` under its own address.
+        /// All or nothing: the failed module rolls back the whole package,
+        /// its index is in the `BatchPublishFailed` event.
+        #[pallet::weight(T::WeightInfo::publish_package(
+                package.len() as u32,
+                package_modules_count(package),
+            )
+            .saturating_add(T::GasWeightMapping::gas_to_weight(*gas_limit)))]
         pub fn publish_package(
             origin: OriginFor<T>,
             package: Vec<u8>,
             gas_limit: u64,
         ) -> DispatchResultWithPostInfo {
-            let sender = match ensure_root(origin.clone()) {
+            let (sender, depositor) = match T::StdPublishOrigin::try_origin(origin) {
                 Ok(_) => {
-                    debug!("executing `publish package` with root");
-                    CORE_CODE_ADDRESS
+                    debug!("executing `publish package` with std origin");
+                    (CORE_CODE_ADDRESS, None)
                 }
-                Err(_) => {
-                    let signer = ensure_signed(origin)?;
+                Err(origin) => {
+                    let signer = T::ModulePublishOrigin::ensure_origin(origin)?;
                     debug!("executing `publish package` with signed {:?}", signer);
-                    addr::account_to_account_address(&signer)
+                    (addr::account_to_account_address(&signer), Some(signer))
                 }
             };
 
-            let vm = Self::get_vm()?;
-            let gas = Self::get_move_gas_limit(gas_limit)?;
-
-            let package = {
-                ModulePackage::try_from(&package[..])
-                    .map_err(|_| Error::<T>::TransactionValidationError)?
-                    .into_tx(sender)
-            };
+            let modules = Self::package_modules(&package, sender)?;
+            ensure!(
+                modules.len() as u32 == package_modules_count(&package),
+                Error::<T>::TransactionValidationError
+            );
+            let base_weight =
+                T::WeightInfo::publish_package(package.len() as u32, modules.len() as u32);
+            let (results, ids) =
+                Self::publish_batch(modules, sender, depositor.as_ref(), gas_limit, base_weight)?;
 
-            let vm_result = vm.publish_module_package(gas, package, false);
+            // Emit an event:
+            Self::deposit_event(Event::PackagePublished(T::Hashing::hash(&package), ids));
 
             // produce result with spended gas:
-            let result = result::from_vm_result::<T>(vm_result)?;
+            let result = result::from_vm_results::<T>(&results, base_weight)?;
 
             Ok(result)
         }
 
-        /// Batch publish std-modules by root account only
-        // #[pallet::weight(T::GasWeightMapping::gas_to_weight(*gas_limit) * modules.len().into())]
-        #[pallet::weight(T::GasWeightMapping::gas_to_weight(*gas_limit))]
+        /// Batch publish std-modules by `StdPublishOrigin` only.
+        /// All or nothing: the failed module rolls back the whole batch,
+        /// its index is in the `BatchPublishFailed` event.
+        #[pallet::weight(T::WeightInfo::publish_std(total_len(modules))
+            .saturating_add(T::GasWeightMapping::gas_to_weight(*gas_limit)))]
         pub fn publish_std(
             origin: OriginFor<T>,
             modules: Vec<Vec<u8>>,
             gas_limit: u64,
+        ) -> DispatchResultWithPostInfo {
+            T::StdPublishOrigin::ensure_origin(origin)?;
+            debug!("executing `publish STD` with std origin");
+
+            let base_weight = T::WeightInfo::publish_std(total_len(&modules));
+            let (results, _) =
+                Self::publish_batch(modules, CORE_CODE_ADDRESS, None, gas_limit, base_weight)?;
+
+            // produce result with spended gas:
+            let result = result::from_vm_results::<T>(&results, base_weight)?;
+
+            Ok(result)
+        }
+
+        /// Authorize account to feed oracle prices, root only
+        #[pallet::weight(T::WeightInfo::add_oracle_feeder())]
+        pub fn add_oracle_feeder(
+            origin: OriginFor<T>,
+            feeder: T::AccountId,
         ) -> DispatchResultWithPostInfo {
             ensure_root(origin)?;
-            debug!("executing `publish STD` with root");
 
-            let vm = Self::get_vm()?;
-            // TODO: use gas_used
-            let mut _gas_used = 0;
-            let mut results = Vec::with_capacity(modules.len());
-            'deploy: for module in modules.into_iter() {
-                // Overflow shound't happen.
-                // As gas_limit always large or equal to used, otherwise getting out of gas error.
-                let gas = Self::get_move_gas_limit(gas_limit - _gas_used)?;
-
-                let tx = ModuleTx::new(module, CORE_CODE_ADDRESS);
-                let res = vm.publish_module(gas, tx, false);
-                debug!("publish result: {:?}", res);
-
-                let is_ok = result::is_ok(&res);
-                _gas_used += res.gas_used;
-                results.push(res);
-                if !is_ok {
-                    break 'deploy;
+            OracleFeeders::<T>::try_mutate(|feeders| {
+                ensure!(
+                    !feeders.contains(&feeder),
+                    Error::<T>::OracleFeederAlreadyExists
+                );
+                feeders.push(feeder.clone());
+                Ok::<_, Error<T>>(())
+            })?;
+
+            Self::deposit_event(Event::OracleFeederAdded(feeder));
+            Ok(().into())
+        }
+
+        /// Remove oracle feeder and all its prices, root only
+        #[pallet::weight(T::WeightInfo::remove_oracle_feeder())]
+        pub fn remove_oracle_feeder(
+            origin: OriginFor<T>,
+            feeder: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+
+            OracleFeeders::<T>::try_mutate(|feeders| {
+                let index = feeders
+                    .iter()
+                    .position(|f| f == &feeder)
+                    .ok_or(Error::<T>::OracleFeederNotFound)?;
+                feeders.remove(index);
+                Ok::<_, Error<T>>(())
+            })?;
+            OraclePrices::<T>::remove_prefix(&feeder);
+
+            Self::deposit_event(Event::OracleFeederRemoved(feeder));
+            Ok(().into())
+        }
+
+        /// Set price for the ticker (e.g. `BTC_PONT`) by the authorized feeder
+        #[pallet::weight(T::WeightInfo::feed_price())]
+        pub fn feed_price(
+            origin: OriginFor<T>,
+            ticker: Vec<u8>,
+            price: u128,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                OracleFeeders::<T>::get().contains(&who),
+                Error::<T>::OracleFeederNotFound
+            );
+
+            let now = <timestamp::Module<T> as UnixTime>::now().as_millis() as u64;
+            OraclePrices::<T>::insert(&who, &ticker, (price, now));
+
+            Self::deposit_event(Event::OraclePriceFed(who, ticker, price));
+            Ok(().into())
+        }
+
+        /// Set upgrade policy of the sender modules, or of the single `module` if passed.
+        /// `StdPublishOrigin` sets policies of the std modules under `0x1`.
+        /// Policy of immutable modules can't be changed.
+        #[pallet::weight(T::WeightInfo::set_upgrade_policy())]
+        pub fn set_upgrade_policy(
+            origin: OriginFor<T>,
+            module: Option<Vec<u8>>,
+            policy: types::UpgradePolicy,
+        ) -> DispatchResultWithPostInfo {
+            let (who, address) = match T::StdPublishOrigin::try_origin(origin) {
+                Ok(_) => (
+                    addr::address_to_account::<T::AccountId>(&CORE_CODE_ADDRESS)
+                        .map_err(|_| Error::<T>::NumConversionError)?,
+                    CORE_CODE_ADDRESS,
+                ),
+                Err(origin) => {
+                    let who = ensure_signed(origin)?;
+                    let address = addr::account_to_account_address(&who);
+                    (who, address)
                 }
+            };
+            let address = address.as_ref().to_vec();
+
+            let current = match &module {
+                Some(name) => Self::upgrade_policy(&address, name),
+                None => Self::account_upgrade_policy(&address),
+            };
+            ensure!(
+                current != types::UpgradePolicy::Immutable,
+                Error::<T>::ModuleUpgradeForbidden
+            );
 
-                // Emit an event:
-                Self::deposit_event(Event::StdModulePublished);
+            match &module {
+                Some(name) => ModuleUpgradePolicy::<T>::insert(&address, name, policy),
+                None => AccountUpgradePolicy::<T>::insert(&address, policy),
             }
 
-            // produce result with spended gas:
-            let result = result::from_vm_results::<T>(&results)?;
+            Self::deposit_event(Event::UpgradePolicySet(who, module, policy));
+            Ok(().into())
+        }
 
-            Ok(result)
+        /// Set the lowest gas price of the Move transactions, root only
+        #[pallet::weight(T::WeightInfo::set_min_gas_price())]
+        pub fn set_min_gas_price(origin: OriginFor<T>, price: u64) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+
+            MinGasPrice::<T>::put(price);
+            Self::deposit_event(Event::MinGasPriceSet(price));
+            Ok(().into())
         }
     }
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+            Self::expire_script_approvals(now)
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            #[cfg(feature = "try-runtime")]
+            if let Err(err) = Self::pre_upgrade() {
+                error!("storage pre-upgrade check failed: {}", err);
+            }
+
+            let weight = migration::migrate::<T>();
+
+            #[cfg(feature = "try-runtime")]
+            if let Err(err) = Self::post_upgrade() {
+                error!("storage post-upgrade check failed: {}", err);
+            }
+
+            weight
+        }
+
         #[cfg(not(feature = "no-vm-static"))]
         fn on_finalize(_: BlockNumberFor<T>) {
             if let Some(vm) = Self::get_move_vm_cell().get() {
@@ -248,6 +836,359 @@ pub mod pallet {
         }
     }
 
+    // Storage migration checks, the same as `Hooks::pre_upgrade` and `Hooks::post_upgrade`
+    // of `frame-support` with `try-runtime`
+    #[cfg(feature = "try-runtime")]
+    impl<T: Config> Pallet<T> {
+        /// Raw storage key of the `VMStorage` entries number, kept between the checks.
+        const TRY_RUNTIME_ENTRIES: &'static [u8] = b":sp_mvm:try_runtime:entries";
+
+        /// Check the storage before the migration, see [`migration::pre_upgrade`].
+        pub fn pre_upgrade() -> Result<(), &'static str> {
+            let entries = migration::pre_upgrade::<T>()?;
+            support::storage::unhashed::put(Self::TRY_RUNTIME_ENTRIES, &entries);
+            Ok(())
+        }
+
+        /// Check the storage after the migration, see [`migration::post_upgrade`].
+        pub fn post_upgrade() -> Result<(), &'static str> {
+            let entries = support::storage::unhashed::take::<u32>(Self::TRY_RUNTIME_ENTRIES)
+                .ok_or("Storage pre-upgrade check wasn't run")?;
+            migration::post_upgrade::<T>(entries)
+        }
+    }
+
+    // Move state reading, used by RPC
+    impl<T: Config> Pallet<T> {
+        /// Resource BCS bytes by the owner address and struct tag (e.g. `0x1::Store::U64`).
+        /// Addresses in the tag are hex only.
+        pub fn get_resource(address: &[u8], tag: &[u8]) -> Result<Option<Vec<u8>>, Vec<u8>> {
+            let address =
+                AccountAddress::try_from(address).map_err(|_| b"Invalid address".to_vec())?;
+            let tag = Self::parse_struct_tag(tag)?;
+
+            let state = State::new(Self::state_storage(), T::Oracle::default());
+            state.get_resource(&address, &tag).map_err(|err| {
+                error!("resource reading failed: {:?}", err);
+                b"Can't read resource".to_vec()
+            })
+        }
+
+        /// Decode resource BCS bytes by the layout from the published modules.
+        pub fn decode_resource(tag: &[u8], bcs: &[u8]) -> Result<types::MoveValue, Vec<u8>> {
+            let tag = Self::parse_struct_tag(tag)?;
+            Self::decode_value(&TypeTag::Struct(tag), bcs)
+        }
+
+        /// Decode event BCS bytes by the type tag (e.g. `0x1::Event::U64` or `u64`).
+        /// Addresses in the tag are hex only.
+        pub fn decode_event(tag: &[u8], bcs: &[u8]) -> Result<types::MoveValue, Vec<u8>> {
+            let tag = core::str::from_utf8(tag)
+                .map_err(|_| "Invalid type tag")
+                .and_then(layout::parse_type_tag)
+                .map_err(|err| err.as_bytes().to_vec())?;
+            Self::decode_value(&tag, bcs)
+        }
+
+        /// Move events deposited in the current block with the payloads decoded
+        /// by the layouts from the published modules.
+        pub fn block_events() -> Vec<types::MoveEventRecord>
+        where
+            <T as frame_system::Config>::Event: TryInto<Event<T>>,
+        {
+            frame_system::Pallet::<T>::events()
+                .into_iter()
+                .enumerate()
+                .filter_map(|(index, record)| {
+                    let event: Result<Event<T>, _> = record.event.try_into();
+                    let (sender, ty_tag, message, caller) = match event {
+                        Ok(Event::Event(sender, ty_tag, message, caller)) => {
+                            (sender, ty_tag, message, caller)
+                        }
+                        _ => return None,
+                    };
+                    let extrinsic = match record.phase {
+                        frame_system::Phase::ApplyExtrinsic(index) => Some(index),
+                        _ => None,
+                    };
+
+                    let ty_tag = TypeTag::try_from(&ty_tag).ok();
+                    let value = ty_tag
+                        .as_ref()
+                        .and_then(|tag| Self::decode_value(tag, &message).ok());
+                    Some(types::MoveEventRecord {
+                        index: index as u32,
+                        extrinsic,
+                        sender: addr::account_to_account_address(&sender).as_ref().to_vec(),
+                        ty_tag: ty_tag
+                            .as_ref()
+                            .map(event::type_tag_string)
+                            .unwrap_or_default(),
+                        message,
+                        value,
+                        caller: caller.map(|caller| {
+                            let address = addr::account_to_account_address(&caller.owner);
+                            (address.as_ref().to_vec(), caller.module)
+                        }),
+                    })
+                })
+                .collect()
+        }
+
+        /// Module bytecode by the Move address and module name.
+        pub fn get_module(address: &[u8], name: &[u8]) -> Result<Option<Vec<u8>>, Vec<u8>> {
+            let address =
+                AccountAddress::try_from(address).map_err(|_| b"Invalid address".to_vec())?;
+            let name = core::str::from_utf8(name)
+                .ok()
+                .and_then(|name| Identifier::new(name).ok())
+                .ok_or_else(|| b"Invalid module name".to_vec())?;
+
+            let state = State::new(Self::state_storage(), T::Oracle::default());
+            state
+                .get_module(&ModuleId::new(address, name))
+                .map_err(|err| {
+                    error!("module reading failed: {:?}", err);
+                    b"Can't read module".to_vec()
+                })
+        }
+
+        /// ABI of the module by the Move address and module name.
+        pub fn get_module_abi(
+            address: &[u8],
+            name: &[u8],
+        ) -> Result<Option<types::ModuleAbi>, Vec<u8>> {
+            match Self::get_module(address, name)? {
+                Some(bytecode) => abi::module_abi(&bytecode)
+                    .map(Some)
+                    .map_err(|err| err.as_bytes().to_vec()),
+                None => Ok(None),
+            }
+        }
+
+        /// Names of the modules published under the Move address.
+        /// Modules inserted as raw genesis storage aren't listed.
+        pub fn list_modules(address: &[u8]) -> Result<Vec<Vec<u8>>, Vec<u8>> {
+            let address =
+                AccountAddress::try_from(address).map_err(|_| b"Invalid address".to_vec())?;
+            Ok(PublishedModules::<T>::get(address.as_ref().to_vec()))
+        }
+
+        /// Number of `VMStorage` keys and their total size owned by the Move address.
+        pub fn storage_usage(address: &[u8]) -> Result<types::StorageUsage, Vec<u8>> {
+            let address =
+                AccountAddress::try_from(address).map_err(|_| b"Invalid address".to_vec())?;
+            Ok(StorageUsage::<T>::get(address.as_ref().to_vec()))
+        }
+
+        /// All `VMStorage` entries (VM key, value) owned by the Move address.
+        pub fn account_storage(address: &[u8]) -> Result<Vec<(Vec<u8>, Vec<u8>)>, Vec<u8>> {
+            let address =
+                AccountAddress::try_from(address).map_err(|_| b"Invalid address".to_vec())?;
+            let address = address.as_ref();
+            Ok(VMStorage::<T>::iter_prefix(address.to_vec())
+                .map(|(path, value)| (storage::join_key(address, &path), value))
+                .collect())
+        }
+
+        fn parse_struct_tag(tag: &[u8]) -> Result<StructTag, Vec<u8>> {
+            core::str::from_utf8(tag)
+                .map_err(|_| "Invalid struct tag")
+                .and_then(layout::parse_struct_tag)
+                .map_err(|err| err.as_bytes().to_vec())
+        }
+
+        fn decode_value(tag: &TypeTag, bcs: &[u8]) -> Result<types::MoveValue, Vec<u8>> {
+            let state = State::new(Self::state_storage(), T::Oracle::default());
+            layout::ValueDecoder::new(|id: &ModuleId| state.get_module(id).ok().flatten())
+                .decode(tag, bcs)
+                .map_err(|err| err.as_bytes().to_vec())
+        }
+
+        fn state_storage() -> storage::StorageAdapter<VMStorage<T>> {
+            Self::move_vm_storage()
+        }
+    }
+
+    // Published modules index
+    impl<T: Config> Pallet<T> {
+        /// Add successfully published module to the `PublishedModules` of its self address
+        /// and emit `ModulePublished`, the module already in the index is an upgrade.
+        fn note_published_module(module_bc: &[u8]) -> Option<types::MoveModuleId<T::AccountId>> {
+            let id = match CompiledModule::deserialize(module_bc) {
+                Ok(module) => module.self_id(),
+                Err(err) => {
+                    error!("published module can't be indexed: {:?}", err);
+                    return None;
+                }
+            };
+
+            let name = id.name().as_str().as_bytes().to_vec();
+            let is_upgrade =
+                PublishedModules::<T>::mutate(id.address().as_ref().to_vec(), |names| {
+                    let is_upgrade = names.contains(&name);
+                    if !is_upgrade {
+                        names.push(name);
+                    }
+                    is_upgrade
+                });
+
+            let id = match types::MoveModuleId::<T::AccountId>::try_from(id) {
+                Ok(id) => id,
+                Err(err) => {
+                    error!("published module id can't be converted: {:?}", err);
+                    return None;
+                }
+            };
+            Self::deposit_event(Event::ModulePublished(
+                id.clone(),
+                T::Hashing::hash(module_bc),
+                is_upgrade,
+            ));
+            Some(id)
+        }
+
+        /// Add all modules of successfully published package.
+        fn note_published_package(package: &[u8], sender: AccountAddress) {
+            match ModulePackage::try_from(package) {
+                Ok(package) => {
+                    let (modules, _) = package.into_tx(sender).into_inner();
+                    for module in modules.iter() {
+                        Self::note_published_module(module);
+                    }
+                }
+                Err(_) => error!("published package can't be indexed"),
+            }
+        }
+
+        /// Modules bytecode of the package published by the `sender`.
+        fn package_modules(
+            package: &[u8],
+            sender: AccountAddress,
+        ) -> Result<Vec<Vec<u8>>, Error<T>> {
+            let (modules, _) = ModulePackage::try_from(package)
+                .map_err(|_| Error::<T>::TransactionValidationError)?
+                .into_tx(sender)
+                .into_inner();
+            Ok(modules)
+        }
+    }
+
+    // Multi-signer script approvals
+    impl<T: Config> Pallet<T> {
+        /// Release deposits of the approvals dropped from `ScriptApprovals`.
+        fn release_script_approvals(
+            pending: types::ScriptApproval<T::AccountId, T::BlockNumber, BalanceOf<T>>,
+        ) {
+            for (signer, deposit) in pending.approvals.into_iter() {
+                <balances::Module<T> as ReservableCurrency<T::AccountId>>::unreserve(
+                    &signer, deposit,
+                );
+            }
+        }
+
+        /// Drop approvals of the scripts expiring at the block and release their deposits.
+        /// Scripts already executed, cancelled or approved again since are skipped.
+        fn expire_script_approvals(now: T::BlockNumber) -> Weight {
+            let db = T::DbWeight::get();
+            let mut weight = db.reads_writes(1, 1);
+
+            for script_hash in ScriptApprovalExpiries::<T>::take(now) {
+                weight = weight.saturating_add(db.reads(1));
+                let pending = match ScriptApprovals::<T>::get(script_hash) {
+                    Some(pending) if pending.expires_at <= now => pending,
+                    _ => continue,
+                };
+                ScriptApprovals::<T>::remove(script_hash);
+                // every unreserve reads and writes the signer account:
+                let signers = pending.approvals.len() as Weight;
+                weight = weight.saturating_add(db.reads_writes(signers, signers + 1));
+                Self::release_script_approvals(pending);
+                Self::deposit_event(Event::ScriptApprovalsExpired(script_hash));
+            }
+            weight
+        }
+    }
+
+    // Module upgrades
+    impl<T: Config> Pallet<T> {
+        /// Upgrade policy of the module by the Move address and module name:
+        /// the module own policy or the policy of its address.
+        pub fn upgrade_policy(address: &[u8], name: &[u8]) -> types::UpgradePolicy {
+            ModuleUpgradePolicy::<T>::get(address, name)
+                .unwrap_or_else(|| Self::account_upgrade_policy(address))
+        }
+
+        /// Upgrade policy of the Move address.
+        /// Std is already governed by `StdPublishOrigin`, so it isn't `Compatible` by default.
+        fn account_upgrade_policy(address: &[u8]) -> types::UpgradePolicy {
+            if address == CORE_CODE_ADDRESS.as_ref()
+                && !AccountUpgradePolicy::<T>::contains_key(address)
+            {
+                types::UpgradePolicy::Arbitrary
+            } else {
+                AccountUpgradePolicy::<T>::get(address)
+            }
+        }
+
+        /// Check modules replacing the already published ones against their upgrade policies.
+        /// Returns replaced modules with the old and new bytecode hashes.
+        /// Modules which can't be deserialized are left to the VM.
+        fn check_module_upgrades(
+            modules: &[Vec<u8>],
+        ) -> Result<Vec<(types::MoveModuleId<T::AccountId>, T::Hash, T::Hash)>, Error<T>>
+        {
+            let state = State::new(Self::state_storage(), T::Oracle::default());
+
+            let mut upgrades = Vec::new();
+            for module in modules.iter() {
+                let id = match CompiledModule::deserialize(module) {
+                    Ok(module) => module.self_id(),
+                    Err(_) => continue,
+                };
+                let old = match state.get_module(&id) {
+                    Ok(Some(old)) if &old != module => old,
+                    _ => continue,
+                };
+
+                let address = id.address().as_ref();
+                let name = id.name().as_str().as_bytes();
+                match Self::upgrade_policy(address, name) {
+                    types::UpgradePolicy::Arbitrary => {}
+                    types::UpgradePolicy::Compatible => {
+                        let compatible = compat::is_compatible(&old, module).map_err(|err| {
+                            error!("published module {} can't be checked: {}", id, err);
+                            Error::<T>::InvalidPublishedModule
+                        })?;
+                        ensure!(compatible, Error::<T>::IncompatibleModuleUpgrade)
+                    }
+                    types::UpgradePolicy::Immutable => {
+                        return Err(Error::<T>::ModuleUpgradeForbidden)
+                    }
+                }
+
+                let id = types::MoveModuleId::try_from(id)
+                    .map_err(|_| Error::<T>::NumConversionError)?;
+                upgrades.push((id, T::Hashing::hash(&old), T::Hashing::hash(module)));
+            }
+            Ok(upgrades)
+        }
+
+        /// Emit `ModuleUpgraded` for successfully replaced modules.
+        /// Old modules code mustn't stay in the VM cache.
+        fn note_module_upgrades(
+            upgrades: Vec<(types::MoveModuleId<T::AccountId>, T::Hash, T::Hash)>,
+        ) {
+            if upgrades.is_empty() {
+                return;
+            }
+            Self::clear_vm_cache();
+            for (id, old_hash, new_hash) in upgrades.into_iter() {
+                Self::deposit_event(Event::ModuleUpgraded(id, old_hash, new_hash));
+            }
+        }
+    }
+
     // get VM methods unification
     impl<T: Config> Pallet<T> {
         #[cfg(not(feature = "no-vm-static"))]
@@ -257,16 +1198,72 @@ pub mod pallet {
         }
 
         #[cfg(feature = "no-vm-static")]
-        fn get_vm() -> Result<
-            DefaultVm<VMStorage<T>, event::DefaultEventHandler, oracle::DummyOracle, T>,
-            Error<T>,
-        > {
+        fn get_vm() -> Result<<Self as mvm::TryCreateMoveVm<T>>::Vm, Error<T>> {
             let vm = Self::try_create_move_vm()?;
             Ok(vm)
         }
     }
 
+    // oracle for the VM
+    impl<T: Config> Pallet<T> {
+        #[cfg(not(feature = "no-vm-static"))]
+        fn create_move_oracle() -> boxed::OracleAdapter {
+            boxed::OracleAdapter::new(T::Oracle::default())
+        }
+
+        #[cfg(feature = "no-vm-static")]
+        fn create_move_oracle() -> T::Oracle {
+            Default::default()
+        }
+    }
+
+    /// Total size of the byte arguments, the weight component of the batch calls.
+    fn total_len(items: &[Vec<u8>]) -> u32 {
+        items.iter().map(Vec::len).sum::<usize>() as u32
+    }
+
+    /// Number of modules declared by the package, the weight component of the package publishing.
+    /// It's the length prefix of the BCS encoded module list, the package isn't decoded
+    /// before the fee is charged. Publishing checks it against the decoded modules.
+    fn package_modules_count(package: &[u8]) -> u32 {
+        let mut count = 0u64;
+        for (i, byte) in package.iter().take(5).enumerate() {
+            count |= u64::from(byte & 0x7f) << (7 * i);
+            if byte & 0x80 == 0 {
+                return count.min(package.len() as u64) as u32;
+            }
+        }
+        0
+    }
+
+    /// Largest module of the signed extrinsics, the module size of the function calls
+    /// before the fee is charged. The actual size is read by the call.
+    fn max_module_size<T: Config>() -> u32 {
+        <T as frame_system::Config>::BlockLength::get()
+            .max
+            .get(DispatchClass::Normal)
+    }
+
+    /// Bytecode size of the called module, the weight component of the function calls.
+    fn module_size<T: Config>(module: &types::MoveModuleId<T::AccountId>) -> u32 {
+        let name = match core::str::from_utf8(&module.module)
+            .ok()
+            .and_then(|name| Identifier::new(name).ok())
+        {
+            Some(name) => name,
+            None => return 0,
+        };
+        let key =
+            ModuleId::new(addr::account_to_account_address(&module.owner), name).access_vector();
+        let (address, path) = storage::split_key(&key);
+        VMStorage::<T>::decode_len(address, path).unwrap_or(0) as u32
+    }
+
+    /// Gas unit price inside the VM, the fee price is set by `fee::ChargeGasPrice`.
     const GAS_UNIT_PRICE: u64 = 1;
+    /// Gas limit for every module or package published at genesis.
+    #[cfg(feature = "std")]
+    const GENESIS_GAS_LIMIT: u64 = 1_000_000_000;
 
     impl<T: Config> Pallet<T> {
         #![allow(clippy::useless_conversion)]
@@ -274,7 +1271,6 @@ pub mod pallet {
             Gas::new(gas_limit, GAS_UNIT_PRICE).map_err(|_| Error::InvalidGasAmountMaxValue)
         }
 
-        // TODO: support for multiplay signers.
         pub fn raw_execute_script(
             account: &T::AccountId,
             tx_bc: Vec<u8>,
@@ -286,23 +1282,43 @@ pub mod pallet {
             // T::BlockNumber: BaseArithmetic,
             // T::BlockNumber: UniqueSaturatedInto<u64>,
             T::BlockNumber: TryInto<u64>,
+        {
+            Self::raw_execute_script_with_signers(
+                sp_std::slice::from_ref(account),
+                account,
+                tx_bc,
+                gas_limit,
+                dry_run,
+            )
+        }
+
+        /// Execute script with all passed `signers` in the given order.
+        /// Script without signer arguments ignores `signers`.
+        /// Storage deposit of the script writes is reserved from the `depositor`.
+        pub fn raw_execute_script_with_signers(
+            signers: &[T::AccountId],
+            depositor: &T::AccountId,
+            tx_bc: Vec<u8>,
+            gas_limit: u64,
+            dry_run: bool,
+        ) -> Result<VmResult, Error<T>>
+        where
+            <T as timestamp::Config>::Moment: UniqueSaturatedInto<u64>,
+            T::BlockNumber: TryInto<u64>,
         {
             // TODO: some minimum gas for processing transaction from bytes?
             let transaction = Transaction::try_from(&tx_bc[..])
                 .map_err(|_| Error::<T>::TransactionValidationError)?;
 
-            let vm = Self::get_vm()?;
-            let gas = Self::get_move_gas_limit(gas_limit)?;
-
             let tx = {
                 let signers = if transaction.signers_count() == 0 {
                     Vec::with_capacity(0)
                 } else {
-                    debug!("executing `execute` with signed {:?}", account);
-                    let sender = addr::account_to_bytes(account);
-                    debug!("converted sender: {:?}", sender);
-
-                    vec![AccountAddress::new(sender)]
+                    debug!("executing `execute` with signed {:?}", signers);
+                    signers
+                        .iter()
+                        .map(addr::account_to_account_address)
+                        .collect::<Vec<_>>()
                 };
 
                 if transaction.signers_count() as usize != signers.len() {
@@ -319,6 +1335,82 @@ pub mod pallet {
                     .map_err(|_| Error::<T>::TransactionValidationError)?
             };
 
+            Self::raw_execute_script_tx(tx, Some(depositor), gas_limit, dry_run)
+        }
+
+        /// Call public module function with the `account` as signer,
+        /// see [`function::function_call`].
+        pub fn raw_execute_function(
+            account: &T::AccountId,
+            module: types::MoveModuleId<T::AccountId>,
+            function: Vec<u8>,
+            type_args: Vec<Vec<u8>>,
+            args: Vec<Vec<u8>>,
+            gas_limit: u64,
+            dry_run: bool,
+        ) -> Result<VmResult, Error<T>>
+        where
+            <T as timestamp::Config>::Moment: UniqueSaturatedInto<u64>,
+            T::BlockNumber: TryInto<u64>,
+        {
+            let identifier = |name: Vec<u8>| {
+                core::str::from_utf8(&name)
+                    .ok()
+                    .and_then(|name| Identifier::new(name).ok())
+                    .ok_or(Error::<T>::FunctionNotFound)
+            };
+            let module_id = ModuleId::new(
+                addr::account_to_account_address(&module.owner),
+                identifier(module.module)?,
+            );
+            let function = identifier(function)?;
+
+            let state = State::new(Self::state_storage(), T::Oracle::default());
+            let module = state
+                .get_module(&module_id)
+                .ok()
+                .flatten()
+                .ok_or(Error::<T>::FunctionNotFound)?;
+            let module = CompiledModule::deserialize(&module)
+                .map_err(|_| Error::<T>::UnexpectedDeserializationError)?;
+            let call = function::function_call(&module, &function)?;
+
+            let signers = match call.signers_count {
+                0 => Vec::with_capacity(0),
+                1 => vec![addr::account_to_account_address(account)],
+                _ => return Err(Error::<T>::TransactionSignersNumError),
+            };
+            let type_args = type_args
+                .iter()
+                .map(|tag| core::str::from_utf8(tag).map_err(|_| "Invalid type tag"))
+                .map(|tag| tag.and_then(layout::parse_type_tag))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|_| Error::<T>::InvalidFunctionArguments)?;
+            let args = function::decode_args(&call.params, &args)
+                .map_err(|_| Error::<T>::InvalidFunctionArguments)?;
+
+            let tx = ScriptTx::new(call.code, args, type_args, signers)
+                .map_err(|_| Error::<T>::TransactionValidationError)?;
+
+            Self::raw_execute_script_tx(tx, Some(account), gas_limit, dry_run)
+        }
+
+        /// Execute script transaction with the current block context,
+        /// native balance failures revert the result.
+        /// Storage deposit of the script writes is reserved from the `depositor`.
+        fn raw_execute_script_tx(
+            tx: ScriptTx,
+            depositor: Option<&T::AccountId>,
+            gas_limit: u64,
+            dry_run: bool,
+        ) -> Result<VmResult, Error<T>>
+        where
+            <T as timestamp::Config>::Moment: UniqueSaturatedInto<u64>,
+            T::BlockNumber: TryInto<u64>,
+        {
+            let vm = Self::get_vm()?;
+            let gas = Self::get_move_gas_limit(gas_limit)?;
+
             let ctx = {
                 let height = frame_system::Module::<T>::block_number()
                     .try_into()
@@ -327,10 +1419,18 @@ pub mod pallet {
                 ExecutionContext::new(time, height as u64)
             };
 
-            let res = vm.execute_script(gas, ctx, tx, dry_run);
+            let res = Self::with_storage_depositor(depositor, || {
+                vm.execute_script(gas, ctx, tx, dry_run)
+            });
             debug!("execution result: {:?}", res);
 
-            Ok(res)
+            // Native balance failure wins over the VM status, the script relied on it.
+            if let Some(err) = BalanceAccessFailure::<T>::take() {
+                error!("native balance access failed: {:?}", err);
+                return Err(err.into());
+            }
+
+            res
         }
 
         pub fn raw_publish_module(
@@ -342,19 +1442,383 @@ pub mod pallet {
             let vm = Self::get_vm()?;
             let gas = Self::get_move_gas_limit(gas_limit)?;
 
+            let upgrades = Self::check_module_upgrades(sp_std::slice::from_ref(&module_bc))?;
+
             let tx = {
                 let sender = addr::account_to_bytes(account);
                 debug!("converted sender: {:?}", sender);
-                ModuleTx::new(module_bc, AccountAddress::new(sender))
+                ModuleTx::new(module_bc.clone(), AccountAddress::new(sender))
             };
 
-            let res = vm.publish_module(gas, tx, dry_run);
+            let res = Self::with_storage_depositor(Some(account), || {
+                vm.publish_module(gas, tx, dry_run)
+            })?;
             debug!("publication result: {:?}", res);
 
+            if !dry_run && result::is_ok(&res) {
+                Self::note_published_module(&module_bc);
+                Self::note_module_upgrades(upgrades);
+            }
+
             Ok(res)
         }
     }
 
+    // Transaction simulation, used by RPC
+    impl<T: Config> Pallet<T> {
+        /// Execute script and collect its events, write set and native balance changes.
+        /// All the changes are rolled back.
+        pub fn simulate_execute(
+            account: &T::AccountId,
+            tx_bc: Vec<u8>,
+            gas_limit: u64,
+        ) -> Result<(VmResult, types::SimulationRecord), Error<T>>
+        where
+            <T as timestamp::Config>::Moment: UniqueSaturatedInto<u64>,
+            T::BlockNumber: TryInto<u64>,
+        {
+            Self::simulate(|| Self::raw_execute_script(account, tx_bc, gas_limit, false))
+        }
+
+        /// Publish module and collect its write set. All the changes are rolled back.
+        pub fn simulate_publish(
+            account: &T::AccountId,
+            module_bc: Vec<u8>,
+            gas_limit: u64,
+        ) -> Result<(VmResult, types::SimulationRecord), Error<T>> {
+            Self::simulate(|| Self::raw_publish_module(account, module_bc, gas_limit, false))
+        }
+
+        fn simulate<F>(f: F) -> Result<(VmResult, types::SimulationRecord), Error<T>>
+        where
+            F: FnOnce() -> Result<VmResult, Error<T>>,
+        {
+            let result = support::storage::with_transaction(|| {
+                Simulation::<T>::put(types::SimulationRecord::default());
+                let result = f().map(|res| (res, Simulation::<T>::take().unwrap_or_default()));
+                support::storage::TransactionOutcome::Rollback(result)
+            });
+
+            // Modules published by the simulation mustn't stay in the VM cache.
+            Self::clear_vm_cache();
+
+            result
+        }
+
+        #[cfg(not(feature = "no-vm-static"))]
+        fn clear_vm_cache() {
+            if let Some(vm) = Self::get_move_vm_cell().get() {
+                vm.clear();
+                trace!("VM cache cleared");
+            }
+        }
+
+        #[cfg(feature = "no-vm-static")]
+        fn clear_vm_cache() {}
+
+        /// Update the simulation record if the simulation is in progress.
+        pub(crate) fn record_simulation<F: FnOnce(&mut types::SimulationRecord)>(f: F) {
+            if Simulation::<T>::exists() {
+                Simulation::<T>::mutate(|record| {
+                    if let Some(record) = record {
+                        f(record)
+                    }
+                });
+            }
+        }
+    }
+
+    // Batch publishing
+    impl<T: Config> Pallet<T> {
+        /// Publish modules one by one with the shared gas limit, so every module sees
+        /// the previous ones. All or nothing: the first failed module rolls back
+        /// the whole batch with its events, its index goes to `BatchPublishFailed`.
+        /// Returns the VM results and ids of the published modules.
+        fn publish_batch(
+            modules: Vec<Vec<u8>>,
+            sender: AccountAddress,
+            depositor: Option<&T::AccountId>,
+            gas_limit: u64,
+            base_weight: Weight,
+        ) -> Result<
+            (Vec<VmResult>, Vec<types::MoveModuleId<T::AccountId>>),
+            DispatchErrorWithPostInfo,
+        > {
+            let vm = Self::get_vm()?;
+
+            let mut results = Vec::with_capacity(modules.len());
+            let mut ids = Vec::with_capacity(modules.len());
+            let outcome = with_transaction(|| {
+                for (index, module) in modules.into_iter().enumerate() {
+                    let gas_used: u64 = results.iter().map(|res: &VmResult| res.gas_used).sum();
+                    let res = Self::get_move_gas_limit(gas_limit.saturating_sub(gas_used))
+                        .and_then(|gas| {
+                            let upgrades =
+                                Self::check_module_upgrades(sp_std::slice::from_ref(&module))?;
+                            let tx = ModuleTx::new(module.clone(), sender);
+                            let res = Self::with_storage_depositor(depositor, || {
+                                vm.publish_module(gas, tx, false)
+                            })?;
+                            debug!("publish result: {:?}", res);
+
+                            let status = result::from_status_code::<T>(res.status_code);
+                            results.push(res);
+                            status.map(|_| upgrades)
+                        });
+
+                    match res {
+                        Ok(upgrades) => {
+                            ids.extend(Self::note_published_module(&module));
+                            Self::note_module_upgrades(upgrades);
+                        }
+                        Err(err) => return TransactionOutcome::Rollback(Err((index, err))),
+                    }
+                }
+                TransactionOutcome::Commit(Ok(()))
+            });
+
+            match outcome {
+                Ok(()) => Ok((results, ids)),
+                Err((index, err)) => {
+                    error!("batch publishing failed at module {}: {:?}", index, err);
+                    // Modules of the rolled back batch mustn't stay in the VM cache.
+                    Self::clear_vm_cache();
+                    let err = DispatchError::from(err);
+                    Self::deposit_event(Event::BatchPublishFailed(index as u32, err));
+                    Err(result::batch_error::<T>(&results, base_weight, err))
+                }
+            }
+        }
+    }
+
+    // Gas estimation of batch publishing, used by RPC
+    impl<T: Config> Pallet<T> {
+        /// Estimate gas of every package module published by the `account`.
+        pub fn estimate_publish_package(
+            account: &T::AccountId,
+            package: &[u8],
+            gas_limit: u64,
+        ) -> Result<Vec<(Vec<u8>, VmResult)>, Error<T>> {
+            let sender = addr::account_to_account_address(account);
+            let modules = Self::package_modules(package, sender)?;
+            Self::estimate_publish_modules(modules, sender, gas_limit)
+        }
+
+        /// Estimate gas of every std module published under `0x1`.
+        pub fn estimate_publish_std(
+            modules: Vec<Vec<u8>>,
+            gas_limit: u64,
+        ) -> Result<Vec<(Vec<u8>, VmResult)>, Error<T>> {
+            Self::estimate_publish_modules(modules, CORE_CODE_ADDRESS, gas_limit)
+        }
+
+        /// Publish modules one by one with the shared gas limit, so every module sees
+        /// the previous ones, then roll all the changes back.
+        /// Results are module names with publishing results, up to the first failed module.
+        fn estimate_publish_modules(
+            modules: Vec<Vec<u8>>,
+            sender: AccountAddress,
+            gas_limit: u64,
+        ) -> Result<Vec<(Vec<u8>, VmResult)>, Error<T>> {
+            let vm = Self::get_vm()?;
+
+            let results = support::storage::with_transaction(|| {
+                let mut gas_used = 0;
+                let mut results = Vec::with_capacity(modules.len());
+                for module in modules.into_iter() {
+                    let gas = match Self::get_move_gas_limit(gas_limit.saturating_sub(gas_used)) {
+                        Ok(gas) => gas,
+                        Err(err) => {
+                            return support::storage::TransactionOutcome::Rollback(Err(err))
+                        }
+                    };
+
+                    let name = CompiledModule::deserialize(&module)
+                        .map(|module| module.self_id().name().as_str().as_bytes().to_vec())
+                        .unwrap_or_default();
+                    let res = vm.publish_module(gas, ModuleTx::new(module, sender), false);
+                    debug!("estimation publish result: {:?}", res);
+
+                    let is_ok = result::is_ok(&res);
+                    gas_used += res.gas_used;
+                    results.push((name, res));
+                    if !is_ok {
+                        break;
+                    }
+                }
+                support::storage::TransactionOutcome::Rollback(Ok(results))
+            });
+
+            // Estimated modules mustn't stay in the VM cache.
+            Self::clear_vm_cache();
+
+            results
+        }
+    }
+
+    // Storage deposit of the VM writes
+    impl<T: Config> Pallet<T> {
+        /// Total storage deposit reserved from the account.
+        pub fn storage_deposit(account: &T::AccountId) -> BalanceOf<T> {
+            StorageDepositOf::<T>::get(account)
+        }
+
+        /// Run the VM call `f` with the `depositor` paying storage deposit of its writes.
+        /// Writes without depositor are free.
+        fn with_storage_depositor<R, F>(
+            depositor: Option<&T::AccountId>,
+            f: F,
+        ) -> Result<R, Error<T>>
+        where
+            F: FnOnce() -> R,
+        {
+            if let Some(depositor) = depositor {
+                StorageDepositor::<T>::put(depositor);
+            }
+            let res = f();
+            StorageDepositor::<T>::kill();
+
+            if let Some(err) = StorageWriteFailure::<T>::take() {
+                error!("storage write rejected: {:?}", err);
+                return Err(err.into());
+            }
+            Ok(res)
+        }
+
+        /// Record the rejected write, only the first one is kept.
+        fn fail_storage_write(err: storage::StorageWriteError) {
+            StorageWriteFailure::<T>::mutate(|failure| {
+                if failure.is_none() {
+                    *failure = Some(err);
+                }
+            });
+        }
+
+        /// Reserve deposit for the created or grown `VMStorage` entry from the current depositor,
+        /// every depositor keeps its share of the entry deposit.
+        /// Shrunk entries release the writer share first, then the latest shares,
+        /// removal releases them all. Writes without depositor are free.
+        fn update_storage_deposit(key: &[u8], value_len: Option<usize>) {
+            let depositor = StorageDepositor::<T>::get();
+            if value_len.is_some() && depositor.is_none() {
+                return;
+            }
+
+            let required = value_len
+                .map(|len| {
+                    let bytes = BalanceOf::<T>::from((key.len() + len) as u32);
+                    T::StorageDepositPerByte::get().saturating_mul(bytes)
+                })
+                .unwrap_or_else(Zero::zero);
+
+            let mut shares = StorageDeposits::<T>::get(key);
+            let reserved = shares
+                .iter()
+                .fold(BalanceOf::<T>::zero(), |total, (_, share)| {
+                    total.saturating_add(*share)
+                });
+
+            if required > reserved {
+                let who = match depositor {
+                    Some(who) => who,
+                    None => return,
+                };
+                let extra = required - reserved;
+                if let Err(err) = Self::reserve_storage_deposit(&who, extra) {
+                    error!("storage deposit can't be reserved: {:?}", err);
+                    Self::fail_storage_write(storage::StorageWriteError::InsufficientDeposit);
+                    return;
+                }
+                match shares.iter_mut().find(|(account, _)| account == &who) {
+                    Some((_, share)) => *share = share.saturating_add(extra),
+                    None => shares.push((who, extra)),
+                }
+            } else if required < reserved {
+                // the writer share goes first:
+                let writer = depositor
+                    .and_then(|who| shares.iter().position(|(account, _)| account == &who));
+                if let Some(index) = writer {
+                    let share = shares.remove(index);
+                    shares.push(share);
+                }
+
+                let mut excess = reserved - required;
+                while !excess.is_zero() {
+                    let (who, share) = match shares.last_mut() {
+                        Some(last) => last,
+                        None => break,
+                    };
+                    let released = (*share).min(excess);
+                    Self::release_storage_deposit(who, released);
+                    *share -= released;
+                    excess -= released;
+                    if share.is_zero() {
+                        shares.pop();
+                    }
+                }
+            }
+
+            if shares.is_empty() {
+                StorageDeposits::<T>::remove(key);
+            } else {
+                StorageDeposits::<T>::insert(key, shares);
+            }
+        }
+
+        fn reserve_storage_deposit(who: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+            <balances::Module<T> as ReservableCurrency<T::AccountId>>::reserve(who, amount)?;
+            StorageDepositOf::<T>::mutate(who, |total| *total = total.saturating_add(amount));
+            Ok(())
+        }
+
+        fn release_storage_deposit(who: &T::AccountId, amount: BalanceOf<T>) {
+            <balances::Module<T> as ReservableCurrency<T::AccountId>>::unreserve(who, amount);
+            StorageDepositOf::<T>::mutate(who, |total| *total = total.saturating_sub(amount));
+        }
+    }
+
+    // Storage usage of the Move addresses
+    impl<T: Config> Pallet<T> {
+        /// Account the written `VMStorage` entry to its owner.
+        /// Writes growing the owner usage over the quota are rejected whoever the writer is,
+        /// so the others can't fill the address up. Writes without depositor
+        /// (genesis and std publishing) aren't limited.
+        fn update_storage_usage(key: &[u8], value_len: Option<usize>) {
+            let owner = match storage::key_owner(key) {
+                Some(owner) => owner.as_ref().to_vec(),
+                None => return,
+            };
+            let (address, path) = storage::split_key(key);
+            let old_len = VMStorage::<T>::decode_len(address, path);
+            let entry_bytes = |len: usize| (key.len() + len) as u64;
+
+            let mut usage = StorageUsage::<T>::get(&owner);
+            if let Some(len) = old_len {
+                usage.keys = usage.keys.saturating_sub(1);
+                usage.bytes = usage.bytes.saturating_sub(entry_bytes(len));
+            }
+            if let Some(len) = value_len {
+                usage.keys = usage.keys.saturating_add(1);
+                usage.bytes = usage.bytes.saturating_add(entry_bytes(len));
+            }
+
+            let grows = value_len > old_len;
+            let exceeded = usage.keys > T::StorageQuotaKeys::get()
+                || usage.bytes > T::StorageQuotaBytes::get();
+            let limited = StorageDepositor::<T>::exists();
+            if grows && exceeded && limited {
+                error!("storage quota exceeded: {:?}", usage);
+                Self::fail_storage_write(storage::StorageWriteError::QuotaExceeded);
+            }
+
+            if usage == Default::default() {
+                StorageUsage::<T>::remove(&owner);
+            } else {
+                StorageUsage::<T>::insert(&owner, usage);
+            }
+        }
+    }
+
     /// Get storage adapter ready for the VM
     impl<T: Config, K, V> super::storage::MoveVmStorage<T, K, V> for Pallet<T>
     where
@@ -364,6 +1828,20 @@ pub mod pallet {
         type VmStorage = VMStorage<T>;
     }
 
+    impl<T: Config> storage::StorageObserver for Pallet<T> {
+        fn on_insert(key: &[u8], value: &[u8]) {
+            Self::record_simulation(|record| record.write(key, Some(value)));
+            Self::update_storage_usage(key, Some(value.len()));
+            Self::update_storage_deposit(key, Some(value.len()));
+        }
+
+        fn on_remove(key: &[u8]) {
+            Self::record_simulation(|record| record.write(key, None));
+            Self::update_storage_usage(key, None);
+            Self::update_storage_deposit(key, None);
+        }
+    }
+
     impl<T: Config> event::DepositMoveEvent for Pallet<T> {
         fn deposit_move_event(e: MoveEventArguments) {
             debug!(
@@ -371,9 +1849,22 @@ pub mod pallet {
                 e.addr, e.caller, e.ty_tag, e.message
             );
 
+            let sender = e.addr.as_ref().to_vec();
+            let ty_tag = e.ty_tag_string();
+
             // Emit an event:
             // TODO: dispatch up the error by TryInto. Error is almost impossible but who knows..
-            Self::deposit_event(e.try_into().expect("Cannot back-convert address"));
+            let event = e.try_into().expect("Cannot back-convert address");
+            if let Event::Event(_, _, message, _) = &event {
+                Self::record_simulation(|record| {
+                    record.events.push(types::SimulatedEvent {
+                        sender,
+                        ty_tag,
+                        message: message.clone(),
+                    })
+                });
+            }
+            Self::deposit_event(event);
         }
     }
 
@@ -382,14 +1873,14 @@ pub mod pallet {
         type Vm = Mvm<
             boxed::StorageAdapter,
             event::DefaultEventHandler,
-            oracle::DummyOracle,
+            boxed::OracleAdapter,
             boxed::BalancesAdapter,
         >;
         #[cfg(feature = "no-vm-static")]
         type Vm = Mvm<
-            StorageAdapter<VMStorage<T>>,
+            storage::ObservedStorage<StorageAdapter<VMStorage<T>>, Self>,
             event::DefaultEventHandler,
-            oracle::DummyOracle,
+            T::Oracle,
             balance::BalancesAdapter<T>,
         >;
         type Error = Error<T>;
@@ -397,9 +1888,9 @@ pub mod pallet {
         fn try_create_move_vm() -> Result<Self::Vm, Self::Error> {
             trace!("MoveVM created");
             Mvm::new(
-                Self::move_vm_storage().into(),
+                storage::ObservedStorage::<_, Self>::new(Self::move_vm_storage()).into(),
                 Self::create_move_event_handler(),
-                Default::default(),
+                Self::create_move_oracle(),
                 balance::BalancesAdapter::<T>::new().into(),
             )
             .map_err(|err| {
@@ -759,5 +2250,51 @@ pub mod pallet {
         VmMaxValueDepthReached,
         /// Unknown status.
         UnknownStatus,
+
+        /// Sender isn't one of the script signers
+        NotScriptSigner,
+        /// Same account passed as script signer twice
+        DuplicateScriptSigner,
+        /// Script already approved by the sender
+        ScriptAlreadyApproved,
+        /// Gas limit differs from the one of the first approval
+        ScriptGasLimitMismatch,
+        /// Script approval deposit can't be reserved
+        InsufficientApprovalDeposit,
+        /// No pending approval of the script by the sender
+        ScriptApprovalNotFound,
+
+        /// Account is already an oracle feeder
+        OracleFeederAlreadyExists,
+        /// Account isn't an oracle feeder
+        OracleFeederNotFound,
+
+        /// Native balance address or amount can't be converted from Move
+        NativeBalanceConversionFailed,
+        /// Native balance can't be withdrawn for Move deposit
+        NativeWithdrawFailed,
+        /// Native balance can't be deposited for Move withdrawal
+        NativeDepositFailed,
+
+        /// Module or its function not found
+        FunctionNotFound,
+        /// Function isn't public
+        FunctionNotPublic,
+        /// Function parameters or return values aren't signers, primitives or `vector<u8>`
+        UnsupportedFunctionSignature,
+        /// Function type arguments or arguments can't be decoded
+        InvalidFunctionArguments,
+
+        /// Module or its upgrade policy is immutable
+        ModuleUpgradeForbidden,
+        /// New module changes structs or public function signatures of the published one
+        IncompatibleModuleUpgrade,
+        /// Published module can't be deserialized to check its upgrade
+        InvalidPublishedModule,
+
+        /// Storage deposit of the written Move state can't be reserved
+        InsufficientStorageDeposit,
+        /// Move state of the address exceeds the storage quota
+        StorageQuotaExceeded,
     }
 }
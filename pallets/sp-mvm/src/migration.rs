@@ -0,0 +1,149 @@
+//! Versioned migrations of the pallet storage layout.
+//!
+//! The VM storage keys are produced by the pinned `sp-move-vm` revision.
+//! Any change of the key format (or of the `VMStorage` layout) must come
+//! with a new [`Releases`] variant and a migration step in [`steps`],
+//! otherwise existing entries become unreachable by the VM.
+
+use sp_std::prelude::*;
+use codec::{Decode, Encode};
+use frame_support::RuntimeDebug;
+use frame_support::storage::{unhashed, StoragePrefixedMap};
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
+
+use crate::storage::{join_key, split_key};
+use crate::{Config, StorageVersion, VMStorage};
+
+/// Storage layout versions, recorded in `StorageVersion`.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, RuntimeDebug)]
+pub enum Releases {
+    /// Flat `VMStorage` keyed by the whole VM key.
+    V1_0_0,
+    /// `VMStorage` keyed by the owner address and the access path.
+    V2_0_0,
+}
+
+impl Releases {
+    /// Storage layout of the current pallet code.
+    pub const LATEST: Releases = Releases::V2_0_0;
+}
+
+impl Default for Releases {
+    /// Chains started before the versioning have the first layout.
+    fn default() -> Self {
+        Releases::V1_0_0
+    }
+}
+
+/// Migration steps in ascending order, each one upgrades the storage to its release.
+fn steps<T: Config>() -> Vec<(Releases, fn() -> Weight)> {
+    vec![(Releases::V2_0_0, migrate_flat_vm_storage::<T>)]
+}
+
+/// Apply the steps newer than the recorded storage version, one by one.
+pub fn migrate<T: Config>() -> Weight {
+    let mut version = StorageVersion::<T>::get();
+    let mut weight = T::DbWeight::get().reads(1);
+
+    for (release, step) in steps::<T>() {
+        if version >= release {
+            continue;
+        }
+        info!("migrating storage from {:?} to {:?}", version, release);
+        weight = weight.saturating_add(step());
+        version = release;
+        StorageVersion::<T>::put(version);
+        weight = weight.saturating_add(T::DbWeight::get().writes(1));
+    }
+    weight
+}
+
+/// Checks before the migration, returns the number of `VMStorage` entries.
+pub fn pre_upgrade<T: Config>() -> Result<u32, &'static str> {
+    if StorageVersion::<T>::get() > Releases::LATEST {
+        return Err("Storage version is newer than the pallet code");
+    }
+    Ok(raw_keys(&VMStorage::<T>::final_prefix()).count() as u32)
+}
+
+/// Checks after the migration: nothing is lost and every entry is reachable by the VM key.
+pub fn post_upgrade<T: Config>(entries: u32) -> Result<(), &'static str> {
+    if StorageVersion::<T>::get() != Releases::LATEST {
+        return Err("Storage version is not the latest one");
+    }
+
+    let prefix = VMStorage::<T>::final_prefix();
+    if raw_keys(&prefix).count() as u32 != entries {
+        return Err("Number of VMStorage entries changed");
+    }
+    if raw_keys(&prefix).any(|raw_key| flat_key(&raw_key[prefix.len()..]).is_some()) {
+        return Err("Flat VMStorage entries left");
+    }
+
+    let reachable = VMStorage::<T>::iter().all(|(address, path, _)| {
+        let (new_address, new_path) = split_key(&join_key(&address, &path));
+        new_address == address && new_path == path
+    });
+    if reachable {
+        Ok(())
+    } else {
+        Err("VMStorage entry is unreachable by its VM key")
+    }
+}
+
+/// Move `VMStorage` entries from the flat map keyed by the whole VM key
+/// into the double map keyed by the owner address and the access path.
+///
+/// Entries are moved one by one while iterating the raw keys, nothing is collected in memory.
+/// Entries already in the new layout are left as is, so the migration is idempotent.
+/// Returns the exact weight: every visited key and migrated value is read,
+/// every migrated entry is removed and inserted.
+pub fn migrate_flat_vm_storage<T: Config>() -> Weight {
+    let prefix = VMStorage::<T>::final_prefix();
+    let mut visited: Weight = 0;
+    let mut migrated: Weight = 0;
+
+    for raw_key in raw_keys(&prefix) {
+        visited += 1;
+        let key = match flat_key(&raw_key[prefix.len()..]) {
+            Some(key) => key,
+            None => continue,
+        };
+        if let Some(value) = unhashed::get::<Vec<u8>>(&raw_key) {
+            unhashed::kill(&raw_key);
+            let (address, path) = split_key(&key);
+            VMStorage::<T>::insert(address, path, value);
+            migrated += 1;
+        }
+    }
+    info!(
+        "{} VMStorage entries migrated to the account layout",
+        migrated
+    );
+
+    T::DbWeight::get().reads_writes(visited + migrated, migrated * 2)
+}
+
+/// Raw storage keys under the prefix, read lazily one by one.
+/// The next key is read before the current one is yielded, so it can be removed.
+fn raw_keys(prefix: &[u8]) -> impl Iterator<Item = Vec<u8>> + '_ {
+    sp_std::iter::successors(sp_io::storage::next_key(prefix), |key| {
+        sp_io::storage::next_key(key)
+    })
+    .take_while(move |key| key.starts_with(prefix))
+}
+
+/// VM key of the flat map entry: `Blake2_128Concat` hashed `Vec<u8>` and nothing more.
+fn flat_key(hashed: &[u8]) -> Option<Vec<u8>> {
+    const HASH_LEN: usize = 16;
+
+    let encoded = hashed.get(HASH_LEN..)?;
+    let mut input = encoded;
+    let key = Vec::<u8>::decode(&mut input).ok()?;
+    if input.is_empty() && sp_io::hashing::blake2_128(encoded)[..] == hashed[..HASH_LEN] {
+        Some(key)
+    } else {
+        None
+    }
+}
@@ -29,7 +29,7 @@ mod vm_static {
     use move_vm::types::ScriptTx;
     use move_vm::data::ExecutionContext;
 
-    use crate::oracle::DummyOracle;
+    use crate::oracle::boxed::OracleAdapter;
     use crate::storage::boxed::*;
     use crate::balance::boxed::BalancesAdapter;
     use crate::event::DefaultEventHandler;
@@ -42,7 +42,7 @@ mod vm_static {
 
     /// Default type of Move VM implementation
     pub type DefaultVm<E, O> = Mvm<VmStorageAdapter, E, O, BalancesAdapter>;
-    pub type VmWrapperTy = VmWrapper<DefaultVm<DefaultEventHandler, DummyOracle>>;
+    pub type VmWrapperTy = VmWrapper<DefaultVm<DefaultEventHandler, OracleAdapter>>;
 
     /// New-type with unsafe impl Send + Sync.
     /// This is just wrapper around VM without Pin or ref-counting,
@@ -1,5 +1,11 @@
+use core::marker::PhantomData;
+use sp_std::prelude::*;
+use frame_support::traits::Get;
+use frame_support::traits::UnixTime;
 use move_vm::data::Oracle;
 
+use crate::{Config, OracleFeeders, OraclePrices};
+
 #[derive(Clone, Copy, Default)]
 pub struct DummyOracle;
 
@@ -8,3 +14,88 @@ impl Oracle for DummyOracle {
         Some(1)
     }
 }
+
+/// Oracle over prices stored by authorized feeders.
+/// Returns median of the fresh feeders prices for the ticker (e.g. `BTC_PONT`).
+pub struct StorageOracle<T>(PhantomData<T>);
+
+impl<T> Default for StorageOracle<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: Config> Oracle for StorageOracle<T> {
+    fn get_price(&self, ticker: &str) -> Option<u128> {
+        let now = <timestamp::Module<T> as UnixTime>::now().as_millis() as u64;
+        let lifetime = T::OraclePriceLifetime::get();
+
+        let mut prices = OracleFeeders::<T>::get()
+            .iter()
+            .filter_map(|feeder| OraclePrices::<T>::get(feeder, ticker.as_bytes()))
+            .filter(|(_, updated)| now.saturating_sub(*updated) <= lifetime)
+            .map(|(price, _)| price)
+            .collect::<Vec<_>>();
+
+        let price = median(&mut prices);
+        trace!("oracle price for '{}': {:?}", ticker, price);
+        price
+    }
+}
+
+/// Median of the prices, mean of the two middle ones for even count.
+pub fn median(prices: &mut [u128]) -> Option<u128> {
+    if prices.is_empty() {
+        return None;
+    }
+
+    prices.sort_unstable();
+    let mid = prices.len() / 2;
+    if prices.len() % 2 == 1 {
+        Some(prices[mid])
+    } else {
+        let (a, b) = (prices[mid - 1], prices[mid]);
+        Some(a / 2 + b / 2 + (a % 2 + b % 2) / 2)
+    }
+}
+
+#[cfg(not(feature = "no-vm-static"))]
+pub mod boxed {
+    use sp_std::prelude::*;
+    use move_vm::data::Oracle;
+
+    pub type OracleAdapter = OracleBoxedAdapter;
+
+    /// Oracle boxed adapter for the static VM
+    pub struct OracleBoxedAdapter {
+        f_get_price: Box<dyn Fn(&str) -> Option<u128>>,
+    }
+
+    impl OracleBoxedAdapter {
+        pub fn new<O: Oracle + 'static>(oracle: O) -> Self {
+            Self {
+                f_get_price: Box::new(move |ticker| oracle.get_price(ticker)),
+            }
+        }
+    }
+
+    impl Oracle for OracleBoxedAdapter {
+        fn get_price(&self, ticker: &str) -> Option<u128> {
+            (self.f_get_price)(ticker)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::median;
+
+    #[test]
+    fn median_of_prices() {
+        assert_eq!(None, median(&mut []));
+        assert_eq!(Some(5), median(&mut [5]));
+        assert_eq!(Some(3), median(&mut [7, 1, 3]));
+        assert_eq!(Some(4), median(&mut [7, 1, 3, 5]));
+        assert_eq!(Some(u128::MAX), median(&mut [u128::MAX, u128::MAX]));
+    }
+}
@@ -1,10 +1,14 @@
 use super::{Config, Error};
 use crate::gas::GasWeightMapping;
+use crate::balance::BalanceAccessError;
+use crate::function::FunctionCallError;
+use crate::storage::StorageWriteError;
 use frame_support::dispatch::DispatchErrorWithPostInfo;
 use frame_support::dispatch::DispatchResultWithPostInfo;
 use frame_support::dispatch::PostDispatchInfo;
 use frame_support::dispatch::Weight;
 use frame_support::weights::Pays;
+use sp_runtime::DispatchError;
 use move_vm::types::VmResult;
 use move_core_types::vm_status::StatusCode;
 
@@ -39,9 +43,15 @@ pub fn from_status_code_with_gas<T: Config>(
     }
 }
 
-pub fn from_vm_result<T: Config>(vm_result: VmResult) -> DispatchResultWithPostInfo {
+/// Result with the actual weight: benchmarked `base_weight` and the used gas.
+pub fn from_vm_result<T: Config>(
+    vm_result: VmResult,
+    base_weight: Weight,
+) -> DispatchResultWithPostInfo {
     let gas = PostDispatchInfo {
-        actual_weight: Some(T::GasWeightMapping::gas_to_weight(vm_result.gas_used)),
+        actual_weight: Some(
+            base_weight.saturating_add(T::GasWeightMapping::gas_to_weight(vm_result.gas_used)),
+        ),
         pays_fee: Pays::Yes,
     };
 
@@ -56,7 +66,11 @@ pub fn from_vm_result<T: Config>(vm_result: VmResult) -> DispatchResultWithPostI
     }
 }
 
-pub fn from_vm_results<T: Config>(vm_results: &[VmResult]) -> DispatchResultWithPostInfo {
+/// Result of the batch with the actual weight: benchmarked `base_weight` and the used gas.
+pub fn from_vm_results<T: Config>(
+    vm_results: &[VmResult],
+    base_weight: Weight,
+) -> DispatchResultWithPostInfo {
     let mut gas_total = 0;
     for vm_result in vm_results {
         gas_total += vm_result.gas_used;
@@ -65,7 +79,9 @@ pub fn from_vm_results<T: Config>(vm_results: &[VmResult]) -> DispatchResultWith
             StatusCode::EXECUTED => {}
             status_code => {
                 let gas = PostDispatchInfo {
-                    actual_weight: Some(T::GasWeightMapping::gas_to_weight(gas_total)),
+                    actual_weight: Some(
+                        base_weight.saturating_add(T::GasWeightMapping::gas_to_weight(gas_total)),
+                    ),
                     pays_fee: Pays::Yes,
                 };
                 return Err({
@@ -79,13 +95,34 @@ pub fn from_vm_results<T: Config>(vm_results: &[VmResult]) -> DispatchResultWith
     }
 
     let gas = PostDispatchInfo {
-        actual_weight: Some(T::GasWeightMapping::gas_to_weight(gas_total)),
+        actual_weight: Some(
+            base_weight.saturating_add(T::GasWeightMapping::gas_to_weight(gas_total)),
+        ),
         pays_fee: Pays::Yes,
     };
 
     Ok(gas)
 }
 
+/// Error of the rolled back batch with the actual weight:
+/// benchmarked `base_weight` and the gas used up to the failure.
+pub fn batch_error<T: Config>(
+    vm_results: &[VmResult],
+    base_weight: Weight,
+    error: DispatchError,
+) -> DispatchErrorWithPostInfo {
+    let gas_total = vm_results.iter().map(|res| res.gas_used).sum();
+    DispatchErrorWithPostInfo {
+        post_info: PostDispatchInfo {
+            actual_weight: Some(
+                base_weight.saturating_add(T::GasWeightMapping::gas_to_weight(gas_total)),
+            ),
+            pays_fee: Pays::Yes,
+        },
+        error,
+    }
+}
+
 impl<T: Config> From<StatusCode> for Error<T> {
     fn from(sp: StatusCode) -> Self {
         match sp {
@@ -284,3 +321,32 @@ impl<T: Config> From<StatusCode> for Error<T> {
         }
     }
 }
+
+impl<T: Config> From<BalanceAccessError> for Error<T> {
+    fn from(err: BalanceAccessError) -> Self {
+        match err {
+            BalanceAccessError::Conversion => Error::<T>::NativeBalanceConversionFailed,
+            BalanceAccessError::Withdraw => Error::<T>::NativeWithdrawFailed,
+            BalanceAccessError::Deposit => Error::<T>::NativeDepositFailed,
+        }
+    }
+}
+
+impl<T: Config> From<FunctionCallError> for Error<T> {
+    fn from(err: FunctionCallError) -> Self {
+        match err {
+            FunctionCallError::NotFound => Error::<T>::FunctionNotFound,
+            FunctionCallError::NotPublic => Error::<T>::FunctionNotPublic,
+            FunctionCallError::UnsupportedSignature => Error::<T>::UnsupportedFunctionSignature,
+        }
+    }
+}
+
+impl<T: Config> From<StorageWriteError> for Error<T> {
+    fn from(err: StorageWriteError) -> Self {
+        match err {
+            StorageWriteError::InsufficientDeposit => Error::<T>::InsufficientStorageDeposit,
+            StorageWriteError::QuotaExceeded => Error::<T>::StorageQuotaExceeded,
+        }
+    }
+}
@@ -1,8 +1,12 @@
+use core::convert::TryFrom;
 use core::marker::PhantomData;
 use sp_std::prelude::*;
+use codec::{Encode, Decode};
 use codec::FullCodec;
 use codec::FullEncode;
-use frame_support::storage::StorageMap;
+use frame_support::storage::StorageDoubleMap;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::identifier::IdentStr;
 use move_vm::data::Storage;
 
 pub trait MoveVmStorage<T, K: FullEncode, V: FullCodec> {
@@ -10,7 +14,7 @@ pub trait MoveVmStorage<T, K: FullEncode, V: FullCodec> {
 
     fn move_vm_storage() -> StorageAdapter<Self::VmStorage, K, V>
     where
-        Self::VmStorage: StorageMap<K, V, Query = Option<V>>,
+        Self::VmStorage: StorageDoubleMap<K, K, V, Query = Option<V>>,
     {
         Default::default()
     }
@@ -25,23 +29,145 @@ impl<T, K, V> Default for StorageAdapter<T, K, V> {
     }
 }
 
-/// Default VM storage implementation
-impl<T: StorageMap<Vec<u8>, Vec<u8>, Query = Option<Vec<u8>>>> Storage
+/// Default VM storage implementation, keys are split by [`split_key`]
+impl<T: StorageDoubleMap<Vec<u8>, Vec<u8>, Vec<u8>, Query = Option<Vec<u8>>>> Storage
     for StorageAdapter<T, Vec<u8>, Vec<u8>>
 {
     fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
         trace!("storage::get {:?}", key);
-        T::get(key)
+        let (address, path) = split_key(key);
+        T::get(address, path)
     }
 
     fn insert(&self, key: &[u8], value: &[u8]) {
         trace!("storage::set {:?} <= {} bytes", key, value.len());
-        T::insert(key, value)
+        let (address, path) = split_key(key);
+        T::insert(address, path, value)
     }
 
     fn remove(&self, key: &[u8]) {
         trace!("storage::rem {:?}", key);
-        T::remove(key)
+        let (address, path) = split_key(key);
+        T::remove(address, path)
+    }
+}
+
+/// Access path tag of the module keys.
+const CODE_TAG: u8 = 0;
+
+/// Split the VM storage key into the owner Move address and the access path:
+/// - module key `0 ++ address ++ name` into `address` and `0 ++ name`;
+/// - resource key `address ++ 1 ++ tag` into `address` and `1 ++ tag`.
+///
+/// Any other key is kept whole with empty path. [`join_key`] restores the key.
+pub fn split_key(key: &[u8]) -> (Vec<u8>, Vec<u8>) {
+    const LEN: usize = AccountAddress::LENGTH;
+
+    if is_module_key(key) {
+        let mut path = Vec::with_capacity(key.len() - LEN);
+        path.push(CODE_TAG);
+        path.extend_from_slice(&key[1 + LEN..]);
+        (key[1..1 + LEN].to_vec(), path)
+    } else if key.len() > LEN && key[LEN] != CODE_TAG {
+        (key[..LEN].to_vec(), key[LEN..].to_vec())
+    } else {
+        (key.to_vec(), Vec::new())
+    }
+}
+
+/// VM storage key by the parts produced by [`split_key`].
+pub fn join_key(address: &[u8], path: &[u8]) -> Vec<u8> {
+    let mut key = Vec::with_capacity(address.len() + path.len());
+    match path.split_first() {
+        Some((&CODE_TAG, name)) => {
+            key.push(CODE_TAG);
+            key.extend_from_slice(address);
+            key.extend_from_slice(name);
+        }
+        _ => {
+            key.extend_from_slice(address);
+            key.extend_from_slice(path);
+        }
+    }
+    key
+}
+
+/// Module key is the code tag followed by BCS encoded `ModuleId`:
+/// address and ULEB128 length prefixed identifier.
+fn is_module_key(key: &[u8]) -> bool {
+    const LEN: usize = AccountAddress::LENGTH;
+
+    if key.len() <= 1 + LEN || key[0] != CODE_TAG {
+        return false;
+    }
+
+    let mut name = &key[1 + LEN..];
+    let mut len = 0usize;
+    for shift in (0..32).step_by(7) {
+        let (byte, rest) = match name.split_first() {
+            Some(split) => split,
+            None => return false,
+        };
+        name = rest;
+        len |= ((byte & 0x7f) as usize) << shift;
+        if byte & 0x80 == 0 {
+            return len == name.len()
+                && core::str::from_utf8(name)
+                    .map(|name| IdentStr::new(name).is_ok())
+                    .unwrap_or(false);
+        }
+    }
+    false
+}
+
+/// Observer of the VM storage changes, notified right before the change is written
+pub trait StorageObserver {
+    fn on_insert(key: &[u8], value: &[u8]);
+    fn on_remove(key: &[u8]);
+}
+
+/// Move address owning the VM storage key, see [`split_key`].
+pub fn key_owner(key: &[u8]) -> Option<AccountAddress> {
+    let (address, path) = split_key(key);
+    if path.is_empty() {
+        return None;
+    }
+    AccountAddress::try_from(&address[..]).ok()
+}
+
+/// VM storage write rejected by the pallet.
+/// `Storage` can't return errors, so the failure is recorded
+/// and turned into the transaction error after the VM call.
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Debug)]
+pub enum StorageWriteError {
+    /// Storage deposit can't be reserved from the writer.
+    InsufficientDeposit,
+    /// Storage quota of the key owner exceeded.
+    QuotaExceeded,
+}
+
+/// Vm storage adapter notifying the observer `O` about every change
+pub struct ObservedStorage<S, O>(S, PhantomData<O>);
+
+impl<S, O> ObservedStorage<S, O> {
+    pub fn new(storage: S) -> Self {
+        Self(storage, PhantomData)
+    }
+}
+
+impl<S: Storage, O: StorageObserver> Storage for ObservedStorage<S, O> {
+    fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
+        self.0.get(key)
+    }
+
+    fn insert(&self, key: &[u8], value: &[u8]) {
+        O::on_insert(key, value);
+        self.0.insert(key, value)
+    }
+
+    fn remove(&self, key: &[u8]) {
+        O::on_remove(key);
+        self.0.remove(key)
     }
 }
 
@@ -59,25 +185,63 @@ pub mod boxed {
 
     pub fn into_boxfn_adapter<T>() -> VmStorageBoxAdapter
     where
-        T: super::StorageMap<Vec<u8>, Vec<u8>, Query = Option<Vec<u8>>>,
+        T: super::StorageDoubleMap<Vec<u8>, Vec<u8>, Vec<u8>, Query = Option<Vec<u8>>>,
     {
         #![allow(clippy::redundant_closure)]
         VmStorageBoxAdapter {
-            f_get: Box::new(|key: &[u8]| T::get(key)),
-            f_insert: Box::new(|key, value| T::insert(key, value)),
-            f_remove: Box::new(|key| T::remove(key)),
+            f_get: Box::new(|key: &[u8]| {
+                let (address, path) = super::split_key(key);
+                T::get(address, path)
+            }),
+            f_insert: Box::new(|key, value| {
+                let (address, path) = super::split_key(key);
+                T::insert(address, path, value)
+            }),
+            f_remove: Box::new(|key| {
+                let (address, path) = super::split_key(key);
+                T::remove(address, path)
+            }),
         }
     }
 
     impl<T> From<super::StorageAdapter<T, Vec<u8>, Vec<u8>>> for VmStorageBoxAdapter
     where
-        T: super::StorageMap<Vec<u8>, Vec<u8>, Query = Option<Vec<u8>>>,
+        T: super::StorageDoubleMap<Vec<u8>, Vec<u8>, Vec<u8>, Query = Option<Vec<u8>>>,
     {
         fn from(_: super::StorageAdapter<T, Vec<u8>, Vec<u8>>) -> Self {
             into_boxfn_adapter::<T>()
         }
     }
 
+    impl<T, O> From<super::ObservedStorage<super::StorageAdapter<T, Vec<u8>, Vec<u8>>, O>>
+        for VmStorageBoxAdapter
+    where
+        T: super::StorageDoubleMap<Vec<u8>, Vec<u8>, Vec<u8>, Query = Option<Vec<u8>>> + 'static,
+        O: super::StorageObserver + 'static,
+    {
+        fn from(
+            _: super::ObservedStorage<super::StorageAdapter<T, Vec<u8>, Vec<u8>>, O>,
+        ) -> Self {
+            #![allow(clippy::redundant_closure)]
+            VmStorageBoxAdapter {
+                f_get: Box::new(|key: &[u8]| {
+                    let (address, path) = super::split_key(key);
+                    T::get(address, path)
+                }),
+                f_insert: Box::new(|key, value| {
+                    O::on_insert(key, value);
+                    let (address, path) = super::split_key(key);
+                    T::insert(address, path, value)
+                }),
+                f_remove: Box::new(|key| {
+                    O::on_remove(key);
+                    let (address, path) = super::split_key(key);
+                    T::remove(address, path)
+                }),
+            }
+        }
+    }
+
     impl move_vm::data::Storage for VmStorageBoxAdapter {
         fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
             trace!("storage::get {:?}", key);
@@ -95,3 +259,46 @@ pub mod boxed {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{join_key, key_owner, split_key};
+    use move_core_types::account_address::AccountAddress;
+    use move_core_types::identifier::Identifier;
+    use move_core_types::language_storage::ModuleId;
+
+    fn address() -> AccountAddress {
+        AccountAddress::from_hex_literal("0x42").unwrap()
+    }
+
+    #[test]
+    fn split_module_key() {
+        let key = ModuleId::new(address(), Identifier::new("Store").unwrap()).access_vector();
+        let (owner, path) = split_key(&key);
+        assert_eq!(owner, address().as_ref());
+        assert_eq!(path, [&[0, 5][..], b"Store"].concat());
+        assert_eq!(join_key(&owner, &path), key);
+        assert_eq!(key_owner(&key), Some(address()));
+    }
+
+    #[test]
+    fn split_resource_key() {
+        let key = [address().as_ref(), &[1, 2, 3][..]].concat();
+        let (owner, path) = split_key(&key);
+        assert_eq!(owner, address().as_ref());
+        assert_eq!(path, vec![1, 2, 3]);
+        assert_eq!(join_key(&owner, &path), key);
+        assert_eq!(key_owner(&key), Some(address()));
+    }
+
+    #[test]
+    fn split_raw_key() {
+        for key in [vec![], vec![1, 2, 3], vec![0; 40]].iter() {
+            let (owner, path) = split_key(key);
+            assert_eq!(&owner, key);
+            assert!(path.is_empty());
+            assert_eq!(&join_key(&owner, &path), key);
+            assert_eq!(key_owner(key), None);
+        }
+    }
+}
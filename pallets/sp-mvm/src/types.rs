@@ -2,7 +2,7 @@ use core::convert::{TryInto, TryFrom};
 use move_core_types::identifier::Identifier;
 use sp_std::prelude::*;
 use codec::{Encode, Decode};
-use crate::addr::address_to_account;
+use crate::addr::{account_to_account_address, address_to_account};
 use move_core_types::language_storage::ModuleId as InternalModuleId;
 use move_core_types::language_storage::StructTag as InternalStructTag;
 use move_core_types::language_storage::TypeTag as InternalTypeTag;
@@ -24,8 +24,11 @@ impl<AccountId: Decode> TryFrom<InternalModuleId> for MoveModuleId<AccountId> {
     }
 }
 
+/// SCALE encodable `TypeTag` with the addresses converted to accounts.
+// Bounds of the recursive types are the declared ones only.
 #[derive(Clone, PartialEq, Encode, Decode, Debug)]
-pub enum MoveTypeTag<AccountId: Decode> {
+#[codec(dumb_trait_bound)]
+pub enum MoveTypeTag<AccountId: Encode + Decode> {
     Bool,
     U8,
     U64,
@@ -36,7 +39,7 @@ pub enum MoveTypeTag<AccountId: Decode> {
     Struct(MoveStructTag<AccountId>),
 }
 
-impl<AccountId: Decode> TryFrom<InternalTypeTag> for MoveTypeTag<AccountId> {
+impl<AccountId: Encode + Decode> TryFrom<InternalTypeTag> for MoveTypeTag<AccountId> {
     type Error = codec::Error;
 
     fn try_from(tt: InternalTypeTag) -> Result<Self, Self::Error> {
@@ -52,39 +55,46 @@ impl<AccountId: Decode> TryFrom<InternalTypeTag> for MoveTypeTag<AccountId> {
         })
     }
 }
-impl<AccountId: Decode> TryFrom<Box<InternalTypeTag>> for MoveTypeTag<AccountId> {
+impl<AccountId: Encode + Decode> TryFrom<Box<InternalTypeTag>> for MoveTypeTag<AccountId> {
     type Error = codec::Error;
 
     fn try_from(tt: Box<InternalTypeTag>) -> Result<Self, Self::Error> {
-        Ok(match *tt {
-            InternalTypeTag::Bool => MoveTypeTag::Bool,
-            InternalTypeTag::U8 => MoveTypeTag::U8,
-            InternalTypeTag::U64 => MoveTypeTag::U64,
-            InternalTypeTag::U128 => MoveTypeTag::U128,
-            InternalTypeTag::Address => MoveTypeTag::Address,
-            InternalTypeTag::Signer => MoveTypeTag::Signer,
-            InternalTypeTag::Vector(tt) => MoveTypeTag::Vector(Box::new(tt.try_into()?)),
-            InternalTypeTag::Struct(st) => MoveTypeTag::Struct(st.try_into()?),
+        MoveTypeTag::try_from(*tt)
+    }
+}
+
+impl<AccountId: Encode + Decode> TryFrom<&MoveTypeTag<AccountId>> for InternalTypeTag {
+    type Error = &'static str;
+
+    fn try_from(tt: &MoveTypeTag<AccountId>) -> Result<Self, Self::Error> {
+        Ok(match tt {
+            MoveTypeTag::Bool => InternalTypeTag::Bool,
+            MoveTypeTag::U8 => InternalTypeTag::U8,
+            MoveTypeTag::U64 => InternalTypeTag::U64,
+            MoveTypeTag::U128 => InternalTypeTag::U128,
+            MoveTypeTag::Address => InternalTypeTag::Address,
+            MoveTypeTag::Signer => InternalTypeTag::Signer,
+            MoveTypeTag::Vector(tt) => InternalTypeTag::Vector(Box::new(tt.as_ref().try_into()?)),
+            MoveTypeTag::Struct(st) => InternalTypeTag::Struct(st.try_into()?),
         })
     }
 }
 
 #[derive(Clone, PartialEq, Encode, Decode, Debug)]
-pub struct MoveStructTag<AccountId: Decode /* TryFrom<AccountAddress> */> {
+#[codec(dumb_trait_bound)]
+pub struct MoveStructTag<AccountId: Encode + Decode /* TryFrom<AccountAddress> */> {
     pub owner: AccountId,
     pub module: Vec<u8>, /* from Identifier, use Text in web-UI */
     pub name: Vec<u8>,   /* from Identifier, use Text in web-UI */
-
-    // TODO: fix recursion on types (MoveTypeTag in MoveTypeTag)
-    pub ty_params: Vec<()>,
+    pub ty_params: Vec<MoveTypeTag<AccountId>>,
 }
 
-impl<AccountId: Decode> MoveStructTag<AccountId> {
+impl<AccountId: Encode + Decode> MoveStructTag<AccountId> {
     pub fn new(
         owner: AccountId,
         module: Identifier,
         name: Identifier,
-        ty_params: Vec<()>,
+        ty_params: Vec<MoveTypeTag<AccountId>>,
     ) -> Self {
         Self {
             owner,
@@ -95,15 +105,15 @@ impl<AccountId: Decode> MoveStructTag<AccountId> {
     }
 }
 
-impl<AccountId: Decode> TryFrom<InternalStructTag> for MoveStructTag<AccountId> {
+impl<AccountId: Encode + Decode> TryFrom<InternalStructTag> for MoveStructTag<AccountId> {
     type Error = codec::Error;
 
     fn try_from(st: InternalStructTag) -> Result<Self, Self::Error> {
-        let mut type_params = Vec::new();
-        for tp in st.type_params.into_iter() {
-            let _tp: MoveTypeTag<AccountId> = tp.try_into()?;
-            type_params.push(());
-        }
+        let type_params = st
+            .type_params
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, _>>()?;
 
         Ok(Self {
             owner: address_to_account::<AccountId>(&st.address)?,
@@ -113,3 +123,184 @@ impl<AccountId: Decode> TryFrom<InternalStructTag> for MoveStructTag<AccountId>
         })
     }
 }
+
+impl<AccountId: Encode + Decode> TryFrom<&MoveStructTag<AccountId>> for InternalStructTag {
+    type Error = &'static str;
+
+    fn try_from(st: &MoveStructTag<AccountId>) -> Result<Self, Self::Error> {
+        let identifier = |name: &[u8]| {
+            core::str::from_utf8(name)
+                .ok()
+                .and_then(|name| Identifier::new(name).ok())
+                .ok_or("Invalid identifier")
+        };
+
+        Ok(Self {
+            address: account_to_account_address(&st.owner),
+            module: identifier(&st.module)?,
+            name: identifier(&st.name)?,
+            type_params: st
+                .ty_params
+                .iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/// Decoded Move value, see [`crate::layout`].
+#[derive(Clone, PartialEq, Encode, Decode, Debug)]
+pub enum MoveValue {
+    Bool(bool),
+    U8(u8),
+    U64(u64),
+    U128(u128),
+    Address(Vec<u8>),
+    Signer(Vec<u8>),
+    Vector(Vec<MoveValue>),
+    /// Fields with names in declaration order
+    Struct(Vec<(Vec<u8>, MoveValue)>),
+}
+
+/// Module ABI, see [`crate::abi`].
+#[derive(Clone, PartialEq, Encode, Decode, Debug)]
+pub struct ModuleAbi {
+    pub address: Vec<u8>,
+    pub name: Vec<u8>,
+    /// Public functions only
+    pub functions: Vec<FunctionAbi>,
+    pub structs: Vec<StructAbi>,
+}
+
+#[derive(Clone, PartialEq, Encode, Decode, Debug)]
+pub struct FunctionAbi {
+    pub name: Vec<u8>,
+    pub type_parameters: Vec<TypeParameterKind>,
+    /// Parameter types as Move source types, e.g. `&signer`
+    pub parameters: Vec<Vec<u8>>,
+    pub returns: Vec<Vec<u8>>,
+    /// Names of the module structs acquired by the function
+    pub acquires: Vec<Vec<u8>>,
+}
+
+#[derive(Clone, PartialEq, Encode, Decode, Debug)]
+pub struct StructAbi {
+    pub name: Vec<u8>,
+    /// Declared as `resource struct`
+    pub is_resource: bool,
+    pub type_parameters: Vec<TypeParameterKind>,
+    /// Field names and types, `None` for native structs
+    pub fields: Option<Vec<(Vec<u8>, Vec<u8>)>>,
+}
+
+/// Type parameter constraint: any type, resource or copyable.
+#[derive(Clone, Copy, PartialEq, Encode, Decode, Debug)]
+pub enum TypeParameterKind {
+    All,
+    Resource,
+    Copyable,
+}
+
+/// Changes made by the transaction simulation, see [`crate::Pallet::simulate_execute`].
+#[derive(Clone, PartialEq, Encode, Decode, Debug, Default)]
+pub struct SimulationRecord {
+    pub events: Vec<SimulatedEvent>,
+    /// Written `VMStorage` keys with their new values, `None` for removed ones.
+    pub write_set: Vec<(Vec<u8>, Option<Vec<u8>>)>,
+    pub balance_changes: Vec<NativeBalanceChange>,
+}
+
+impl SimulationRecord {
+    /// Only the last change of the key is kept.
+    pub fn write(&mut self, key: &[u8], value: Option<&[u8]>) {
+        let value = value.map(|value| value.to_vec());
+        match self.write_set.iter_mut().find(|(k, _)| k == key) {
+            Some((_, v)) => *v = value,
+            None => self.write_set.push((key.to_vec(), value)),
+        }
+    }
+}
+
+/// Move event deposited in the block, see `Pallet::block_events`.
+#[derive(Clone, PartialEq, Encode, Decode, Debug)]
+pub struct MoveEventRecord {
+    /// Index of the event in the block
+    pub index: u32,
+    /// Index of the extrinsic, `None` for events out of extrinsics
+    pub extrinsic: Option<u32>,
+    /// Move address
+    pub sender: Vec<u8>,
+    /// Type tag as String
+    pub ty_tag: Vec<u8>,
+    /// BCS encoded event
+    pub message: Vec<u8>,
+    /// Decoded event, if the type layout is known
+    pub value: Option<MoveValue>,
+    /// Module emitted the event (Move address, module name), `None` for script
+    pub caller: Option<(Vec<u8>, Vec<u8>)>,
+}
+
+#[derive(Clone, PartialEq, Encode, Decode, Debug)]
+pub struct SimulatedEvent {
+    /// Move address
+    pub sender: Vec<u8>,
+    /// Type tag as String
+    pub ty_tag: Vec<u8>,
+    /// BCS encoded event
+    pub message: Vec<u8>,
+}
+
+/// Native balance change made by Move script.
+#[derive(Clone, PartialEq, Encode, Decode, Debug)]
+pub enum NativeBalanceChange {
+    /// Native balance decreased, moved into Move
+    Withdraw {
+        address: Vec<u8>,
+        ticker: Vec<u8>,
+        amount: u128,
+    },
+    /// Native balance increased, moved out of Move
+    Deposit {
+        address: Vec<u8>,
+        ticker: Vec<u8>,
+        amount: u128,
+    },
+}
+
+/// Pending approvals of the multi-signer script.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, Debug)]
+pub struct ScriptApproval<AccountId, BlockNumber, Balance> {
+    /// Signers approved the script with their reserved deposits
+    pub approvals: Vec<(AccountId, Balance)>,
+    /// Gas limit set by the first approval, the others must pass the same one
+    pub gas_limit: u64,
+    /// Block the approvals expire at
+    pub expires_at: BlockNumber,
+}
+
+/// Which module upgrades are allowed, see [`crate::compat`].
+/// `Compatible` by default, except std under `0x1` which is `Arbitrary`.
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, Debug)]
+pub enum UpgradePolicy {
+    /// Module can be replaced with any bytecode
+    Arbitrary,
+    /// Module can be replaced keeping its structs and public function signatures
+    Compatible,
+    /// Module can't be replaced
+    Immutable,
+}
+
+impl Default for UpgradePolicy {
+    fn default() -> Self {
+        UpgradePolicy::Compatible
+    }
+}
+
+/// Move state owned by the address, see [`crate::storage::key_owner`].
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, Debug, Default)]
+pub struct StorageUsage {
+    /// Number of `VMStorage` keys
+    pub keys: u32,
+    /// Total size of the keys and values
+    pub bytes: u64,
+}
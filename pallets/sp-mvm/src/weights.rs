@@ -0,0 +1,181 @@
+//! Weights for sp_mvm, in the format of the benchmark CLI output.
+//!
+//! The gas-derived part of the VM calls is added by the pallet,
+//! these are the costs around the VM: decoding and verification of the bytecode,
+//! storage access and events.
+//!
+//! The values are placeholders, they weren't measured yet.
+//! Replace this file with the output of `make weights` on the reference hardware,
+//! and regenerate it after changes of the benchmarks, the VM or the hardware.
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{
+    traits::Get,
+    weights::{Weight, constants::RocksDbWeight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for sp_mvm.
+pub trait WeightInfo {
+    fn execute(b: u32) -> Weight;
+    fn execute_multi_signer(b: u32) -> Weight;
+    fn execute_function(b: u32, m: u32) -> Weight;
+    fn cancel_script_approval() -> Weight;
+    fn publish_module(b: u32) -> Weight;
+    fn publish_package(b: u32, m: u32) -> Weight;
+    fn publish_std(b: u32) -> Weight;
+    fn add_oracle_feeder() -> Weight;
+    fn remove_oracle_feeder() -> Weight;
+    fn feed_price() -> Weight;
+    fn set_upgrade_policy() -> Weight;
+    fn set_min_gas_price() -> Weight;
+}
+
+/// Weights for sp_mvm using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    fn execute(b: u32) -> Weight {
+        (48_312_000 as Weight)
+            .saturating_add((21_000 as Weight).saturating_mul(b as Weight))
+            .saturating_add(T::DbWeight::get().reads(6 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    fn execute_multi_signer(b: u32) -> Weight {
+        (52_184_000 as Weight)
+            .saturating_add((21_000 as Weight).saturating_mul(b as Weight))
+            .saturating_add(T::DbWeight::get().reads(7 as Weight))
+            .saturating_add(T::DbWeight::get().writes(3 as Weight))
+    }
+    fn execute_function(b: u32, m: u32) -> Weight {
+        (45_930_000 as Weight)
+            .saturating_add((2_000 as Weight).saturating_mul(b as Weight))
+            .saturating_add((9_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(T::DbWeight::get().reads(6 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    fn cancel_script_approval() -> Weight {
+        (32_408_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    fn publish_module(b: u32) -> Weight {
+        (61_875_000 as Weight)
+            .saturating_add((34_000 as Weight).saturating_mul(b as Weight))
+            .saturating_add(T::DbWeight::get().reads(7 as Weight))
+            .saturating_add(T::DbWeight::get().writes(4 as Weight))
+    }
+    fn publish_package(b: u32, m: u32) -> Weight {
+        (58_402_000 as Weight)
+            .saturating_add((34_000 as Weight).saturating_mul(b as Weight))
+            .saturating_add((11_263_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(T::DbWeight::get().reads(7 as Weight))
+            .saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(m as Weight)))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(m as Weight)))
+    }
+    fn publish_std(b: u32) -> Weight {
+        (64_520_000 as Weight)
+            .saturating_add((41_000 as Weight).saturating_mul(b as Weight))
+            .saturating_add(T::DbWeight::get().reads(20 as Weight))
+            .saturating_add(T::DbWeight::get().writes(34 as Weight))
+    }
+    fn add_oracle_feeder() -> Weight {
+        (21_407_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(1 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn remove_oracle_feeder() -> Weight {
+        (27_963_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(1 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    fn feed_price() -> Weight {
+        (26_114_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn set_upgrade_policy() -> Weight {
+        (24_736_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn set_min_gas_price() -> Weight {
+        (14_382_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+    fn execute(b: u32) -> Weight {
+        (48_312_000 as Weight)
+            .saturating_add((21_000 as Weight).saturating_mul(b as Weight))
+            .saturating_add(RocksDbWeight::get().reads(6 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(2 as Weight))
+    }
+    fn execute_multi_signer(b: u32) -> Weight {
+        (52_184_000 as Weight)
+            .saturating_add((21_000 as Weight).saturating_mul(b as Weight))
+            .saturating_add(RocksDbWeight::get().reads(7 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(3 as Weight))
+    }
+    fn execute_function(b: u32, m: u32) -> Weight {
+        (45_930_000 as Weight)
+            .saturating_add((2_000 as Weight).saturating_mul(b as Weight))
+            .saturating_add((9_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(RocksDbWeight::get().reads(6 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(2 as Weight))
+    }
+    fn cancel_script_approval() -> Weight {
+        (32_408_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(2 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(2 as Weight))
+    }
+    fn publish_module(b: u32) -> Weight {
+        (61_875_000 as Weight)
+            .saturating_add((34_000 as Weight).saturating_mul(b as Weight))
+            .saturating_add(RocksDbWeight::get().reads(7 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(4 as Weight))
+    }
+    fn publish_package(b: u32, m: u32) -> Weight {
+        (58_402_000 as Weight)
+            .saturating_add((34_000 as Weight).saturating_mul(b as Weight))
+            .saturating_add((11_263_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(RocksDbWeight::get().reads(7 as Weight))
+            .saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(m as Weight)))
+            .saturating_add(RocksDbWeight::get().writes(2 as Weight))
+            .saturating_add(
+                RocksDbWeight::get().writes((2 as Weight).saturating_mul(m as Weight)),
+            )
+    }
+    fn publish_std(b: u32) -> Weight {
+        (64_520_000 as Weight)
+            .saturating_add((41_000 as Weight).saturating_mul(b as Weight))
+            .saturating_add(RocksDbWeight::get().reads(20 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(34 as Weight))
+    }
+    fn add_oracle_feeder() -> Weight {
+        (21_407_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(1 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(1 as Weight))
+    }
+    fn remove_oracle_feeder() -> Weight {
+        (27_963_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(1 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(2 as Weight))
+    }
+    fn feed_price() -> Weight {
+        (26_114_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(2 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(1 as Weight))
+    }
+    fn set_upgrade_policy() -> Weight {
+        (24_736_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(2 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(1 as Weight))
+    }
+    fn set_min_gas_price() -> Weight {
+        (14_382_000 as Weight).saturating_add(RocksDbWeight::get().writes(1 as Weight))
+    }
+}
@@ -14,6 +14,8 @@ dove ct "store_native_withdraw(false)"
 dove ct "store_native_withdraw(true)" -o=store_native_withdraw_reg
 dove ct "missed_native_balance()"
 dove ct "get_price_test()"
+dove ct "store_u64_multi(42)"
+dove ct "store_native_deposit_overdraft()"
 popd
 
 pushd ./root
@@ -0,0 +1,17 @@
+script {
+    use 0x1::Account;
+    use 0x1::Signer;
+    use 0x1::Pontem;
+    use 0x1::PONT;
+    use 5FHneW46xGXgs5mUiveU4sbTyGBzmstUspZC92UhjJM694ty::Store;
+
+    fun store_native_deposit_overdraft(account: &signer) {
+        Pontem::register_coin<PONT::T>(b"PONT", 2);
+
+        let balance = Account::get_native_balance<PONT::T>(account);
+        let ponts = Account::deposit_native<PONT::T>(account, balance + 1);
+        Account::deposit(account, Signer::address_of(account), ponts);
+
+        Store::store_u128(account, balance + 1);
+    }
+}
@@ -0,0 +1,8 @@
+script {
+    use 5FHneW46xGXgs5mUiveU4sbTyGBzmstUspZC92UhjJM694ty::Store;
+
+    fun store_u64_multi(first: &signer, second: &signer, val: u64) {
+        Store::store_u64(first, val);
+        Store::store_u64(second, val);
+    }
+}
@@ -4,6 +4,10 @@ use serde::Deserialize;
 use move_core_types::identifier::Identifier;
 use move_core_types::language_storage::StructTag;
 use move_core_types::account_address::AccountAddress;
+use move_vm::data::*;
+use move_vm_runtime::data_cache::RemoteCache;
+use sp_mvm::BalanceAccessFailure;
+use sp_mvm::storage::MoveVmStorage;
 
 mod common;
 use common::assets::*;
@@ -132,10 +136,47 @@ fn execute_deposit_withdraw_balance() {
     });
 }
 
+#[test]
+fn execute_deposit_overdraft_reverted() {
+    new_test_ext().execute_with(|| {
+        let account = origin_ps_acc();
+
+        // publish entire std lib:
+        publish_std();
+
+        // publish user module:
+        publish_module(account, UserMod::Store);
+
+        // execute tx:
+        let signer = Origin::signed(account);
+        let result = execute_tx_unchecked(signer, UserTx::StoreNativeDepositOverdraft, GAS_LIMIT);
+        assert!(result.is_err());
+
+        // nothing is stored and nothing is withdrawn:
+        let tag = StructTag {
+            address: to_move_addr(account),
+            module: Identifier::new(UserMod::Store.name()).unwrap(),
+            name: Identifier::new("U128").unwrap(),
+            type_params: vec![],
+        };
+        let state = State::new(Mvm::move_vm_storage(), MockOracle(None));
+        assert_eq!(
+            None,
+            state.get_resource(&to_move_addr(account), &tag).unwrap()
+        );
+
+        let balance = balances::Pallet::<Test>::free_balance(&account);
+        assert_eq!(INITIAL_BALANCE, balance);
+        assert_eq!(None, BalanceAccessFailure::<Test>::get());
+    });
+}
+
 mod adapter {
     use move_vm::data::BalanceAccess;
     use sp_mvm::balance::BalancesAdapter;
     use sp_mvm::balance::boxed::BalancesAdapter as BoxedBalancesAdapter;
+    use sp_mvm::balance::MultiCurrency;
+    use sp_mvm::balance::BalanceAccessError;
 
     use super::*;
 
@@ -178,6 +219,82 @@ mod adapter {
         });
     }
 
+    fn test_other_currency_with<T: BalanceAccess>(adapter: &T) {
+        new_test_ext().execute_with(|| {
+            let origin = origin_ps_acc();
+            let account = to_move_addr(origin.clone());
+            let btc = || MockCurrencies::free_balance(CurrencyId::Btc, &origin);
+
+            assert_eq!(Some(0), adapter.get_balance(&account, "BTC"));
+
+            adapter.withdraw(&account, "BTC", 100);
+            assert_eq!(100, btc());
+            assert_eq!(Some(100), adapter.get_balance(&account, "BTC"));
+
+            adapter.deposit(&account, "BTC", 40);
+            assert_eq!(60, btc());
+
+            // native balance is untouched:
+            let balance = balances::Pallet::<Test>::free_balance(&origin);
+            assert_eq!(INITIAL_BALANCE, balance);
+
+            // unregistered ticker:
+            assert_eq!(None, adapter.get_balance(&account, "ETH"));
+        });
+    }
+
+    #[test]
+    fn other_currency() {
+        let adapter = BalancesAdapter::<Test>::new();
+        test_other_currency_with(&adapter);
+    }
+
+    #[test]
+    fn other_currency_boxed() {
+        let adapter = BoxedBalancesAdapter::from(BalancesAdapter::<Test>::new());
+        test_other_currency_with(&adapter);
+    }
+
+    fn test_failure_with<T: BalanceAccess>(adapter: &T) {
+        new_test_ext().execute_with(|| {
+            let origin = origin_ps_acc();
+            let account = to_move_addr(origin.clone());
+
+            adapter.deposit(&account, "PONT", INITIAL_BALANCE + 1);
+            assert_eq!(
+                Some(BalanceAccessError::Withdraw),
+                BalanceAccessFailure::<Test>::get()
+            );
+            let balance = balances::Pallet::<Test>::free_balance(&origin);
+            assert_eq!(INITIAL_BALANCE, balance);
+
+            // only the first failure is kept:
+            adapter.withdraw(&account, "ETH", 1);
+            assert_eq!(
+                Some(BalanceAccessError::Withdraw),
+                BalanceAccessFailure::<Test>::take()
+            );
+
+            adapter.withdraw(&account, "ETH", 1);
+            assert_eq!(
+                Some(BalanceAccessError::Conversion),
+                BalanceAccessFailure::<Test>::get()
+            );
+        });
+    }
+
+    #[test]
+    fn failure() {
+        let adapter = BalancesAdapter::<Test>::new();
+        test_failure_with(&adapter);
+    }
+
+    #[test]
+    fn failure_boxed() {
+        let adapter = BoxedBalancesAdapter::from(BalancesAdapter::<Test>::new());
+        test_failure_with(&adapter);
+    }
+
     #[test]
     fn get_balance() {
         let adapter = BalancesAdapter::<Test>::new();
@@ -213,4 +330,30 @@ mod adapter {
         let adapter = BoxedBalancesAdapter::from(BalancesAdapter::<Test>::new());
         test_withdraw_with(&adapter);
     }
+
+    #[test]
+    /// native deposit below the existential deposit fails instead of crediting nothing
+    fn native_deposit_below_existential_deposit() {
+        new_test_ext().execute_with(|| {
+            ExistentialDeposit::set(10);
+            let fresh = sp_core::sr25519::Public::from_raw([7; 32]);
+
+            assert_eq!(
+                Err(balances::Error::<Test>::ExistentialDeposit.into()),
+                MockCurrencies::deposit(CurrencyId::Pont, &fresh, 5)
+            );
+            assert_eq!(0, balances::Pallet::<Test>::free_balance(&fresh));
+            assert_ok!(MockCurrencies::deposit(CurrencyId::Pont, &fresh, 10));
+            assert_eq!(10, balances::Pallet::<Test>::free_balance(&fresh));
+
+            // Move withdraw to the fresh account is recorded as a failure:
+            let adapter = BalancesAdapter::<Test>::new();
+            let other = to_move_addr(sp_core::sr25519::Public::from_raw([8; 32]));
+            adapter.withdraw(&other, "PONT", 5);
+            assert_eq!(
+                Some(BalanceAccessError::Deposit),
+                BalanceAccessFailure::<Test>::get()
+            );
+        });
+    }
 }
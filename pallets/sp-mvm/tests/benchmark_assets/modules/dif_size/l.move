@@ -16,5 +16,7 @@ module L {
     const BUFF_64KB_13: vector<u8> =x"14c0e63e52b19f5951d7ae970d231f6687b35f07569cc14da4c708753da03907732f3d072cc3e4c6210d57c3c3aed55cdb32232790a336a83fc80dc316aa28f28ba79ac75978dd6d0754b3319925c0be6e359c2c92cbdb8905dec93a1db046f36003f1b567441488278d90a27dccfed751f6b9f2f3db8be12f27ba5b6526d3501f8da5aee55340a6dfd2e001a31700a5c880ed782707d295b9f7e92c1c95ffc00c6ebbf4a6a67604aaa55fb25dc120f1f6cf60d622edd407d716da4d3dbeca21bef249620d28c3e875431114c944eda197c54680fb214f149733b5fa2bce097bc9a90bf2a1553920574ec9ea25256906caeafe05746e17ee57dc8905dbf9e1c043106f33ab612281c9eec272ad83c3e18546ff2a2af3d15a6c344cd239e2c30eddac22fe0e73bcc42c4f0f9e017f40fc717b945d9d49f70101ba3cb29d37a5959586c500512766af94e43afd2cb067822a00f5a757aede0d117670b63fae558c00c7a969ea9efb205fa1fd787696236f9d0c0c523922dd117b215c76a659fc565173ff053eb62324ac41b6770647eac12e0afb4577b79e5d5edcf4d5591d6c02277d36e6ca980a57ea0611412277b5be048215324b1985afb3cfd0da298714bdba8c04c164c9bbc2d1ca7b3ffeaac81a4bdfc713d0b01cd704249f6a1651d8660717e7a5f9004eb3ec279218a5220815791d5026fee0764413c61991105e1f720032aee85f90a36a7d9d638ab88d9874869daee0902d7834419f32f9a5eb15d33d2c9101f623917f82372004d9c6a89f12c27ec3778f00e043216eb241b4a047b07977bca57e0ca700de2012b206844de0c04eccd91781a8910c3a003e46fa8022987c775843cabdc1682213c3542d2a3fa705cdcb9dd260250caee0699c56fc348a2fba1a3d28055317d501cc7009c25472ab9d1c60b5ad5fbbb79237f4b78a45dd9ca9a3658d987cd39df50c8d9d807aca0237682b16537fbcc54c6de831d008e5506b41d9ad16b07ab42dc0076024a2881c5432b1010ef50d99df58fcdbc9e6cca903fb8545925b26fe304d037da81e0c13647e98b65cac93a0fe1a8dc8f0ace732979ed848fabfd74d53ea7664059988b40f0260e725da254c9d6f7297ee9bca10d87d5d1ea9775b50d3fb02bd20a3158e309b35b24a5d1440d922871a04fcedfe743bb7e272f95700c34ed837e6f5a926b70a61a9e65128194b8dd5e1b48d2b38cfadf3222a33f7487019d74ead3a9819bf53fc76709309e5a50855452a4a2c57ca49ea64d50df9a22121ede045cba1a44f2ea35f8ede7996da637161017e21f1c1a897fb4f132575c3cb83291b8d39e939c2962f977940cc895a014830c77ecd672f9c4e261db2be32c8cf16cf8ffa4e94ab4e2dd420774db94047f9ae6f5afd87cf12e8d92debd929787e98c42baf6725aaa3b6a704618082f2d53aa4a2674448adc0f83313731a407bf3164b3b9235ce36284a3408c3cfd5a38db76aaa12af336a2c9be292849c8fb6bfe0fa170065a965ea211cac225668a1b54b436ac7434b6be217bc12f4ccb2d516383dffa6a660556f7aa7e4cef9b433c83965847444e9445c254c60f9a91cb5467a4b282d0e43fe605467438f8dccbab85539ced9353d1e91b2d14aeb790cf3182236414e2479019f96e2a0a9ecdcb978ba799904bb622d4612b546fecf97c1a5310eccfc1ed19854875c5ff7989412d4b32c94511c6becfd66a5d228a5d849c0de32002741c568a3128aacc01313c78d8e3a5a24004eda46a173977c870e159c82aa5f2ff03c3b4d3f18027b95e1d20728878057898b8d12546d622bd600ffa784a9a7caf6cf9f457583cff87a8657d7d9615f4d405059d87be383d456e16416bea051e4cc964b4e588ed362870c2002aef60b8aaaa443ddb728aaf505441599914d0e618b88b98c2734fa970e7f2bb993b8729d7ba08ebb296dee06acedc6ea9def519ce41aa4d569bdc6b8d857ae28c9ee77167e32294a775a0e1203713b94e5658199ee5f14ade86899bd99b94f7faa7431985fc9ca981b423b9b8170f2d403fb009d1529b9ea82c1bc7a15f6371a8e2e09b0f58e46f217e3e20014f4b55ca63a62e52550a5e5c72e09c31147bc7f0d6a2adafa84c9365f71dcfbb961b252a99077f36bc4ab66211fd2adaa79aba7e0d513b56342c3dce4cb0f15e6b14c9532c6cc9fd2a22ec48904f7a88e50f8a1c214b3c314ea6ad23d547c053e97a986dc6021a01789c09ac0a6778fc4d353df404a7d8b5586aba79ec0476e7384f11852c1e53c7d1f4c4385ec3f616574ac252b8b88a0e913d09c813f0a0a03637fccd188fb94e2a21fd150b75089fae3060ae13f49ae56bf3ea78c4ed0fab6a72aba1dd8df284e242b1a2e9ed59ca4ce4f2306d9d84debcbdff453b9e3edf0e0e09763a850387565749024da5f92f095f6f178a09ebfef0801344e8914665f3308cea62b1c782ad00301f3b53037af86fd64a9f5e4a932992304645917f0d34d991e35813b5a88553c1649a54837341d0b7a57499c03f27108e3fb6c9173452440664c6148ef2aee5fad67ea5071865e27b6b2cf2c578a90394d97fe2f16b1dfe7d981718dbd5deeae15421f3ed20578268a800f1d713d1ec029310ee762b5095874e6e32c3a78f7755d67e72e207657d1dca12d2c851a597d6b1b842271a08641e04ab7555351d2978edf22ce1b3cced686b38c979ec8beb985c7f53e4ae0f6a3d04405969148fef6ef1fb20c35bb1bc2678ffd35d9828102b17a0463a62eb71840c3c8de2f3c1ee05173b123e3e8528108b1e1f89f9956f2a145f09520f56b18e1ef45fc7fdca01a1f3ecb4c8d3ed27611b5f62d6a10eecf403b55c859a5563605d42726c0afa9e42fab93e85eea389e010ac5636fb88b086f886f5aaead0d8b5612b5119071da3f7a635a3fa7e8a6a20e059018a1dd4938ea3d6e7fce75bc67afcb5ab947000de6445c13b50366d4392e0ee3049b90fa6858cebb7686632109d05aa17ca6cb71a9ad4930d722b64bb05800c9532a4a23b4ca05309831d3196210d86be0c12f7a7c146e9e3378405ce03c92ec33431a45337b1724b2c4defdab0c643848c05f8f3f7e7a27aaeac3d7ef59fb91ed6688ae586e33d78ff51fb1d0bcd876bdac61b220d4aaff3064be9c056696b146efdbb0840c9bedfc77022f845531cb103efd9b7666d138a663d476c6b11ea619db318acc9b90dac08b1667366520528be68448bc2654de42ac48968a4a2eb4a4dc8d6334f80787860200b41b8c83f3a5e0498be18ef49a56526d16abc8833e5e1238df75fb148e4657ed1f1e3842a05efff26181b30df696892d6d1e770ecd9f4506439c15e02b63ee9670689364cfbe08d4af2909b08503294b774d3f736ba62495ed1bebc2c5fd870514ef85f1e8dc9bfa9078788fc8f4d43f874fe019a93dc43999d1e37d257756825b36de40c8ee59704814e9a4dafa5b6e7069106e203586a46d2154fd32e272d0e95c711e60a7e4bfc80f37be2fe1c999ef6413d6a825ecdb95e233050b5de89c62be461622f211347bb44c7ece84828f6c961ebbb2997642170a8ff98cf5848ad9ed4cb8b5003c6090142eedb64ceeb1fccc73d40960295ece5fbaaad64bf122269f5a1e974f70048db1f088a16fd7f1d6341a55d95f9fd4929d469418091925fca10816d7977115b4c448ebf407e477533f03acd8228bf9f39d9606b6ddd1595a40fc01c43caed806a748da06596e1992a7884f061204b8ff9e1c94323003c470329dcbff14fe5db7b17bb2d8b2b8d52568001aef3983fd9d5c2488d0b9e14c947e6db4dcbb05ce64778f7a3632dea2696fabb5e2d08b8fd2768028158163b7a03afc8fe44e5d6be758937523d37ebc59bb10e661598afac4534ac3bbb8fc29c64f022c8a1ae8ef8b435edaabb37da4567923ebef5b15694a6546d4eb46f027a9b64b0a61ae3db390e06c3ea837dbd54a5ce5afba6347ed0ea90d3dc540119000754ef45f9d195e1483b055c58ad2c930afccbb58ee7629250e158f91ac58aa16523df0d3702e8ded36f04065dc443a7d0bdecea53fa6366f4c6a01163071a32809601f71860dd0e975e8546d07b75917b283206e13a19d5909702d92d67a1013601a8e9821a2d0eec5bb30b3862ccc7f425f7c4594a4bca19f864debecd9da7759c4e6c4db414a8dc763f44d5d4602c465d26c3519f4fff8e71f34a7f9a8cfb4919134c671dd631e99bc00c54e62f8744da6ef85a04ede60f77d95dc049f893128badb27472a2a389a74afb89272e4db3934ce3e3e359f58b11d3f736466c2db0f686a5f4c5529aee4de974711ac535ae78c4710fa0ef7832b8f0f0e460c705d00a28e043ade61ea83034a8a832886f687bcd2d27631a94f81167365c123fbfddb981b9043de4bbe63d8dd964fcad2516f9b8566e5cc8e9d355e02477b3c0776e5dab30bd6e185c4bc2b92d574041076faf176bdba5c7abc9c2630e66ba34b1007a7c9f1ba847a601a43017c87f892a1ad2f6d516549313482b7a8e3b5444bbfe8d274489b1eb753e3d8dad0827638667b0c79088027f8a5333a4656f1ef3cc710c9b614d909c1aedcffb8aacd63e0ab1a4c9cce6f6520cc5cb4ab64464572e805a90f6cdba9100293f2dabfc870ea2e6d35b3ff0d4219836cfeab75c10273312d7450bfe2886c6d8a0a7e982347c8cb4a517b2adfaeb899836c28d1fc1df663207f3c9559203faa6cb540ae3d4f4062722aef276038e515344eeebdba9c1d37e22ed5a50d499c34f95973fc19997311f2637664598f19aef78c156756fef782beaf71aeb58d2221686b146d609e3490e87e88369c451fc224d609e2da6ff0e27ee61864b08df3918703e81b0f7bee14019f4f1f4e31e2f718757384eb5861572683ff0be1b5d9ed53c37075889098225f966286751a5c5a106613f7ca428f8193c3ec3f15f8fa9b687c84dcb4ae82be373c748cad75f0c5666eb44011747547a155a1216aa6f98cbc6d1c1a2ccfd911a6747e5d64f0c4778d7cdc3b4f5bd834c485aefeefa2a1410409a38be513a4ad7226144f4c6ebb19f8de70c6cce2876cb20acac9817b7a7ad2155ad4b94fcbabbfd6bad1b9c3c3f3d664096694bff2bf0ec2660cb5243bb1d445440ddb3930f6a13235237edad3f341426236c25d8e5df87b5f5f26d260bfe85443220efe2c78717969b243ed3cc776c23e67d579462eda0a50bf585ef473bb659e3a043dc0cb60517ec65398e262b692eb38ea055604ed43a34ef7e1434ece54d825790dcc5177922153d9683eaf53142244767c5155f6f9f9f643dfbce407b83a57ce740e279aacf274bd11b99774e1592d4f501901589fc8369f08c4e1fe42705a560db8c7fdc76c5a1930e379499b2f21a8c410c6018f3ad084b41face9cf7002c6c06ee36c01b7f08d3d13d65a090d40d1cdc267439a7b455eb5a98edc72f92509c88a351dad0e14d202302e7600d323e93a2b29b278b0fa9f538d3e43d27cc43aee103520760004ace277f0e2c665190a21601556030fef52d4b5ef1850da56db1c5a491f38b61917f327ce41008cd23a173cd43df04293472581924cb91d170a52b1d0e26b8d6dfdd97c8801b635792a1c8708b15f60933de72bc4def347cea41a1df02dea7ffd65fe30b571ee0ca8878dace1900dfb16f5fddd9a748e04ee8c3bf1f7b8831a33f790ba85b48452534a39776f7facb7890bfe5bc6153c9fa10fd6d87edd103286f4ddecdcaead0238fed64f53450d8be02b7a0f83a35ab61db08c30195f11d251c4d929abc4aebcd9a2998a2da3d8ce7d192e94647cf8e89582658e8ae09921cea7f44a2610e406c392079d3f26c34e4d150e21dfd0acb2dff8a2178db17e05e999597410427cea3653435efbc3ff905f2fc4e7b6c696d1fc9318d70907722166b381dd98af9fbf9a16b04e6668eaf9c3b23624a5a55cf9f9eff926fc9a7aaf07f46d0545f961106ce668a7f4eef279f015f9e36cbe87c9c328099e99535b6a8a2a9b6da0b5c36c1b1ba16f64d018809a5304334160a6d92f707ad4a3518cc9d96c43693c0aa50a275cc89568424af44e86709deaa4a3bba43e7ae27d8180b63fb5c8a7c69405ac67ad382996b0ed0bdc6a7fd1461ddd614f1d3581ac51250bf97b5c375b8e0d7a1f9e1af20e013204423041579943c5144f8850001aebb6c068e72d8bfbc972c37ff3842c25c5f8ae1df16f63c9096dcf55d7a5cee4807c86ea96d0ec31860779da30ba2a7bec941d09f44798bf21d64b65a0276f23aeeeaab1aafb0af77f4cc1ee5abb38e151f754e33ddb26de10a82a188ac0367070004c0e6b6eaa24d46e41a1c982311016639bff684813a50f71e6afa777f55f0a92d524b6c0b6d6332b21984617e8a0454bb389a6cf7b6658a4ebe89d9d2cc82a412709174bbe20342ef5852ad474bae15e13883bd96a799f660d1d0c86d6e9d5b1d7200ae982dbd67eaaae2c7ff905b55fae105d229d86f004568e866a1f5e20bf328278db3c01c8be2d6e2734fa03489890d1735ff1aa7a83c436da689f8a56285e5e6af680908d7408b6f2627ee30bb92db73f45a77e498967625c740547ea2299d129911733fce04d55b9126b67d320a8545912623cd8b8b66d2105a8eb1cb317cea921e1a2422aadd8766c58fb8f36be44dd0513c132d9936728f737bfbbdbe83822db5e62a25569224dca2581243e45384b2f55c5df9dcf906197297c563acdcf322854d9114898ff3c1336277f840299c641db69b20495e930670b3dbc9a0c3df3bff03a9a3dc06a27b27e6566a68ac3686d08d66f939914364455feb826d4ce5d7befa49ca52761966f19063a80901f52010d1034328e184ab28f718cbaca96f854d6264aa3d6e0e18193faeb47bb31ca08f2688b7d3c1fbb82e3fc424067c3db2cb75eb3a43118337e07eab5fdffcc73eff0697a5bd338328923599c5cdce722e90bc10573517999ca52f82955a6f6f0da28360fe903b4fbf1fae08bc595f73031ce25c557dc7b1376aac845cdd90270e7fc0718fdda473845d8139eff4f7b4bf564ba8965c8c38f651e156ad797bfb2be753773c9497640446601df971d84b15d56920087addcc37893f2e39cf1c630c0cf3c95426859e8a7d473cc4de97cc6f5d41867ad0dd46896580ba23441e57ad7583e9afab56889ea8b78db91bccafbb8159108a77bd103e7ab28dc6d51c3b1f46e372f14aab8ca8d456db59909d5982b02555cfaadcf9772c60d593408d69c59c56e75e4348a22a5c770f480f6008925332dc5ad3fefd1717fee32c1e1f2d671b6cc25e94e3d2a7d834b44c68df0359b2280b6c317bee6a4cb18574bb3e172692af2649a0065661da67797ce4b8e00336eb9db63f33ddf652787b9dbff77d5704bb7d2369baf22aa7730216a31eb73c1e9ecfc200a5ec2d2d0ad046108bbe201c1e8ac2936ec252f6870a9215a833526780079e9ffe090ef7984b37e023392ad3e1d6c4632942fe88149e7af56900815062ff9edbcb33b9cd1347ed948026fc66961d023bc1c57024d10625fbe5ce8c604f723c7978d18f537c86acd2f8de4394f061b2eabb056301a1617eb82e673e3c3deb4be2cfcbc2013a3ee542a8808e9f6eb1d034c888d7845ffdeccaecc3ca63d72297f8d52d2cd23f6325a8da9d493f5187474b93d23872d834af17364850ef5246a9e233e861bfb8d34f5b614d6e690dcfbedb69c4b821340c41994df56eae72f10bbd63b2cfe71aee614cb56b26bc3ab2a642ac89cdc1297d52ad78905fc3270d3f00c01ec032abdd93c8b562518495df99a4373ab3975204cf0104c11fcf3b53e3d0867db53b97fbaf9e00ea9e062942d252567a9003efce2f9e6edf2d43fc3a72a6bb88ad7982b5ba85627ebc0e08a2993f685cd6fe5b8920038a51dda4bf6c64c4a634d805377a69e5dd6e279afdad4f7571bce42545e996c184ecc606f36e0435a924198bcdd709af9d4c2215c922b77441242ecb5a983bd5fb1d0a10e7c586b6c61d4ac2ea388124d19ae36ccdd2643296fa820ff6363bae1278ac15ef315e8b71718db6c78645038e6a2203d916c601258967f36eb32d8332ca52ae173c3e8ef1592dc5a243b9a9f7f5bf55ddc20d7e21aceb40cb0e3ade9a9bba18b75a53dc90421cabba857c07a3e0b2676dadf560fb9e20da190facc999ccfbaa52d74662e0691cedca391e6f650f07e7e3686b87d82d66b732d910277adf8b9b5a06434a0c7263a6a07335cc738ee2c0c58ace75eaef1ad940bacfdc349c9b70b1f51757a27ca7d06e710b0983a368bff128b52637fbcc62a40202bd0b6af894f6caad8b5ee591c48d51513995f0812623fdfb7af6cf4642ca1dad8cf5beaa2228649a86f4fb9317635e77e9f045efbf9f3789c74ab753224c938dccf7689c81820be86bee766f04b9cf6b125d4d930d5b0322b964232c553c420d8f9ba803c4c971bb0f634c8f04adc3e8530e70b460fdc77255c252ccb54783c19b1672ddb254b5d993040b1f065c659817ebc7a822fdd0d04bc4897d7769b4c086da86bb51e5081f4ffaeb1f0c3455083fbf60100bf012270eff599cee5004adba4083eb0b37d102cb2cb0b207542373a0b85648d3f65ba0793f65a7074a20e6f1ce0a3276eacf4a08927c3a1130b97b6061e6597d2587e89103ada6fb8c3d56e40f9d019fbc2e814d3ce2d1e56c757acd96f4fdeb00765758331075db28144d00dc78ded213491de767d17b22f404c4a859811c4c75ab1418532f36b704f943783e47708609a5368d0d2819230519502517b8f919934be4c722dc04ca88eddf52e8c0e51616801660b87ad3e3eb681e59ed36e13a49abaacd7ea2d65d1b80d32530bbadbe355bdeb37d1f409e74829352750a64e79e47a3884fd097c2e58fdfab08b391d23c24e3e9cfdf30eb6d65cb2111bccb50edcc73e0516f41cc63f57aeb28e7617f8b86589b7491259bb91f49c2a60a48e887daf44ec335e0fa31a54a56671b79929267c4aef4ef930f85344d9ee1adf688980864537d1e8a015603c9cf991ceb8c9f2086a0c7eefa0a0eaf8da135bd5cf896e0092f5cc5a8bdde8c2548557ca8303482c2f4e0c64117c44614ebffd0462399bae699a9cdfb88093c381da5273f63cb338643c7d91741c914814e71797e7d385cbccf4089ea81849c45f21fde2db67d57237873ac1ce5ff4f2f50cd483e90b52fdcca7114c6db41baac365eb9717b701f47e8e876ff436596384a0b1a5f916a163706a4457738b53b0517d590a51e730f21d8c9bd910cbcabf0247f1609802050e44af2f592b3b1b710afda8ece60d4ae7aafd462f2ea34e8314ae53e8cae48c6fed9d3ac5e7387c76d0962daa5e8e29b4c28bc4bd63a7c6f3d3004d8973191806b610a29f9e2c335c3000fae443bb0079196c625f38dcbac93bfaeb252ea30098979899c67b5c141321923cfb9d038925e21c7800677c96abacc811c49b69bd8f2eb4c82fea87b09fa130222a74df49f14ea502852f51cb4d6252e44a1fb629a2dd66d86565d1bc044b290ad3b8ab1585394686a3a6e9b6e5393c1f7fd6484a7b9e2f73cd414ad3632adb0df572949410dc211c5dd9ac397cbb7543b5710d6e64d4495bd4001b3a6cc377b8392c8de2281ea9ac0ab3fe9d56eba10e4237a965e2ba437f99295aa6bb18c68ed9e5b0351a3b046c9801fa3266fa686cbd7cafc33820b22f815b8e58c0a8e4a4d9c012ba34652bf223160a6371d68b390ec1763c85555581ebb6d28887bbfcffbf2943da0d1212098c8d5bf6cba2b8df74e9d444bc45b45db76ae2df564708ac0f936e6f52b70e096c39b91b61a043c62d8eaa8bc9765f44757b78db2f4c29133b6a096721d04707dbacc497561202a5ef10988af0486688d2ef5bfd221448a84adeece524633051939b7d96e07b31c000037ff0ead05458e3117e1b30be94fdaaac1a7868c947b2c03ac0db19fe9d239b39ef5a2319ac733ca2d6d4f0eb51bd58894a0c98a666277539d9ece8ebe7e616568d83fd0f8fdfbf4c1a7326feb17e8bd6b63f14826bfcd4b6272302bc1c6b8af522197e534a0eb23b49a92108124dcbce0773f7f26ac4b8a8582b064c45ce336c15e77caaef0aea4519bc38aa9a9ea590511908cd3b9b15bcd6fcceb7ddaa97ff2fdb36575afde5c07281584ae6c6669f85b580586083ea4c2b408a679276c8355379031452a3b459c8bda1cf2e2a84c6351847c10b82db0a55d1ab365b82c56701d448faf900eba14d19e4a3a37029605aab7a5c1df79ae119e53c6629be31f804840e971ee8ebf0741197ca26613bd5fcb16166e37f7baf4560f404d726b9da69ad1f041ad15d6fa759235d9fbdadea67aaf0c5a633ed78562bc77df2bd482cb7a4ea8e73b78f3c7fbc1bf9e02421190794f28ffefa1b5a1f007162328679087b61b03f974a307cb3c0164bbbaf5904043e2905984fc3c62e0953f25517cd6e0df00984cf946253d62851d6780ca7f6baff889919a1e22c3c15cd2caab13a303213160c5b2bcb153bd0f737a8ada23dd5d37f90045f6ac530697e95cb01e03e14a8ec3ed58b808913f3769c598696082ddc2d5c43e170fe548f95acb5ba535d80b6c8f68b4c4b50230b78c9cf821fdc206bdf8ebf38b379084177f94b177dca82fdba7e952a1dae2f1b28dfbba472242e3ed81a747c6c8605ac2a6ff0d91afe9565ff652a8023636c3f7e400d5484bc9d5db5c9623d99e647bf09ae14eb9e4073c53e0d58cefc8ee4b3ef71fbad839836c8c85f117209513e8084280ce00502463dbbcae35089dff9c894a36133b9cbe40038c40dae9fab2c2079f49bffdff5b6a9eddcd4c48c53cddcbbadb5c252972957eaf615a30056aceeb9248f64f847b6dab84fcae37dc11558dc748f3fb786b7b3c3d712bd58b02cc2a824440c149baf14c238c68f58470830d2caedc938f97375ded0dfd56553b69402c49a0ba5fab9ab076ea4468bfcf5ef2c174d3deb06f704960fa2cbf116e0a2c4ed91783c066ee8653031c951f2d0060c0b3ba70bebccf9f2142485732e92198cd5e852539fc9dbdb753348c47700f2aaa28cd4d6c487fadbee2635a2b535c05851d726807ccf49cbfa85698f5ca729a36c8f7bdfaa4523eb156a47b37909de3cce85b8a0e7d0825e15e7e889d58703052db4d053d5d69521e1b346c099a2091c093bb89c10cd387cafe37d7152926264e4ae0c12cd10c5174f9960ce54190bc1216752de5c9d3b81285770296a205adccf7efe9db7358a54643cb4c7375646912b8e599d000286672fe2105793047e92186a9560815011ca30eadf657a44bad223085f6860376a669db64bd1c3870dadb55ab9a0ea673c098ce0813facc40c0a79b700008b2667efb34865420b700fd481969b1bc60db0466958b094257fffd67a2622cdbb6279d446be252beec191963daff553bb8bc1cfda5dfceba5d35d3267a945f5d794ca20496a188365f6d79b312523043349c30f9c43bd463b14030e040cbbf2fe37f1134da77367360f3ae9b46d858395a14c72f8b3abe23b74fad5e18e5404e7ae993c34526d9d821e3f56d9f0d3742660b72b5b193a0e4d6f290a11102b6f45569ea5e167648356b03e8b907d1104fc1929c12fcfc88b6949ccca4107acad56a8e2f245930ce8ef5283e6e14fa8c48edeff1aa04f4b6103e91aaeab9716c91dd2ce616172f3ba0145203e638b2fac86c69addc708f5469cc4a197eb7110cf3a03c4d4b5ead5e3a1babedeae4b5b09a69571411c4f337818309bb2e6ab416d2120e3d5efd7210433ca0caf69a1cfd206b0cfc06bd11d136bff00b47a93fe5743412ea1d8171a48c6e0d60375d5e0e040c3facdf9a9e55ad31b8fa1ee4f955eb3493283b191bdc184eea994ea4b37ac51325765de09e65f157ad62f7e42b1126ab800d1f221388276f4d3f538912c2f7a489a9a2c0d6df9604aa15d3932c5e15079201b8ecd58d0ba5f137a17ecb155472922cfb7c0cc2d982484e4ede52ef791520dfe81ad6c42ab409fac59793e17c139ae2af43926dbbb05c8f75b58e190772cc9118dff0cf305fc277c4668391be5bbc4128480e2b50224ee97d093929fd07c00bc33c8f2495cfbf7ff0fc66eeeffe0cb8cb85dc06c942a6944cd15282829a5533fb1de71987711cdf244d582698a53ade46ebedf5a858a4522c085f076edeac2befdab3aaf3275a194ede26a81c648be314d8923f4834dfde2d770b6a8f3fb9f535f893f1d91434dacb84245a69a23168854e7a62e45358dfcb6b25d04f4ca10edb9ade052b2cc30cb5ef8aca09b94e716dc3acaf73cad8811107a24859e9936809496587f9573281282ca0af0bee2df2504c1ccc6a1007edc259994598bfc8d0be263357b4e682f79bfc826a76860a5d2d3074092df4f72e462c339746c33c483da80240fef76f8dfcde2fe7376057ccadd7638d1d5b171042fb18ef24591ed692696706c690eb15e74fa7ef0bd22652fc402e9809389d7321ab7e4a572cc546a00f2998fded77479b4fa4b3089dac1eb5aa18a0bd1ce49a0a102e259c35d07da74e906917dd7551378187e203a4c469512fbee7b59751c0627663ef5f4ddea7d410804c7822bac07870b3012474e038357509ae1d82f613c71be3950d03cd8c6008c5ef885751cd77fb085935a8fb764c1336e5d3428a618742c9b49b84713afadf05c3b600ddb9a83f0454aaf0d457822c3d27362ddc0956616199daf48ba753d26f6b0f06ace91ff97f0ef901b6e0d55e010d5a72de7276f5b17ee4bf1326feed3209d07eed87dcc729ba175ef65fe35b87f3ad517bd45017edeed1b464680f64ebdd0162c42b7817bd9cbda19231f90238d81bafe93c53ab9d3aac707961f99bc16ea13280458e34fcc48af63ad8e3610ccdcd3f66b6f6b63379943388123366ff893946a004b7ae9d88870a73bffacf32cd4df240367ec2822e27d710c9bd446063d93ab600271ffd599e5f6a72f74c37c5eb65a8389ca80e088c57efb221239ea44e13212fd61113940f997fcb1dd981e88631fb7ccc8fe572990ad3d73153a7aca898206e78d3363ce08cb17345a764ac1ba65b09e4ae297e0a3bd1c410275d4589723f9b7c5bb67ea525babf9f010b754ba1f3ee38dd86edd3ee52926b9828a9bc086cb8ffc3b66df1a3c48cb9d0826a3e65dd73121f70f59a7f5c6f76985b9c5c826b04c61d9c4bf31e92483c49173dc2c55448f293064e9202d227404dcee65cf467fed2c840fd52929fecd69f3bdb63c07708a8dee7a4714e4a60014e2afd7fd17460c46d1e22d66ec4462992b5674de1a74d7d219034afbab523d1379058dbcd0f2ed824f23c9846342ab0b28c7c2c885916adcc0a67ddd850eeb5e9665fcfb528fdcbbad91f22b3cd965bc3bcb432154f59e5ce6ea5951223289c430395786cc5586f788818699c984b57ac3a48f8b6b5491ebb31936be197289f34e03748be4733bd724c9864c38309ef21a7fe36a4f3cb9ffc9c6c052e0b7c35e38e32dc9827b7b5db4c1320f2faa095646d713648ae8cdc5e4000d936ba2df7a992d4f4c74d8b447036d66d0bc5c822e90ec639eff87136c98d0cc044360e96d4e36ddbe2d9468efa736dd18aa155fbc109335948b898d11b55f716ea33fb8367cf4bb872bf98b181549b8c8da81bc72b6d4cdb6829bdb3845417896f3a9891f465d634c223656fe02115f7ebfc9d999d9f27ee631421a44be1611ba85e5860d53ae451785eb7298334032a537d4fc0183da64bba782c4b7c1ba61112a6a6c5f8cdee8d47f24834d5c0b80b813debc247a9f5cb270b20d81a77244e24ab1641b77574c943344caecd6fc0662e75f04af5cccbd74f2b39cafbd8689b82c649ec5c92abcfa193a19bd994d8109d02648fbd9d41d97c4b9174035b831f4f6e5d02c4b5c30f8353953ae9e41255899be72c19b81ba607f2b28afe59f3f34d6953404567f52ca67fa8778470922bfad0d4679b46ece5af8e1b43808ada0ef9fe1e347987f6d9f0617cc076974cbd00148e75a59d74ece5ad5b0132850080ddbe776945bb73fd43395aed49d2d5d478bcbc0d8e5ae7e0013db7c69644951e8fb69dcf61ab65cbd2df1dc1e9949168183d0474aef07aef2a518065ac6bb8f30131aa86e121892652d276a082498a510c65c1a12a0e241245d50b71ebb3cbb1b5bdf2b255d3b99507da7bb7961c2db55a6166dd836c1bd824d3e378cf0bc6284b00e0fecebc13d372011b88c2453bfc2c4f4a094ef4bacae855a0cbb3351f5c6c0789d4d7fee8186c40542dda22ac42ced0e2fa3a12ab85a895caf58f42199faf1623997c6e1f195400764c163b0f60ae15a1fb25cfc6106930d4e2c1a4ec5536006f89789901650c6409377170ca2d6a13793edda0fa1ed8b32086a2c57f8f6695b323095bfc4f29795163f199da682a20ca3c70cf4dea916a1d647074abcc06a5cce9d706baad7eb32b6ed571d34f9cf7ef80fe134e415a05aaa6e2d4ca2312ffac5317a324ec623dbda406ae420d5a9d008644349162f11dcf5e23c45ec910132235eea6165c3a3d5ebf30e6cff4044a4a65bd34d7035400c70f1fa4b5d3223c2eec7d829705f2e8bcdc7ba510a77432a3eff6f4be7775996df7bfb44a9d0afdd7768e5ee3ad41bfbf3794b4c41059dee523116e53426cb7bce1c31f34959545eb2f5ed55eb854de27236ba0367c73ac67b2485b46cb23d280f4e302a0c05147dec12adef65a84f2becb3c901d632f41a2b80ec065535562e52376831bd2cc6349dce2af8fd27f01dde22447367bc201f9d1d83d864b5ba4a392e8348b86e28a6707b4ce6aa3a59f68f553f045baf9940adae117d5e9874ed1e3c373baed8ae72701694b1677e048b3f6a8c58b4acff6d8251a4c906376aa8db2f71beb12643d4286550f3ced2f2cceba202274249c935721e9fa7ac93cc8d27b82954bf0eda2c1a5e903f01fbe9a3ddd5a413746e093828cee25d01c0b7848a4ff83a68e27dc751f2a210c0943faf361cce2d471d5f32e5d6ecbad54e4d9a9d752b0e9244a86cb2742eff5b1c27cd2f9ee497b626b47a7b227acbb5fb076c1147428cd4c54ad57858585e1d7149b90a5b7741a78f4e9ec876f53410b7c26fd3f939050bf56a19e42e5012b54dbaa7961708ca1fd8b46f4e10d537ce4d725c6f9ff9c51fd8f49e98b17378732f9d5e459529e4945def341ece9fbeaa6982ea0e36b2783202d441ac98a410b45b032d7c628a2507268daa04fef14aa6eb248891ffa2043a879918ca5a179ff93791894a989f9152409ff4eb2bd3b4706c6fa2b6792222d0b707e66209fced2c260cfa42969e6ac784dc2e7a63400ca57bf667e9164f15940e30f86617b77d00b8690b74cb93a2be5886456fc1297a1d1e4ca7866ec6592133f5e8e57e74242dbeeac7c5ba4790dfb21d52aa68088a413eaf7f91ed2dee657b13c9c5d3e9aea8e7ffff859ac121566cd7356bf3fc8d2a388ee54d7d939ae5bb286dde562b6ceef513abcdd52f12bbeb0852406cf43abe41ffa1a96caba517454caa347452cb2337a8be3f654158f4d1d516402399c6673f0fc1d3d7d033915f3e8cd50bcf15137859e8a41687e1589b6c2794190494e43bff0e56072f5b79f220789121ec9ed3dabe86c2e0a00dc707639f442eb05fd7d0526e54f2a52a9437bb5d53586f9c6de24567ac79efa958713290c9fbc4cdca267ac9e8ada79cd3746d6894455080af7668558d28bfdd9f71efbdf0a640168062fa9f4f2cb21dbe28d4b089dccb8e2e615038de6066e42e228e4f7bcdfda556e992444c10931e083b62ed6daa11d188fb9764051243bb9f86c506a540f6557455478ce5cb0bc3a930781737bb39dadcf19aa85d61b0766988a7a8f392e79fcafca760031324be83e5da62b856661cd71fb0105a801fb6f9058eb8bce6dce9ee7c9d01b83387aeba6b374c1d92fe81fd3839f4a611f5083ca324246501e5176118dbde8404c11eb7aa1f379db8aa726b1d83e78382a17a59f31b5868a5e724a198e3e802482b4082f195aefa1ab357d51712f8905e609463bc963f537b3f75fedd1a5bfcc55a9d01a5a02abef54f6bcd173f0d7fd7c1b443abcb20946aa4ad2b5c28902884c076f3e92f883b339a91f35fb3dd8748957d662c6804b1a3b68e0d5beb17012903739ba475f4322e660d2825e326094d8b7aabb14781bb12fb722c3fe836a9a9f716aa75cb0f54cb130274ff24b3445e5dd1b70edf77a1b1d174cf7b045b61f51f9a72880bd7521bcf1f540d9f78ffb7f1166698eac60113e9983c13e022faeddabb35ff167c3721d14ef3f3de0914ee96671942fc5229a3f029b3d6cfbc7da72204748469e7467ae14ba8d058850a438c7628154d6f8af0458e9e53d88911e9f79c576f27c0118f4eb3b68e626472dcbb4ec3f129cab300130c9f1bd364330115b36856d4961d351e9fe8420609944927403b1612b4f767526ea096f90256817a7963537f4832cb7e2fa507167b2a6ff7115f35bd7c6d6bcd9aa293e83d5f0900587d42dac0785ea64fc3380c898891d225e21367eede9b6ac25f79b8576363ff66a497216eeaba5f198c99320704cf97a5c6eb086ba28d3c1cb5d593c02fa139c20aa5f78c917649dfde46971369c08895c5d5bdc42efe8d52a1affdf4b1548991c9376a58f0157d30e14a10d708fe148a4c1d9095c62455b6beff482b6bf7acb6fcacfed21d211729c588720ad8511737d5e3f5379e7d9522c1426b8b821d1287df913a70028d69ec5b75a87b0445624649624276ab6155bef0417a3261846fb4dbfe9c0d1ca5e4cc3fe4cccfba6730eb402502409b4405b75c65260f011d0e43fdfc9af9309459cffb62f9e4f4c180982075115f2bdc8bd4fd34dc891daaa519c0e3c473f44c0840abcedb6b68cbaf087da69c39d5165be1e53b96d0105b044173e4f15ef88312b65b3bd66c95b838e7b0169a1e6cd3818f4c127b019cb775f109284db661c953843e490d09dedcca2631dc257e44a156dc44184e556a286eada0c28e0dc996b5ac2d27dba9e7e7b902ee3a02f460a419cc80a52a6b125e487ba54e0659a1ba48a028bf69f7339e00f75008cb10228448c38318e3f453615b5d53efbb4f717006ab50a8912a423aa165847aad4027b28d18c6f6019597b186afb3c4d8793961c83caa0e78dc91169859dc8aa4c87eae0ccafcb7c0ed33ab105b30f04dfe29f5d42bf9f5da6615eb8839ed0b70530ebddc6d9376c5c732b5b47f8a456f94de95cdbe8908acc22ab29302b8620f72f49108ce723dc73a62e6ca48939e464916360ed6cdfc55b8a332589d267ad68d3c3ee7c24658be6c0dc80c412041ee41d51942501662a28c4dbea0759e5a32a8e74f771502906cbc4d2b29353c605b443d27e8887372bc39d0af49e9354ea5690c431b7d7340673185fc2b855983fac73bb7058c26ac7003a758f92f706f06bce9d380a815f3a301a4a0fd5d30a86337442205891e8817a5b70e1c02b91c5f2e3ede2bd847f6e786bd06cdbfd36787a5cf2639122c073a57cc9a709ee3e57b852ac8928d1fdbc56fc43c91f22a0241c3702dbc42c7e6c5132f90d98cc6356315e42f7da72a7bff1c381d33c5c1a967c203f02a15b305a8ddaad7f9889079818fa0255323a8a9d1f682f1684076d789bdc6f4342c51dba453c4cc59542a02c57c30e43c814d04261ef0f33f32a97e5515ca61a2ee06f195e1c4813ab869808b3c3463748b703be516aa5f4259e8a8b7b4118d32225fc6a2a1d9011326a80a41a172fc7691a3f2751722dcf6366d56f674bbbbbb0b29c81319961679028e58519610bcd565d276bf6f1a7e2e99d5a136463caec0cfb73c4684a749d9dcf70b6a32ed93b39eafa8974a38a468d08cb3c0ba6ade1c3e1094f4cd1846862c835842456387c1b2736394866703e9036b301b6a32347c0036f18538be59c8ce8bf85db5008defd373756b4b082869d5c9b3130266b24ad087a7b3ff14497daf1fdd7a70c398a22c094e39d42b16b3f5ed2dea6e10592276e7a0052e6fa816fb6d144c45300f31eb20108a3a28ce25b80fe2b0166652b7fbac61df42a910c09ff1f7c61ee6f31b00addcf8f36eedcdc8e15d2c1c84c5d7842c2327883b2df8fef6a24577be5d586d8fe78c3ca152b77b6d0d757a80e12c5e88d98e02a824635ad3fb71fd8c78ca1d1bcb3ac76a04e4cf48eac776a44ce924ba0bdad6d71bcbbc633194478d3b79ee4c34ec4f539fba8d7f8acdd1881f793ac34e5a4da9b3f7b38ff0a48d6683674c3a025b8f9a531a3bef3c88046fe690c54faf67f1c27da17111b0d0d021a5d74aede97bf5bb000996be11dda9e3860bcd82b04889688c6a9b62b5ca093decbb209805f7e7f1379a7b92454460c0e63947704fc361db58fca0217426ef7213e799861936c3e2263024e3ca9f7bf6dd5092d32d4d0cf739be1cefa3a2a4d2bafece05d1cdbb64c1aec3b9a07ee6c6360d8c6b5f53b402da19aee94b7d4397ec4ebce2f9eb732014a829bca344a56425211e372c7b4d31cbcc89721a49ff792a8a80b41e5cd4eb047569ad169a307fd23bd25629c55b31f8466cc4261edb8ad696a9e8d4fe77ba8b1670ddd3ff9158e058ba2bb0e6207caace886d7567a77e562842ebc18144bd9bbd93d34dd9d1ca9128958ab525bd6985c94d1dd737c094d28a2eaecb6980de1a1a21cb3d7460217d780d9c11c590270a347210a98990cf99a47f8a4b8951110966e21e541edd20884a67c5868c75dfce091e20c3284f07d355ddc6613e1265d1bf36f116963717f893f02f3bab77f8b1f9e778d05218a7f03f83993c82cfcb0c76269f49acd43259fba0bad2e3bff47a5c741760fc6e0f04bf2250fc12345c6ebfa1e4623cdc5f321d1583743ca7db6da0dbd41ec82896494b2436723a7224a9b8f9ea365b770d3e5271ab815a226fcbf8297019b5a8a350c37e1ffc144f461691f4bae54548582db194aa53ffb32f4e1ce908df515c757381445871f3cc5ad66c2c39836bd6d0c051a148ffea8b2da430fed488c01a59e9cde3888657126695ddd98a0fc8ac47142359ed94c99c32ddca39f6e76a12d5924fb7c0df9bad1c6e3ac315de695399c210bf261f2e670a809c1faa1924cf9215146020691a0468bc17e2b7d2002a0c6fa66955be5838767d82647cc920233216606a4511878c4266b5d579b52a7e620a7da76ad71d8f867c21ed56f4810c7b4aa018f584cdaf1fde7fc5fc6b117cc65831f6b3d161c6a52a6afc847bd91da7fa1e2568951b446bcf2dd65e135de3edaccec3b80ad5c9738e362f675651f55b6d26a1b953507aa34b59367db6bbcead3be0a21ead3ec8cbad307ecc0a1d64afbac6cec011b7e14af06c552b3cf21b694cd6e16d189f86bc7e0e4683f6d1a24204cf499b7574c04c4728a33fb978e0502adb535f429cea981fa2eafdaae01099e87f1fd8152d6e7a19984a2f55990c5e2e37bd22970117b8a1e6c40260b04d99c4e5866a517c2cef3a8644f23448d27a3adaeb66232983701811661aefeefeef106b48f61b2032fc5b531af9576e59dd9190ac27c2a7be41ea0a3bf0c8f36472a6c44b0f1104a4152afaadac53b4848e761c04f66cede2e10576b7048113823a808c97e3e9e4a14e194f5b6d1ece48e9cf0baaa3089b83f923ee3b9f6a0ab15a7cb5fddfc9d699042697c973e2b8749443fcbb331043dc49fa66303c9877392de98cfcd5573b38c6ffc838cca7a6f75329afcf204a15516b9ff68b28ef08021066d8ef5e6fb8036ef254ac17a1ad2e680a6ed157ab33f53653ee70fe19c10fcf34e13e7d3a0a8a36f25cd1f621579f1f0678731af30530519362f24e461b4c5f9b4cfecd5be1362811bdf5f523c25d923c9b707478c6426434ffa6832630405bce9ca3617ae85ecd7717375c48c3ffe88c4ce8f433e93195f85ff20853518e2cd5e60ba8a302cfe3f60e89897c1cd002f8e56f0b08073f934f2163dfd5269c02c990b8a350483fa04f8c1a4979cd964b6cf172314f7ac751f582a21eef5b21642a1e1f24d56e2a23fb3835b9a95d375ed14ff99460f65e6366f72412bde34e040e86addf4036d64a2291e0789a4371824fbd2f565aab3e95a1a2c767dca53513b821cada1825187f80340899469f981fdba119bacaacb04420219dda11dccb4b8fb767613d9fcc9b9f7af8380eabafc350731e4435300f97709e9ffc5fe68daa7c4ad84d4078dd422fb6e9cfb43c38e72fb327325d3916e487f6cfa5cee39f86f338ea928fefe6d3db86e0ed352ea3ef771a5ccc6ef07fd119b629b4f7cfd1027625a7950c53d18132b42dabcada772baf0376d1e316dc6d1caf1b156c96eff80e5610f876e8e1059e0ce530a687c6ab9ad8d47836d595f4b495d1fff509d460df2e8efe0eba7f4e15c3d8f1d5eb1dabf65049829b9317d533470c77dc0fad776724b603a1ad1ed829eb6de849603f3d683987d7fccce943254752f731e2ec4a70e872ac07db9d053333f3b2147481c3d7525ce5c539729c382be6d9ab1b7200744233bddb173eacd1f475ddd687e33ea5e71f7973a02fc342ab03ab46327c005143e1510af9399c1288ce0516f632c07139bb8464e8c0747ad35d440c41d50d8162bd60637f99e02ed8bd660d9a394a0619e9ba7842154124313f2fb06cca91aa6d541506feae16006c7f6f45aa2af6569ed2167b09ee704b3d2c76469c84342fcca201dfb9760cf6c27ef59459d165c6de21f165e0efd21a858303a8961c9b9aab0dbf5d0eecdd09e1fd1a9b34dc5278c60c73f5fe11ed8394b2223fdb8d80a23d0595bef5ac1cedd2caa80b5ffc1c3a4773a9781cef4812a34e5cbc034db345b18372bf8ca948c8860a6eaa423eecb6a075f67e1f3ed330a2a93f1856ad67b41283dab1e394dd3a0ae0a5b97c3f52f68289a47b967d278f7315c631d1b1e2f0c7e32d508f144dc919c042631194062a5cff2021420f3d253f0d67cace8a577d9c9e56fa77eef9a6f12cbdd6585b798054fa47676531496a372d9969f7b935fe46174aa5d4931a2cd1b73950f067a9a28b271715a6dc7f7e6ab25eb26153c0687fce1ccfd63eef37c4edb0d47f337191cad4ead95aebf401f7a4e48e0f60a36c1ad88c642eddf759bfb4e19c143492596a8d23b95298f3091d6dce32fa8e2ef5269a93fbac3d746cac8b98745d80b8ae66329b5d499eb1d190bc04ffa2d1fa1bc9b82ab62762ffe5d3f2cd5167b2d9d330c3843b2ae085f9da3a5036a55c05063348b6874458de31db762a96f39e0813c9924e794f8962765ad2c3e53004240c5d4a4c0a03ff27746bbb058a0d93e8e6171ccf520962e1db1970ac75a0eddca2d3976451937cb6eb07cc10d3e912e796ec8b7f1ff32851f141bb457daf964759259967faa8dcf51c7f5684a550850bb88005802930c1b7fc85f3a09318b06f6574b2cb0da395050df2fdaea8dd4a7d80f4fbbcf7f4050c95d762460047837c6ffcec1c5f738bc9168c009119b2e30c2a7acd21c2026fbbc429a3eecbb99545a4ecdb666b3c7edb4a02fc3b30d7daffafa41691dfcd3f120c217d391c0f9bfb7d5f517a2e41b39b33c26b0237949da5d4007651443219b9a953674116954758986a33f4de6d0695f5efd12aeabbadc51f5211311ff35d1af7e1f37a0e6e705de2fc7b6895c065404fd4ae351f452ea59cdbcc099d59377ccf7dc9e96abd80b8b208e35bed13b7f7d796c625ac1ff5a9f4a77e287c4a85f487378cf3bb198abff91bc986a2e403088cb73956bd3980e756af7d6f6556a5c2eb29d8bff51427ec35d940f6fe259485f8e4124947daeab3f8de9005172b07c8deca08ca4c0eb3af37380944de4429cd4586b1e9fb0374071771f53f7c2eb91a854b71a24e4fd0877de9681e66f018ac76536aac92063057bf3304f9e2c3c757e93652e9161dd5382eea3badee126a5e303e8f9c6f5b4c5261acea5b0fba0293b4a006a21f03d5323b38d30769375d815aa1cc10d8b0c45cc370234fe8a303af92eb055cb6d8d3c114b0553b76c83880fb71d97a9597313863d6c000e55ce2a37e61642f43490885f15e94782d54f2c366aca9d8f08136909b0e07239503fe61bebc22742560050c18c6ada014c35c7b1e62895d89fb977cb05601954c5e653bbcddbc232db769da355f291c1c9a7640163510606042945e27643626a86d7928dd8c4c094178951b1b5418f0a9f29445ea5d8d50d062636eb596c630f179784c33e43f5534ed2ebb04b471d49d50507576ac52092e2c3469c5681cfd91aed20868adac289882312b48af71568f5bb94316ab1529f39def1a09367491b886e61eb04bfac2324ebe27f9bdd5a0dc032c6dec2b3b4eb9d7ab8b902ed4f6e5aaf91f6faafed67062cf0b55043cbfa6f80c9e0961cfdc79835150f71ead3babd4dfc2459cf19e968796e1968d89ed4a1fbffcaee17913424008d8d19d080c4ee07e782f9e2b0a44ebaf8a8ec3eb412ac896ae8dc48ed683430d3bb66948f9a5a4a2805abb910a96258fd5193a681fa342d47febf7cc97816c987d0191c8df3dcd755c435522fa97fe783c5657643ba1b6a74805f4b3f5e1c1a24fbca35746493b8f7840fffa38f18fdc7f675329fb134be1c1e5dabc5b6eb89e881c6c3f6159418a47f819f12aa62b45fafeb535c2da7c4c4e12563d3cca1d738fed9efdc4ccb7b2a6b53f1d2f98d267ece7fe0ef3b2db9c47f96b2a8e9ddb19ef695efe01a33180f8e774358d2efdbcfacdaa4b7fc8c17dea871b4cc17b436f64bf9624f918b8f741c84519878ab70c4bfbb1428858487ad94de4e88e5f975d380e51818a8fa4bf614ba21239a00cbcc2cff3ebc4e4760b2844fc999a59f0958a2b2ca944e04d510b78d1ef3fb4aa115db63aa6b313a43fe6b018920650b6f42e3c9b94d5eb68ba9fc498e0673a209f3b7c21896612ce8cad82155ed16ffd8ce82d9134a8e40a8c0fbc770de2e2bdcf4ad9d5e1b70d5f4dec4f5f665743bb26cbfa3d7e3d71fc21a377951cbec0e5ac5bebf3cf6d67e911eaed5bdc704877f128b1ef59cb358f95eb8e50f1f9b0cec30ce7a288f0a93664fc95b39b03adf9eac7d9d79ed37b814716e16a144ae203ddc4d4dd57839f2e9d3fdb34bcc808e995138eb137e6b0199f1aca76287b9d9ce3cd6c53db9e4c28ebba9eb6c111fd18445baa790c8ba9cdb718d60b26697958e8b89d4a354aacaed5359295808bb419db140aa90c2f197c0f047e96ce762ed9d3c5e684eb79875f14c39d03991c5d3643d1dd773c66c53a9572b28c4845eb47a43448811a758c0ab96deab41d63833be20ba090eab24bf5824cccdbe59d5bb2376a51ff3bb6a8de5cb66ecf9eb83e678093852d5db5542678f9b5c53902100b9e843371638eb245bd4044e9d2042e71ca3384d17364bc646865bc888ecc11e5f8573dd24913adacafbd70e1fa0c23e945426619f222f041f8f4436415ded6e1bb39f42eadb9bc6d3fab4d8cee73aa5ca600b47251942126f0e785f4ffa4376e610eee263d0c3f77b1be6a215735d18a9668d7dbfb74c488b37f326df46d349e94bba256247a38421c04b2807ad05e2ee56d29bd8a2fbf7da1ba0a5697b83198957814b9fbf25703b067a2891c178cb0f9cd0c02c6037711e5d274f96ff544c5f46548702ef6653847f28932cf884d055aa4a5aabffb55a4704fa04c51c2df010338599ca201d177b90b6d0547e85e6ee44dc4ef266aaeba50a1ea1f90f637bd0fe59d096f7aa729ee843bef8ede9e99d033102f7c4ff91acc948ec32556311e4b90a4ee44cbe3aea8388564839b504652576e1df527bf154d259063a7bb266d4aa7d65aea2fe97566f17c8feef84766390a402056bf69e2ebdf35a100640f584ba8f4d7c09f5b37a65f4d83ac4222b2366f66ee26c2ee8dc4606ff3bf15458a61cff4bf34967a0c66edd088c45308cccf0530126a9caab84cdabd5b964fa750e5517005e407ec81b69b817322b66f506f83b2f376a77fcb692f9ed98fd94823925d17898f4eb8c364aafdf31e4f4b6cff23fd80e5864e835505b5eb7a3ba71cb9625f80ad92e4eef77e34e07e06273509c08fef7cacc6f9df8f82046019c5f376ff9a5c6807b15c40e1a6eff2f709365f9445f9cf2bda812bf0ffbdbfd59516829e54063580bbc6fea94c9c1f8f1ade10ee60e712a73ed310c3cb9f006cd3efbeaaf360a005f2c136431207b8204bd3013298626c50527ff5d955a7443d406e5efaed62de0a4ddc65a69b162024cae204180b3f7d7817b84a1c0dc3b579dc8dd292fe6feb54947fabf900905e84f957925bc548179b1830cadbd894a3fd3befad1f16edb06afd483647d7169477fc2cfdc15fab8f2a468c5459de7f3a5cb7c366fb9ee4f874338bca7928ba8011f46416047029fabd73725376481380cdccf9e5df0f858d038d6bb656218646158a88bde42c041bc3d6971b1b890164de5bf763efc8ef44a5a9b259b18f056559c634be109240577a90d1d94297ccd03d395d43d99d744762212e3a2181595f65fef7fb80ac30f73ef7865cbd05aa9aff661df56564d2316cd137e3d1b7d8c6d843ef773b622bc20302e1a2e3629735a5e629030709757750f702618ebb467e5d44a05f929396ad6ec95d811a3b3b3247497e2fbf021c1b2503ae0d87dcb88b95d87c0440b12effcfc812556030b3fe92df260be924492ea1c3f34353ed17c0fb352211855464015c3f07069a0d1bdd31c98ccf2ce9b610d5ce34ec317ed8c9dcdb33c918bb0003e3eb616630318160b46c13e1d9e0d974050249b4a1a8224374a9264d6b24ac460fa1f66ac4089da81f462dd8e864911c178d54180b35f652287ea76718d6ff8666e98afd5b92d620f12fdab02bbf5e8a43bee54fe4a4389353fd528894e68e3eb9da945a8cff7fdadee07926080c2640da867f83b2cd5748e131961d50d2cb4884899b7ca3c3ccbf63f374dec3443f1ba7199a689c75bb1abead1bdaa5e0975e7b0dd6051a7a226d8bb9e74bd7cca46931d1530c4e1a6f747deba13d0c7f79a71a08dddda89321b20e91ca66b321d2bbe4d8eddd6b4d5f05ba8fed30f4cf71c1644b3a80c1b1ddb16fcf49290e0f201b6fba19813466844dbf901c1c7957a0a49241582f367ca8daf9bc5219d037b42672dc63a91c05b521afff5c7bcf0ddf5c44a3060f30cf58e7f7b05a5ec3a082268da3de62106e90c856b662ae5e228b68d4eb1bcf3f34516e9f18fab9a5d34b6e44e51aa93b9f4a4c4e6877c2892130cb71d89de4fbede737d6b3e26c019c2ab8429b71f0ac6fb2485a4c566774198bd416ddd04233137796d19a4568525389c0e158237f2fef0df95c9aad4702af48b8d567ad8fb7a8bbce18cb8b113e33fad4bdc5464bab21b0783924a5ecbca39860007db939f4d40c1de892fce7db72cde8e6c3bec120a5a3c417917e6514fdea6ee2da9fd4d2869dcdbbc4fbba7117bb0d77ec571279a992e25807f85d53e17f8f3b09a09f8c4ae5434cbc8db8bc79d93493c2bbac389f0fef19b12185ef5a976a3ce509e3cf5b230304ac8a8c2da35d6b2ff1ebd9d94fa6b5789ac8fefa26d3cc6909baa941f54499e1de23cc305a9c3032ef25aec553a23404ac425c4b2f0771fb84646728fd59cd630c66e9667d196554ade673a2f9b4e390a9a918db8b22ed78ac1520a94c7dd27cb7a3be1af1132c81197448d0fd6fc921215895dd12507d8c5d62dc6565fc73e208e480f5215e155abcbba5241d61d46911da377c745832200d31c73c8af2cccceeed43326a53ed9fb55bc02d206d7ee025bcbed2b61a4d1e94457bc68513b255c0d7e27ba7ddcfdc95df661f5d8ca1995c7a4c59c7c9d861d832d930ebb28c1c1acb3d671def0241cfd0c5d4213a8d2ae8924df1e703bb10a502d9f1bacb0bd6a02e6e13403eba6269893a9b566285596385354c51b46cde4325a17cb3dea3c824ace8826784081a48fce8a20a1e9ca01f7e443528e89fb0df95220e40a82fed3a19d4195daabac67396276a3d83a59f3e63708c34a6f02eb4a07dca40fd11acb9dac419c5488443e605792da84397ff71db73736e0d7c5b97f49da39c00c7c3fb03dac49db2f5b54d07cee91edf10790fed75116c4a391bd3aa14fe01715e7595c5448993ff348b7bc7eaa8d0c8b2113725a8feb9093b39441f857acf325e8ac507c43307d7e98eaed2306dd9f092427df9a2986bea8dfd64df4d8c66ec61daa8324fb8c6fb94091cb13985c85b80a9a1b10a8f97f6c83c22ba98f7d4b7c03e5c341e4f8b283c238aef7a82b070ac47f111c23d7098b5cd89b022468ccc93cc71d5ebfcf73f6bd27d3e03d52c99983896203efb32dac72a3fd1085d9c751d787c07d869ab2e55a13797092382270019c30ed0b666f6001afdb98e466be6e608f5d664e4e7c69f74fef097853b3b74359e343419b26007adc8924c0e28f7be7cfa30fe90f0282b90c787c3ffe4453a5754187169d920e3472eff375394a2206df8f90530b54b5e5c1df9537366ced8968e9a78871f85b0eb26e3338a903685308a07becbdeb98c589aa75d6fa8b1c539e1774de597be761e3f3d2b24be865f75ec7b7e6bbe5d35a1d71b8dedf25584872c9115f8f0d20819dcc147cf86ac0a56b8a8eb4bf28cbe52a698b818514398c7c70f77f9b532fc74f4cf717e1a7b4981894b54d0ce34a20e4d324dbe39d7507a7b098c06e648481bab7eb1ba9a46b96df1ad141075eab90f8e37917a47ada92fccd32e26091f5f489474b6119268dc5118122adcd99e1720a34512a7af64fe861c37a55f2f55c260ab1bd3430ba9ac726602dbc53ec71931fb22a198da5346856ae8c7e9e8ad7eb7597ef6341e275f710781f9f96734d98e8b6691bc7e0f253408c46a4e38368835c7305328a0157bcbd48868f1a3495d5bf63844e16f11d12a931700fd719ee0e5f99f92d8355f416f14da07807ff8ff3729f74e8e63ec5464b57faffdce0e243f41abb8daaadea0950504892a22ac7c6ba8c52946f405cbe721b9c0afe4a733768293ca139bac24d1330b889a2dc080e61212eb845513390f4e8ed097a76a0c50fda3e316817b639208cfcf4f59bb408baea5b4879d2270827c7046b7dcc9ec0028088d5340416f6ca1ce5cbf236295deacafa1d191832838eaca153c6d0168cd9472f675ed757f8496561badb65196e5e16a65ce0519f6ecbbfc80d97f9f2b1ce549d8032e04a0d5bbb947adf504602f96ff914c025d7db8b5cef2ffd6d19caf79cc7f0fad16842a8f7d80b7187a9fdfa4026132e6d3f027391721080563fb15f368e4496e6443fb6a2904456b2d5142c598209e1db53b29004ea093cfc72b3f7f25e43ab4c8c5f544b5df71c2576f5c2dc7b9be13bd6bf551a3b638d680472ea496ed8dd68ff6e020247d5a60fde9d9cdfc8c7db0db8584281611bcf28d83ede3a4fd765ddcbb36f6bdf10591a9bd472b1f518e088590296a2887f769b23d4eb7f7013109230c1e431b37db6db21691bd52da4e51373f46a64199079824aeb333468b68693518b108f56c19020f0a8b985765d305fbc252fe3aa015e63e974b880fd3c28bf2c3a3e10ddb91db258693cd886bc27a8b3b7a485e3b8b63d054037ad5e6fdd7be869d513c83eab0f95c3a62a1f134e42d37e6139399a8fd1648a7b4efacdd21d8c29840ec02d97af3a914109196d0f82c176a09edabe72176f95454f00301f6a5909a616bf24b534421d727fa5713a46bbd25395a9696d5b5b8449e8bc3e661b0b65399418d8c4fed3eb4d52bdebf561f6cbeca51760d5cde81854cf453d9bb87bde5dbf36be21bafd980433ef124879972647e9837929354b1cc15ffbba1e67cead45c6168668069156060f805bde2786e20f49660794e1bed7a1b04787bc6f0230fb88f4faf11122755af381cc680b48cd8a3741cb0aef2452c282b88be83182b553d36716a4ef19c15bed6a785c8d87bcbe02fe419ab8fab7f0e2ed4e3d23c3e2c69b4cc392b8320fc6782a9bdcb4eb6ab697f5f522725d6db78e269bf1600e1deb14e6b03c7cd418db938d51c3693d22d023c86cb6e40897baec6476eff4b32adc97e13833bfdbaa6ab1523da573f8080a3a0983883b3999c1c073b2f70a2c945853d8085ec8730897516b0d4b375c770ae6cbe34a4ab909b6f790f24a95573e8772f84631bbdfb9f408a33b71e5471d6be71b4ac308a65fdc5dc47db8a8e45ca30f51ff86729d95222f662fe148854fd7787a6fa5aa67579bf6a77e125f13b146acbd929257aa4ba8f5399d6eb8f12580ba1669ee17ee4f0a008e282c5efb00850d880e17af7968ba8c83577b0215bfc62c6085be5e9ad49a6c5a739184a9fe6a29ec0262ad6ea906290a4996d211cb032d4d54758400b28c76ae5e3b4e0149bd98810da68e6b743f8cd928c59797b2845e80ef7de86576cfd56357e765118df38b2e71ea592ed6215bd53c2bc0b38f2bc3eda5abefc2aa5aa030a17325f2e089ec119f7f8c9118cf5a9316b1285e8d43e34cd4084c432374cf79b8cd5d9602232697d769d0cc16893ee668bb1badaa1f88c3f0221830a412a15b8c343025a1fc8244c53d02f046d8578f10853b03e4d6c2678489790c17270a955f452e2df8485fb6c9fd66d0bc15c814124b252763c34a3c57785167cf87513bbe480a81a877833e0802079b80ca95428b761ee2f0ccf8a956d306864ad72b72e4d968343c59943451345c7e04a703fd7c0d1bc0eded139594e314b58f16ff0a83ee1fb4c7bb70995c5983ba9d0a28340ce8931d9d07e8d9c5325c8cc67b36dda6adce11e42c79ba8ebee6390d0bf212639783b0cb677e57bd86d3d38951c8c3fcb7e29c9a8414c72d23f98350a2d44d69f1cf1b5ccc1c9daf0b06de2bbc222a481d02aee148faff916a7e622053f377f01bbd341684fd6d0f147a68b9e47d2b979e29593c470623698c0c159c47846d268a22ffd252a54fd77c041058499d61b4be2f23aae06158f85b23218858cb0a799cdc923ded83c084faeca187dd11b7fda70dcf05205354e4cf996e0bb780b87a23123df483a28e8d98396ccf4677bdbc75c35a86a4f86054cb9e75c17b35e3d2d36f162cd8852c86f14cda26890cda37c95d2519a9e3fb54369617cde1fce7c3913331b75954dcad39e538e709c153403e10f4043642a35b98ce95b8f1b668ffc72784034ad154695382ff1fe4502f772741ed5c2f6cfa804cdb2f0b2e27705c537c49c9b595ab986b5c862ae21c8ec7dc0c669eb0e85f7592c5faf3644c851f257c9da3290c251c64e6b06ee9ec88d95760cbfba7403a46da9037c8a6f8d394d1cb58f0350ead2594cca0fe7d64fd8b2ab85940d14fcf64de63094b8ab566dcc3110466ef2d9f6442165a70f78dd9529c67edb44cfb8516fb450ec5144ef649807510898b10242cd68c84b7ac1aca7affb6a6aabd0c3334034002d417c3d7ec7cf1d2f68238524d1ab74211e1ba9ba7469d9ee56ab2c714f161f6b51226ecd9166344b5017e976a04fc790f3c1035ab747767f0211fc3916caeae94d7972b94f37bbf390ccdacbe5dd7010864f5ec6e16ba9bc4431590eb481718b9897088bd913343db1ac40aa96cbe14152c7fa65803977edc7985351f4065b1742e02a17804b21b9432129bb93ce153b34f3046d690d4dc64b2b8ab3bb10845de3ba0754fa0636a6232c9a66ce1c397340ee5bb55c741ad551ef821d73689b8a2d63d75a706677c6c3a59b26ae8d0c6883015b12f066295ff3a65a160b527a98221d935f2245ffc29f26806b4925e1e740667cc86c556af206d28e24c5dedb3833faee119fa402b5e044a3aa84b3c7616ebee45706ee24303a066f72f9b3d311eae9e12a4f830583dc702ad8aafb6450979adafcfae1cc83e22bbe1f7b47647a7617cf7c23e6b50982bdfb32d71322e5c7297a0f71b99ecce8e04067b5a8cf594bc52e6266edc420d0aa74523bbc6597bcb1f3dbf043cce31984ecc9763764930568d640b11ce01fb2499ce6d4dea1e7e7d21de4292427de9dcc89e248f1b7d16082725a09c8ce8ff676ad4f64486e8d9b2ceb7d77319e91324f04f4a7e9aad4990a0420c92c655434f3458d5e7b13f6e66b740bd6db583d9e4992ff6e20f44607fdeb8ce04762e210f5f46d8548259127e303593ff75f84a159be6eef0e8175f6ca7dbb4702cc3e1a1dbe9951f1bf682bcb0696c1e9fe9872c485628620fcef3db8954330f070d315d322ad2976641334d9307bf8540ddcb53cd22b43b8451a1377495192795b081129a7ac7da42a2bfa283c6824232e765b36313cd84d4f835302120c4526225d2762cbce0b728e24c15c240ab2269d7d642616978e54c4781b183c5291f73d5c3f7e1b350295fdfcf751d9ec6c3140d1ad7bc61ed8e0dcd4ba9658c69a41d6ab0b9ce6ed328888658cbc0c773e77c69ea99a33db2b89544673c3ff6b165273bf1863cdd43186c35184e030e5026ff5cbf45e7ecb9f8ddef771619e56d268aa8b63fb828a4e0315af7cfec8835ffa6474e6f3147aab1ee903c2dc292231ffb4827f0de877f4adfe5aa1ced95d7553d938f7c1da20eb76faa3565678af5c60ac9d516a40571973b66002113edabff1eac1a68c5d2efd71be278988ec2bdb02eddd1508d4203101332adb9f807cdb3324b8f07f5f2531424a348485cbc5f3ea11f08c711aa2fa373f31dc62065eb3bbec03558b4e48cfa27ed45aec43f276314a2b7642e44ba15cdb1aff8fe7b64c2299676ab0ab1672266ab445ef731a58328dbfdfb18c7336f0923458d256ecc3cc76353eb034fe085e8f5a724b2d36a840aa4d0cd022ef768a95b64ef90381a534e5ea8e5b7b45f345d555073cc22ab28beb595accada46da99119087fdd1d47edf97d067365758308e777e05059dad0741459a3a3e665b063719099b4a0f888afc29b2385f450718c4356019255f1ee3b5502df610a230a84f0cea7107e831cf1c7939a4461ed40a615732d8b5c629f82e7784e8ca0e84938bfeabf89cfaf919f72b9a61ad2963d74b4ba02df251de0a892a0a5f3f807e65595e2636a2206335875bb7f8eadf0af9ef27534293c76ad19a12938ce9ba3bf39dbce3382e9fcb907521e26984005dd0b9ec50a85a5a13cef97251e545d95cc929119e5ed3683022a97b2fad3777b417af6f81e50a25aeb9e6e7bfd235d5286e6e7eed7d9f3886295478f6aa9535ba1854d4e71e22f0e63020bbae78da054335956022a51ad84fa626b37f889ace9fb251213dc1d406a8abb0606c68559fdb9a6637ba5b8cc64324acb171fd7f4ba8898a330f78bec04cf15784a512108f9496d66da059ae008671fcd8b02ed4c404d618bd0644dbacb966eb96ce4b16670a8012c879aafb8218c31957c5722200f818eed1c4c4824e52e64e092d85e314bc51c4e741ada79c9281e08a16d91163c86755123c7c6ec16f0640f3a9dea31b3ae9e53dff006ac7bd1f3e5086c6b6684061d9d213b07ff7d5052a12d3a2ddc6298cc569d8d46496603c0058de776ef82ec940949a4d494c8d092e78eadc949237102756a0c605cd83eb2254fc253a1d02dad8965c76b7d214f4f178e58c652c54762744a91f118821729942d947c16917b0df624a18f49e8bd20ef29827335f75b42187f9e9099acd60c0183069b0f883f9cb19251fe512a2c2e7c9d7dc5cc141ab2d9498b8ef8b941fd56e1a793c94aa5611b1f7f954d5125dec975fea03f374aba2f4e121be557a61fa5654efb34a448869aaab80f60936c776be59e04cb43b1500764cb91e8559d801a257cd130774b9b536d661b60677381cf75921695fd83894b283db6924d7f042d2e183cd380132d190a56d50584067a4bc1f66b6ca92ab8973cd18f5751c828b3adfb6d0096cd648df3433540d39a3d8bb78881fe055bc02055dbf7c8e8b7438d7ac67580d001de706d4fdfd8cfdcf10fa62e1957c0505288ec531a95a380a7fa8647d2fff4a73be6b4192563744e257ca21dcedd0b0abe368fbc0a598a801c62f1d3dae1623d8cf1b90fb820809739b76491619c72c3e0a3ff95ceae134f3760607b71c8804a3001b24ad52c7a59a8b7ddefe798b983561ac6780359abea227d6735fe2e871a7daf227efce37c7b61b0b33eefdec63839a8af3025f1aa3660dbb3863862aecc3865e623fea7856855aea41aff83b0be85002b8a109b0dd305e6d52706e3567ddd3e465b57fcbf5aadd0f4b0ba742aeaf9024e552a3b4fdeefef714672bcec18f0afbe3691b57dc859bf9de566eab17722bbed45e5ddcf4986623fa004fed8916f7bea3dd9ddbe34a7475b6d7952df32d14e7c7f1e916671cf7d082ad971301bb8589ae7ee80ce8c49563aef582dabd41ac32bdb67330bc90580063d2af7a50363808ed82f040e8ba06405de0526841a768ddf9bfe40310e6fe61cef8d7415fbcf2384417f2164a6e4709f4512991d2a8a08c08e0a7dd9262a90d2725f75762f4dbaa23188f629d67891f17ab22b62c1bc8c85647b1c476e3b3af794d06f8bd88b1758c848db19056a844ee72e4d919984bfc81c82419b75b211fd6b3fb9402aa269479ebe30f8d41b0cab841726a7d3a3a644992e3d1be04ef8eeb688ba670b5254972e8daec8c7536c28953ce8d0555f06e7a42aaf81de46ead5c278fcf2545d0d83449dd2834095f35867fe0fef8ada7ea0d333706a4912d00a16a63a3bf7ea17e885fd5c4ff7b8fccaec4b300405af4ef95b8980b5428296f3ddd3f43be91e080e12141015e27773d85c86a4d88c22bf1fd55600f4cddd62e35ba5d1c00e14a713efae7571f400acf3e480e580eabef51e1489e151a4bdd85c8665cd578939286f6b78ea3107d6d3bfe2c171b59ed7e284d5e4a5ec33fb03d82c4598f2d56d9c2ccb857c0bf4839d2fb4751bbfa30288fd9411e318c288fe30ea5f3a686d34e329039b2a1a7e44ecc0b9d9af3cef680ce1ba0bccf8beef8deb62289885f5af915bf80c6cbe3f34083feb478ea1860bafa5914d9f91815232e53efedb8259ef8cc530f5402a94a3f0ad32a5baf9810eb0243ff40213ff91958bb4b35299436a85814a8ead1a1ffeb54f19a81c5396d3a3bcb212243643f6b7da5c69c1b0d862b7b0ae3a08ad023c8057e86f4a4f117e82e98dfc8582ada04e659e7027b0b447254b56b80d78edcd6f025c3de528f043a8884824f40d4df1d3b59115f09a15d575786a4ffee42f0d4a6f0386818efa44946e85d1d6cb1fa7611ba5de676c3a52149bf6761dbd04e573a9ec92ccfb3c7d2d222ab420205f65adb20a55691f056dac14ef0d686dad144f92a8579412f72ab6ac049b61a58d97b5fd3f3c089d6077b831f77d4a1673e041d0fa85eea612767e823918b57d2228324631ee8cb7ca68107be895479368f72e057280d82cfd7bc3fa1ca5fa1a080b0532862f758919d607709cf898ab5c4142f5dd8a103838f51ae2d70ec491d1327d3d3216bcf274c6092712ad3f2a243fe6c520d253b7b3ce8535f1c281638c8017f590e26f6cdc0aa5bfae30574b09828755d19462b755d57a95a203be25e01b833af64af18f3cbd3c5fb78a19d0f16ddc9e1748d1809dfd2d49a2d98741903d8832ab2b8868a9ef316f93d4853899fd5bf9cb6efb464ff7212846052a8bffb8e82157a4a53ad90233c535764d702f6616520b04bca24d3f598b51b0647a354a547151809349134d5a888fdc8e8332d1b7af1efc371b380ddd7b0bf1c7ba4a77234c03551dc1e82d20732b8b4b71e64c8e24941135cd6bb2aa2dbf8f4de6e671cfb690a8c32ef52c9a05cb6a5a1c7f486eb8c0ddb47bcbb595de47ac050f2e01919dd0753773da30123e18e487f6fccaf03255fda53e82476e80a7975792fa9c281d7493a1d970b2674e530027d489ca568dd41eba16ba646a4478737063864222f128c7efe6aa6b1362845535367c2041397517c5ea774b02325c15f8710224dbec1f9ac5b30958e93c9502d9f4b6c97628824eec15bfdc517697c1e0affa937c2c66479c7959acaa7783327aa8054553384a374fc04f86c318a503848c03347787bda74b6f8390991808e44ae44f7e44a5a0ed687256a50d8cb4e3b45990687c9984415bb372d25a8075b72b7f3de6d2c08d157833d46e742ebe37038a902eb9c080d8cb401758ee8340b5cfc9b0f25f51e5c5c816cd1fa3b3a802e3fbda718e14431de4db1a30027c139cb63a30ca4df9409337cbef706dd2fa3f5f92dd3a48c0e11d2bf6cc7a2d77ef6b0895da04790888e084ec6e9ccb9b0a3b9defcb77f2c8179cb33d753a53587386462cfa66b4c0c9e6eb2719c290812492d5cf2bc8090ccc1890c42f006f1561f0759b1bf6a87c4c03123d726df6d28af4c44f8038cdeb3442799b0753f21ea9916110effe0516d0715bf2fac9b2a16a67668ebb20362aeb7d7d0ca00ca9dd2d02a90bef53c5fb65329e5ec9f4f48697f8a7af4dfe138b2969a8578f183a21ad877313ea29d104eb16b12a22078c4f05ccb3be6629f53477d51148d59b7ec3d95f7dfc9fa8363dabf9b470e6ff9e11ba41b256c12fa7849d3ad9535f9fd4431b068ed14982eb11c59ec4168dfee5eb9b020e0906ff9f0a6e348475ea78b9bbef86fcfff4c69460c1496534b5bf6e6dbd8088dd13e0cda2bc3d0f600ca8c8d8c05a85681619398c7e6d50cdb0b8fbf421afba59af36f3a202202340de5761c609b8fc21998561c1a393edf38374455d1dc2a563e025e13a6a80a8c597a385ab96b1beea8c1c95bd0706d0d2ce53f60f80a9b3b87bf8a6d8592d2bc9f231d709c5945eed6b4fe2890a00b1a35ad79401858bc307531c763a36493e0ec3cea0bfb316a2a61589425f4a2c34959801ff7e6eb6c291fe16391fe7c0ac55aa498ac0d18b458a899c2500d0f182523829c9818367a1ee90513d5252d3e0b3ab40e3a890e2f8bb0276247d64c62c4363ac25b526da597bd15a311688195acc0b80900a17b213c09ea6bc78ffadb0d3965682fc31bf2401645211ce2991844969d3575b307ee6c3faf829785a091ae83f645d5a2bfd05d337ca87e552457d5659a79f33da70f4e67d266fe5f64e1f44becc4df868c4c0e9de49e4cf8e7a85ae412c30241f2d603940b0b5ae181d9b11118eb6cadc12065890d5ee0a2f484a05d74067acfb373126849791daca73e722357d3f9df5cbb644a6002f30071f8d1dadcbf4f06a6247c3bc146368a47786ddcb85958edf5650639bf34435b64bc2805f8d8145a811ed7f3a96224f73e0a98c7d1b586865c0b9d80a34f8924517c6e341606a2af557c961f885baf59e8656c16a773040a803cfc217a8235ab49c517a01344c650f2d69b6bad9a238de3031e96ef14413edc2a02dd1ed15135bc0f19c6ae4bc15907d1378f430e638d56ee27e33fa858efe7742661c80442e2817f895837c01f07d5c7faec8717197d8e01537cc6d999927a3151c4db0c515e48db93d5d0239b96873ea9fc60f604fde5e0d8b376d70d2c57180f85163c7b002f2b3eb031f30d32ac055883f78c188b8c9787c25ff3e151cd3b397fa5659c3e2f71de4fe9b4584075195b0f53cbbb5450d4ddd4bee4a80e3663ad40b89c9c6a1eb7c3c237a84c0eb55939f2968cfad6f7063626b0242d951cd69f4b994ca1f7dc0525557327fd49d1c3d72b3e4c726da070b74578ac99224f4605215bff128b05011980fe7a3f5d88ebbf7c74abafe0e9f7de1d12d4dc6896c3449b56a985a6ca376d2437559efb8ea20d462c8490c263ba4de87093a1473b2b80952fe2d826bda138a54085edc1c773a9e992c6ecde11d0894128d6c77cd7d46f7f459b355e953c4a6ee752005980b6e9c9df3ab58c6284731d3293ed55813794d2825361b752006995c1b0c95483051d7c737c985ab10df5d04a300a4b846d0233da8df9244b0c8e3d6e872edef58a00a404dfdc9f7f6dd4aa1e2f8d457822bc8fa37f68b2d8d49f5a400683ee3036f42cee2832546605b1d4a5347a23702efd247e9c7a653411bcbf7c3c17f3870f01e764a866096e86d777696a4ce42d86d1f17bf5557856ab047a8947aa936d4bca42af389be0e6bd9140706abb813092d72247a9c0eb339fdcc0494ac389c7e304ca47618d0d3e634ea83c843bbc0efcd5d2d8cc2a12e427ca6905a74b2e2fbf06df0a6eff279487c7538f9b5ec916496f97e4bc10debd7c35e8a2d1bccd64fb6194024f6e0b17fe793a32f4431ca697f4cce0726e54db118a4ffe1c1978035a940463ed5bc37db446be7479c2a2fb208a728a446ebc437139dfd2edc1d0cea502bc720ed8e43d0bcf61038396c4f16cff308ed17c2bbb999d5a702df2810154a9c753386f2c0395a61e47c022db1c9e9869219a660f58e78b5d5cacfd1b8668eca6a84a3c93cd44c44be92b9ba354caa93e67ee700257f3d772c4a4f3ad8564d2e1b602e77f5412178093b9e99b35e0454abcac269a8899844fbb2b4de10068deef063e59c2d3aa58c132851bf4758f58c4aa18d0cd64f19c254bd7f28e209a9124198972bb157e5e20f57747a9b7d0f690792a0efc3ec8c0a7b2dfe64df47796f62ef1935827d785b67ece150851647605a0f6803bde59b49cdacf11614860b3248457002a69ea8f24843cdb4a30a05f91278253ad4b78d429c08bc535df25a4a9fb2a56e9155ad5f35cfdb0d34bbbd9bdbf5aeff037b625d4c1680d1fb6c2828da4a340eb123f496e81b0e86996c5f1498f8d8388a7c700b7be7fdabac712d62f90b1952536c9f1abed556fe6f3b04d45bbe3b80dffc511d2bb6df85c50f54433dcdc672f3226f85416cb15eab758300fff62a847f958e3c1ec0639e7106aa0c1816ed97a6b22628319c0b872390daa1f02fbebcc6df55bf69ec7a85e201c803e0fd37022a19187a028e96caa8ec9e17e55e1362009a22a16ebf637323d5567f78d8decb02fc8d41949ecd62c58629f01016e4afdf3303d2e8f3d01fafb4bc373da512926eb6cd7aeaa166b4508605e3bf61036a64a93b2aa37211d1cd139c5372f37526be82cf47a5cf6eabdbf53389809831ae723ad4771457049338bd4c4ba06f5e45350a75bbd01d1f7d20c9c5252383152f028f0732a6c66c5f061e9ff98d79222cd53576597ba23b38213c549c48c936b4bc9ffcaf11cc35ed87a805ab36b40c80476da7f627b6842316115948703196880d564943e585647da6df3689b177315ceec5a8c57a5706413dcc00b5ee210c84b4a2232841a365c2d10dd83a51b749548093d47552a1e3a9f0a8feb584b26c06ceb4016d826316dd2a2b20c637ae6b71da5ca4b0f67f33691cd64f216adbb9aa6460c25a81ddce806f541c97ccc9f3c700eae57c196980d2eefcb7819139d58e10fab21ae9494b3f2463f691813f28b7b726473d46b1dd4e356dd2a907b111e87680f7eead23619a70bc7252f34a1c9d6c893e451ce605f047972562226193b2367912da2a134151f5f9f87ad2e03615b2d86bf7bed5e18c698c74e58ce70fee5c4f5c16889e09f45d59fb1dc710839f63de71bdc182856d65bfbd15bf9043153b7b264a7c89785d307879cd75acd7bd5cca18a4d37c6e9fcd3513be743dcf5d84642500f960531da81ce267540d3a1983888ba793c2e5ba54a368a21696b55bef6975e87b6defa8ccb4d879ae3fbc755f39cfdb5f7b938b0b5847f8e53e6d10ae7cad57575e3c623ab7b6252bd6e0fc102584bc2e033ebd4cfc3a05b75630801077edac0f597c003c1545357711c4279960a15998cb8d270359ef2bff81efbf149af011be41dda53e490662179028cb0ea9460e580627990eb4580b795e27c72a4d469bfcf106633504d4e41527308e1693d5a7c2bfed7a298e9ce7012427a4cdfc2d5c061c3dabdd5bfc95f44d70a73d6de69cd5f345d406fa401ceee2a434a7bcab99916f15e70668e75485e5a848e37f8c0fe59f386623291a55b56804cdd5aaf37f87388bea7858920b0f4fd413301efaf9abad8e119e9c20f4e408067345a3c0b775dea0b0a777159484fcfae64fadcfd8d3b20daddb2598b12c61e0f2967c75082d5148826657464bb4b13212a530e471e676e2d27911f2696fcc5844a148236c874f0f92fb430c851495da8b1bf6a8ebe1280bbf3ee7ac86bd85b49512eb745c89481b6f908334d300da6e1309c02b5da1eb1ad4f078610925d7a79aafd7d934306d6dcb2c3cf029e0d2fdd22b20f5a9d7fe8f355b122ddcd26728a352d508f6549d2e513c6242faf7faaf7309af8bfdb4081fb360f7c1bb35c61df5ccdda733ff4d52cdb83425b43a360e4a5b1424b91790bf6db1bcc2555e2c73b40226edaf34113a1c1a2eb84ef5197d226876950a242120bce13a9e98a9cf640e1f0fd74f8141cc1b5fb5999dac502314863189a6f9033a7c35a4cf809998bfd4ce575672d056cc33d52c98c4ec9d8ec76182f535dbae161931ee4f3c858d18114b05bddcf1faf329ba80d23caf91eed9122c0aa703ce42049d98f6b982ba94db740f8d835fad73d3270c4978c4be1bf65bd18272bad4563b2ab9a7721437514964edd2e95c0184f64ab2532f6635aa5ac8dd7e2916ef8166277651aa4982aa4e948342e24b53cf35d6439dd90e2d9c91c6ebb2ab3bfb4c59eed2b022da2bcced1d7e4b80331b45abfe7845a65e69368209675cc194dd3b6a0085ba4d50854b81da4543852c59570164e90a6423bdecad0b7307a98a79c33550bbbeb3b0d368c4c5ffe51e39f70e415fc0644c19dd496e3799bd61d29b9c808db755b6fcca250b5365f39d248432ca6c3c0cf3a5a633f858ce23996b2e3ca47e09a25fe96c8923890f312adf26c0c4b1b9095e039170175beb8f9e6cc66c8f368cf3dfb769b621406b6371451f23c388e5a923e0aff1ffb37fd5c2821567af23478e0490cb25dab77f23e1b7b92843aa31b83faa78d8b51fdbf3828e6760e9fc17ecf4745c91b04b80a9fe3f71782139165058a8e533dd7f664312e8bc2c51662779389ebc0a9c817d3b535367b7b63d0d3b33b8e2ddf42070af526c4fd45090a437eda00f204f6f0aa0096b37fa1e36e168a0ca0646250a4b2cb51889eb265d6895fe47c34e72c5e45954eea054e34f8a17619de73cb9a0b9cef814f415d30a2724fe519f4f09f404aaa0ad33a2baa8132a01d0ac4a5491c389331cad80f20249867905f48e03f1095e3145a87154a6c1e0c5a9c3ab0342f332be4b4e6e05268eb2503975ad00322327accd43fbb1232a95941715585170024553197938dad67524b1d9b8faa22fefc9c835e9002eaa056345647ec96c531eb350cfcfceae9b8aa0ce1c90678182f35f65df602e3222780ba17ce8d227d0031a4954bea165d62cdf482e5330a278b67be7c120336828a63fb23d4c98cb4ed71692c077ac63615e34d62e3dcf1377ad4172ff748d08a623579a80142e4c6300f869519ca557ebb2827a53283b57e182b3b5f85fe5d80d07ac444b5225ec02b6c2e21923434de0383e2887df6fcbc7324695c20635c8f4167317c2613e579f6816d702140e0d0e79fa9887596b9609e78467ccc511915be2261b14b3d2b29830d9b8c1619f7bfbcf2474a87cd8d3f989016f2f63b87ffd5672b65cfa58a5fdc5628914bbe238482a422756f7799ce84097b51a7459dbfa31c358abe94801d2a435357bcd9045740cce1281af30a2381affd300b50bb324146dfef3236ee1804f70c4144e427d5be290a252a9a35237f3abff5296b8c60866b7f388268cb5a17b35b79a90525005ebe8bb7e2eff1efd079f878251968a4d040e4c4d9db9157f2785d4206e7aa19bee9a54f2d3a86ae5162a201b5243298b66c8a335f1c98ef8abc47151543faa5c57e3ad5c1b4b178a9e8f462ed78bb29ddf261b3574853549a1809d8ff8e16dd39a2888f71e496d9a2a1a85dd67c186c85c087a973033afad4704423b51f743e4e54755af9de6f6c7531d57ba2f027c8c32d25618966afffe6364cd23e42f6512a756c9697b8fea9ce64a1587fd91af13b3313b287424386af2cbd970238c32b4fc836681b32d9b30e3081fe2d76b0df02373a512c5e4767bffd78125d919f3bf1932ba455772d1506044aeed0298da49d8934c004d901d5c1176ce7fe3b917fc5b2c43ffc54e32b8098157a9be1265a67461673b1c0dbd6253a760cddd9d29c43eae521be561c16faea19c7125a12c9dcf959cd14203a76d86005f55e3f18cd2d222315059c125e5f965261b06798ad17c910b81b53e32091cd3a15091912e58cd7d765b891e26474b5864bc7349375861086d1c49b967a6ba9354d840e78b0b2e7925c2e59ac85c5c7ce953316ab0220dcdbe5a749d1ebccb4b2644e3a315d7da2f318b976fe0b047c2cd6ddb69a032140e30367e8a78093af4594531acb7f5366781f53906d605454b7fbc1e5135e69dc4ffc31b58c8b481916e58e64d9a4f8231d51386b7096a479a756c93188119981d90ed697b7986d3903ae50ebfb71e60149865b2cad0952fd03c6683ecf23b3a808af9231051c5e41eae648f1a974d4fa60e04edde91ef64e515b8e6e3807ede43e8a23d2c0992b8b044e9b465cbf91bc69362b1e040feea033242bae1b392e800cfcd236b11404ebe35f3f6dccf08bd3913a4468cca0c28837abb3dadbb61660ee1e814c59d9dc4691f1d7cd9ed978030d26b19fc726e8de82472925a6ac299c1a1e2b182ccebba1a52b8593172222eb5e0589ca848ded0f06fb573e85ea446e43186f835edd8d9a13ff76c172155b5e524e79d294718900197077c3728addf61b06ee8a0ffd825edad34bbb34aefaf3107ffb14a4473614adf4424f4677d42361ec27145e99b95b053d03a7915421c397ab8d08dc347180fcf4bb9d20c85ee394255153c43e3d43c28dd87117290863a804471b9524f4003ed809c2f5719bfaf72ff8b54058f3955a14436e752de9ba9827eb30802d4bed569e2b9da461316fbeca5341b9aa25612c4e391e7370f863f55ea3491999de251db07bb1dceb5b0cc05a29690599db5cdcf1165bfdcf11699c44f30135217cae687c5e7bafed7b3021e0cd626c077c842888e74e6a613c619f9d06fe15993be0f387041a9397fa6d661b868e8b396b22ff6336450a09c022c2ea8de428703cdb68b22286b374ba566e6a763fadda4d75c6750a2cc9f4e3db1500ff2c5b4c2ea4f86f236b1d2aa73e95a2e16cfaf04ef7fdccc3a090ab7a5bf3a76067bf93b3a1524b48a0018aee9b01b5a012a9511e82925ae3bf2b18c3a26a9d327d9f3b79eec4a6758a7438423850c0d11b06e57a60325f62707420832e633a0f65e40078800281b2021dd561409a3ea7c38f4b576565601f65979b72518efd671c528cad77d0d5af11bb648264c62f3d683fe47895ba3d2ead46a84520106e057182b9031c431ccf97e9882d8ee0feb1ec6f6b10efd0ec34a7fa7c186f53656c476261236d8ce4d89a401b6790e3681586359dee32c346cba5939d7f972cd38e47da0209ad76b855798957c6b4aba3198f1beb95c7a6a7fb6a59b5fbe756e0c92bb992f2f296cbbd1a884fa0df9b71a7dab72c5ec8458fe1054abd2a02cf800f99c2218588cb3fbac46ecfa46439e697c37b91b2fea3e93ae1a11c8622ad9070dc6baa18f0249d8bb933a78a4aaaca5bc5ade369a6ac5d3decd016456511338f2df1494fc5ed33f690d2a93e4a9b380a4f1a925c57febb3e85af15d44f3c3ce46c1cbdace2b8e6db44e64e78e8ffa6a4a39ef79eb87598a5e886f66e8577c18703e30283b7e1255dc0ab48e6370e2c20d4ae01f6ba0b71d4b52ca43fddc683098ddee66b65de0345b882221e59acadd2bfe41de5661244c6da31d866551412660db3ca106b9728676940ee9ab1a1d81cfb634aaa63db9f4e92f1f68cf87527533256a7fa36b4da47fb6ef86a0a79e8deecc925d21b47dc59d8e76c23f23a5d65da2409c4ee7bb0e311730c2312a9ca7adee42a9b4707a89d36dae3f9fc0c5dcbe2efc4a0d7a62dcff0d4ef5d5ef306cd590a7fc71249850cef0c8994a6c65259bbf38c72b04340b49f94050f5b9c06aec8f1183a85dab6b53cb5c87692656f0425be4cf7737104cd7bc092df6a47a4cbe2fbdaf0e9bb25961ffd18ea524e70ed42b8a3952f76c64f90494b8e64b3ed94f1b025ba0e61fbac3193d510be754bb0bd0baf8ba21e66374840a0b6db950cd8e0e63c90bf5cb1a48553471a6eda81b7152ab915690dc12b2af4f12c53c67636c3dce6205e60d82f6b36aea559d7f45ba27d7c31d5eaaeabfcaf49cdf4d860a5eb9b26555e6c88394c814b89bc760a62a02ffea02bc385dc272bf64c5e2f28ac8c758117ceff2b5a726f813a8985ebee735f56bb09f18fb265cae3f48447b14be5bd1e740110d541442624196c5c5e37d8d607ce0419f86fad906d5ba8fc0ff8e66595a6dc0bfa526b2a8d43a29d4dd83c7d3473eb0b11cfd20cfec71f2a65ab96c4fa1985dc40a4be293b55158781a6d7eb00d7d5735cf247bc72c466c4cd9e4bb890e9f0f0327f6a748c396b1ebd32f900649cfff18804920a9c390f3ebcd4d5ddf57b017972e02985bea54a775d2e655d12776ba4783dd1d7585c5db2eb8bee0dd4da1f20b7bd5312d449bc1918ba22f58589f810d5c043900b4061f1ecd33ebe64a15a97a6e8bc9bc18f8fc4f4914918148aed00e08d0b46a4f553f1a0fa9f14f9df54ec0a00748ee637aba701fc1bee19e3d036ffc0874a403316274deaf20aa15cd3f7f7e5a7bd04cdde435f943db20306af4ed85528a31cafb03d9a11853dba35ccd293660897efe0d7b8f3ef21450bd02d2b4d55ec9eed06e36b680c0ff7d214d2123b4904f96552cde5048582a808f086787df936ddc532911732c0a53f4b0a50c649a56562fc3dcceab170299847249a1ac2a7f20dbdc73b94ea253396d2f73ba4a917d529e573b6d7c99881b50b10913af0980672c4a8519cbe57e0d78f934a6d4582204fab6c6a2b2a6a08c5405697c74b49b3c6a196de85c7bdea74c5d73bd15e8eb3600f67b517c5479f0bf45921b78f04a4ea51a3f2ba2990a2b9ef95630ca5857d32d680d962b66d32a76b4179c8045838d3898eebc95b10e2c8087f5f9c95d0cfcb57350f689593b479f25bede9291c2fb3fe7a343deca772b9b69c401870096a4bade478b3acede40b541a04e9171f34053a1caa4c34dfe1d49beb4acfd2be38cbd144aa3642fcc599c9ba9099fc300c514637baf41d0f36cb22bbbb1e4822f957c5e34b792513f6f0cea95974a6f628c8a395565d700e99a013d4f6767fbb9581f2e29f130be6f5394f4b646b11e885482c4ad4e18e1c18b44b342266e0ac3fcb46fcdce442b09756b5fcf88892ca90b98ee89b9799936af4648f6b2b56a910e3b8e65e5cc4b1022d2082fb3f065feb8ff44d5b7f038d6c4f1ab6b7cbb3c55e72e7daf5d530687f97e7c7dc0cb0d072a42bb3581769d80b67bb2d1f7edcf8727edf3d3e7e9900363165af31da6364858adc687d392bd215823deeab67211c933b9be6f26c671bd777265c38a25e367d1617200b46eca418464cd0b325adb7b87c9ddb4e75df091e356f75bda3ac86377bbb8f0019fac5f2233efdecd57376856d140227d4beb303b0a1f5f76ebf5993de2c6cc98eaba2dee7a3f0cd14162cf5d25947d2644c35e27e940ecf38c565dddfd7f2c441d25398e7b3b98737f0aff85a7601191dcdfdb71bdc37025b68f2cf2ab9d989dcc01648f0088ad8b0bd1af82a706752cb1897f4d04a4eba605d4017ba79b4381e8fef45f71b456bcf9e016e8267488d9760b23a3fc355c2767454c506414b48e1cc805faf24102a826e2fb424c03af8b41d5b21eb6c5fff15234a20648d8bd85164b067fc26aefdefd682b0225e3cac22222cd00cab4ba3bbace159e36035cb4bbadd3ff2416776a11421bc4cc2d79720877f24e577b1a332bf1640972089bfd1d61c24bb872da2067efd0cad7edf0d9d9376bda14fbb6ceab7c026220802b03396c80086e079f997e67dd29b3ca7c041ac34514009553a29ff15f2ed695fc101c2e35ee21cd8fa1ac604637289983a762ffdca3f3f05e38763a939db11e28abaa728240c2004c0ac97009463e894b86cbc93e5c05d9f728a40843fc4dfdd321b9a095956ead66d1d5b04c5e403610df091d33427f3850a745423782d322bab09ef36de462ff17f4e83955473ae8947db07d205ae1b56f5c0b88bdc7b345b2999e72c1034a94ff5bd073a1d86d37333440ce00bf4e6853fb4e68f8b2ac93a32c6c2b596645c7f87be5bd9a240b293a9b406a021114957de757736f5fb8a729b35150fe1ab55fd9406149f41205388c495c2f4fb9a6225cfd6f3d9486a2919dd2719be476e20116a3917718aadcd1e96dd0a6ea53184a9e86b31f97f220abe5df51fe8eb5438d6b238b476312292fca4558509aac6115f2548f55c46c991faccfe4f4af558862b62d753bea2a387151734bb583a5c3a04ce1255e32ff18aca777628f82606da5b4026d347748449072f7f0a82a22113939f0f94381cbf3d470cf0263e1ea37954eaf4cf37f5f7df42f35e8ffb639be021d5481f0b1311beeff655cb0390071e56b27ccf7cf721cb4ce73c59b57c4470877b1fbc646bcbf37b0410744424f8603c5cee2735117d72ebb389ae0be966421791fa6ab607ce1e464677e528ca8627a5a4cdb28e8a2e20b62285af7e53ab87d74b3e574059ecfecedbcc5d7b81a208e5c8a39e63894a96396e6dfc019ab4e3380bb070dd83ee37ef5bcabedc57ce457e6d3d16454e7a0be984815a6069ad556295d6e58bddc303e280733c49079753ecee4183840c5529d9ddee9d53e708fb760d90940a252c33aa19d0fdb86d1450992c45effe7f01bc6567f00504be5d239f0ebda2bb1eed33ce221aa2985eede2cd4a4beb2b6dd20e1c1acc7f4ca55af8e004eb6f8c713cb9bad0b6261aa7324321081a05203b6c656ad1e2a0fc1019525feed4cca69a5320e123ade227d5f509f0a5227a290663ab880a882ca346fd33e2df3b796aa43a7eb06f99ae8e713f76e4a6edba6b06b1694416eb3b3f441cc07d1f88c4703798695b37aca4ff0e252cc83a2577c356b8de8d1922c8a33a02b2e12fe635727ad291976c3b9e1ebd2a09eef53e51caed267ebce1165da5678b53c7f0a3c5b9d32c14dc29ad779c65246d417c10d52cf6b853c2983824167366896d1067468993bf9e9ab3efc5b93af1dccaf1aa26fff8e7f9cd8f2e4ef0dcc10931f8211b62822f4b4b46bded928d43b06654174cd6473ebc5dd362cfb9b84077e633706d145e06eef5328bc820dd53d4d991b9e07477c8d02d4fd118ef2e9d4499a048a4ff60f7134bd5fa7302b0ed4affe2a53f02add92051dd925fa84381126cd116cad86bf12675c709c1976581dc025f046ed38641cb29c886ddc25a8e5271022706fd794210a490fac13ad55e636df075a1908b4b0006e6d4b8ab52202220ad59ffe091e74a9098785350ef438137a9ec8a8a192b5ae29d438badebbcaf92f1e21bdb64cdd5cd05967c401e42d566bde25c4ef97ce1607aa5d99760bceea7bb6cbc04100f99568025f375b8fc5d0a3fb78714baa16101ac0a266a1ae8c5e4f7fc9c1d30bb6826f52592dce162954973891d1ce065e61e0916e418723690a5d60ad58a054b1cebfa3b544257ad17fe44edcd3cc988c0fe06c0ca77c45bcc7d397ed87ecedc5219e3bf07a31ed138fd797e60d8d4597a8fc06b14c2a110d395676937fe085831b5e03d244ac5652f3793dd460e0649c17a60e36bc5b2b9df46b8544973665dc3d8073e86b85b2832ec4ce647dd41e8653a7a2bd8018a4205e668800c7234296da9c9bafa94d5b43eb196f33b91689197c6684adbad40a1af0ec8be2ddfb497ba1939539017ae66d4f2c08e387386208677b7540aa4fe48ee6ca832b7288a011242bff09c8e0f74a8fa8e9e6cf78fc47f44f34ac1a57c4752d16f0196756444ad68c98a2d62fef91888bd5246d052343a6abf0e7be09b241c5c8f6f3dca22d3e1a5eabd04339baedefa2ab6df17a905284296f8f39c652bbd41515aa90442658f90bf25e93a21ba71cc831a7eb3d594ffcf9b53ac7f3c959775878b0babe76fd5901af49096ee328c4a8574e0613ac13a0ab2bdc446d8a1cb1aa37c97eed60bd9e5c6ce47c4a8e7b59b7778078d5c34577cee0f61cc2b53251bb2f3da6e0f8e558cebe0b2c93fd010b0b4464531ad3a596552e93031d51111ea411a607c5202249978566ef78e685dd3bcf1a47d17d36f3cda8f6703cd926ae3c8fb8931ba9adb66f4af4cf4b86277d73297f640c8382479cade3739e8cda3e4a6f6a455647b5e1c87fe29baf429619788685ebb48dc4a7ba28f286a85192978ba8a8450dacb327159f7651399594fb3836f999be5b9b8974dc962bf7fffb3f6cf13e5d495eb2746baa5d4691564f8265217c23ccad3133f1a7d25d1b3522c311b3792418f8809eff1b5a9fc40601836a36b172ac163202bb4a93f6dd17bcb0e330bc594dbaea44d3fd073f02f26da72e5f87ac09499b597ecf88116af4e3da8087a108a63668aafbb5f346def153733faa1504879260b80cf8e0f085794f7d5e1e50f3f5cca222046ee138a964a5c3fd3f82130338196fe13c50bdf239503a81fae1cee2a7a40e58f9900a20e4796c88b758900e4d3546613597f0ff95003ef59a594168ced4534661cfcf48e64cf0a972d7928c2540d327dc1125d3cbfb9bb2eff7f97dbe6d23b134d89f55360732b0238b31328673da55696cf2d7ee56b1b261629db00bf3bfcfd04d40758319b36457b660b5db0244d1511a4017cae233ec9483dfcebd21194200e4430b56791f9b13e1b819ceb903761ef5c67ad283698a1c01c7bf2009eacd42ac5df14f71afe29e59480a9a8f1bc13682646b2060093ddfbd8ce03112b1c2fe7707eb697a6cc90223b94969b6e84e3a653fb8f2a3340f643dc18683fc1a9ea16cd6f37c51df87eb43ea52bddf744423a68e5a8c5c4cdf130450e6c2ae63b0a7027232b6914b4ee1652f3181fa62b58b7efb3de550695512f78db02d68d83b858a048749303e1bc8a95296a9af37965ec9d3e4832be728945dd58c3058c0c4e8c6e40c27e9c0535fdc94107e2f3325975f5307726d52944be357b00e40ce5010c756f2d01aa36d58d5db9b35da6d68d62c721fa2ae9bac2dafc7974688f942275f13c6922bbd6b981ad9bbfea7a836d5a1953861d02515efd52efe978d7be9986c9f456490a20e78c97f298baebd656d32ea4e96c8c507ea6cb5975f74ec450fd3bbe26b2565bcec6e259b5d4e4a367a6eaaa6045c58ac48cac6f96400fbabf4950d948d74c83516a8411fe0c4e15a3fba80bc2895e9acd1f67e4b48b86a5cf08a58bd730507d57e711755e3736103421f8f5a1fb0481efd1eb91b2670f2943d9cba62e7a8fa8654f2f24d07426630fee219c9f07cd31696c7088f2360fe855d42e05ac40a3d00048dbf12bbd8e3e874674d65e3fe84100d25aae5d9c584cdc35bc769d1312076137b4dde73c9a5ade0db457fe3484df4cde78d287a244cc3655af2f8a999bd83b593725755452cd45c658117030b5d996b7542f7345dedad4f0d2a75bc73e82b94a3dce561804670515add85a42960d9db516ea732bb197eb75eaa7cf436d60d8de06d579608375b9fda8311f2210d36f535302406072e1df44ea59a2f9a189b4c0263ecf000578f85588b276c60b2390d5569d9b203d9c486bc6cb98f037474ac736cadfa3b67af4ecc6ae38e5b9dd6a9c7da6cf1eb46d844d232dda90832a9e32cecdf2b719c35598ce7b41224b7ff3b3407fb8ec30add8e6ea9493e527e49a0d041d4ee7a9fabdb4bc87c44ab8c6f930aad1f6f19ea76eaf7a7c2cf205599391acc172c4a3f0c89abb3ea5eead14a4d582c99f42f2c5211c15d3e6f23644bfa6dcdcc15f2dc39016ea13044ec09ec2b3a53d496e93b98f011ea535e9629b757d9928ee6661ccf423606e5cf22a068af9f6af4747c636b7a182629fc94714a5e42f1f7067c059fffc7571686ae872c5138667f39a4c5cba96e5682347d105391e14d3751a10c09e0b397be6405723f11858d54ebed2a591c14c80e6a217a8f8b20094019773841fc9a7dae493b4bf35ed47fab7bb3aaf37714320dc0a1cea620526680002f953e29598a0d1d0b5e63aaab0285356149f191d01b8e99ec6ff4a63473adcff0382c1e1197856598b242715ea5c0155c119b44346207640c660a5fd064c255d6664312f8918aa4e6797f6cc851e5373b75ed24d08a910cd09de6849fbdccb413292d50d5cb4c0011a6771a0e693adc92606ba336b191b169251fabdc785a4bda4aa67999ab1a0406361089ccb5fcb9c551330260013b531d8b8b396a7f6756131898e2f4c6f0e1e2569b8d5a79714c7833d366932f5fc92ce4f66260fa18b501a090918b1ffb0ef47bc3512803b20e37427e70525d2e439d8a245f2685108627775ecba504f86c71a007d6621c65898fcd1ff14b05edcd43d892ad77a2c282de470a09f9c5703dd9901b3ef543fc14ee3e5e381fe11722f751288d7b9a452f72502e08cf8ea1a5ce167fe4759f3cd9b891a0c49abf606a50fbfc8b6b8d6ebe543ba34e2f7f5952b513e041c506af8e74f1bb872a59d5eea640440ee411974e653c56462e2b384839daf41ff6615287cf8a4af52684426a37c6a057908817e0966acb7e294d10d9463994af493bc125c58a348bc327b26c6580ff71a7e28c6bdd53216d1c04d17213f2c5815a86c44ada66c82aca43c0bd198297cdd69773f8a110a88de6475b2abdfc3e922dd9023e373eb0a7a045e6b43595b104e8663cc092480ef4ea37e37b3b39bcc1820904da1c0672ef2c66f1c7a2a93a945b19c4d6ae8d9b434a043bbc253c409ee7d6ff778d1c4e5fbec26eba1a3ccae4f75f8b3bba84a161f59d62da8a375dec0a05e098229a796bcfe528519bcacc8318e74f863d4261c44a791a6279b6eec93962b462b1e0dc7644c5981132c8020a4c63566a9ff3e20cba28abe88ca706e0a29995ed1c4fbe850faef5960da20addb9344811772a05cec43d40e0c60c680bfe201125dc3ae2ee3ce4a01610eb1429e1704c528c03b96ae7f431528c9c28b6356cdbf285d9f1ba8d914b4577076a86c2408ac44eb9772cfe039a6ce48020798423d78bab0aace2e3fa900135e96aea305722218de2fadd8c61e874847ea11a79dd88249c078a29491609e69f30d67a777a2e35995bad3395d266e502fd387b728d8025d8fb660012bdccc01c692ecb9e865a0faabefccb6c2bedd197abaf237344f68399bf9abbab274527431e8eb303d934b0dd4f157a76f7a72ffc830ac28511d1ff1ad98124b8c3823a846e6b6744ddddb37f5097355aa24d510b08a2cca59a0af2f87bef15ec4a254c54ed885aa996772a92a0fb028a10a4482e2f2aa293064b91506136f50b0f22177afb7ca19646f3d9c06905e678b0b437ace7f91083e889b0238c847ef4172e0861cf630c246aa4f307b7a3bfd783438169eb275872576b33231cfb0ccdab6b9e49207d0a37c1594bdccc9bf95b4f16adb91125b3bffac296a0fc3fb849798f40cc8d7c3e74487b6477adec389e525bb059cb44ec990083d650bda3398f2670f9fb92d253832653d78e926407de3c0e9a299c85f3e8e711ec6ca2589be256d437ce8aa0187cfc7385188551bcc5cc35bd4c4aa3a771ade67c165461c5cc6b8044ac17037eb60afdaad49771fc74b6774f590345d4332a35a193e11793a533dfe488798ebdc9f211d6db7508789d43b5222b5835fccd92657cde3ff5a560a12f6539ef49976f52d264952f9b5c93ccf1448671914d5f10cafa084d9d943435a8de50866af369970e9e9e175a174c2381deb188241b77fe8ffa84f983efe61e799c1accc361826501100f8ddf824542173c1a09164039e164d6f9a89251502727d6ed099bbf29f705305a0cf2dedd7d7d7f809dfca8994bf6fe09875be5413b91264d67a1a13cd79f3adf272553f227a5d5e7ae80e90b175c5de10add46d8758be6a990d5d793fe03c18b45f8f9c4203e9d47d41135ccd713d016cf464fae2ac4841550f2e6b6b50764db73689f3f0dc12d15d5d646b645e4417d66dd2bd0cfd1a5c7e0ea148906beecccadb2f5161e822c9bdd2104d00db0c39707025c6b8d20031798fd69e347ff14e6091c050f32a79607e1af4a7fc75e4b3f0d160bd0ef65aea57083f80be8e734dc9b4227182c86749be8e63c0ffdc3e8d53bb1caaf60bc5068111366c447ee6d804f27c1e08f8cc97b43b20cd0f92f89bdddda0c986b3222bb6fd3492150d1ceb54c07450d0c95c0f580b299b012f4e2662401cbaf2e7164c44cd2bde758a5e847ad8e610e653dfc26bb4a8fd0f96a5db8c7f6c8eaa73d377f290952616bbed010c70980a32ce9c08ba3713bb2f0555e3eba1d8dc0fa47e3187241b2c81c8a18f4229c11f77b4db45e1baebd8d68160420072048ed74f2d1934d89c796fd37f627aaa7cd28be85c42f4a04a698c520c134958b364bd1e0c06e531e2baf66cb1ef9a5d3308ea4d316062d3e4ae5a70dff7690226f1b10894fb06fb0016c18250e5d18d4b1f468641a2bf7bbc396268b1d159192c70450882772557988484e14b2749ef6a6ee2a8a02599434b4fdfcaad44e17dc37238776545e166978f90e700e9b32fa7b06a3d09f87417eb39a44846761ffc71ab76340944efeb725fc2454cf7cd0fe8225ff392481dcf6166d5fa6c664c54318a717c73dc823d17946f68e07636d249c587ece561ba8d511b3a7afeede4819c25f932bc82830a94e7e7ee769705d5676fd237cddd9dbc16ad74675e5d95955559adf0ba5add49f56d57fc21c328d8551b4e4a747ca9c8adc2e58955230c3948724f65a120310f8500a77682555bab682cad2d581aed8631dd5b157df5f5664fc8dbef8cf9d05e8b52f4f6e716adeaccb9951f44150c649ad9d15a3a40a8e74ef3a90304c440136c6aa1c91fa5520f461658fafae160de8940d3af52a14b8bb69f8995a0afa852bab2acffcd53671f8bbe16f67639010b1fd09a3cee7995bee7878f86a937c3c2c2ce7a5bfd10a12f483ec6824bcd5f29c46cc7a21d21462f2e8cf0459c2514fcbe4c9a267c79b23e4c77ff8c1c4766551a851f41d8aadaeb6d85a885598317a13060e510bd5cb91e2554e72abda93d3c30691355daeb179e8018710c16d607f561f19b50f60e7b35d03d57f5efc302bd8691b65dce2df46b1d5c8ac06f97bdd825a921c311b66031a2ab1b741c95be9f03d8240468bb379cee7c9bb7bee5994b2a7be238696c49368611bdc0709710fb12d3fb282ab909c4fdf0dfa8b78940d0a4dd55e6fbc9e7f39d6fca1f34d1340befaacc29a5adc51ce72d73b646b8d12a5225bb2add439f4d7cd2fa759e0c191b037828efbb6e7393ffce97e5148f6d9f32bfaeef2283b92e939e6e99cdb54ff52792a9b720c11d5dcc7981b44abc696061a5a2c48467dd9952f48501828601ab135831ca33fd50d376ba1303e40f3adb4d8cb1a438e00f954fe4457e31e82335e9dd0949214d6868c218f496e7ab224917d5bef9fe1edc42e0b2d2b19a5782bf1e8254f01dc68a530181761bcb0d7922ae8b28b1372b880dd5a2e89e3b9d250a66510288b4b9d825f2cf090f5cc692b3907d96edb12daae3dd46241c422f3e9a06ac242e1deee9bd14cef71a9d3b5b91e34546ffb58887f99308d02d93faaaa2ddf3226f88e5814577b664f8846837216107a717a2472b3ebb31f0b6641dac7bf49fcc01383c6600be011d001db6e33d818039994094126bfa80e45ecee5c3137f8a38be79a13b58b966543d1ab8ba3bb5c0aa87052535b87d016ef7885e11393de9fc02fdf0d7af1bf140047ee9e4363de3096e8c0cbdde7904786827e6f7a6fee0bf5bf16206c2d85ffbb4d5c44cc359bfb2a903931abb452bde3cad7c6b4a3fd5dbcc3e215f1f6e9919b3280d0626459408023781ed346bbe68fd2a7bb8abeb187cffcbec084b0bc46c9a16920e03365d8e33720a1b3c7dbca0cf633b57083e2617084a86e73cc9e2df92c15ff65b21c413791682694377e3e4e5d6a64dba185a5d271048606ada3f0386d48ebb802b559f2b9b8ce2478b66718ef6cc65fe7a75c7320f4ce0f59c2692386e3bc1e48c7a89e3fa0bcad3a83d777f981d1c48b0597f2db159b7d925d0b0b1067664a35458ee734e77b15c3fd152ed8579de9020862529f4d2cdf5988a9ee9fd1b6787ba87aade1becac81d45dbc14385d5fbddbd6ffb9b634463dbe4430fe646fe149717fae0a853d37d73f013cb45398e289f0051b622e2e95f60daa99807384198107dcad20dbfa3c4628f414d24a1af05c19d754dd1544e3eb0a926f6b6f5ee84afd3f841b3cc5cd84e3f7ac8bbd9b4deffa71078430b59fb971fa2533f5638235097bc131d2d9ae5dd24a5a9ef66bc27b3d523adc040c65c3d67275133463ae257b4011c4a306f02ed9426eeced852daf744145093d2f00e49ffaaa1254edf8d0ca89b1b9f0d6bb4c714ffc0eed05aa429c990333356b73fbc04c1606e6fd60665af331f459139b414e874f2d4d0fef235d742f862f94c98eeb11929ef35c2e2fc8e9630dc4d1eeee9d01d9d35fb1f88f6a5fa6b47d2c0002beafcd5d8f29e4a8d55f91f8e54985ed66f73c138decc0ad70692d87e2b8a0ea0a9eac56d625979b26e921301998dd4c55d793cb6afe656419c1e01bfb2173ab1834bcca6adbce43f157b0118ba78c8770af814cd5f44590ca45c91a3273e8a35e22d3a615642d26acc2766ec99d1d7ab4ec8dc28b40f2e476d551bb74a9005d6b45a295d6e11fbfec13d54007bc8d4fa94fbd0e1e55c5892bcad0c4588739914b9ef6e796ca6edfe1732f007b3c3fa133a75bb4ca807d3b82e2dce26312e1207031b8e95c9b3a2971a8fc5451cfcdda050ec9e9002d07f7663e037a8b0b39118ffe0c2da986d5eb6237e01d87ebb8eb5baecf8875a66e82714b7aec3e737846cf1b93b39d119b98d168fac225ec6293bea9f9b7543b9a6687f681f650e43f1580eb400ba7dba40f6fa1cec80461a9fc2e23486f32a34afa5e7283fc59bed2e3981032a9dabc4d812b5c1d32edfc29a1db084d96aed93c0d9c595854b106bc97d9b366b870235e0d15ec51d2c6db0408d557894af1a2bc493bd6c61de903d8f6c2a0a796e9d934e8987c644c4d83f840a0b9227b89ee44266104ea8bb5d03c0bbbc547c4fab3bcb18f69bad64e96f78d2503a716aeafee2acfc659293bb5028ad81eec5a705305586e30e50e22b9ac03a08ca872b8f3bd1e2fa65cb0cd1557ef905b809d91bf07b60a5972f5ac9c5425c9c1c68b651f372b01364f695da512caa8fdcbf9f3ccdb6023d76c1d5e93f9fab39cb11296c573210bb88200d8e5cab94eec0dd64e9c074ab2058bb8fb41c1d9df1f9f84642dc688a52878f4f07b41a303d09f292d0196ebe0522e522c1cc11b1a49e0b04b849d3a7e6d7f8273789b24424ebeab1871e75f4d466ddd729c56e823ed588257584726a50693e71be1c8a056bb3a957d903523ad2b82ab894181f7b6a2b656ace31a4ece70101471ec6b9594c913d3d99bd8866960247469f469a62fc392ebf6c55f610ef89de8be05314770cc4e5d453cc84fc7cf25000a10ba1a09c3586bb2cc795f9088c2be8c22f59452297331da6373b29983477c9d2c398302ef5f93d8f34bce144536af99455346edaf8b450ba7cd424d36c5d28d7b9557dd300f71d2ece159369ad3a89849eef017831173848967ee4f2b3fdde48d58fa3c45de125dbbbe60004c7501c4ee530d4aba6e1450dcfe34146f651668bebf1d62af42c1eef7b97ba7a7756924ef3bdf0368f74253e5c1e023e084b8fe450c8779ed472ebd57f253089ceab70efe2429dd4464741d81473418e72c8f1475a49a3287f0334773d662ee0e34e8b1e4b5ccf8b0611f201a736b481ffcd6e137b1983eac9f94ad7552f6571015efd6b37989a32616c660b4135024e8f17f1850a1012096edf4cf4541265e602b023debb6217b608663c0b7494f9b05975467cb418cf6f1f621be05134f76bfe2b09aca95cf5cd2ff8906cd9c235973d2214fa94cdeacc8303e74c435b883c325f6957053fd00c5e7669ae947972cb6e26674f304e5d3b24289e33e212290f67b8c7e1b0e1cd52b6bbf5c4f9407a2ead901274540e4091afa8ac5861e7899f9bea27b352617ca3d760303440195d9166c2d47ecf94dcf96815da08acbbf6db2fce681b7ea9cfafc7b6625ac43d3d6c7c59db3a7a800ef6690cf246a9d842ba92d026c8ca158eb0f30792cf0c87552bf64e434c57b4d1c440b1d6573becd88567b38a698f471d6a1fb484ca302c605197600a23b1d3f8a89e8aefb4cec68a7491cd69d5c48e169bbcadc35707ec6084e0adab0c0a3133a2e69cd89fb2891fa7354a5eeb947c290e20239c5ce47fa94177834a0cfbc5314a28730f76848a89d70c7ea25c33bcdde8a6c62dcb610c6fa9d13f35b74e336f104afb91d2f12dabcec727d83f7443c37a3d9c8a2fbe76bc5e2b4770692a4d1ca80d3a20044d89cc01401cfe144ff9978f44d80146e91c4bfb6c054ef5ae35c268628e131441ca2008bd1644b97644338700eb01fe7bbd5a25c9979bfdde5c2a7b8747eb259d08d942ba63943a5dc74277c7506b1a9f74438b612b3399afc83b5f1fc4b201187c4fb8373bb5fb40d5b0a75ae2f7eb3d3466f5c1204a7940b61140d08934c2be2b4fe8f25a81be14cfe8b5c97bb13accbe3f0cf4bb0b104179cf60e039915afaa31eb333e738f1add1a7d952caf97bcea7ad6df4aef4fbd0709c6f494f5d913d78f7e92fc9cc1c6493043c12677e96db98977af7eea65d0b0b71a50190a2ce80361324bf51f352804f2a2f177849cd85ca2f937d2aa3065e9fc44805e2fd065718e5276d55d14f633aab77911fa0c834a3eb62ad35944e021dcb242a67baea6c72b38d532475daf5e847cc130b2cd3af624541ff553b8f13ef65614d51a5b62fc38d7663d7f5eec7ffde6e017e903b976235cdddb8074e3590b994b32652ce5445fbb6dde04ca117678103a66ec30759cebcadf5c62f7746c400a55d91f3d0768d58ca0d313ab1acecb8e719c3d05739195aafe09ece5d08fdbaf8d848765395c01d53645a705bf85b42d659971e47cb83f03dee627c9953817c0daf0be480355bfe19bd3518b975d0bd982fd99acc3964b6fe9918dd60568ee7c14a07d7304b7297731062232fcd6437449541b1559c591c2cbca02563432cbc937e18f4b46e1167611b26767e38a2c83d3a9095f18f5edc6024a179a7766e4cbbaa53752b6aa5cbb3fdad46e8d6971a97b8c92723f75e3e2e8eb23911ab230cfd1f4c105b1ae11c0836b1713cf6283024337af0d3f3b228a7c24473b06aaca7f40e4dca5f110589fd89a3cf11c110112a30c4d4a70a97d7f85611602a6f52cd094fa39cadca294a858ebda3046302cf0c9d53774fb47ccd5567de78796d4840baf5deb933a1c94f7b361f5f2023ae4021ec7e7bce2ae4d8652fa8b35acf96472458fb7484dc075cc8d10f925c4dbd9f271631494f39c81df9438ac285d9ee9318b86c8d8aee6fca2ee3c9c307fe7ebaaf905baac5fbdedb6fac300332e1f6f531ca0349c52cd50567631c2c5eb447bf7cfdfd86bd99fa5eb0c08f0779686e26503e40bdea416d7675d5271bf7b6359ff1eeebcb175964830495531bbb4ba7ea8fa46f4ee1ccaac9caf828ee12ef7eb9ac507e3ccb5aeb37d7d7306c78bb96604f660372353227b12725a1f43610b73b4bf00d19d83df4960ad8e9248491ef0464c8c41235c850b63820766a32fd3eec388bec29570d82139d07d273532f1a006c4bbcfc294bbfab3e84b1601e96024bf3a8ecbd020d46eda126b2516048339e266a131ccd5601bea17379e55a98c4c89c2e9ebf264bffd806513627426d6edaeac84554202f16596e0a1e9c9473c99710dce43d2d99f36976c4385f2e1e31c8fdf1ee649c7ab6aa52212cd238a37c082b8cfe9847e1b5dd48fdb434686f630aed0ccf6cc2c731bd53147f3db76537ebcdc1e5e29ffd702d90f7aca6eda85d58e973917d86aabba97d97a481f938e468f689017f85414e0a471bf2e3b068c85a461b1ae3ebb67bd6241c74b5b7a374972a73a9df61954fdbaabfb0aeab9797b1e776376e6ea6779c6de4a67868ad2bc4b51e1b2a0abbb15f590ef978980e851ad72bcec395951ec451db5ff5a45ead43cfbcbaa8ad7ff8bfac3abb34c9215c75d02467e004e251ee8c8440cc12a90b5dc56b05405348af8302966235c23df26a8d8a7efbd090bb96a36c1151475a3e4bd502c7d77be530cf3b42555898460313b6d1a7bad3cc4feaa955b3993c5b0ed1c0799f95f7c50e667e9d68c289273f5a9f8952bd9e41e65735534a2b1cb6ef20d4192ead0e4581ab10fba2b1ae5c7c683ea6aee3175a86eb34231f89474dc0c71fb500e6359a3982ee00849054cb72cef7df3560aa7425510ca314982739d015f814b5b5a8de7fa8155f98a2cf670372484ff0660ecc26eb432be65f4c81aac8ad678dabbc7a7c8f7cb076f048c4a4083abad760fd3626d252666650ebe97381ba98e847e35dbcce0cbb3f5b3cb1efc2b0ccbd8630d80c0a811f2d320f9d32d4e2bf5281c6ccf75e4bb9ace8dee1ee0c954339b1bbe8d6d473745459e345064ce2c2fa979f37232a96c75ecb655cfcdda15f7a56d6eb90c32b572d506567e717c22648c82c13542002f4d54c5aaa562870fad3ad5ec3ae876ae32d0a098c2ecd96dc57d95d0e3674a60074d613448ceae32b7aa244ad7d853d08c26d5113e8e2cb7414a7a72bc1bba59f56edc8c0356ac70489d0e9327b85091d692a0affd3b8e0b87bee0e238090be2d1171492d44591e1cee0f14b2a6c8c3a6b6ba6165594afd5a70fac31e8d3814e4aa0faf0cf16a44806c095f79bfeb159cad1682b0ac2056c98a238b049400615001d02d306172843045b836b86abcdff4fae3962f2534ec3a593f596956224af31e966c5e9dbef7e1d6946797941bc2fd0f9fe6889ad3b4ff6923a328251315c25c1cb9d9b4b6aad57f7cba5167074684e75bdc3569f14381d39ed5c8864076bdb45f9c1937f02bf0945da2711e4a76884871dcc2b0af8f0278b6d1cf6796f91d78f6695982b6c8696dcbb7b2dab9104556f46ebaacf2b39dda64943a7e691471db30aebda61a0827b9e04e634f481518c350640d4365b3263c59294bce18d58261e556ea05b9fc6873d4d7d6771a680c669b8123ed431c0c4eecb92f50796d3f3d6ccc77592fddda251394299548cd4498dd5a44ec6deca615d7f3ffd69eb887a9e95ba9e7ddb04aded7907d7a594aba1b9bd9c1bbb8491456bb640978e094ef90e353b0a017015439c32066108e141dce48a238e0c13124ee7c89746f0bdc52bc9629ee9daa5b2cdb8e52a0e5d8f8ab0062e22d940786ab1fcb340024685e4663968140d27d22e5deaf7f37b28531c64ed40a6763c10f07b6d09ee1f8cf72707f27a6f306174370d7a3934ae9e0875416141230a5f89c5dca3b0a3bbc7dbad79c7394e8bcb1a69d6875c9daf3d31755a0cc413c71961992a122729a31c99e1b9bdd4a7c7cc3fe2c9c0c9f728531fdee776091e16140c38ba677929066bc2f26c650bf24e72b4d6df0d408dec789389704b58b48bbd4739ade47685c3ab414037dc15b744873be0ba49ee0f3e348c3a126cc4e63e61f34fb8499d1150cd2c57f70d5d8bbb4df8f715a382396f757b301310d6145d708961b84130f05325d12981ca1be599cc7afbb674d81b161786fde767ead4a1511db3270db86819266a1e782ed82a6ad431457996aa213fd99252a12a37ed6048366c0714e693a5bc82ec5500dab05361cef4de8598ea340d3193d6381809aae5f492af81b9885accf67850621ce72c141148259eeb68017915b6097193306b87c5007894dd269ebb26b923ea1f8a481f9927e9baa830edfbe43b1a2fa852305f78d7ec7cc9db9cb828d51973eabf47820181219c36e4b4f139dfe143d5541eab38641e06d6cd2cdc8cb374cf0aa040501ef6eab901c74cc6474f1e0b2f9884e57cb1f3330e8d14d8d26a1b8ea2447e90316d240250e599c0de52d45f025f3ccbeed3cf613355821cdf8dadd66dcb421a72097c18c6db139629c81463ef16c9b8263276e03d3e0fb90a4dab24248de1dacb2632f9e2a89104a1a32ca51d9761d69bc41c411bf7677ff2dc25ab2e9a135ee5596419fcf0faa9464fd787bd0857bea3971a15e3bdf07bcd00f703675febee836865014b61fa21a673df9d8b0ff160fd91109e66ef220cf4b36ed020483810700042ec207b1b87be645f22c7576ecf9d9eced9b29810ee24c4702aac0a5e4a32fc8028917ab684d8e7d66e12d5f090485584e438e5514a06a148d8e3eeddcf526952b5ec60c83448563a3fcc20541e8824604d82e0187d664815a88e8681ebb23981d73af36ef2c8013efaee2373fe0c668a96bb663295da9ae91ccb2f1530421080cb78aea59fd8c684ebfa59f9e7368501bced571b1a1196ae743f483efc121f873a51f98b7036a0251068b8da8189d9fa458af3cba279b2d37239e0acace9857c557cfa5528ec72e03c1e36de5bcf6dee2fe93fdefd20594824c929c4181b5c9fbc927ac0bbd8af6a77c6beec75dc65f76a6d5edec9c00d9c630555557d10576d03b2cdb9f5e474f135a29330e5dbd83e56f6d84ac2360a08254b1e81bec804b8d87698b6b4d74d92a1ea357833fe5e8505f79d4bf8a52009c89d407d2bdc0a601d4baea7c22446fee16e5a8653d9987705cded73463295b820e7515d19c552a279a142f37ff16eb3a4f9cee52c8719bc1dfe1c30f8b675c6c2a0a22c31dd419d192df0eef48c2a13caab140e87c67a2ec368ddb27e1b76b829b78c800077d68ac0a451f1a0aff878f8130d89498c5e5b00f26a964b975d73795afde4c10bbe2ce30aa37a95536dcb63374984889c8d99e54ee3f12ce441f903d0a075ceeda6882925d26c14e12e149372f40104f96443e94e6230d8f9bb5bb01a175db0de6314f59264d3e9f23ccf46644e50318930059372d5fb50f47305a930ab8edd51507b96a630c89ec1b09ee9af3e46cb1341a6559c2eacc52deeb963bf11b0b5e2bdda2401cca2b4b740b80ab5ec727b23b64a2f845db0eacd5e6b70e396b89cf1ee6ad790f3cebee5a414ce77324b99d83501508588689c5e54f80a26e67d5cb0d4a2c92a970e4005ea3d696f9ee041e37fa666ad29ba2cae874adb91d016110653c32973e47043b65fb70aef0c1801bf4545bc2a9cd715c13ab847fcadb7300b25ab832cf89a5644ddf951b0d0fc6b9dba2dc90bdff4e4f23bc6527449282fb171dcd9f540b693b7e9c9b7d2567b6f6bb29600027795e9552cdfd9222e9f9b45cc23fe8bb449150d48ddab838288f0c6c22ea2049a8c4dfb948d13ef1f374efe9266a83f57e31b70cbf782938ab0e3b0cda5a1845373836c02979ff4547bcee9db73a166faf4823306a69361a1bd6e67c358a83db80f760483bd38b3f98ba6f0d5e372a3b3b3cfc7d4f590753a4a2652190da777eec21c9fb0957b1ed16ca33b8dcd53a5b64824b865ab84904a4e594f58de0f5e434402062f17aefe5a8586dceaf7bb9f356e411c89b8761d9306088fe48b26449cb1da3582db37ad0a6e453eba03144c1feed40afa6fdd44f5a14c78e4c6eaf3e44c41b6e9d76802c8bb927178b06b64a1ec7238e75025ea8da439faa30a46c7c73e6f65f54777293f45b79d2b8f2ccd73cb3d3b807bb2977853f1621418d8d46bd8354f5fe527bfe8209def4ba7074f23df7b909844e564d670a36a79afb0801c575697597ddd3248fe673b6d174e3b98dcc4da186a4669c77c2bf19db11174deeac4a8ab7bd68b1f972398674bd5e11944c76c7f8a1c2fa5128577e75246434db2a6bd3633b0a0ed998fe63dfae8e1813ced4e74dce590b31bfbfd180665bba5917afb029d4868af2011afbb5c2853df5e32fc2b411cff632f33329555cfee643edb4a6072bc306f8c2e323f20329f947dc37dcfe7c7203b477b11187627e5bb5cb3a19da81c77e1d15c9523e7d3352e1c2a8cd3fdc419feb8473979914a1451d127b38245a20cfa4a730227691e90c00f9481ed358cafeb9abcbda4d31b605001b8061fbb29eaa69600f1f61e3802549d563dafdd64762b2d8dbc9fab8a95bb7155db20f0bf1b3853f16028bda558e74c22c0216377f9f38a04b292a4edffbccee6e129789f4082f87133ddd03fd0fef945b14418145a2d249969eaae4e87487ebeb4e919d7fd7801b3919625f2b5122b664c007889c3ed18e470a85a56ecaa07eaf441a4f931a337ebfbe0775f379d400270b00d5aa0d676accda4d4c25a2053d237def01edf3546d5dc04fcf6b74df47a2597f0da4bd91d0fa22a31e4c80a201c0ccaf25f29d05b4dde26a4ce7abad9c623493136ef69b6996edd243f7f5831077eb014abedb2717522154f51745f7a2b168777ca5dd372aef67949347e418df64453da75bd42e6a9361603d4c31d6fc975aecf6ac640a030b041c54bc51a4682d6a061469c4a3137530bf3dd570f5713465b4f1b7997b68eec57bc07ba9986bc95a9218d19f2e7e4d0a00b1b988190027938d4ba37c82974193af45c7da8655174374ecf92e5cb09e0ef851f12ccf24cba82c5cf4f3a1fb37b0b1ababdd3d2ad9c5d5af30bdb24da974f4fadde2933ebc043b5b4ba7a03d73399b9de40259631ab603e56e71ae6d672ca44acb638a521f3e4b5d2958d1099358e125e92ed7222d8408ce5abf39bd6a366a9a2646c8068d90746670bb17ed8fcc121a5d02b0c76b215cba6e2c52558f42183335ad4fd20b4a5f92b7bcaea8106782c97d8f6e71b5be756317614f4e286d1ab4537d365e8a7cc6f88d8bab847eebe4df0ea17946e84943184984e2fe005f6455522bd440cf0c63d17ad4394d94e94e2b271e923689675fedab561c7fc174bf68991d2c193c19f58aca5d0ac7843fa602989b3133cc06fde59ca414431205648e17d0bfbfa443b6ffd9e86e136dfa5908cf897e12b7e135d231e03665aff3d2a07ccc0d610a673aa18a11096a3790b3e3b8e2093a9ca9a311b3981c8a9c527498967fcc4d6dd2d5a24fdb73dc7c1e789b609b1a98785b531f5e0654f4b7a6c3b2a7551dafcd2ebe31501cc2d98100fae411c2a7287ffdb00dbf9fc573dbd9b0107f432010d6dfe750af3c0cd5571433ea23a5f5ed07ea548bbd7f0049090c0e0bfe856631e1a1917b91e2fa2ad661f9d9a377d70e002ef3982576e2d2f34762c83f9009b550891b2e8770e4a19d224c861ce3a98eae78b098278a0a00336fbb6795a36edfe779c814737844cce34abc10fe1090c76019613c0b0758fc64fe63d5e8b0debcb0a857bc1d5bb31ec4d038277653e2a96f778bc7892a61b58a18af040e095cc2ac7b24d60244e4925098d0a090e77714680fb013e463b8b69b27300a11dd702f3bcba07e0e9ecb839dda4b3002d923fab7448331b8e5e16c4d6ba0901da3b82e9b514629941729cad9ef72b1e77743f3999f3a29736ae664b3deb89733b81d96891665e868a462193afd5387be0a880e08d701c0f2e94b12fc6da01ba282cf8e344dc3f628cc913f9e8362b639c5348f6420146995a753221b386cf33273a616fb26d474a71a163128244683199a59ae8d505fae6fcc0c30ea4bfd7b197f0f48c892c521261a1a046388b4669352fba0d60f3914a27ed558199f8ad29d1427477d18fc0f8e276bd505f8ae6398580aeffedd1680c2f31ec0909abfdc24a8b6a552b42ce3a8e4f90fe6055d611ea39c82bc39605f23ebebda03414af31ecd7495e3ae8c1c7bd9bb3e9395f5fa7faf206102713729d88d88910f6c01a8eedff68fff6ab124190d67812b18a872ab55506f0e600d47aeb6dbfe6f0b34b8e70927c2f5e5be72611824532b85ae0501daf814eb0ee61f2ffec7d8374a706b3398d2476731476f63ca10d74243a6d031c352b6d11fe5dde7a7e0a54c52f5b78da2448f82a283c5a7a59770366aab6c13b473dde594afe9468c9bae2fd740022cdc0309c6a2173ff2a8dbb6651ad43a194613158d41998c8eaae8a3ea36a47a768571b2a55751774227e4c26d435ecc15dc5e25a2b29c9e10b2898b6be287fd32c4f71b13ade2fa8913c2aaa048492d9a7653997df15ab5af23879d4f244d006f8916760c4348459142ef7630394e0ecb2d35eb51674d6cb1ba014b61bf8267c13e5ca16dae488c59f5985a6c9f77724f3732891b9a150659b667ee44a58370400129c4a68ea9bc6bf19133582570f54ce79e230e2bd4111897599c68b31e4ebedb2815ac5b5894d3720ddfaf1c19a059462f419784c73bdf39dfbdf168765b3ed8ebdd13284f1b50662ac6090ad2d283e3034cc0d6e89bd0c0ab41e4b933c7e4972ec9ce6d6d7403970d991e1ce27ca87d1a1dc06135442db1e0fbda96a7909bb3c339fbcb0370616470b5890b4ecda4a5b5acc0a896e41b6e23eebe53e5af563b79de45ab7ac1e97dff8f6af0c14fc19c82a264306ec8e26726f5529b5ec335bc6359385700b3cf0e4f1e38d1fd3e04f49147603dae9062d41658e959bde256d5d92b708f2aaf4fb9b14c82d4ce94607a6f3fd35a08dda14fef08a21c1f6273302c8ddc51aa97eddd0491f14cd8e7d90229e066c33d6d409c7b5e59e8a1ebbfee9aa1746535eea771af2be4fe30d932cda627aaa8aa741d70395e93b8e1b4ae8cd9da8075d97c454633ee0973cdafd66383eabbf8671ccb1124fd3e789f555058d92550a8bbfff4cd11c72d58d8ecce87869395bd7d54bfccd8e726ba0b02a9f955c6a3c4fef9cf132fb26a22d1801b0544fda82c3a9b57ff5d7d249bfd0d0c07f85a95b58465aa9285a0c309dbe37aa9e9caf8657fca5b8c14d0cd1ab2421a006a230cc5768cc1a7167fe3a6589c487449ff808ee18bfba8d6d8bc017043063326dc241cac1c9e945422ce40d0095b26d2c6b7c41d03a46beb567a9b94a710f56d4cb89eb044e0de30ac82161b2a9c0bf544286393adf0fd04bb8a787cc653773606a73cd0216e03a057bd55a3b5cb065c4d15377bb5e63a58baa993e1868f077e8dcbef7ebf4274c262933f352c2148dda271cd4af99d4b7d38ed4ea9517ea033f702d68ddfaff767554145e73361a04efde64a1e458b851f4feed68962f247bc723d2d0815f6cc2cbd468f7eb73c3a7d741bac64193849a4934bf791976b0a6fd324e4c49bf5b2c40c73cd72d6bd40f4f856fa799532584259b26badd664ac8dff35f611a70ba30c4b680fa9424379ce5b1b4f5522fa83d7422f582554fbe3b82a320968e1b93dfff8e0c296e4a6928b994a131b19d9d4bed9c4d97a8e773a816462f79fcb61dba075e344ff2c264b54c322ee52d5f9e7858b1ef898d82f6ac335adb997f21656d622a8369c94eabe20bd6a2ecf0519a4af85bbc8e59adb25108774f49750eedc8b8cf545325edcf55f8dce981bfe5c786c6a543cdfdb2fbe489cad26c8e782f68619e5567c7e61d7b87dc9ae04f9b11a5a671a27d6e5e7f3d941982ad3015fa32c616a5897345373e50de5a79be3b600e2b99aac9cb9f3cf954f91a9c60bd690d4e8f317b92d0c1ff8d056b267437a1610d586d9dd0d3037520d5b76e64ca388a9bc3971f62e48cbcdbb9aea487b5578bfb9524a5a1114ce1bfbf36a2387e4f950f9890493ae67ef5c6869e9d47ed26bd1b35a95d2daf493ce59ded3fb70767f4325390142dd89345ed8a8426d028f99480f9b103faa55204a0863fbc6514607b9e6cea34b956b2e5c9d4a3a6aa9926f64e0114b6a083bf383ffbdbec220437a4a73b5eddad521502b5a42edfe93efaa4cda1cae3e40891f73043eec5f505175e9613a1f2038cb0a93b4b0bff8c0852e15a0bd1e4a72b4be48526aa84d647629e5be8e474d8d62bc6ba0fc59656c4cc0861f129de714f51423d36161bdc0e81a4cdd7442c619728c099a33bb8a92343bcfb19b79d73b5bee7705ffe2bde3db171290c6c13e55cc22b6652ce1c9f8257b5be6b5f54aec386f81284b6d28d2974990d771be0a48d547f4e389484888790c4c5204b84ce31e5b78edc11b9820954c7012f56f3158a32129c1e693e07cf7713116b57d490b6f30e1974873b296e3e75abaa02512f52751acee30d4a878c71d134a7b4d812aa8839350f398f14033be1044926ce5e765784e9918456d2f2a013cca538bd1c3e94f21f91e53bd61335706c5efefb847de14db1cb6ef0d8b272ef24ab90ee8819828aaa76a61d09e88e96a02f744a6e15041cc616941ea3f4f2622825b8273b06e138a08a2360b5c7a3ac06c1a1b245f142ff933277cc05c199b2f5b4adaddef8edf2c6bcfa9a52e69424a07bed5e12e6dbf5a16ae4b5c0b97062737a14c9578d4320ab4dae50658a3ced08612c8186d2968b2bb4cdc726deb27d49d5c1268e23dae1d3fedcf9fa927234a3302f146de421663d6e49671787be750c7041b281478e8b19420039fcd309adb64829eb00b837699716bdfd4a862c57e5d08f4ad0e006f9ebd6c5219567822cfc1bc797c34241152b0f3eeee1a64df1d9da1eb78633244bc36cea654107b7eced9ba1ae0e1e1cd6e6abd98d63934838f1283bc84ac618d368629b880ce7d3ab6064297812537aaee3a78c3ecf0c2ccdce0307f0c838863eb6f9857bb616109d3bf89b8b5a9f8aa11e1f6554725420c95b8c6481246ecf90f857760f278ef52d38d2504db69e73b908fc3afb9c3d2b64960e5a676a52302cbc8d3882593ca017ef95022b49631623dd0a983ea044aa8a889995ae1b56080f330927abca77b56fb0a03d36e847d94b37940f78d6738bf94923561718730b9db661dc11621d242ba704834ac80e88370ffb32835ee17caa74911a8e3c39d9c9845bca9bf2f37a1ecfc0f52ca45d40ebd21e5a55ad12f0480bbd6149fbe45e2e056bcc7d76d6431ffc01247bd62f2575f99fe6c16c81ccec447e3914ce0712adb9dc39dd5b714ca975d1a9de98d5ac2a8258a0bc09d2eb5f64bea5934e3d13efc1fcd6822362251caaabc9418339c84948221d9416aafee0a49afe35b76f73e3400c53de520d63ea8662f3a9d433247c3d3b4064f9c1aa0653a4779a6181a35281b3c36ee2da347e3a4f8f4d652d6922325c64108dc9981120cf396bca6b7d424096ccabf9287286cee0b91b8f98af9d5056e211676938f6741788099aaed6bb4fd60f12ab6fdefc0f1f1004caa692bf0d1b0a61199ea8b0af93e199a7587561eb9e4556b2022e3ca793590321f80b039099ff13d39eb583c90b2c31063817d6326dd181dab77b4f6c9c7fe8b8ef8a47d786f8e7074183f523dbdc03f3f43b801165b1758c4c081fbb7ca373e67978a45e2d6e52c6bebc7560521a1fc6f85d09c614309a653eddec47434f6ca9d99ef70093ada05ee153432e04f55320c3a0b3d03799c546ca78a93b2c11da6e2a8999e25b490abb539b7926f7ddd912f2059ae11bb543b86fd828f337c570ec315a2e3ab5382a1a5e3505a7f7dc4a60218c7211202206f0e434d34b7472dfce5953aac2df0a6ff48e65ae3966c9ee290f1192fc5368e0e20ed152f3619d26495a14fa00d87afae538f7fb928c48c86517b21c0557ab0f277a1530f33d70d26765b393698826b59a58ef85a43a8453459c08b02c258889640430b1ef39125b83c7b03781cf1595309df27c5c028a2cc180cdac9b71d5dc7732eeef1d2bcfbefe8d6b7fe859fca62c7ab6b0e1c49d7f4db0fb0aa5b9a30b5091a08ba2f73dbbe1c7f8598d4427263aa827b799909ec59631a235ece9fd8fc8988098c0ab596174150b37e1d2062cf8b5b7934e4cf692b5e129154ad43e93025d03b7df33b91cf7d284c3041d604b1fdca815cd768c3bbe82a80a82706aadd2b25bfa8d7604321c2d3a84f840fe3010844b4e80b7ac707bdee849d60b2126591dd8feafec0db2cfa6cbfacdf32ced7cee9145d1c709e918a38ff7b3a8e716e2baf5acbc26e88fb6e7afe3261f294939e437e679976be203ff6d6dd8a7ab7badba7a9cf4c0d7764608695014e4353d9792e0bacf438bb4b3f808e0d4eb0527dba6bbf787239a77d116e2434d4a1ba67e468f767986063431b5f449b2340f5f0888caf7004303657ccb0e96ba142e5adcc999bb138b8a7da59456052d6d63cb6d89d93ffd0b050377948a7192107e738bc89bb714b4e3ba034d555c37d825f3d78b956e060eb4ea9ce7585fed402f39ca8072eb25f9ef25bc4c3530f53491d0b7b09b78c96be6790d716f6d1cbf3955043d4c2bf16e428d3229c69b29537de6b062e07230de924e4a865baa037bbf504b010f9a749b90c74fd0207fae5f9c012af4e24069a0a0d880e699dc118c50df311be54c0cb8fb2026bb0de5f8a2a4665986333a8860e1a4b9d2de41bf72b9fb70d3e8fdd2af42e8024f441e09020e84c8beba850aed16c564ddad3ac62dca2785083be35101440902e0671eebe33f91db3585514ac0c8905b97d26ace8f6537c827c1b41b86fb8303129c8adb8f2bfff3398a1f1c1644f06cf3cc9a5a0956c636e343fb95169bd0a6ad419ff53066b9d7e5420c0354da2f69ec897dd4bee0573859c06af1f839b979faedd3e685dfd0fcf9e263686024fca765f04d0f41a0ab0827dedff7d35f2b6350557a8f6822a24f0441e5126030e9d38d5f51966550179b8927114f97bab8cfef408ff1c7bd3130bd5315d582147edff2065f21de50bc4bebe613aba13b18081a1da9c93938fc4a5e3d2dbd315cae299724284e39a3844cdc222d682715090d891629c542891bf0f2406be8c4b26af2c5b1d66cf2f9f8f53e67ac1f1e446bd50744252734ad3f42d7356d2388ca5188cc2eeed176280877972cc13a70125524308678396488fea9696ba061cf9de0fc157bf4f610933606d83fd810cdeaf11847f38a3df6fb27485843c406380dbe63168fa4d2ba72848637d1b26a6c6af0770aecf4c11f14c8c6d516850366b55175c3186268a8ddb198c1023bf128704f0edbafc47f38a4b2ed980bd94313228a25cbfdddb52f041be7258e1481c0b5bb4a546180be13a7e69cc7ed578310a8554fcc08e29449bc859ec63a4103f9fc4d946c1633c039afd10517f552ea59c93ce18ebf5674afb7be3849cc9c8e5c54ebb69cee796e532937e020c40c7aa78f7b819ef9ea665088b210818b33f2a0deb6bb7406512d50b8ad99502b571bafa6d2cafdb3edb01a68f74f280b9a56df359e07566d46e91f76ca5e66d96b4e3ebf45c4d9983fb91f66337a42cd574a4d3ee9bf15b070fdc130fd5cef68f7133a22428a99de1eafc6dc09e98cc970515c4e07735f486a6d6f2edb3d35aae3daa427c4f8cd56d5179806a783073e3c6c2c6a0c6ee62f66cef7133b56d8bdb94280cc6a900ce90ea4bfe52d894940a64964f7a003e5fe7d5cc0a6f158c3dbace5d90b6ae090a3b1b9556813f4daed2f09f48c42619854dd17f98904c0955dd4c8ae71198860eb7077fe8878a258f276d66e0860ddf1ea135e860d9ec29a86d8da9e3dfc05716f528c2d211077fefb799ed0d20a1e33a900ac310842b47396544647fda2d62bd4654eb79faddd3404f7633936331b43592ce76afdac7e4adc642713eee048718c60861e399c55f1afbb40b5a24a600dbe5d5dd79eb0c86d6e7c7a709e73ba68c42e08c79ad567a4a6495cc70de31f2c5caf888caa3939b7d076013446794f1aad97994a4b9c680f4f0a792b0c8eb6eb4beefc90e83c037ed937b039665cc3b75780822a5f49cb941db63367d1c04456ac4be900268aa045634461eed466d48df7319aaafb4c4f789eee4fea601992eb1087a0707e8d19cd1b92ec5e4718df5846e2f9b574ff4f97c8e8bf3082cab2daee684dcb2819f5107f98fa8876577774fe4a9b2c0705f9d51378290cdb87df920a3e632fad0e7d71915c5cf06415948d05bf45e718f021a42286c7a213b9a05c73159a8b214ecd0c752798e6a64c697a065db3982de687b6f5a93376857c5ec767374271f2c39a2352b55c905a49fe0678579e1dbad955aee2570fcab949686f61a47396b94d318b9f59b22f71e84d98a68500fc7fa1ad883801795e0d65c7bfced23231257f588658a236aef66a7c6e98dfd394d97d83be0d62ccfcdb29446075cd0a2394aaa116d23fc901bb54c71ee52936d5f4a650c8680a17542faafce12ba24f0a92bbe1816f2da2f075b58e12785d3d701d11c4ba526234114040e03579dc1497edbb7faf00a1d063972a90e972336d6996827ae179eb331c8a95d944bcd18e925c89c5df2e749d9417e67e9af6ee3a8b56829abae37577cb038ce17845469d1885ad27f74bd791f8ae80ebfee5ce46aeaa912e4925a2487a0f8dd4ddfe6859a4a438864bab4474f182d691ccc48dad79963ea86f15d076bb0a6552b734d50a1ae96028536f16d1d3246c26ec94a448650d0d9f0f4d35503ebcf129da035060cd7413ddc5697c197df18dfbbf24b433a022e1c247dccf73a3037fa817f450775c84ae0c34f2046ea780e059ba5d1beae0c904f46fb2aa533eb21d7269c241c2f4118f2f2015221fdc0c46bef570dec4b26ccc210d988346ade30bbd40215978004991f10daa1ff85cff5f37f2223df8e75aef1b9797369b6c7b40128fb4e3ae6fdcccb07b6fff03425b5da4ae14582a002e04079589313f6dabefbacd8bdbbf00d6e89d0772cf7fdb1287476530a33358c28baf99d9eceefb41cc6bf93dfe42c888d85648ff4c6a322ee0d826d5473cba9b9cc10fc2e5eab8e4a7cdd62df22ca9a3452cbb64f5eb7bda8261abd44b0ec86e99f828596948af5bdbb7c92b98bbca523568c8bb43906f6be4e38ddc22b18dc87a3b4284d08c06736346290c87cca8df1b3fc5293a730581b4d19e3d8c31bb23cd27bdeecaacd9e22d4def555d282e080a9f8817be3cef5bf4b4ecbc83b126dd8c1a542124f6454fc0a50ff6a451abe16dfd7c310d4e34ecaf969f90c68ea31e0e4e80ecc2688915d3c353a4529d4effadf88fa3a03769673ca47f58d3f7b266770101dad5bbdb5b7344a870ecc2322341ee0909d91e446fe154501081dd6ac4b01abb715505863faf75ff3e50a9d461024e09710fb829ecd7f8cd9e756802620b38313cc93d9ac39c70c0df47c334643f225d2e1c98022babf1eb003360570288c8b7061357381f3c982c811cdb006d6092bc9dd2f95c86a558aef67635c941eb1023fcf1670d2744f6a258e2e865d9484fc5e356287c722b32714229a4eb2a3b523ed8f4a2005020139cdac6407cdb578be85ddbf3e7003536ba6a28908268b1f332de7e9d5b84436b0d52bf1cfcffaff5fc80e4d7783b1189169143c58519e7e024829ce976fbb55d046e101fac6a892f9c46a13d786d03d995374b36e3f9c97ad2c6a4956be5a4d5631795b241d4f27f047013d32b50c658b082ce23d954f4afec7f52eff9b7bab64243ddfd0d0367fd7ac1f821a8c362b95388e25b123327674609343956afb8cfce32efa5448871bbf44bd22a8fc5c0890b242782d932eb5f7c8273b4f424cde490beefe38fcc6440ad6170b58485176e198e3e6a3fd0797befd088ca78fe0e43aa62f95f8079ed5240ab9673b67b6fa300e7ad0282702c06eb03f8f15f73bd4f2fd7bfa92a734c82989075fd561c4e57ad7effabf4e9bc1ce54e0d00f0b2eeb91ee0b313f647f0b0de79ccd350ad25a87b9a9f981fb548142c4b6b594135d06d7e75c3d70d8d136d014ed288d22cda60a25801dee7b719b58d7f84136df8da16a951c35eaade3b104476fb2ae39f3cef11df8dd8aeeda73c6de7f1cf3ccdb5f6535e218f4a0fd56d9eb8c4f4eb4a2b2a0bdf4f9af1ee76344fb7def8177b577fdb975231c36750a89fb9bbf3018ed5cdf9be278c08d65b085831cc5159dff851a34ce4d3a36ef038d78d1574115e0fa6585c51602ec77c3d6ceb0d5b639650f6070936ae63bfdeda4c189160e5106760a25caf825c97a48b5f3c407fd8c80cae5424e1990133e800c7ff469b6f079817470e71fcc3e07c358ff2b63531611e49f3be2a8d094abe07ba6671c7c5dabdbdd5ea0131ec75dcd82a5dcbaeeeebbb53200f47665f1aefcb7d897c9febaccf61014e822874a5d5c028c6866bef2122e47107f459d23dfbeb5831b7944407f990598f7919e263aecf777184b2c851056e72349ef18583dd64dfaf2418dd49afd75aa828f80798a5ffc2eec50260c6e27d43f650912944c44ecb50ac149776a0e074fa881e32de6721e975dbf61a4a89a03c51d78ca7679fc98b5842db0f80fe22678a71b998d1b9cb8c539c5e2492ed11ce7b23f73c50bee524f3bc0ae1191a68199e28ea44d6cc6a48a4821ac4ea0775f2686e19429401f20805a1b4f991c9a9db7a37ac324c7eaf1e8f4234e2a347a38ceba3f6052910cf409689df2827cd584999d637ee821412ebd6077287605c193475250b9456025695a7d99354c464e8672f26579567748e560e79e32afbd230b9635ab021f4f2a0ed64ff29b7cf4236293518e59eed8a825fceeb930ebcadf2991a944437d224fa4a55846e163abeed1afe0f60cbd63e0c1b5c8e575666be73d05c4da7bb1e70adb08d0ea364c2f533ffd59b59a643237b9df57f00a29f5e56cc8f7f2e637dec0f8b6df72941a5223f2d87ea5b5ed8a186725c464aa1b604c1969f74fe6829029c45534f52fe383bc2509a198ab7520b35d3c58f68dfdd51309f79532a182f4c7e61f0c0e52af7c17228bccc1a6f2806f832c1e5a72b29328a9d01c765a4ce7269ff5166a5aa03e87467475c79ffaaadf0b2f4d0fb5245f9aa8167490683eab086744d4c6a8fc96e036f12e29edfb4fa897fa41b7f619010d9e02c079414aaa416f06d8f88dec6dc0b433ddd972982d030bdcf2ffc57c3f7cd6350c8cf009677708122e99b10092cec1676a497aa498edc6f6eb2bfe91a3ecda0bb15b42b95c16f805053b1c75e8cf69913c07ba07b9157630f3e12140bc71882a6eef5f0b7869a3fafd00cfedda6c01274cbda966f00074b1eee1bccf8f2f7df35289f1c43fdd91e5076a9ef92d21925bcfc3174bfb5ae3e82aabccba9b5175c31cbcf6dab67ccc027a6ca50caf5017fb79c721e9e185b19e59143eb65e2bf2d62e44712ec96a25bd940ad60a8f6a55d1ec70f700599b2d2dc6fbbd67000a6194b4b3f83b89b2ff52340fdfd06599675ef9298bcb0be3d5ed8b54ec8f6567b56f992ef9bec1ba0be3c37fd7a45f0288173dff5e29943c4983734cc19cf03aa445ee6e76190905b2409a96d5ff6e27861702b102791286e8acd96a1965ed7187e9a13cb4252385f7e826bdf4030f96cbd43269cd0c268a4a3b3c1c674ec1d755216ac29c6d7b88d93daaf4606f65c9cf338bee9ee6f066f07720e9643fb6a7bcb9aeb9977b02180ff33283e04c47123b9171326538ad77f50e3bd6882c9f77201fed9bd0ef6a97addcb5bee55a68cdeaf9847808c081a625b2d145c42fe7f1c1129b67187a100fc96a27472b5510d2d334efe289ed23a2eae479ee12e3c2a9ccab0a37a4dab16cf4311e06bbc739f43be825f6bb094976e67be39b695a1cfdcc699b12ea96643bde5a5709548cede4b6cd3a9c3d8dde7e32754871916bf55d6dfb3cb93c7dea17a524d03d81206a5fe92d57714e1b0be7c57f12a82e3a433dda2c182d270ba61477bbec3551c7fd53958926817ebb7d8bf1696fd1dcec127adc6ed56db1ff6fa1dc8dcf89b9063bfab3377fcfe99479c5b8f217e2b9ac732dd14b5fb702a8f0a4bf27dfbf5bf9273d6b71ccbf0843ed7379fe219bbf6b377deb20f9e4d8e57a950624d475f026c1652fdc711300620a7bbd88169e3427c467e7821f8a8d13f9b1e9d9d819aa669526399ffe274521fe3ecf6bb1585a3bbbe4524ca0ccdd7e8c397ec122a366ac8363af5afb383ad353b82c4e51c5ced529a60d283409d6f0a483f2f90d123711b123478094d073ca5f027136ee5a898f5f0fb68d51c716e51aebfe5e1be4dec32743ba9fb0ef5f54f4932ac19f65f419d0b1c1cf1bd321cb46743fac9ba27d18f798f091868df6138d0ac892237a0b5bebd1ae5dc2a4937e60bb2ff359c878c2b23b913d9ec1e2d9c2d4f4fcf799b1dc1e3d235e349b5838df1abff1955785481b1f1e66f42e7db257fc2f7b81f4d746fcd017912519efa83c2452b275ef1b1559d1ed7c8c2363a6c38423d49cf7e2d08ede111eb21c1aa9924a8415ddd2410c367e4ca4c76f652b6596b793924a980e97a48466226653241cc5c985dafe60fab761db848317cded2ff07b3ba9b90b490ce344033bbc9a1e8fed2f2551e1597e48fdde66061d292d8cb111d78ecbc6265162ceadc5aa8510f2a54e42ef4fd995f7fc4e05bf9cea81c294179885bf705615e4d7bfbba33a9af931ac9de985a9b294608c49ad69a5d00f63e709bdccd924788d3e266b4894ba5dba98f3763a09a57356b2a0b55abb9bf4f529f251b06619cc2850b69e24074c5086ce46b209c5d2dc785cc4ea856d6898194dbdead5377df22f4baee9bd567e84261f5587cd6393b631118be397bb5c8f09e692371a48a6812251f50211f51f20377e14e017fc6eee9d663c2e455413ee24f5e48f0dbcf5419a07593970b571d33e72c3de938fa9a7751bdcbed7adc1bdf9f9d4dbee650b65a0a8c8ab8ff130aca2637ce04b7bc71b59875e781ffc22f15674b0921a0b6bca8982fd7ce2254d0cbd2eeed72e98f96fdd4518c70984913e980aa825268f5c3df20444c835aa62be8f9dc631bbe49fea7d26fe2fedc0f3c57c07df31f6eb2a3c2b94fc7674e21a194b785d35db7ac1e532810c99d88d73b2291b948af0e39a330192f69567e6ee96d13460fc45f40b34446452f11c6e7ce84a1577fcb82a0a62bb4badfdf13acd63942e06bcf606d916b3e711af1bc8df1c021aae617e77c0fedb6c90a8020fdc68615d54a088ec60c66de7045fd4f8105c9b635a4f6c960ada54128a7e2a4ff37cfe4dd4c9fbae797eb5acbc066b1fa49ae9646aa33607512c7bf1d7315b1019d1b89e3e293d8ea2bd7292f90998826b2b64531735312f5741ad876c36b6235bc8f1ae40f3cb341f0357c2789994bc2b18d622fbc45c6a15668fb6e16c7edad30d2e69f724038aa3b38b52ef19dc4715dab7c9e30aeba5effe697f65593a5d730a7178c374bb76e5d0a2ff3adcb0795aaee43edc0b7aa4543c339694b22f3748856c20307a7bf5e383c36734401cab279fa486ee7ca797aa1c1083ae36d04a5440f5e59ea12ba151c2c2bac21026c36e515f78373959da4682fbe28317abb85b05cfb49ee10be7016dcff2934385e092342d97a2ccf930f224b3341f62ac04910298ea654f628a10f1a0f40f0cb6709c31af0286e18c02290ff39e8e94d1685fe762ada70ac24b2bda76f3ae12b60521505892ecd6461fa0659e92d2f5f2997fc680266c2384b47576b62957f266c366da7bef64f9e31e88dc82f35abd0ab2b415cd54db617ac74401767d68ac2a92e3daad3c35ea106f60723471fd55317bf2b178d877c1169dc0dc11bea7ab747c133180bfc9dc089d403a765d662567fd8f4ad8108508ea4fa3c2c2bbd23ec941e1edbdc8822646b48ece539b616578995ba9a2004e194d17d54ab1bd4be3eefa5f66c7a141dc8c4542e3c4c6a554743e42066c20d578e7bea5c432a18ad6bbee2abfffb778b715d66871beba2ef87df56484129d298e607a6236ef9419e68c3a3cb3217e0f4ee0cdd16d6338bcb2253e95a07509868c4fca45614313a56ab76164cb5c16ad4fd85cb01d4df9dfd829447baece5da9649f4b468cf02fd42305a230a745960d61053383a9470b141b0219657cb8e0547442bf4a40e98591ea9b24d30d6396dbaaa5c028af914ca6552ebad08c46da1bc2c803889d87846cc414a1c79a72a3baee3f43cc0dd9ae05b94076f18dc7bfca520c61bbd3282d583301cb3fb8013e214da79ba47bb19b331661ae5e2c32f9b5cd03b3763230ac8aa949c14fc7428e9318b181f771fb49bdc585fb67619924b0611076b6b4a3b661a831dcafb5ef11e955e8b9578d4bac0b0273d2b05df7ac607e8559224e27c701aec54cd3147bf315664625b822f1c53433c49379303a6e5ed8c689e23e54c9b7c8939abd355a6494fdec8836d64b8dd46d7a41a4b8e26beb7f34ad6e53c459ae6387129a1584430ad636611d5a00df37c5fc1edc9699de3e3ca3ce18da4ace69c1d7ad122783fb5109fa2b4ec2c4e8fec11961cc4c61ee3b5469b7f776ada51e30de2ad334bbf7942845388cb89b514daa8e6b5ea3b3d3758e67bd5a0b876d01834bfec767268ccec5366faa1a1de5bc26762c803b6e1e0bae228d9ae7d88e8fe0942dd4ad1d9663bef75d9a5f4c7d2319f3457080f459bb9302f6872c68da026f49e7bbb2f0459dc2cb1e5db84669c860c843572eb4580f5499f0961785cc3baa5740891bd0279ac37a8237e5a5bf1d93aa3bdd9182d9213aa1e12e4d1cf844dbbe18ac4408ede1fb2b0eb78eb527fe34e62bfc20ad83c519c3740519b1d897e88fef00eb244519a101b9c0260f5f6d7c9654b69b4d2ae1ca84bb7f74f8e1c0220d6a2dedc0c3cfecaf38176086496d6a16e64d337708ff77d8241b36eede50ed4532394850aebef821c7cc72240c555672deeb02413ebc9f0e7a059a448dbd4c41abe12e3702550dd11de113da4b0092665692f02af88475893950f21bcb81000dba18906eb7d13b5bed8bfbfb34bf992c20d6f980d36380d2449c4bdfe3000e49c6d8af9398abe09c01b111af5f073a2771daff195185ee56f49c9c81aaf820d8b15270f938b3333769a65a31c75e10158004d69908450ae2102311d0993cd69a1014d17885313c269ccd1a3decbb2c22c6e69338f95d803e14b56898831855b78951ff2baf1e6354ecd0d81b88d85142c3a936aa4701cf2c0beb037e62d1127d93de3c1aad30d3706d1c7e8b59651fa59da49ef7a534e1a48a1b7b4ce6b9eb8644c5d158f96d84e1324c0ce75df8e93e21fde748fa75d41d36734acf8268cb70c6a3952abd45a948992e3b3205a9158eb0bf2e1375a6e160f84da7ac84c6ab9d25a188646db1d63921f6dee73da5ddd772a97abf6473c477c65a2becf3f953bbce6c8775454d5c778eae7dede8e5d73e0ed7e71d6e6528c2c84f6fd379b4d54630ce38b5d18a887bf4b48757cab0ee756a946e56019457a3076b645b941d82e7947783ba34cc415f8552dd111d61b349684a6bf2ad5ee6cfbd5c4feddb10b01e35e5623cdc7861d584b927d9807228ef31c6d0fa26acab8930c7b0a03e416044952f660537ff58536b13f83ebaba556d3da4d1b3fc0c7c21a5dd14058fcca84c2439a516ab0aaae6f4d25f4d2f3e68683ba88f7b93d4bc5bf28a60708dcf5f0ead1163984d45e1403d3deed31dc1ddea501f23ac7c53c28bc9eb3ed880602b87b482c2f8f2596e0d3d5a9c48e6f59c7264b7f83107a0ba906a4bb9e85999a1ea913cad024924cbf613bd2837f37cd8fd2aba31da0cf1c22d0a17a2ae8fb6dccee738c9d88c39a4e4ee768846a5a2a1138afbd66bb7d3efd3fab94fecedb1c1844db845c6e00befe435bd65f344c5424c8999860bc2cbb423890da0baa3b7e75f573bde51c877601788161aab5375d25d8c9ef7e7f20758c871ffac1d252ef39f75d9a9d76c52bb66579b1aec21a20523acd85e69a1065d2ea5d11adb78e5cb08047e1da8997265f3e89e3772d8195f6364a34661b2898790f08e39be1f1b5106a04f32f2a442161b73cc2449a2675906a9f009a1170a360cf2065d929bdb0ac909b7dc63928c7292d872cd9c35048d73c031ede936bec3f5b9914965ef3fbc30057beb9c6011cb0ca6a6830bb58183fc8365241487ced6c9cce8aa8ce970d7414818522e35b7328eb8a5023f7209ccb7ba8dee494965af79d80eb4c80b0ddd0ad871e053895e00c9e7e83b2756b3b560ac2cfbd7e286ae12235caa0f6f5efbaa3e52c421fdefecf9c38eb757e80d8908c6f7e7c71314a5d12f97b318369b5a0d748f8518e8e6a6b4e67a4821635c82ce308e4692ee9cf61130c12c87794d7606eae2ae6f8e82ed354fb53215cf2d0fb38e45750695b4d7a006ba7dbdc65090c2e24ee51ae806a491400d8902f1ded626113033ac97bfd5e55fe715d3da29aef00e67cc5469ebb597602b5615fdb1fbf5396c08d1a7c13d73d85ea78f4e9e3acb8556619138ca3f76bc138874149a95afed2227e65ad3ddcc0f698c253ff2d2eb7c00edc47ef24de478fc4e460dc7d710e46505bc6e2c0f8937a4b705747ce2984a747261b90446045e75e20fa7312198e420833331b8ef232caa82ef01a7f47dc4937206bbc91e02bd59c81b97645893147038ae306d7e10c41784d73c1320c744c1f296a1a5d4410be755b5750d616771e81d1ebccb144e76623278467d3cf7d90a06ce54cad82ba306c4ebb9bd55cdff9a093c01096eab817429bdc31f02356658c66def9ecaa2a5f8178b6c096402abb6e985496c618eb01b4b858cbbd837bea015ff207cad213ec1698d1c83d6734f3f4992970ed01c82707f4ed58efcbbb38e1e51a93f95285256e951ebea8c3275b777a9a5ca2edb0f7cab1969cc72722ee752b1378051412725471fe89629500cd8553eb28b7bc35dc71c268e7bcb47057486a5b5d784f8365d4e84dad544ade5a498a80f9f9b1f459bd7a9ef2da2b39aa2ecce1ab00983d79fece305290e61ec3b69946272f58ee961dadd7f1aedd2631dc4576c84d4d27ba36c7351856d46e57842d22b38ff98b6dfd7091dc452d441fb5524128c6571024fbc2223b3a9727a4bbfe26b746f41d825392c3d1089dab9225f25b37f10c792c4ad8d8ef3c858398a6a703516d8a108527aba440a96f2cf7ac160591b5f7c67e30d69b607be9c9093b840bf9ec2b4686a32cfeb1ff2b3a155547dd08567ffcf85733cedf60c6ec1a7ba967a94a2e0027e7d094dcd155853504a156c7678af2a381650ef6a489f624428cf5e1f67c474521ff9b1d6834156100aab11db69efba4c9aab3ad21145fbf3d6bea7e17f0b3c6152397d5b66912c09ac2d846b390b9217ad2536d872a4f72169abaa3305b9a9ba7e4c9a9640beb44c62de3446017f5da9c0fb1bb72417a2ffb350b62fcd90dd56fbd65a48b5daf07f06b98e3ea829759d3a101e3884d870ae94b33adabbfd2a155a1e922f58fa18866a39948b4a79a78a4c8621cc97cbed6165ea3cfe5a57b3c3751d0e9e49aebb2a68f57a8a1d5cfc7aaf883e023fe83aeee6f2b897c29e501981a15b4253c8f8c135c327ca194370913d78f04ed5a8e36b4b9e5981d580be1501c3aecae688ecf44dd6be3b7d035ace8a57bbb7aa59181aab20198facb1e3879fbb24cb765503be328af6b62eb372c1b71fd95fa41fc89e640e0917ab5faf5411ee279f43dec2bfa9dfd676a1fdc2cb86284fe10c09b421a5f5cdcc446665df48c81e8b91008b1f9b101c41b9cb18523bdbc616bab569dcce8677aa2ad8d1ac0333817f218d9f2a4e2f74b75c6e277281b6c628695c7d20183f4f6b6b126477afde53e5d4cb1e5977e4ab12d6b3334e776c44cd0f0cc31ecd0b54b6c43d39d9d107d4ee401d40e207de053d36dbb04442b74533e987cfa7cd215860c381dddd283c2ff87e379dca1bcb6828de3a509742be10ba257752ac29e99ed058980eabd91d92266d038127e56901281d0fe2644611d3ec3b9b36d27c245077427a543e38ca249d5af9ca85f2d00d9df17946e3e7237e0783ad53941f16f9f6a9b03e8c50a1c1ddba40af25fb6b74c40f040bfe1530a8bfa0f1ba5f46fa7b7c0a5d2b3ed6118c98243dbf13170bdaeeedbe57ed9cdfe468445b7f7c616cb00e7f5e2e2d9520e9e2cd21404e892489b0df4ad2f09a544481d300a473440d9f6a12b87cd7f98e75e44b9e7856735962a4f303b58405e99417af88760e27bdf1440df382b6af5162357ed06116e2c56dc737345cbf2676f5fe66625f10ea031745ed7be02299d638ae1d822e80b94a164dbdf323034cdb001259f96b8486098a76548d297b5a230286d5d307aa6a9994db056f689d0d8c9f40c81e99906ad2ab6fff2e4b7c5481dab80ee2a8056093c1fba0804cfcba0011d862ebe206684050c3f69c3660a10257f4781f3115c9ea878cb2af1bc19913720ac177c16726aba2f3afdb7588963a7893970eee0c14eedd018f2ef8d78848d109a8be1cd463a6904a97d2d608937ff7b8772c5cbb8fd7a50437116b54e52f5cd2cac7689aae281c7b94e7f2352b95469b797d79eea4e362b5fee2680e8908d99ab829b45ccf1b18b48ac3e3f8ffaf45b2a29a3725d6b12b45b23b3f2a7fb772f16741a70d0c4b409aaca1fbdd2e73127be4af9481700d6ea6f9e25a68c8b04697176ee13187b364c4c16b27b0fcf81be048d4f9f7d723431cfe2837b208f74ecdc84e3fa010d00bad327c18cce00624049157e00539664d8e66c44bde4511ec822486d8db80197a4ed40110d45a4d470093b8c1522679c0b4164f6d6aa71b8292c911aa089cb83e29e52aa308bdf320350aba2210157cba44e5f0551a41101ce33ec6ed9391fa1c6aa2be1e625ba34c0a0799b0e7ccc7242f57badd1d5f0d712ffc967932260c31b92f0121123d32ef7acfe30d8eca43f70a38e9f7e4dd6d32b20014e971ef389b8aef8f16f5ef5a24243ef9fd5e3211922965ed5935c687d6b42fd5ca04d6f9adb6915d6d76bd6a125f0c15f178b86461a0883c31f8482ae754b15546edc81fa4ff3e1b505881167c3b178068c09186631c21fe67ca4d0df06a519ad78cfcf70a575231d2b373921d6826d49022c5741d9f5628d18e1ea9f8b7d60b28f0597ec3b3de27c5e30b2da554cd2cf0b853db2cbfd8c94ac2e1a52fd44a5b1bbda03bbd0d91a1800251f0de9f17233d9a31e4152c6947cec9922215b58d517da6499411dccfe4a57b95716eb12942b4e864cea6283d92772d6480cef08c7efdff10196734cfb397a9f29ae4a43fe2274a724140debbb3e0dea689ae09cbd6ca3437aef87911d90de053fb8c40a17d3cda25a7fcf0c2bf1de7f1cdbe525203752613ed038e6901ee8f6380c6a475839ea6a53d2bbd69558e425cf43e1d75be602b01e158e908c9b02e577ec77f1f2c0bbd66843960a22ca9740864ae35eede46477fdb462a1d28d8431e26fff328edcb13724ad3bb900325604a46644517bd58e2144859373380f5253554b7d9592060525328a11c40b3b74600382484dde999b71a20fd06791b9aae5e898b406f1a6272603426959cc42e258a6f7e93d66432d7166121cdd2b9f22158c0ca79e34689f161c449959705c43ecbc11046516fca5d5399c2e15e057260040858c109457d77eacdb48ae22ecfa84b948801fb4ddb62f9b71b2d0a25d9c020b4515df93a1501ea8f2ce9191bde5e118d0f886edf66a9b49832c35266d3eb41572ea0b192ebcc34efa1b18820a2b2e01ef03f04875dd497f4e1f49dbf1672e0695ce919c537d3812973e59690c006999a2aa7ad3a676778cd5e28e0579db7d7a53260d4ca5e27c5df59649974ac1ae6e825dcd06a28235d7aca4e4a4d0b80b7560b5c0ae0f4fca5f51db3ccc46891188ccb54cc9447542754c92caf8d8d28ae6ed19cc298b09be1fc636739c03cc67bacd66541a1a0e7712231aa92847a2992d13b441657250ebdc96f0e25cd3b466205518fe5cb0534f31259daf9a931991482d98abdd625eb01d0747e281af43905daf8a119a138aa3a1f08bc5c4cc280fef148c1a2deed82b085a1f8531780d96818e92f605b61c2bf0f8792933bf7b91cf8ba7bda1425c671c169747fb871711c97c63ce1c13cb508fedd3948f22027e06cb2e01895fd5b4ae685123620367e30927cd74c816144cb3fdded42d973668be778ce296cc5759259985791e86a1f369b68468a849333907a019fd8244bd37f3a3f6d9d23963382a1f1969b0e231978230e70e34df8d63ed69d6d7a5f56e945d2f71985788fcdd2b754107ced9cd4df4010359caa32610127835ce51fb2eeb7f0718ef5a139df5771be6d4e5a4bfd090b6717a2e2878483ef966a186536f4cc82bf8a839fdcdfe3c50cd328e61f37faa088be39ed91962802943b9ca8ea4eb2b0855333f43eb9fdec009e2ead02a816a9401901a526131b1081fc39bc952e7a7d8b36479b9b1941a46d00f52fa54582a5588c2bd07ed79c3a695d135986e6f62ff393262a12837ee5676dbc03e8248ccdc20632aced315b2e99706fb71e819b230d4e58b3a2de22c4650f209cfe5bd269ee9fb5466cd992e67cf69efc24658b83412205525901a90ef7eb7946537dd04fb53c26646df02f33a2a7c5f9b96220055f5eba38ea4b81d6ba5980820de4f116087d0c96a1e8876bd607a6ca73d55a8d3654fdf73a6c7ed1a576e07e716394fabee4a58d4baf7d18ffbe9dec46f29b8f7abc773247dec76526ce3eb72c04333faf68f812d519f01376d50cfd391a2d89f1e95b0467ea77088cfe687cabad4a7a5b4d670d64e1f588a4f5690bf72b8fd2d340e24bd6fae070775adef7f4690171a324a7a19d00e21fadf4ee54ed119be5e32d2d04ac4b7f70dd898ac6fdeb071c389afbde10c7f81d09f0e4264ac5c432f2cad23ac1a693640fe949fca643ccfa0fb7a3f8ab4c68b3d86b00c16c4d89d841dd09ca0ac64fd97c6b9e3659969a55d6d0df9a59b6fa367ff54ff048396bedf453a0335c90f57085bf5c9912972d7c35a134125b927abcf37eb2fd86aa9d3ac82ee4a316f13e135bb4f4aee1d9cf64c9abc4ba9bdaedb07acd4964a5d35ee52b7c0eebc2397796d7c25bea5ed9f2b8103d92242638ea9ca9502c93a1a02eb6fe9877e2b4c11b5cbc0f90b6a41401fd7a380c9f0d92c8209045fa8b275b7e62f39712b4976c6ee0cb0d44caf8407f6b1f8f3fcd6d7fb8de395da5f67103ff37f89c0cec106260e80559c594c9f8c7fbd5657267c07900f7ca7fb46f2aceb7232acebc477ee0b956346d84a94cfba2d004cad75e90a3a964ac7ae636806fb0dccb2f4a558e20cc54e56e26ad8e6130dd6cff47c04cfa3523ada3cccb0a509bbf992961c81027545bec8ca65e10139a16148d69a9ad466512c5a49793d850f3689f056b40cfe98e609f7efd9dc351d687ad08e8c48f4a2e8672aa77d07f61bf3a5d398e6cb9db3f6eb9ea3ebccd62aae03ada05e5d759a37053aeb37d705e271cb9b10aba447ff3b2fbb5656e7647078b4433bfb0058b73cb58fd8f9604438a01ad06de5751ce26169764708bd15328c58f153ca7b1aa78cf3795e52838de785eaf48ef951b1d22c4e0f8f2a40ee992283009bbd365e854ba8d4f20da36bbf6e6a2ed9576c87b23d896618d9805a4fa57c09717d6e9704eb7b77d68a16647714823f479976f1cc1d3d78afbeb0886c1bb7dba345139bdbbc1cd083a23bf024dc5282030c407c7ced2eafbaa391450be5e77b65b23a6df4b15f5594dd8a4bd0da4b38265ce7914763b7e87ebf9434fbe660132b3375c8e0dc8a5d1b58fa1d7d933c41a0d9f87a5219a1d4c11c4c77de99dcf15e9f81623e924a50852865c4f625a70d9346479d5a1618115737b2b8181431a79de87b6d22cb0cbf00628c8526067749999e3205b76c8a6272c9ee47e7fd53dbd53d5f8d4610e40a3dc8d556f2e77e03013ca42c397ae30270f19855801afe2289b08454b1af64f4ffa900374b0073263c747275c700975c42853e8b0c4f6cfbe1cb7b6f52f49c544a1fb7489926be9e22d15345b547996a37b690f9cbcd73c5dd9fbfd1df6d615f6a345fb4138e5b859cbf27a6bc4acd1f106e5c8d1b67340c127c21b3b485f90ba9d56ab94246473e31475890fa756fb723e08599e7bd018987e4902a5dd9b0f38f244dc7811089e7b87fa10f29dbe82d2f56894c1c0c32b4dedb4e30afa23960c3ea5d627c396db60d7e64344d281f71b1b8d57d9ad318c285b30c073373279a8cbe66d1afd33ba677e1962f73536522d7a089b24235d90b36ace55f68236bffce5a84086cef6d65cade2aae855d3ef729d7965e782ca43ace229783f32d16fafcc37f1ec4975076c6efe6e6af414ed2cada5a4821ce2bdcebfc491044678c59a815b6bbebda435ace6f1b91c699ad33ebc189a75681512809f5c1d2a9728064b1c142c5f9b0e3acd832a0e9b35439bc60a9cea004120093680c91594b9833849b4efe78c0728af3bf5556e4a530ab0481bb57e21b3d15d080461c2643cbcd33398f7fc62ee9b6638ee2af267208635c09a99cc2fde37bc772681446728fe990d5448ef44a987f727d5b36857c30f35d3d5bf422bf5786399cbc60d805d1e7230cb14ead64a3a5e56c012291da017e683658f4d9751400e92f3f529ecd16ae974baf9ddc86924c27e70dc9f339a29912de6e379b4ab65a433c34f845809f21f607eafffb56c28cf2c73f1449246134a76a437a7c2c96d0a421b26a1096e6b2ea87c0111c28844290b7c83ade9b4e809a1ae16b7b6f3a00d55f177828bfdee8b39b122d9e8cf98df25461eb80df9fcf7943fa323c614fdfd79726113e37548fb35358ee2c20540b8acb33aedb21c76ef81bc06638a471f043a0dbee0520dd3527378f0eb14957adb6e45b3357573aa12712033fe6e1bd0ca5e9c68f9ee045f3db9ee8315818d945bad91de93620232328e9a1c139f63cc666dfe9bb91c3ca35de6f482599d503091bc4e0762c9cc9803067e5129b09c482b55c9f9545df13eb4f36c8130a5fd10c0589a4d0e4523eeb29c8e6d868ff50869fc477aea865641fe2da97313bf58e607dc291944dfab02a653d6f1ca8ee21ebe5a4e488fd89a1ae344a88165dd95037bf5acfc61d28ffff2350ac5cbb21f3bdcfa9918b4d9323c884d8fcb01d6cedb33075ea81ea74bed925b87695174f31ecfa39e822b06cc8b90a304f129da0676e77cf3021b7455c830934f757b12441cd88fd8d0fa1ff1bfb7988999581b6433a418e87ccb166d6335668e9bdc1b3a24a38ef33dda2bef8fd606eda139b2761f5b659e7ad23ca517686d06c7128163852bcd14b8654e7e1d64d70f8ff344ba950cde8bd17e5358a01b4a8ff40f57cda1e9cacd96d448179eb1676aee3faecef2ee0634600fae22e28b4a17085d5c8ed98847425bf7b6d70bfb24e96161cba3bbe42ea2a81fc1e232f497f332ef9f984ac6e9e48c23a03458dacd914a22fdde41ba2eadb68647e148ab1ff5262420ad6b56f5ca23211fcaf77226ed26ad62f30321696dd03b5031d65d8348ce1353d02f3e462d5d42e8dea0a58dcb9d05528d970099d7d9556affd532d73ebbe8483ef0c44ff360e9a8b597f7ea3dd9b8b35ef5a648ae109932d9d9a07cfaef7e51084a1c3d7357d60cf8c6538bc469621d57cb4ee42b4af8449f1874dceddb844a85113cc9b08da8257122c2e644226a3b5a625cafe1b7804648779c2334fbfe69c258e8da287864bf340cc7684f98dc17bb1345171295d3c0cef2e21d1add1abe3198e0ba159fa1c5f81b858d3406d40102a83ddbadbb5031469bf2d2bba43260fabc31d0fd8950c79ba27a7ed2528e20bc5124d729ea8757f2fc6c7f3173d5937193eb52cab962e28dd32a8665de954b2856b140d787b582581e08c41eb4dfc41bea9bf4c8ed048dc1f862c0894445ec41baccf572704ad680ce4fc8c023d635c2c1a31b6cd86bec4b585a1fb22ec7182fb0fa6a3530035de933d0da021590edb2b57117297aba735a84d201b9b48530e545976c03172920779c13fc05fa44b2c7a5d5189f55537cce50440d5f705334adeac2b7c1fe80f38c4274bbab4a8e6a7f8a9d297428e620e27180c5f5acbf2e493ddc84817442ac25c526e151b8cf7e03060e4dfc3a9b2b190abaf8b68184cd4ec5e1815d1d59ad3f780ad2b1512def62f8bdaa221bd053967abb08e065234b1ba4cfc8fff04095d514b74a9f9b073fac8f5207328279c17e5287e52001c7e88739e7296e25fc7ae57d59f2b3ce88952ca25b5f622a980223aaa65083a0f172383226964187194a7e6b692fac287a45a11d8792e7c47a05053fc9063dd2713aa816e766aab99772b7399de2fd2e1e6402984a0cf2f69e90955d0ee0b6c6cf755b494f55db4be3533e6a3ab007895a125fe1a2e547b0d7aae6a5624337826f04989e482b02203b5dd7f9f781a2bf7c51b45db1915b328a7af84d70da45ae495b28c15f7afbf4ac1556299ea0ed907719fb289256aa676b9551268be23267a5b621f7e41fed955b2bbf5101e69df43bc518628079de5657e2eb9efc57f695dc79497c1586ed014a4b7aa91c82844963575c5c8bdae72f6c48620901429a5a297f38938f0037be7d0f0a28b765cf6e53489a24fec49c725c84d2956a09574a01b2f901c49617f3b34b10263f6dbe6e0ac0ff715c606004b3997b29200192e5ede528c8c2b37aee1b5d4e83bd0aa219d3cf40389e63ae58b9331f6b6af1cc8164e54c9222ab3d91a0839b4f693a724d71381756ee76c5941fba2c13a9a77e8a426e7f5482043766768e45af68f543faa548bb1dc755cc1af83793a66c1c8f28be0c49c16bf73a6801b864369b9a3449ec4e10ee664fa1541d17b3d89898cdb8d8c07ed6119b4a76ec7762cdb497f1e55013f28ca010f174c2d30ff43c14d7810205b1f2d1b3f649e0340e883690ebf79f3fc98625a9692eaec91a4c69ed5b95dc09c21a6c0dd5bbacff7702038305b007be17a34cfdd482f5e0e4609df09e75d28c45322ca750addb6453f3ca5dc8edd1287f71cc2b2bce602e6a8e7488b045faa671b05b89a87f9c2d07e9fa1ad97da750c3164e325c6dcd2d0548962b39287d38205cf4d63ff780fc6d71beb74d814e6954f361ee1d0de9cb26c9a607050234dc8fbd61a12568d650e381da5d6e1f7a5292873ae2b3b02d62ae61ff8ba3f40faa4bf9d54ccb708494347a3a610a4c87448d4eca8350534f44d59997685bee8a50fb280191854f4db63e1f045eec1e283ff01eb0a5e20acf7346057d4996dcd6b2b85172e6c6d00e83208a6db18c0cbbf02ec5ec7700b88d352ad237aade1613deb1afd67969288a0cc050af052b31fd1d7c5270bc96363d908469de976f68ebe1a5664cbcc3cf0cb7fb2d484cec14e463de83ebe555bbb0e29924c16c3328f9cb204022fa4c0a34d04fc2a444011a39ffdbfb71bf182e303c08b96bad12dade6d77ff8f4c4d73aba5b65af4fdc60288112b97f85ab821dc29630e6a2998496d5b93811435b7be21c7e22048de5928b875c4f6aac92730a089b5ed7b57d10fba194380c771dc0d31079e89ded4ff01ff66aa9274790ea182bac51859520651d11a195a69415d6376f711bbdfd5aa39ad83354f7999fdd6a9a94cccf4382b07d23ab7fc773f138ed631150a8bd7fa06e005d5fe41e1aa4f450be640c8efa6e8bf6d09f68f4c731e4a30d0e3d750aec85d7b08e3ce51986fd812f3b9cf3e33f60eb347812d29de5b7325448a9360a2923d62364b509f32ae2a26ef78ef5774338f07259ff6c4cf1b24c118ebbd9cf6ce10c7420125d5dcf8d292c69b02a0e474f78da2c954fc644b906bfe48d65fbc42a60ba62186c2b354067ec24fef4e48c921ca6f77d15e00d907e9d37dbcc361e025e7cfbd632d6bb1bd511a6bbf591dd09d4c9a1d7298552b39a5254e6ed9d19516ac8d6d241e5740287ae892600ab9699509f6cfe06e29a0bf8065d9517790e949bd752f67c6b40a5621bfb3cc0344f5923d4f31bc35f03cdf246ea0c20c4825388670193141561f32bc2e3c33a53b95c37f985a1a71dbc8eebf5135f77d257adebec6f5456e653c5f4d855dcc5083a1b726b4244d49e65148e8d697e8e5642718fa3fdb836fbd7f99cd26fdfffc1d99a04b9087cdd973d0b737cc1bdcddc834825a2d22fdef40c674a994e1e62f59e85c9cc8df952057aa59d5b8464a2f2d6619d5796e4fe7eae9f223490851fa9358b05d7a957a15955237239c0c49cafe9bdfe115148be9c7f3f496fb5c85bb9bdb271e59d4eeb13a0ed532e3a4f0d16bef0861468fc157cbb2bddf27365cf29623f080030af0ee64b076fbffc1cd3a219129b4a9470b377875a619141db2dcc01cc16a6dbb39182587750f58c6c0ed0d3cd4aac4e5d210dd7013bb364b3a6b3c3f5bdd5effe239cdb9c3d9b2a3bbf7e1f3319dbbea7452cb887ddd224c56e821fd534f5f4d1c78719d63316abca05bd3a21a6a1bc794eb8aa98412a4c5b22c3dfe8ffd295604f3d15cbf45cf644f980c8c4c9105257d6bfcfff7be89ef74e8c263145092c3b56ce097112b422ecf6fefca0f3f55672343e579a8f9267a6c214fe06d14d2dc8dddd6dd6e7e110d98ad77b668c5640c46d9854942f96cc38794ea6f57e43a18172de0c14d37d5bd54f9f931076b0703c1069974d6849451b14f59e542a16409e0a0418997993c71e3bb5e498783bbcb10e33b2cedd6058fe72b2596da09d60c830649ecd6c7fc6b23fe29708edc5246aa7bb4483ccb63cf8c96896356930e82ddac8b0f5c5aeea698495a4044acad7febcd60b4fc2310c54f1ae16dfe20218854a51f3681c5e9bbe5e8eaeb5060a47eda56b3dda66c614b533ce615936fdc0d6a48a716d45e908f2e5088d2ca3664d5c95cb0a0663649449744393712cadb0e630f7cbffa49125e0eea80b6cc452007a736362ad603731dc1470189988ec5bcd5064a7662385d197b3fe6c00b688ea7fdf303c895e0cc3f20e366b476910707758147995438461999df0e802c9e95998506025457a0ea3fecd71d9024a3986422e58c4e704d999b44967bb70662526447d5016f2c6214756acdd6f16ec6968f0e396fb3951f5232b117e256b3527632243dd2d55918f96e0b79e034ddb4c24b18eedea38d9228cdbfd7101d8f0003305b341e52427454d5286959734598761dcecb241e1b7628a5fc5ef5a7280c359ad47357e7b55427c4601e3040363f601fb85eddb16373ad13200380f6de2d9f924477035acdccb8819948f7e20336eeff006938bb82fac88ca876ae2de7787809b0d8ae1a522457e16acf141911f31c0cbf8f5b5398a816f3dae87aa4eeb527b8d6dc60f844031d51e9222a16cb3307a98474d7c8e12789e6adbb710134533f6a92f40e680da367d997b458fd770066c95a1f36bfce17be2ad596016f51c03d5351330f7cc0efc68ff86450be23375f6cfd2cf48502bff22bb744879aa738a1c1bf13758d2a816a9451ef0c0922ecbad55ded8670a8394f75cbcec285b600bf923ea14f4ed54ab764d30f0bf334a1c92d2f322106cfbde67169111c660f0f780e31b33b49cb6d17bad966f88aa58ed87104ca7ddae1efe19e5c0917240197f94f53f6094987ee3eb5379a8842a2c1f173757a043d1457e362248cb65364aba1a4a03d0ac8f9a06dbd0494ceeafc97f72e2df7658f57b59f5a96aee2b7459110f95e75b500b68bcc9234c9c9b4c192f5e0f5851b0ca96a95eae0a17e253ce21ba9bdcf0d75c30f9465743c30e67c7ebaea576f8e8ba8afe54d58fa678c1615ccd99b0ef55962ea0afb5159cb842a39f8e850798ce4fbaf85d78840bcda90b87782f31a2279cea26fad1adb146fbcf2e3a0b1e115dbf8e1852e139fa43035136d61a0982e474873e81473b214d0aa711994cc2db4bde177f655f5c7f77bf12e4e25024bc9fe97e000f85f09f788247566857c0fbd21c454f1306684d0b579e9365dc82c9c39b9c15cdef18fb428616b51e088ae6080ce309ff84cc542c7374ef3e149ccc26cde6af6894b680c7240d228c3102086a208bea52c83b2bce1925399731079fc8a0ffaf4fa12b4d6908b92c867d9d79285386b1cc27abb3f35a10564374ae62d638da4fdb5b99e1550731af5d2359967cca2d4c55c1c08086ccbbafd8ea29c9c4afc0de36046b527aab034a94971e393e7025f6a8728168f205a8062a34c29f404c3ad292f2e217e8a75dddb021b67eb163e3c057f2616297a9d7e1ffd5b4474d1bca0f337bc720849f39fcb7134248025c55ce13382c53a03517087f05776bd493ab39db60c652f002cb3b3d27f138ce83be0804c6f0f5be5223a3537b01141d5e3e7ec141a17fcabb6cd50bba076e3bcd69aeb7c3678fe2b260d2a3c031ce52a9ebc76eca605a2d3f3c75d2d432ec6c299e277079e0214a60a6c18ce98f78b740188312b35feeacd13cbfbe1f2dd9a64d4bccb6a2bf4db07b2a3188c9ef6ec51d36ca320aff60f50a96f9fbdad2d7aea24371c277414be5cb1f5c263655af7d685c3914d4d260440bfc99dd13ff0f6140742629d356329d6efde1094546ad9b213ce87fffec78b63ac5dd9745650b03ac5cb7a67772dacae04a2dc5c508acd01019f40204b5171614bc26fa96f6d85c2f0565b6f10c314c243a0c1542a012911051755c70a442445a8eba5c2689cb0de7a35f7b801c9fdbf34c5bce5d0abf53c9b52d3449b640bdc4bd9a657da0181a72bb2a6039fdca4dc1e75fc9663017310c7e4b070c7bff095333004414c4da21a4a50d8e54205a513b5a794cd06a4515f4c40d2eba6c53e6b175c69e122acff22103f752e67c436161a0ea6ec7908a44c4e48c7771d2457124ff7b2f27a9271482b50aae84af5866638eb99ee98f16bec5ad92f367da94a7379ae6bbf255d9671ffa0012050f7bdf189c862ca9d9794238ff7969b858f008fda95e58ceffc5124c46df597055367b2463d14cc747c3f7d45228e30ec61ec8cb2bd1cb46104f0c714b6d4f2a119dae3bb48f882f936cea1aebf1c0c817fc8645c149baee0bf7aef34bca8faf77180fedd7304abcec87796dbe50b777e48f473c4f9eee7b354db9914c6a5b5fccd9d7ef32c50b66dcd70f8041a4cbfa076a68c7771b867ff11a4b1cc7c8016b10739176decaa43f4b6345a4ea32605a09abdf7dfad0a7100790c3baaef7e8f91082f647595267a43a12c29323ff9e91e00c56db6c22ea8b0b12da8a99fc2422fc7958cf8f18fb31f8a36e13c789e7582072943a68a6bdbac192fb1bc5539d698f73c33759109badc0c4d891170b462b79df89d0a799bb38cca6edba137a8f0ff29f1022a7cd5c1634c7247b4c2b6faedf241f4b2ab185f631e7c9845cef880df4d6ff992cf41644facfc45c710b950c75f7891a6da1ff5905e704f9b710d5cfb7b683aa7aa69f56d08651fc48f20c39870ea9df0c14b5e86ca2ce976e4ea574759a26414b9a1e865409cb2239c579b0f32763db1a05e87188ad2827c3940db6920a8d92f5d9db6b11769b4c2d2fe83b258c161854bb4c06f78fb61ad7b09e5877cd2c3f7639f41ef90c7a2c14bf3ce557b3d85b4c9e2f97bf8ebf86103115b651a435de60b235c635f5558f0d2a3c61b75392f8738f777ca3327e2c3f9fe7c459ffd1a7b0aed9593f16a345888605462285a01e1c40541f3cf0e023fba2cf6a0fef32cc5afc38cb94c5e08763626858413c8b188473da79e4f3fd4b2eedcc24f77b5290c0db16fb44ec8ea6b3a1b9320b29d1c95be7a5778549166341d01a7d290f2e4b8f802edb63d2358df9fa98bfdc7993b7f8351f7d6686dffc96a369460867fba53f564e8e485b796288926c0fba228188796bbe35b66c3b54f76766c7e9341d34b012426cc08d03f5a68a2025b22aedc47a21656488eb349bb54f01c9983a9876f372a454a55bbc86ae840f244994442e5d7309be02010aaec0a9dfd6e40f327e30dbead1449a5d2223b0fd32295c9c2b577e234727d8574efa115e3b4c0a7c751106ada4b1048b9e97a7d26eb1ce743632d6894b614cf081b7fea0adf61bbc2885e0f9be3fc447d1020802c737028344c58f42b07c07331294e779576d06859a1f99fa1b7caa10e7cb84de6ac4b20da0d6ff6702cbf4c3bca9554458b4ed8a2609eb9492983fe695c700a105d71265a88d83e0b1562897ef0fb133af778c7f7ac02de3e59bff14653e5dfee13ec3229bdfebbe63b1731517e4926c40e975c9cd7a7f7693721f22c3bda3248c4439fc56f676c97dc8acfb7eb81677aa00b2ebce10cff73f49e6a74712e6c812d68a8e7c71c56ff74a604f167fe359e13f768e74c94f7f235ce20b912e2445baf438142f04582b63da9e973bba08e0c2a79551980aafd128ef55a470f2e7ffa6b2b705fa0c056ecc58418de851bc5b21ef3cbc8e06848b0e45fc4c3d67041d4daee88b18014425046b4fe4d7ebdc88a13dc6fe93c90017f7633676428b8161836f3ea60052c6c4a1219eb96b3a81827a6f18343540468077d533d75f5e854e4a37013f34438fec6b8dd02acbd9b8bb01bbfa84a5453d196f477dd3d3244ac35ff745daa82b2d855a3e82cea5f17b90b8b26bb698f765bc76a8cb704d50b20e61ffd925b973f31ac96e466cee6cac6bca7cb5ce4dbd1e974bb943d69638dabdfb8b599e657ba7cddb2ee54f9b4601a853be58caaa7ff51b66a019f8d021d9d9208fe14070c5b4dec92ed0c93e17d2170e705c49099a2e89939258d3f991947bb92be8380a6bf26b03e33c28cd8c4b59262c35eb38ac0d780c567684f74409d188c5359c5b8f88b1f1935084fa0d952bb9f7322c2e023d55c10708bc99cb095ec94a878e4b86eccf4d07eafafc303e98f83aa6761e1e816c3278ff58cbf7f0cceb2808fffe3b4038464f63172c08b2df1aa110095763403fc59587d6d39534682cbbad4bc44b1f250b9b6641fb146f9113b6a855e9340732ed0ac0374d02c8c22a462720ba564760829856e7da1ae5ba32eff661aa42de8b04a5036e49be4b61a279802e7df9317331232e79d55d5d400f666d4ea25d40e80b872d9a2126f18137983fd7f885e8e44cb520e1da5faa209188facfbd4a1a712d5f23b76828f58f6187b9ced054f45bc0e569cfe26a8258483ca42420149ada47654412421de74c3b5a2d18b8196ea9ddb953acc92a57959df196462764c42299daeb9f47a2e949bffce2dd4046941374eb12c8ccd189cf74a80252a9bdf4218e46df3656200224d52d94a5c53174c250b228d6f2d1c986f66fcad4bb1fb58cc60e0e8424480e380953b27588243173c2bb382cafef83597d19d1284fa5a3d527f5e2bc988a9cc7716cf754aa1de1b0da9aa64c6766cb789bb503e9f734c778bc05a04f77bc3f1ec9777495b548421392a8da5ea9f9401388c2165a1caeea7fce65167729021dc4c97d8a0237b91b4851e60802a2e6918ba3fdbcc25aeaad5f64111a7d7274fb3af3121a439012fdf67b74e7a0981c2a804014c733bc51067291d63e354e46f64fbe4ee12a722584cc2419c6590953fafb3a96918a48d6664843c861fea37472632d36a948e554509ae2985bdcc8be081a26dafe8fa2c42f456a85c46bdd6bf6fdbb7d4f4cc4cd593b3aa6b3d7c343fbac3b04629edddcab1de8202e68029b7d708345240b2dae47cbcc0067905ae4392b43b202e949f73b32b1712d72de1fdb578fecf0bd7e8993cb7baafac4b462019265d42ed377621c34d980527571337b6aa4214c9107f37428b4f5557422ea0494a717e28dc0082c8ae52ad342cb617943795ea733839f4e4fe76df91576458373660e6c55380f2a55ea68b53be031647be7982f230fbdfef6290f37bb6df92f776f0cd38c055985ce28a3ff5acbce0b4930b3040ed21fe06d43428d184c2b3dd8a761e72af6cc163fda59b134e4cf6b7261fe8633b1340ce3f78193018d66b387c1faebcf51b9422a287003104f75c543f213326e52e6e18eea602f4606977dd0aee592f25a322eb6215108f86165ea50fe16367fa76118753fad3248d53969c0ddc804a693c05dbc69ac04b13c6ae562d3aaceb097695c87831d88e110b7140baf92f0e192d269cb18ad294ab35fb960f036e33fd5723ce574e03ea836429eb18135f11ba1864fb888532aa80f2a0a6b7c255ec76121dc634ebf66573c85ff804feb4103b2a71f0ddb0cbf63d05f92260727252f23962e375e27f0c58a55be92925fd1e47dcbbd7b0555c000ded065293f087f9863b75e3dcb73246d30f286ad85aad1a412f0a24669b340ba3f9d51277d71c7dfbc2d2dc0b99be4c02dd84761dedf3f2b9856bdadb10b093ff41cc98c4d7a7e20c26c5fd381d540b89071f1942857f55aab419af4cc38838c98aa51fffb8c15913a408b3686e244b11bd2502bb4bce31172890095ffa9c9c29e1e2741f8ac86c2c1b2f80d6db25add11f85a7e2fe9660ca3b96a1e7cde43cce4a53a6f95979a98cfdadbfa113fd28f9d35d9ccbe434e67ce5594f1b5db3436e716e129d1cf50178974f2be530280e2f47dd8549957e18aa122485a4196b1921cfd4b9dfaf80a1a47dfe8cb292a4c52aa7bcde23cd2f3f4e93f48a4ce28858a61827f607777da35fa7e591dd6fd0a50d202c9165345e8d563b4564c35385021b1177fcd7d03280315d3b5cd95d189c51fd602899b89288194876dbcd5e03772aa5f5f44f7cd26757eeca88145e5a13a4fa1f4d9ad61038c91d2fd6a4b1063100b2d0b62eff0137b4cbee02858be92ad91b05b85ba5fe1d939eda299c";
     const BUFF_64KB_14: vector<u8> =x"15c0e63e52b19f5951d7ae970d231f6687b35f07569cc14da4c708753da03907732f3d072cc3e4c6210d57c3c3aed55cdb32232790a336a83fc80dc316aa28f28ba79ac75978dd6d0754b3319925c0be6e359c2c92cbdb8905dec93a1db046f36003f1b567441488278d90a27dccfed751f6b9f2f3db8be12f27ba5b6526d3501f8da5aee55340a6dfd2e001a31700a5c880ed782707d295b9f7e92c1c95ffc00c6ebbf4a6a67604aaa55fb25dc120f1f6cf60d622edd407d716da4d3dbeca21bef249620d28c3e875431114c944eda197c54680fb214f149733b5fa2bce097bc9a90bf2a1553920574ec9ea25256906caeafe05746e17ee57dc8905dbf9e1c043106f33ab612281c9eec272ad83c3e18546ff2a2af3d15a6c344cd239e2c30eddac22fe0e73bcc42c4f0f9e017f40fc717b945d9d49f70101ba3cb29d37a5959586c500512766af94e43afd2cb067822a00f5a757aede0d117670b63fae558c00c7a969ea9efb205fa1fd787696236f9d0c0c523922dd117b215c76a659fc565173ff053eb62324ac41b6770647eac12e0afb4577b79e5d5edcf4d5591d6c02277d36e6ca980a57ea0611412277b5be048215324b1985afb3cfd0da298714bdba8c04c164c9bbc2d1ca7b3ffeaac81a4bdfc713d0b01cd704249f6a1651d8660717e7a5f9004eb3ec279218a5220815791d5026fee0764413c61991105e1f720032aee85f90a36a7d9d638ab88d9874869daee0902d7834419f32f9a5eb15d33d2c9101f623917f82372004d9c6a89f12c27ec3778f00e043216eb241b4a047b07977bca57e0ca700de2012b206844de0c04eccd91781a8910c3a003e46fa8022987c775843cabdc1682213c3542d2a3fa705cdcb9dd260250caee0699c56fc348a2fba1a3d28055317d501cc7009c25472ab9d1c60b5ad5fbbb79237f4b78a45dd9ca9a3658d987cd39df50c8d9d807aca0237682b16537fbcc54c6de831d008e5506b41d9ad16b07ab42dc0076024a2881c5432b1010ef50d99df58fcdbc9e6cca903fb8545925b26fe304d037da81e0c13647e98b65cac93a0fe1a8dc8f0ace732979ed848fabfd74d53ea7664059988b40f0260e725da254c9d6f7297ee9bca10d87d5d1ea9775b50d3fb02bd20a3158e309b35b24a5d1440d922871a04fcedfe743bb7e272f95700c34ed837e6f5a926b70a61a9e65128194b8dd5e1b48d2b38cfadf3222a33f7487019d74ead3a9819bf53fc76709309e5a50855452a4a2c57ca49ea64d50df9a22121ede045cba1a44f2ea35f8ede7996da637161017e21f1c1a897fb4f132575c3cb83291b8d39e939c2962f977940cc895a014830c77ecd672f9c4e261db2be32c8cf16cf8ffa4e94ab4e2dd420774db94047f9ae6f5afd87cf12e8d92debd929787e98c42baf6725aaa3b6a704618082f2d53aa4a2674448adc0f83313731a407bf3164b3b9235ce36284a3408c3cfd5a38db76aaa12af336a2c9be292849c8fb6bfe0fa170065a965ea211cac225668a1b54b436ac7434b6be217bc12f4ccb2d516383dffa6a660556f7aa7e4cef9b433c83965847444e9445c254c60f9a91cb5467a4b282d0e43fe605467438f8dccbab85539ced9353d1e91b2d14aeb790cf3182236414e2479019f96e2a0a9ecdcb978ba799904bb622d4612b546fecf97c1a5310eccfc1ed19854875c5ff7989412d4b32c94511c6becfd66a5d228a5d849c0de32002741c568a3128aacc01313c78d8e3a5a24004eda46a173977c870e159c82aa5f2ff03c3b4d3f18027b95e1d20728878057898b8d12546d622bd600ffa784a9a7caf6cf9f457583cff87a8657d7d9615f4d405059d87be383d456e16416bea051e4cc964b4e588ed362870c2002aef60b8aaaa443ddb728aaf505441599914d0e618b88b98c2734fa970e7f2bb993b8729d7ba08ebb296dee06acedc6ea9def519ce41aa4d569bdc6b8d857ae28c9ee77167e32294a775a0e1203713b94e5658199ee5f14ade86899bd99b94f7faa7431985fc9ca981b423b9b8170f2d403fb009d1529b9ea82c1bc7a15f6371a8e2e09b0f58e46f217e3e20014f4b55ca63a62e52550a5e5c72e09c31147bc7f0d6a2adafa84c9365f71dcfbb961b252a99077f36bc4ab66211fd2adaa79aba7e0d513b56342c3dce4cb0f15e6b14c9532c6cc9fd2a22ec48904f7a88e50f8a1c214b3c314ea6ad23d547c053e97a986dc6021a01789c09ac0a6778fc4d353df404a7d8b5586aba79ec0476e7384f11852c1e53c7d1f4c4385ec3f616574ac252b8b88a0e913d09c813f0a0a03637fccd188fb94e2a21fd150b75089fae3060ae13f49ae56bf3ea78c4ed0fab6a72aba1dd8df284e242b1a2e9ed59ca4ce4f2306d9d84debcbdff453b9e3edf0e0e09763a850387565749024da5f92f095f6f178a09ebfef0801344e8914665f3308cea62b1c782ad00301f3b53037af86fd64a9f5e4a932992304645917f0d34d991e35813b5a88553c1649a54837341d0b7a57499c03f27108e3fb6c9173452440664c6148ef2aee5fad67ea5071865e27b6b2cf2c578a90394d97fe2f16b1dfe7d981718dbd5deeae15421f3ed20578268a800f1d713d1ec029310ee762b5095874e6e32c3a78f7755d67e72e207657d1dca12d2c851a597d6b1b842271a08641e04ab7555351d2978edf22ce1b3cced686b38c979ec8beb985c7f53e4ae0f6a3d04405969148fef6ef1fb20c35bb1bc2678ffd35d9828102b17a0463a62eb71840c3c8de2f3c1ee05173b123e3e8528108b1e1f89f9956f2a145f09520f56b18e1ef45fc7fdca01a1f3ecb4c8d3ed27611b5f62d6a10eecf403b55c859a5563605d42726c0afa9e42fab93e85eea389e010ac5636fb88b086f886f5aaead0d8b5612b5119071da3f7a635a3fa7e8a6a20e059018a1dd4938ea3d6e7fce75bc67afcb5ab947000de6445c13b50366d4392e0ee3049b90fa6858cebb7686632109d05aa17ca6cb71a9ad4930d722b64bb05800c9532a4a23b4ca05309831d3196210d86be0c12f7a7c146e9e3378405ce03c92ec33431a45337b1724b2c4defdab0c643848c05f8f3f7e7a27aaeac3d7ef59fb91ed6688ae586e33d78ff51fb1d0bcd876bdac61b220d4aaff3064be9c056696b146efdbb0840c9bedfc77022f845531cb103efd9b7666d138a663d476c6b11ea619db318acc9b90dac08b1667366520528be68448bc2654de42ac48968a4a2eb4a4dc8d6334f80787860200b41b8c83f3a5e0498be18ef49a56526d16abc8833e5e1238df75fb148e4657ed1f1e3842a05efff26181b30df696892d6d1e770ecd9f4506439c15e02b63ee9670689364cfbe08d4af2909b08503294b774d3f736ba62495ed1bebc2c5fd870514ef85f1e8dc9bfa9078788fc8f4d43f874fe019a93dc43999d1e37d257756825b36de40c8ee59704814e9a4dafa5b6e7069106e203586a46d2154fd32e272d0e95c711e60a7e4bfc80f37be2fe1c999ef6413d6a825ecdb95e233050b5de89c62be461622f211347bb44c7ece84828f6c961ebbb2997642170a8ff98cf5848ad9ed4cb8b5003c6090142eedb64ceeb1fccc73d40960295ece5fbaaad64bf122269f5a1e974f70048db1f088a16fd7f1d6341a55d95f9fd4929d469418091925fca10816d7977115b4c448ebf407e477533f03acd8228bf9f39d9606b6ddd1595a40fc01c43caed806a748da06596e1992a7884f061204b8ff9e1c94323003c470329dcbff14fe5db7b17bb2d8b2b8d52568001aef3983fd9d5c2488d0b9e14c947e6db4dcbb05ce64778f7a3632dea2696fabb5e2d08b8fd2768028158163b7a03afc8fe44e5d6be758937523d37ebc59bb10e661598afac4534ac3bbb8fc29c64f022c8a1ae8ef8b435edaabb37da4567923ebef5b15694a6546d4eb46f027a9b64b0a61ae3db390e06c3ea837dbd54a5ce5afba6347ed0ea90d3dc540119000754ef45f9d195e1483b055c58ad2c930afccbb58ee7629250e158f91ac58aa16523df0d3702e8ded36f04065dc443a7d0bdecea53fa6366f4c6a01163071a32809601f71860dd0e975e8546d07b75917b283206e13a19d5909702d92d67a1013601a8e9821a2d0eec5bb30b3862ccc7f425f7c4594a4bca19f864debecd9da7759c4e6c4db414a8dc763f44d5d4602c465d26c3519f4fff8e71f34a7f9a8cfb4919134c671dd631e99bc00c54e62f8744da6ef85a04ede60f77d95dc049f893128badb27472a2a389a74afb89272e4db3934ce3e3e359f58b11d3f736466c2db0f686a5f4c5529aee4de974711ac535ae78c4710fa0ef7832b8f0f0e460c705d00a28e043ade61ea83034a8a832886f687bcd2d27631a94f81167365c123fbfddb981b9043de4bbe63d8dd964fcad2516f9b8566e5cc8e9d355e02477b3c0776e5dab30bd6e185c4bc2b92d574041076faf176bdba5c7abc9c2630e66ba34b1007a7c9f1ba847a601a43017c87f892a1ad2f6d516549313482b7a8e3b5444bbfe8d274489b1eb753e3d8dad0827638667b0c79088027f8a5333a4656f1ef3cc710c9b614d909c1aedcffb8aacd63e0ab1a4c9cce6f6520cc5cb4ab64464572e805a90f6cdba9100293f2dabfc870ea2e6d35b3ff0d4219836cfeab75c10273312d7450bfe2886c6d8a0a7e982347c8cb4a517b2adfaeb899836c28d1fc1df663207f3c9559203faa6cb540ae3d4f4062722aef276038e515344eeebdba9c1d37e22ed5a50d499c34f95973fc19997311f2637664598f19aef78c156756fef782beaf71aeb58d2221686b146d609e3490e87e88369c451fc224d609e2da6ff0e27ee61864b08df3918703e81b0f7bee14019f4f1f4e31e2f718757384eb5861572683ff0be1b5d9ed53c37075889098225f966286751a5c5a106613f7ca428f8193c3ec3f15f8fa9b687c84dcb4ae82be373c748cad75f0c5666eb44011747547a155a1216aa6f98cbc6d1c1a2ccfd911a6747e5d64f0c4778d7cdc3b4f5bd834c485aefeefa2a1410409a38be513a4ad7226144f4c6ebb19f8de70c6cce2876cb20acac9817b7a7ad2155ad4b94fcbabbfd6bad1b9c3c3f3d664096694bff2bf0ec2660cb5243bb1d445440ddb3930f6a13235237edad3f341426236c25d8e5df87b5f5f26d260bfe85443220efe2c78717969b243ed3cc776c23e67d579462eda0a50bf585ef473bb659e3a043dc0cb60517ec65398e262b692eb38ea055604ed43a34ef7e1434ece54d825790dcc5177922153d9683eaf53142244767c5155f6f9f9f643dfbce407b83a57ce740e279aacf274bd11b99774e1592d4f501901589fc8369f08c4e1fe42705a560db8c7fdc76c5a1930e379499b2f21a8c410c6018f3ad084b41face9cf7002c6c06ee36c01b7f08d3d13d65a090d40d1cdc267439a7b455eb5a98edc72f92509c88a351dad0e14d202302e7600d323e93a2b29b278b0fa9f538d3e43d27cc43aee103520760004ace277f0e2c665190a21601556030fef52d4b5ef1850da56db1c5a491f38b61917f327ce41008cd23a173cd43df04293472581924cb91d170a52b1d0e26b8d6dfdd97c8801b635792a1c8708b15f60933de72bc4def347cea41a1df02dea7ffd65fe30b571ee0ca8878dace1900dfb16f5fddd9a748e04ee8c3bf1f7b8831a33f790ba85b48452534a39776f7facb7890bfe5bc6153c9fa10fd6d87edd103286f4ddecdcaead0238fed64f53450d8be02b7a0f83a35ab61db08c30195f11d251c4d929abc4aebcd9a2998a2da3d8ce7d192e94647cf8e89582658e8ae09921cea7f44a2610e406c392079d3f26c34e4d150e21dfd0acb2dff8a2178db17e05e999597410427cea3653435efbc3ff905f2fc4e7b6c696d1fc9318d70907722166b381dd98af9fbf9a16b04e6668eaf9c3b23624a5a55cf9f9eff926fc9a7aaf07f46d0545f961106ce668a7f4eef279f015f9e36cbe87c9c328099e99535b6a8a2a9b6da0b5c36c1b1ba16f64d018809a5304334160a6d92f707ad4a3518cc9d96c43693c0aa50a275cc89568424af44e86709deaa4a3bba43e7ae27d8180b63fb5c8a7c69405ac67ad382996b0ed0bdc6a7fd1461ddd614f1d3581ac51250bf97b5c375b8e0d7a1f9e1af20e013204423041579943c5144f8850001aebb6c068e72d8bfbc972c37ff3842c25c5f8ae1df16f63c9096dcf55d7a5cee4807c86ea96d0ec31860779da30ba2a7bec941d09f44798bf21d64b65a0276f23aeeeaab1aafb0af77f4cc1ee5abb38e151f754e33ddb26de10a82a188ac0367070004c0e6b6eaa24d46e41a1c982311016639bff684813a50f71e6afa777f55f0a92d524b6c0b6d6332b21984617e8a0454bb389a6cf7b6658a4ebe89d9d2cc82a412709174bbe20342ef5852ad474bae15e13883bd96a799f660d1d0c86d6e9d5b1d7200ae982dbd67eaaae2c7ff905b55fae105d229d86f004568e866a1f5e20bf328278db3c01c8be2d6e2734fa03489890d1735ff1aa7a83c436da689f8a56285e5e6af680908d7408b6f2627ee30bb92db73f45a77e498967625c740547ea2299d129911733fce04d55b9126b67d320a8545912623cd8b8b66d2105a8eb1cb317cea921e1a2422aadd8766c58fb8f36be44dd0513c132d9936728f737bfbbdbe83822db5e62a25569224dca2581243e45384b2f55c5df9dcf906197297c563acdcf322854d9114898ff3c1336277f840299c641db69b20495e930670b3dbc9a0c3df3bff03a9a3dc06a27b27e6566a68ac3686d08d66f939914364455feb826d4ce5d7befa49ca52761966f19063a80901f52010d1034328e184ab28f718cbaca96f854d6264aa3d6e0e18193faeb47bb31ca08f2688b7d3c1fbb82e3fc424067c3db2cb75eb3a43118337e07eab5fdffcc73eff0697a5bd338328923599c5cdce722e90bc10573517999ca52f82955a6f6f0da28360fe903b4fbf1fae08bc595f73031ce25c557dc7b1376aac845cdd90270e7fc0718fdda473845d8139eff4f7b4bf564ba8965c8c38f651e156ad797bfb2be753773c9497640446601df971d84b15d56920087addcc37893f2e39cf1c630c0cf3c95426859e8a7d473cc4de97cc6f5d41867ad0dd46896580ba23441e57ad7583e9afab56889ea8b78db91bccafbb8159108a77bd103e7ab28dc6d51c3b1f46e372f14aab8ca8d456db59909d5982b02555cfaadcf9772c60d593408d69c59c56e75e4348a22a5c770f480f6008925332dc5ad3fefd1717fee32c1e1f2d671b6cc25e94e3d2a7d834b44c68df0359b2280b6c317bee6a4cb18574bb3e172692af2649a0065661da67797ce4b8e00336eb9db63f33ddf652787b9dbff77d5704bb7d2369baf22aa7730216a31eb73c1e9ecfc200a5ec2d2d0ad046108bbe201c1e8ac2936ec252f6870a9215a833526780079e9ffe090ef7984b37e023392ad3e1d6c4632942fe88149e7af56900815062ff9edbcb33b9cd1347ed948026fc66961d023bc1c57024d10625fbe5ce8c604f723c7978d18f537c86acd2f8de4394f061b2eabb056301a1617eb82e673e3c3deb4be2cfcbc2013a3ee542a8808e9f6eb1d034c888d7845ffdeccaecc3ca63d72297f8d52d2cd23f6325a8da9d493f5187474b93d23872d834af17364850ef5246a9e233e861bfb8d34f5b614d6e690dcfbedb69c4b821340c41994df56eae72f10bbd63b2cfe71aee614cb56b26bc3ab2a642ac89cdc1297d52ad78905fc3270d3f00c01ec032abdd93c8b562518495df99a4373ab3975204cf0104c11fcf3b53e3d0867db53b97fbaf9e00ea9e062942d252567a9003efce2f9e6edf2d43fc3a72a6bb88ad7982b5ba85627ebc0e08a2993f685cd6fe5b8920038a51dda4bf6c64c4a634d805377a69e5dd6e279afdad4f7571bce42545e996c184ecc606f36e0435a924198bcdd709af9d4c2215c922b77441242ecb5a983bd5fb1d0a10e7c586b6c61d4ac2ea388124d19ae36ccdd2643296fa820ff6363bae1278ac15ef315e8b71718db6c78645038e6a2203d916c601258967f36eb32d8332ca52ae173c3e8ef1592dc5a243b9a9f7f5bf55ddc20d7e21aceb40cb0e3ade9a9bba18b75a53dc90421cabba857c07a3e0b2676dadf560fb9e20da190facc999ccfbaa52d74662e0691cedca391e6f650f07e7e3686b87d82d66b732d910277adf8b9b5a06434a0c7263a6a07335cc738ee2c0c58ace75eaef1ad940bacfdc349c9b70b1f51757a27ca7d06e710b0983a368bff128b52637fbcc62a40202bd0b6af894f6caad8b5ee591c48d51513995f0812623fdfb7af6cf4642ca1dad8cf5beaa2228649a86f4fb9317635e77e9f045efbf9f3789c74ab753224c938dccf7689c81820be86bee766f04b9cf6b125d4d930d5b0322b964232c553c420d8f9ba803c4c971bb0f634c8f04adc3e8530e70b460fdc77255c252ccb54783c19b1672ddb254b5d993040b1f065c659817ebc7a822fdd0d04bc4897d7769b4c086da86bb51e5081f4ffaeb1f0c3455083fbf60100bf012270eff599cee5004adba4083eb0b37d102cb2cb0b207542373a0b85648d3f65ba0793f65a7074a20e6f1ce0a3276eacf4a08927c3a1130b97b6061e6597d2587e89103ada6fb8c3d56e40f9d019fbc2e814d3ce2d1e56c757acd96f4fdeb00765758331075db28144d00dc78ded213491de767d17b22f404c4a859811c4c75ab1418532f36b704f943783e47708609a5368d0d2819230519502517b8f919934be4c722dc04ca88eddf52e8c0e51616801660b87ad3e3eb681e59ed36e13a49abaacd7ea2d65d1b80d32530bbadbe355bdeb37d1f409e74829352750a64e79e47a3884fd097c2e58fdfab08b391d23c24e3e9cfdf30eb6d65cb2111bccb50edcc73e0516f41cc63f57aeb28e7617f8b86589b7491259bb91f49c2a60a48e887daf44ec335e0fa31a54a56671b79929267c4aef4ef930f85344d9ee1adf688980864537d1e8a015603c9cf991ceb8c9f2086a0c7eefa0a0eaf8da135bd5cf896e0092f5cc5a8bdde8c2548557ca8303482c2f4e0c64117c44614ebffd0462399bae699a9cdfb88093c381da5273f63cb338643c7d91741c914814e71797e7d385cbccf4089ea81849c45f21fde2db67d57237873ac1ce5ff4f2f50cd483e90b52fdcca7114c6db41baac365eb9717b701f47e8e876ff436596384a0b1a5f916a163706a4457738b53b0517d590a51e730f21d8c9bd910cbcabf0247f1609802050e44af2f592b3b1b710afda8ece60d4ae7aafd462f2ea34e8314ae53e8cae48c6fed9d3ac5e7387c76d0962daa5e8e29b4c28bc4bd63a7c6f3d3004d8973191806b610a29f9e2c335c3000fae443bb0079196c625f38dcbac93bfaeb252ea30098979899c67b5c141321923cfb9d038925e21c7800677c96abacc811c49b69bd8f2eb4c82fea87b09fa130222a74df49f14ea502852f51cb4d6252e44a1fb629a2dd66d86565d1bc044b290ad3b8ab1585394686a3a6e9b6e5393c1f7fd6484a7b9e2f73cd414ad3632adb0df572949410dc211c5dd9ac397cbb7543b5710d6e64d4495bd4001b3a6cc377b8392c8de2281ea9ac0ab3fe9d56eba10e4237a965e2ba437f99295aa6bb18c68ed9e5b0351a3b046c9801fa3266fa686cbd7cafc33820b22f815b8e58c0a8e4a4d9c012ba34652bf223160a6371d68b390ec1763c85555581ebb6d28887bbfcffbf2943da0d1212098c8d5bf6cba2b8df74e9d444bc45b45db76ae2df564708ac0f936e6f52b70e096c39b91b61a043c62d8eaa8bc9765f44757b78db2f4c29133b6a096721d04707dbacc497561202a5ef10988af0486688d2ef5bfd221448a84adeece524633051939b7d96e07b31c000037ff0ead05458e3117e1b30be94fdaaac1a7868c947b2c03ac0db19fe9d239b39ef5a2319ac733ca2d6d4f0eb51bd58894a0c98a666277539d9ece8ebe7e616568d83fd0f8fdfbf4c1a7326feb17e8bd6b63f14826bfcd4b6272302bc1c6b8af522197e534a0eb23b49a92108124dcbce0773f7f26ac4b8a8582b064c45ce336c15e77caaef0aea4519bc38aa9a9ea590511908cd3b9b15bcd6fcceb7ddaa97ff2fdb36575afde5c07281584ae6c6669f85b580586083ea4c2b408a679276c8355379031452a3b459c8bda1cf2e2a84c6351847c10b82db0a55d1ab365b82c56701d448faf900eba14d19e4a3a37029605aab7a5c1df79ae119e53c6629be31f804840e971ee8ebf0741197ca26613bd5fcb16166e37f7baf4560f404d726b9da69ad1f041ad15d6fa759235d9fbdadea67aaf0c5a633ed78562bc77df2bd482cb7a4ea8e73b78f3c7fbc1bf9e02421190794f28ffefa1b5a1f007162328679087b61b03f974a307cb3c0164bbbaf5904043e2905984fc3c62e0953f25517cd6e0df00984cf946253d62851d6780ca7f6baff889919a1e22c3c15cd2caab13a303213160c5b2bcb153bd0f737a8ada23dd5d37f90045f6ac530697e95cb01e03e14a8ec3ed58b808913f3769c598696082ddc2d5c43e170fe548f95acb5ba535d80b6c8f68b4c4b50230b78c9cf821fdc206bdf8ebf38b379084177f94b177dca82fdba7e952a1dae2f1b28dfbba472242e3ed81a747c6c8605ac2a6ff0d91afe9565ff652a8023636c3f7e400d5484bc9d5db5c9623d99e647bf09ae14eb9e4073c53e0d58cefc8ee4b3ef71fbad839836c8c85f117209513e8084280ce00502463dbbcae35089dff9c894a36133b9cbe40038c40dae9fab2c2079f49bffdff5b6a9eddcd4c48c53cddcbbadb5c252972957eaf615a30056aceeb9248f64f847b6dab84fcae37dc11558dc748f3fb786b7b3c3d712bd58b02cc2a824440c149baf14c238c68f58470830d2caedc938f97375ded0dfd56553b69402c49a0ba5fab9ab076ea4468bfcf5ef2c174d3deb06f704960fa2cbf116e0a2c4ed91783c066ee8653031c951f2d0060c0b3ba70bebccf9f2142485732e92198cd5e852539fc9dbdb753348c47700f2aaa28cd4d6c487fadbee2635a2b535c05851d726807ccf49cbfa85698f5ca729a36c8f7bdfaa4523eb156a47b37909de3cce85b8a0e7d0825e15e7e889d58703052db4d053d5d69521e1b346c099a2091c093bb89c10cd387cafe37d7152926264e4ae0c12cd10c5174f9960ce54190bc1216752de5c9d3b81285770296a205adccf7efe9db7358a54643cb4c7375646912b8e599d000286672fe2105793047e92186a9560815011ca30eadf657a44bad223085f6860376a669db64bd1c3870dadb55ab9a0ea673c098ce0813facc40c0a79b700008b2667efb34865420b700fd481969b1bc60db0466958b094257fffd67a2622cdbb6279d446be252beec191963daff553bb8bc1cfda5dfceba5d35d3267a945f5d794ca20496a188365f6d79b312523043349c30f9c43bd463b14030e040cbbf2fe37f1134da77367360f3ae9b46d858395a14c72f8b3abe23b74fad5e18e5404e7ae993c34526d9d821e3f56d9f0d3742660b72b5b193a0e4d6f290a11102b6f45569ea5e167648356b03e8b907d1104fc1929c12fcfc88b6949ccca4107acad56a8e2f245930ce8ef5283e6e14fa8c48edeff1aa04f4b6103e91aaeab9716c91dd2ce616172f3ba0145203e638b2fac86c69addc708f5469cc4a197eb7110cf3a03c4d4b5ead5e3a1babedeae4b5b09a69571411c4f337818309bb2e6ab416d2120e3d5efd7210433ca0caf69a1cfd206b0cfc06bd11d136bff00b47a93fe5743412ea1d8171a48c6e0d60375d5e0e040c3facdf9a9e55ad31b8fa1ee4f955eb3493283b191bdc184eea994ea4b37ac51325765de09e65f157ad62f7e42b1126ab800d1f221388276f4d3f538912c2f7a489a9a2c0d6df9604aa15d3932c5e15079201b8ecd58d0ba5f137a17ecb155472922cfb7c0cc2d982484e4ede52ef791520dfe81ad6c42ab409fac59793e17c139ae2af43926dbbb05c8f75b58e190772cc9118dff0cf305fc277c4668391be5bbc4128480e2b50224ee97d093929fd07c00bc33c8f2495cfbf7ff0fc66eeeffe0cb8cb85dc06c942a6944cd15282829a5533fb1de71987711cdf244d582698a53ade46ebedf5a858a4522c085f076edeac2befdab3aaf3275a194ede26a81c648be314d8923f4834dfde2d770b6a8f3fb9f535f893f1d91434dacb84245a69a23168854e7a62e45358dfcb6b25d04f4ca10edb9ade052b2cc30cb5ef8aca09b94e716dc3acaf73cad8811107a24859e9936809496587f9573281282ca0af0bee2df2504c1ccc6a1007edc259994598bfc8d0be263357b4e682f79bfc826a76860a5d2d3074092df4f72e462c339746c33c483da80240fef76f8dfcde2fe7376057ccadd7638d1d5b171042fb18ef24591ed692696706c690eb15e74fa7ef0bd22652fc402e9809389d7321ab7e4a572cc546a00f2998fded77479b4fa4b3089dac1eb5aa18a0bd1ce49a0a102e259c35d07da74e906917dd7551378187e203a4c469512fbee7b59751c0627663ef5f4ddea7d410804c7822bac07870b3012474e038357509ae1d82f613c71be3950d03cd8c6008c5ef885751cd77fb085935a8fb764c1336e5d3428a618742c9b49b84713afadf05c3b600ddb9a83f0454aaf0d457822c3d27362ddc0956616199daf48ba753d26f6b0f06ace91ff97f0ef901b6e0d55e010d5a72de7276f5b17ee4bf1326feed3209d07eed87dcc729ba175ef65fe35b87f3ad517bd45017edeed1b464680f64ebdd0162c42b7817bd9cbda19231f90238d81bafe93c53ab9d3aac707961f99bc16ea13280458e34fcc48af63ad8e3610ccdcd3f66b6f6b63379943388123366ff893946a004b7ae9d88870a73bffacf32cd4df240367ec2822e27d710c9bd446063d93ab600271ffd599e5f6a72f74c37c5eb65a8389ca80e088c57efb221239ea44e13212fd61113940f997fcb1dd981e88631fb7ccc8fe572990ad3d73153a7aca898206e78d3363ce08cb17345a764ac1ba65b09e4ae297e0a3bd1c410275d4589723f9b7c5bb67ea525babf9f010b754ba1f3ee38dd86edd3ee52926b9828a9bc086cb8ffc3b66df1a3c48cb9d0826a3e65dd73121f70f59a7f5c6f76985b9c5c826b04c61d9c4bf31e92483c49173dc2c55448f293064e9202d227404dcee65cf467fed2c840fd52929fecd69f3bdb63c07708a8dee7a4714e4a60014e2afd7fd17460c46d1e22d66ec4462992b5674de1a74d7d219034afbab523d1379058dbcd0f2ed824f23c9846342ab0b28c7c2c885916adcc0a67ddd850eeb5e9665fcfb528fdcbbad91f22b3cd965bc3bcb432154f59e5ce6ea5951223289c430395786cc5586f788818699c984b57ac3a48f8b6b5491ebb31936be197289f34e03748be4733bd724c9864c38309ef21a7fe36a4f3cb9ffc9c6c052e0b7c35e38e32dc9827b7b5db4c1320f2faa095646d713648ae8cdc5e4000d936ba2df7a992d4f4c74d8b447036d66d0bc5c822e90ec639eff87136c98d0cc044360e96d4e36ddbe2d9468efa736dd18aa155fbc109335948b898d11b55f716ea33fb8367cf4bb872bf98b181549b8c8da81bc72b6d4cdb6829bdb3845417896f3a9891f465d634c223656fe02115f7ebfc9d999d9f27ee631421a44be1611ba85e5860d53ae451785eb7298334032a537d4fc0183da64bba782c4b7c1ba61112a6a6c5f8cdee8d47f24834d5c0b80b813debc247a9f5cb270b20d81a77244e24ab1641b77574c943344caecd6fc0662e75f04af5cccbd74f2b39cafbd8689b82c649ec5c92abcfa193a19bd994d8109d02648fbd9d41d97c4b9174035b831f4f6e5d02c4b5c30f8353953ae9e41255899be72c19b81ba607f2b28afe59f3f34d6953404567f52ca67fa8778470922bfad0d4679b46ece5af8e1b43808ada0ef9fe1e347987f6d9f0617cc076974cbd00148e75a59d74ece5ad5b0132850080ddbe776945bb73fd43395aed49d2d5d478bcbc0d8e5ae7e0013db7c69644951e8fb69dcf61ab65cbd2df1dc1e9949168183d0474aef07aef2a518065ac6bb8f30131aa86e121892652d276a082498a510c65c1a12a0e241245d50b71ebb3cbb1b5bdf2b255d3b99507da7bb7961c2db55a6166dd836c1bd824d3e378cf0bc6284b00e0fecebc13d372011b88c2453bfc2c4f4a094ef4bacae855a0cbb3351f5c6c0789d4d7fee8186c40542dda22ac42ced0e2fa3a12ab85a895caf58f42199faf1623997c6e1f195400764c163b0f60ae15a1fb25cfc6106930d4e2c1a4ec5536006f89789901650c6409377170ca2d6a13793edda0fa1ed8b32086a2c57f8f6695b323095bfc4f29795163f199da682a20ca3c70cf4dea916a1d647074abcc06a5cce9d706baad7eb32b6ed571d34f9cf7ef80fe134e415a05aaa6e2d4ca2312ffac5317a324ec623dbda406ae420d5a9d008644349162f11dcf5e23c45ec910132235eea6165c3a3d5ebf30e6cff4044a4a65bd34d7035400c70f1fa4b5d3223c2eec7d829705f2e8bcdc7ba510a77432a3eff6f4be7775996df7bfb44a9d0afdd7768e5ee3ad41bfbf3794b4c41059dee523116e53426cb7bce1c31f34959545eb2f5ed55eb854de27236ba0367c73ac67b2485b46cb23d280f4e302a0c05147dec12adef65a84f2becb3c901d632f41a2b80ec065535562e52376831bd2cc6349dce2af8fd27f01dde22447367bc201f9d1d83d864b5ba4a392e8348b86e28a6707b4ce6aa3a59f68f553f045baf9940adae117d5e9874ed1e3c373baed8ae72701694b1677e048b3f6a8c58b4acff6d8251a4c906376aa8db2f71beb12643d4286550f3ced2f2cceba202274249c935721e9fa7ac93cc8d27b82954bf0eda2c1a5e903f01fbe9a3ddd5a413746e093828cee25d01c0b7848a4ff83a68e27dc751f2a210c0943faf361cce2d471d5f32e5d6ecbad54e4d9a9d752b0e9244a86cb2742eff5b1c27cd2f9ee497b626b47a7b227acbb5fb076c1147428cd4c54ad57858585e1d7149b90a5b7741a78f4e9ec876f53410b7c26fd3f939050bf56a19e42e5012b54dbaa7961708ca1fd8b46f4e10d537ce4d725c6f9ff9c51fd8f49e98b17378732f9d5e459529e4945def341ece9fbeaa6982ea0e36b2783202d441ac98a410b45b032d7c628a2507268daa04fef14aa6eb248891ffa2043a879918ca5a179ff93791894a989f9152409ff4eb2bd3b4706c6fa2b6792222d0b707e66209fced2c260cfa42969e6ac784dc2e7a63400ca57bf667e9164f15940e30f86617b77d00b8690b74cb93a2be5886456fc1297a1d1e4ca7866ec6592133f5e8e57e74242dbeeac7c5ba4790dfb21d52aa68088a413eaf7f91ed2dee657b13c9c5d3e9aea8e7ffff859ac121566cd7356bf3fc8d2a388ee54d7d939ae5bb286dde562b6ceef513abcdd52f12bbeb0852406cf43abe41ffa1a96caba517454caa347452cb2337a8be3f654158f4d1d516402399c6673f0fc1d3d7d033915f3e8cd50bcf15137859e8a41687e1589b6c2794190494e43bff0e56072f5b79f220789121ec9ed3dabe86c2e0a00dc707639f442eb05fd7d0526e54f2a52a9437bb5d53586f9c6de24567ac79efa958713290c9fbc4cdca267ac9e8ada79cd3746d6894455080af7668558d28bfdd9f71efbdf0a640168062fa9f4f2cb21dbe28d4b089dccb8e2e615038de6066e42e228e4f7bcdfda556e992444c10931e083b62ed6daa11d188fb9764051243bb9f86c506a540f6557455478ce5cb0bc3a930781737bb39dadcf19aa85d61b0766988a7a8f392e79fcafca760031324be83e5da62b856661cd71fb0105a801fb6f9058eb8bce6dce9ee7c9d01b83387aeba6b374c1d92fe81fd3839f4a611f5083ca324246501e5176118dbde8404c11eb7aa1f379db8aa726b1d83e78382a17a59f31b5868a5e724a198e3e802482b4082f195aefa1ab357d51712f8905e609463bc963f537b3f75fedd1a5bfcc55a9d01a5a02abef54f6bcd173f0d7fd7c1b443abcb20946aa4ad2b5c28902884c076f3e92f883b339a91f35fb3dd8748957d662c6804b1a3b68e0d5beb17012903739ba475f4322e660d2825e326094d8b7aabb14781bb12fb722c3fe836a9a9f716aa75cb0f54cb130274ff24b3445e5dd1b70edf77a1b1d174cf7b045b61f51f9a72880bd7521bcf1f540d9f78ffb7f1166698eac60113e9983c13e022faeddabb35ff167c3721d14ef3f3de0914ee96671942fc5229a3f029b3d6cfbc7da72204748469e7467ae14ba8d058850a438c7628154d6f8af0458e9e53d88911e9f79c576f27c0118f4eb3b68e626472dcbb4ec3f129cab300130c9f1bd364330115b36856d4961d351e9fe8420609944927403b1612b4f767526ea096f90256817a7963537f4832cb7e2fa507167b2a6ff7115f35bd7c6d6bcd9aa293e83d5f0900587d42dac0785ea64fc3380c898891d225e21367eede9b6ac25f79b8576363ff66a497216eeaba5f198c99320704cf97a5c6eb086ba28d3c1cb5d593c02fa139c20aa5f78c917649dfde46971369c08895c5d5bdc42efe8d52a1affdf4b1548991c9376a58f0157d30e14a10d708fe148a4c1d9095c62455b6beff482b6bf7acb6fcacfed21d211729c588720ad8511737d5e3f5379e7d9522c1426b8b821d1287df913a70028d69ec5b75a87b0445624649624276ab6155bef0417a3261846fb4dbfe9c0d1ca5e4cc3fe4cccfba6730eb402502409b4405b75c65260f011d0e43fdfc9af9309459cffb62f9e4f4c180982075115f2bdc8bd4fd34dc891daaa519c0e3c473f44c0840abcedb6b68cbaf087da69c39d5165be1e53b96d0105b044173e4f15ef88312b65b3bd66c95b838e7b0169a1e6cd3818f4c127b019cb775f109284db661c953843e490d09dedcca2631dc257e44a156dc44184e556a286eada0c28e0dc996b5ac2d27dba9e7e7b902ee3a02f460a419cc80a52a6b125e487ba54e0659a1ba48a028bf69f7339e00f75008cb10228448c38318e3f453615b5d53efbb4f717006ab50a8912a423aa165847aad4027b28d18c6f6019597b186afb3c4d8793961c83caa0e78dc91169859dc8aa4c87eae0ccafcb7c0ed33ab105b30f04dfe29f5d42bf9f5da6615eb8839ed0b70530ebddc6d9376c5c732b5b47f8a456f94de95cdbe8908acc22ab29302b8620f72f49108ce723dc73a62e6ca48939e464916360ed6cdfc55b8a332589d267ad68d3c3ee7c24658be6c0dc80c412041ee41d51942501662a28c4dbea0759e5a32a8e74f771502906cbc4d2b29353c605b443d27e8887372bc39d0af49e9354ea5690c431b7d7340673185fc2b855983fac73bb7058c26ac7003a758f92f706f06bce9d380a815f3a301a4a0fd5d30a86337442205891e8817a5b70e1c02b91c5f2e3ede2bd847f6e786bd06cdbfd36787a5cf2639122c073a57cc9a709ee3e57b852ac8928d1fdbc56fc43c91f22a0241c3702dbc42c7e6c5132f90d98cc6356315e42f7da72a7bff1c381d33c5c1a967c203f02a15b305a8ddaad7f9889079818fa0255323a8a9d1f682f1684076d789bdc6f4342c51dba453c4cc59542a02c57c30e43c814d04261ef0f33f32a97e5515ca61a2ee06f195e1c4813ab869808b3c3463748b703be516aa5f4259e8a8b7b4118d32225fc6a2a1d9011326a80a41a172fc7691a3f2751722dcf6366d56f674bbbbbb0b29c81319961679028e58519610bcd565d276bf6f1a7e2e99d5a136463caec0cfb73c4684a749d9dcf70b6a32ed93b39eafa8974a38a468d08cb3c0ba6ade1c3e1094f4cd1846862c835842456387c1b2736394866703e9036b301b6a32347c0036f18538be59c8ce8bf85db5008defd373756b4b082869d5c9b3130266b24ad087a7b3ff14497daf1fdd7a70c398a22c094e39d42b16b3f5ed2dea6e10592276e7a0052e6fa816fb6d144c45300f31eb20108a3a28ce25b80fe2b0166652b7fbac61df42a910c09ff1f7c61ee6f31b00addcf8f36eedcdc8e15d2c1c84c5d7842c2327883b2df8fef6a24577be5d586d8fe78c3ca152b77b6d0d757a80e12c5e88d98e02a824635ad3fb71fd8c78ca1d1bcb3ac76a04e4cf48eac776a44ce924ba0bdad6d71bcbbc633194478d3b79ee4c34ec4f539fba8d7f8acdd1881f793ac34e5a4da9b3f7b38ff0a48d6683674c3a025b8f9a531a3bef3c88046fe690c54faf67f1c27da17111b0d0d021a5d74aede97bf5bb000996be11dda9e3860bcd82b04889688c6a9b62b5ca093decbb209805f7e7f1379a7b92454460c0e63947704fc361db58fca0217426ef7213e799861936c3e2263024e3ca9f7bf6dd5092d32d4d0cf739be1cefa3a2a4d2bafece05d1cdbb64c1aec3b9a07ee6c6360d8c6b5f53b402da19aee94b7d4397ec4ebce2f9eb732014a829bca344a56425211e372c7b4d31cbcc89721a49ff792a8a80b41e5cd4eb047569ad169a307fd23bd25629c55b31f8466cc4261edb8ad696a9e8d4fe77ba8b1670ddd3ff9158e058ba2bb0e6207caace886d7567a77e562842ebc18144bd9bbd93d34dd9d1ca9128958ab525bd6985c94d1dd737c094d28a2eaecb6980de1a1a21cb3d7460217d780d9c11c590270a347210a98990cf99a47f8a4b8951110966e21e541edd20884a67c5868c75dfce091e20c3284f07d355ddc6613e1265d1bf36f116963717f893f02f3bab77f8b1f9e778d05218a7f03f83993c82cfcb0c76269f49acd43259fba0bad2e3bff47a5c741760fc6e0f04bf2250fc12345c6ebfa1e4623cdc5f321d1583743ca7db6da0dbd41ec82896494b2436723a7224a9b8f9ea365b770d3e5271ab815a226fcbf8297019b5a8a350c37e1ffc144f461691f4bae54548582db194aa53ffb32f4e1ce908df515c757381445871f3cc5ad66c2c39836bd6d0c051a148ffea8b2da430fed488c01a59e9cde3888657126695ddd98a0fc8ac47142359ed94c99c32ddca39f6e76a12d5924fb7c0df9bad1c6e3ac315de695399c210bf261f2e670a809c1faa1924cf9215146020691a0468bc17e2b7d2002a0c6fa66955be5838767d82647cc920233216606a4511878c4266b5d579b52a7e620a7da76ad71d8f867c21ed56f4810c7b4aa018f584cdaf1fde7fc5fc6b117cc65831f6b3d161c6a52a6afc847bd91da7fa1e2568951b446bcf2dd65e135de3edaccec3b80ad5c9738e362f675651f55b6d26a1b953507aa34b59367db6bbcead3be0a21ead3ec8cbad307ecc0a1d64afbac6cec011b7e14af06c552b3cf21b694cd6e16d189f86bc7e0e4683f6d1a24204cf499b7574c04c4728a33fb978e0502adb535f429cea981fa2eafdaae01099e87f1fd8152d6e7a19984a2f55990c5e2e37bd22970117b8a1e6c40260b04d99c4e5866a517c2cef3a8644f23448d27a3adaeb66232983701811661aefeefeef106b48f61b2032fc5b531af9576e59dd9190ac27c2a7be41ea0a3bf0c8f36472a6c44b0f1104a4152afaadac53b4848e761c04f66cede2e10576b7048113823a808c97e3e9e4a14e194f5b6d1ece48e9cf0baaa3089b83f923ee3b9f6a0ab15a7cb5fddfc9d699042697c973e2b8749443fcbb331043dc49fa66303c9877392de98cfcd5573b38c6ffc838cca7a6f75329afcf204a15516b9ff68b28ef08021066d8ef5e6fb8036ef254ac17a1ad2e680a6ed157ab33f53653ee70fe19c10fcf34e13e7d3a0a8a36f25cd1f621579f1f0678731af30530519362f24e461b4c5f9b4cfecd5be1362811bdf5f523c25d923c9b707478c6426434ffa6832630405bce9ca3617ae85ecd7717375c48c3ffe88c4ce8f433e93195f85ff20853518e2cd5e60ba8a302cfe3f60e89897c1cd002f8e56f0b08073f934f2163dfd5269c02c990b8a350483fa04f8c1a4979cd964b6cf172314f7ac751f582a21eef5b21642a1e1f24d56e2a23fb3835b9a95d375ed14ff99460f65e6366f72412bde34e040e86addf4036d64a2291e0789a4371824fbd2f565aab3e95a1a2c767dca53513b821cada1825187f80340899469f981fdba119bacaacb04420219dda11dccb4b8fb767613d9fcc9b9f7af8380eabafc350731e4435300f97709e9ffc5fe68daa7c4ad84d4078dd422fb6e9cfb43c38e72fb327325d3916e487f6cfa5cee39f86f338ea928fefe6d3db86e0ed352ea3ef771a5ccc6ef07fd119b629b4f7cfd1027625a7950c53d18132b42dabcada772baf0376d1e316dc6d1caf1b156c96eff80e5610f876e8e1059e0ce530a687c6ab9ad8d47836d595f4b495d1fff509d460df2e8efe0eba7f4e15c3d8f1d5eb1dabf65049829b9317d533470c77dc0fad776724b603a1ad1ed829eb6de849603f3d683987d7fccce943254752f731e2ec4a70e872ac07db9d053333f3b2147481c3d7525ce5c539729c382be6d9ab1b7200744233bddb173eacd1f475ddd687e33ea5e71f7973a02fc342ab03ab46327c005143e1510af9399c1288ce0516f632c07139bb8464e8c0747ad35d440c41d50d8162bd60637f99e02ed8bd660d9a394a0619e9ba7842154124313f2fb06cca91aa6d541506feae16006c7f6f45aa2af6569ed2167b09ee704b3d2c76469c84342fcca201dfb9760cf6c27ef59459d165c6de21f165e0efd21a858303a8961c9b9aab0dbf5d0eecdd09e1fd1a9b34dc5278c60c73f5fe11ed8394b2223fdb8d80a23d0595bef5ac1cedd2caa80b5ffc1c3a4773a9781cef4812a34e5cbc034db345b18372bf8ca948c8860a6eaa423eecb6a075f67e1f3ed330a2a93f1856ad67b41283dab1e394dd3a0ae0a5b97c3f52f68289a47b967d278f7315c631d1b1e2f0c7e32d508f144dc919c042631194062a5cff2021420f3d253f0d67cace8a577d9c9e56fa77eef9a6f12cbdd6585b798054fa47676531496a372d9969f7b935fe46174aa5d4931a2cd1b73950f067a9a28b271715a6dc7f7e6ab25eb26153c0687fce1ccfd63eef37c4edb0d47f337191cad4ead95aebf401f7a4e48e0f60a36c1ad88c642eddf759bfb4e19c143492596a8d23b95298f3091d6dce32fa8e2ef5269a93fbac3d746cac8b98745d80b8ae66329b5d499eb1d190bc04ffa2d1fa1bc9b82ab62762ffe5d3f2cd5167b2d9d330c3843b2ae085f9da3a5036a55c05063348b6874458de31db762a96f39e0813c9924e794f8962765ad2c3e53004240c5d4a4c0a03ff27746bbb058a0d93e8e6171ccf520962e1db1970ac75a0eddca2d3976451937cb6eb07cc10d3e912e796ec8b7f1ff32851f141bb457daf964759259967faa8dcf51c7f5684a550850bb88005802930c1b7fc85f3a09318b06f6574b2cb0da395050df2fdaea8dd4a7d80f4fbbcf7f4050c95d762460047837c6ffcec1c5f738bc9168c009119b2e30c2a7acd21c2026fbbc429a3eecbb99545a4ecdb666b3c7edb4a02fc3b30d7daffafa41691dfcd3f120c217d391c0f9bfb7d5f517a2e41b39b33c26b0237949da5d4007651443219b9a953674116954758986a33f4de6d0695f5efd12aeabbadc51f5211311ff35d1af7e1f37a0e6e705de2fc7b6895c065404fd4ae351f452ea59cdbcc099d59377ccf7dc9e96abd80b8b208e35bed13b7f7d796c625ac1ff5a9f4a77e287c4a85f487378cf3bb198abff91bc986a2e403088cb73956bd3980e756af7d6f6556a5c2eb29d8bff51427ec35d940f6fe259485f8e4124947daeab3f8de9005172b07c8deca08ca4c0eb3af37380944de4429cd4586b1e9fb0374071771f53f7c2eb91a854b71a24e4fd0877de9681e66f018ac76536aac92063057bf3304f9e2c3c757e93652e9161dd5382eea3badee126a5e303e8f9c6f5b4c5261acea5b0fba0293b4a006a21f03d5323b38d30769375d815aa1cc10d8b0c45cc370234fe8a303af92eb055cb6d8d3c114b0553b76c83880fb71d97a9597313863d6c000e55ce2a37e61642f43490885f15e94782d54f2c366aca9d8f08136909b0e07239503fe61bebc22742560050c18c6ada014c35c7b1e62895d89fb977cb05601954c5e653bbcddbc232db769da355f291c1c9a7640163510606042945e27643626a86d7928dd8c4c094178951b1b5418f0a9f29445ea5d8d50d062636eb596c630f179784c33e43f5534ed2ebb04b471d49d50507576ac52092e2c3469c5681cfd91aed20868adac289882312b48af71568f5bb94316ab1529f39def1a09367491b886e61eb04bfac2324ebe27f9bdd5a0dc032c6dec2b3b4eb9d7ab8b902ed4f6e5aaf91f6faafed67062cf0b55043cbfa6f80c9e0961cfdc79835150f71ead3babd4dfc2459cf19e968796e1968d89ed4a1fbffcaee17913424008d8d19d080c4ee07e782f9e2b0a44ebaf8a8ec3eb412ac896ae8dc48ed683430d3bb66948f9a5a4a2805abb910a96258fd5193a681fa342d47febf7cc97816c987d0191c8df3dcd755c435522fa97fe783c5657643ba1b6a74805f4b3f5e1c1a24fbca35746493b8f7840fffa38f18fdc7f675329fb134be1c1e5dabc5b6eb89e881c6c3f6159418a47f819f12aa62b45fafeb535c2da7c4c4e12563d3cca1d738fed9efdc4ccb7b2a6b53f1d2f98d267ece7fe0ef3b2db9c47f96b2a8e9ddb19ef695efe01a33180f8e774358d2efdbcfacdaa4b7fc8c17dea871b4cc17b436f64bf9624f918b8f741c84519878ab70c4bfbb1428858487ad94de4e88e5f975d380e51818a8fa4bf614ba21239a00cbcc2cff3ebc4e4760b2844fc999a59f0958a2b2ca944e04d510b78d1ef3fb4aa115db63aa6b313a43fe6b018920650b6f42e3c9b94d5eb68ba9fc498e0673a209f3b7c21896612ce8cad82155ed16ffd8ce82d9134a8e40a8c0fbc770de2e2bdcf4ad9d5e1b70d5f4dec4f5f665743bb26cbfa3d7e3d71fc21a377951cbec0e5ac5bebf3cf6d67e911eaed5bdc704877f128b1ef59cb358f95eb8e50f1f9b0cec30ce7a288f0a93664fc95b39b03adf9eac7d9d79ed37b814716e16a144ae203ddc4d4dd57839f2e9d3fdb34bcc808e995138eb137e6b0199f1aca76287b9d9ce3cd6c53db9e4c28ebba9eb6c111fd18445baa790c8ba9cdb718d60b26697958e8b89d4a354aacaed5359295808bb419db140aa90c2f197c0f047e96ce762ed9d3c5e684eb79875f14c39d03991c5d3643d1dd773c66c53a9572b28c4845eb47a43448811a758c0ab96deab41d63833be20ba090eab24bf5824cccdbe59d5bb2376a51ff3bb6a8de5cb66ecf9eb83e678093852d5db5542678f9b5c53902100b9e843371638eb245bd4044e9d2042e71ca3384d17364bc646865bc888ecc11e5f8573dd24913adacafbd70e1fa0c23e945426619f222f041f8f4436415ded6e1bb39f42eadb9bc6d3fab4d8cee73aa5ca600b47251942126f0e785f4ffa4376e610eee263d0c3f77b1be6a215735d18a9668d7dbfb74c488b37f326df46d349e94bba256247a38421c04b2807ad05e2ee56d29bd8a2fbf7da1ba0a5697b83198957814b9fbf25703b067a2891c178cb0f9cd0c02c6037711e5d274f96ff544c5f46548702ef6653847f28932cf884d055aa4a5aabffb55a4704fa04c51c2df010338599ca201d177b90b6d0547e85e6ee44dc4ef266aaeba50a1ea1f90f637bd0fe59d096f7aa729ee843bef8ede9e99d033102f7c4ff91acc948ec32556311e4b90a4ee44cbe3aea8388564839b504652576e1df527bf154d259063a7bb266d4aa7d65aea2fe97566f17c8feef84766390a402056bf69e2ebdf35a100640f584ba8f4d7c09f5b37a65f4d83ac4222b2366f66ee26c2ee8dc4606ff3bf15458a61cff4bf34967a0c66edd088c45308cccf0530126a9caab84cdabd5b964fa750e5517005e407ec81b69b817322b66f506f83b2f376a77fcb692f9ed98fd94823925d17898f4eb8c364aafdf31e4f4b6cff23fd80e5864e835505b5eb7a3ba71cb9625f80ad92e4eef77e34e07e06273509c08fef7cacc6f9df8f82046019c5f376ff9a5c6807b15c40e1a6eff2f709365f9445f9cf2bda812bf0ffbdbfd59516829e54063580bbc6fea94c9c1f8f1ade10ee60e712a73ed310c3cb9f006cd3efbeaaf360a005f2c136431207b8204bd3013298626c50527ff5d955a7443d406e5efaed62de0a4ddc65a69b162024cae204180b3f7d7817b84a1c0dc3b579dc8dd292fe6feb54947fabf900905e84f957925bc548179b1830cadbd894a3fd3befad1f16edb06afd483647d7169477fc2cfdc15fab8f2a468c5459de7f3a5cb7c366fb9ee4f874338bca7928ba8011f46416047029fabd73725376481380cdccf9e5df0f858d038d6bb656218646158a88bde42c041bc3d6971b1b890164de5bf763efc8ef44a5a9b259b18f056559c634be109240577a90d1d94297ccd03d395d43d99d744762212e3a2181595f65fef7fb80ac30f73ef7865cbd05aa9aff661df56564d2316cd137e3d1b7d8c6d843ef773b622bc20302e1a2e3629735a5e629030709757750f702618ebb467e5d44a05f929396ad6ec95d811a3b3b3247497e2fbf021c1b2503ae0d87dcb88b95d87c0440b12effcfc812556030b3fe92df260be924492ea1c3f34353ed17c0fb352211855464015c3f07069a0d1bdd31c98ccf2ce9b610d5ce34ec317ed8c9dcdb33c918bb0003e3eb616630318160b46c13e1d9e0d974050249b4a1a8224374a9264d6b24ac460fa1f66ac4089da81f462dd8e864911c178d54180b35f652287ea76718d6ff8666e98afd5b92d620f12fdab02bbf5e8a43bee54fe4a4389353fd528894e68e3eb9da945a8cff7fdadee07926080c2640da867f83b2cd5748e131961d50d2cb4884899b7ca3c3ccbf63f374dec3443f1ba7199a689c75bb1abead1bdaa5e0975e7b0dd6051a7a226d8bb9e74bd7cca46931d1530c4e1a6f747deba13d0c7f79a71a08dddda89321b20e91ca66b321d2bbe4d8eddd6b4d5f05ba8fed30f4cf71c1644b3a80c1b1ddb16fcf49290e0f201b6fba19813466844dbf901c1c7957a0a49241582f367ca8daf9bc5219d037b42672dc63a91c05b521afff5c7bcf0ddf5c44a3060f30cf58e7f7b05a5ec3a082268da3de62106e90c856b662ae5e228b68d4eb1bcf3f34516e9f18fab9a5d34b6e44e51aa93b9f4a4c4e6877c2892130cb71d89de4fbede737d6b3e26c019c2ab8429b71f0ac6fb2485a4c566774198bd416ddd04233137796d19a4568525389c0e158237f2fef0df95c9aad4702af48b8d567ad8fb7a8bbce18cb8b113e33fad4bdc5464bab21b0783924a5ecbca39860007db939f4d40c1de892fce7db72cde8e6c3bec120a5a3c417917e6514fdea6ee2da9fd4d2869dcdbbc4fbba7117bb0d77ec571279a992e25807f85d53e17f8f3b09a09f8c4ae5434cbc8db8bc79d93493c2bbac389f0fef19b12185ef5a976a3ce509e3cf5b230304ac8a8c2da35d6b2ff1ebd9d94fa6b5789ac8fefa26d3cc6909baa941f54499e1de23cc305a9c3032ef25aec553a23404ac425c4b2f0771fb84646728fd59cd630c66e9667d196554ade673a2f9b4e390a9a918db8b22ed78ac1520a94c7dd27cb7a3be1af1132c81197448d0fd6fc921215895dd12507d8c5d62dc6565fc73e208e480f5215e155abcbba5241d61d46911da377c745832200d31c73c8af2cccceeed43326a53ed9fb55bc02d206d7ee025bcbed2b61a4d1e94457bc68513b255c0d7e27ba7ddcfdc95df661f5d8ca1995c7a4c59c7c9d861d832d930ebb28c1c1acb3d671def0241cfd0c5d4213a8d2ae8924df1e703bb10a502d9f1bacb0bd6a02e6e13403eba6269893a9b566285596385354c51b46cde4325a17cb3dea3c824ace8826784081a48fce8a20a1e9ca01f7e443528e89fb0df95220e40a82fed3a19d4195daabac67396276a3d83a59f3e63708c34a6f02eb4a07dca40fd11acb9dac419c5488443e605792da84397ff71db73736e0d7c5b97f49da39c00c7c3fb03dac49db2f5b54d07cee91edf10790fed75116c4a391bd3aa14fe01715e7595c5448993ff348b7bc7eaa8d0c8b2113725a8feb9093b39441f857acf325e8ac507c43307d7e98eaed2306dd9f092427df9a2986bea8dfd64df4d8c66ec61daa8324fb8c6fb94091cb13985c85b80a9a1b10a8f97f6c83c22ba98f7d4b7c03e5c341e4f8b283c238aef7a82b070ac47f111c23d7098b5cd89b022468ccc93cc71d5ebfcf73f6bd27d3e03d52c99983896203efb32dac72a3fd1085d9c751d787c07d869ab2e55a13797092382270019c30ed0b666f6001afdb98e466be6e608f5d664e4e7c69f74fef097853b3b74359e343419b26007adc8924c0e28f7be7cfa30fe90f0282b90c787c3ffe4453a5754187169d920e3472eff375394a2206df8f90530b54b5e5c1df9537366ced8968e9a78871f85b0eb26e3338a903685308a07becbdeb98c589aa75d6fa8b1c539e1774de597be761e3f3d2b24be865f75ec7b7e6bbe5d35a1d71b8dedf25584872c9115f8f0d20819dcc147cf86ac0a56b8a8eb4bf28cbe52a698b818514398c7c70f77f9b532fc74f4cf717e1a7b4981894b54d0ce34a20e4d324dbe39d7507a7b098c06e648481bab7eb1ba9a46b96df1ad141075eab90f8e37917a47ada92fccd32e26091f5f489474b6119268dc5118122adcd99e1720a34512a7af64fe861c37a55f2f55c260ab1bd3430ba9ac726602dbc53ec71931fb22a198da5346856ae8c7e9e8ad7eb7597ef6341e275f710781f9f96734d98e8b6691bc7e0f253408c46a4e38368835c7305328a0157bcbd48868f1a3495d5bf63844e16f11d12a931700fd719ee0e5f99f92d8355f416f14da07807ff8ff3729f74e8e63ec5464b57faffdce0e243f41abb8daaadea0950504892a22ac7c6ba8c52946f405cbe721b9c0afe4a733768293ca139bac24d1330b889a2dc080e61212eb845513390f4e8ed097a76a0c50fda3e316817b639208cfcf4f59bb408baea5b4879d2270827c7046b7dcc9ec0028088d5340416f6ca1ce5cbf236295deacafa1d191832838eaca153c6d0168cd9472f675ed757f8496561badb65196e5e16a65ce0519f6ecbbfc80d97f9f2b1ce549d8032e04a0d5bbb947adf504602f96ff914c025d7db8b5cef2ffd6d19caf79cc7f0fad16842a8f7d80b7187a9fdfa4026132e6d3f027391721080563fb15f368e4496e6443fb6a2904456b2d5142c598209e1db53b29004ea093cfc72b3f7f25e43ab4c8c5f544b5df71c2576f5c2dc7b9be13bd6bf551a3b638d680472ea496ed8dd68ff6e020247d5a60fde9d9cdfc8c7db0db8584281611bcf28d83ede3a4fd765ddcbb36f6bdf10591a9bd472b1f518e088590296a2887f769b23d4eb7f7013109230c1e431b37db6db21691bd52da4e51373f46a64199079824aeb333468b68693518b108f56c19020f0a8b985765d305fbc252fe3aa015e63e974b880fd3c28bf2c3a3e10ddb91db258693cd886bc27a8b3b7a485e3b8b63d054037ad5e6fdd7be869d513c83eab0f95c3a62a1f134e42d37e6139399a8fd1648a7b4efacdd21d8c29840ec02d97af3a914109196d0f82c176a09edabe72176f95454f00301f6a5909a616bf24b534421d727fa5713a46bbd25395a9696d5b5b8449e8bc3e661b0b65399418d8c4fed3eb4d52bdebf561f6cbeca51760d5cde81854cf453d9bb87bde5dbf36be21bafd980433ef124879972647e9837929354b1cc15ffbba1e67cead45c6168668069156060f805bde2786e20f49660794e1bed7a1b04787bc6f0230fb88f4faf11122755af381cc680b48cd8a3741cb0aef2452c282b88be83182b553d36716a4ef19c15bed6a785c8d87bcbe02fe419ab8fab7f0e2ed4e3d23c3e2c69b4cc392b8320fc6782a9bdcb4eb6ab697f5f522725d6db78e269bf1600e1deb14e6b03c7cd418db938d51c3693d22d023c86cb6e40897baec6476eff4b32adc97e13833bfdbaa6ab1523da573f8080a3a0983883b3999c1c073b2f70a2c945853d8085ec8730897516b0d4b375c770ae6cbe34a4ab909b6f790f24a95573e8772f84631bbdfb9f408a33b71e5471d6be71b4ac308a65fdc5dc47db8a8e45ca30f51ff86729d95222f662fe148854fd7787a6fa5aa67579bf6a77e125f13b146acbd929257aa4ba8f5399d6eb8f12580ba1669ee17ee4f0a008e282c5efb00850d880e17af7968ba8c83577b0215bfc62c6085be5e9ad49a6c5a739184a9fe6a29ec0262ad6ea906290a4996d211cb032d4d54758400b28c76ae5e3b4e0149bd98810da68e6b743f8cd928c59797b2845e80ef7de86576cfd56357e765118df38b2e71ea592ed6215bd53c2bc0b38f2bc3eda5abefc2aa5aa030a17325f2e089ec119f7f8c9118cf5a9316b1285e8d43e34cd4084c432374cf79b8cd5d9602232697d769d0cc16893ee668bb1badaa1f88c3f0221830a412a15b8c343025a1fc8244c53d02f046d8578f10853b03e4d6c2678489790c17270a955f452e2df8485fb6c9fd66d0bc15c814124b252763c34a3c57785167cf87513bbe480a81a877833e0802079b80ca95428b761ee2f0ccf8a956d306864ad72b72e4d968343c59943451345c7e04a703fd7c0d1bc0eded139594e314b58f16ff0a83ee1fb4c7bb70995c5983ba9d0a28340ce8931d9d07e8d9c5325c8cc67b36dda6adce11e42c79ba8ebee6390d0bf212639783b0cb677e57bd86d3d38951c8c3fcb7e29c9a8414c72d23f98350a2d44d69f1cf1b5ccc1c9daf0b06de2bbc222a481d02aee148faff916a7e622053f377f01bbd341684fd6d0f147a68b9e47d2b979e29593c470623698c0c159c47846d268a22ffd252a54fd77c041058499d61b4be2f23aae06158f85b23218858cb0a799cdc923ded83c084faeca187dd11b7fda70dcf05205354e4cf996e0bb780b87a23123df483a28e8d98396ccf4677bdbc75c35a86a4f86054cb9e75c17b35e3d2d36f162cd8852c86f14cda26890cda37c95d2519a9e3fb54369617cde1fce7c3913331b75954dcad39e538e709c153403e10f4043642a35b98ce95b8f1b668ffc72784034ad154695382ff1fe4502f772741ed5c2f6cfa804cdb2f0b2e27705c537c49c9b595ab986b5c862ae21c8ec7dc0c669eb0e85f7592c5faf3644c851f257c9da3290c251c64e6b06ee9ec88d95760cbfba7403a46da9037c8a6f8d394d1cb58f0350ead2594cca0fe7d64fd8b2ab85940d14fcf64de63094b8ab566dcc3110466ef2d9f6442165a70f78dd9529c67edb44cfb8516fb450ec5144ef649807510898b10242cd68c84b7ac1aca7affb6a6aabd0c3334034002d417c3d7ec7cf1d2f68238524d1ab74211e1ba9ba7469d9ee56ab2c714f161f6b51226ecd9166344b5017e976a04fc790f3c1035ab747767f0211fc3916caeae94d7972b94f37bbf390ccdacbe5dd7010864f5ec6e16ba9bc4431590eb481718b9897088bd913343db1ac40aa96cbe14152c7fa65803977edc7985351f4065b1742e02a17804b21b9432129bb93ce153b34f3046d690d4dc64b2b8ab3bb10845de3ba0754fa0636a6232c9a66ce1c397340ee5bb55c741ad551ef821d73689b8a2d63d75a706677c6c3a59b26ae8d0c6883015b12f066295ff3a65a160b527a98221d935f2245ffc29f26806b4925e1e740667cc86c556af206d28e24c5dedb3833faee119fa402b5e044a3aa84b3c7616ebee45706ee24303a066f72f9b3d311eae9e12a4f830583dc702ad8aafb6450979adafcfae1cc83e22bbe1f7b47647a7617cf7c23e6b50982bdfb32d71322e5c7297a0f71b99ecce8e04067b5a8cf594bc52e6266edc420d0aa74523bbc6597bcb1f3dbf043cce31984ecc9763764930568d640b11ce01fb2499ce6d4dea1e7e7d21de4292427de9dcc89e248f1b7d16082725a09c8ce8ff676ad4f64486e8d9b2ceb7d77319e91324f04f4a7e9aad4990a0420c92c655434f3458d5e7b13f6e66b740bd6db583d9e4992ff6e20f44607fdeb8ce04762e210f5f46d8548259127e303593ff75f84a159be6eef0e8175f6ca7dbb4702cc3e1a1dbe9951f1bf682bcb0696c1e9fe9872c485628620fcef3db8954330f070d315d322ad2976641334d9307bf8540ddcb53cd22b43b8451a1377495192795b081129a7ac7da42a2bfa283c6824232e765b36313cd84d4f835302120c4526225d2762cbce0b728e24c15c240ab2269d7d642616978e54c4781b183c5291f73d5c3f7e1b350295fdfcf751d9ec6c3140d1ad7bc61ed8e0dcd4ba9658c69a41d6ab0b9ce6ed328888658cbc0c773e77c69ea99a33db2b89544673c3ff6b165273bf1863cdd43186c35184e030e5026ff5cbf45e7ecb9f8ddef771619e56d268aa8b63fb828a4e0315af7cfec8835ffa6474e6f3147aab1ee903c2dc292231ffb4827f0de877f4adfe5aa1ced95d7553d938f7c1da20eb76faa3565678af5c60ac9d516a40571973b66002113edabff1eac1a68c5d2efd71be278988ec2bdb02eddd1508d4203101332adb9f807cdb3324b8f07f5f2531424a348485cbc5f3ea11f08c711aa2fa373f31dc62065eb3bbec03558b4e48cfa27ed45aec43f276314a2b7642e44ba15cdb1aff8fe7b64c2299676ab0ab1672266ab445ef731a58328dbfdfb18c7336f0923458d256ecc3cc76353eb034fe085e8f5a724b2d36a840aa4d0cd022ef768a95b64ef90381a534e5ea8e5b7b45f345d555073cc22ab28beb595accada46da99119087fdd1d47edf97d067365758308e777e05059dad0741459a3a3e665b063719099b4a0f888afc29b2385f450718c4356019255f1ee3b5502df610a230a84f0cea7107e831cf1c7939a4461ed40a615732d8b5c629f82e7784e8ca0e84938bfeabf89cfaf919f72b9a61ad2963d74b4ba02df251de0a892a0a5f3f807e65595e2636a2206335875bb7f8eadf0af9ef27534293c76ad19a12938ce9ba3bf39dbce3382e9fcb907521e26984005dd0b9ec50a85a5a13cef97251e545d95cc929119e5ed3683022a97b2fad3777b417af6f81e50a25aeb9e6e7bfd235d5286e6e7eed7d9f3886295478f6aa9535ba1854d4e71e22f0e63020bbae78da054335956022a51ad84fa626b37f889ace9fb251213dc1d406a8abb0606c68559fdb9a6637ba5b8cc64324acb171fd7f4ba8898a330f78bec04cf15784a512108f9496d66da059ae008671fcd8b02ed4c404d618bd0644dbacb966eb96ce4b16670a8012c879aafb8218c31957c5722200f818eed1c4c4824e52e64e092d85e314bc51c4e741ada79c9281e08a16d91163c86755123c7c6ec16f0640f3a9dea31b3ae9e53dff006ac7bd1f3e5086c6b6684061d9d213b07ff7d5052a12d3a2ddc6298cc569d8d46496603c0058de776ef82ec940949a4d494c8d092e78eadc949237102756a0c605cd83eb2254fc253a1d02dad8965c76b7d214f4f178e58c652c54762744a91f118821729942d947c16917b0df624a18f49e8bd20ef29827335f75b42187f9e9099acd60c0183069b0f883f9cb19251fe512a2c2e7c9d7dc5cc141ab2d9498b8ef8b941fd56e1a793c94aa5611b1f7f954d5125dec975fea03f374aba2f4e121be557a61fa5654efb34a448869aaab80f60936c776be59e04cb43b1500764cb91e8559d801a257cd130774b9b536d661b60677381cf75921695fd83894b283db6924d7f042d2e183cd380132d190a56d50584067a4bc1f66b6ca92ab8973cd18f5751c828b3adfb6d0096cd648df3433540d39a3d8bb78881fe055bc02055dbf7c8e8b7438d7ac67580d001de706d4fdfd8cfdcf10fa62e1957c0505288ec531a95a380a7fa8647d2fff4a73be6b4192563744e257ca21dcedd0b0abe368fbc0a598a801c62f1d3dae1623d8cf1b90fb820809739b76491619c72c3e0a3ff95ceae134f3760607b71c8804a3001b24ad52c7a59a8b7ddefe798b983561ac6780359abea227d6735fe2e871a7daf227efce37c7b61b0b33eefdec63839a8af3025f1aa3660dbb3863862aecc3865e623fea7856855aea41aff83b0be85002b8a109b0dd305e6d52706e3567ddd3e465b57fcbf5aadd0f4b0ba742aeaf9024e552a3b4fdeefef714672bcec18f0afbe3691b57dc859bf9de566eab17722bbed45e5ddcf4986623fa004fed8916f7bea3dd9ddbe34a7475b6d7952df32d14e7c7f1e916671cf7d082ad971301bb8589ae7ee80ce8c49563aef582dabd41ac32bdb67330bc90580063d2af7a50363808ed82f040e8ba06405de0526841a768ddf9bfe40310e6fe61cef8d7415fbcf2384417f2164a6e4709f4512991d2a8a08c08e0a7dd9262a90d2725f75762f4dbaa23188f629d67891f17ab22b62c1bc8c85647b1c476e3b3af794d06f8bd88b1758c848db19056a844ee72e4d919984bfc81c82419b75b211fd6b3fb9402aa269479ebe30f8d41b0cab841726a7d3a3a644992e3d1be04ef8eeb688ba670b5254972e8daec8c7536c28953ce8d0555f06e7a42aaf81de46ead5c278fcf2545d0d83449dd2834095f35867fe0fef8ada7ea0d333706a4912d00a16a63a3bf7ea17e885fd5c4ff7b8fccaec4b300405af4ef95b8980b5428296f3ddd3f43be91e080e12141015e27773d85c86a4d88c22bf1fd55600f4cddd62e35ba5d1c00e14a713efae7571f400acf3e480e580eabef51e1489e151a4bdd85c8665cd578939286f6b78ea3107d6d3bfe2c171b59ed7e284d5e4a5ec33fb03d82c4598f2d56d9c2ccb857c0bf4839d2fb4751bbfa30288fd9411e318c288fe30ea5f3a686d34e329039b2a1a7e44ecc0b9d9af3cef680ce1ba0bccf8beef8deb62289885f5af915bf80c6cbe3f34083feb478ea1860bafa5914d9f91815232e53efedb8259ef8cc530f5402a94a3f0ad32a5baf9810eb0243ff40213ff91958bb4b35299436a85814a8ead1a1ffeb54f19a81c5396d3a3bcb212243643f6b7da5c69c1b0d862b7b0ae3a08ad023c8057e86f4a4f117e82e98dfc8582ada04e659e7027b0b447254b56b80d78edcd6f025c3de528f043a8884824f40d4df1d3b59115f09a15d575786a4ffee42f0d4a6f0386818efa44946e85d1d6cb1fa7611ba5de676c3a52149bf6761dbd04e573a9ec92ccfb3c7d2d222ab420205f65adb20a55691f056dac14ef0d686dad144f92a8579412f72ab6ac049b61a58d97b5fd3f3c089d6077b831f77d4a1673e041d0fa85eea612767e823918b57d2228324631ee8cb7ca68107be895479368f72e057280d82cfd7bc3fa1ca5fa1a080b0532862f758919d607709cf898ab5c4142f5dd8a103838f51ae2d70ec491d1327d3d3216bcf274c6092712ad3f2a243fe6c520d253b7b3ce8535f1c281638c8017f590e26f6cdc0aa5bfae30574b09828755d19462b755d57a95a203be25e01b833af64af18f3cbd3c5fb78a19d0f16ddc9e1748d1809dfd2d49a2d98741903d8832ab2b8868a9ef316f93d4853899fd5bf9cb6efb464ff7212846052a8bffb8e82157a4a53ad90233c535764d702f6616520b04bca24d3f598b51b0647a354a547151809349134d5a888fdc8e8332d1b7af1efc371b380ddd7b0bf1c7ba4a77234c03551dc1e82d20732b8b4b71e64c8e24941135cd6bb2aa2dbf8f4de6e671cfb690a8c32ef52c9a05cb6a5a1c7f486eb8c0ddb47bcbb595de47ac050f2e01919dd0753773da30123e18e487f6fccaf03255fda53e82476e80a7975792fa9c281d7493a1d970b2674e530027d489ca568dd41eba16ba646a4478737063864222f128c7efe6aa6b1362845535367c2041397517c5ea774b02325c15f8710224dbec1f9ac5b30958e93c9502d9f4b6c97628824eec15bfdc517697c1e0affa937c2c66479c7959acaa7783327aa8054553384a374fc04f86c318a503848c03347787bda74b6f8390991808e44ae44f7e44a5a0ed687256a50d8cb4e3b45990687c9984415bb372d25a8075b72b7f3de6d2c08d157833d46e742ebe37038a902eb9c080d8cb401758ee8340b5cfc9b0f25f51e5c5c816cd1fa3b3a802e3fbda718e14431de4db1a30027c139cb63a30ca4df9409337cbef706dd2fa3f5f92dd3a48c0e11d2bf6cc7a2d77ef6b0895da04790888e084ec6e9ccb9b0a3b9defcb77f2c8179cb33d753a53587386462cfa66b4c0c9e6eb2719c290812492d5cf2bc8090ccc1890c42f006f1561f0759b1bf6a87c4c03123d726df6d28af4c44f8038cdeb3442799b0753f21ea9916110effe0516d0715bf2fac9b2a16a67668ebb20362aeb7d7d0ca00ca9dd2d02a90bef53c5fb65329e5ec9f4f48697f8a7af4dfe138b2969a8578f183a21ad877313ea29d104eb16b12a22078c4f05ccb3be6629f53477d51148d59b7ec3d95f7dfc9fa8363dabf9b470e6ff9e11ba41b256c12fa7849d3ad9535f9fd4431b068ed14982eb11c59ec4168dfee5eb9b020e0906ff9f0a6e348475ea78b9bbef86fcfff4c69460c1496534b5bf6e6dbd8088dd13e0cda2bc3d0f600ca8c8d8c05a85681619398c7e6d50cdb0b8fbf421afba59af36f3a202202340de5761c609b8fc21998561c1a393edf38374455d1dc2a563e025e13a6a80a8c597a385ab96b1beea8c1c95bd0706d0d2ce53f60f80a9b3b87bf8a6d8592d2bc9f231d709c5945eed6b4fe2890a00b1a35ad79401858bc307531c763a36493e0ec3cea0bfb316a2a61589425f4a2c34959801ff7e6eb6c291fe16391fe7c0ac55aa498ac0d18b458a899c2500d0f182523829c9818367a1ee90513d5252d3e0b3ab40e3a890e2f8bb0276247d64c62c4363ac25b526da597bd15a311688195acc0b80900a17b213c09ea6bc78ffadb0d3965682fc31bf2401645211ce2991844969d3575b307ee6c3faf829785a091ae83f645d5a2bfd05d337ca87e552457d5659a79f33da70f4e67d266fe5f64e1f44becc4df868c4c0e9de49e4cf8e7a85ae412c30241f2d603940b0b5ae181d9b11118eb6cadc12065890d5ee0a2f484a05d74067acfb373126849791daca73e722357d3f9df5cbb644a6002f30071f8d1dadcbf4f06a6247c3bc146368a47786ddcb85958edf5650639bf34435b64bc2805f8d8145a811ed7f3a96224f73e0a98c7d1b586865c0b9d80a34f8924517c6e341606a2af557c961f885baf59e8656c16a773040a803cfc217a8235ab49c517a01344c650f2d69b6bad9a238de3031e96ef14413edc2a02dd1ed15135bc0f19c6ae4bc15907d1378f430e638d56ee27e33fa858efe7742661c80442e2817f895837c01f07d5c7faec8717197d8e01537cc6d999927a3151c4db0c515e48db93d5d0239b96873ea9fc60f604fde5e0d8b376d70d2c57180f85163c7b002f2b3eb031f30d32ac055883f78c188b8c9787c25ff3e151cd3b397fa5659c3e2f71de4fe9b4584075195b0f53cbbb5450d4ddd4bee4a80e3663ad40b89c9c6a1eb7c3c237a84c0eb55939f2968cfad6f7063626b0242d951cd69f4b994ca1f7dc0525557327fd49d1c3d72b3e4c726da070b74578ac99224f4605215bff128b05011980fe7a3f5d88ebbf7c74abafe0e9f7de1d12d4dc6896c3449b56a985a6ca376d2437559efb8ea20d462c8490c263ba4de87093a1473b2b80952fe2d826bda138a54085edc1c773a9e992c6ecde11d0894128d6c77cd7d46f7f459b355e953c4a6ee752005980b6e9c9df3ab58c6284731d3293ed55813794d2825361b752006995c1b0c95483051d7c737c985ab10df5d04a300a4b846d0233da8df9244b0c8e3d6e872edef58a00a404dfdc9f7f6dd4aa1e2f8d457822bc8fa37f68b2d8d49f5a400683ee3036f42cee2832546605b1d4a5347a23702efd247e9c7a653411bcbf7c3c17f3870f01e764a866096e86d777696a4ce42d86d1f17bf5557856ab047a8947aa936d4bca42af389be0e6bd9140706abb813092d72247a9c0eb339fdcc0494ac389c7e304ca47618d0d3e634ea83c843bbc0efcd5d2d8cc2a12e427ca6905a74b2e2fbf06df0a6eff279487c7538f9b5ec916496f97e4bc10debd7c35e8a2d1bccd64fb6194024f6e0b17fe793a32f4431ca697f4cce0726e54db118a4ffe1c1978035a940463ed5bc37db446be7479c2a2fb208a728a446ebc437139dfd2edc1d0cea502bc720ed8e43d0bcf61038396c4f16cff308ed17c2bbb999d5a702df2810154a9c753386f2c0395a61e47c022db1c9e9869219a660f58e78b5d5cacfd1b8668eca6a84a3c93cd44c44be92b9ba354caa93e67ee700257f3d772c4a4f3ad8564d2e1b602e77f5412178093b9e99b35e0454abcac269a8899844fbb2b4de10068deef063e59c2d3aa58c132851bf4758f58c4aa18d0cd64f19c254bd7f28e209a9124198972bb157e5e20f57747a9b7d0f690792a0efc3ec8c0a7b2dfe64df47796f62ef1935827d785b67ece150851647605a0f6803bde59b49cdacf11614860b3248457002a69ea8f24843cdb4a30a05f91278253ad4b78d429c08bc535df25a4a9fb2a56e9155ad5f35cfdb0d34bbbd9bdbf5aeff037b625d4c1680d1fb6c2828da4a340eb123f496e81b0e86996c5f1498f8d8388a7c700b7be7fdabac712d62f90b1952536c9f1abed556fe6f3b04d45bbe3b80dffc511d2bb6df85c50f54433dcdc672f3226f85416cb15eab758300fff62a847f958e3c1ec0639e7106aa0c1816ed97a6b22628319c0b872390daa1f02fbebcc6df55bf69ec7a85e201c803e0fd37022a19187a028e96caa8ec9e17e55e1362009a22a16ebf637323d5567f78d8decb02fc8d41949ecd62c58629f01016e4afdf3303d2e8f3d01fafb4bc373da512926eb6cd7aeaa166b4508605e3bf61036a64a93b2aa37211d1cd139c5372f37526be82cf47a5cf6eabdbf53389809831ae723ad4771457049338bd4c4ba06f5e45350a75bbd01d1f7d20c9c5252383152f028f0732a6c66c5f061e9ff98d79222cd53576597ba23b38213c549c48c936b4bc9ffcaf11cc35ed87a805ab36b40c80476da7f627b6842316115948703196880d564943e585647da6df3689b177315ceec5a8c57a5706413dcc00b5ee210c84b4a2232841a365c2d10dd83a51b749548093d47552a1e3a9f0a8feb584b26c06ceb4016d826316dd2a2b20c637ae6b71da5ca4b0f67f33691cd64f216adbb9aa6460c25a81ddce806f541c97ccc9f3c700eae57c196980d2eefcb7819139d58e10fab21ae9494b3f2463f691813f28b7b726473d46b1dd4e356dd2a907b111e87680f7eead23619a70bc7252f34a1c9d6c893e451ce605f047972562226193b2367912da2a134151f5f9f87ad2e03615b2d86bf7bed5e18c698c74e58ce70fee5c4f5c16889e09f45d59fb1dc710839f63de71bdc182856d65bfbd15bf9043153b7b264a7c89785d307879cd75acd7bd5cca18a4d37c6e9fcd3513be743dcf5d84642500f960531da81ce267540d3a1983888ba793c2e5ba54a368a21696b55bef6975e87b6defa8ccb4d879ae3fbc755f39cfdb5f7b938b0b5847f8e53e6d10ae7cad57575e3c623ab7b6252bd6e0fc102584bc2e033ebd4cfc3a05b75630801077edac0f597c003c1545357711c4279960a15998cb8d270359ef2bff81efbf149af011be41dda53e490662179028cb0ea9460e580627990eb4580b795e27c72a4d469bfcf106633504d4e41527308e1693d5a7c2bfed7a298e9ce7012427a4cdfc2d5c061c3dabdd5bfc95f44d70a73d6de69cd5f345d406fa401ceee2a434a7bcab99916f15e70668e75485e5a848e37f8c0fe59f386623291a55b56804cdd5aaf37f87388bea7858920b0f4fd413301efaf9abad8e119e9c20f4e408067345a3c0b775dea0b0a777159484fcfae64fadcfd8d3b20daddb2598b12c61e0f2967c75082d5148826657464bb4b13212a530e471e676e2d27911f2696fcc5844a148236c874f0f92fb430c851495da8b1bf6a8ebe1280bbf3ee7ac86bd85b49512eb745c89481b6f908334d300da6e1309c02b5da1eb1ad4f078610925d7a79aafd7d934306d6dcb2c3cf029e0d2fdd22b20f5a9d7fe8f355b122ddcd26728a352d508f6549d2e513c6242faf7faaf7309af8bfdb4081fb360f7c1bb35c61df5ccdda733ff4d52cdb83425b43a360e4a5b1424b91790bf6db1bcc2555e2c73b40226edaf34113a1c1a2eb84ef5197d226876950a242120bce13a9e98a9cf640e1f0fd74f8141cc1b5fb5999dac502314863189a6f9033a7c35a4cf809998bfd4ce575672d056cc33d52c98c4ec9d8ec76182f535dbae161931ee4f3c858d18114b05bddcf1faf329ba80d23caf91eed9122c0aa703ce42049d98f6b982ba94db740f8d835fad73d3270c4978c4be1bf65bd18272bad4563b2ab9a7721437514964edd2e95c0184f64ab2532f6635aa5ac8dd7e2916ef8166277651aa4982aa4e948342e24b53cf35d6439dd90e2d9c91c6ebb2ab3bfb4c59eed2b022da2bcced1d7e4b80331b45abfe7845a65e69368209675cc194dd3b6a0085ba4d50854b81da4543852c59570164e90a6423bdecad0b7307a98a79c33550bbbeb3b0d368c4c5ffe51e39f70e415fc0644c19dd496e3799bd61d29b9c808db755b6fcca250b5365f39d248432ca6c3c0cf3a5a633f858ce23996b2e3ca47e09a25fe96c8923890f312adf26c0c4b1b9095e039170175beb8f9e6cc66c8f368cf3dfb769b621406b6371451f23c388e5a923e0aff1ffb37fd5c2821567af23478e0490cb25dab77f23e1b7b92843aa31b83faa78d8b51fdbf3828e6760e9fc17ecf4745c91b04b80a9fe3f71782139165058a8e533dd7f664312e8bc2c51662779389ebc0a9c817d3b535367b7b63d0d3b33b8e2ddf42070af526c4fd45090a437eda00f204f6f0aa0096b37fa1e36e168a0ca0646250a4b2cb51889eb265d6895fe47c34e72c5e45954eea054e34f8a17619de73cb9a0b9cef814f415d30a2724fe519f4f09f404aaa0ad33a2baa8132a01d0ac4a5491c389331cad80f20249867905f48e03f1095e3145a87154a6c1e0c5a9c3ab0342f332be4b4e6e05268eb2503975ad00322327accd43fbb1232a95941715585170024553197938dad67524b1d9b8faa22fefc9c835e9002eaa056345647ec96c531eb350cfcfceae9b8aa0ce1c90678182f35f65df602e3222780ba17ce8d227d0031a4954bea165d62cdf482e5330a278b67be7c120336828a63fb23d4c98cb4ed71692c077ac63615e34d62e3dcf1377ad4172ff748d08a623579a80142e4c6300f869519ca557ebb2827a53283b57e182b3b5f85fe5d80d07ac444b5225ec02b6c2e21923434de0383e2887df6fcbc7324695c20635c8f4167317c2613e579f6816d702140e0d0e79fa9887596b9609e78467ccc511915be2261b14b3d2b29830d9b8c1619f7bfbcf2474a87cd8d3f989016f2f63b87ffd5672b65cfa58a5fdc5628914bbe238482a422756f7799ce84097b51a7459dbfa31c358abe94801d2a435357bcd9045740cce1281af30a2381affd300b50bb324146dfef3236ee1804f70c4144e427d5be290a252a9a35237f3abff5296b8c60866b7f388268cb5a17b35b79a90525005ebe8bb7e2eff1efd079f878251968a4d040e4c4d9db9157f2785d4206e7aa19bee9a54f2d3a86ae5162a201b5243298b66c8a335f1c98ef8abc47151543faa5c57e3ad5c1b4b178a9e8f462ed78bb29ddf261b3574853549a1809d8ff8e16dd39a2888f71e496d9a2a1a85dd67c186c85c087a973033afad4704423b51f743e4e54755af9de6f6c7531d57ba2f027c8c32d25618966afffe6364cd23e42f6512a756c9697b8fea9ce64a1587fd91af13b3313b287424386af2cbd970238c32b4fc836681b32d9b30e3081fe2d76b0df02373a512c5e4767bffd78125d919f3bf1932ba455772d1506044aeed0298da49d8934c004d901d5c1176ce7fe3b917fc5b2c43ffc54e32b8098157a9be1265a67461673b1c0dbd6253a760cddd9d29c43eae521be561c16faea19c7125a12c9dcf959cd14203a76d86005f55e3f18cd2d222315059c125e5f965261b06798ad17c910b81b53e32091cd3a15091912e58cd7d765b891e26474b5864bc7349375861086d1c49b967a6ba9354d840e78b0b2e7925c2e59ac85c5c7ce953316ab0220dcdbe5a749d1ebccb4b2644e3a315d7da2f318b976fe0b047c2cd6ddb69a032140e30367e8a78093af4594531acb7f5366781f53906d605454b7fbc1e5135e69dc4ffc31b58c8b481916e58e64d9a4f8231d51386b7096a479a756c93188119981d90ed697b7986d3903ae50ebfb71e60149865b2cad0952fd03c6683ecf23b3a808af9231051c5e41eae648f1a974d4fa60e04edde91ef64e515b8e6e3807ede43e8a23d2c0992b8b044e9b465cbf91bc69362b1e040feea033242bae1b392e800cfcd236b11404ebe35f3f6dccf08bd3913a4468cca0c28837abb3dadbb61660ee1e814c59d9dc4691f1d7cd9ed978030d26b19fc726e8de82472925a6ac299c1a1e2b182ccebba1a52b8593172222eb5e0589ca848ded0f06fb573e85ea446e43186f835edd8d9a13ff76c172155b5e524e79d294718900197077c3728addf61b06ee8a0ffd825edad34bbb34aefaf3107ffb14a4473614adf4424f4677d42361ec27145e99b95b053d03a7915421c397ab8d08dc347180fcf4bb9d20c85ee394255153c43e3d43c28dd87117290863a804471b9524f4003ed809c2f5719bfaf72ff8b54058f3955a14436e752de9ba9827eb30802d4bed569e2b9da461316fbeca5341b9aa25612c4e391e7370f863f55ea3491999de251db07bb1dceb5b0cc05a29690599db5cdcf1165bfdcf11699c44f30135217cae687c5e7bafed7b3021e0cd626c077c842888e74e6a613c619f9d06fe15993be0f387041a9397fa6d661b868e8b396b22ff6336450a09c022c2ea8de428703cdb68b22286b374ba566e6a763fadda4d75c6750a2cc9f4e3db1500ff2c5b4c2ea4f86f236b1d2aa73e95a2e16cfaf04ef7fdccc3a090ab7a5bf3a76067bf93b3a1524b48a0018aee9b01b5a012a9511e82925ae3bf2b18c3a26a9d327d9f3b79eec4a6758a7438423850c0d11b06e57a60325f62707420832e633a0f65e40078800281b2021dd561409a3ea7c38f4b576565601f65979b72518efd671c528cad77d0d5af11bb648264c62f3d683fe47895ba3d2ead46a84520106e057182b9031c431ccf97e9882d8ee0feb1ec6f6b10efd0ec34a7fa7c186f53656c476261236d8ce4d89a401b6790e3681586359dee32c346cba5939d7f972cd38e47da0209ad76b855798957c6b4aba3198f1beb95c7a6a7fb6a59b5fbe756e0c92bb992f2f296cbbd1a884fa0df9b71a7dab72c5ec8458fe1054abd2a02cf800f99c2218588cb3fbac46ecfa46439e697c37b91b2fea3e93ae1a11c8622ad9070dc6baa18f0249d8bb933a78a4aaaca5bc5ade369a6ac5d3decd016456511338f2df1494fc5ed33f690d2a93e4a9b380a4f1a925c57febb3e85af15d44f3c3ce46c1cbdace2b8e6db44e64e78e8ffa6a4a39ef79eb87598a5e886f66e8577c18703e30283b7e1255dc0ab48e6370e2c20d4ae01f6ba0b71d4b52ca43fddc683098ddee66b65de0345b882221e59acadd2bfe41de5661244c6da31d866551412660db3ca106b9728676940ee9ab1a1d81cfb634aaa63db9f4e92f1f68cf87527533256a7fa36b4da47fb6ef86a0a79e8deecc925d21b47dc59d8e76c23f23a5d65da2409c4ee7bb0e311730c2312a9ca7adee42a9b4707a89d36dae3f9fc0c5dcbe2efc4a0d7a62dcff0d4ef5d5ef306cd590a7fc71249850cef0c8994a6c65259bbf38c72b04340b49f94050f5b9c06aec8f1183a85dab6b53cb5c87692656f0425be4cf7737104cd7bc092df6a47a4cbe2fbdaf0e9bb25961ffd18ea524e70ed42b8a3952f76c64f90494b8e64b3ed94f1b025ba0e61fbac3193d510be754bb0bd0baf8ba21e66374840a0b6db950cd8e0e63c90bf5cb1a48553471a6eda81b7152ab915690dc12b2af4f12c53c67636c3dce6205e60d82f6b36aea559d7f45ba27d7c31d5eaaeabfcaf49cdf4d860a5eb9b26555e6c88394c814b89bc760a62a02ffea02bc385dc272bf64c5e2f28ac8c758117ceff2b5a726f813a8985ebee735f56bb09f18fb265cae3f48447b14be5bd1e740110d541442624196c5c5e37d8d607ce0419f86fad906d5ba8fc0ff8e66595a6dc0bfa526b2a8d43a29d4dd83c7d3473eb0b11cfd20cfec71f2a65ab96c4fa1985dc40a4be293b55158781a6d7eb00d7d5735cf247bc72c466c4cd9e4bb890e9f0f0327f6a748c396b1ebd32f900649cfff18804920a9c390f3ebcd4d5ddf57b017972e02985bea54a775d2e655d12776ba4783dd1d7585c5db2eb8bee0dd4da1f20b7bd5312d449bc1918ba22f58589f810d5c043900b4061f1ecd33ebe64a15a97a6e8bc9bc18f8fc4f4914918148aed00e08d0b46a4f553f1a0fa9f14f9df54ec0a00748ee637aba701fc1bee19e3d036ffc0874a403316274deaf20aa15cd3f7f7e5a7bd04cdde435f943db20306af4ed85528a31cafb03d9a11853dba35ccd293660897efe0d7b8f3ef21450bd02d2b4d55ec9eed06e36b680c0ff7d214d2123b4904f96552cde5048582a808f086787df936ddc532911732c0a53f4b0a50c649a56562fc3dcceab170299847249a1ac2a7f20dbdc73b94ea253396d2f73ba4a917d529e573b6d7c99881b50b10913af0980672c4a8519cbe57e0d78f934a6d4582204fab6c6a2b2a6a08c5405697c74b49b3c6a196de85c7bdea74c5d73bd15e8eb3600f67b517c5479f0bf45921b78f04a4ea51a3f2ba2990a2b9ef95630ca5857d32d680d962b66d32a76b4179c8045838d3898eebc95b10e2c8087f5f9c95d0cfcb57350f689593b479f25bede9291c2fb3fe7a343deca772b9b69c401870096a4bade478b3acede40b541a04e9171f34053a1caa4c34dfe1d49beb4acfd2be38cbd144aa3642fcc599c9ba9099fc300c514637baf41d0f36cb22bbbb1e4822f957c5e34b792513f6f0cea95974a6f628c8a395565d700e99a013d4f6767fbb9581f2e29f130be6f5394f4b646b11e885482c4ad4e18e1c18b44b342266e0ac3fcb46fcdce442b09756b5fcf88892ca90b98ee89b9799936af4648f6b2b56a910e3b8e65e5cc4b1022d2082fb3f065feb8ff44d5b7f038d6c4f1ab6b7cbb3c55e72e7daf5d530687f97e7c7dc0cb0d072a42bb3581769d80b67bb2d1f7edcf8727edf3d3e7e9900363165af31da6364858adc687d392bd215823deeab67211c933b9be6f26c671bd777265c38a25e367d1617200b46eca418464cd0b325adb7b87c9ddb4e75df091e356f75bda3ac86377bbb8f0019fac5f2233efdecd57376856d140227d4beb303b0a1f5f76ebf5993de2c6cc98eaba2dee7a3f0cd14162cf5d25947d2644c35e27e940ecf38c565dddfd7f2c441d25398e7b3b98737f0aff85a7601191dcdfdb71bdc37025b68f2cf2ab9d989dcc01648f0088ad8b0bd1af82a706752cb1897f4d04a4eba605d4017ba79b4381e8fef45f71b456bcf9e016e8267488d9760b23a3fc355c2767454c506414b48e1cc805faf24102a826e2fb424c03af8b41d5b21eb6c5fff15234a20648d8bd85164b067fc26aefdefd682b0225e3cac22222cd00cab4ba3bbace159e36035cb4bbadd3ff2416776a11421bc4cc2d79720877f24e577b1a332bf1640972089bfd1d61c24bb872da2067efd0cad7edf0d9d9376bda14fbb6ceab7c026220802b03396c80086e079f997e67dd29b3ca7c041ac34514009553a29ff15f2ed695fc101c2e35ee21cd8fa1ac604637289983a762ffdca3f3f05e38763a939db11e28abaa728240c2004c0ac97009463e894b86cbc93e5c05d9f728a40843fc4dfdd321b9a095956ead66d1d5b04c5e403610df091d33427f3850a745423782d322bab09ef36de462ff17f4e83955473ae8947db07d205ae1b56f5c0b88bdc7b345b2999e72c1034a94ff5bd073a1d86d37333440ce00bf4e6853fb4e68f8b2ac93a32c6c2b596645c7f87be5bd9a240b293a9b406a021114957de757736f5fb8a729b35150fe1ab55fd9406149f41205388c495c2f4fb9a6225cfd6f3d9486a2919dd2719be476e20116a3917718aadcd1e96dd0a6ea53184a9e86b31f97f220abe5df51fe8eb5438d6b238b476312292fca4558509aac6115f2548f55c46c991faccfe4f4af558862b62d753bea2a387151734bb583a5c3a04ce1255e32ff18aca777628f82606da5b4026d347748449072f7f0a82a22113939f0f94381cbf3d470cf0263e1ea37954eaf4cf37f5f7df42f35e8ffb639be021d5481f0b1311beeff655cb0390071e56b27ccf7cf721cb4ce73c59b57c4470877b1fbc646bcbf37b0410744424f8603c5cee2735117d72ebb389ae0be966421791fa6ab607ce1e464677e528ca8627a5a4cdb28e8a2e20b62285af7e53ab87d74b3e574059ecfecedbcc5d7b81a208e5c8a39e63894a96396e6dfc019ab4e3380bb070dd83ee37ef5bcabedc57ce457e6d3d16454e7a0be984815a6069ad556295d6e58bddc303e280733c49079753ecee4183840c5529d9ddee9d53e708fb760d90940a252c33aa19d0fdb86d1450992c45effe7f01bc6567f00504be5d239f0ebda2bb1eed33ce221aa2985eede2cd4a4beb2b6dd20e1c1acc7f4ca55af8e004eb6f8c713cb9bad0b6261aa7324321081a05203b6c656ad1e2a0fc1019525feed4cca69a5320e123ade227d5f509f0a5227a290663ab880a882ca346fd33e2df3b796aa43a7eb06f99ae8e713f76e4a6edba6b06b1694416eb3b3f441cc07d1f88c4703798695b37aca4ff0e252cc83a2577c356b8de8d1922c8a33a02b2e12fe635727ad291976c3b9e1ebd2a09eef53e51caed267ebce1165da5678b53c7f0a3c5b9d32c14dc29ad779c65246d417c10d52cf6b853c2983824167366896d1067468993bf9e9ab3efc5b93af1dccaf1aa26fff8e7f9cd8f2e4ef0dcc10931f8211b62822f4b4b46bded928d43b06654174cd6473ebc5dd362cfb9b84077e633706d145e06eef5328bc820dd53d4d991b9e07477c8d02d4fd118ef2e9d4499a048a4ff60f7134bd5fa7302b0ed4affe2a53f02add92051dd925fa84381126cd116cad86bf12675c709c1976581dc025f046ed38641cb29c886ddc25a8e5271022706fd794210a490fac13ad55e636df075a1908b4b0006e6d4b8ab52202220ad59ffe091e74a9098785350ef438137a9ec8a8a192b5ae29d438badebbcaf92f1e21bdb64cdd5cd05967c401e42d566bde25c4ef97ce1607aa5d99760bceea7bb6cbc04100f99568025f375b8fc5d0a3fb78714baa16101ac0a266a1ae8c5e4f7fc9c1d30bb6826f52592dce162954973891d1ce065e61e0916e418723690a5d60ad58a054b1cebfa3b544257ad17fe44edcd3cc988c0fe06c0ca77c45bcc7d397ed87ecedc5219e3bf07a31ed138fd797e60d8d4597a8fc06b14c2a110d395676937fe085831b5e03d244ac5652f3793dd460e0649c17a60e36bc5b2b9df46b8544973665dc3d8073e86b85b2832ec4ce647dd41e8653a7a2bd8018a4205e668800c7234296da9c9bafa94d5b43eb196f33b91689197c6684adbad40a1af0ec8be2ddfb497ba1939539017ae66d4f2c08e387386208677b7540aa4fe48ee6ca832b7288a011242bff09c8e0f74a8fa8e9e6cf78fc47f44f34ac1a57c4752d16f0196756444ad68c98a2d62fef91888bd5246d052343a6abf0e7be09b241c5c8f6f3dca22d3e1a5eabd04339baedefa2ab6df17a905284296f8f39c652bbd41515aa90442658f90bf25e93a21ba71cc831a7eb3d594ffcf9b53ac7f3c959775878b0babe76fd5901af49096ee328c4a8574e0613ac13a0ab2bdc446d8a1cb1aa37c97eed60bd9e5c6ce47c4a8e7b59b7778078d5c34577cee0f61cc2b53251bb2f3da6e0f8e558cebe0b2c93fd010b0b4464531ad3a596552e93031d51111ea411a607c5202249978566ef78e685dd3bcf1a47d17d36f3cda8f6703cd926ae3c8fb8931ba9adb66f4af4cf4b86277d73297f640c8382479cade3739e8cda3e4a6f6a455647b5e1c87fe29baf429619788685ebb48dc4a7ba28f286a85192978ba8a8450dacb327159f7651399594fb3836f999be5b9b8974dc962bf7fffb3f6cf13e5d495eb2746baa5d4691564f8265217c23ccad3133f1a7d25d1b3522c311b3792418f8809eff1b5a9fc40601836a36b172ac163202bb4a93f6dd17bcb0e330bc594dbaea44d3fd073f02f26da72e5f87ac09499b597ecf88116af4e3da8087a108a63668aafbb5f346def153733faa1504879260b80cf8e0f085794f7d5e1e50f3f5cca222046ee138a964a5c3fd3f82130338196fe13c50bdf239503a81fae1cee2a7a40e58f9900a20e4796c88b758900e4d3546613597f0ff95003ef59a594168ced4534661cfcf48e64cf0a972d7928c2540d327dc1125d3cbfb9bb2eff7f97dbe6d23b134d89f55360732b0238b31328673da55696cf2d7ee56b1b261629db00bf3bfcfd04d40758319b36457b660b5db0244d1511a4017cae233ec9483dfcebd21194200e4430b56791f9b13e1b819ceb903761ef5c67ad283698a1c01c7bf2009eacd42ac5df14f71afe29e59480a9a8f1bc13682646b2060093ddfbd8ce03112b1c2fe7707eb697a6cc90223b94969b6e84e3a653fb8f2a3340f643dc18683fc1a9ea16cd6f37c51df87eb43ea52bddf744423a68e5a8c5c4cdf130450e6c2ae63b0a7027232b6914b4ee1652f3181fa62b58b7efb3de550695512f78db02d68d83b858a048749303e1bc8a95296a9af37965ec9d3e4832be728945dd58c3058c0c4e8c6e40c27e9c0535fdc94107e2f3325975f5307726d52944be357b00e40ce5010c756f2d01aa36d58d5db9b35da6d68d62c721fa2ae9bac2dafc7974688f942275f13c6922bbd6b981ad9bbfea7a836d5a1953861d02515efd52efe978d7be9986c9f456490a20e78c97f298baebd656d32ea4e96c8c507ea6cb5975f74ec450fd3bbe26b2565bcec6e259b5d4e4a367a6eaaa6045c58ac48cac6f96400fbabf4950d948d74c83516a8411fe0c4e15a3fba80bc2895e9acd1f67e4b48b86a5cf08a58bd730507d57e711755e3736103421f8f5a1fb0481efd1eb91b2670f2943d9cba62e7a8fa8654f2f24d07426630fee219c9f07cd31696c7088f2360fe855d42e05ac40a3d00048dbf12bbd8e3e874674d65e3fe84100d25aae5d9c584cdc35bc769d1312076137b4dde73c9a5ade0db457fe3484df4cde78d287a244cc3655af2f8a999bd83b593725755452cd45c658117030b5d996b7542f7345dedad4f0d2a75bc73e82b94a3dce561804670515add85a42960d9db516ea732bb197eb75eaa7cf436d60d8de06d579608375b9fda8311f2210d36f535302406072e1df44ea59a2f9a189b4c0263ecf000578f85588b276c60b2390d5569d9b203d9c486bc6cb98f037474ac736cadfa3b67af4ecc6ae38e5b9dd6a9c7da6cf1eb46d844d232dda90832a9e32cecdf2b719c35598ce7b41224b7ff3b3407fb8ec30add8e6ea9493e527e49a0d041d4ee7a9fabdb4bc87c44ab8c6f930aad1f6f19ea76eaf7a7c2cf205599391acc172c4a3f0c89abb3ea5eead14a4d582c99f42f2c5211c15d3e6f23644bfa6dcdcc15f2dc39016ea13044ec09ec2b3a53d496e93b98f011ea535e9629b757d9928ee6661ccf423606e5cf22a068af9f6af4747c636b7a182629fc94714a5e42f1f7067c059fffc7571686ae872c5138667f39a4c5cba96e5682347d105391e14d3751a10c09e0b397be6405723f11858d54ebed2a591c14c80e6a217a8f8b20094019773841fc9a7dae493b4bf35ed47fab7bb3aaf37714320dc0a1cea620526680002f953e29598a0d1d0b5e63aaab0285356149f191d01b8e99ec6ff4a63473adcff0382c1e1197856598b242715ea5c0155c119b44346207640c660a5fd064c255d6664312f8918aa4e6797f6cc851e5373b75ed24d08a910cd09de6849fbdccb413292d50d5cb4c0011a6771a0e693adc92606ba336b191b169251fabdc785a4bda4aa67999ab1a0406361089ccb5fcb9c551330260013b531d8b8b396a7f6756131898e2f4c6f0e1e2569b8d5a79714c7833d366932f5fc92ce4f66260fa18b501a090918b1ffb0ef47bc3512803b20e37427e70525d2e439d8a245f2685108627775ecba504f86c71a007d6621c65898fcd1ff14b05edcd43d892ad77a2c282de470a09f9c5703dd9901b3ef543fc14ee3e5e381fe11722f751288d7b9a452f72502e08cf8ea1a5ce167fe4759f3cd9b891a0c49abf606a50fbfc8b6b8d6ebe543ba34e2f7f5952b513e041c506af8e74f1bb872a59d5eea640440ee411974e653c56462e2b384839daf41ff6615287cf8a4af52684426a37c6a057908817e0966acb7e294d10d9463994af493bc125c58a348bc327b26c6580ff71a7e28c6bdd53216d1c04d17213f2c5815a86c44ada66c82aca43c0bd198297cdd69773f8a110a88de6475b2abdfc3e922dd9023e373eb0a7a045e6b43595b104e8663cc092480ef4ea37e37b3b39bcc1820904da1c0672ef2c66f1c7a2a93a945b19c4d6ae8d9b434a043bbc253c409ee7d6ff778d1c4e5fbec26eba1a3ccae4f75f8b3bba84a161f59d62da8a375dec0a05e098229a796bcfe528519bcacc8318e74f863d4261c44a791a6279b6eec93962b462b1e0dc7644c5981132c8020a4c63566a9ff3e20cba28abe88ca706e0a29995ed1c4fbe850faef5960da20addb9344811772a05cec43d40e0c60c680bfe201125dc3ae2ee3ce4a01610eb1429e1704c528c03b96ae7f431528c9c28b6356cdbf285d9f1ba8d914b4577076a86c2408ac44eb9772cfe039a6ce48020798423d78bab0aace2e3fa900135e96aea305722218de2fadd8c61e874847ea11a79dd88249c078a29491609e69f30d67a777a2e35995bad3395d266e502fd387b728d8025d8fb660012bdccc01c692ecb9e865a0faabefccb6c2bedd197abaf237344f68399bf9abbab274527431e8eb303d934b0dd4f157a76f7a72ffc830ac28511d1ff1ad98124b8c3823a846e6b6744ddddb37f5097355aa24d510b08a2cca59a0af2f87bef15ec4a254c54ed885aa996772a92a0fb028a10a4482e2f2aa293064b91506136f50b0f22177afb7ca19646f3d9c06905e678b0b437ace7f91083e889b0238c847ef4172e0861cf630c246aa4f307b7a3bfd783438169eb275872576b33231cfb0ccdab6b9e49207d0a37c1594bdccc9bf95b4f16adb91125b3bffac296a0fc3fb849798f40cc8d7c3e74487b6477adec389e525bb059cb44ec990083d650bda3398f2670f9fb92d253832653d78e926407de3c0e9a299c85f3e8e711ec6ca2589be256d437ce8aa0187cfc7385188551bcc5cc35bd4c4aa3a771ade67c165461c5cc6b8044ac17037eb60afdaad49771fc74b6774f590345d4332a35a193e11793a533dfe488798ebdc9f211d6db7508789d43b5222b5835fccd92657cde3ff5a560a12f6539ef49976f52d264952f9b5c93ccf1448671914d5f10cafa084d9d943435a8de50866af369970e9e9e175a174c2381deb188241b77fe8ffa84f983efe61e799c1accc361826501100f8ddf824542173c1a09164039e164d6f9a89251502727d6ed099bbf29f705305a0cf2dedd7d7d7f809dfca8994bf6fe09875be5413b91264d67a1a13cd79f3adf272553f227a5d5e7ae80e90b175c5de10add46d8758be6a990d5d793fe03c18b45f8f9c4203e9d47d41135ccd713d016cf464fae2ac4841550f2e6b6b50764db73689f3f0dc12d15d5d646b645e4417d66dd2bd0cfd1a5c7e0ea148906beecccadb2f5161e822c9bdd2104d00db0c39707025c6b8d20031798fd69e347ff14e6091c050f32a79607e1af4a7fc75e4b3f0d160bd0ef65aea57083f80be8e734dc9b4227182c86749be8e63c0ffdc3e8d53bb1caaf60bc5068111366c447ee6d804f27c1e08f8cc97b43b20cd0f92f89bdddda0c986b3222bb6fd3492150d1ceb54c07450d0c95c0f580b299b012f4e2662401cbaf2e7164c44cd2bde758a5e847ad8e610e653dfc26bb4a8fd0f96a5db8c7f6c8eaa73d377f290952616bbed010c70980a32ce9c08ba3713bb2f0555e3eba1d8dc0fa47e3187241b2c81c8a18f4229c11f77b4db45e1baebd8d68160420072048ed74f2d1934d89c796fd37f627aaa7cd28be85c42f4a04a698c520c134958b364bd1e0c06e531e2baf66cb1ef9a5d3308ea4d316062d3e4ae5a70dff7690226f1b10894fb06fb0016c18250e5d18d4b1f468641a2bf7bbc396268b1d159192c70450882772557988484e14b2749ef6a6ee2a8a02599434b4fdfcaad44e17dc37238776545e166978f90e700e9b32fa7b06a3d09f87417eb39a44846761ffc71ab76340944efeb725fc2454cf7cd0fe8225ff392481dcf6166d5fa6c664c54318a717c73dc823d17946f68e07636d249c587ece561ba8d511b3a7afeede4819c25f932bc82830a94e7e7ee769705d5676fd237cddd9dbc16ad74675e5d95955559adf0ba5add49f56d57fc21c328d8551b4e4a747ca9c8adc2e58955230c3948724f65a120310f8500a77682555bab682cad2d581aed8631dd5b157df5f5664fc8dbef8cf9d05e8b52f4f6e716adeaccb9951f44150c649ad9d15a3a40a8e74ef3a90304c440136c6aa1c91fa5520f461658fafae160de8940d3af52a14b8bb69f8995a0afa852bab2acffcd53671f8bbe16f67639010b1fd09a3cee7995bee7878f86a937c3c2c2ce7a5bfd10a12f483ec6824bcd5f29c46cc7a21d21462f2e8cf0459c2514fcbe4c9a267c79b23e4c77ff8c1c4766551a851f41d8aadaeb6d85a885598317a13060e510bd5cb91e2554e72abda93d3c30691355daeb179e8018710c16d607f561f19b50f60e7b35d03d57f5efc302bd8691b65dce2df46b1d5c8ac06f97bdd825a921c311b66031a2ab1b741c95be9f03d8240468bb379cee7c9bb7bee5994b2a7be238696c49368611bdc0709710fb12d3fb282ab909c4fdf0dfa8b78940d0a4dd55e6fbc9e7f39d6fca1f34d1340befaacc29a5adc51ce72d73b646b8d12a5225bb2add439f4d7cd2fa759e0c191b037828efbb6e7393ffce97e5148f6d9f32bfaeef2283b92e939e6e99cdb54ff52792a9b720c11d5dcc7981b44abc696061a5a2c48467dd9952f48501828601ab135831ca33fd50d376ba1303e40f3adb4d8cb1a438e00f954fe4457e31e82335e9dd0949214d6868c218f496e7ab224917d5bef9fe1edc42e0b2d2b19a5782bf1e8254f01dc68a530181761bcb0d7922ae8b28b1372b880dd5a2e89e3b9d250a66510288b4b9d825f2cf090f5cc692b3907d96edb12daae3dd46241c422f3e9a06ac242e1deee9bd14cef71a9d3b5b91e34546ffb58887f99308d02d93faaaa2ddf3226f88e5814577b664f8846837216107a717a2472b3ebb31f0b6641dac7bf49fcc01383c6600be011d001db6e33d818039994094126bfa80e45ecee5c3137f8a38be79a13b58b966543d1ab8ba3bb5c0aa87052535b87d016ef7885e11393de9fc02fdf0d7af1bf140047ee9e4363de3096e8c0cbdde7904786827e6f7a6fee0bf5bf16206c2d85ffbb4d5c44cc359bfb2a903931abb452bde3cad7c6b4a3fd5dbcc3e215f1f6e9919b3280d0626459408023781ed346bbe68fd2a7bb8abeb187cffcbec084b0bc46c9a16920e03365d8e33720a1b3c7dbca0cf633b57083e2617084a86e73cc9e2df92c15ff65b21c413791682694377e3e4e5d6a64dba185a5d271048606ada3f0386d48ebb802b559f2b9b8ce2478b66718ef6cc65fe7a75c7320f4ce0f59c2692386e3bc1e48c7a89e3fa0bcad3a83d777f981d1c48b0597f2db159b7d925d0b0b1067664a35458ee734e77b15c3fd152ed8579de9020862529f4d2cdf5988a9ee9fd1b6787ba87aade1becac81d45dbc14385d5fbddbd6ffb9b634463dbe4430fe646fe149717fae0a853d37d73f013cb45398e289f0051b622e2e95f60daa99807384198107dcad20dbfa3c4628f414d24a1af05c19d754dd1544e3eb0a926f6b6f5ee84afd3f841b3cc5cd84e3f7ac8bbd9b4deffa71078430b59fb971fa2533f5638235097bc131d2d9ae5dd24a5a9ef66bc27b3d523adc040c65c3d67275133463ae257b4011c4a306f02ed9426eeced852daf744145093d2f00e49ffaaa1254edf8d0ca89b1b9f0d6bb4c714ffc0eed05aa429c990333356b73fbc04c1606e6fd60665af331f459139b414e874f2d4d0fef235d742f862f94c98eeb11929ef35c2e2fc8e9630dc4d1eeee9d01d9d35fb1f88f6a5fa6b47d2c0002beafcd5d8f29e4a8d55f91f8e54985ed66f73c138decc0ad70692d87e2b8a0ea0a9eac56d625979b26e921301998dd4c55d793cb6afe656419c1e01bfb2173ab1834bcca6adbce43f157b0118ba78c8770af814cd5f44590ca45c91a3273e8a35e22d3a615642d26acc2766ec99d1d7ab4ec8dc28b40f2e476d551bb74a9005d6b45a295d6e11fbfec13d54007bc8d4fa94fbd0e1e55c5892bcad0c4588739914b9ef6e796ca6edfe1732f007b3c3fa133a75bb4ca807d3b82e2dce26312e1207031b8e95c9b3a2971a8fc5451cfcdda050ec9e9002d07f7663e037a8b0b39118ffe0c2da986d5eb6237e01d87ebb8eb5baecf8875a66e82714b7aec3e737846cf1b93b39d119b98d168fac225ec6293bea9f9b7543b9a6687f681f650e43f1580eb400ba7dba40f6fa1cec80461a9fc2e23486f32a34afa5e7283fc59bed2e3981032a9dabc4d812b5c1d32edfc29a1db084d96aed93c0d9c595854b106bc97d9b366b870235e0d15ec51d2c6db0408d557894af1a2bc493bd6c61de903d8f6c2a0a796e9d934e8987c644c4d83f840a0b9227b89ee44266104ea8bb5d03c0bbbc547c4fab3bcb18f69bad64e96f78d2503a716aeafee2acfc659293bb5028ad81eec5a705305586e30e50e22b9ac03a08ca872b8f3bd1e2fa65cb0cd1557ef905b809d91bf07b60a5972f5ac9c5425c9c1c68b651f372b01364f695da512caa8fdcbf9f3ccdb6023d76c1d5e93f9fab39cb11296c573210bb88200d8e5cab94eec0dd64e9c074ab2058bb8fb41c1d9df1f9f84642dc688a52878f4f07b41a303d09f292d0196ebe0522e522c1cc11b1a49e0b04b849d3a7e6d7f8273789b24424ebeab1871e75f4d466ddd729c56e823ed588257584726a50693e71be1c8a056bb3a957d903523ad2b82ab894181f7b6a2b656ace31a4ece70101471ec6b9594c913d3d99bd8866960247469f469a62fc392ebf6c55f610ef89de8be05314770cc4e5d453cc84fc7cf25000a10ba1a09c3586bb2cc795f9088c2be8c22f59452297331da6373b29983477c9d2c398302ef5f93d8f34bce144536af99455346edaf8b450ba7cd424d36c5d28d7b9557dd300f71d2ece159369ad3a89849eef017831173848967ee4f2b3fdde48d58fa3c45de125dbbbe60004c7501c4ee530d4aba6e1450dcfe34146f651668bebf1d62af42c1eef7b97ba7a7756924ef3bdf0368f74253e5c1e023e084b8fe450c8779ed472ebd57f253089ceab70efe2429dd4464741d81473418e72c8f1475a49a3287f0334773d662ee0e34e8b1e4b5ccf8b0611f201a736b481ffcd6e137b1983eac9f94ad7552f6571015efd6b37989a32616c660b4135024e8f17f1850a1012096edf4cf4541265e602b023debb6217b608663c0b7494f9b05975467cb418cf6f1f621be05134f76bfe2b09aca95cf5cd2ff8906cd9c235973d2214fa94cdeacc8303e74c435b883c325f6957053fd00c5e7669ae947972cb6e26674f304e5d3b24289e33e212290f67b8c7e1b0e1cd52b6bbf5c4f9407a2ead901274540e4091afa8ac5861e7899f9bea27b352617ca3d760303440195d9166c2d47ecf94dcf96815da08acbbf6db2fce681b7ea9cfafc7b6625ac43d3d6c7c59db3a7a800ef6690cf246a9d842ba92d026c8ca158eb0f30792cf0c87552bf64e434c57b4d1c440b1d6573becd88567b38a698f471d6a1fb484ca302c605197600a23b1d3f8a89e8aefb4cec68a7491cd69d5c48e169bbcadc35707ec6084e0adab0c0a3133a2e69cd89fb2891fa7354a5eeb947c290e20239c5ce47fa94177834a0cfbc5314a28730f76848a89d70c7ea25c33bcdde8a6c62dcb610c6fa9d13f35b74e336f104afb91d2f12dabcec727d83f7443c37a3d9c8a2fbe76bc5e2b4770692a4d1ca80d3a20044d89cc01401cfe144ff9978f44d80146e91c4bfb6c054ef5ae35c268628e131441ca2008bd1644b97644338700eb01fe7bbd5a25c9979bfdde5c2a7b8747eb259d08d942ba63943a5dc74277c7506b1a9f74438b612b3399afc83b5f1fc4b201187c4fb8373bb5fb40d5b0a75ae2f7eb3d3466f5c1204a7940b61140d08934c2be2b4fe8f25a81be14cfe8b5c97bb13accbe3f0cf4bb0b104179cf60e039915afaa31eb333e738f1add1a7d952caf97bcea7ad6df4aef4fbd0709c6f494f5d913d78f7e92fc9cc1c6493043c12677e96db98977af7eea65d0b0b71a50190a2ce80361324bf51f352804f2a2f177849cd85ca2f937d2aa3065e9fc44805e2fd065718e5276d55d14f633aab77911fa0c834a3eb62ad35944e021dcb242a67baea6c72b38d532475daf5e847cc130b2cd3af624541ff553b8f13ef65614d51a5b62fc38d7663d7f5eec7ffde6e017e903b976235cdddb8074e3590b994b32652ce5445fbb6dde04ca117678103a66ec30759cebcadf5c62f7746c400a55d91f3d0768d58ca0d313ab1acecb8e719c3d05739195aafe09ece5d08fdbaf8d848765395c01d53645a705bf85b42d659971e47cb83f03dee627c9953817c0daf0be480355bfe19bd3518b975d0bd982fd99acc3964b6fe9918dd60568ee7c14a07d7304b7297731062232fcd6437449541b1559c591c2cbca02563432cbc937e18f4b46e1167611b26767e38a2c83d3a9095f18f5edc6024a179a7766e4cbbaa53752b6aa5cbb3fdad46e8d6971a97b8c92723f75e3e2e8eb23911ab230cfd1f4c105b1ae11c0836b1713cf6283024337af0d3f3b228a7c24473b06aaca7f40e4dca5f110589fd89a3cf11c110112a30c4d4a70a97d7f85611602a6f52cd094fa39cadca294a858ebda3046302cf0c9d53774fb47ccd5567de78796d4840baf5deb933a1c94f7b361f5f2023ae4021ec7e7bce2ae4d8652fa8b35acf96472458fb7484dc075cc8d10f925c4dbd9f271631494f39c81df9438ac285d9ee9318b86c8d8aee6fca2ee3c9c307fe7ebaaf905baac5fbdedb6fac300332e1f6f531ca0349c52cd50567631c2c5eb447bf7cfdfd86bd99fa5eb0c08f0779686e26503e40bdea416d7675d5271bf7b6359ff1eeebcb175964830495531bbb4ba7ea8fa46f4ee1ccaac9caf828ee12ef7eb9ac507e3ccb5aeb37d7d7306c78bb96604f660372353227b12725a1f43610b73b4bf00d19d83df4960ad8e9248491ef0464c8c41235c850b63820766a32fd3eec388bec29570d82139d07d273532f1a006c4bbcfc294bbfab3e84b1601e96024bf3a8ecbd020d46eda126b2516048339e266a131ccd5601bea17379e55a98c4c89c2e9ebf264bffd806513627426d6edaeac84554202f16596e0a1e9c9473c99710dce43d2d99f36976c4385f2e1e31c8fdf1ee649c7ab6aa52212cd238a37c082b8cfe9847e1b5dd48fdb434686f630aed0ccf6cc2c731bd53147f3db76537ebcdc1e5e29ffd702d90f7aca6eda85d58e973917d86aabba97d97a481f938e468f689017f85414e0a471bf2e3b068c85a461b1ae3ebb67bd6241c74b5b7a374972a73a9df61954fdbaabfb0aeab9797b1e776376e6ea6779c6de4a67868ad2bc4b51e1b2a0abbb15f590ef978980e851ad72bcec395951ec451db5ff5a45ead43cfbcbaa8ad7ff8bfac3abb34c9215c75d02467e004e251ee8c8440cc12a90b5dc56b05405348af8302966235c23df26a8d8a7efbd090bb96a36c1151475a3e4bd502c7d77be530cf3b42555898460313b6d1a7bad3cc4feaa955b3993c5b0ed1c0799f95f7c50e667e9d68c289273f5a9f8952bd9e41e65735534a2b1cb6ef20d4192ead0e4581ab10fba2b1ae5c7c683ea6aee3175a86eb34231f89474dc0c71fb500e6359a3982ee00849054cb72cef7df3560aa7425510ca314982739d015f814b5b5a8de7fa8155f98a2cf670372484ff0660ecc26eb432be65f4c81aac8ad678dabbc7a7c8f7cb076f048c4a4083abad760fd3626d252666650ebe97381ba98e847e35dbcce0cbb3f5b3cb1efc2b0ccbd8630d80c0a811f2d320f9d32d4e2bf5281c6ccf75e4bb9ace8dee1ee0c954339b1bbe8d6d473745459e345064ce2c2fa979f37232a96c75ecb655cfcdda15f7a56d6eb90c32b572d506567e717c22648c82c13542002f4d54c5aaa562870fad3ad5ec3ae876ae32d0a098c2ecd96dc57d95d0e3674a60074d613448ceae32b7aa244ad7d853d08c26d5113e8e2cb7414a7a72bc1bba59f56edc8c0356ac70489d0e9327b85091d692a0affd3b8e0b87bee0e238090be2d1171492d44591e1cee0f14b2a6c8c3a6b6ba6165594afd5a70fac31e8d3814e4aa0faf0cf16a44806c095f79bfeb159cad1682b0ac2056c98a238b049400615001d02d306172843045b836b86abcdff4fae3962f2534ec3a593f596956224af31e966c5e9dbef7e1d6946797941bc2fd0f9fe6889ad3b4ff6923a328251315c25c1cb9d9b4b6aad57f7cba5167074684e75bdc3569f14381d39ed5c8864076bdb45f9c1937f02bf0945da2711e4a76884871dcc2b0af8f0278b6d1cf6796f91d78f6695982b6c8696dcbb7b2dab9104556f46ebaacf2b39dda64943a7e691471db30aebda61a0827b9e04e634f481518c350640d4365b3263c59294bce18d58261e556ea05b9fc6873d4d7d6771a680c669b8123ed431c0c4eecb92f50796d3f3d6ccc77592fddda251394299548cd4498dd5a44ec6deca615d7f3ffd69eb887a9e95ba9e7ddb04aded7907d7a594aba1b9bd9c1bbb8491456bb640978e094ef90e353b0a017015439c32066108e141dce48a238e0c13124ee7c89746f0bdc52bc9629ee9daa5b2cdb8e52a0e5d8f8ab0062e22d940786ab1fcb340024685e4663968140d27d22e5deaf7f37b28531c64ed40a6763c10f07b6d09ee1f8cf72707f27a6f306174370d7a3934ae9e0875416141230a5f89c5dca3b0a3bbc7dbad79c7394e8bcb1a69d6875c9daf3d31755a0cc413c71961992a122729a31c99e1b9bdd4a7c7cc3fe2c9c0c9f728531fdee776091e16140c38ba677929066bc2f26c650bf24e72b4d6df0d408dec789389704b58b48bbd4739ade47685c3ab414037dc15b744873be0ba49ee0f3e348c3a126cc4e63e61f34fb8499d1150cd2c57f70d5d8bbb4df8f715a382396f757b301310d6145d708961b84130f05325d12981ca1be599cc7afbb674d81b161786fde767ead4a1511db3270db86819266a1e782ed82a6ad431457996aa213fd99252a12a37ed6048366c0714e693a5bc82ec5500dab05361cef4de8598ea340d3193d6381809aae5f492af81b9885accf67850621ce72c141148259eeb68017915b6097193306b87c5007894dd269ebb26b923ea1f8a481f9927e9baa830edfbe43b1a2fa852305f78d7ec7cc9db9cb828d51973eabf47820181219c36e4b4f139dfe143d5541eab38641e06d6cd2cdc8cb374cf0aa040501ef6eab901c74cc6474f1e0b2f9884e57cb1f3330e8d14d8d26a1b8ea2447e90316d240250e599c0de52d45f025f3ccbeed3cf613355821cdf8dadd66dcb421a72097c18c6db139629c81463ef16c9b8263276e03d3e0fb90a4dab24248de1dacb2632f9e2a89104a1a32ca51d9761d69bc41c411bf7677ff2dc25ab2e9a135ee5596419fcf0faa9464fd787bd0857bea3971a15e3bdf07bcd00f703675febee836865014b61fa21a673df9d8b0ff160fd91109e66ef220cf4b36ed020483810700042ec207b1b87be645f22c7576ecf9d9eced9b29810ee24c4702aac0a5e4a32fc8028917ab684d8e7d66e12d5f090485584e438e5514a06a148d8e3eeddcf526952b5ec60c83448563a3fcc20541e8824604d82e0187d664815a88e8681ebb23981d73af36ef2c8013efaee2373fe0c668a96bb663295da9ae91ccb2f1530421080cb78aea59fd8c684ebfa59f9e7368501bced571b1a1196ae743f483efc121f873a51f98b7036a0251068b8da8189d9fa458af3cba279b2d37239e0acace9857c557cfa5528ec72e03c1e36de5bcf6dee2fe93fdefd20594824c929c4181b5c9fbc927ac0bbd8af6a77c6beec75dc65f76a6d5edec9c00d9c630555557d10576d03b2cdb9f5e474f135a29330e5dbd83e56f6d84ac2360a08254b1e81bec804b8d87698b6b4d74d92a1ea357833fe5e8505f79d4bf8a52009c89d407d2bdc0a601d4baea7c22446fee16e5a8653d9987705cded73463295b820e7515d19c552a279a142f37ff16eb3a4f9cee52c8719bc1dfe1c30f8b675c6c2a0a22c31dd419d192df0eef48c2a13caab140e87c67a2ec368ddb27e1b76b829b78c800077d68ac0a451f1a0aff878f8130d89498c5e5b00f26a964b975d73795afde4c10bbe2ce30aa37a95536dcb63374984889c8d99e54ee3f12ce441f903d0a075ceeda6882925d26c14e12e149372f40104f96443e94e6230d8f9bb5bb01a175db0de6314f59264d3e9f23ccf46644e50318930059372d5fb50f47305a930ab8edd51507b96a630c89ec1b09ee9af3e46cb1341a6559c2eacc52deeb963bf11b0b5e2bdda2401cca2b4b740b80ab5ec727b23b64a2f845db0eacd5e6b70e396b89cf1ee6ad790f3cebee5a414ce77324b99d83501508588689c5e54f80a26e67d5cb0d4a2c92a970e4005ea3d696f9ee041e37fa666ad29ba2cae874adb91d016110653c32973e47043b65fb70aef0c1801bf4545bc2a9cd715c13ab847fcadb7300b25ab832cf89a5644ddf951b0d0fc6b9dba2dc90bdff4e4f23bc6527449282fb171dcd9f540b693b7e9c9b7d2567b6f6bb29600027795e9552cdfd9222e9f9b45cc23fe8bb449150d48ddab838288f0c6c22ea2049a8c4dfb948d13ef1f374efe9266a83f57e31b70cbf782938ab0e3b0cda5a1845373836c02979ff4547bcee9db73a166faf4823306a69361a1bd6e67c358a83db80f760483bd38b3f98ba6f0d5e372a3b3b3cfc7d4f590753a4a2652190da777eec21c9fb0957b1ed16ca33b8dcd53a5b64824b865ab84904a4e594f58de0f5e434402062f17aefe5a8586dceaf7bb9f356e411c89b8761d9306088fe48b26449cb1da3582db37ad0a6e453eba03144c1feed40afa6fdd44f5a14c78e4c6eaf3e44c41b6e9d76802c8bb927178b06b64a1ec7238e75025ea8da439faa30a46c7c73e6f65f54777293f45b79d2b8f2ccd73cb3d3b807bb2977853f1621418d8d46bd8354f5fe527bfe8209def4ba7074f23df7b909844e564d670a36a79afb0801c575697597ddd3248fe673b6d174e3b98dcc4da186a4669c77c2bf19db11174deeac4a8ab7bd68b1f972398674bd5e11944c76c7f8a1c2fa5128577e75246434db2a6bd3633b0a0ed998fe63dfae8e1813ced4e74dce590b31bfbfd180665bba5917afb029d4868af2011afbb5c2853df5e32fc2b411cff632f33329555cfee643edb4a6072bc306f8c2e323f20329f947dc37dcfe7c7203b477b11187627e5bb5cb3a19da81c77e1d15c9523e7d3352e1c2a8cd3fdc419feb8473979914a1451d127b38245a20cfa4a730227691e90c00f9481ed358cafeb9abcbda4d31b605001b8061fbb29eaa69600f1f61e3802549d563dafdd64762b2d8dbc9fab8a95bb7155db20f0bf1b3853f16028bda558e74c22c0216377f9f38a04b292a4edffbccee6e129789f4082f87133ddd03fd0fef945b14418145a2d249969eaae4e87487ebeb4e919d7fd7801b3919625f2b5122b664c007889c3ed18e470a85a56ecaa07eaf441a4f931a337ebfbe0775f379d400270b00d5aa0d676accda4d4c25a2053d237def01edf3546d5dc04fcf6b74df47a2597f0da4bd91d0fa22a31e4c80a201c0ccaf25f29d05b4dde26a4ce7abad9c623493136ef69b6996edd243f7f5831077eb014abedb2717522154f51745f7a2b168777ca5dd372aef67949347e418df64453da75bd42e6a9361603d4c31d6fc975aecf6ac640a030b041c54bc51a4682d6a061469c4a3137530bf3dd570f5713465b4f1b7997b68eec57bc07ba9986bc95a9218d19f2e7e4d0a00b1b988190027938d4ba37c82974193af45c7da8655174374ecf92e5cb09e0ef851f12ccf24cba82c5cf4f3a1fb37b0b1ababdd3d2ad9c5d5af30bdb24da974f4fadde2933ebc043b5b4ba7a03d73399b9de40259631ab603e56e71ae6d672ca44acb638a521f3e4b5d2958d1099358e125e92ed7222d8408ce5abf39bd6a366a9a2646c8068d90746670bb17ed8fcc121a5d02b0c76b215cba6e2c52558f42183335ad4fd20b4a5f92b7bcaea8106782c97d8f6e71b5be756317614f4e286d1ab4537d365e8a7cc6f88d8bab847eebe4df0ea17946e84943184984e2fe005f6455522bd440cf0c63d17ad4394d94e94e2b271e923689675fedab561c7fc174bf68991d2c193c19f58aca5d0ac7843fa602989b3133cc06fde59ca414431205648e17d0bfbfa443b6ffd9e86e136dfa5908cf897e12b7e135d231e03665aff3d2a07ccc0d610a673aa18a11096a3790b3e3b8e2093a9ca9a311b3981c8a9c527498967fcc4d6dd2d5a24fdb73dc7c1e789b609b1a98785b531f5e0654f4b7a6c3b2a7551dafcd2ebe31501cc2d98100fae411c2a7287ffdb00dbf9fc573dbd9b0107f432010d6dfe750af3c0cd5571433ea23a5f5ed07ea548bbd7f0049090c0e0bfe856631e1a1917b91e2fa2ad661f9d9a377d70e002ef3982576e2d2f34762c83f9009b550891b2e8770e4a19d224c861ce3a98eae78b098278a0a00336fbb6795a36edfe779c814737844cce34abc10fe1090c76019613c0b0758fc64fe63d5e8b0debcb0a857bc1d5bb31ec4d038277653e2a96f778bc7892a61b58a18af040e095cc2ac7b24d60244e4925098d0a090e77714680fb013e463b8b69b27300a11dd702f3bcba07e0e9ecb839dda4b3002d923fab7448331b8e5e16c4d6ba0901da3b82e9b514629941729cad9ef72b1e77743f3999f3a29736ae664b3deb89733b81d96891665e868a462193afd5387be0a880e08d701c0f2e94b12fc6da01ba282cf8e344dc3f628cc913f9e8362b639c5348f6420146995a753221b386cf33273a616fb26d474a71a163128244683199a59ae8d505fae6fcc0c30ea4bfd7b197f0f48c892c521261a1a046388b4669352fba0d60f3914a27ed558199f8ad29d1427477d18fc0f8e276bd505f8ae6398580aeffedd1680c2f31ec0909abfdc24a8b6a552b42ce3a8e4f90fe6055d611ea39c82bc39605f23ebebda03414af31ecd7495e3ae8c1c7bd9bb3e9395f5fa7faf206102713729d88d88910f6c01a8eedff68fff6ab124190d67812b18a872ab55506f0e600d47aeb6dbfe6f0b34b8e70927c2f5e5be72611824532b85ae0501daf814eb0ee61f2ffec7d8374a706b3398d2476731476f63ca10d74243a6d031c352b6d11fe5dde7a7e0a54c52f5b78da2448f82a283c5a7a59770366aab6c13b473dde594afe9468c9bae2fd740022cdc0309c6a2173ff2a8dbb6651ad43a194613158d41998c8eaae8a3ea36a47a768571b2a55751774227e4c26d435ecc15dc5e25a2b29c9e10b2898b6be287fd32c4f71b13ade2fa8913c2aaa048492d9a7653997df15ab5af23879d4f244d006f8916760c4348459142ef7630394e0ecb2d35eb51674d6cb1ba014b61bf8267c13e5ca16dae488c59f5985a6c9f77724f3732891b9a150659b667ee44a58370400129c4a68ea9bc6bf19133582570f54ce79e230e2bd4111897599c68b31e4ebedb2815ac5b5894d3720ddfaf1c19a059462f419784c73bdf39dfbdf168765b3ed8ebdd13284f1b50662ac6090ad2d283e3034cc0d6e89bd0c0ab41e4b933c7e4972ec9ce6d6d7403970d991e1ce27ca87d1a1dc06135442db1e0fbda96a7909bb3c339fbcb0370616470b5890b4ecda4a5b5acc0a896e41b6e23eebe53e5af563b79de45ab7ac1e97dff8f6af0c14fc19c82a264306ec8e26726f5529b5ec335bc6359385700b3cf0e4f1e38d1fd3e04f49147603dae9062d41658e959bde256d5d92b708f2aaf4fb9b14c82d4ce94607a6f3fd35a08dda14fef08a21c1f6273302c8ddc51aa97eddd0491f14cd8e7d90229e066c33d6d409c7b5e59e8a1ebbfee9aa1746535eea771af2be4fe30d932cda627aaa8aa741d70395e93b8e1b4ae8cd9da8075d97c454633ee0973cdafd66383eabbf8671ccb1124fd3e789f555058d92550a8bbfff4cd11c72d58d8ecce87869395bd7d54bfccd8e726ba0b02a9f955c6a3c4fef9cf132fb26a22d1801b0544fda82c3a9b57ff5d7d249bfd0d0c07f85a95b58465aa9285a0c309dbe37aa9e9caf8657fca5b8c14d0cd1ab2421a006a230cc5768cc1a7167fe3a6589c487449ff808ee18bfba8d6d8bc017043063326dc241cac1c9e945422ce40d0095b26d2c6b7c41d03a46beb567a9b94a710f56d4cb89eb044e0de30ac82161b2a9c0bf544286393adf0fd04bb8a787cc653773606a73cd0216e03a057bd55a3b5cb065c4d15377bb5e63a58baa993e1868f077e8dcbef7ebf4274c262933f352c2148dda271cd4af99d4b7d38ed4ea9517ea033f702d68ddfaff767554145e73361a04efde64a1e458b851f4feed68962f247bc723d2d0815f6cc2cbd468f7eb73c3a7d741bac64193849a4934bf791976b0a6fd324e4c49bf5b2c40c73cd72d6bd40f4f856fa799532584259b26badd664ac8dff35f611a70ba30c4b680fa9424379ce5b1b4f5522fa83d7422f582554fbe3b82a320968e1b93dfff8e0c296e4a6928b994a131b19d9d4bed9c4d97a8e773a816462f79fcb61dba075e344ff2c264b54c322ee52d5f9e7858b1ef898d82f6ac335adb997f21656d622a8369c94eabe20bd6a2ecf0519a4af85bbc8e59adb25108774f49750eedc8b8cf545325edcf55f8dce981bfe5c786c6a543cdfdb2fbe489cad26c8e782f68619e5567c7e61d7b87dc9ae04f9b11a5a671a27d6e5e7f3d941982ad3015fa32c616a5897345373e50de5a79be3b600e2b99aac9cb9f3cf954f91a9c60bd690d4e8f317b92d0c1ff8d056b267437a1610d586d9dd0d3037520d5b76e64ca388a9bc3971f62e48cbcdbb9aea487b5578bfb9524a5a1114ce1bfbf36a2387e4f950f9890493ae67ef5c6869e9d47ed26bd1b35a95d2daf493ce59ded3fb70767f4325390142dd89345ed8a8426d028f99480f9b103faa55204a0863fbc6514607b9e6cea34b956b2e5c9d4a3a6aa9926f64e0114b6a083bf383ffbdbec220437a4a73b5eddad521502b5a42edfe93efaa4cda1cae3e40891f73043eec5f505175e9613a1f2038cb0a93b4b0bff8c0852e15a0bd1e4a72b4be48526aa84d647629e5be8e474d8d62bc6ba0fc59656c4cc0861f129de714f51423d36161bdc0e81a4cdd7442c619728c099a33bb8a92343bcfb19b79d73b5bee7705ffe2bde3db171290c6c13e55cc22b6652ce1c9f8257b5be6b5f54aec386f81284b6d28d2974990d771be0a48d547f4e389484888790c4c5204b84ce31e5b78edc11b9820954c7012f56f3158a32129c1e693e07cf7713116b57d490b6f30e1974873b296e3e75abaa02512f52751acee30d4a878c71d134a7b4d812aa8839350f398f14033be1044926ce5e765784e9918456d2f2a013cca538bd1c3e94f21f91e53bd61335706c5efefb847de14db1cb6ef0d8b272ef24ab90ee8819828aaa76a61d09e88e96a02f744a6e15041cc616941ea3f4f2622825b8273b06e138a08a2360b5c7a3ac06c1a1b245f142ff933277cc05c199b2f5b4adaddef8edf2c6bcfa9a52e69424a07bed5e12e6dbf5a16ae4b5c0b97062737a14c9578d4320ab4dae50658a3ced08612c8186d2968b2bb4cdc726deb27d49d5c1268e23dae1d3fedcf9fa927234a3302f146de421663d6e49671787be750c7041b281478e8b19420039fcd309adb64829eb00b837699716bdfd4a862c57e5d08f4ad0e006f9ebd6c5219567822cfc1bc797c34241152b0f3eeee1a64df1d9da1eb78633244bc36cea654107b7eced9ba1ae0e1e1cd6e6abd98d63934838f1283bc84ac618d368629b880ce7d3ab6064297812537aaee3a78c3ecf0c2ccdce0307f0c838863eb6f9857bb616109d3bf89b8b5a9f8aa11e1f6554725420c95b8c6481246ecf90f857760f278ef52d38d2504db69e73b908fc3afb9c3d2b64960e5a676a52302cbc8d3882593ca017ef95022b49631623dd0a983ea044aa8a889995ae1b56080f330927abca77b56fb0a03d36e847d94b37940f78d6738bf94923561718730b9db661dc11621d242ba704834ac80e88370ffb32835ee17caa74911a8e3c39d9c9845bca9bf2f37a1ecfc0f52ca45d40ebd21e5a55ad12f0480bbd6149fbe45e2e056bcc7d76d6431ffc01247bd62f2575f99fe6c16c81ccec447e3914ce0712adb9dc39dd5b714ca975d1a9de98d5ac2a8258a0bc09d2eb5f64bea5934e3d13efc1fcd6822362251caaabc9418339c84948221d9416aafee0a49afe35b76f73e3400c53de520d63ea8662f3a9d433247c3d3b4064f9c1aa0653a4779a6181a35281b3c36ee2da347e3a4f8f4d652d6922325c64108dc9981120cf396bca6b7d424096ccabf9287286cee0b91b8f98af9d5056e211676938f6741788099aaed6bb4fd60f12ab6fdefc0f1f1004caa692bf0d1b0a61199ea8b0af93e199a7587561eb9e4556b2022e3ca793590321f80b039099ff13d39eb583c90b2c31063817d6326dd181dab77b4f6c9c7fe8b8ef8a47d786f8e7074183f523dbdc03f3f43b801165b1758c4c081fbb7ca373e67978a45e2d6e52c6bebc7560521a1fc6f85d09c614309a653eddec47434f6ca9d99ef70093ada05ee153432e04f55320c3a0b3d03799c546ca78a93b2c11da6e2a8999e25b490abb539b7926f7ddd912f2059ae11bb543b86fd828f337c570ec315a2e3ab5382a1a5e3505a7f7dc4a60218c7211202206f0e434d34b7472dfce5953aac2df0a6ff48e65ae3966c9ee290f1192fc5368e0e20ed152f3619d26495a14fa00d87afae538f7fb928c48c86517b21c0557ab0f277a1530f33d70d26765b393698826b59a58ef85a43a8453459c08b02c258889640430b1ef39125b83c7b03781cf1595309df27c5c028a2cc180cdac9b71d5dc7732eeef1d2bcfbefe8d6b7fe859fca62c7ab6b0e1c49d7f4db0fb0aa5b9a30b5091a08ba2f73dbbe1c7f8598d4427263aa827b799909ec59631a235ece9fd8fc8988098c0ab596174150b37e1d2062cf8b5b7934e4cf692b5e129154ad43e93025d03b7df33b91cf7d284c3041d604b1fdca815cd768c3bbe82a80a82706aadd2b25bfa8d7604321c2d3a84f840fe3010844b4e80b7ac707bdee849d60b2126591dd8feafec0db2cfa6cbfacdf32ced7cee9145d1c709e918a38ff7b3a8e716e2baf5acbc26e88fb6e7afe3261f294939e437e679976be203ff6d6dd8a7ab7badba7a9cf4c0d7764608695014e4353d9792e0bacf438bb4b3f808e0d4eb0527dba6bbf787239a77d116e2434d4a1ba67e468f767986063431b5f449b2340f5f0888caf7004303657ccb0e96ba142e5adcc999bb138b8a7da59456052d6d63cb6d89d93ffd0b050377948a7192107e738bc89bb714b4e3ba034d555c37d825f3d78b956e060eb4ea9ce7585fed402f39ca8072eb25f9ef25bc4c3530f53491d0b7b09b78c96be6790d716f6d1cbf3955043d4c2bf16e428d3229c69b29537de6b062e07230de924e4a865baa037bbf504b010f9a749b90c74fd0207fae5f9c012af4e24069a0a0d880e699dc118c50df311be54c0cb8fb2026bb0de5f8a2a4665986333a8860e1a4b9d2de41bf72b9fb70d3e8fdd2af42e8024f441e09020e84c8beba850aed16c564ddad3ac62dca2785083be35101440902e0671eebe33f91db3585514ac0c8905b97d26ace8f6537c827c1b41b86fb8303129c8adb8f2bfff3398a1f1c1644f06cf3cc9a5a0956c636e343fb95169bd0a6ad419ff53066b9d7e5420c0354da2f69ec897dd4bee0573859c06af1f839b979faedd3e685dfd0fcf9e263686024fca765f04d0f41a0ab0827dedff7d35f2b6350557a8f6822a24f0441e5126030e9d38d5f51966550179b8927114f97bab8cfef408ff1c7bd3130bd5315d582147edff2065f21de50bc4bebe613aba13b18081a1da9c93938fc4a5e3d2dbd315cae299724284e39a3844cdc222d682715090d891629c542891bf0f2406be8c4b26af2c5b1d66cf2f9f8f53e67ac1f1e446bd50744252734ad3f42d7356d2388ca5188cc2eeed176280877972cc13a70125524308678396488fea9696ba061cf9de0fc157bf4f610933606d83fd810cdeaf11847f38a3df6fb27485843c406380dbe63168fa4d2ba72848637d1b26a6c6af0770aecf4c11f14c8c6d516850366b55175c3186268a8ddb198c1023bf128704f0edbafc47f38a4b2ed980bd94313228a25cbfdddb52f041be7258e1481c0b5bb4a546180be13a7e69cc7ed578310a8554fcc08e29449bc859ec63a4103f9fc4d946c1633c039afd10517f552ea59c93ce18ebf5674afb7be3849cc9c8e5c54ebb69cee796e532937e020c40c7aa78f7b819ef9ea665088b210818b33f2a0deb6bb7406512d50b8ad99502b571bafa6d2cafdb3edb01a68f74f280b9a56df359e07566d46e91f76ca5e66d96b4e3ebf45c4d9983fb91f66337a42cd574a4d3ee9bf15b070fdc130fd5cef68f7133a22428a99de1eafc6dc09e98cc970515c4e07735f486a6d6f2edb3d35aae3daa427c4f8cd56d5179806a783073e3c6c2c6a0c6ee62f66cef7133b56d8bdb94280cc6a900ce90ea4bfe52d894940a64964f7a003e5fe7d5cc0a6f158c3dbace5d90b6ae090a3b1b9556813f4daed2f09f48c42619854dd17f98904c0955dd4c8ae71198860eb7077fe8878a258f276d66e0860ddf1ea135e860d9ec29a86d8da9e3dfc05716f528c2d211077fefb799ed0d20a1e33a900ac310842b47396544647fda2d62bd4654eb79faddd3404f7633936331b43592ce76afdac7e4adc642713eee048718c60861e399c55f1afbb40b5a24a600dbe5d5dd79eb0c86d6e7c7a709e73ba68c42e08c79ad567a4a6495cc70de31f2c5caf888caa3939b7d076013446794f1aad97994a4b9c680f4f0a792b0c8eb6eb4beefc90e83c037ed937b039665cc3b75780822a5f49cb941db63367d1c04456ac4be900268aa045634461eed466d48df7319aaafb4c4f789eee4fea601992eb1087a0707e8d19cd1b92ec5e4718df5846e2f9b574ff4f97c8e8bf3082cab2daee684dcb2819f5107f98fa8876577774fe4a9b2c0705f9d51378290cdb87df920a3e632fad0e7d71915c5cf06415948d05bf45e718f021a42286c7a213b9a05c73159a8b214ecd0c752798e6a64c697a065db3982de687b6f5a93376857c5ec767374271f2c39a2352b55c905a49fe0678579e1dbad955aee2570fcab949686f61a47396b94d318b9f59b22f71e84d98a68500fc7fa1ad883801795e0d65c7bfced23231257f588658a236aef66a7c6e98dfd394d97d83be0d62ccfcdb29446075cd0a2394aaa116d23fc901bb54c71ee52936d5f4a650c8680a17542faafce12ba24f0a92bbe1816f2da2f075b58e12785d3d701d11c4ba526234114040e03579dc1497edbb7faf00a1d063972a90e972336d6996827ae179eb331c8a95d944bcd18e925c89c5df2e749d9417e67e9af6ee3a8b56829abae37577cb038ce17845469d1885ad27f74bd791f8ae80ebfee5ce46aeaa912e4925a2487a0f8dd4ddfe6859a4a438864bab4474f182d691ccc48dad79963ea86f15d076bb0a6552b734d50a1ae96028536f16d1d3246c26ec94a448650d0d9f0f4d35503ebcf129da035060cd7413ddc5697c197df18dfbbf24b433a022e1c247dccf73a3037fa817f450775c84ae0c34f2046ea780e059ba5d1beae0c904f46fb2aa533eb21d7269c241c2f4118f2f2015221fdc0c46bef570dec4b26ccc210d988346ade30bbd40215978004991f10daa1ff85cff5f37f2223df8e75aef1b9797369b6c7b40128fb4e3ae6fdcccb07b6fff03425b5da4ae14582a002e04079589313f6dabefbacd8bdbbf00d6e89d0772cf7fdb1287476530a33358c28baf99d9eceefb41cc6bf93dfe42c888d85648ff4c6a322ee0d826d5473cba9b9cc10fc2e5eab8e4a7cdd62df22ca9a3452cbb64f5eb7bda8261abd44b0ec86e99f828596948af5bdbb7c92b98bbca523568c8bb43906f6be4e38ddc22b18dc87a3b4284d08c06736346290c87cca8df1b3fc5293a730581b4d19e3d8c31bb23cd27bdeecaacd9e22d4def555d282e080a9f8817be3cef5bf4b4ecbc83b126dd8c1a542124f6454fc0a50ff6a451abe16dfd7c310d4e34ecaf969f90c68ea31e0e4e80ecc2688915d3c353a4529d4effadf88fa3a03769673ca47f58d3f7b266770101dad5bbdb5b7344a870ecc2322341ee0909d91e446fe154501081dd6ac4b01abb715505863faf75ff3e50a9d461024e09710fb829ecd7f8cd9e756802620b38313cc93d9ac39c70c0df47c334643f225d2e1c98022babf1eb003360570288c8b7061357381f3c982c811cdb006d6092bc9dd2f95c86a558aef67635c941eb1023fcf1670d2744f6a258e2e865d9484fc5e356287c722b32714229a4eb2a3b523ed8f4a2005020139cdac6407cdb578be85ddbf3e7003536ba6a28908268b1f332de7e9d5b84436b0d52bf1cfcffaff5fc80e4d7783b1189169143c58519e7e024829ce976fbb55d046e101fac6a892f9c46a13d786d03d995374b36e3f9c97ad2c6a4956be5a4d5631795b241d4f27f047013d32b50c658b082ce23d954f4afec7f52eff9b7bab64243ddfd0d0367fd7ac1f821a8c362b95388e25b123327674609343956afb8cfce32efa5448871bbf44bd22a8fc5c0890b242782d932eb5f7c8273b4f424cde490beefe38fcc6440ad6170b58485176e198e3e6a3fd0797befd088ca78fe0e43aa62f95f8079ed5240ab9673b67b6fa300e7ad0282702c06eb03f8f15f73bd4f2fd7bfa92a734c82989075fd561c4e57ad7effabf4e9bc1ce54e0d00f0b2eeb91ee0b313f647f0b0de79ccd350ad25a87b9a9f981fb548142c4b6b594135d06d7e75c3d70d8d136d014ed288d22cda60a25801dee7b719b58d7f84136df8da16a951c35eaade3b104476fb2ae39f3cef11df8dd8aeeda73c6de7f1cf3ccdb5f6535e218f4a0fd56d9eb8c4f4eb4a2b2a0bdf4f9af1ee76344fb7def8177b577fdb975231c36750a89fb9bbf3018ed5cdf9be278c08d65b085831cc5159dff851a34ce4d3a36ef038d78d1574115e0fa6585c51602ec77c3d6ceb0d5b639650f6070936ae63bfdeda4c189160e5106760a25caf825c97a48b5f3c407fd8c80cae5424e1990133e800c7ff469b6f079817470e71fcc3e07c358ff2b63531611e49f3be2a8d094abe07ba6671c7c5dabdbdd5ea0131ec75dcd82a5dcbaeeeebbb53200f47665f1aefcb7d897c9febaccf61014e822874a5d5c028c6866bef2122e47107f459d23dfbeb5831b7944407f990598f7919e263aecf777184b2c851056e72349ef18583dd64dfaf2418dd49afd75aa828f80798a5ffc2eec50260c6e27d43f650912944c44ecb50ac149776a0e074fa881e32de6721e975dbf61a4a89a03c51d78ca7679fc98b5842db0f80fe22678a71b998d1b9cb8c539c5e2492ed11ce7b23f73c50bee524f3bc0ae1191a68199e28ea44d6cc6a48a4821ac4ea0775f2686e19429401f20805a1b4f991c9a9db7a37ac324c7eaf1e8f4234e2a347a38ceba3f6052910cf409689df2827cd584999d637ee821412ebd6077287605c193475250b9456025695a7d99354c464e8672f26579567748e560e79e32afbd230b9635ab021f4f2a0ed64ff29b7cf4236293518e59eed8a825fceeb930ebcadf2991a944437d224fa4a55846e163abeed1afe0f60cbd63e0c1b5c8e575666be73d05c4da7bb1e70adb08d0ea364c2f533ffd59b59a643237b9df57f00a29f5e56cc8f7f2e637dec0f8b6df72941a5223f2d87ea5b5ed8a186725c464aa1b604c1969f74fe6829029c45534f52fe383bc2509a198ab7520b35d3c58f68dfdd51309f79532a182f4c7e61f0c0e52af7c17228bccc1a6f2806f832c1e5a72b29328a9d01c765a4ce7269ff5166a5aa03e87467475c79ffaaadf0b2f4d0fb5245f9aa8167490683eab086744d4c6a8fc96e036f12e29edfb4fa897fa41b7f619010d9e02c079414aaa416f06d8f88dec6dc0b433ddd972982d030bdcf2ffc57c3f7cd6350c8cf009677708122e99b10092cec1676a497aa498edc6f6eb2bfe91a3ecda0bb15b42b95c16f805053b1c75e8cf69913c07ba07b9157630f3e12140bc71882a6eef5f0b7869a3fafd00cfedda6c01274cbda966f00074b1eee1bccf8f2f7df35289f1c43fdd91e5076a9ef92d21925bcfc3174bfb5ae3e82aabccba9b5175c31cbcf6dab67ccc027a6ca50caf5017fb79c721e9e185b19e59143eb65e2bf2d62e44712ec96a25bd940ad60a8f6a55d1ec70f700599b2d2dc6fbbd67000a6194b4b3f83b89b2ff52340fdfd06599675ef9298bcb0be3d5ed8b54ec8f6567b56f992ef9bec1ba0be3c37fd7a45f0288173dff5e29943c4983734cc19cf03aa445ee6e76190905b2409a96d5ff6e27861702b102791286e8acd96a1965ed7187e9a13cb4252385f7e826bdf4030f96cbd43269cd0c268a4a3b3c1c674ec1d755216ac29c6d7b88d93daaf4606f65c9cf338bee9ee6f066f07720e9643fb6a7bcb9aeb9977b02180ff33283e04c47123b9171326538ad77f50e3bd6882c9f77201fed9bd0ef6a97addcb5bee55a68cdeaf9847808c081a625b2d145c42fe7f1c1129b67187a100fc96a27472b5510d2d334efe289ed23a2eae479ee12e3c2a9ccab0a37a4dab16cf4311e06bbc739f43be825f6bb094976e67be39b695a1cfdcc699b12ea96643bde5a5709548cede4b6cd3a9c3d8dde7e32754871916bf55d6dfb3cb93c7dea17a524d03d81206a5fe92d57714e1b0be7c57f12a82e3a433dda2c182d270ba61477bbec3551c7fd53958926817ebb7d8bf1696fd1dcec127adc6ed56db1ff6fa1dc8dcf89b9063bfab3377fcfe99479c5b8f217e2b9ac732dd14b5fb702a8f0a4bf27dfbf5bf9273d6b71ccbf0843ed7379fe219bbf6b377deb20f9e4d8e57a950624d475f026c1652fdc711300620a7bbd88169e3427c467e7821f8a8d13f9b1e9d9d819aa669526399ffe274521fe3ecf6bb1585a3bbbe4524ca0ccdd7e8c397ec122a366ac8363af5afb383ad353b82c4e51c5ced529a60d283409d6f0a483f2f90d123711b123478094d073ca5f027136ee5a898f5f0fb68d51c716e51aebfe5e1be4dec32743ba9fb0ef5f54f4932ac19f65f419d0b1c1cf1bd321cb46743fac9ba27d18f798f091868df6138d0ac892237a0b5bebd1ae5dc2a4937e60bb2ff359c878c2b23b913d9ec1e2d9c2d4f4fcf799b1dc1e3d235e349b5838df1abff1955785481b1f1e66f42e7db257fc2f7b81f4d746fcd017912519efa83c2452b275ef1b1559d1ed7c8c2363a6c38423d49cf7e2d08ede111eb21c1aa9924a8415ddd2410c367e4ca4c76f652b6596b793924a980e97a48466226653241cc5c985dafe60fab761db848317cded2ff07b3ba9b90b490ce344033bbc9a1e8fed2f2551e1597e48fdde66061d292d8cb111d78ecbc6265162ceadc5aa8510f2a54e42ef4fd995f7fc4e05bf9cea81c294179885bf705615e4d7bfbba33a9af931ac9de985a9b294608c49ad69a5d00f63e709bdccd924788d3e266b4894ba5dba98f3763a09a57356b2a0b55abb9bf4f529f251b06619cc2850b69e24074c5086ce46b209c5d2dc785cc4ea856d6898194dbdead5377df22f4baee9bd567e84261f5587cd6393b631118be397bb5c8f09e692371a48a6812251f50211f51f20377e14e017fc6eee9d663c2e455413ee24f5e48f0dbcf5419a07593970b571d33e72c3de938fa9a7751bdcbed7adc1bdf9f9d4dbee650b65a0a8c8ab8ff130aca2637ce04b7bc71b59875e781ffc22f15674b0921a0b6bca8982fd7ce2254d0cbd2eeed72e98f96fdd4518c70984913e980aa825268f5c3df20444c835aa62be8f9dc631bbe49fea7d26fe2fedc0f3c57c07df31f6eb2a3c2b94fc7674e21a194b785d35db7ac1e532810c99d88d73b2291b948af0e39a330192f69567e6ee96d13460fc45f40b34446452f11c6e7ce84a1577fcb82a0a62bb4badfdf13acd63942e06bcf606d916b3e711af1bc8df1c021aae617e77c0fedb6c90a8020fdc68615d54a088ec60c66de7045fd4f8105c9b635a4f6c960ada54128a7e2a4ff37cfe4dd4c9fbae797eb5acbc066b1fa49ae9646aa33607512c7bf1d7315b1019d1b89e3e293d8ea2bd7292f90998826b2b64531735312f5741ad876c36b6235bc8f1ae40f3cb341f0357c2789994bc2b18d622fbc45c6a15668fb6e16c7edad30d2e69f724038aa3b38b52ef19dc4715dab7c9e30aeba5effe697f65593a5d730a7178c374bb76e5d0a2ff3adcb0795aaee43edc0b7aa4543c339694b22f3748856c20307a7bf5e383c36734401cab279fa486ee7ca797aa1c1083ae36d04a5440f5e59ea12ba151c2c2bac21026c36e515f78373959da4682fbe28317abb85b05cfb49ee10be7016dcff2934385e092342d97a2ccf930f224b3341f62ac04910298ea654f628a10f1a0f40f0cb6709c31af0286e18c02290ff39e8e94d1685fe762ada70ac24b2bda76f3ae12b60521505892ecd6461fa0659e92d2f5f2997fc680266c2384b47576b62957f266c366da7bef64f9e31e88dc82f35abd0ab2b415cd54db617ac74401767d68ac2a92e3daad3c35ea106f60723471fd55317bf2b178d877c1169dc0dc11bea7ab747c133180bfc9dc089d403a765d662567fd8f4ad8108508ea4fa3c2c2bbd23ec941e1edbdc8822646b48ece539b616578995ba9a2004e194d17d54ab1bd4be3eefa5f66c7a141dc8c4542e3c4c6a554743e42066c20d578e7bea5c432a18ad6bbee2abfffb778b715d66871beba2ef87df56484129d298e607a6236ef9419e68c3a3cb3217e0f4ee0cdd16d6338bcb2253e95a07509868c4fca45614313a56ab76164cb5c16ad4fd85cb01d4df9dfd829447baece5da9649f4b468cf02fd42305a230a745960d61053383a9470b141b0219657cb8e0547442bf4a40e98591ea9b24d30d6396dbaaa5c028af914ca6552ebad08c46da1bc2c803889d87846cc414a1c79a72a3baee3f43cc0dd9ae05b94076f18dc7bfca520c61bbd3282d583301cb3fb8013e214da79ba47bb19b331661ae5e2c32f9b5cd03b3763230ac8aa949c14fc7428e9318b181f771fb49bdc585fb67619924b0611076b6b4a3b661a831dcafb5ef11e955e8b9578d4bac0b0273d2b05df7ac607e8559224e27c701aec54cd3147bf315664625b822f1c53433c49379303a6e5ed8c689e23e54c9b7c8939abd355a6494fdec8836d64b8dd46d7a41a4b8e26beb7f34ad6e53c459ae6387129a1584430ad636611d5a00df37c5fc1edc9699de3e3ca3ce18da4ace69c1d7ad122783fb5109fa2b4ec2c4e8fec11961cc4c61ee3b5469b7f776ada51e30de2ad334bbf7942845388cb89b514daa8e6b5ea3b3d3758e67bd5a0b876d01834bfec767268ccec5366faa1a1de5bc26762c803b6e1e0bae228d9ae7d88e8fe0942dd4ad1d9663bef75d9a5f4c7d2319f3457080f459bb9302f6872c68da026f49e7bbb2f0459dc2cb1e5db84669c860c843572eb4580f5499f0961785cc3baa5740891bd0279ac37a8237e5a5bf1d93aa3bdd9182d9213aa1e12e4d1cf844dbbe18ac4408ede1fb2b0eb78eb527fe34e62bfc20ad83c519c3740519b1d897e88fef00eb244519a101b9c0260f5f6d7c9654b69b4d2ae1ca84bb7f74f8e1c0220d6a2dedc0c3cfecaf38176086496d6a16e64d337708ff77d8241b36eede50ed4532394850aebef821c7cc72240c555672deeb02413ebc9f0e7a059a448dbd4c41abe12e3702550dd11de113da4b0092665692f02af88475893950f21bcb81000dba18906eb7d13b5bed8bfbfb34bf992c20d6f980d36380d2449c4bdfe3000e49c6d8af9398abe09c01b111af5f073a2771daff195185ee56f49c9c81aaf820d8b15270f938b3333769a65a31c75e10158004d69908450ae2102311d0993cd69a1014d17885313c269ccd1a3decbb2c22c6e69338f95d803e14b56898831855b78951ff2baf1e6354ecd0d81b88d85142c3a936aa4701cf2c0beb037e62d1127d93de3c1aad30d3706d1c7e8b59651fa59da49ef7a534e1a48a1b7b4ce6b9eb8644c5d158f96d84e1324c0ce75df8e93e21fde748fa75d41d36734acf8268cb70c6a3952abd45a948992e3b3205a9158eb0bf2e1375a6e160f84da7ac84c6ab9d25a188646db1d63921f6dee73da5ddd772a97abf6473c477c65a2becf3f953bbce6c8775454d5c778eae7dede8e5d73e0ed7e71d6e6528c2c84f6fd379b4d54630ce38b5d18a887bf4b48757cab0ee756a946e56019457a3076b645b941d82e7947783ba34cc415f8552dd111d61b349684a6bf2ad5ee6cfbd5c4feddb10b01e35e5623cdc7861d584b927d9807228ef31c6d0fa26acab8930c7b0a03e416044952f660537ff58536b13f83ebaba556d3da4d1b3fc0c7c21a5dd14058fcca84c2439a516ab0aaae6f4d25f4d2f3e68683ba88f7b93d4bc5bf28a60708dcf5f0ead1163984d45e1403d3deed31dc1ddea501f23ac7c53c28bc9eb3ed880602b87b482c2f8f2596e0d3d5a9c48e6f59c7264b7f83107a0ba906a4bb9e85999a1ea913cad024924cbf613bd2837f37cd8fd2aba31da0cf1c22d0a17a2ae8fb6dccee738c9d88c39a4e4ee768846a5a2a1138afbd66bb7d3efd3fab94fecedb1c1844db845c6e00befe435bd65f344c5424c8999860bc2cbb423890da0baa3b7e75f573bde51c877601788161aab5375d25d8c9ef7e7f20758c871ffac1d252ef39f75d9a9d76c52bb66579b1aec21a20523acd85e69a1065d2ea5d11adb78e5cb08047e1da8997265f3e89e3772d8195f6364a34661b2898790f08e39be1f1b5106a04f32f2a442161b73cc2449a2675906a9f009a1170a360cf2065d929bdb0ac909b7dc63928c7292d872cd9c35048d73c031ede936bec3f5b9914965ef3fbc30057beb9c6011cb0ca6a6830bb58183fc8365241487ced6c9cce8aa8ce970d7414818522e35b7328eb8a5023f7209ccb7ba8dee494965af79d80eb4c80b0ddd0ad871e053895e00c9e7e83b2756b3b560ac2cfbd7e286ae12235caa0f6f5efbaa3e52c421fdefecf9c38eb757e80d8908c6f7e7c71314a5d12f97b318369b5a0d748f8518e8e6a6b4e67a4821635c82ce308e4692ee9cf61130c12c87794d7606eae2ae6f8e82ed354fb53215cf2d0fb38e45750695b4d7a006ba7dbdc65090c2e24ee51ae806a491400d8902f1ded626113033ac97bfd5e55fe715d3da29aef00e67cc5469ebb597602b5615fdb1fbf5396c08d1a7c13d73d85ea78f4e9e3acb8556619138ca3f76bc138874149a95afed2227e65ad3ddcc0f698c253ff2d2eb7c00edc47ef24de478fc4e460dc7d710e46505bc6e2c0f8937a4b705747ce2984a747261b90446045e75e20fa7312198e420833331b8ef232caa82ef01a7f47dc4937206bbc91e02bd59c81b97645893147038ae306d7e10c41784d73c1320c744c1f296a1a5d4410be755b5750d616771e81d1ebccb144e76623278467d3cf7d90a06ce54cad82ba306c4ebb9bd55cdff9a093c01096eab817429bdc31f02356658c66def9ecaa2a5f8178b6c096402abb6e985496c618eb01b4b858cbbd837bea015ff207cad213ec1698d1c83d6734f3f4992970ed01c82707f4ed58efcbbb38e1e51a93f95285256e951ebea8c3275b777a9a5ca2edb0f7cab1969cc72722ee752b1378051412725471fe89629500cd8553eb28b7bc35dc71c268e7bcb47057486a5b5d784f8365d4e84dad544ade5a498a80f9f9b1f459bd7a9ef2da2b39aa2ecce1ab00983d79fece305290e61ec3b69946272f58ee961dadd7f1aedd2631dc4576c84d4d27ba36c7351856d46e57842d22b38ff98b6dfd7091dc452d441fb5524128c6571024fbc2223b3a9727a4bbfe26b746f41d825392c3d1089dab9225f25b37f10c792c4ad8d8ef3c858398a6a703516d8a108527aba440a96f2cf7ac160591b5f7c67e30d69b607be9c9093b840bf9ec2b4686a32cfeb1ff2b3a155547dd08567ffcf85733cedf60c6ec1a7ba967a94a2e0027e7d094dcd155853504a156c7678af2a381650ef6a489f624428cf5e1f67c474521ff9b1d6834156100aab11db69efba4c9aab3ad21145fbf3d6bea7e17f0b3c6152397d5b66912c09ac2d846b390b9217ad2536d872a4f72169abaa3305b9a9ba7e4c9a9640beb44c62de3446017f5da9c0fb1bb72417a2ffb350b62fcd90dd56fbd65a48b5daf07f06b98e3ea829759d3a101e3884d870ae94b33adabbfd2a155a1e922f58fa18866a39948b4a79a78a4c8621cc97cbed6165ea3cfe5a57b3c3751d0e9e49aebb2a68f57a8a1d5cfc7aaf883e023fe83aeee6f2b897c29e501981a15b4253c8f8c135c327ca194370913d78f04ed5a8e36b4b9e5981d580be1501c3aecae688ecf44dd6be3b7d035ace8a57bbb7aa59181aab20198facb1e3879fbb24cb765503be328af6b62eb372c1b71fd95fa41fc89e640e0917ab5faf5411ee279f43dec2bfa9dfd676a1fdc2cb86284fe10c09b421a5f5cdcc446665df48c81e8b91008b1f9b101c41b9cb18523bdbc616bab569dcce8677aa2ad8d1ac0333817f218d9f2a4e2f74b75c6e277281b6c628695c7d20183f4f6b6b126477afde53e5d4cb1e5977e4ab12d6b3334e776c44cd0f0cc31ecd0b54b6c43d39d9d107d4ee401d40e207de053d36dbb04442b74533e987cfa7cd215860c381dddd283c2ff87e379dca1bcb6828de3a509742be10ba257752ac29e99ed058980eabd91d92266d038127e56901281d0fe2644611d3ec3b9b36d27c245077427a543e38ca249d5af9ca85f2d00d9df17946e3e7237e0783ad53941f16f9f6a9b03e8c50a1c1ddba40af25fb6b74c40f040bfe1530a8bfa0f1ba5f46fa7b7c0a5d2b3ed6118c98243dbf13170bdaeeedbe57ed9cdfe468445b7f7c616cb00e7f5e2e2d9520e9e2cd21404e892489b0df4ad2f09a544481d300a473440d9f6a12b87cd7f98e75e44b9e7856735962a4f303b58405e99417af88760e27bdf1440df382b6af5162357ed06116e2c56dc737345cbf2676f5fe66625f10ea031745ed7be02299d638ae1d822e80b94a164dbdf323034cdb001259f96b8486098a76548d297b5a230286d5d307aa6a9994db056f689d0d8c9f40c81e99906ad2ab6fff2e4b7c5481dab80ee2a8056093c1fba0804cfcba0011d862ebe206684050c3f69c3660a10257f4781f3115c9ea878cb2af1bc19913720ac177c16726aba2f3afdb7588963a7893970eee0c14eedd018f2ef8d78848d109a8be1cd463a6904a97d2d608937ff7b8772c5cbb8fd7a50437116b54e52f5cd2cac7689aae281c7b94e7f2352b95469b797d79eea4e362b5fee2680e8908d99ab829b45ccf1b18b48ac3e3f8ffaf45b2a29a3725d6b12b45b23b3f2a7fb772f16741a70d0c4b409aaca1fbdd2e73127be4af9481700d6ea6f9e25a68c8b04697176ee13187b364c4c16b27b0fcf81be048d4f9f7d723431cfe2837b208f74ecdc84e3fa010d00bad327c18cce00624049157e00539664d8e66c44bde4511ec822486d8db80197a4ed40110d45a4d470093b8c1522679c0b4164f6d6aa71b8292c911aa089cb83e29e52aa308bdf320350aba2210157cba44e5f0551a41101ce33ec6ed9391fa1c6aa2be1e625ba34c0a0799b0e7ccc7242f57badd1d5f0d712ffc967932260c31b92f0121123d32ef7acfe30d8eca43f70a38e9f7e4dd6d32b20014e971ef389b8aef8f16f5ef5a24243ef9fd5e3211922965ed5935c687d6b42fd5ca04d6f9adb6915d6d76bd6a125f0c15f178b86461a0883c31f8482ae754b15546edc81fa4ff3e1b505881167c3b178068c09186631c21fe67ca4d0df06a519ad78cfcf70a575231d2b373921d6826d49022c5741d9f5628d18e1ea9f8b7d60b28f0597ec3b3de27c5e30b2da554cd2cf0b853db2cbfd8c94ac2e1a52fd44a5b1bbda03bbd0d91a1800251f0de9f17233d9a31e4152c6947cec9922215b58d517da6499411dccfe4a57b95716eb12942b4e864cea6283d92772d6480cef08c7efdff10196734cfb397a9f29ae4a43fe2274a724140debbb3e0dea689ae09cbd6ca3437aef87911d90de053fb8c40a17d3cda25a7fcf0c2bf1de7f1cdbe525203752613ed038e6901ee8f6380c6a475839ea6a53d2bbd69558e425cf43e1d75be602b01e158e908c9b02e577ec77f1f2c0bbd66843960a22ca9740864ae35eede46477fdb462a1d28d8431e26fff328edcb13724ad3bb900325604a46644517bd58e2144859373380f5253554b7d9592060525328a11c40b3b74600382484dde999b71a20fd06791b9aae5e898b406f1a6272603426959cc42e258a6f7e93d66432d7166121cdd2b9f22158c0ca79e34689f161c449959705c43ecbc11046516fca5d5399c2e15e057260040858c109457d77eacdb48ae22ecfa84b948801fb4ddb62f9b71b2d0a25d9c020b4515df93a1501ea8f2ce9191bde5e118d0f886edf66a9b49832c35266d3eb41572ea0b192ebcc34efa1b18820a2b2e01ef03f04875dd497f4e1f49dbf1672e0695ce919c537d3812973e59690c006999a2aa7ad3a676778cd5e28e0579db7d7a53260d4ca5e27c5df59649974ac1ae6e825dcd06a28235d7aca4e4a4d0b80b7560b5c0ae0f4fca5f51db3ccc46891188ccb54cc9447542754c92caf8d8d28ae6ed19cc298b09be1fc636739c03cc67bacd66541a1a0e7712231aa92847a2992d13b441657250ebdc96f0e25cd3b466205518fe5cb0534f31259daf9a931991482d98abdd625eb01d0747e281af43905daf8a119a138aa3a1f08bc5c4cc280fef148c1a2deed82b085a1f8531780d96818e92f605b61c2bf0f8792933bf7b91cf8ba7bda1425c671c169747fb871711c97c63ce1c13cb508fedd3948f22027e06cb2e01895fd5b4ae685123620367e30927cd74c816144cb3fdded42d973668be778ce296cc5759259985791e86a1f369b68468a849333907a019fd8244bd37f3a3f6d9d23963382a1f1969b0e231978230e70e34df8d63ed69d6d7a5f56e945d2f71985788fcdd2b754107ced9cd4df4010359caa32610127835ce51fb2eeb7f0718ef5a139df5771be6d4e5a4bfd090b6717a2e2878483ef966a186536f4cc82bf8a839fdcdfe3c50cd328e61f37faa088be39ed91962802943b9ca8ea4eb2b0855333f43eb9fdec009e2ead02a816a9401901a526131b1081fc39bc952e7a7d8b36479b9b1941a46d00f52fa54582a5588c2bd07ed79c3a695d135986e6f62ff393262a12837ee5676dbc03e8248ccdc20632aced315b2e99706fb71e819b230d4e58b3a2de22c4650f209cfe5bd269ee9fb5466cd992e67cf69efc24658b83412205525901a90ef7eb7946537dd04fb53c26646df02f33a2a7c5f9b96220055f5eba38ea4b81d6ba5980820de4f116087d0c96a1e8876bd607a6ca73d55a8d3654fdf73a6c7ed1a576e07e716394fabee4a58d4baf7d18ffbe9dec46f29b8f7abc773247dec76526ce3eb72c04333faf68f812d519f01376d50cfd391a2d89f1e95b0467ea77088cfe687cabad4a7a5b4d670d64e1f588a4f5690bf72b8fd2d340e24bd6fae070775adef7f4690171a324a7a19d00e21fadf4ee54ed119be5e32d2d04ac4b7f70dd898ac6fdeb071c389afbde10c7f81d09f0e4264ac5c432f2cad23ac1a693640fe949fca643ccfa0fb7a3f8ab4c68b3d86b00c16c4d89d841dd09ca0ac64fd97c6b9e3659969a55d6d0df9a59b6fa367ff54ff048396bedf453a0335c90f57085bf5c9912972d7c35a134125b927abcf37eb2fd86aa9d3ac82ee4a316f13e135bb4f4aee1d9cf64c9abc4ba9bdaedb07acd4964a5d35ee52b7c0eebc2397796d7c25bea5ed9f2b8103d92242638ea9ca9502c93a1a02eb6fe9877e2b4c11b5cbc0f90b6a41401fd7a380c9f0d92c8209045fa8b275b7e62f39712b4976c6ee0cb0d44caf8407f6b1f8f3fcd6d7fb8de395da5f67103ff37f89c0cec106260e80559c594c9f8c7fbd5657267c07900f7ca7fb46f2aceb7232acebc477ee0b956346d84a94cfba2d004cad75e90a3a964ac7ae636806fb0dccb2f4a558e20cc54e56e26ad8e6130dd6cff47c04cfa3523ada3cccb0a509bbf992961c81027545bec8ca65e10139a16148d69a9ad466512c5a49793d850f3689f056b40cfe98e609f7efd9dc351d687ad08e8c48f4a2e8672aa77d07f61bf3a5d398e6cb9db3f6eb9ea3ebccd62aae03ada05e5d759a37053aeb37d705e271cb9b10aba447ff3b2fbb5656e7647078b4433bfb0058b73cb58fd8f9604438a01ad06de5751ce26169764708bd15328c58f153ca7b1aa78cf3795e52838de785eaf48ef951b1d22c4e0f8f2a40ee992283009bbd365e854ba8d4f20da36bbf6e6a2ed9576c87b23d896618d9805a4fa57c09717d6e9704eb7b77d68a16647714823f479976f1cc1d3d78afbeb0886c1bb7dba345139bdbbc1cd083a23bf024dc5282030c407c7ced2eafbaa391450be5e77b65b23a6df4b15f5594dd8a4bd0da4b38265ce7914763b7e87ebf9434fbe660132b3375c8e0dc8a5d1b58fa1d7d933c41a0d9f87a5219a1d4c11c4c77de99dcf15e9f81623e924a50852865c4f625a70d9346479d5a1618115737b2b8181431a79de87b6d22cb0cbf00628c8526067749999e3205b76c8a6272c9ee47e7fd53dbd53d5f8d4610e40a3dc8d556f2e77e03013ca42c397ae30270f19855801afe2289b08454b1af64f4ffa900374b0073263c747275c700975c42853e8b0c4f6cfbe1cb7b6f52f49c544a1fb7489926be9e22d15345b547996a37b690f9cbcd73c5dd9fbfd1df6d615f6a345fb4138e5b859cbf27a6bc4acd1f106e5c8d1b67340c127c21b3b485f90ba9d56ab94246473e31475890fa756fb723e08599e7bd018987e4902a5dd9b0f38f244dc7811089e7b87fa10f29dbe82d2f56894c1c0c32b4dedb4e30afa23960c3ea5d627c396db60d7e64344d281f71b1b8d57d9ad318c285b30c073373279a8cbe66d1afd33ba677e1962f73536522d7a089b24235d90b36ace55f68236bffce5a84086cef6d65cade2aae855d3ef729d7965e782ca43ace229783f32d16fafcc37f1ec4975076c6efe6e6af414ed2cada5a4821ce2bdcebfc491044678c59a815b6bbebda435ace6f1b91c699ad33ebc189a75681512809f5c1d2a9728064b1c142c5f9b0e3acd832a0e9b35439bc60a9cea004120093680c91594b9833849b4efe78c0728af3bf5556e4a530ab0481bb57e21b3d15d080461c2643cbcd33398f7fc62ee9b6638ee2af267208635c09a99cc2fde37bc772681446728fe990d5448ef44a987f727d5b36857c30f35d3d5bf422bf5786399cbc60d805d1e7230cb14ead64a3a5e56c012291da017e683658f4d9751400e92f3f529ecd16ae974baf9ddc86924c27e70dc9f339a29912de6e379b4ab65a433c34f845809f21f607eafffb56c28cf2c73f1449246134a76a437a7c2c96d0a421b26a1096e6b2ea87c0111c28844290b7c83ade9b4e809a1ae16b7b6f3a00d55f177828bfdee8b39b122d9e8cf98df25461eb80df9fcf7943fa323c614fdfd79726113e37548fb35358ee2c20540b8acb33aedb21c76ef81bc06638a471f043a0dbee0520dd3527378f0eb14957adb6e45b3357573aa12712033fe6e1bd0ca5e9c68f9ee045f3db9ee8315818d945bad91de93620232328e9a1c139f63cc666dfe9bb91c3ca35de6f482599d503091bc4e0762c9cc9803067e5129b09c482b55c9f9545df13eb4f36c8130a5fd10c0589a4d0e4523eeb29c8e6d868ff50869fc477aea865641fe2da97313bf58e607dc291944dfab02a653d6f1ca8ee21ebe5a4e488fd89a1ae344a88165dd95037bf5acfc61d28ffff2350ac5cbb21f3bdcfa9918b4d9323c884d8fcb01d6cedb33075ea81ea74bed925b87695174f31ecfa39e822b06cc8b90a304f129da0676e77cf3021b7455c830934f757b12441cd88fd8d0fa1ff1bfb7988999581b6433a418e87ccb166d6335668e9bdc1b3a24a38ef33dda2bef8fd606eda139b2761f5b659e7ad23ca517686d06c7128163852bcd14b8654e7e1d64d70f8ff344ba950cde8bd17e5358a01b4a8ff40f57cda1e9cacd96d448179eb1676aee3faecef2ee0634600fae22e28b4a17085d5c8ed98847425bf7b6d70bfb24e96161cba3bbe42ea2a81fc1e232f497f332ef9f984ac6e9e48c23a03458dacd914a22fdde41ba2eadb68647e148ab1ff5262420ad6b56f5ca23211fcaf77226ed26ad62f30321696dd03b5031d65d8348ce1353d02f3e462d5d42e8dea0a58dcb9d05528d970099d7d9556affd532d73ebbe8483ef0c44ff360e9a8b597f7ea3dd9b8b35ef5a648ae109932d9d9a07cfaef7e51084a1c3d7357d60cf8c6538bc469621d57cb4ee42b4af8449f1874dceddb844a85113cc9b08da8257122c2e644226a3b5a625cafe1b7804648779c2334fbfe69c258e8da287864bf340cc7684f98dc17bb1345171295d3c0cef2e21d1add1abe3198e0ba159fa1c5f81b858d3406d40102a83ddbadbb5031469bf2d2bba43260fabc31d0fd8950c79ba27a7ed2528e20bc5124d729ea8757f2fc6c7f3173d5937193eb52cab962e28dd32a8665de954b2856b140d787b582581e08c41eb4dfc41bea9bf4c8ed048dc1f862c0894445ec41baccf572704ad680ce4fc8c023d635c2c1a31b6cd86bec4b585a1fb22ec7182fb0fa6a3530035de933d0da021590edb2b57117297aba735a84d201b9b48530e545976c03172920779c13fc05fa44b2c7a5d5189f55537cce50440d5f705334adeac2b7c1fe80f38c4274bbab4a8e6a7f8a9d297428e620e27180c5f5acbf2e493ddc84817442ac25c526e151b8cf7e03060e4dfc3a9b2b190abaf8b68184cd4ec5e1815d1d59ad3f780ad2b1512def62f8bdaa221bd053967abb08e065234b1ba4cfc8fff04095d514b74a9f9b073fac8f5207328279c17e5287e52001c7e88739e7296e25fc7ae57d59f2b3ce88952ca25b5f622a980223aaa65083a0f172383226964187194a7e6b692fac287a45a11d8792e7c47a05053fc9063dd2713aa816e766aab99772b7399de2fd2e1e6402984a0cf2f69e90955d0ee0b6c6cf755b494f55db4be3533e6a3ab007895a125fe1a2e547b0d7aae6a5624337826f04989e482b02203b5dd7f9f781a2bf7c51b45db1915b328a7af84d70da45ae495b28c15f7afbf4ac1556299ea0ed907719fb289256aa676b9551268be23267a5b621f7e41fed955b2bbf5101e69df43bc518628079de5657e2eb9efc57f695dc79497c1586ed014a4b7aa91c82844963575c5c8bdae72f6c48620901429a5a297f38938f0037be7d0f0a28b765cf6e53489a24fec49c725c84d2956a09574a01b2f901c49617f3b34b10263f6dbe6e0ac0ff715c606004b3997b29200192e5ede528c8c2b37aee1b5d4e83bd0aa219d3cf40389e63ae58b9331f6b6af1cc8164e54c9222ab3d91a0839b4f693a724d71381756ee76c5941fba2c13a9a77e8a426e7f5482043766768e45af68f543faa548bb1dc755cc1af83793a66c1c8f28be0c49c16bf73a6801b864369b9a3449ec4e10ee664fa1541d17b3d89898cdb8d8c07ed6119b4a76ec7762cdb497f1e55013f28ca010f174c2d30ff43c14d7810205b1f2d1b3f649e0340e883690ebf79f3fc98625a9692eaec91a4c69ed5b95dc09c21a6c0dd5bbacff7702038305b007be17a34cfdd482f5e0e4609df09e75d28c45322ca750addb6453f3ca5dc8edd1287f71cc2b2bce602e6a8e7488b045faa671b05b89a87f9c2d07e9fa1ad97da750c3164e325c6dcd2d0548962b39287d38205cf4d63ff780fc6d71beb74d814e6954f361ee1d0de9cb26c9a607050234dc8fbd61a12568d650e381da5d6e1f7a5292873ae2b3b02d62ae61ff8ba3f40faa4bf9d54ccb708494347a3a610a4c87448d4eca8350534f44d59997685bee8a50fb280191854f4db63e1f045eec1e283ff01eb0a5e20acf7346057d4996dcd6b2b85172e6c6d00e83208a6db18c0cbbf02ec5ec7700b88d352ad237aade1613deb1afd67969288a0cc050af052b31fd1d7c5270bc96363d908469de976f68ebe1a5664cbcc3cf0cb7fb2d484cec14e463de83ebe555bbb0e29924c16c3328f9cb204022fa4c0a34d04fc2a444011a39ffdbfb71bf182e303c08b96bad12dade6d77ff8f4c4d73aba5b65af4fdc60288112b97f85ab821dc29630e6a2998496d5b93811435b7be21c7e22048de5928b875c4f6aac92730a089b5ed7b57d10fba194380c771dc0d31079e89ded4ff01ff66aa9274790ea182bac51859520651d11a195a69415d6376f711bbdfd5aa39ad83354f7999fdd6a9a94cccf4382b07d23ab7fc773f138ed631150a8bd7fa06e005d5fe41e1aa4f450be640c8efa6e8bf6d09f68f4c731e4a30d0e3d750aec85d7b08e3ce51986fd812f3b9cf3e33f60eb347812d29de5b7325448a9360a2923d62364b509f32ae2a26ef78ef5774338f07259ff6c4cf1b24c118ebbd9cf6ce10c7420125d5dcf8d292c69b02a0e474f78da2c954fc644b906bfe48d65fbc42a60ba62186c2b354067ec24fef4e48c921ca6f77d15e00d907e9d37dbcc361e025e7cfbd632d6bb1bd511a6bbf591dd09d4c9a1d7298552b39a5254e6ed9d19516ac8d6d241e5740287ae892600ab9699509f6cfe06e29a0bf8065d9517790e949bd752f67c6b40a5621bfb3cc0344f5923d4f31bc35f03cdf246ea0c20c4825388670193141561f32bc2e3c33a53b95c37f985a1a71dbc8eebf5135f77d257adebec6f5456e653c5f4d855dcc5083a1b726b4244d49e65148e8d697e8e5642718fa3fdb836fbd7f99cd26fdfffc1d99a04b9087cdd973d0b737cc1bdcddc834825a2d22fdef40c674a994e1e62f59e85c9cc8df952057aa59d5b8464a2f2d6619d5796e4fe7eae9f223490851fa9358b05d7a957a15955237239c0c49cafe9bdfe115148be9c7f3f496fb5c85bb9bdb271e59d4eeb13a0ed532e3a4f0d16bef0861468fc157cbb2bddf27365cf29623f080030af0ee64b076fbffc1cd3a219129b4a9470b377875a619141db2dcc01cc16a6dbb39182587750f58c6c0ed0d3cd4aac4e5d210dd7013bb364b3a6b3c3f5bdd5effe239cdb9c3d9b2a3bbf7e1f3319dbbea7452cb887ddd224c56e821fd534f5f4d1c78719d63316abca05bd3a21a6a1bc794eb8aa98412a4c5b22c3dfe8ffd295604f3d15cbf45cf644f980c8c4c9105257d6bfcfff7be89ef74e8c263145092c3b56ce097112b422ecf6fefca0f3f55672343e579a8f9267a6c214fe06d14d2dc8dddd6dd6e7e110d98ad77b668c5640c46d9854942f96cc38794ea6f57e43a18172de0c14d37d5bd54f9f931076b0703c1069974d6849451b14f59e542a16409e0a0418997993c71e3bb5e498783bbcb10e33b2cedd6058fe72b2596da09d60c830649ecd6c7fc6b23fe29708edc5246aa7bb4483ccb63cf8c96896356930e82ddac8b0f5c5aeea698495a4044acad7febcd60b4fc2310c54f1ae16dfe20218854a51f3681c5e9bbe5e8eaeb5060a47eda56b3dda66c614b533ce615936fdc0d6a48a716d45e908f2e5088d2ca3664d5c95cb0a0663649449744393712cadb0e630f7cbffa49125e0eea80b6cc452007a736362ad603731dc1470189988ec5bcd5064a7662385d197b3fe6c00b688ea7fdf303c895e0cc3f20e366b476910707758147995438461999df0e802c9e95998506025457a0ea3fecd71d9024a3986422e58c4e704d999b44967bb70662526447d5016f2c6214756acdd6f16ec6968f0e396fb3951f5232b117e256b3527632243dd2d55918f96e0b79e034ddb4c24b18eedea38d9228cdbfd7101d8f0003305b341e52427454d5286959734598761dcecb241e1b7628a5fc5ef5a7280c359ad47357e7b55427c4601e3040363f601fb85eddb16373ad13200380f6de2d9f924477035acdccb8819948f7e20336eeff006938bb82fac88ca876ae2de7787809b0d8ae1a522457e16acf141911f31c0cbf8f5b5398a816f3dae87aa4eeb527b8d6dc60f844031d51e9222a16cb3307a98474d7c8e12789e6adbb710134533f6a92f40e680da367d997b458fd770066c95a1f36bfce17be2ad596016f51c03d5351330f7cc0efc68ff86450be23375f6cfd2cf48502bff22bb744879aa738a1c1bf13758d2a816a9451ef0c0922ecbad55ded8670a8394f75cbcec285b600bf923ea14f4ed54ab764d30f0bf334a1c92d2f322106cfbde67169111c660f0f780e31b33b49cb6d17bad966f88aa58ed87104ca7ddae1efe19e5c0917240197f94f53f6094987ee3eb5379a8842a2c1f173757a043d1457e362248cb65364aba1a4a03d0ac8f9a06dbd0494ceeafc97f72e2df7658f57b59f5a96aee2b7459110f95e75b500b68bcc9234c9c9b4c192f5e0f5851b0ca96a95eae0a17e253ce21ba9bdcf0d75c30f9465743c30e67c7ebaea576f8e8ba8afe54d58fa678c1615ccd99b0ef55962ea0afb5159cb842a39f8e850798ce4fbaf85d78840bcda90b87782f31a2279cea26fad1adb146fbcf2e3a0b1e115dbf8e1852e139fa43035136d61a0982e474873e81473b214d0aa711994cc2db4bde177f655f5c7f77bf12e4e25024bc9fe97e000f85f09f788247566857c0fbd21c454f1306684d0b579e9365dc82c9c39b9c15cdef18fb428616b51e088ae6080ce309ff84cc542c7374ef3e149ccc26cde6af6894b680c7240d228c3102086a208bea52c83b2bce1925399731079fc8a0ffaf4fa12b4d6908b92c867d9d79285386b1cc27abb3f35a10564374ae62d638da4fdb5b99e1550731af5d2359967cca2d4c55c1c08086ccbbafd8ea29c9c4afc0de36046b527aab034a94971e393e7025f6a8728168f205a8062a34c29f404c3ad292f2e217e8a75dddb021b67eb163e3c057f2616297a9d7e1ffd5b4474d1bca0f337bc720849f39fcb7134248025c55ce13382c53a03517087f05776bd493ab39db60c652f002cb3b3d27f138ce83be0804c6f0f5be5223a3537b01141d5e3e7ec141a17fcabb6cd50bba076e3bcd69aeb7c3678fe2b260d2a3c031ce52a9ebc76eca605a2d3f3c75d2d432ec6c299e277079e0214a60a6c18ce98f78b740188312b35feeacd13cbfbe1f2dd9a64d4bccb6a2bf4db07b2a3188c9ef6ec51d36ca320aff60f50a96f9fbdad2d7aea24371c277414be5cb1f5c263655af7d685c3914d4d260440bfc99dd13ff0f6140742629d356329d6efde1094546ad9b213ce87fffec78b63ac5dd9745650b03ac5cb7a67772dacae04a2dc5c508acd01019f40204b5171614bc26fa96f6d85c2f0565b6f10c314c243a0c1542a012911051755c70a442445a8eba5c2689cb0de7a35f7b801c9fdbf34c5bce5d0abf53c9b52d3449b640bdc4bd9a657da0181a72bb2a6039fdca4dc1e75fc9663017310c7e4b070c7bff095333004414c4da21a4a50d8e54205a513b5a794cd06a4515f4c40d2eba6c53e6b175c69e122acff22103f752e67c436161a0ea6ec7908a44c4e48c7771d2457124ff7b2f27a9271482b50aae84af5866638eb99ee98f16bec5ad92f367da94a7379ae6bbf255d9671ffa0012050f7bdf189c862ca9d9794238ff7969b858f008fda95e58ceffc5124c46df597055367b2463d14cc747c3f7d45228e30ec61ec8cb2bd1cb46104f0c714b6d4f2a119dae3bb48f882f936cea1aebf1c0c817fc8645c149baee0bf7aef34bca8faf77180fedd7304abcec87796dbe50b777e48f473c4f9eee7b354db9914c6a5b5fccd9d7ef32c50b66dcd70f8041a4cbfa076a68c7771b867ff11a4b1cc7c8016b10739176decaa43f4b6345a4ea32605a09abdf7dfad0a7100790c3baaef7e8f91082f647595267a43a12c29323ff9e91e00c56db6c22ea8b0b12da8a99fc2422fc7958cf8f18fb31f8a36e13c789e7582072943a68a6bdbac192fb1bc5539d698f73c33759109badc0c4d891170b462b79df89d0a799bb38cca6edba137a8f0ff29f1022a7cd5c1634c7247b4c2b6faedf241f4b2ab185f631e7c9845cef880df4d6ff992cf41644facfc45c710b950c75f7891a6da1ff5905e704f9b710d5cfb7b683aa7aa69f56d08651fc48f20c39870ea9df0c14b5e86ca2ce976e4ea574759a26414b9a1e865409cb2239c579b0f32763db1a05e87188ad2827c3940db6920a8d92f5d9db6b11769b4c2d2fe83b258c161854bb4c06f78fb61ad7b09e5877cd2c3f7639f41ef90c7a2c14bf3ce557b3d85b4c9e2f97bf8ebf86103115b651a435de60b235c635f5558f0d2a3c61b75392f8738f777ca3327e2c3f9fe7c459ffd1a7b0aed9593f16a345888605462285a01e1c40541f3cf0e023fba2cf6a0fef32cc5afc38cb94c5e08763626858413c8b188473da79e4f3fd4b2eedcc24f77b5290c0db16fb44ec8ea6b3a1b9320b29d1c95be7a5778549166341d01a7d290f2e4b8f802edb63d2358df9fa98bfdc7993b7f8351f7d6686dffc96a369460867fba53f564e8e485b796288926c0fba228188796bbe35b66c3b54f76766c7e9341d34b012426cc08d03f5a68a2025b22aedc47a21656488eb349bb54f01c9983a9876f372a454a55bbc86ae840f244994442e5d7309be02010aaec0a9dfd6e40f327e30dbead1449a5d2223b0fd32295c9c2b577e234727d8574efa115e3b4c0a7c751106ada4b1048b9e97a7d26eb1ce743632d6894b614cf081b7fea0adf61bbc2885e0f9be3fc447d1020802c737028344c58f42b07c07331294e779576d06859a1f99fa1b7caa10e7cb84de6ac4b20da0d6ff6702cbf4c3bca9554458b4ed8a2609eb9492983fe695c700a105d71265a88d83e0b1562897ef0fb133af778c7f7ac02de3e59bff14653e5dfee13ec3229bdfebbe63b1731517e4926c40e975c9cd7a7f7693721f22c3bda3248c4439fc56f676c97dc8acfb7eb81677aa00b2ebce10cff73f49e6a74712e6c812d68a8e7c71c56ff74a604f167fe359e13f768e74c94f7f235ce20b912e2445baf438142f04582b63da9e973bba08e0c2a79551980aafd128ef55a470f2e7ffa6b2b705fa0c056ecc58418de851bc5b21ef3cbc8e06848b0e45fc4c3d67041d4daee88b18014425046b4fe4d7ebdc88a13dc6fe93c90017f7633676428b8161836f3ea60052c6c4a1219eb96b3a81827a6f18343540468077d533d75f5e854e4a37013f34438fec6b8dd02acbd9b8bb01bbfa84a5453d196f477dd3d3244ac35ff745daa82b2d855a3e82cea5f17b90b8b26bb698f765bc76a8cb704d50b20e61ffd925b973f31ac96e466cee6cac6bca7cb5ce4dbd1e974bb943d69638dabdfb8b599e657ba7cddb2ee54f9b4601a853be58caaa7ff51b66a019f8d021d9d9208fe14070c5b4dec92ed0c93e17d2170e705c49099a2e89939258d3f991947bb92be8380a6bf26b03e33c28cd8c4b59262c35eb38ac0d780c567684f74409d188c5359c5b8f88b1f1935084fa0d952bb9f7322c2e023d55c10708bc99cb095ec94a878e4b86eccf4d07eafafc303e98f83aa6761e1e816c3278ff58cbf7f0cceb2808fffe3b4038464f63172c08b2df1aa110095763403fc59587d6d39534682cbbad4bc44b1f250b9b6641fb146f9113b6a855e9340732ed0ac0374d02c8c22a462720ba564760829856e7da1ae5ba32eff661aa42de8b04a5036e49be4b61a279802e7df9317331232e79d55d5d400f666d4ea25d40e80b872d9a2126f18137983fd7f885e8e44cb520e1da5faa209188facfbd4a1a712d5f23b76828f58f6187b9ced054f45bc0e569cfe26a8258483ca42420149ada47654412421de74c3b5a2d18b8196ea9ddb953acc92a57959df196462764c42299daeb9f47a2e949bffce2dd4046941374eb12c8ccd189cf74a80252a9bdf4218e46df3656200224d52d94a5c53174c250b228d6f2d1c986f66fcad4bb1fb58cc60e0e8424480e380953b27588243173c2bb382cafef83597d19d1284fa5a3d527f5e2bc988a9cc7716cf754aa1de1b0da9aa64c6766cb789bb503e9f734c778bc05a04f77bc3f1ec9777495b548421392a8da5ea9f9401388c2165a1caeea7fce65167729021dc4c97d8a0237b91b4851e60802a2e6918ba3fdbcc25aeaad5f64111a7d7274fb3af3121a439012fdf67b74e7a0981c2a804014c733bc51067291d63e354e46f64fbe4ee12a722584cc2419c6590953fafb3a96918a48d6664843c861fea37472632d36a948e554509ae2985bdcc8be081a26dafe8fa2c42f456a85c46bdd6bf6fdbb7d4f4cc4cd593b3aa6b3d7c343fbac3b04629edddcab1de8202e68029b7d708345240b2dae47cbcc0067905ae4392b43b202e949f73b32b1712d72de1fdb578fecf0bd7e8993cb7baafac4b462019265d42ed377621c34d980527571337b6aa4214c9107f37428b4f5557422ea0494a717e28dc0082c8ae52ad342cb617943795ea733839f4e4fe76df91576458373660e6c55380f2a55ea68b53be031647be7982f230fbdfef6290f37bb6df92f776f0cd38c055985ce28a3ff5acbce0b4930b3040ed21fe06d43428d184c2b3dd8a761e72af6cc163fda59b134e4cf6b7261fe8633b1340ce3f78193018d66b387c1faebcf51b9422a287003104f75c543f213326e52e6e18eea602f4606977dd0aee592f25a322eb6215108f86165ea50fe16367fa76118753fad3248d53969c0ddc804a693c05dbc69ac04b13c6ae562d3aaceb097695c87831d88e110b7140baf92f0e192d269cb18ad294ab35fb960f036e33fd5723ce574e03ea836429eb18135f11ba1864fb888532aa80f2a0a6b7c255ec76121dc634ebf66573c85ff804feb4103b2a71f0ddb0cbf63d05f92260727252f23962e375e27f0c58a55be92925fd1e47dcbbd7b0555c000ded065293f087f9863b75e3dcb73246d30f286ad85aad1a412f0a24669b340ba3f9d51277d71c7dfbc2d2dc0b99be4c02dd84761dedf3f2b9856bdadb10b093ff41cc98c4d7a7e20c26c5fd381d540b89071f1942857f55aab419af4cc38838c98aa51fffb8c15913a408b3686e244b11bd2502bb4bce31172890095ffa9c9c29e1e2741f8ac86c2c1b2f80d6db25add11f85a7e2fe9660ca3b96a1e7cde43cce4a53a6f95979a98cfdadbfa113fd28f9d35d9ccbe434e67ce5594f1b5db3436e716e129d1cf50178974f2be530280e2f47dd8549957e18aa122485a4196b1921cfd4b9dfaf80a1a47dfe8cb292a4c52aa7bcde23cd2f3f4e93f48a4ce28858a61827f607777da35fa7e591dd6fd0a50d202c9165345e8d563b4564c35385021b1177fcd7d03280315d3b5cd95d189c51fd602899b89288194876dbcd5e03772aa5f5f44f7cd26757eeca88145e5a13a4fa1f4d9ad61038c91d2fd6a4b1063100b2d0b62eff0137b4cbee02858be92ad91b05b85ba5fe1d939eda299c";
     const BUFF_64KB_15: vector<u8> =x"16c0e63e52b19f5951d7ae970d231f6687b35f07569cc14da4c708753da03907732f3d072cc3e4c6210d57c3c3aed55cdb32232790a336a83fc80dc316aa28f28ba79ac75978dd6d0754b3319925c0be6e359c2c92cbdb8905dec93a1db046f36003f1b567441488278d90a27dccfed751f6b9f2f3db8be12f27ba5b6526d3501f8da5aee55340a6dfd2e001a31700a5c880ed782707d295b9f7e92c1c95ffc00c6ebbf4a6a67604aaa55fb25dc120f1f6cf60d622edd407d716da4d3dbeca21bef249620d28c3e875431114c944eda197c54680fb214f149733b5fa2bce097bc9a90bf2a1553920574ec9ea25256906caeafe05746e17ee57dc8905dbf9e1c043106f33ab612281c9eec272ad83c3e18546ff2a2af3d15a6c344cd239e2c30eddac22fe0e73bcc42c4f0f9e017f40fc717b945d9d49f70101ba3cb29d37a5959586c500512766af94e43afd2cb067822a00f5a757aede0d117670b63fae558c00c7a969ea9efb205fa1fd787696236f9d0c0c523922dd117b215c76a659fc565173ff053eb62324ac41b6770647eac12e0afb4577b79e5d5edcf4d5591d6c02277d36e6ca980a57ea0611412277b5be048215324b1985afb3cfd0da298714bdba8c04c164c9bbc2d1ca7b3ffeaac81a4bdfc713d0b01cd704249f6a1651d8660717e7a5f9004eb3ec279218a5220815791d5026fee0764413c61991105e1f720032aee85f90a36a7d9d638ab88d9874869daee0902d7834419f32f9a5eb15d33d2c9101f623917f82372004d9c6a89f12c27ec3778f00e043216eb241b4a047b07977bca57e0ca700de2012b206844de0c04eccd91781a8910c3a003e46fa8022987c775843cabdc1682213c3542d2a3fa705cdcb9dd260250caee0699c56fc348a2fba1a3d28055317d501cc7009c25472ab9d1c60b5ad5fbbb79237f4b78a45dd9ca9a3658d987cd39df50c8d9d807aca0237682b16537fbcc54c6de831d008e5506b41d9ad16b07ab42dc0076024a2881c5432b1010ef50d99df58fcdbc9e6cca903fb8545925b26fe304d037da81e0c13647e98b65cac93a0fe1a8dc8f0ace732979ed848fabfd74d53ea7664059988b40f0260e725da254c9d6f7297ee9bca10d87d5d1ea9775b50d3fb02bd20a3158e309b35b24a5d1440d922871a04fcedfe743bb7e272f95700c34ed837e6f5a926b70a61a9e65128194b8dd5e1b48d2b38cfadf3222a33f7487019d74ead3a9819bf53fc76709309e5a50855452a4a2c57ca49ea64d50df9a22121ede045cba1a44f2ea35f8ede7996da637161017e21f1c1a897fb4f132575c3cb83291b8d39e939c2962f977940cc895a014830c77ecd672f9c4e261db2be32c8cf16cf8ffa4e94ab4e2dd420774db94047f9ae6f5afd87cf12e8d92debd929787e98c42baf6725aaa3b6a704618082f2d53aa4a2674448adc0f83313731a407bf3164b3b9235ce36284a3408c3cfd5a38db76aaa12af336a2c9be292849c8fb6bfe0fa170065a965ea211cac225668a1b54b436ac7434b6be217bc12f4ccb2d516383dffa6a660556f7aa7e4cef9b433c83965847444e9445c254c60f9a91cb5467a4b282d0e43fe605467438f8dccbab85539ced9353d1e91b2d14aeb790cf3182236414e2479019f96e2a0a9ecdcb978ba799904bb622d4612b546fecf97c1a5310eccfc1ed19854875c5ff7989412d4b32c94511c6becfd66a5d228a5d849c0de32002741c568a3128aacc01313c78d8e3a5a24004eda46a173977c870e159c82aa5f2ff03c3b4d3f18027b95e1d20728878057898b8d12546d622bd600ffa784a9a7caf6cf9f457583cff87a8657d7d9615f4d405059d87be383d456e16416bea051e4cc964b4e588ed362870c2002aef60b8aaaa443ddb728aaf505441599914d0e618b88b98c2734fa970e7f2bb993b8729d7ba08ebb296dee06acedc6ea9def519ce41aa4d569bdc6b8d857ae28c9ee77167e32294a775a0e1203713b94e5658199ee5f14ade86899bd99b94f7faa7431985fc9ca981b423b9b8170f2d403fb009d1529b9ea82c1bc7a15f6371a8e2e09b0f58e46f217e3e20014f4b55ca63a62e52550a5e5c72e09c31147bc7f0d6a2adafa84c9365f71dcfbb961b252a99077f36bc4ab66211fd2adaa79aba7e0d513b56342c3dce4cb0f15e6b14c9532c6cc9fd2a22ec48904f7a88e50f8a1c214b3c314ea6ad23d547c053e97a986dc6021a01789c09ac0a6778fc4d353df404a7d8b5586aba79ec0476e7384f11852c1e53c7d1f4c4385ec3f616574ac252b8b88a0e913d09c813f0a0a03637fccd188fb94e2a21fd150b75089fae3060ae13f49ae56bf3ea78c4ed0fab6a72aba1dd8df284e242b1a2e9ed59ca4ce4f2306d9d84debcbdff453b9e3edf0e0e09763a850387565749024da5f92f095f6f178a09ebfef0801344e8914665f3308cea62b1c782ad00301f3b53037af86fd64a9f5e4a932992304645917f0d34d991e35813b5a88553c1649a54837341d0b7a57499c03f27108e3fb6c9173452440664c6148ef2aee5fad67ea5071865e27b6b2cf2c578a90394d97fe2f16b1dfe7d981718dbd5deeae15421f3ed20578268a800f1d713d1ec029310ee762b5095874e6e32c3a78f7755d67e72e207657d1dca12d2c851a597d6b1b842271a08641e04ab7555351d2978edf22ce1b3cced686b38c979ec8beb985c7f53e4ae0f6a3d04405969148fef6ef1fb20c35bb1bc2678ffd35d9828102b17a0463a62eb71840c3c8de2f3c1ee05173b123e3e8528108b1e1f89f9956f2a145f09520f56b18e1ef45fc7fdca01a1f3ecb4c8d3ed27611b5f62d6a10eecf403b55c859a5563605d42726c0afa9e42fab93e85eea389e010ac5636fb88b086f886f5aaead0d8b5612b5119071da3f7a635a3fa7e8a6a20e059018a1dd4938ea3d6e7fce75bc67afcb5ab947000de6445c13b50366d4392e0ee3049b90fa6858cebb7686632109d05aa17ca6cb71a9ad4930d722b64bb05800c9532a4a23b4ca05309831d3196210d86be0c12f7a7c146e9e3378405ce03c92ec33431a45337b1724b2c4defdab0c643848c05f8f3f7e7a27aaeac3d7ef59fb91ed6688ae586e33d78ff51fb1d0bcd876bdac61b220d4aaff3064be9c056696b146efdbb0840c9bedfc77022f845531cb103efd9b7666d138a663d476c6b11ea619db318acc9b90dac08b1667366520528be68448bc2654de42ac48968a4a2eb4a4dc8d6334f80787860200b41b8c83f3a5e0498be18ef49a56526d16abc8833e5e1238df75fb148e4657ed1f1e3842a05efff26181b30df696892d6d1e770ecd9f4506439c15e02b63ee9670689364cfbe08d4af2909b08503294b774d3f736ba62495ed1bebc2c5fd870514ef85f1e8dc9bfa9078788fc8f4d43f874fe019a93dc43999d1e37d257756825b36de40c8ee59704814e9a4dafa5b6e7069106e203586a46d2154fd32e272d0e95c711e60a7e4bfc80f37be2fe1c999ef6413d6a825ecdb95e233050b5de89c62be461622f211347bb44c7ece84828f6c961ebbb2997642170a8ff98cf5848ad9ed4cb8b5003c6090142eedb64ceeb1fccc73d40960295ece5fbaaad64bf122269f5a1e974f70048db1f088a16fd7f1d6341a55d95f9fd4929d469418091925fca10816d7977115b4c448ebf407e477533f03acd8228bf9f39d9606b6ddd1595a40fc01c43caed806a748da06596e1992a7884f061204b8ff9e1c94323003c470329dcbff14fe5db7b17bb2d8b2b8d52568001aef3983fd9d5c2488d0b9e14c947e6db4dcbb05ce64778f7a3632dea2696fabb5e2d08b8fd2768028158163b7a03afc8fe44e5d6be758937523d37ebc59bb10e661598afac4534ac3bbb8fc29c64f022c8a1ae8ef8b435edaabb37da4567923ebef5b15694a6546d4eb46f027a9b64b0a61ae3db390e06c3ea837dbd54a5ce5afba6347ed0ea90d3dc540119000754ef45f9d195e1483b055c58ad2c930afccbb58ee7629250e158f91ac58aa16523df0d3702e8ded36f04065dc443a7d0bdecea53fa6366f4c6a01163071a32809601f71860dd0e975e8546d07b75917b283206e13a19d5909702d92d67a1013601a8e9821a2d0eec5bb30b3862ccc7f425f7c4594a4bca19f864debecd9da7759c4e6c4db414a8dc763f44d5d4602c465d26c3519f4fff8e71f34a7f9a8cfb4919134c671dd631e99bc00c54e62f8744da6ef85a04ede60f77d95dc049f893128badb27472a2a389a74afb89272e4db3934ce3e3e359f58b11d3f736466c2db0f686a5f4c5529aee4de974711ac535ae78c4710fa0ef7832b8f0f0e460c705d00a28e043ade61ea83034a8a832886f687bcd2d27631a94f81167365c123fbfddb981b9043de4bbe63d8dd964fcad2516f9b8566e5cc8e9d355e02477b3c0776e5dab30bd6e185c4bc2b92d574041076faf176bdba5c7abc9c2630e66ba34b1007a7c9f1ba847a601a43017c87f892a1ad2f6d516549313482b7a8e3b5444bbfe8d274489b1eb753e3d8dad0827638667b0c79088027f8a5333a4656f1ef3cc710c9b614d909c1aedcffb8aacd63e0ab1a4c9cce6f6520cc5cb4ab64464572e805a90f6cdba9100293f2dabfc870ea2e6d35b3ff0d4219836cfeab75c10273312d7450bfe2886c6d8a0a7e982347c8cb4a517b2adfaeb899836c28d1fc1df663207f3c9559203faa6cb540ae3d4f4062722aef276038e515344eeebdba9c1d37e22ed5a50d499c34f95973fc19997311f2637664598f19aef78c156756fef782beaf71aeb58d2221686b146d609e3490e87e88369c451fc224d609e2da6ff0e27ee61864b08df3918703e81b0f7bee14019f4f1f4e31e2f718757384eb5861572683ff0be1b5d9ed53c37075889098225f966286751a5c5a106613f7ca428f8193c3ec3f15f8fa9b687c84dcb4ae82be373c748cad75f0c5666eb44011747547a155a1216aa6f98cbc6d1c1a2ccfd911a6747e5d64f0c4778d7cdc3b4f5bd834c485aefeefa2a1410409a38be513a4ad7226144f4c6ebb19f8de70c6cce2876cb20acac9817b7a7ad2155ad4b94fcbabbfd6bad1b9c3c3f3d664096694bff2bf0ec2660cb5243bb1d445440ddb3930f6a13235237edad3f341426236c25d8e5df87b5f5f26d260bfe85443220efe2c78717969b243ed3cc776c23e67d579462eda0a50bf585ef473bb659e3a043dc0cb60517ec65398e262b692eb38ea055604ed43a34ef7e1434ece54d825790dcc5177922153d9683eaf53142244767c5155f6f9f9f643dfbce407b83a57ce740e279aacf274bd11b99774e1592d4f501901589fc8369f08c4e1fe42705a560db8c7fdc76c5a1930e379499b2f21a8c410c6018f3ad084b41face9cf7002c6c06ee36c01b7f08d3d13d65a090d40d1cdc267439a7b455eb5a98edc72f92509c88a351dad0e14d202302e7600d323e93a2b29b278b0fa9f538d3e43d27cc43aee103520760004ace277f0e2c665190a21601556030fef52d4b5ef1850da56db1c5a491f38b61917f327ce41008cd23a173cd43df04293472581924cb91d170a52b1d0e26b8d6dfdd97c8801b635792a1c8708b15f60933de72bc4def347cea41a1df02dea7ffd65fe30b571ee0ca8878dace1900dfb16f5fddd9a748e04ee8c3bf1f7b8831a33f790ba85b48452534a39776f7facb7890bfe5bc6153c9fa10fd6d87edd103286f4ddecdcaead0238fed64f53450d8be02b7a0f83a35ab61db08c30195f11d251c4d929abc4aebcd9a2998a2da3d8ce7d192e94647cf8e89582658e8ae09921cea7f44a2610e406c392079d3f26c34e4d150e21dfd0acb2dff8a2178db17e05e999597410427cea3653435efbc3ff905f2fc4e7b6c696d1fc9318d70907722166b381dd98af9fbf9a16b04e6668eaf9c3b23624a5a55cf9f9eff926fc9a7aaf07f46d0545f961106ce668a7f4eef279f015f9e36cbe87c9c328099e99535b6a8a2a9b6da0b5c36c1b1ba16f64d018809a5304334160a6d92f707ad4a3518cc9d96c43693c0aa50a275cc89568424af44e86709deaa4a3bba43e7ae27d8180b63fb5c8a7c69405ac67ad382996b0ed0bdc6a7fd1461ddd614f1d3581ac51250bf97b5c375b8e0d7a1f9e1af20e013204423041579943c5144f8850001aebb6c068e72d8bfbc972c37ff3842c25c5f8ae1df16f63c9096dcf55d7a5cee4807c86ea96d0ec31860779da30ba2a7bec941d09f44798bf21d64b65a0276f23aeeeaab1aafb0af77f4cc1ee5abb38e151f754e33ddb26de10a82a188ac0367070004c0e6b6eaa24d46e41a1c982311016639bff684813a50f71e6afa777f55f0a92d524b6c0b6d6332b21984617e8a0454bb389a6cf7b6658a4ebe89d9d2cc82a412709174bbe20342ef5852ad474bae15e13883bd96a799f660d1d0c86d6e9d5b1d7200ae982dbd67eaaae2c7ff905b55fae105d229d86f004568e866a1f5e20bf328278db3c01c8be2d6e2734fa03489890d1735ff1aa7a83c436da689f8a56285e5e6af680908d7408b6f2627ee30bb92db73f45a77e498967625c740547ea2299d129911733fce04d55b9126b67d320a8545912623cd8b8b66d2105a8eb1cb317cea921e1a2422aadd8766c58fb8f36be44dd0513c132d9936728f737bfbbdbe83822db5e62a25569224dca2581243e45384b2f55c5df9dcf906197297c563acdcf322854d9114898ff3c1336277f840299c641db69b20495e930670b3dbc9a0c3df3bff03a9a3dc06a27b27e6566a68ac3686d08d66f939914364455feb826d4ce5d7befa49ca52761966f19063a80901f52010d1034328e184ab28f718cbaca96f854d6264aa3d6e0e18193faeb47bb31ca08f2688b7d3c1fbb82e3fc424067c3db2cb75eb3a43118337e07eab5fdffcc73eff0697a5bd338328923599c5cdce722e90bc10573517999ca52f82955a6f6f0da28360fe903b4fbf1fae08bc595f73031ce25c557dc7b1376aac845cdd90270e7fc0718fdda473845d8139eff4f7b4bf564ba8965c8c38f651e156ad797bfb2be753773c9497640446601df971d84b15d56920087addcc37893f2e39cf1c630c0cf3c95426859e8a7d473cc4de97cc6f5d41867ad0dd46896580ba23441e57ad7583e9afab56889ea8b78db91bccafbb8159108a77bd103e7ab28dc6d51c3b1f46e372f14aab8ca8d456db59909d5982b02555cfaadcf9772c60d593408d69c59c56e75e4348a22a5c770f480f6008925332dc5ad3fefd1717fee32c1e1f2d671b6cc25e94e3d2a7d834b44c68df0359b2280b6c317bee6a4cb18574bb3e172692af2649a0065661da67797ce4b8e00336eb9db63f33ddf652787b9dbff77d5704bb7d2369baf22aa7730216a31eb73c1e9ecfc200a5ec2d2d0ad046108bbe201c1e8ac2936ec252f6870a9215a833526780079e9ffe090ef7984b37e023392ad3e1d6c4632942fe88149e7af56900815062ff9edbcb33b9cd1347ed948026fc66961d023bc1c57024d10625fbe5ce8c604f723c7978d18f537c86acd2f8de4394f061b2eabb056301a1617eb82e673e3c3deb4be2cfcbc2013a3ee542a8808e9f6eb1d034c888d7845ffdeccaecc3ca63d72297f8d52d2cd23f6325a8da9d493f5187474b93d23872d834af17364850ef5246a9e233e861bfb8d34f5b614d6e690dcfbedb69c4b821340c41994df56eae72f10bbd63b2cfe71aee614cb56b26bc3ab2a642ac89cdc1297d52ad78905fc3270d3f00c01ec032abdd93c8b562518495df99a4373ab3975204cf0104c11fcf3b53e3d0867db53b97fbaf9e00ea9e062942d252567a9003efce2f9e6edf2d43fc3a72a6bb88ad7982b5ba85627ebc0e08a2993f685cd6fe5b8920038a51dda4bf6c64c4a634d805377a69e5dd6e279afdad4f7571bce42545e996c184ecc606f36e0435a924198bcdd709af9d4c2215c922b77441242ecb5a983bd5fb1d0a10e7c586b6c61d4ac2ea388124d19ae36ccdd2643296fa820ff6363bae1278ac15ef315e8b71718db6c78645038e6a2203d916c601258967f36eb32d8332ca52ae173c3e8ef1592dc5a243b9a9f7f5bf55ddc20d7e21aceb40cb0e3ade9a9bba18b75a53dc90421cabba857c07a3e0b2676dadf560fb9e20da190facc999ccfbaa52d74662e0691cedca391e6f650f07e7e3686b87d82d66b732d910277adf8b9b5a06434a0c7263a6a07335cc738ee2c0c58ace75eaef1ad940bacfdc349c9b70b1f51757a27ca7d06e710b0983a368bff128b52637fbcc62a40202bd0b6af894f6caad8b5ee591c48d51513995f0812623fdfb7af6cf4642ca1dad8cf5beaa2228649a86f4fb9317635e77e9f045efbf9f3789c74ab753224c938dccf7689c81820be86bee766f04b9cf6b125d4d930d5b0322b964232c553c420d8f9ba803c4c971bb0f634c8f04adc3e8530e70b460fdc77255c252ccb54783c19b1672ddb254b5d993040b1f065c659817ebc7a822fdd0d04bc4897d7769b4c086da86bb51e5081f4ffaeb1f0c3455083fbf60100bf012270eff599cee5004adba4083eb0b37d102cb2cb0b207542373a0b85648d3f65ba0793f65a7074a20e6f1ce0a3276eacf4a08927c3a1130b97b6061e6597d2587e89103ada6fb8c3d56e40f9d019fbc2e814d3ce2d1e56c757acd96f4fdeb00765758331075db28144d00dc78ded213491de767d17b22f404c4a859811c4c75ab1418532f36b704f943783e47708609a5368d0d2819230519502517b8f919934be4c722dc04ca88eddf52e8c0e51616801660b87ad3e3eb681e59ed36e13a49abaacd7ea2d65d1b80d32530bbadbe355bdeb37d1f409e74829352750a64e79e47a3884fd097c2e58fdfab08b391d23c24e3e9cfdf30eb6d65cb2111bccb50edcc73e0516f41cc63f57aeb28e7617f8b86589b7491259bb91f49c2a60a48e887daf44ec335e0fa31a54a56671b79929267c4aef4ef930f85344d9ee1adf688980864537d1e8a015603c9cf991ceb8c9f2086a0c7eefa0a0eaf8da135bd5cf896e0092f5cc5a8bdde8c2548557ca8303482c2f4e0c64117c44614ebffd0462399bae699a9cdfb88093c381da5273f63cb338643c7d91741c914814e71797e7d385cbccf4089ea81849c45f21fde2db67d57237873ac1ce5ff4f2f50cd483e90b52fdcca7114c6db41baac365eb9717b701f47e8e876ff436596384a0b1a5f916a163706a4457738b53b0517d590a51e730f21d8c9bd910cbcabf0247f1609802050e44af2f592b3b1b710afda8ece60d4ae7aafd462f2ea34e8314ae53e8cae48c6fed9d3ac5e7387c76d0962daa5e8e29b4c28bc4bd63a7c6f3d3004d8973191806b610a29f9e2c335c3000fae443bb0079196c625f38dcbac93bfaeb252ea30098979899c67b5c141321923cfb9d038925e21c7800677c96abacc811c49b69bd8f2eb4c82fea87b09fa130222a74df49f14ea502852f51cb4d6252e44a1fb629a2dd66d86565d1bc044b290ad3b8ab1585394686a3a6e9b6e5393c1f7fd6484a7b9e2f73cd414ad3632adb0df572949410dc211c5dd9ac397cbb7543b5710d6e64d4495bd4001b3a6cc377b8392c8de2281ea9ac0ab3fe9d56eba10e4237a965e2ba437f99295aa6bb18c68ed9e5b0351a3b046c9801fa3266fa686cbd7cafc33820b22f815b8e58c0a8e4a4d9c012ba34652bf223160a6371d68b390ec1763c85555581ebb6d28887bbfcffbf2943da0d1212098c8d5bf6cba2b8df74e9d444bc45b45db76ae2df564708ac0f936e6f52b70e096c39b91b61a043c62d8eaa8bc9765f44757b78db2f4c29133b6a096721d04707dbacc497561202a5ef10988af0486688d2ef5bfd221448a84adeece524633051939b7d96e07b31c000037ff0ead05458e3117e1b30be94fdaaac1a7868c947b2c03ac0db19fe9d239b39ef5a2319ac733ca2d6d4f0eb51bd58894a0c98a666277539d9ece8ebe7e616568d83fd0f8fdfbf4c1a7326feb17e8bd6b63f14826bfcd4b6272302bc1c6b8af522197e534a0eb23b49a92108124dcbce0773f7f26ac4b8a8582b064c45ce336c15e77caaef0aea4519bc38aa9a9ea590511908cd3b9b15bcd6fcceb7ddaa97ff2fdb36575afde5c07281584ae6c6669f85b580586083ea4c2b408a679276c8355379031452a3b459c8bda1cf2e2a84c6351847c10b82db0a55d1ab365b82c56701d448faf900eba14d19e4a3a37029605aab7a5c1df79ae119e53c6629be31f804840e971ee8ebf0741197ca26613bd5fcb16166e37f7baf4560f404d726b9da69ad1f041ad15d6fa759235d9fbdadea67aaf0c5a633ed78562bc77df2bd482cb7a4ea8e73b78f3c7fbc1bf9e02421190794f28ffefa1b5a1f007162328679087b61b03f974a307cb3c0164bbbaf5904043e2905984fc3c62e0953f25517cd6e0df00984cf946253d62851d6780ca7f6baff889919a1e22c3c15cd2caab13a303213160c5b2bcb153bd0f737a8ada23dd5d37f90045f6ac530697e95cb01e03e14a8ec3ed58b808913f3769c598696082ddc2d5c43e170fe548f95acb5ba535d80b6c8f68b4c4b50230b78c9cf821fdc206bdf8ebf38b379084177f94b177dca82fdba7e952a1dae2f1b28dfbba472242e3ed81a747c6c8605ac2a6ff0d91afe9565ff652a8023636c3f7e400d5484bc9d5db5c9623d99e647bf09ae14eb9e4073c53e0d58cefc8ee4b3ef71fbad839836c8c85f117209513e8084280ce00502463dbbcae35089dff9c894a36133b9cbe40038c40dae9fab2c2079f49bffdff5b6a9eddcd4c48c53cddcbbadb5c252972957eaf615a30056aceeb9248f64f847b6dab84fcae37dc11558dc748f3fb786b7b3c3d712bd58b02cc2a824440c149baf14c238c68f58470830d2caedc938f97375ded0dfd56553b69402c49a0ba5fab9ab076ea4468bfcf5ef2c174d3deb06f704960fa2cbf116e0a2c4ed91783c066ee8653031c951f2d0060c0b3ba70bebccf9f2142485732e92198cd5e852539fc9dbdb753348c47700f2aaa28cd4d6c487fadbee2635a2b535c05851d726807ccf49cbfa85698f5ca729a36c8f7bdfaa4523eb156a47b37909de3cce85b8a0e7d0825e15e7e889d58703052db4d053d5d69521e1b346c099a2091c093bb89c10cd387cafe37d7152926264e4ae0c12cd10c5174f9960ce54190bc1216752de5c9d3b81285770296a205adccf7efe9db7358a54643cb4c7375646912b8e599d000286672fe2105793047e92186a9560815011ca30eadf657a44bad223085f6860376a669db64bd1c3870dadb55ab9a0ea673c098ce0813facc40c0a79b700008b2667efb34865420b700fd481969b1bc60db0466958b094257fffd67a2622cdbb6279d446be252beec191963daff553bb8bc1cfda5dfceba5d35d3267a945f5d794ca20496a188365f6d79b312523043349c30f9c43bd463b14030e040cbbf2fe37f1134da77367360f3ae9b46d858395a14c72f8b3abe23b74fad5e18e5404e7ae993c34526d9d821e3f56d9f0d3742660b72b5b193a0e4d6f290a11102b6f45569ea5e167648356b03e8b907d1104fc1929c12fcfc88b6949ccca4107acad56a8e2f245930ce8ef5283e6e14fa8c48edeff1aa04f4b6103e91aaeab9716c91dd2ce616172f3ba0145203e638b2fac86c69addc708f5469cc4a197eb7110cf3a03c4d4b5ead5e3a1babedeae4b5b09a69571411c4f337818309bb2e6ab416d2120e3d5efd7210433ca0caf69a1cfd206b0cfc06bd11d136bff00b47a93fe5743412ea1d8171a48c6e0d60375d5e0e040c3facdf9a9e55ad31b8fa1ee4f955eb3493283b191bdc184eea994ea4b37ac51325765de09e65f157ad62f7e42b1126ab800d1f221388276f4d3f538912c2f7a489a9a2c0d6df9604aa15d3932c5e15079201b8ecd58d0ba5f137a17ecb155472922cfb7c0cc2d982484e4ede52ef791520dfe81ad6c42ab409fac59793e17c139ae2af43926dbbb05c8f75b58e190772cc9118dff0cf305fc277c4668391be5bbc4128480e2b50224ee97d093929fd07c00bc33c8f2495cfbf7ff0fc66eeeffe0cb8cb85dc06c942a6944cd15282829a5533fb1de71987711cdf244d582698a53ade46ebedf5a858a4522c085f076edeac2befdab3aaf3275a194ede26a81c648be314d8923f4834dfde2d770b6a8f3fb9f535f893f1d91434dacb84245a69a23168854e7a62e45358dfcb6b25d04f4ca10edb9ade052b2cc30cb5ef8aca09b94e716dc3acaf73cad8811107a24859e9936809496587f9573281282ca0af0bee2df2504c1ccc6a1007edc259994598bfc8d0be263357b4e682f79bfc826a76860a5d2d3074092df4f72e462c339746c33c483da80240fef76f8dfcde2fe7376057ccadd7638d1d5b171042fb18ef24591ed692696706c690eb15e74fa7ef0bd22652fc402e9809389d7321ab7e4a572cc546a00f2998fded77479b4fa4b3089dac1eb5aa18a0bd1ce49a0a102e259c35d07da74e906917dd7551378187e203a4c469512fbee7b59751c0627663ef5f4ddea7d410804c7822bac07870b3012474e038357509ae1d82f613c71be3950d03cd8c6008c5ef885751cd77fb085935a8fb764c1336e5d3428a618742c9b49b84713afadf05c3b600ddb9a83f0454aaf0d457822c3d27362ddc0956616199daf48ba753d26f6b0f06ace91ff97f0ef901b6e0d55e010d5a72de7276f5b17ee4bf1326feed3209d07eed87dcc729ba175ef65fe35b87f3ad517bd45017edeed1b464680f64ebdd0162c42b7817bd9cbda19231f90238d81bafe93c53ab9d3aac707961f99bc16ea13280458e34fcc48af63ad8e3610ccdcd3f66b6f6b63379943388123366ff893946a004b7ae9d88870a73bffacf32cd4df240367ec2822e27d710c9bd446063d93ab600271ffd599e5f6a72f74c37c5eb65a8389ca80e088c57efb221239ea44e13212fd61113940f997fcb1dd981e88631fb7ccc8fe572990ad3d73153a7aca898206e78d3363ce08cb17345a764ac1ba65b09e4ae297e0a3bd1c410275d4589723f9b7c5bb67ea525babf9f010b754ba1f3ee38dd86edd3ee52926b9828a9bc086cb8ffc3b66df1a3c48cb9d0826a3e65dd73121f70f59a7f5c6f76985b9c5c826b04c61d9c4bf31e92483c49173dc2c55448f293064e9202d227404dcee65cf467fed2c840fd52929fecd69f3bdb63c07708a8dee7a4714e4a60014e2afd7fd17460c46d1e22d66ec4462992b5674de1a74d7d219034afbab523d1379058dbcd0f2ed824f23c9846342ab0b28c7c2c885916adcc0a67ddd850eeb5e9665fcfb528fdcbbad91f22b3cd965bc3bcb432154f59e5ce6ea5951223289c430395786cc5586f788818699c984b57ac3a48f8b6b5491ebb31936be197289f34e03748be4733bd724c9864c38309ef21a7fe36a4f3cb9ffc9c6c052e0b7c35e38e32dc9827b7b5db4c1320f2faa095646d713648ae8cdc5e4000d936ba2df7a992d4f4c74d8b447036d66d0bc5c822e90ec639eff87136c98d0cc044360e96d4e36ddbe2d9468efa736dd18aa155fbc109335948b898d11b55f716ea33fb8367cf4bb872bf98b181549b8c8da81bc72b6d4cdb6829bdb3845417896f3a9891f465d634c223656fe02115f7ebfc9d999d9f27ee631421a44be1611ba85e5860d53ae451785eb7298334032a537d4fc0183da64bba782c4b7c1ba61112a6a6c5f8cdee8d47f24834d5c0b80b813debc247a9f5cb270b20d81a77244e24ab1641b77574c943344caecd6fc0662e75f04af5cccbd74f2b39cafbd8689b82c649ec5c92abcfa193a19bd994d8109d02648fbd9d41d97c4b9174035b831f4f6e5d02c4b5c30f8353953ae9e41255899be72c19b81ba607f2b28afe59f3f34d6953404567f52ca67fa8778470922bfad0d4679b46ece5af8e1b43808ada0ef9fe1e347987f6d9f0617cc076974cbd00148e75a59d74ece5ad5b0132850080ddbe776945bb73fd43395aed49d2d5d478bcbc0d8e5ae7e0013db7c69644951e8fb69dcf61ab65cbd2df1dc1e9949168183d0474aef07aef2a518065ac6bb8f30131aa86e121892652d276a082498a510c65c1a12a0e241245d50b71ebb3cbb1b5bdf2b255d3b99507da7bb7961c2db55a6166dd836c1bd824d3e378cf0bc6284b00e0fecebc13d372011b88c2453bfc2c4f4a094ef4bacae855a0cbb3351f5c6c0789d4d7fee8186c40542dda22ac42ced0e2fa3a12ab85a895caf58f42199faf1623997c6e1f195400764c163b0f60ae15a1fb25cfc6106930d4e2c1a4ec5536006f89789901650c6409377170ca2d6a13793edda0fa1ed8b32086a2c57f8f6695b323095bfc4f29795163f199da682a20ca3c70cf4dea916a1d647074abcc06a5cce9d706baad7eb32b6ed571d34f9cf7ef80fe134e415a05aaa6e2d4ca2312ffac5317a324ec623dbda406ae420d5a9d008644349162f11dcf5e23c45ec910132235eea6165c3a3d5ebf30e6cff4044a4a65bd34d7035400c70f1fa4b5d3223c2eec7d829705f2e8bcdc7ba510a77432a3eff6f4be7775996df7bfb44a9d0afdd7768e5ee3ad41bfbf3794b4c41059dee523116e53426cb7bce1c31f34959545eb2f5ed55eb854de27236ba0367c73ac67b2485b46cb23d280f4e302a0c05147dec12adef65a84f2becb3c901d632f41a2b80ec065535562e52376831bd2cc6349dce2af8fd27f01dde22447367bc201f9d1d83d864b5ba4a392e8348b86e28a6707b4ce6aa3a59f68f553f045baf9940adae117d5e9874ed1e3c373baed8ae72701694b1677e048b3f6a8c58b4acff6d8251a4c906376aa8db2f71beb12643d4286550f3ced2f2cceba202274249c935721e9fa7ac93cc8d27b82954bf0eda2c1a5e903f01fbe9a3ddd5a413746e093828cee25d01c0b7848a4ff83a68e27dc751f2a210c0943faf361cce2d471d5f32e5d6ecbad54e4d9a9d752b0e9244a86cb2742eff5b1c27cd2f9ee497b626b47a7b227acbb5fb076c1147428cd4c54ad57858585e1d7149b90a5b7741a78f4e9ec876f53410b7c26fd3f939050bf56a19e42e5012b54dbaa7961708ca1fd8b46f4e10d537ce4d725c6f9ff9c51fd8f49e98b17378732f9d5e459529e4945def341ece9fbeaa6982ea0e36b2783202d441ac98a410b45b032d7c628a2507268daa04fef14aa6eb248891ffa2043a879918ca5a179ff93791894a989f9152409ff4eb2bd3b4706c6fa2b6792222d0b707e66209fced2c260cfa42969e6ac784dc2e7a63400ca57bf667e9164f15940e30f86617b77d00b8690b74cb93a2be5886456fc1297a1d1e4ca7866ec6592133f5e8e57e74242dbeeac7c5ba4790dfb21d52aa68088a413eaf7f91ed2dee657b13c9c5d3e9aea8e7ffff859ac121566cd7356bf3fc8d2a388ee54d7d939ae5bb286dde562b6ceef513abcdd52f12bbeb0852406cf43abe41ffa1a96caba517454caa347452cb2337a8be3f654158f4d1d516402399c6673f0fc1d3d7d033915f3e8cd50bcf15137859e8a41687e1589b6c2794190494e43bff0e56072f5b79f220789121ec9ed3dabe86c2e0a00dc707639f442eb05fd7d0526e54f2a52a9437bb5d53586f9c6de24567ac79efa958713290c9fbc4cdca267ac9e8ada79cd3746d6894455080af7668558d28bfdd9f71efbdf0a640168062fa9f4f2cb21dbe28d4b089dccb8e2e615038de6066e42e228e4f7bcdfda556e992444c10931e083b62ed6daa11d188fb9764051243bb9f86c506a540f6557455478ce5cb0bc3a930781737bb39dadcf19aa85d61b0766988a7a8f392e79fcafca760031324be83e5da62b856661cd71fb0105a801fb6f9058eb8bce6dce9ee7c9d01b83387aeba6b374c1d92fe81fd3839f4a611f5083ca324246501e5176118dbde8404c11eb7aa1f379db8aa726b1d83e78382a17a59f31b5868a5e724a198e3e802482b4082f195aefa1ab357d51712f8905e609463bc963f537b3f75fedd1a5bfcc55a9d01a5a02abef54f6bcd173f0d7fd7c1b443abcb20946aa4ad2b5c28902884c076f3e92f883b339a91f35fb3dd8748957d662c6804b1a3b68e0d5beb17012903739ba475f4322e660d2825e326094d8b7aabb14781bb12fb722c3fe836a9a9f716aa75cb0f54cb130274ff24b3445e5dd1b70edf77a1b1d174cf7b045b61f51f9a72880bd7521bcf1f540d9f78ffb7f1166698eac60113e9983c13e022faeddabb35ff167c3721d14ef3f3de0914ee96671942fc5229a3f029b3d6cfbc7da72204748469e7467ae14ba8d058850a438c7628154d6f8af0458e9e53d88911e9f79c576f27c0118f4eb3b68e626472dcbb4ec3f129cab300130c9f1bd364330115b36856d4961d351e9fe8420609944927403b1612b4f767526ea096f90256817a7963537f4832cb7e2fa507167b2a6ff7115f35bd7c6d6bcd9aa293e83d5f0900587d42dac0785ea64fc3380c898891d225e21367eede9b6ac25f79b8576363ff66a497216eeaba5f198c99320704cf97a5c6eb086ba28d3c1cb5d593c02fa139c20aa5f78c917649dfde46971369c08895c5d5bdc42efe8d52a1affdf4b1548991c9376a58f0157d30e14a10d708fe148a4c1d9095c62455b6beff482b6bf7acb6fcacfed21d211729c588720ad8511737d5e3f5379e7d9522c1426b8b821d1287df913a70028d69ec5b75a87b0445624649624276ab6155bef0417a3261846fb4dbfe9c0d1ca5e4cc3fe4cccfba6730eb402502409b4405b75c65260f011d0e43fdfc9af9309459cffb62f9e4f4c180982075115f2bdc8bd4fd34dc891daaa519c0e3c473f44c0840abcedb6b68cbaf087da69c39d5165be1e53b96d0105b044173e4f15ef88312b65b3bd66c95b838e7b0169a1e6cd3818f4c127b019cb775f109284db661c953843e490d09dedcca2631dc257e44a156dc44184e556a286eada0c28e0dc996b5ac2d27dba9e7e7b902ee3a02f460a419cc80a52a6b125e487ba54e0659a1ba48a028bf69f7339e00f75008cb10228448c38318e3f453615b5d53efbb4f717006ab50a8912a423aa165847aad4027b28d18c6f6019597b186afb3c4d8793961c83caa0e78dc91169859dc8aa4c87eae0ccafcb7c0ed33ab105b30f04dfe29f5d42bf9f5da6615eb8839ed0b70530ebddc6d9376c5c732b5b47f8a456f94de95cdbe8908acc22ab29302b8620f72f49108ce723dc73a62e6ca48939e464916360ed6cdfc55b8a332589d267ad68d3c3ee7c24658be6c0dc80c412041ee41d51942501662a28c4dbea0759e5a32a8e74f771502906cbc4d2b29353c605b443d27e8887372bc39d0af49e9354ea5690c431b7d7340673185fc2b855983fac73bb7058c26ac7003a758f92f706f06bce9d380a815f3a301a4a0fd5d30a86337442205891e8817a5b70e1c02b91c5f2e3ede2bd847f6e786bd06cdbfd36787a5cf2639122c073a57cc9a709ee3e57b852ac8928d1fdbc56fc43c91f22a0241c3702dbc42c7e6c5132f90d98cc6356315e42f7da72a7bff1c381d33c5c1a967c203f02a15b305a8ddaad7f9889079818fa0255323a8a9d1f682f1684076d789bdc6f4342c51dba453c4cc59542a02c57c30e43c814d04261ef0f33f32a97e5515ca61a2ee06f195e1c4813ab869808b3c3463748b703be516aa5f4259e8a8b7b4118d32225fc6a2a1d9011326a80a41a172fc7691a3f2751722dcf6366d56f674bbbbbb0b29c81319961679028e58519610bcd565d276bf6f1a7e2e99d5a136463caec0cfb73c4684a749d9dcf70b6a32ed93b39eafa8974a38a468d08cb3c0ba6ade1c3e1094f4cd1846862c835842456387c1b2736394866703e9036b301b6a32347c0036f18538be59c8ce8bf85db5008defd373756b4b082869d5c9b3130266b24ad087a7b3ff14497daf1fdd7a70c398a22c094e39d42b16b3f5ed2dea6e10592276e7a0052e6fa816fb6d144c45300f31eb20108a3a28ce25b80fe2b0166652b7fbac61df42a910c09ff1f7c61ee6f31b00addcf8f36eedcdc8e15d2c1c84c5d7842c2327883b2df8fef6a24577be5d586d8fe78c3ca152b77b6d0d757a80e12c5e88d98e02a824635ad3fb71fd8c78ca1d1bcb3ac76a04e4cf48eac776a44ce924ba0bdad6d71bcbbc633194478d3b79ee4c34ec4f539fba8d7f8acdd1881f793ac34e5a4da9b3f7b38ff0a48d6683674c3a025b8f9a531a3bef3c88046fe690c54faf67f1c27da17111b0d0d021a5d74aede97bf5bb000996be11dda9e3860bcd82b04889688c6a9b62b5ca093decbb209805f7e7f1379a7b92454460c0e63947704fc361db58fca0217426ef7213e799861936c3e2263024e3ca9f7bf6dd5092d32d4d0cf739be1cefa3a2a4d2bafece05d1cdbb64c1aec3b9a07ee6c6360d8c6b5f53b402da19aee94b7d4397ec4ebce2f9eb732014a829bca344a56425211e372c7b4d31cbcc89721a49ff792a8a80b41e5cd4eb047569ad169a307fd23bd25629c55b31f8466cc4261edb8ad696a9e8d4fe77ba8b1670ddd3ff9158e058ba2bb0e6207caace886d7567a77e562842ebc18144bd9bbd93d34dd9d1ca9128958ab525bd6985c94d1dd737c094d28a2eaecb6980de1a1a21cb3d7460217d780d9c11c590270a347210a98990cf99a47f8a4b8951110966e21e541edd20884a67c5868c75dfce091e20c3284f07d355ddc6613e1265d1bf36f116963717f893f02f3bab77f8b1f9e778d05218a7f03f83993c82cfcb0c76269f49acd43259fba0bad2e3bff47a5c741760fc6e0f04bf2250fc12345c6ebfa1e4623cdc5f321d1583743ca7db6da0dbd41ec82896494b2436723a7224a9b8f9ea365b770d3e5271ab815a226fcbf8297019b5a8a350c37e1ffc144f461691f4bae54548582db194aa53ffb32f4e1ce908df515c757381445871f3cc5ad66c2c39836bd6d0c051a148ffea8b2da430fed488c01a59e9cde3888657126695ddd98a0fc8ac47142359ed94c99c32ddca39f6e76a12d5924fb7c0df9bad1c6e3ac315de695399c210bf261f2e670a809c1faa1924cf9215146020691a0468bc17e2b7d2002a0c6fa66955be5838767d82647cc920233216606a4511878c4266b5d579b52a7e620a7da76ad71d8f867c21ed56f4810c7b4aa018f584cdaf1fde7fc5fc6b117cc65831f6b3d161c6a52a6afc847bd91da7fa1e2568951b446bcf2dd65e135de3edaccec3b80ad5c9738e362f675651f55b6d26a1b953507aa34b59367db6bbcead3be0a21ead3ec8cbad307ecc0a1d64afbac6cec011b7e14af06c552b3cf21b694cd6e16d189f86bc7e0e4683f6d1a24204cf499b7574c04c4728a33fb978e0502adb535f429cea981fa2eafdaae01099e87f1fd8152d6e7a19984a2f55990c5e2e37bd22970117b8a1e6c40260b04d99c4e5866a517c2cef3a8644f23448d27a3adaeb66232983701811661aefeefeef106b48f61b2032fc5b531af9576e59dd9190ac27c2a7be41ea0a3bf0c8f36472a6c44b0f1104a4152afaadac53b4848e761c04f66cede2e10576b7048113823a808c97e3e9e4a14e194f5b6d1ece48e9cf0baaa3089b83f923ee3b9f6a0ab15a7cb5fddfc9d699042697c973e2b8749443fcbb331043dc49fa66303c9877392de98cfcd5573b38c6ffc838cca7a6f75329afcf204a15516b9ff68b28ef08021066d8ef5e6fb8036ef254ac17a1ad2e680a6ed157ab33f53653ee70fe19c10fcf34e13e7d3a0a8a36f25cd1f621579f1f0678731af30530519362f24e461b4c5f9b4cfecd5be1362811bdf5f523c25d923c9b707478c6426434ffa6832630405bce9ca3617ae85ecd7717375c48c3ffe88c4ce8f433e93195f85ff20853518e2cd5e60ba8a302cfe3f60e89897c1cd002f8e56f0b08073f934f2163dfd5269c02c990b8a350483fa04f8c1a4979cd964b6cf172314f7ac751f582a21eef5b21642a1e1f24d56e2a23fb3835b9a95d375ed14ff99460f65e6366f72412bde34e040e86addf4036d64a2291e0789a4371824fbd2f565aab3e95a1a2c767dca53513b821cada1825187f80340899469f981fdba119bacaacb04420219dda11dccb4b8fb767613d9fcc9b9f7af8380eabafc350731e4435300f97709e9ffc5fe68daa7c4ad84d4078dd422fb6e9cfb43c38e72fb327325d3916e487f6cfa5cee39f86f338ea928fefe6d3db86e0ed352ea3ef771a5ccc6ef07fd119b629b4f7cfd1027625a7950c53d18132b42dabcada772baf0376d1e316dc6d1caf1b156c96eff80e5610f876e8e1059e0ce530a687c6ab9ad8d47836d595f4b495d1fff509d460df2e8efe0eba7f4e15c3d8f1d5eb1dabf65049829b9317d533470c77dc0fad776724b603a1ad1ed829eb6de849603f3d683987d7fccce943254752f731e2ec4a70e872ac07db9d053333f3b2147481c3d7525ce5c539729c382be6d9ab1b7200744233bddb173eacd1f475ddd687e33ea5e71f7973a02fc342ab03ab46327c005143e1510af9399c1288ce0516f632c07139bb8464e8c0747ad35d440c41d50d8162bd60637f99e02ed8bd660d9a394a0619e9ba7842154124313f2fb06cca91aa6d541506feae16006c7f6f45aa2af6569ed2167b09ee704b3d2c76469c84342fcca201dfb9760cf6c27ef59459d165c6de21f165e0efd21a858303a8961c9b9aab0dbf5d0eecdd09e1fd1a9b34dc5278c60c73f5fe11ed8394b2223fdb8d80a23d0595bef5ac1cedd2caa80b5ffc1c3a4773a9781cef4812a34e5cbc034db345b18372bf8ca948c8860a6eaa423eecb6a075f67e1f3ed330a2a93f1856ad67b41283dab1e394dd3a0ae0a5b97c3f52f68289a47b967d278f7315c631d1b1e2f0c7e32d508f144dc919c042631194062a5cff2021420f3d253f0d67cace8a577d9c9e56fa77eef9a6f12cbdd6585b798054fa47676531496a372d9969f7b935fe46174aa5d4931a2cd1b73950f067a9a28b271715a6dc7f7e6ab25eb26153c0687fce1ccfd63eef37c4edb0d47f337191cad4ead95aebf401f7a4e48e0f60a36c1ad88c642eddf759bfb4e19c143492596a8d23b95298f3091d6dce32fa8e2ef5269a93fbac3d746cac8b98745d80b8ae66329b5d499eb1d190bc04ffa2d1fa1bc9b82ab62762ffe5d3f2cd5167b2d9d330c3843b2ae085f9da3a5036a55c05063348b6874458de31db762a96f39e0813c9924e794f8962765ad2c3e53004240c5d4a4c0a03ff27746bbb058a0d93e8e6171ccf520962e1db1970ac75a0eddca2d3976451937cb6eb07cc10d3e912e796ec8b7f1ff32851f141bb457daf964759259967faa8dcf51c7f5684a550850bb88005802930c1b7fc85f3a09318b06f6574b2cb0da395050df2fdaea8dd4a7d80f4fbbcf7f4050c95d762460047837c6ffcec1c5f738bc9168c009119b2e30c2a7acd21c2026fbbc429a3eecbb99545a4ecdb666b3c7edb4a02fc3b30d7daffafa41691dfcd3f120c217d391c0f9bfb7d5f517a2e41b39b33c26b0237949da5d4007651443219b9a953674116954758986a33f4de6d0695f5efd12aeabbadc51f5211311ff35d1af7e1f37a0e6e705de2fc7b6895c065404fd4ae351f452ea59cdbcc099d59377ccf7dc9e96abd80b8b208e35bed13b7f7d796c625ac1ff5a9f4a77e287c4a85f487378cf3bb198abff91bc986a2e403088cb73956bd3980e756af7d6f6556a5c2eb29d8bff51427ec35d940f6fe259485f8e4124947daeab3f8de9005172b07c8deca08ca4c0eb3af37380944de4429cd4586b1e9fb0374071771f53f7c2eb91a854b71a24e4fd0877de9681e66f018ac76536aac92063057bf3304f9e2c3c757e93652e9161dd5382eea3badee126a5e303e8f9c6f5b4c5261acea5b0fba0293b4a006a21f03d5323b38d30769375d815aa1cc10d8b0c45cc370234fe8a303af92eb055cb6d8d3c114b0553b76c83880fb71d97a9597313863d6c000e55ce2a37e61642f43490885f15e94782d54f2c366aca9d8f08136909b0e07239503fe61bebc22742560050c18c6ada014c35c7b1e62895d89fb977cb05601954c5e653bbcddbc232db769da355f291c1c9a7640163510606042945e27643626a86d7928dd8c4c094178951b1b5418f0a9f29445ea5d8d50d062636eb596c630f179784c33e43f5534ed2ebb04b471d49d50507576ac52092e2c3469c5681cfd91aed20868adac289882312b48af71568f5bb94316ab1529f39def1a09367491b886e61eb04bfac2324ebe27f9bdd5a0dc032c6dec2b3b4eb9d7ab8b902ed4f6e5aaf91f6faafed67062cf0b55043cbfa6f80c9e0961cfdc79835150f71ead3babd4dfc2459cf19e968796e1968d89ed4a1fbffcaee17913424008d8d19d080c4ee07e782f9e2b0a44ebaf8a8ec3eb412ac896ae8dc48ed683430d3bb66948f9a5a4a2805abb910a96258fd5193a681fa342d47febf7cc97816c987d0191c8df3dcd755c435522fa97fe783c5657643ba1b6a74805f4b3f5e1c1a24fbca35746493b8f7840fffa38f18fdc7f675329fb134be1c1e5dabc5b6eb89e881c6c3f6159418a47f819f12aa62b45fafeb535c2da7c4c4e12563d3cca1d738fed9efdc4ccb7b2a6b53f1d2f98d267ece7fe0ef3b2db9c47f96b2a8e9ddb19ef695efe01a33180f8e774358d2efdbcfacdaa4b7fc8c17dea871b4cc17b436f64bf9624f918b8f741c84519878ab70c4bfbb1428858487ad94de4e88e5f975d380e51818a8fa4bf614ba21239a00cbcc2cff3ebc4e4760b2844fc999a59f0958a2b2ca944e04d510b78d1ef3fb4aa115db63aa6b313a43fe6b018920650b6f42e3c9b94d5eb68ba9fc498e0673a209f3b7c21896612ce8cad82155ed16ffd8ce82d9134a8e40a8c0fbc770de2e2bdcf4ad9d5e1b70d5f4dec4f5f665743bb26cbfa3d7e3d71fc21a377951cbec0e5ac5bebf3cf6d67e911eaed5bdc704877f128b1ef59cb358f95eb8e50f1f9b0cec30ce7a288f0a93664fc95b39b03adf9eac7d9d79ed37b814716e16a144ae203ddc4d4dd57839f2e9d3fdb34bcc808e995138eb137e6b0199f1aca76287b9d9ce3cd6c53db9e4c28ebba9eb6c111fd18445baa790c8ba9cdb718d60b26697958e8b89d4a354aacaed5359295808bb419db140aa90c2f197c0f047e96ce762ed9d3c5e684eb79875f14c39d03991c5d3643d1dd773c66c53a9572b28c4845eb47a43448811a758c0ab96deab41d63833be20ba090eab24bf5824cccdbe59d5bb2376a51ff3bb6a8de5cb66ecf9eb83e678093852d5db5542678f9b5c53902100b9e843371638eb245bd4044e9d2042e71ca3384d17364bc646865bc888ecc11e5f8573dd24913adacafbd70e1fa0c23e945426619f222f041f8f4436415ded6e1bb39f42eadb9bc6d3fab4d8cee73aa5ca600b47251942126f0e785f4ffa4376e610eee263d0c3f77b1be6a215735d18a9668d7dbfb74c488b37f326df46d349e94bba256247a38421c04b2807ad05e2ee56d29bd8a2fbf7da1ba0a5697b83198957814b9fbf25703b067a2891c178cb0f9cd0c02c6037711e5d274f96ff544c5f46548702ef6653847f28932cf884d055aa4a5aabffb55a4704fa04c51c2df010338599ca201d177b90b6d0547e85e6ee44dc4ef266aaeba50a1ea1f90f637bd0fe59d096f7aa729ee843bef8ede9e99d033102f7c4ff91acc948ec32556311e4b90a4ee44cbe3aea8388564839b504652576e1df527bf154d259063a7bb266d4aa7d65aea2fe97566f17c8feef84766390a402056bf69e2ebdf35a100640f584ba8f4d7c09f5b37a65f4d83ac4222b2366f66ee26c2ee8dc4606ff3bf15458a61cff4bf34967a0c66edd088c45308cccf0530126a9caab84cdabd5b964fa750e5517005e407ec81b69b817322b66f506f83b2f376a77fcb692f9ed98fd94823925d17898f4eb8c364aafdf31e4f4b6cff23fd80e5864e835505b5eb7a3ba71cb9625f80ad92e4eef77e34e07e06273509c08fef7cacc6f9df8f82046019c5f376ff9a5c6807b15c40e1a6eff2f709365f9445f9cf2bda812bf0ffbdbfd59516829e54063580bbc6fea94c9c1f8f1ade10ee60e712a73ed310c3cb9f006cd3efbeaaf360a005f2c136431207b8204bd3013298626c50527ff5d955a7443d406e5efaed62de0a4ddc65a69b162024cae204180b3f7d7817b84a1c0dc3b579dc8dd292fe6feb54947fabf900905e84f957925bc548179b1830cadbd894a3fd3befad1f16edb06afd483647d7169477fc2cfdc15fab8f2a468c5459de7f3a5cb7c366fb9ee4f874338bca7928ba8011f46416047029fabd73725376481380cdccf9e5df0f858d038d6bb656218646158a88bde42c041bc3d6971b1b890164de5bf763efc8ef44a5a9b259b18f056559c634be109240577a90d1d94297ccd03d395d43d99d744762212e3a2181595f65fef7fb80ac30f73ef7865cbd05aa9aff661df56564d2316cd137e3d1b7d8c6d843ef773b622bc20302e1a2e3629735a5e629030709757750f702618ebb467e5d44a05f929396ad6ec95d811a3b3b3247497e2fbf021c1b2503ae0d87dcb88b95d87c0440b12effcfc812556030b3fe92df260be924492ea1c3f34353ed17c0fb352211855464015c3f07069a0d1bdd31c98ccf2ce9b610d5ce34ec317ed8c9dcdb33c918bb0003e3eb616630318160b46c13e1d9e0d974050249b4a1a8224374a9264d6b24ac460fa1f66ac4089da81f462dd8e864911c178d54180b35f652287ea76718d6ff8666e98afd5b92d620f12fdab02bbf5e8a43bee54fe4a4389353fd528894e68e3eb9da945a8cff7fdadee07926080c2640da867f83b2cd5748e131961d50d2cb4884899b7ca3c3ccbf63f374dec3443f1ba7199a689c75bb1abead1bdaa5e0975e7b0dd6051a7a226d8bb9e74bd7cca46931d1530c4e1a6f747deba13d0c7f79a71a08dddda89321b20e91ca66b321d2bbe4d8eddd6b4d5f05ba8fed30f4cf71c1644b3a80c1b1ddb16fcf49290e0f201b6fba19813466844dbf901c1c7957a0a49241582f367ca8daf9bc5219d037b42672dc63a91c05b521afff5c7bcf0ddf5c44a3060f30cf58e7f7b05a5ec3a082268da3de62106e90c856b662ae5e228b68d4eb1bcf3f34516e9f18fab9a5d34b6e44e51aa93b9f4a4c4e6877c2892130cb71d89de4fbede737d6b3e26c019c2ab8429b71f0ac6fb2485a4c566774198bd416ddd04233137796d19a4568525389c0e158237f2fef0df95c9aad4702af48b8d567ad8fb7a8bbce18cb8b113e33fad4bdc5464bab21b0783924a5ecbca39860007db939f4d40c1de892fce7db72cde8e6c3bec120a5a3c417917e6514fdea6ee2da9fd4d2869dcdbbc4fbba7117bb0d77ec571279a992e25807f85d53e17f8f3b09a09f8c4ae5434cbc8db8bc79d93493c2bbac389f0fef19b12185ef5a976a3ce509e3cf5b230304ac8a8c2da35d6b2ff1ebd9d94fa6b5789ac8fefa26d3cc6909baa941f54499e1de23cc305a9c3032ef25aec553a23404ac425c4b2f0771fb84646728fd59cd630c66e9667d196554ade673a2f9b4e390a9a918db8b22ed78ac1520a94c7dd27cb7a3be1af1132c81197448d0fd6fc921215895dd12507d8c5d62dc6565fc73e208e480f5215e155abcbba5241d61d46911da377c745832200d31c73c8af2cccceeed43326a53ed9fb55bc02d206d7ee025bcbed2b61a4d1e94457bc68513b255c0d7e27ba7ddcfdc95df661f5d8ca1995c7a4c59c7c9d861d832d930ebb28c1c1acb3d671def0241cfd0c5d4213a8d2ae8924df1e703bb10a502d9f1bacb0bd6a02e6e13403eba6269893a9b566285596385354c51b46cde4325a17cb3dea3c824ace8826784081a48fce8a20a1e9ca01f7e443528e89fb0df95220e40a82fed3a19d4195daabac67396276a3d83a59f3e63708c34a6f02eb4a07dca40fd11acb9dac419c5488443e605792da84397ff71db73736e0d7c5b97f49da39c00c7c3fb03dac49db2f5b54d07cee91edf10790fed75116c4a391bd3aa14fe01715e7595c5448993ff348b7bc7eaa8d0c8b2113725a8feb9093b39441f857acf325e8ac507c43307d7e98eaed2306dd9f092427df9a2986bea8dfd64df4d8c66ec61daa8324fb8c6fb94091cb13985c85b80a9a1b10a8f97f6c83c22ba98f7d4b7c03e5c341e4f8b283c238aef7a82b070ac47f111c23d7098b5cd89b022468ccc93cc71d5ebfcf73f6bd27d3e03d52c99983896203efb32dac72a3fd1085d9c751d787c07d869ab2e55a13797092382270019c30ed0b666f6001afdb98e466be6e608f5d664e4e7c69f74fef097853b3b74359e343419b26007adc8924c0e28f7be7cfa30fe90f0282b90c787c3ffe4453a5754187169d920e3472eff375394a2206df8f90530b54b5e5c1df9537366ced8968e9a78871f85b0eb26e3338a903685308a07becbdeb98c589aa75d6fa8b1c539e1774de597be761e3f3d2b24be865f75ec7b7e6bbe5d35a1d71b8dedf25584872c9115f8f0d20819dcc147cf86ac0a56b8a8eb4bf28cbe52a698b818514398c7c70f77f9b532fc74f4cf717e1a7b4981894b54d0ce34a20e4d324dbe39d7507a7b098c06e648481bab7eb1ba9a46b96df1ad141075eab90f8e37917a47ada92fccd32e26091f5f489474b6119268dc5118122adcd99e1720a34512a7af64fe861c37a55f2f55c260ab1bd3430ba9ac726602dbc53ec71931fb22a198da5346856ae8c7e9e8ad7eb7597ef6341e275f710781f9f96734d98e8b6691bc7e0f253408c46a4e38368835c7305328a0157bcbd48868f1a3495d5bf63844e16f11d12a931700fd719ee0e5f99f92d8355f416f14da07807ff8ff3729f74e8e63ec5464b57faffdce0e243f41abb8daaadea0950504892a22ac7c6ba8c52946f405cbe721b9c0afe4a733768293ca139bac24d1330b889a2dc080e61212eb845513390f4e8ed097a76a0c50fda3e316817b639208cfcf4f59bb408baea5b4879d2270827c7046b7dcc9ec0028088d5340416f6ca1ce5cbf236295deacafa1d191832838eaca153c6d0168cd9472f675ed757f8496561badb65196e5e16a65ce0519f6ecbbfc80d97f9f2b1ce549d8032e04a0d5bbb947adf504602f96ff914c025d7db8b5cef2ffd6d19caf79cc7f0fad16842a8f7d80b7187a9fdfa4026132e6d3f027391721080563fb15f368e4496e6443fb6a2904456b2d5142c598209e1db53b29004ea093cfc72b3f7f25e43ab4c8c5f544b5df71c2576f5c2dc7b9be13bd6bf551a3b638d680472ea496ed8dd68ff6e020247d5a60fde9d9cdfc8c7db0db8584281611bcf28d83ede3a4fd765ddcbb36f6bdf10591a9bd472b1f518e088590296a2887f769b23d4eb7f7013109230c1e431b37db6db21691bd52da4e51373f46a64199079824aeb333468b68693518b108f56c19020f0a8b985765d305fbc252fe3aa015e63e974b880fd3c28bf2c3a3e10ddb91db258693cd886bc27a8b3b7a485e3b8b63d054037ad5e6fdd7be869d513c83eab0f95c3a62a1f134e42d37e6139399a8fd1648a7b4efacdd21d8c29840ec02d97af3a914109196d0f82c176a09edabe72176f95454f00301f6a5909a616bf24b534421d727fa5713a46bbd25395a9696d5b5b8449e8bc3e661b0b65399418d8c4fed3eb4d52bdebf561f6cbeca51760d5cde81854cf453d9bb87bde5dbf36be21bafd980433ef124879972647e9837929354b1cc15ffbba1e67cead45c6168668069156060f805bde2786e20f49660794e1bed7a1b04787bc6f0230fb88f4faf11122755af381cc680b48cd8a3741cb0aef2452c282b88be83182b553d36716a4ef19c15bed6a785c8d87bcbe02fe419ab8fab7f0e2ed4e3d23c3e2c69b4cc392b8320fc6782a9bdcb4eb6ab697f5f522725d6db78e269bf1600e1deb14e6b03c7cd418db938d51c3693d22d023c86cb6e40897baec6476eff4b32adc97e13833bfdbaa6ab1523da573f8080a3a0983883b3999c1c073b2f70a2c945853d8085ec8730897516b0d4b375c770ae6cbe34a4ab909b6f790f24a95573e8772f84631bbdfb9f408a33b71e5471d6be71b4ac308a65fdc5dc47db8a8e45ca30f51ff86729d95222f662fe148854fd7787a6fa5aa67579bf6a77e125f13b146acbd929257aa4ba8f5399d6eb8f12580ba1669ee17ee4f0a008e282c5efb00850d880e17af7968ba8c83577b0215bfc62c6085be5e9ad49a6c5a739184a9fe6a29ec0262ad6ea906290a4996d211cb032d4d54758400b28c76ae5e3b4e0149bd98810da68e6b743f8cd928c59797b2845e80ef7de86576cfd56357e765118df38b2e71ea592ed6215bd53c2bc0b38f2bc3eda5abefc2aa5aa030a17325f2e089ec119f7f8c9118cf5a9316b1285e8d43e34cd4084c432374cf79b8cd5d9602232697d769d0cc16893ee668bb1badaa1f88c3f0221830a412a15b8c343025a1fc8244c53d02f046d8578f10853b03e4d6c2678489790c17270a955f452e2df8485fb6c9fd66d0bc15c814124b252763c34a3c57785167cf87513bbe480a81a877833e0802079b80ca95428b761ee2f0ccf8a956d306864ad72b72e4d968343c59943451345c7e04a703fd7c0d1bc0eded139594e314b58f16ff0a83ee1fb4c7bb70995c5983ba9d0a28340ce8931d9d07e8d9c5325c8cc67b36dda6adce11e42c79ba8ebee6390d0bf212639783b0cb677e57bd86d3d38951c8c3fcb7e29c9a8414c72d23f98350a2d44d69f1cf1b5ccc1c9daf0b06de2bbc222a481d02aee148faff916a7e622053f377f01bbd341684fd6d0f147a68b9e47d2b979e29593c470623698c0c159c47846d268a22ffd252a54fd77c041058499d61b4be2f23aae06158f85b23218858cb0a799cdc923ded83c084faeca187dd11b7fda70dcf05205354e4cf996e0bb780b87a23123df483a28e8d98396ccf4677bdbc75c35a86a4f86054cb9e75c17b35e3d2d36f162cd8852c86f14cda26890cda37c95d2519a9e3fb54369617cde1fce7c3913331b75954dcad39e538e709c153403e10f4043642a35b98ce95b8f1b668ffc72784034ad154695382ff1fe4502f772741ed5c2f6cfa804cdb2f0b2e27705c537c49c9b595ab986b5c862ae21c8ec7dc0c669eb0e85f7592c5faf3644c851f257c9da3290c251c64e6b06ee9ec88d95760cbfba7403a46da9037c8a6f8d394d1cb58f0350ead2594cca0fe7d64fd8b2ab85940d14fcf64de63094b8ab566dcc3110466ef2d9f6442165a70f78dd9529c67edb44cfb8516fb450ec5144ef649807510898b10242cd68c84b7ac1aca7affb6a6aabd0c3334034002d417c3d7ec7cf1d2f68238524d1ab74211e1ba9ba7469d9ee56ab2c714f161f6b51226ecd9166344b5017e976a04fc790f3c1035ab747767f0211fc3916caeae94d7972b94f37bbf390ccdacbe5dd7010864f5ec6e16ba9bc4431590eb481718b9897088bd913343db1ac40aa96cbe14152c7fa65803977edc7985351f4065b1742e02a17804b21b9432129bb93ce153b34f3046d690d4dc64b2b8ab3bb10845de3ba0754fa0636a6232c9a66ce1c397340ee5bb55c741ad551ef821d73689b8a2d63d75a706677c6c3a59b26ae8d0c6883015b12f066295ff3a65a160b527a98221d935f2245ffc29f26806b4925e1e740667cc86c556af206d28e24c5dedb3833faee119fa402b5e044a3aa84b3c7616ebee45706ee24303a066f72f9b3d311eae9e12a4f830583dc702ad8aafb6450979adafcfae1cc83e22bbe1f7b47647a7617cf7c23e6b50982bdfb32d71322e5c7297a0f71b99ecce8e04067b5a8cf594bc52e6266edc420d0aa74523bbc6597bcb1f3dbf043cce31984ecc9763764930568d640b11ce01fb2499ce6d4dea1e7e7d21de4292427de9dcc89e248f1b7d16082725a09c8ce8ff676ad4f64486e8d9b2ceb7d77319e91324f04f4a7e9aad4990a0420c92c655434f3458d5e7b13f6e66b740bd6db583d9e4992ff6e20f44607fdeb8ce04762e210f5f46d8548259127e303593ff75f84a159be6eef0e8175f6ca7dbb4702cc3e1a1dbe9951f1bf682bcb0696c1e9fe9872c485628620fcef3db8954330f070d315d322ad2976641334d9307bf8540ddcb53cd22b43b8451a1377495192795b081129a7ac7da42a2bfa283c6824232e765b36313cd84d4f835302120c4526225d2762cbce0b728e24c15c240ab2269d7d642616978e54c4781b183c5291f73d5c3f7e1b350295fdfcf751d9ec6c3140d1ad7bc61ed8e0dcd4ba9658c69a41d6ab0b9ce6ed328888658cbc0c773e77c69ea99a33db2b89544673c3ff6b165273bf1863cdd43186c35184e030e5026ff5cbf45e7ecb9f8ddef771619e56d268aa8b63fb828a4e0315af7cfec8835ffa6474e6f3147aab1ee903c2dc292231ffb4827f0de877f4adfe5aa1ced95d7553d938f7c1da20eb76faa3565678af5c60ac9d516a40571973b66002113edabff1eac1a68c5d2efd71be278988ec2bdb02eddd1508d4203101332adb9f807cdb3324b8f07f5f2531424a348485cbc5f3ea11f08c711aa2fa373f31dc62065eb3bbec03558b4e48cfa27ed45aec43f276314a2b7642e44ba15cdb1aff8fe7b64c2299676ab0ab1672266ab445ef731a58328dbfdfb18c7336f0923458d256ecc3cc76353eb034fe085e8f5a724b2d36a840aa4d0cd022ef768a95b64ef90381a534e5ea8e5b7b45f345d555073cc22ab28beb595accada46da99119087fdd1d47edf97d067365758308e777e05059dad0741459a3a3e665b063719099b4a0f888afc29b2385f450718c4356019255f1ee3b5502df610a230a84f0cea7107e831cf1c7939a4461ed40a615732d8b5c629f82e7784e8ca0e84938bfeabf89cfaf919f72b9a61ad2963d74b4ba02df251de0a892a0a5f3f807e65595e2636a2206335875bb7f8eadf0af9ef27534293c76ad19a12938ce9ba3bf39dbce3382e9fcb907521e26984005dd0b9ec50a85a5a13cef97251e545d95cc929119e5ed3683022a97b2fad3777b417af6f81e50a25aeb9e6e7bfd235d5286e6e7eed7d9f3886295478f6aa9535ba1854d4e71e22f0e63020bbae78da054335956022a51ad84fa626b37f889ace9fb251213dc1d406a8abb0606c68559fdb9a6637ba5b8cc64324acb171fd7f4ba8898a330f78bec04cf15784a512108f9496d66da059ae008671fcd8b02ed4c404d618bd0644dbacb966eb96ce4b16670a8012c879aafb8218c31957c5722200f818eed1c4c4824e52e64e092d85e314bc51c4e741ada79c9281e08a16d91163c86755123c7c6ec16f0640f3a9dea31b3ae9e53dff006ac7bd1f3e5086c6b6684061d9d213b07ff7d5052a12d3a2ddc6298cc569d8d46496603c0058de776ef82ec940949a4d494c8d092e78eadc949237102756a0c605cd83eb2254fc253a1d02dad8965c76b7d214f4f178e58c652c54762744a91f118821729942d947c16917b0df624a18f49e8bd20ef29827335f75b42187f9e9099acd60c0183069b0f883f9cb19251fe512a2c2e7c9d7dc5cc141ab2d9498b8ef8b941fd56e1a793c94aa5611b1f7f954d5125dec975fea03f374aba2f4e121be557a61fa5654efb34a448869aaab80f60936c776be59e04cb43b1500764cb91e8559d801a257cd130774b9b536d661b60677381cf75921695fd83894b283db6924d7f042d2e183cd380132d190a56d50584067a4bc1f66b6ca92ab8973cd18f5751c828b3adfb6d0096cd648df3433540d39a3d8bb78881fe055bc02055dbf7c8e8b7438d7ac67580d001de706d4fdfd8cfdcf10fa62e1957c0505288ec531a95a380a7fa8647d2fff4a73be6b4192563744e257ca21dcedd0b0abe368fbc0a598a801c62f1d3dae1623d8cf1b90fb820809739b76491619c72c3e0a3ff95ceae134f3760607b71c8804a3001b24ad52c7a59a8b7ddefe798b983561ac6780359abea227d6735fe2e871a7daf227efce37c7b61b0b33eefdec63839a8af3025f1aa3660dbb3863862aecc3865e623fea7856855aea41aff83b0be85002b8a109b0dd305e6d52706e3567ddd3e465b57fcbf5aadd0f4b0ba742aeaf9024e552a3b4fdeefef714672bcec18f0afbe3691b57dc859bf9de566eab17722bbed45e5ddcf4986623fa004fed8916f7bea3dd9ddbe34a7475b6d7952df32d14e7c7f1e916671cf7d082ad971301bb8589ae7ee80ce8c49563aef582dabd41ac32bdb67330bc90580063d2af7a50363808ed82f040e8ba06405de0526841a768ddf9bfe40310e6fe61cef8d7415fbcf2384417f2164a6e4709f4512991d2a8a08c08e0a7dd9262a90d2725f75762f4dbaa23188f629d67891f17ab22b62c1bc8c85647b1c476e3b3af794d06f8bd88b1758c848db19056a844ee72e4d919984bfc81c82419b75b211fd6b3fb9402aa269479ebe30f8d41b0cab841726a7d3a3a644992e3d1be04ef8eeb688ba670b5254972e8daec8c7536c28953ce8d0555f06e7a42aaf81de46ead5c278fcf2545d0d83449dd2834095f35867fe0fef8ada7ea0d333706a4912d00a16a63a3bf7ea17e885fd5c4ff7b8fccaec4b300405af4ef95b8980b5428296f3ddd3f43be91e080e12141015e27773d85c86a4d88c22bf1fd55600f4cddd62e35ba5d1c00e14a713efae7571f400acf3e480e580eabef51e1489e151a4bdd85c8665cd578939286f6b78ea3107d6d3bfe2c171b59ed7e284d5e4a5ec33fb03d82c4598f2d56d9c2ccb857c0bf4839d2fb4751bbfa30288fd9411e318c288fe30ea5f3a686d34e329039b2a1a7e44ecc0b9d9af3cef680ce1ba0bccf8beef8deb62289885f5af915bf80c6cbe3f34083feb478ea1860bafa5914d9f91815232e53efedb8259ef8cc530f5402a94a3f0ad32a5baf9810eb0243ff40213ff91958bb4b35299436a85814a8ead1a1ffeb54f19a81c5396d3a3bcb212243643f6b7da5c69c1b0d862b7b0ae3a08ad023c8057e86f4a4f117e82e98dfc8582ada04e659e7027b0b447254b56b80d78edcd6f025c3de528f043a8884824f40d4df1d3b59115f09a15d575786a4ffee42f0d4a6f0386818efa44946e85d1d6cb1fa7611ba5de676c3a52149bf6761dbd04e573a9ec92ccfb3c7d2d222ab420205f65adb20a55691f056dac14ef0d686dad144f92a8579412f72ab6ac049b61a58d97b5fd3f3c089d6077b831f77d4a1673e041d0fa85eea612767e823918b57d2228324631ee8cb7ca68107be895479368f72e057280d82cfd7bc3fa1ca5fa1a080b0532862f758919d607709cf898ab5c4142f5dd8a103838f51ae2d70ec491d1327d3d3216bcf274c6092712ad3f2a243fe6c520d253b7b3ce8535f1c281638c8017f590e26f6cdc0aa5bfae30574b09828755d19462b755d57a95a203be25e01b833af64af18f3cbd3c5fb78a19d0f16ddc9e1748d1809dfd2d49a2d98741903d8832ab2b8868a9ef316f93d4853899fd5bf9cb6efb464ff7212846052a8bffb8e82157a4a53ad90233c535764d702f6616520b04bca24d3f598b51b0647a354a547151809349134d5a888fdc8e8332d1b7af1efc371b380ddd7b0bf1c7ba4a77234c03551dc1e82d20732b8b4b71e64c8e24941135cd6bb2aa2dbf8f4de6e671cfb690a8c32ef52c9a05cb6a5a1c7f486eb8c0ddb47bcbb595de47ac050f2e01919dd0753773da30123e18e487f6fccaf03255fda53e82476e80a7975792fa9c281d7493a1d970b2674e530027d489ca568dd41eba16ba646a4478737063864222f128c7efe6aa6b1362845535367c2041397517c5ea774b02325c15f8710224dbec1f9ac5b30958e93c9502d9f4b6c97628824eec15bfdc517697c1e0affa937c2c66479c7959acaa7783327aa8054553384a374fc04f86c318a503848c03347787bda74b6f8390991808e44ae44f7e44a5a0ed687256a50d8cb4e3b45990687c9984415bb372d25a8075b72b7f3de6d2c08d157833d46e742ebe37038a902eb9c080d8cb401758ee8340b5cfc9b0f25f51e5c5c816cd1fa3b3a802e3fbda718e14431de4db1a30027c139cb63a30ca4df9409337cbef706dd2fa3f5f92dd3a48c0e11d2bf6cc7a2d77ef6b0895da04790888e084ec6e9ccb9b0a3b9defcb77f2c8179cb33d753a53587386462cfa66b4c0c9e6eb2719c290812492d5cf2bc8090ccc1890c42f006f1561f0759b1bf6a87c4c03123d726df6d28af4c44f8038cdeb3442799b0753f21ea9916110effe0516d0715bf2fac9b2a16a67668ebb20362aeb7d7d0ca00ca9dd2d02a90bef53c5fb65329e5ec9f4f48697f8a7af4dfe138b2969a8578f183a21ad877313ea29d104eb16b12a22078c4f05ccb3be6629f53477d51148d59b7ec3d95f7dfc9fa8363dabf9b470e6ff9e11ba41b256c12fa7849d3ad9535f9fd4431b068ed14982eb11c59ec4168dfee5eb9b020e0906ff9f0a6e348475ea78b9bbef86fcfff4c69460c1496534b5bf6e6dbd8088dd13e0cda2bc3d0f600ca8c8d8c05a85681619398c7e6d50cdb0b8fbf421afba59af36f3a202202340de5761c609b8fc21998561c1a393edf38374455d1dc2a563e025e13a6a80a8c597a385ab96b1beea8c1c95bd0706d0d2ce53f60f80a9b3b87bf8a6d8592d2bc9f231d709c5945eed6b4fe2890a00b1a35ad79401858bc307531c763a36493e0ec3cea0bfb316a2a61589425f4a2c34959801ff7e6eb6c291fe16391fe7c0ac55aa498ac0d18b458a899c2500d0f182523829c9818367a1ee90513d5252d3e0b3ab40e3a890e2f8bb0276247d64c62c4363ac25b526da597bd15a311688195acc0b80900a17b213c09ea6bc78ffadb0d3965682fc31bf2401645211ce2991844969d3575b307ee6c3faf829785a091ae83f645d5a2bfd05d337ca87e552457d5659a79f33da70f4e67d266fe5f64e1f44becc4df868c4c0e9de49e4cf8e7a85ae412c30241f2d603940b0b5ae181d9b11118eb6cadc12065890d5ee0a2f484a05d74067acfb373126849791daca73e722357d3f9df5cbb644a6002f30071f8d1dadcbf4f06a6247c3bc146368a47786ddcb85958edf5650639bf34435b64bc2805f8d8145a811ed7f3a96224f73e0a98c7d1b586865c0b9d80a34f8924517c6e341606a2af557c961f885baf59e8656c16a773040a803cfc217a8235ab49c517a01344c650f2d69b6bad9a238de3031e96ef14413edc2a02dd1ed15135bc0f19c6ae4bc15907d1378f430e638d56ee27e33fa858efe7742661c80442e2817f895837c01f07d5c7faec8717197d8e01537cc6d999927a3151c4db0c515e48db93d5d0239b96873ea9fc60f604fde5e0d8b376d70d2c57180f85163c7b002f2b3eb031f30d32ac055883f78c188b8c9787c25ff3e151cd3b397fa5659c3e2f71de4fe9b4584075195b0f53cbbb5450d4ddd4bee4a80e3663ad40b89c9c6a1eb7c3c237a84c0eb55939f2968cfad6f7063626b0242d951cd69f4b994ca1f7dc0525557327fd49d1c3d72b3e4c726da070b74578ac99224f4605215bff128b05011980fe7a3f5d88ebbf7c74abafe0e9f7de1d12d4dc6896c3449b56a985a6ca376d2437559efb8ea20d462c8490c263ba4de87093a1473b2b80952fe2d826bda138a54085edc1c773a9e992c6ecde11d0894128d6c77cd7d46f7f459b355e953c4a6ee752005980b6e9c9df3ab58c6284731d3293ed55813794d2825361b752006995c1b0c95483051d7c737c985ab10df5d04a300a4b846d0233da8df9244b0c8e3d6e872edef58a00a404dfdc9f7f6dd4aa1e2f8d457822bc8fa37f68b2d8d49f5a400683ee3036f42cee2832546605b1d4a5347a23702efd247e9c7a653411bcbf7c3c17f3870f01e764a866096e86d777696a4ce42d86d1f17bf5557856ab047a8947aa936d4bca42af389be0e6bd9140706abb813092d72247a9c0eb339fdcc0494ac389c7e304ca47618d0d3e634ea83c843bbc0efcd5d2d8cc2a12e427ca6905a74b2e2fbf06df0a6eff279487c7538f9b5ec916496f97e4bc10debd7c35e8a2d1bccd64fb6194024f6e0b17fe793a32f4431ca697f4cce0726e54db118a4ffe1c1978035a940463ed5bc37db446be7479c2a2fb208a728a446ebc437139dfd2edc1d0cea502bc720ed8e43d0bcf61038396c4f16cff308ed17c2bbb999d5a702df2810154a9c753386f2c0395a61e47c022db1c9e9869219a660f58e78b5d5cacfd1b8668eca6a84a3c93cd44c44be92b9ba354caa93e67ee700257f3d772c4a4f3ad8564d2e1b602e77f5412178093b9e99b35e0454abcac269a8899844fbb2b4de10068deef063e59c2d3aa58c132851bf4758f58c4aa18d0cd64f19c254bd7f28e209a9124198972bb157e5e20f57747a9b7d0f690792a0efc3ec8c0a7b2dfe64df47796f62ef1935827d785b67ece150851647605a0f6803bde59b49cdacf11614860b3248457002a69ea8f24843cdb4a30a05f91278253ad4b78d429c08bc535df25a4a9fb2a56e9155ad5f35cfdb0d34bbbd9bdbf5aeff037b625d4c1680d1fb6c2828da4a340eb123f496e81b0e86996c5f1498f8d8388a7c700b7be7fdabac712d62f90b1952536c9f1abed556fe6f3b04d45bbe3b80dffc511d2bb6df85c50f54433dcdc672f3226f85416cb15eab758300fff62a847f958e3c1ec0639e7106aa0c1816ed97a6b22628319c0b872390daa1f02fbebcc6df55bf69ec7a85e201c803e0fd37022a19187a028e96caa8ec9e17e55e1362009a22a16ebf637323d5567f78d8decb02fc8d41949ecd62c58629f01016e4afdf3303d2e8f3d01fafb4bc373da512926eb6cd7aeaa166b4508605e3bf61036a64a93b2aa37211d1cd139c5372f37526be82cf47a5cf6eabdbf53389809831ae723ad4771457049338bd4c4ba06f5e45350a75bbd01d1f7d20c9c5252383152f028f0732a6c66c5f061e9ff98d79222cd53576597ba23b38213c549c48c936b4bc9ffcaf11cc35ed87a805ab36b40c80476da7f627b6842316115948703196880d564943e585647da6df3689b177315ceec5a8c57a5706413dcc00b5ee210c84b4a2232841a365c2d10dd83a51b749548093d47552a1e3a9f0a8feb584b26c06ceb4016d826316dd2a2b20c637ae6b71da5ca4b0f67f33691cd64f216adbb9aa6460c25a81ddce806f541c97ccc9f3c700eae57c196980d2eefcb7819139d58e10fab21ae9494b3f2463f691813f28b7b726473d46b1dd4e356dd2a907b111e87680f7eead23619a70bc7252f34a1c9d6c893e451ce605f047972562226193b2367912da2a134151f5f9f87ad2e03615b2d86bf7bed5e18c698c74e58ce70fee5c4f5c16889e09f45d59fb1dc710839f63de71bdc182856d65bfbd15bf9043153b7b264a7c89785d307879cd75acd7bd5cca18a4d37c6e9fcd3513be743dcf5d84642500f960531da81ce267540d3a1983888ba793c2e5ba54a368a21696b55bef6975e87b6defa8ccb4d879ae3fbc755f39cfdb5f7b938b0b5847f8e53e6d10ae7cad57575e3c623ab7b6252bd6e0fc102584bc2e033ebd4cfc3a05b75630801077edac0f597c003c1545357711c4279960a15998cb8d270359ef2bff81efbf149af011be41dda53e490662179028cb0ea9460e580627990eb4580b795e27c72a4d469bfcf106633504d4e41527308e1693d5a7c2bfed7a298e9ce7012427a4cdfc2d5c061c3dabdd5bfc95f44d70a73d6de69cd5f345d406fa401ceee2a434a7bcab99916f15e70668e75485e5a848e37f8c0fe59f386623291a55b56804cdd5aaf37f87388bea7858920b0f4fd413301efaf9abad8e119e9c20f4e408067345a3c0b775dea0b0a777159484fcfae64fadcfd8d3b20daddb2598b12c61e0f2967c75082d5148826657464bb4b13212a530e471e676e2d27911f2696fcc5844a148236c874f0f92fb430c851495da8b1bf6a8ebe1280bbf3ee7ac86bd85b49512eb745c89481b6f908334d300da6e1309c02b5da1eb1ad4f078610925d7a79aafd7d934306d6dcb2c3cf029e0d2fdd22b20f5a9d7fe8f355b122ddcd26728a352d508f6549d2e513c6242faf7faaf7309af8bfdb4081fb360f7c1bb35c61df5ccdda733ff4d52cdb83425b43a360e4a5b1424b91790bf6db1bcc2555e2c73b40226edaf34113a1c1a2eb84ef5197d226876950a242120bce13a9e98a9cf640e1f0fd74f8141cc1b5fb5999dac502314863189a6f9033a7c35a4cf809998bfd4ce575672d056cc33d52c98c4ec9d8ec76182f535dbae161931ee4f3c858d18114b05bddcf1faf329ba80d23caf91eed9122c0aa703ce42049d98f6b982ba94db740f8d835fad73d3270c4978c4be1bf65bd18272bad4563b2ab9a7721437514964edd2e95c0184f64ab2532f6635aa5ac8dd7e2916ef8166277651aa4982aa4e948342e24b53cf35d6439dd90e2d9c91c6ebb2ab3bfb4c59eed2b022da2bcced1d7e4b80331b45abfe7845a65e69368209675cc194dd3b6a0085ba4d50854b81da4543852c59570164e90a6423bdecad0b7307a98a79c33550bbbeb3b0d368c4c5ffe51e39f70e415fc0644c19dd496e3799bd61d29b9c808db755b6fcca250b5365f39d248432ca6c3c0cf3a5a633f858ce23996b2e3ca47e09a25fe96c8923890f312adf26c0c4b1b9095e039170175beb8f9e6cc66c8f368cf3dfb769b621406b6371451f23c388e5a923e0aff1ffb37fd5c2821567af23478e0490cb25dab77f23e1b7b92843aa31b83faa78d8b51fdbf3828e6760e9fc17ecf4745c91b04b80a9fe3f71782139165058a8e533dd7f664312e8bc2c51662779389ebc0a9c817d3b535367b7b63d0d3b33b8e2ddf42070af526c4fd45090a437eda00f204f6f0aa0096b37fa1e36e168a0ca0646250a4b2cb51889eb265d6895fe47c34e72c5e45954eea054e34f8a17619de73cb9a0b9cef814f415d30a2724fe519f4f09f404aaa0ad33a2baa8132a01d0ac4a5491c389331cad80f20249867905f48e03f1095e3145a87154a6c1e0c5a9c3ab0342f332be4b4e6e05268eb2503975ad00322327accd43fbb1232a95941715585170024553197938dad67524b1d9b8faa22fefc9c835e9002eaa056345647ec96c531eb350cfcfceae9b8aa0ce1c90678182f35f65df602e3222780ba17ce8d227d0031a4954bea165d62cdf482e5330a278b67be7c120336828a63fb23d4c98cb4ed71692c077ac63615e34d62e3dcf1377ad4172ff748d08a623579a80142e4c6300f869519ca557ebb2827a53283b57e182b3b5f85fe5d80d07ac444b5225ec02b6c2e21923434de0383e2887df6fcbc7324695c20635c8f4167317c2613e579f6816d702140e0d0e79fa9887596b9609e78467ccc511915be2261b14b3d2b29830d9b8c1619f7bfbcf2474a87cd8d3f989016f2f63b87ffd5672b65cfa58a5fdc5628914bbe238482a422756f7799ce84097b51a7459dbfa31c358abe94801d2a435357bcd9045740cce1281af30a2381affd300b50bb324146dfef3236ee1804f70c4144e427d5be290a252a9a35237f3abff5296b8c60866b7f388268cb5a17b35b79a90525005ebe8bb7e2eff1efd079f878251968a4d040e4c4d9db9157f2785d4206e7aa19bee9a54f2d3a86ae5162a201b5243298b66c8a335f1c98ef8abc47151543faa5c57e3ad5c1b4b178a9e8f462ed78bb29ddf261b3574853549a1809d8ff8e16dd39a2888f71e496d9a2a1a85dd67c186c85c087a973033afad4704423b51f743e4e54755af9de6f6c7531d57ba2f027c8c32d25618966afffe6364cd23e42f6512a756c9697b8fea9ce64a1587fd91af13b3313b287424386af2cbd970238c32b4fc836681b32d9b30e3081fe2d76b0df02373a512c5e4767bffd78125d919f3bf1932ba455772d1506044aeed0298da49d8934c004d901d5c1176ce7fe3b917fc5b2c43ffc54e32b8098157a9be1265a67461673b1c0dbd6253a760cddd9d29c43eae521be561c16faea19c7125a12c9dcf959cd14203a76d86005f55e3f18cd2d222315059c125e5f965261b06798ad17c910b81b53e32091cd3a15091912e58cd7d765b891e26474b5864bc7349375861086d1c49b967a6ba9354d840e78b0b2e7925c2e59ac85c5c7ce953316ab0220dcdbe5a749d1ebccb4b2644e3a315d7da2f318b976fe0b047c2cd6ddb69a032140e30367e8a78093af4594531acb7f5366781f53906d605454b7fbc1e5135e69dc4ffc31b58c8b481916e58e64d9a4f8231d51386b7096a479a756c93188119981d90ed697b7986d3903ae50ebfb71e60149865b2cad0952fd03c6683ecf23b3a808af9231051c5e41eae648f1a974d4fa60e04edde91ef64e515b8e6e3807ede43e8a23d2c0992b8b044e9b465cbf91bc69362b1e040feea033242bae1b392e800cfcd236b11404ebe35f3f6dccf08bd3913a4468cca0c28837abb3dadbb61660ee1e814c59d9dc4691f1d7cd9ed978030d26b19fc726e8de82472925a6ac299c1a1e2b182ccebba1a52b8593172222eb5e0589ca848ded0f06fb573e85ea446e43186f835edd8d9a13ff76c172155b5e524e79d294718900197077c3728addf61b06ee8a0ffd825edad34bbb34aefaf3107ffb14a4473614adf4424f4677d42361ec27145e99b95b053d03a7915421c397ab8d08dc347180fcf4bb9d20c85ee394255153c43e3d43c28dd87117290863a804471b9524f4003ed809c2f5719bfaf72ff8b54058f3955a14436e752de9ba9827eb30802d4bed569e2b9da461316fbeca5341b9aa25612c4e391e7370f863f55ea3491999de251db07bb1dceb5b0cc05a29690599db5cdcf1165bfdcf11699c44f30135217cae687c5e7bafed7b3021e0cd626c077c842888e74e6a613c619f9d06fe15993be0f387041a9397fa6d661b868e8b396b22ff6336450a09c022c2ea8de428703cdb68b22286b374ba566e6a763fadda4d75c6750a2cc9f4e3db1500ff2c5b4c2ea4f86f236b1d2aa73e95a2e16cfaf04ef7fdccc3a090ab7a5bf3a76067bf93b3a1524b48a0018aee9b01b5a012a9511e82925ae3bf2b18c3a26a9d327d9f3b79eec4a6758a7438423850c0d11b06e57a60325f62707420832e633a0f65e40078800281b2021dd561409a3ea7c38f4b576565601f65979b72518efd671c528cad77d0d5af11bb648264c62f3d683fe47895ba3d2ead46a84520106e057182b9031c431ccf97e9882d8ee0feb1ec6f6b10efd0ec34a7fa7c186f53656c476261236d8ce4d89a401b6790e3681586359dee32c346cba5939d7f972cd38e47da0209ad76b855798957c6b4aba3198f1beb95c7a6a7fb6a59b5fbe756e0c92bb992f2f296cbbd1a884fa0df9b71a7dab72c5ec8458fe1054abd2a02cf800f99c2218588cb3fbac46ecfa46439e697c37b91b2fea3e93ae1a11c8622ad9070dc6baa18f0249d8bb933a78a4aaaca5bc5ade369a6ac5d3decd016456511338f2df1494fc5ed33f690d2a93e4a9b380a4f1a925c57febb3e85af15d44f3c3ce46c1cbdace2b8e6db44e64e78e8ffa6a4a39ef79eb87598a5e886f66e8577c18703e30283b7e1255dc0ab48e6370e2c20d4ae01f6ba0b71d4b52ca43fddc683098ddee66b65de0345b882221e59acadd2bfe41de5661244c6da31d866551412660db3ca106b9728676940ee9ab1a1d81cfb634aaa63db9f4e92f1f68cf87527533256a7fa36b4da47fb6ef86a0a79e8deecc925d21b47dc59d8e76c23f23a5d65da2409c4ee7bb0e311730c2312a9ca7adee42a9b4707a89d36dae3f9fc0c5dcbe2efc4a0d7a62dcff0d4ef5d5ef306cd590a7fc71249850cef0c8994a6c65259bbf38c72b04340b49f94050f5b9c06aec8f1183a85dab6b53cb5c87692656f0425be4cf7737104cd7bc092df6a47a4cbe2fbdaf0e9bb25961ffd18ea524e70ed42b8a3952f76c64f90494b8e64b3ed94f1b025ba0e61fbac3193d510be754bb0bd0baf8ba21e66374840a0b6db950cd8e0e63c90bf5cb1a48553471a6eda81b7152ab915690dc12b2af4f12c53c67636c3dce6205e60d82f6b36aea559d7f45ba27d7c31d5eaaeabfcaf49cdf4d860a5eb9b26555e6c88394c814b89bc760a62a02ffea02bc385dc272bf64c5e2f28ac8c758117ceff2b5a726f813a8985ebee735f56bb09f18fb265cae3f48447b14be5bd1e740110d541442624196c5c5e37d8d607ce0419f86fad906d5ba8fc0ff8e66595a6dc0bfa526b2a8d43a29d4dd83c7d3473eb0b11cfd20cfec71f2a65ab96c4fa1985dc40a4be293b55158781a6d7eb00d7d5735cf247bc72c466c4cd9e4bb890e9f0f0327f6a748c396b1ebd32f900649cfff18804920a9c390f3ebcd4d5ddf57b017972e02985bea54a775d2e655d12776ba4783dd1d7585c5db2eb8bee0dd4da1f20b7bd5312d449bc1918ba22f58589f810d5c043900b4061f1ecd33ebe64a15a97a6e8bc9bc18f8fc4f4914918148aed00e08d0b46a4f553f1a0fa9f14f9df54ec0a00748ee637aba701fc1bee19e3d036ffc0874a403316274deaf20aa15cd3f7f7e5a7bd04cdde435f943db20306af4ed85528a31cafb03d9a11853dba35ccd293660897efe0d7b8f3ef21450bd02d2b4d55ec9eed06e36b680c0ff7d214d2123b4904f96552cde5048582a808f086787df936ddc532911732c0a53f4b0a50c649a56562fc3dcceab170299847249a1ac2a7f20dbdc73b94ea253396d2f73ba4a917d529e573b6d7c99881b50b10913af0980672c4a8519cbe57e0d78f934a6d4582204fab6c6a2b2a6a08c5405697c74b49b3c6a196de85c7bdea74c5d73bd15e8eb3600f67b517c5479f0bf45921b78f04a4ea51a3f2ba2990a2b9ef95630ca5857d32d680d962b66d32a76b4179c8045838d3898eebc95b10e2c8087f5f9c95d0cfcb57350f689593b479f25bede9291c2fb3fe7a343deca772b9b69c401870096a4bade478b3acede40b541a04e9171f34053a1caa4c34dfe1d49beb4acfd2be38cbd144aa3642fcc599c9ba9099fc300c514637baf41d0f36cb22bbbb1e4822f957c5e34b792513f6f0cea95974a6f628c8a395565d700e99a013d4f6767fbb9581f2e29f130be6f5394f4b646b11e885482c4ad4e18e1c18b44b342266e0ac3fcb46fcdce442b09756b5fcf88892ca90b98ee89b9799936af4648f6b2b56a910e3b8e65e5cc4b1022d2082fb3f065feb8ff44d5b7f038d6c4f1ab6b7cbb3c55e72e7daf5d530687f97e7c7dc0cb0d072a42bb3581769d80b67bb2d1f7edcf8727edf3d3e7e9900363165af31da6364858adc687d392bd215823deeab67211c933b9be6f26c671bd777265c38a25e367d1617200b46eca418464cd0b325adb7b87c9ddb4e75df091e356f75bda3ac86377bbb8f0019fac5f2233efdecd57376856d140227d4beb303b0a1f5f76ebf5993de2c6cc98eaba2dee7a3f0cd14162cf5d25947d2644c35e27e940ecf38c565dddfd7f2c441d25398e7b3b98737f0aff85a7601191dcdfdb71bdc37025b68f2cf2ab9d989dcc01648f0088ad8b0bd1af82a706752cb1897f4d04a4eba605d4017ba79b4381e8fef45f71b456bcf9e016e8267488d9760b23a3fc355c2767454c506414b48e1cc805faf24102a826e2fb424c03af8b41d5b21eb6c5fff15234a20648d8bd85164b067fc26aefdefd682b0225e3cac22222cd00cab4ba3bbace159e36035cb4bbadd3ff2416776a11421bc4cc2d79720877f24e577b1a332bf1640972089bfd1d61c24bb872da2067efd0cad7edf0d9d9376bda14fbb6ceab7c026220802b03396c80086e079f997e67dd29b3ca7c041ac34514009553a29ff15f2ed695fc101c2e35ee21cd8fa1ac604637289983a762ffdca3f3f05e38763a939db11e28abaa728240c2004c0ac97009463e894b86cbc93e5c05d9f728a40843fc4dfdd321b9a095956ead66d1d5b04c5e403610df091d33427f3850a745423782d322bab09ef36de462ff17f4e83955473ae8947db07d205ae1b56f5c0b88bdc7b345b2999e72c1034a94ff5bd073a1d86d37333440ce00bf4e6853fb4e68f8b2ac93a32c6c2b596645c7f87be5bd9a240b293a9b406a021114957de757736f5fb8a729b35150fe1ab55fd9406149f41205388c495c2f4fb9a6225cfd6f3d9486a2919dd2719be476e20116a3917718aadcd1e96dd0a6ea53184a9e86b31f97f220abe5df51fe8eb5438d6b238b476312292fca4558509aac6115f2548f55c46c991faccfe4f4af558862b62d753bea2a387151734bb583a5c3a04ce1255e32ff18aca777628f82606da5b4026d347748449072f7f0a82a22113939f0f94381cbf3d470cf0263e1ea37954eaf4cf37f5f7df42f35e8ffb639be021d5481f0b1311beeff655cb0390071e56b27ccf7cf721cb4ce73c59b57c4470877b1fbc646bcbf37b0410744424f8603c5cee2735117d72ebb389ae0be966421791fa6ab607ce1e464677e528ca8627a5a4cdb28e8a2e20b62285af7e53ab87d74b3e574059ecfecedbcc5d7b81a208e5c8a39e63894a96396e6dfc019ab4e3380bb070dd83ee37ef5bcabedc57ce457e6d3d16454e7a0be984815a6069ad556295d6e58bddc303e280733c49079753ecee4183840c5529d9ddee9d53e708fb760d90940a252c33aa19d0fdb86d1450992c45effe7f01bc6567f00504be5d239f0ebda2bb1eed33ce221aa2985eede2cd4a4beb2b6dd20e1c1acc7f4ca55af8e004eb6f8c713cb9bad0b6261aa7324321081a05203b6c656ad1e2a0fc1019525feed4cca69a5320e123ade227d5f509f0a5227a290663ab880a882ca346fd33e2df3b796aa43a7eb06f99ae8e713f76e4a6edba6b06b1694416eb3b3f441cc07d1f88c4703798695b37aca4ff0e252cc83a2577c356b8de8d1922c8a33a02b2e12fe635727ad291976c3b9e1ebd2a09eef53e51caed267ebce1165da5678b53c7f0a3c5b9d32c14dc29ad779c65246d417c10d52cf6b853c2983824167366896d1067468993bf9e9ab3efc5b93af1dccaf1aa26fff8e7f9cd8f2e4ef0dcc10931f8211b62822f4b4b46bded928d43b06654174cd6473ebc5dd362cfb9b84077e633706d145e06eef5328bc820dd53d4d991b9e07477c8d02d4fd118ef2e9d4499a048a4ff60f7134bd5fa7302b0ed4affe2a53f02add92051dd925fa84381126cd116cad86bf12675c709c1976581dc025f046ed38641cb29c886ddc25a8e5271022706fd794210a490fac13ad55e636df075a1908b4b0006e6d4b8ab52202220ad59ffe091e74a9098785350ef438137a9ec8a8a192b5ae29d438badebbcaf92f1e21bdb64cdd5cd05967c401e42d566bde25c4ef97ce1607aa5d99760bceea7bb6cbc04100f99568025f375b8fc5d0a3fb78714baa16101ac0a266a1ae8c5e4f7fc9c1d30bb6826f52592dce162954973891d1ce065e61e0916e418723690a5d60ad58a054b1cebfa3b544257ad17fe44edcd3cc988c0fe06c0ca77c45bcc7d397ed87ecedc5219e3bf07a31ed138fd797e60d8d4597a8fc06b14c2a110d395676937fe085831b5e03d244ac5652f3793dd460e0649c17a60e36bc5b2b9df46b8544973665dc3d8073e86b85b2832ec4ce647dd41e8653a7a2bd8018a4205e668800c7234296da9c9bafa94d5b43eb196f33b91689197c6684adbad40a1af0ec8be2ddfb497ba1939539017ae66d4f2c08e387386208677b7540aa4fe48ee6ca832b7288a011242bff09c8e0f74a8fa8e9e6cf78fc47f44f34ac1a57c4752d16f0196756444ad68c98a2d62fef91888bd5246d052343a6abf0e7be09b241c5c8f6f3dca22d3e1a5eabd04339baedefa2ab6df17a905284296f8f39c652bbd41515aa90442658f90bf25e93a21ba71cc831a7eb3d594ffcf9b53ac7f3c959775878b0babe76fd5901af49096ee328c4a8574e0613ac13a0ab2bdc446d8a1cb1aa37c97eed60bd9e5c6ce47c4a8e7b59b7778078d5c34577cee0f61cc2b53251bb2f3da6e0f8e558cebe0b2c93fd010b0b4464531ad3a596552e93031d51111ea411a607c5202249978566ef78e685dd3bcf1a47d17d36f3cda8f6703cd926ae3c8fb8931ba9adb66f4af4cf4b86277d73297f640c8382479cade3739e8cda3e4a6f6a455647b5e1c87fe29baf429619788685ebb48dc4a7ba28f286a85192978ba8a8450dacb327159f7651399594fb3836f999be5b9b8974dc962bf7fffb3f6cf13e5d495eb2746baa5d4691564f8265217c23ccad3133f1a7d25d1b3522c311b3792418f8809eff1b5a9fc40601836a36b172ac163202bb4a93f6dd17bcb0e330bc594dbaea44d3fd073f02f26da72e5f87ac09499b597ecf88116af4e3da8087a108a63668aafbb5f346def153733faa1504879260b80cf8e0f085794f7d5e1e50f3f5cca222046ee138a964a5c3fd3f82130338196fe13c50bdf239503a81fae1cee2a7a40e58f9900a20e4796c88b758900e4d3546613597f0ff95003ef59a594168ced4534661cfcf48e64cf0a972d7928c2540d327dc1125d3cbfb9bb2eff7f97dbe6d23b134d89f55360732b0238b31328673da55696cf2d7ee56b1b261629db00bf3bfcfd04d40758319b36457b660b5db0244d1511a4017cae233ec9483dfcebd21194200e4430b56791f9b13e1b819ceb903761ef5c67ad283698a1c01c7bf2009eacd42ac5df14f71afe29e59480a9a8f1bc13682646b2060093ddfbd8ce03112b1c2fe7707eb697a6cc90223b94969b6e84e3a653fb8f2a3340f643dc18683fc1a9ea16cd6f37c51df87eb43ea52bddf744423a68e5a8c5c4cdf130450e6c2ae63b0a7027232b6914b4ee1652f3181fa62b58b7efb3de550695512f78db02d68d83b858a048749303e1bc8a95296a9af37965ec9d3e4832be728945dd58c3058c0c4e8c6e40c27e9c0535fdc94107e2f3325975f5307726d52944be357b00e40ce5010c756f2d01aa36d58d5db9b35da6d68d62c721fa2ae9bac2dafc7974688f942275f13c6922bbd6b981ad9bbfea7a836d5a1953861d02515efd52efe978d7be9986c9f456490a20e78c97f298baebd656d32ea4e96c8c507ea6cb5975f74ec450fd3bbe26b2565bcec6e259b5d4e4a367a6eaaa6045c58ac48cac6f96400fbabf4950d948d74c83516a8411fe0c4e15a3fba80bc2895e9acd1f67e4b48b86a5cf08a58bd730507d57e711755e3736103421f8f5a1fb0481efd1eb91b2670f2943d9cba62e7a8fa8654f2f24d07426630fee219c9f07cd31696c7088f2360fe855d42e05ac40a3d00048dbf12bbd8e3e874674d65e3fe84100d25aae5d9c584cdc35bc769d1312076137b4dde73c9a5ade0db457fe3484df4cde78d287a244cc3655af2f8a999bd83b593725755452cd45c658117030b5d996b7542f7345dedad4f0d2a75bc73e82b94a3dce561804670515add85a42960d9db516ea732bb197eb75eaa7cf436d60d8de06d579608375b9fda8311f2210d36f535302406072e1df44ea59a2f9a189b4c0263ecf000578f85588b276c60b2390d5569d9b203d9c486bc6cb98f037474ac736cadfa3b67af4ecc6ae38e5b9dd6a9c7da6cf1eb46d844d232dda90832a9e32cecdf2b719c35598ce7b41224b7ff3b3407fb8ec30add8e6ea9493e527e49a0d041d4ee7a9fabdb4bc87c44ab8c6f930aad1f6f19ea76eaf7a7c2cf205599391acc172c4a3f0c89abb3ea5eead14a4d582c99f42f2c5211c15d3e6f23644bfa6dcdcc15f2dc39016ea13044ec09ec2b3a53d496e93b98f011ea535e9629b757d9928ee6661ccf423606e5cf22a068af9f6af4747c636b7a182629fc94714a5e42f1f7067c059fffc7571686ae872c5138667f39a4c5cba96e5682347d105391e14d3751a10c09e0b397be6405723f11858d54ebed2a591c14c80e6a217a8f8b20094019773841fc9a7dae493b4bf35ed47fab7bb3aaf37714320dc0a1cea620526680002f953e29598a0d1d0b5e63aaab0285356149f191d01b8e99ec6ff4a63473adcff0382c1e1197856598b242715ea5c0155c119b44346207640c660a5fd064c255d6664312f8918aa4e6797f6cc851e5373b75ed24d08a910cd09de6849fbdccb413292d50d5cb4c0011a6771a0e693adc92606ba336b191b169251fabdc785a4bda4aa67999ab1a0406361089ccb5fcb9c551330260013b531d8b8b396a7f6756131898e2f4c6f0e1e2569b8d5a79714c7833d366932f5fc92ce4f66260fa18b501a090918b1ffb0ef47bc3512803b20e37427e70525d2e439d8a245f2685108627775ecba504f86c71a007d6621c65898fcd1ff14b05edcd43d892ad77a2c282de470a09f9c5703dd9901b3ef543fc14ee3e5e381fe11722f751288d7b9a452f72502e08cf8ea1a5ce167fe4759f3cd9b891a0c49abf606a50fbfc8b6b8d6ebe543ba34e2f7f5952b513e041c506af8e74f1bb872a59d5eea640440ee411974e653c56462e2b384839daf41ff6615287cf8a4af52684426a37c6a057908817e0966acb7e294d10d9463994af493bc125c58a348bc327b26c6580ff71a7e28c6bdd53216d1c04d17213f2c5815a86c44ada66c82aca43c0bd198297cdd69773f8a110a88de6475b2abdfc3e922dd9023e373eb0a7a045e6b43595b104e8663cc092480ef4ea37e37b3b39bcc1820904da1c0672ef2c66f1c7a2a93a945b19c4d6ae8d9b434a043bbc253c409ee7d6ff778d1c4e5fbec26eba1a3ccae4f75f8b3bba84a161f59d62da8a375dec0a05e098229a796bcfe528519bcacc8318e74f863d4261c44a791a6279b6eec93962b462b1e0dc7644c5981132c8020a4c63566a9ff3e20cba28abe88ca706e0a29995ed1c4fbe850faef5960da20addb9344811772a05cec43d40e0c60c680bfe201125dc3ae2ee3ce4a01610eb1429e1704c528c03b96ae7f431528c9c28b6356cdbf285d9f1ba8d914b4577076a86c2408ac44eb9772cfe039a6ce48020798423d78bab0aace2e3fa900135e96aea305722218de2fadd8c61e874847ea11a79dd88249c078a29491609e69f30d67a777a2e35995bad3395d266e502fd387b728d8025d8fb660012bdccc01c692ecb9e865a0faabefccb6c2bedd197abaf237344f68399bf9abbab274527431e8eb303d934b0dd4f157a76f7a72ffc830ac28511d1ff1ad98124b8c3823a846e6b6744ddddb37f5097355aa24d510b08a2cca59a0af2f87bef15ec4a254c54ed885aa996772a92a0fb028a10a4482e2f2aa293064b91506136f50b0f22177afb7ca19646f3d9c06905e678b0b437ace7f91083e889b0238c847ef4172e0861cf630c246aa4f307b7a3bfd783438169eb275872576b33231cfb0ccdab6b9e49207d0a37c1594bdccc9bf95b4f16adb91125b3bffac296a0fc3fb849798f40cc8d7c3e74487b6477adec389e525bb059cb44ec990083d650bda3398f2670f9fb92d253832653d78e926407de3c0e9a299c85f3e8e711ec6ca2589be256d437ce8aa0187cfc7385188551bcc5cc35bd4c4aa3a771ade67c165461c5cc6b8044ac17037eb60afdaad49771fc74b6774f590345d4332a35a193e11793a533dfe488798ebdc9f211d6db7508789d43b5222b5835fccd92657cde3ff5a560a12f6539ef49976f52d264952f9b5c93ccf1448671914d5f10cafa084d9d943435a8de50866af369970e9e9e175a174c2381deb188241b77fe8ffa84f983efe61e799c1accc361826501100f8ddf824542173c1a09164039e164d6f9a89251502727d6ed099bbf29f705305a0cf2dedd7d7d7f809dfca8994bf6fe09875be5413b91264d67a1a13cd79f3adf272553f227a5d5e7ae80e90b175c5de10add46d8758be6a990d5d793fe03c18b45f8f9c4203e9d47d41135ccd713d016cf464fae2ac4841550f2e6b6b50764db73689f3f0dc12d15d5d646b645e4417d66dd2bd0cfd1a5c7e0ea148906beecccadb2f5161e822c9bdd2104d00db0c39707025c6b8d20031798fd69e347ff14e6091c050f32a79607e1af4a7fc75e4b3f0d160bd0ef65aea57083f80be8e734dc9b4227182c86749be8e63c0ffdc3e8d53bb1caaf60bc5068111366c447ee6d804f27c1e08f8cc97b43b20cd0f92f89bdddda0c986b3222bb6fd3492150d1ceb54c07450d0c95c0f580b299b012f4e2662401cbaf2e7164c44cd2bde758a5e847ad8e610e653dfc26bb4a8fd0f96a5db8c7f6c8eaa73d377f290952616bbed010c70980a32ce9c08ba3713bb2f0555e3eba1d8dc0fa47e3187241b2c81c8a18f4229c11f77b4db45e1baebd8d68160420072048ed74f2d1934d89c796fd37f627aaa7cd28be85c42f4a04a698c520c134958b364bd1e0c06e531e2baf66cb1ef9a5d3308ea4d316062d3e4ae5a70dff7690226f1b10894fb06fb0016c18250e5d18d4b1f468641a2bf7bbc396268b1d159192c70450882772557988484e14b2749ef6a6ee2a8a02599434b4fdfcaad44e17dc37238776545e166978f90e700e9b32fa7b06a3d09f87417eb39a44846761ffc71ab76340944efeb725fc2454cf7cd0fe8225ff392481dcf6166d5fa6c664c54318a717c73dc823d17946f68e07636d249c587ece561ba8d511b3a7afeede4819c25f932bc82830a94e7e7ee769705d5676fd237cddd9dbc16ad74675e5d95955559adf0ba5add49f56d57fc21c328d8551b4e4a747ca9c8adc2e58955230c3948724f65a120310f8500a77682555bab682cad2d581aed8631dd5b157df5f5664fc8dbef8cf9d05e8b52f4f6e716adeaccb9951f44150c649ad9d15a3a40a8e74ef3a90304c440136c6aa1c91fa5520f461658fafae160de8940d3af52a14b8bb69f8995a0afa852bab2acffcd53671f8bbe16f67639010b1fd09a3cee7995bee7878f86a937c3c2c2ce7a5bfd10a12f483ec6824bcd5f29c46cc7a21d21462f2e8cf0459c2514fcbe4c9a267c79b23e4c77ff8c1c4766551a851f41d8aadaeb6d85a885598317a13060e510bd5cb91e2554e72abda93d3c30691355daeb179e8018710c16d607f561f19b50f60e7b35d03d57f5efc302bd8691b65dce2df46b1d5c8ac06f97bdd825a921c311b66031a2ab1b741c95be9f03d8240468bb379cee7c9bb7bee5994b2a7be238696c49368611bdc0709710fb12d3fb282ab909c4fdf0dfa8b78940d0a4dd55e6fbc9e7f39d6fca1f34d1340befaacc29a5adc51ce72d73b646b8d12a5225bb2add439f4d7cd2fa759e0c191b037828efbb6e7393ffce97e5148f6d9f32bfaeef2283b92e939e6e99cdb54ff52792a9b720c11d5dcc7981b44abc696061a5a2c48467dd9952f48501828601ab135831ca33fd50d376ba1303e40f3adb4d8cb1a438e00f954fe4457e31e82335e9dd0949214d6868c218f496e7ab224917d5bef9fe1edc42e0b2d2b19a5782bf1e8254f01dc68a530181761bcb0d7922ae8b28b1372b880dd5a2e89e3b9d250a66510288b4b9d825f2cf090f5cc692b3907d96edb12daae3dd46241c422f3e9a06ac242e1deee9bd14cef71a9d3b5b91e34546ffb58887f99308d02d93faaaa2ddf3226f88e5814577b664f8846837216107a717a2472b3ebb31f0b6641dac7bf49fcc01383c6600be011d001db6e33d818039994094126bfa80e45ecee5c3137f8a38be79a13b58b966543d1ab8ba3bb5c0aa87052535b87d016ef7885e11393de9fc02fdf0d7af1bf140047ee9e4363de3096e8c0cbdde7904786827e6f7a6fee0bf5bf16206c2d85ffbb4d5c44cc359bfb2a903931abb452bde3cad7c6b4a3fd5dbcc3e215f1f6e9919b3280d0626459408023781ed346bbe68fd2a7bb8abeb187cffcbec084b0bc46c9a16920e03365d8e33720a1b3c7dbca0cf633b57083e2617084a86e73cc9e2df92c15ff65b21c413791682694377e3e4e5d6a64dba185a5d271048606ada3f0386d48ebb802b559f2b9b8ce2478b66718ef6cc65fe7a75c7320f4ce0f59c2692386e3bc1e48c7a89e3fa0bcad3a83d777f981d1c48b0597f2db159b7d925d0b0b1067664a35458ee734e77b15c3fd152ed8579de9020862529f4d2cdf5988a9ee9fd1b6787ba87aade1becac81d45dbc14385d5fbddbd6ffb9b634463dbe4430fe646fe149717fae0a853d37d73f013cb45398e289f0051b622e2e95f60daa99807384198107dcad20dbfa3c4628f414d24a1af05c19d754dd1544e3eb0a926f6b6f5ee84afd3f841b3cc5cd84e3f7ac8bbd9b4deffa71078430b59fb971fa2533f5638235097bc131d2d9ae5dd24a5a9ef66bc27b3d523adc040c65c3d67275133463ae257b4011c4a306f02ed9426eeced852daf744145093d2f00e49ffaaa1254edf8d0ca89b1b9f0d6bb4c714ffc0eed05aa429c990333356b73fbc04c1606e6fd60665af331f459139b414e874f2d4d0fef235d742f862f94c98eeb11929ef35c2e2fc8e9630dc4d1eeee9d01d9d35fb1f88f6a5fa6b47d2c0002beafcd5d8f29e4a8d55f91f8e54985ed66f73c138decc0ad70692d87e2b8a0ea0a9eac56d625979b26e921301998dd4c55d793cb6afe656419c1e01bfb2173ab1834bcca6adbce43f157b0118ba78c8770af814cd5f44590ca45c91a3273e8a35e22d3a615642d26acc2766ec99d1d7ab4ec8dc28b40f2e476d551bb74a9005d6b45a295d6e11fbfec13d54007bc8d4fa94fbd0e1e55c5892bcad0c4588739914b9ef6e796ca6edfe1732f007b3c3fa133a75bb4ca807d3b82e2dce26312e1207031b8e95c9b3a2971a8fc5451cfcdda050ec9e9002d07f7663e037a8b0b39118ffe0c2da986d5eb6237e01d87ebb8eb5baecf8875a66e82714b7aec3e737846cf1b93b39d119b98d168fac225ec6293bea9f9b7543b9a6687f681f650e43f1580eb400ba7dba40f6fa1cec80461a9fc2e23486f32a34afa5e7283fc59bed2e3981032a9dabc4d812b5c1d32edfc29a1db084d96aed93c0d9c595854b106bc97d9b366b870235e0d15ec51d2c6db0408d557894af1a2bc493bd6c61de903d8f6c2a0a796e9d934e8987c644c4d83f840a0b9227b89ee44266104ea8bb5d03c0bbbc547c4fab3bcb18f69bad64e96f78d2503a716aeafee2acfc659293bb5028ad81eec5a705305586e30e50e22b9ac03a08ca872b8f3bd1e2fa65cb0cd1557ef905b809d91bf07b60a5972f5ac9c5425c9c1c68b651f372b01364f695da512caa8fdcbf9f3ccdb6023d76c1d5e93f9fab39cb11296c573210bb88200d8e5cab94eec0dd64e9c074ab2058bb8fb41c1d9df1f9f84642dc688a52878f4f07b41a303d09f292d0196ebe0522e522c1cc11b1a49e0b04b849d3a7e6d7f8273789b24424ebeab1871e75f4d466ddd729c56e823ed588257584726a50693e71be1c8a056bb3a957d903523ad2b82ab894181f7b6a2b656ace31a4ece70101471ec6b9594c913d3d99bd8866960247469f469a62fc392ebf6c55f610ef89de8be05314770cc4e5d453cc84fc7cf25000a10ba1a09c3586bb2cc795f9088c2be8c22f59452297331da6373b29983477c9d2c398302ef5f93d8f34bce144536af99455346edaf8b450ba7cd424d36c5d28d7b9557dd300f71d2ece159369ad3a89849eef017831173848967ee4f2b3fdde48d58fa3c45de125dbbbe60004c7501c4ee530d4aba6e1450dcfe34146f651668bebf1d62af42c1eef7b97ba7a7756924ef3bdf0368f74253e5c1e023e084b8fe450c8779ed472ebd57f253089ceab70efe2429dd4464741d81473418e72c8f1475a49a3287f0334773d662ee0e34e8b1e4b5ccf8b0611f201a736b481ffcd6e137b1983eac9f94ad7552f6571015efd6b37989a32616c660b4135024e8f17f1850a1012096edf4cf4541265e602b023debb6217b608663c0b7494f9b05975467cb418cf6f1f621be05134f76bfe2b09aca95cf5cd2ff8906cd9c235973d2214fa94cdeacc8303e74c435b883c325f6957053fd00c5e7669ae947972cb6e26674f304e5d3b24289e33e212290f67b8c7e1b0e1cd52b6bbf5c4f9407a2ead901274540e4091afa8ac5861e7899f9bea27b352617ca3d760303440195d9166c2d47ecf94dcf96815da08acbbf6db2fce681b7ea9cfafc7b6625ac43d3d6c7c59db3a7a800ef6690cf246a9d842ba92d026c8ca158eb0f30792cf0c87552bf64e434c57b4d1c440b1d6573becd88567b38a698f471d6a1fb484ca302c605197600a23b1d3f8a89e8aefb4cec68a7491cd69d5c48e169bbcadc35707ec6084e0adab0c0a3133a2e69cd89fb2891fa7354a5eeb947c290e20239c5ce47fa94177834a0cfbc5314a28730f76848a89d70c7ea25c33bcdde8a6c62dcb610c6fa9d13f35b74e336f104afb91d2f12dabcec727d83f7443c37a3d9c8a2fbe76bc5e2b4770692a4d1ca80d3a20044d89cc01401cfe144ff9978f44d80146e91c4bfb6c054ef5ae35c268628e131441ca2008bd1644b97644338700eb01fe7bbd5a25c9979bfdde5c2a7b8747eb259d08d942ba63943a5dc74277c7506b1a9f74438b612b3399afc83b5f1fc4b201187c4fb8373bb5fb40d5b0a75ae2f7eb3d3466f5c1204a7940b61140d08934c2be2b4fe8f25a81be14cfe8b5c97bb13accbe3f0cf4bb0b104179cf60e039915afaa31eb333e738f1add1a7d952caf97bcea7ad6df4aef4fbd0709c6f494f5d913d78f7e92fc9cc1c6493043c12677e96db98977af7eea65d0b0b71a50190a2ce80361324bf51f352804f2a2f177849cd85ca2f937d2aa3065e9fc44805e2fd065718e5276d55d14f633aab77911fa0c834a3eb62ad35944e021dcb242a67baea6c72b38d532475daf5e847cc130b2cd3af624541ff553b8f13ef65614d51a5b62fc38d7663d7f5eec7ffde6e017e903b976235cdddb8074e3590b994b32652ce5445fbb6dde04ca117678103a66ec30759cebcadf5c62f7746c400a55d91f3d0768d58ca0d313ab1acecb8e719c3d05739195aafe09ece5d08fdbaf8d848765395c01d53645a705bf85b42d659971e47cb83f03dee627c9953817c0daf0be480355bfe19bd3518b975d0bd982fd99acc3964b6fe9918dd60568ee7c14a07d7304b7297731062232fcd6437449541b1559c591c2cbca02563432cbc937e18f4b46e1167611b26767e38a2c83d3a9095f18f5edc6024a179a7766e4cbbaa53752b6aa5cbb3fdad46e8d6971a97b8c92723f75e3e2e8eb23911ab230cfd1f4c105b1ae11c0836b1713cf6283024337af0d3f3b228a7c24473b06aaca7f40e4dca5f110589fd89a3cf11c110112a30c4d4a70a97d7f85611602a6f52cd094fa39cadca294a858ebda3046302cf0c9d53774fb47ccd5567de78796d4840baf5deb933a1c94f7b361f5f2023ae4021ec7e7bce2ae4d8652fa8b35acf96472458fb7484dc075cc8d10f925c4dbd9f271631494f39c81df9438ac285d9ee9318b86c8d8aee6fca2ee3c9c307fe7ebaaf905baac5fbdedb6fac300332e1f6f531ca0349c52cd50567631c2c5eb447bf7cfdfd86bd99fa5eb0c08f0779686e26503e40bdea416d7675d5271bf7b6359ff1eeebcb175964830495531bbb4ba7ea8fa46f4ee1ccaac9caf828ee12ef7eb9ac507e3ccb5aeb37d7d7306c78bb96604f660372353227b12725a1f43610b73b4bf00d19d83df4960ad8e9248491ef0464c8c41235c850b63820766a32fd3eec388bec29570d82139d07d273532f1a006c4bbcfc294bbfab3e84b1601e96024bf3a8ecbd020d46eda126b2516048339e266a131ccd5601bea17379e55a98c4c89c2e9ebf264bffd806513627426d6edaeac84554202f16596e0a1e9c9473c99710dce43d2d99f36976c4385f2e1e31c8fdf1ee649c7ab6aa52212cd238a37c082b8cfe9847e1b5dd48fdb434686f630aed0ccf6cc2c731bd53147f3db76537ebcdc1e5e29ffd702d90f7aca6eda85d58e973917d86aabba97d97a481f938e468f689017f85414e0a471bf2e3b068c85a461b1ae3ebb67bd6241c74b5b7a374972a73a9df61954fdbaabfb0aeab9797b1e776376e6ea6779c6de4a67868ad2bc4b51e1b2a0abbb15f590ef978980e851ad72bcec395951ec451db5ff5a45ead43cfbcbaa8ad7ff8bfac3abb34c9215c75d02467e004e251ee8c8440cc12a90b5dc56b05405348af8302966235c23df26a8d8a7efbd090bb96a36c1151475a3e4bd502c7d77be530cf3b42555898460313b6d1a7bad3cc4feaa955b3993c5b0ed1c0799f95f7c50e667e9d68c289273f5a9f8952bd9e41e65735534a2b1cb6ef20d4192ead0e4581ab10fba2b1ae5c7c683ea6aee3175a86eb34231f89474dc0c71fb500e6359a3982ee00849054cb72cef7df3560aa7425510ca314982739d015f814b5b5a8de7fa8155f98a2cf670372484ff0660ecc26eb432be65f4c81aac8ad678dabbc7a7c8f7cb076f048c4a4083abad760fd3626d252666650ebe97381ba98e847e35dbcce0cbb3f5b3cb1efc2b0ccbd8630d80c0a811f2d320f9d32d4e2bf5281c6ccf75e4bb9ace8dee1ee0c954339b1bbe8d6d473745459e345064ce2c2fa979f37232a96c75ecb655cfcdda15f7a56d6eb90c32b572d506567e717c22648c82c13542002f4d54c5aaa562870fad3ad5ec3ae876ae32d0a098c2ecd96dc57d95d0e3674a60074d613448ceae32b7aa244ad7d853d08c26d5113e8e2cb7414a7a72bc1bba59f56edc8c0356ac70489d0e9327b85091d692a0affd3b8e0b87bee0e238090be2d1171492d44591e1cee0f14b2a6c8c3a6b6ba6165594afd5a70fac31e8d3814e4aa0faf0cf16a44806c095f79bfeb159cad1682b0ac2056c98a238b049400615001d02d306172843045b836b86abcdff4fae3962f2534ec3a593f596956224af31e966c5e9dbef7e1d6946797941bc2fd0f9fe6889ad3b4ff6923a328251315c25c1cb9d9b4b6aad57f7cba5167074684e75bdc3569f14381d39ed5c8864076bdb45f9c1937f02bf0945da2711e4a76884871dcc2b0af8f0278b6d1cf6796f91d78f6695982b6c8696dcbb7b2dab9104556f46ebaacf2b39dda64943a7e691471db30aebda61a0827b9e04e634f481518c350640d4365b3263c59294bce18d58261e556ea05b9fc6873d4d7d6771a680c669b8123ed431c0c4eecb92f50796d3f3d6ccc77592fddda251394299548cd4498dd5a44ec6deca615d7f3ffd69eb887a9e95ba9e7ddb04aded7907d7a594aba1b9bd9c1bbb8491456bb640978e094ef90e353b0a017015439c32066108e141dce48a238e0c13124ee7c89746f0bdc52bc9629ee9daa5b2cdb8e52a0e5d8f8ab0062e22d940786ab1fcb340024685e4663968140d27d22e5deaf7f37b28531c64ed40a6763c10f07b6d09ee1f8cf72707f27a6f306174370d7a3934ae9e0875416141230a5f89c5dca3b0a3bbc7dbad79c7394e8bcb1a69d6875c9daf3d31755a0cc413c71961992a122729a31c99e1b9bdd4a7c7cc3fe2c9c0c9f728531fdee776091e16140c38ba677929066bc2f26c650bf24e72b4d6df0d408dec789389704b58b48bbd4739ade47685c3ab414037dc15b744873be0ba49ee0f3e348c3a126cc4e63e61f34fb8499d1150cd2c57f70d5d8bbb4df8f715a382396f757b301310d6145d708961b84130f05325d12981ca1be599cc7afbb674d81b161786fde767ead4a1511db3270db86819266a1e782ed82a6ad431457996aa213fd99252a12a37ed6048366c0714e693a5bc82ec5500dab05361cef4de8598ea340d3193d6381809aae5f492af81b9885accf67850621ce72c141148259eeb68017915b6097193306b87c5007894dd269ebb26b923ea1f8a481f9927e9baa830edfbe43b1a2fa852305f78d7ec7cc9db9cb828d51973eabf47820181219c36e4b4f139dfe143d5541eab38641e06d6cd2cdc8cb374cf0aa040501ef6eab901c74cc6474f1e0b2f9884e57cb1f3330e8d14d8d26a1b8ea2447e90316d240250e599c0de52d45f025f3ccbeed3cf613355821cdf8dadd66dcb421a72097c18c6db139629c81463ef16c9b8263276e03d3e0fb90a4dab24248de1dacb2632f9e2a89104a1a32ca51d9761d69bc41c411bf7677ff2dc25ab2e9a135ee5596419fcf0faa9464fd787bd0857bea3971a15e3bdf07bcd00f703675febee836865014b61fa21a673df9d8b0ff160fd91109e66ef220cf4b36ed020483810700042ec207b1b87be645f22c7576ecf9d9eced9b29810ee24c4702aac0a5e4a32fc8028917ab684d8e7d66e12d5f090485584e438e5514a06a148d8e3eeddcf526952b5ec60c83448563a3fcc20541e8824604d82e0187d664815a88e8681ebb23981d73af36ef2c8013efaee2373fe0c668a96bb663295da9ae91ccb2f1530421080cb78aea59fd8c684ebfa59f9e7368501bced571b1a1196ae743f483efc121f873a51f98b7036a0251068b8da8189d9fa458af3cba279b2d37239e0acace9857c557cfa5528ec72e03c1e36de5bcf6dee2fe93fdefd20594824c929c4181b5c9fbc927ac0bbd8af6a77c6beec75dc65f76a6d5edec9c00d9c630555557d10576d03b2cdb9f5e474f135a29330e5dbd83e56f6d84ac2360a08254b1e81bec804b8d87698b6b4d74d92a1ea357833fe5e8505f79d4bf8a52009c89d407d2bdc0a601d4baea7c22446fee16e5a8653d9987705cded73463295b820e7515d19c552a279a142f37ff16eb3a4f9cee52c8719bc1dfe1c30f8b675c6c2a0a22c31dd419d192df0eef48c2a13caab140e87c67a2ec368ddb27e1b76b829b78c800077d68ac0a451f1a0aff878f8130d89498c5e5b00f26a964b975d73795afde4c10bbe2ce30aa37a95536dcb63374984889c8d99e54ee3f12ce441f903d0a075ceeda6882925d26c14e12e149372f40104f96443e94e6230d8f9bb5bb01a175db0de6314f59264d3e9f23ccf46644e50318930059372d5fb50f47305a930ab8edd51507b96a630c89ec1b09ee9af3e46cb1341a6559c2eacc52deeb963bf11b0b5e2bdda2401cca2b4b740b80ab5ec727b23b64a2f845db0eacd5e6b70e396b89cf1ee6ad790f3cebee5a414ce77324b99d83501508588689c5e54f80a26e67d5cb0d4a2c92a970e4005ea3d696f9ee041e37fa666ad29ba2cae874adb91d016110653c32973e47043b65fb70aef0c1801bf4545bc2a9cd715c13ab847fcadb7300b25ab832cf89a5644ddf951b0d0fc6b9dba2dc90bdff4e4f23bc6527449282fb171dcd9f540b693b7e9c9b7d2567b6f6bb29600027795e9552cdfd9222e9f9b45cc23fe8bb449150d48ddab838288f0c6c22ea2049a8c4dfb948d13ef1f374efe9266a83f57e31b70cbf782938ab0e3b0cda5a1845373836c02979ff4547bcee9db73a166faf4823306a69361a1bd6e67c358a83db80f760483bd38b3f98ba6f0d5e372a3b3b3cfc7d4f590753a4a2652190da777eec21c9fb0957b1ed16ca33b8dcd53a5b64824b865ab84904a4e594f58de0f5e434402062f17aefe5a8586dceaf7bb9f356e411c89b8761d9306088fe48b26449cb1da3582db37ad0a6e453eba03144c1feed40afa6fdd44f5a14c78e4c6eaf3e44c41b6e9d76802c8bb927178b06b64a1ec7238e75025ea8da439faa30a46c7c73e6f65f54777293f45b79d2b8f2ccd73cb3d3b807bb2977853f1621418d8d46bd8354f5fe527bfe8209def4ba7074f23df7b909844e564d670a36a79afb0801c575697597ddd3248fe673b6d174e3b98dcc4da186a4669c77c2bf19db11174deeac4a8ab7bd68b1f972398674bd5e11944c76c7f8a1c2fa5128577e75246434db2a6bd3633b0a0ed998fe63dfae8e1813ced4e74dce590b31bfbfd180665bba5917afb029d4868af2011afbb5c2853df5e32fc2b411cff632f33329555cfee643edb4a6072bc306f8c2e323f20329f947dc37dcfe7c7203b477b11187627e5bb5cb3a19da81c77e1d15c9523e7d3352e1c2a8cd3fdc419feb8473979914a1451d127b38245a20cfa4a730227691e90c00f9481ed358cafeb9abcbda4d31b605001b8061fbb29eaa69600f1f61e3802549d563dafdd64762b2d8dbc9fab8a95bb7155db20f0bf1b3853f16028bda558e74c22c0216377f9f38a04b292a4edffbccee6e129789f4082f87133ddd03fd0fef945b14418145a2d249969eaae4e87487ebeb4e919d7fd7801b3919625f2b5122b664c007889c3ed18e470a85a56ecaa07eaf441a4f931a337ebfbe0775f379d400270b00d5aa0d676accda4d4c25a2053d237def01edf3546d5dc04fcf6b74df47a2597f0da4bd91d0fa22a31e4c80a201c0ccaf25f29d05b4dde26a4ce7abad9c623493136ef69b6996edd243f7f5831077eb014abedb2717522154f51745f7a2b168777ca5dd372aef67949347e418df64453da75bd42e6a9361603d4c31d6fc975aecf6ac640a030b041c54bc51a4682d6a061469c4a3137530bf3dd570f5713465b4f1b7997b68eec57bc07ba9986bc95a9218d19f2e7e4d0a00b1b988190027938d4ba37c82974193af45c7da8655174374ecf92e5cb09e0ef851f12ccf24cba82c5cf4f3a1fb37b0b1ababdd3d2ad9c5d5af30bdb24da974f4fadde2933ebc043b5b4ba7a03d73399b9de40259631ab603e56e71ae6d672ca44acb638a521f3e4b5d2958d1099358e125e92ed7222d8408ce5abf39bd6a366a9a2646c8068d90746670bb17ed8fcc121a5d02b0c76b215cba6e2c52558f42183335ad4fd20b4a5f92b7bcaea8106782c97d8f6e71b5be756317614f4e286d1ab4537d365e8a7cc6f88d8bab847eebe4df0ea17946e84943184984e2fe005f6455522bd440cf0c63d17ad4394d94e94e2b271e923689675fedab561c7fc174bf68991d2c193c19f58aca5d0ac7843fa602989b3133cc06fde59ca414431205648e17d0bfbfa443b6ffd9e86e136dfa5908cf897e12b7e135d231e03665aff3d2a07ccc0d610a673aa18a11096a3790b3e3b8e2093a9ca9a311b3981c8a9c527498967fcc4d6dd2d5a24fdb73dc7c1e789b609b1a98785b531f5e0654f4b7a6c3b2a7551dafcd2ebe31501cc2d98100fae411c2a7287ffdb00dbf9fc573dbd9b0107f432010d6dfe750af3c0cd5571433ea23a5f5ed07ea548bbd7f0049090c0e0bfe856631e1a1917b91e2fa2ad661f9d9a377d70e002ef3982576e2d2f34762c83f9009b550891b2e8770e4a19d224c861ce3a98eae78b098278a0a00336fbb6795a36edfe779c814737844cce34abc10fe1090c76019613c0b0758fc64fe63d5e8b0debcb0a857bc1d5bb31ec4d038277653e2a96f778bc7892a61b58a18af040e095cc2ac7b24d60244e4925098d0a090e77714680fb013e463b8b69b27300a11dd702f3bcba07e0e9ecb839dda4b3002d923fab7448331b8e5e16c4d6ba0901da3b82e9b514629941729cad9ef72b1e77743f3999f3a29736ae664b3deb89733b81d96891665e868a462193afd5387be0a880e08d701c0f2e94b12fc6da01ba282cf8e344dc3f628cc913f9e8362b639c5348f6420146995a753221b386cf33273a616fb26d474a71a163128244683199a59ae8d505fae6fcc0c30ea4bfd7b197f0f48c892c521261a1a046388b4669352fba0d60f3914a27ed558199f8ad29d1427477d18fc0f8e276bd505f8ae6398580aeffedd1680c2f31ec0909abfdc24a8b6a552b42ce3a8e4f90fe6055d611ea39c82bc39605f23ebebda03414af31ecd7495e3ae8c1c7bd9bb3e9395f5fa7faf206102713729d88d88910f6c01a8eedff68fff6ab124190d67812b18a872ab55506f0e600d47aeb6dbfe6f0b34b8e70927c2f5e5be72611824532b85ae0501daf814eb0ee61f2ffec7d8374a706b3398d2476731476f63ca10d74243a6d031c352b6d11fe5dde7a7e0a54c52f5b78da2448f82a283c5a7a59770366aab6c13b473dde594afe9468c9bae2fd740022cdc0309c6a2173ff2a8dbb6651ad43a194613158d41998c8eaae8a3ea36a47a768571b2a55751774227e4c26d435ecc15dc5e25a2b29c9e10b2898b6be287fd32c4f71b13ade2fa8913c2aaa048492d9a7653997df15ab5af23879d4f244d006f8916760c4348459142ef7630394e0ecb2d35eb51674d6cb1ba014b61bf8267c13e5ca16dae488c59f5985a6c9f77724f3732891b9a150659b667ee44a58370400129c4a68ea9bc6bf19133582570f54ce79e230e2bd4111897599c68b31e4ebedb2815ac5b5894d3720ddfaf1c19a059462f419784c73bdf39dfbdf168765b3ed8ebdd13284f1b50662ac6090ad2d283e3034cc0d6e89bd0c0ab41e4b933c7e4972ec9ce6d6d7403970d991e1ce27ca87d1a1dc06135442db1e0fbda96a7909bb3c339fbcb0370616470b5890b4ecda4a5b5acc0a896e41b6e23eebe53e5af563b79de45ab7ac1e97dff8f6af0c14fc19c82a264306ec8e26726f5529b5ec335bc6359385700b3cf0e4f1e38d1fd3e04f49147603dae9062d41658e959bde256d5d92b708f2aaf4fb9b14c82d4ce94607a6f3fd35a08dda14fef08a21c1f6273302c8ddc51aa97eddd0491f14cd8e7d90229e066c33d6d409c7b5e59e8a1ebbfee9aa1746535eea771af2be4fe30d932cda627aaa8aa741d70395e93b8e1b4ae8cd9da8075d97c454633ee0973cdafd66383eabbf8671ccb1124fd3e789f555058d92550a8bbfff4cd11c72d58d8ecce87869395bd7d54bfccd8e726ba0b02a9f955c6a3c4fef9cf132fb26a22d1801b0544fda82c3a9b57ff5d7d249bfd0d0c07f85a95b58465aa9285a0c309dbe37aa9e9caf8657fca5b8c14d0cd1ab2421a006a230cc5768cc1a7167fe3a6589c487449ff808ee18bfba8d6d8bc017043063326dc241cac1c9e945422ce40d0095b26d2c6b7c41d03a46beb567a9b94a710f56d4cb89eb044e0de30ac82161b2a9c0bf544286393adf0fd04bb8a787cc653773606a73cd0216e03a057bd55a3b5cb065c4d15377bb5e63a58baa993e1868f077e8dcbef7ebf4274c262933f352c2148dda271cd4af99d4b7d38ed4ea9517ea033f702d68ddfaff767554145e73361a04efde64a1e458b851f4feed68962f247bc723d2d0815f6cc2cbd468f7eb73c3a7d741bac64193849a4934bf791976b0a6fd324e4c49bf5b2c40c73cd72d6bd40f4f856fa799532584259b26badd664ac8dff35f611a70ba30c4b680fa9424379ce5b1b4f5522fa83d7422f582554fbe3b82a320968e1b93dfff8e0c296e4a6928b994a131b19d9d4bed9c4d97a8e773a816462f79fcb61dba075e344ff2c264b54c322ee52d5f9e7858b1ef898d82f6ac335adb997f21656d622a8369c94eabe20bd6a2ecf0519a4af85bbc8e59adb25108774f49750eedc8b8cf545325edcf55f8dce981bfe5c786c6a543cdfdb2fbe489cad26c8e782f68619e5567c7e61d7b87dc9ae04f9b11a5a671a27d6e5e7f3d941982ad3015fa32c616a5897345373e50de5a79be3b600e2b99aac9cb9f3cf954f91a9c60bd690d4e8f317b92d0c1ff8d056b267437a1610d586d9dd0d3037520d5b76e64ca388a9bc3971f62e48cbcdbb9aea487b5578bfb9524a5a1114ce1bfbf36a2387e4f950f9890493ae67ef5c6869e9d47ed26bd1b35a95d2daf493ce59ded3fb70767f4325390142dd89345ed8a8426d028f99480f9b103faa55204a0863fbc6514607b9e6cea34b956b2e5c9d4a3a6aa9926f64e0114b6a083bf383ffbdbec220437a4a73b5eddad521502b5a42edfe93efaa4cda1cae3e40891f73043eec5f505175e9613a1f2038cb0a93b4b0bff8c0852e15a0bd1e4a72b4be48526aa84d647629e5be8e474d8d62bc6ba0fc59656c4cc0861f129de714f51423d36161bdc0e81a4cdd7442c619728c099a33bb8a92343bcfb19b79d73b5bee7705ffe2bde3db171290c6c13e55cc22b6652ce1c9f8257b5be6b5f54aec386f81284b6d28d2974990d771be0a48d547f4e389484888790c4c5204b84ce31e5b78edc11b9820954c7012f56f3158a32129c1e693e07cf7713116b57d490b6f30e1974873b296e3e75abaa02512f52751acee30d4a878c71d134a7b4d812aa8839350f398f14033be1044926ce5e765784e9918456d2f2a013cca538bd1c3e94f21f91e53bd61335706c5efefb847de14db1cb6ef0d8b272ef24ab90ee8819828aaa76a61d09e88e96a02f744a6e15041cc616941ea3f4f2622825b8273b06e138a08a2360b5c7a3ac06c1a1b245f142ff933277cc05c199b2f5b4adaddef8edf2c6bcfa9a52e69424a07bed5e12e6dbf5a16ae4b5c0b97062737a14c9578d4320ab4dae50658a3ced08612c8186d2968b2bb4cdc726deb27d49d5c1268e23dae1d3fedcf9fa927234a3302f146de421663d6e49671787be750c7041b281478e8b19420039fcd309adb64829eb00b837699716bdfd4a862c57e5d08f4ad0e006f9ebd6c5219567822cfc1bc797c34241152b0f3eeee1a64df1d9da1eb78633244bc36cea654107b7eced9ba1ae0e1e1cd6e6abd98d63934838f1283bc84ac618d368629b880ce7d3ab6064297812537aaee3a78c3ecf0c2ccdce0307f0c838863eb6f9857bb616109d3bf89b8b5a9f8aa11e1f6554725420c95b8c6481246ecf90f857760f278ef52d38d2504db69e73b908fc3afb9c3d2b64960e5a676a52302cbc8d3882593ca017ef95022b49631623dd0a983ea044aa8a889995ae1b56080f330927abca77b56fb0a03d36e847d94b37940f78d6738bf94923561718730b9db661dc11621d242ba704834ac80e88370ffb32835ee17caa74911a8e3c39d9c9845bca9bf2f37a1ecfc0f52ca45d40ebd21e5a55ad12f0480bbd6149fbe45e2e056bcc7d76d6431ffc01247bd62f2575f99fe6c16c81ccec447e3914ce0712adb9dc39dd5b714ca975d1a9de98d5ac2a8258a0bc09d2eb5f64bea5934e3d13efc1fcd6822362251caaabc9418339c84948221d9416aafee0a49afe35b76f73e3400c53de520d63ea8662f3a9d433247c3d3b4064f9c1aa0653a4779a6181a35281b3c36ee2da347e3a4f8f4d652d6922325c64108dc9981120cf396bca6b7d424096ccabf9287286cee0b91b8f98af9d5056e211676938f6741788099aaed6bb4fd60f12ab6fdefc0f1f1004caa692bf0d1b0a61199ea8b0af93e199a7587561eb9e4556b2022e3ca793590321f80b039099ff13d39eb583c90b2c31063817d6326dd181dab77b4f6c9c7fe8b8ef8a47d786f8e7074183f523dbdc03f3f43b801165b1758c4c081fbb7ca373e67978a45e2d6e52c6bebc7560521a1fc6f85d09c614309a653eddec47434f6ca9d99ef70093ada05ee153432e04f55320c3a0b3d03799c546ca78a93b2c11da6e2a8999e25b490abb539b7926f7ddd912f2059ae11bb543b86fd828f337c570ec315a2e3ab5382a1a5e3505a7f7dc4a60218c7211202206f0e434d34b7472dfce5953aac2df0a6ff48e65ae3966c9ee290f1192fc5368e0e20ed152f3619d26495a14fa00d87afae538f7fb928c48c86517b21c0557ab0f277a1530f33d70d26765b393698826b59a58ef85a43a8453459c08b02c258889640430b1ef39125b83c7b03781cf1595309df27c5c028a2cc180cdac9b71d5dc7732eeef1d2bcfbefe8d6b7fe859fca62c7ab6b0e1c49d7f4db0fb0aa5b9a30b5091a08ba2f73dbbe1c7f8598d4427263aa827b799909ec59631a235ece9fd8fc8988098c0ab596174150b37e1d2062cf8b5b7934e4cf692b5e129154ad43e93025d03b7df33b91cf7d284c3041d604b1fdca815cd768c3bbe82a80a82706aadd2b25bfa8d7604321c2d3a84f840fe3010844b4e80b7ac707bdee849d60b2126591dd8feafec0db2cfa6cbfacdf32ced7cee9145d1c709e918a38ff7b3a8e716e2baf5acbc26e88fb6e7afe3261f294939e437e679976be203ff6d6dd8a7ab7badba7a9cf4c0d7764608695014e4353d9792e0bacf438bb4b3f808e0d4eb0527dba6bbf787239a77d116e2434d4a1ba67e468f767986063431b5f449b2340f5f0888caf7004303657ccb0e96ba142e5adcc999bb138b8a7da59456052d6d63cb6d89d93ffd0b050377948a7192107e738bc89bb714b4e3ba034d555c37d825f3d78b956e060eb4ea9ce7585fed402f39ca8072eb25f9ef25bc4c3530f53491d0b7b09b78c96be6790d716f6d1cbf3955043d4c2bf16e428d3229c69b29537de6b062e07230de924e4a865baa037bbf504b010f9a749b90c74fd0207fae5f9c012af4e24069a0a0d880e699dc118c50df311be54c0cb8fb2026bb0de5f8a2a4665986333a8860e1a4b9d2de41bf72b9fb70d3e8fdd2af42e8024f441e09020e84c8beba850aed16c564ddad3ac62dca2785083be35101440902e0671eebe33f91db3585514ac0c8905b97d26ace8f6537c827c1b41b86fb8303129c8adb8f2bfff3398a1f1c1644f06cf3cc9a5a0956c636e343fb95169bd0a6ad419ff53066b9d7e5420c0354da2f69ec897dd4bee0573859c06af1f839b979faedd3e685dfd0fcf9e263686024fca765f04d0f41a0ab0827dedff7d35f2b6350557a8f6822a24f0441e5126030e9d38d5f51966550179b8927114f97bab8cfef408ff1c7bd3130bd5315d582147edff2065f21de50bc4bebe613aba13b18081a1da9c93938fc4a5e3d2dbd315cae299724284e39a3844cdc222d682715090d891629c542891bf0f2406be8c4b26af2c5b1d66cf2f9f8f53e67ac1f1e446bd50744252734ad3f42d7356d2388ca5188cc2eeed176280877972cc13a70125524308678396488fea9696ba061cf9de0fc157bf4f610933606d83fd810cdeaf11847f38a3df6fb27485843c406380dbe63168fa4d2ba72848637d1b26a6c6af0770aecf4c11f14c8c6d516850366b55175c3186268a8ddb198c1023bf128704f0edbafc47f38a4b2ed980bd94313228a25cbfdddb52f041be7258e1481c0b5bb4a546180be13a7e69cc7ed578310a8554fcc08e29449bc859ec63a4103f9fc4d946c1633c039afd10517f552ea59c93ce18ebf5674afb7be3849cc9c8e5c54ebb69cee796e532937e020c40c7aa78f7b819ef9ea665088b210818b33f2a0deb6bb7406512d50b8ad99502b571bafa6d2cafdb3edb01a68f74f280b9a56df359e07566d46e91f76ca5e66d96b4e3ebf45c4d9983fb91f66337a42cd574a4d3ee9bf15b070fdc130fd5cef68f7133a22428a99de1eafc6dc09e98cc970515c4e07735f486a6d6f2edb3d35aae3daa427c4f8cd56d5179806a783073e3c6c2c6a0c6ee62f66cef7133b56d8bdb94280cc6a900ce90ea4bfe52d894940a64964f7a003e5fe7d5cc0a6f158c3dbace5d90b6ae090a3b1b9556813f4daed2f09f48c42619854dd17f98904c0955dd4c8ae71198860eb7077fe8878a258f276d66e0860ddf1ea135e860d9ec29a86d8da9e3dfc05716f528c2d211077fefb799ed0d20a1e33a900ac310842b47396544647fda2d62bd4654eb79faddd3404f7633936331b43592ce76afdac7e4adc642713eee048718c60861e399c55f1afbb40b5a24a600dbe5d5dd79eb0c86d6e7c7a709e73ba68c42e08c79ad567a4a6495cc70de31f2c5caf888caa3939b7d076013446794f1aad97994a4b9c680f4f0a792b0c8eb6eb4beefc90e83c037ed937b039665cc3b75780822a5f49cb941db63367d1c04456ac4be900268aa045634461eed466d48df7319aaafb4c4f789eee4fea601992eb1087a0707e8d19cd1b92ec5e4718df5846e2f9b574ff4f97c8e8bf3082cab2daee684dcb2819f5107f98fa8876577774fe4a9b2c0705f9d51378290cdb87df920a3e632fad0e7d71915c5cf06415948d05bf45e718f021a42286c7a213b9a05c73159a8b214ecd0c752798e6a64c697a065db3982de687b6f5a93376857c5ec767374271f2c39a2352b55c905a49fe0678579e1dbad955aee2570fcab949686f61a47396b94d318b9f59b22f71e84d98a68500fc7fa1ad883801795e0d65c7bfced23231257f588658a236aef66a7c6e98dfd394d97d83be0d62ccfcdb29446075cd0a2394aaa116d23fc901bb54c71ee52936d5f4a650c8680a17542faafce12ba24f0a92bbe1816f2da2f075b58e12785d3d701d11c4ba526234114040e03579dc1497edbb7faf00a1d063972a90e972336d6996827ae179eb331c8a95d944bcd18e925c89c5df2e749d9417e67e9af6ee3a8b56829abae37577cb038ce17845469d1885ad27f74bd791f8ae80ebfee5ce46aeaa912e4925a2487a0f8dd4ddfe6859a4a438864bab4474f182d691ccc48dad79963ea86f15d076bb0a6552b734d50a1ae96028536f16d1d3246c26ec94a448650d0d9f0f4d35503ebcf129da035060cd7413ddc5697c197df18dfbbf24b433a022e1c247dccf73a3037fa817f450775c84ae0c34f2046ea780e059ba5d1beae0c904f46fb2aa533eb21d7269c241c2f4118f2f2015221fdc0c46bef570dec4b26ccc210d988346ade30bbd40215978004991f10daa1ff85cff5f37f2223df8e75aef1b9797369b6c7b40128fb4e3ae6fdcccb07b6fff03425b5da4ae14582a002e04079589313f6dabefbacd8bdbbf00d6e89d0772cf7fdb1287476530a33358c28baf99d9eceefb41cc6bf93dfe42c888d85648ff4c6a322ee0d826d5473cba9b9cc10fc2e5eab8e4a7cdd62df22ca9a3452cbb64f5eb7bda8261abd44b0ec86e99f828596948af5bdbb7c92b98bbca523568c8bb43906f6be4e38ddc22b18dc87a3b4284d08c06736346290c87cca8df1b3fc5293a730581b4d19e3d8c31bb23cd27bdeecaacd9e22d4def555d282e080a9f8817be3cef5bf4b4ecbc83b126dd8c1a542124f6454fc0a50ff6a451abe16dfd7c310d4e34ecaf969f90c68ea31e0e4e80ecc2688915d3c353a4529d4effadf88fa3a03769673ca47f58d3f7b266770101dad5bbdb5b7344a870ecc2322341ee0909d91e446fe154501081dd6ac4b01abb715505863faf75ff3e50a9d461024e09710fb829ecd7f8cd9e756802620b38313cc93d9ac39c70c0df47c334643f225d2e1c98022babf1eb003360570288c8b7061357381f3c982c811cdb006d6092bc9dd2f95c86a558aef67635c941eb1023fcf1670d2744f6a258e2e865d9484fc5e356287c722b32714229a4eb2a3b523ed8f4a2005020139cdac6407cdb578be85ddbf3e7003536ba6a28908268b1f332de7e9d5b84436b0d52bf1cfcffaff5fc80e4d7783b1189169143c58519e7e024829ce976fbb55d046e101fac6a892f9c46a13d786d03d995374b36e3f9c97ad2c6a4956be5a4d5631795b241d4f27f047013d32b50c658b082ce23d954f4afec7f52eff9b7bab64243ddfd0d0367fd7ac1f821a8c362b95388e25b123327674609343956afb8cfce32efa5448871bbf44bd22a8fc5c0890b242782d932eb5f7c8273b4f424cde490beefe38fcc6440ad6170b58485176e198e3e6a3fd0797befd088ca78fe0e43aa62f95f8079ed5240ab9673b67b6fa300e7ad0282702c06eb03f8f15f73bd4f2fd7bfa92a734c82989075fd561c4e57ad7effabf4e9bc1ce54e0d00f0b2eeb91ee0b313f647f0b0de79ccd350ad25a87b9a9f981fb548142c4b6b594135d06d7e75c3d70d8d136d014ed288d22cda60a25801dee7b719b58d7f84136df8da16a951c35eaade3b104476fb2ae39f3cef11df8dd8aeeda73c6de7f1cf3ccdb5f6535e218f4a0fd56d9eb8c4f4eb4a2b2a0bdf4f9af1ee76344fb7def8177b577fdb975231c36750a89fb9bbf3018ed5cdf9be278c08d65b085831cc5159dff851a34ce4d3a36ef038d78d1574115e0fa6585c51602ec77c3d6ceb0d5b639650f6070936ae63bfdeda4c189160e5106760a25caf825c97a48b5f3c407fd8c80cae5424e1990133e800c7ff469b6f079817470e71fcc3e07c358ff2b63531611e49f3be2a8d094abe07ba6671c7c5dabdbdd5ea0131ec75dcd82a5dcbaeeeebbb53200f47665f1aefcb7d897c9febaccf61014e822874a5d5c028c6866bef2122e47107f459d23dfbeb5831b7944407f990598f7919e263aecf777184b2c851056e72349ef18583dd64dfaf2418dd49afd75aa828f80798a5ffc2eec50260c6e27d43f650912944c44ecb50ac149776a0e074fa881e32de6721e975dbf61a4a89a03c51d78ca7679fc98b5842db0f80fe22678a71b998d1b9cb8c539c5e2492ed11ce7b23f73c50bee524f3bc0ae1191a68199e28ea44d6cc6a48a4821ac4ea0775f2686e19429401f20805a1b4f991c9a9db7a37ac324c7eaf1e8f4234e2a347a38ceba3f6052910cf409689df2827cd584999d637ee821412ebd6077287605c193475250b9456025695a7d99354c464e8672f26579567748e560e79e32afbd230b9635ab021f4f2a0ed64ff29b7cf4236293518e59eed8a825fceeb930ebcadf2991a944437d224fa4a55846e163abeed1afe0f60cbd63e0c1b5c8e575666be73d05c4da7bb1e70adb08d0ea364c2f533ffd59b59a643237b9df57f00a29f5e56cc8f7f2e637dec0f8b6df72941a5223f2d87ea5b5ed8a186725c464aa1b604c1969f74fe6829029c45534f52fe383bc2509a198ab7520b35d3c58f68dfdd51309f79532a182f4c7e61f0c0e52af7c17228bccc1a6f2806f832c1e5a72b29328a9d01c765a4ce7269ff5166a5aa03e87467475c79ffaaadf0b2f4d0fb5245f9aa8167490683eab086744d4c6a8fc96e036f12e29edfb4fa897fa41b7f619010d9e02c079414aaa416f06d8f88dec6dc0b433ddd972982d030bdcf2ffc57c3f7cd6350c8cf009677708122e99b10092cec1676a497aa498edc6f6eb2bfe91a3ecda0bb15b42b95c16f805053b1c75e8cf69913c07ba07b9157630f3e12140bc71882a6eef5f0b7869a3fafd00cfedda6c01274cbda966f00074b1eee1bccf8f2f7df35289f1c43fdd91e5076a9ef92d21925bcfc3174bfb5ae3e82aabccba9b5175c31cbcf6dab67ccc027a6ca50caf5017fb79c721e9e185b19e59143eb65e2bf2d62e44712ec96a25bd940ad60a8f6a55d1ec70f700599b2d2dc6fbbd67000a6194b4b3f83b89b2ff52340fdfd06599675ef9298bcb0be3d5ed8b54ec8f6567b56f992ef9bec1ba0be3c37fd7a45f0288173dff5e29943c4983734cc19cf03aa445ee6e76190905b2409a96d5ff6e27861702b102791286e8acd96a1965ed7187e9a13cb4252385f7e826bdf4030f96cbd43269cd0c268a4a3b3c1c674ec1d755216ac29c6d7b88d93daaf4606f65c9cf338bee9ee6f066f07720e9643fb6a7bcb9aeb9977b02180ff33283e04c47123b9171326538ad77f50e3bd6882c9f77201fed9bd0ef6a97addcb5bee55a68cdeaf9847808c081a625b2d145c42fe7f1c1129b67187a100fc96a27472b5510d2d334efe289ed23a2eae479ee12e3c2a9ccab0a37a4dab16cf4311e06bbc739f43be825f6bb094976e67be39b695a1cfdcc699b12ea96643bde5a5709548cede4b6cd3a9c3d8dde7e32754871916bf55d6dfb3cb93c7dea17a524d03d81206a5fe92d57714e1b0be7c57f12a82e3a433dda2c182d270ba61477bbec3551c7fd53958926817ebb7d8bf1696fd1dcec127adc6ed56db1ff6fa1dc8dcf89b9063bfab3377fcfe99479c5b8f217e2b9ac732dd14b5fb702a8f0a4bf27dfbf5bf9273d6b71ccbf0843ed7379fe219bbf6b377deb20f9e4d8e57a950624d475f026c1652fdc711300620a7bbd88169e3427c467e7821f8a8d13f9b1e9d9d819aa669526399ffe274521fe3ecf6bb1585a3bbbe4524ca0ccdd7e8c397ec122a366ac8363af5afb383ad353b82c4e51c5ced529a60d283409d6f0a483f2f90d123711b123478094d073ca5f027136ee5a898f5f0fb68d51c716e51aebfe5e1be4dec32743ba9fb0ef5f54f4932ac19f65f419d0b1c1cf1bd321cb46743fac9ba27d18f798f091868df6138d0ac892237a0b5bebd1ae5dc2a4937e60bb2ff359c878c2b23b913d9ec1e2d9c2d4f4fcf799b1dc1e3d235e349b5838df1abff1955785481b1f1e66f42e7db257fc2f7b81f4d746fcd017912519efa83c2452b275ef1b1559d1ed7c8c2363a6c38423d49cf7e2d08ede111eb21c1aa9924a8415ddd2410c367e4ca4c76f652b6596b793924a980e97a48466226653241cc5c985dafe60fab761db848317cded2ff07b3ba9b90b490ce344033bbc9a1e8fed2f2551e1597e48fdde66061d292d8cb111d78ecbc6265162ceadc5aa8510f2a54e42ef4fd995f7fc4e05bf9cea81c294179885bf705615e4d7bfbba33a9af931ac9de985a9b294608c49ad69a5d00f63e709bdccd924788d3e266b4894ba5dba98f3763a09a57356b2a0b55abb9bf4f529f251b06619cc2850b69e24074c5086ce46b209c5d2dc785cc4ea856d6898194dbdead5377df22f4baee9bd567e84261f5587cd6393b631118be397bb5c8f09e692371a48a6812251f50211f51f20377e14e017fc6eee9d663c2e455413ee24f5e48f0dbcf5419a07593970b571d33e72c3de938fa9a7751bdcbed7adc1bdf9f9d4dbee650b65a0a8c8ab8ff130aca2637ce04b7bc71b59875e781ffc22f15674b0921a0b6bca8982fd7ce2254d0cbd2eeed72e98f96fdd4518c70984913e980aa825268f5c3df20444c835aa62be8f9dc631bbe49fea7d26fe2fedc0f3c57c07df31f6eb2a3c2b94fc7674e21a194b785d35db7ac1e532810c99d88d73b2291b948af0e39a330192f69567e6ee96d13460fc45f40b34446452f11c6e7ce84a1577fcb82a0a62bb4badfdf13acd63942e06bcf606d916b3e711af1bc8df1c021aae617e77c0fedb6c90a8020fdc68615d54a088ec60c66de7045fd4f8105c9b635a4f6c960ada54128a7e2a4ff37cfe4dd4c9fbae797eb5acbc066b1fa49ae9646aa33607512c7bf1d7315b1019d1b89e3e293d8ea2bd7292f90998826b2b64531735312f5741ad876c36b6235bc8f1ae40f3cb341f0357c2789994bc2b18d622fbc45c6a15668fb6e16c7edad30d2e69f724038aa3b38b52ef19dc4715dab7c9e30aeba5effe697f65593a5d730a7178c374bb76e5d0a2ff3adcb0795aaee43edc0b7aa4543c339694b22f3748856c20307a7bf5e383c36734401cab279fa486ee7ca797aa1c1083ae36d04a5440f5e59ea12ba151c2c2bac21026c36e515f78373959da4682fbe28317abb85b05cfb49ee10be7016dcff2934385e092342d97a2ccf930f224b3341f62ac04910298ea654f628a10f1a0f40f0cb6709c31af0286e18c02290ff39e8e94d1685fe762ada70ac24b2bda76f3ae12b60521505892ecd6461fa0659e92d2f5f2997fc680266c2384b47576b62957f266c366da7bef64f9e31e88dc82f35abd0ab2b415cd54db617ac74401767d68ac2a92e3daad3c35ea106f60723471fd55317bf2b178d877c1169dc0dc11bea7ab747c133180bfc9dc089d403a765d662567fd8f4ad8108508ea4fa3c2c2bbd23ec941e1edbdc8822646b48ece539b616578995ba9a2004e194d17d54ab1bd4be3eefa5f66c7a141dc8c4542e3c4c6a554743e42066c20d578e7bea5c432a18ad6bbee2abfffb778b715d66871beba2ef87df56484129d298e607a6236ef9419e68c3a3cb3217e0f4ee0cdd16d6338bcb2253e95a07509868c4fca45614313a56ab76164cb5c16ad4fd85cb01d4df9dfd829447baece5da9649f4b468cf02fd42305a230a745960d61053383a9470b141b0219657cb8e0547442bf4a40e98591ea9b24d30d6396dbaaa5c028af914ca6552ebad08c46da1bc2c803889d87846cc414a1c79a72a3baee3f43cc0dd9ae05b94076f18dc7bfca520c61bbd3282d583301cb3fb8013e214da79ba47bb19b331661ae5e2c32f9b5cd03b3763230ac8aa949c14fc7428e9318b181f771fb49bdc585fb67619924b0611076b6b4a3b661a831dcafb5ef11e955e8b9578d4bac0b0273d2b05df7ac607e8559224e27c701aec54cd3147bf315664625b822f1c53433c49379303a6e5ed8c689e23e54c9b7c8939abd355a6494fdec8836d64b8dd46d7a41a4b8e26beb7f34ad6e53c459ae6387129a1584430ad636611d5a00df37c5fc1edc9699de3e3ca3ce18da4ace69c1d7ad122783fb5109fa2b4ec2c4e8fec11961cc4c61ee3b5469b7f776ada51e30de2ad334bbf7942845388cb89b514daa8e6b5ea3b3d3758e67bd5a0b876d01834bfec767268ccec5366faa1a1de5bc26762c803b6e1e0bae228d9ae7d88e8fe0942dd4ad1d9663bef75d9a5f4c7d2319f3457080f459bb9302f6872c68da026f49e7bbb2f0459dc2cb1e5db84669c860c843572eb4580f5499f0961785cc3baa5740891bd0279ac37a8237e5a5bf1d93aa3bdd9182d9213aa1e12e4d1cf844dbbe18ac4408ede1fb2b0eb78eb527fe34e62bfc20ad83c519c3740519b1d897e88fef00eb244519a101b9c0260f5f6d7c9654b69b4d2ae1ca84bb7f74f8e1c0220d6a2dedc0c3cfecaf38176086496d6a16e64d337708ff77d8241b36eede50ed4532394850aebef821c7cc72240c555672deeb02413ebc9f0e7a059a448dbd4c41abe12e3702550dd11de113da4b0092665692f02af88475893950f21bcb81000dba18906eb7d13b5bed8bfbfb34bf992c20d6f980d36380d2449c4bdfe3000e49c6d8af9398abe09c01b111af5f073a2771daff195185ee56f49c9c81aaf820d8b15270f938b3333769a65a31c75e10158004d69908450ae2102311d0993cd69a1014d17885313c269ccd1a3decbb2c22c6e69338f95d803e14b56898831855b78951ff2baf1e6354ecd0d81b88d85142c3a936aa4701cf2c0beb037e62d1127d93de3c1aad30d3706d1c7e8b59651fa59da49ef7a534e1a48a1b7b4ce6b9eb8644c5d158f96d84e1324c0ce75df8e93e21fde748fa75d41d36734acf8268cb70c6a3952abd45a948992e3b3205a9158eb0bf2e1375a6e160f84da7ac84c6ab9d25a188646db1d63921f6dee73da5ddd772a97abf6473c477c65a2becf3f953bbce6c8775454d5c778eae7dede8e5d73e0ed7e71d6e6528c2c84f6fd379b4d54630ce38b5d18a887bf4b48757cab0ee756a946e56019457a3076b645b941d82e7947783ba34cc415f8552dd111d61b349684a6bf2ad5ee6cfbd5c4feddb10b01e35e5623cdc7861d584b927d9807228ef31c6d0fa26acab8930c7b0a03e416044952f660537ff58536b13f83ebaba556d3da4d1b3fc0c7c21a5dd14058fcca84c2439a516ab0aaae6f4d25f4d2f3e68683ba88f7b93d4bc5bf28a60708dcf5f0ead1163984d45e1403d3deed31dc1ddea501f23ac7c53c28bc9eb3ed880602b87b482c2f8f2596e0d3d5a9c48e6f59c7264b7f83107a0ba906a4bb9e85999a1ea913cad024924cbf613bd2837f37cd8fd2aba31da0cf1c22d0a17a2ae8fb6dccee738c9d88c39a4e4ee768846a5a2a1138afbd66bb7d3efd3fab94fecedb1c1844db845c6e00befe435bd65f344c5424c8999860bc2cbb423890da0baa3b7e75f573bde51c877601788161aab5375d25d8c9ef7e7f20758c871ffac1d252ef39f75d9a9d76c52bb66579b1aec21a20523acd85e69a1065d2ea5d11adb78e5cb08047e1da8997265f3e89e3772d8195f6364a34661b2898790f08e39be1f1b5106a04f32f2a442161b73cc2449a2675906a9f009a1170a360cf2065d929bdb0ac909b7dc63928c7292d872cd9c35048d73c031ede936bec3f5b9914965ef3fbc30057beb9c6011cb0ca6a6830bb58183fc8365241487ced6c9cce8aa8ce970d7414818522e35b7328eb8a5023f7209ccb7ba8dee494965af79d80eb4c80b0ddd0ad871e053895e00c9e7e83b2756b3b560ac2cfbd7e286ae12235caa0f6f5efbaa3e52c421fdefecf9c38eb757e80d8908c6f7e7c71314a5d12f97b318369b5a0d748f8518e8e6a6b4e67a4821635c82ce308e4692ee9cf61130c12c87794d7606eae2ae6f8e82ed354fb53215cf2d0fb38e45750695b4d7a006ba7dbdc65090c2e24ee51ae806a491400d8902f1ded626113033ac97bfd5e55fe715d3da29aef00e67cc5469ebb597602b5615fdb1fbf5396c08d1a7c13d73d85ea78f4e9e3acb8556619138ca3f76bc138874149a95afed2227e65ad3ddcc0f698c253ff2d2eb7c00edc47ef24de478fc4e460dc7d710e46505bc6e2c0f8937a4b705747ce2984a747261b90446045e75e20fa7312198e420833331b8ef232caa82ef01a7f47dc4937206bbc91e02bd59c81b97645893147038ae306d7e10c41784d73c1320c744c1f296a1a5d4410be755b5750d616771e81d1ebccb144e76623278467d3cf7d90a06ce54cad82ba306c4ebb9bd55cdff9a093c01096eab817429bdc31f02356658c66def9ecaa2a5f8178b6c096402abb6e985496c618eb01b4b858cbbd837bea015ff207cad213ec1698d1c83d6734f3f4992970ed01c82707f4ed58efcbbb38e1e51a93f95285256e951ebea8c3275b777a9a5ca2edb0f7cab1969cc72722ee752b1378051412725471fe89629500cd8553eb28b7bc35dc71c268e7bcb47057486a5b5d784f8365d4e84dad544ade5a498a80f9f9b1f459bd7a9ef2da2b39aa2ecce1ab00983d79fece305290e61ec3b69946272f58ee961dadd7f1aedd2631dc4576c84d4d27ba36c7351856d46e57842d22b38ff98b6dfd7091dc452d441fb5524128c6571024fbc2223b3a9727a4bbfe26b746f41d825392c3d1089dab9225f25b37f10c792c4ad8d8ef3c858398a6a703516d8a108527aba440a96f2cf7ac160591b5f7c67e30d69b607be9c9093b840bf9ec2b4686a32cfeb1ff2b3a155547dd08567ffcf85733cedf60c6ec1a7ba967a94a2e0027e7d094dcd155853504a156c7678af2a381650ef6a489f624428cf5e1f67c474521ff9b1d6834156100aab11db69efba4c9aab3ad21145fbf3d6bea7e17f0b3c6152397d5b66912c09ac2d846b390b9217ad2536d872a4f72169abaa3305b9a9ba7e4c9a9640beb44c62de3446017f5da9c0fb1bb72417a2ffb350b62fcd90dd56fbd65a48b5daf07f06b98e3ea829759d3a101e3884d870ae94b33adabbfd2a155a1e922f58fa18866a39948b4a79a78a4c8621cc97cbed6165ea3cfe5a57b3c3751d0e9e49aebb2a68f57a8a1d5cfc7aaf883e023fe83aeee6f2b897c29e501981a15b4253c8f8c135c327ca194370913d78f04ed5a8e36b4b9e5981d580be1501c3aecae688ecf44dd6be3b7d035ace8a57bbb7aa59181aab20198facb1e3879fbb24cb765503be328af6b62eb372c1b71fd95fa41fc89e640e0917ab5faf5411ee279f43dec2bfa9dfd676a1fdc2cb86284fe10c09b421a5f5cdcc446665df48c81e8b91008b1f9b101c41b9cb18523bdbc616bab569dcce8677aa2ad8d1ac0333817f218d9f2a4e2f74b75c6e277281b6c628695c7d20183f4f6b6b126477afde53e5d4cb1e5977e4ab12d6b3334e776c44cd0f0cc31ecd0b54b6c43d39d9d107d4ee401d40e207de053d36dbb04442b74533e987cfa7cd215860c381dddd283c2ff87e379dca1bcb6828de3a509742be10ba257752ac29e99ed058980eabd91d92266d038127e56901281d0fe2644611d3ec3b9b36d27c245077427a543e38ca249d5af9ca85f2d00d9df17946e3e7237e0783ad53941f16f9f6a9b03e8c50a1c1ddba40af25fb6b74c40f040bfe1530a8bfa0f1ba5f46fa7b7c0a5d2b3ed6118c98243dbf13170bdaeeedbe57ed9cdfe468445b7f7c616cb00e7f5e2e2d9520e9e2cd21404e892489b0df4ad2f09a544481d300a473440d9f6a12b87cd7f98e75e44b9e7856735962a4f303b58405e99417af88760e27bdf1440df382b6af5162357ed06116e2c56dc737345cbf2676f5fe66625f10ea031745ed7be02299d638ae1d822e80b94a164dbdf323034cdb001259f96b8486098a76548d297b5a230286d5d307aa6a9994db056f689d0d8c9f40c81e99906ad2ab6fff2e4b7c5481dab80ee2a8056093c1fba0804cfcba0011d862ebe206684050c3f69c3660a10257f4781f3115c9ea878cb2af1bc19913720ac177c16726aba2f3afdb7588963a7893970eee0c14eedd018f2ef8d78848d109a8be1cd463a6904a97d2d608937ff7b8772c5cbb8fd7a50437116b54e52f5cd2cac7689aae281c7b94e7f2352b95469b797d79eea4e362b5fee2680e8908d99ab829b45ccf1b18b48ac3e3f8ffaf45b2a29a3725d6b12b45b23b3f2a7fb772f16741a70d0c4b409aaca1fbdd2e73127be4af9481700d6ea6f9e25a68c8b04697176ee13187b364c4c16b27b0fcf81be048d4f9f7d723431cfe2837b208f74ecdc84e3fa010d00bad327c18cce00624049157e00539664d8e66c44bde4511ec822486d8db80197a4ed40110d45a4d470093b8c1522679c0b4164f6d6aa71b8292c911aa089cb83e29e52aa308bdf320350aba2210157cba44e5f0551a41101ce33ec6ed9391fa1c6aa2be1e625ba34c0a0799b0e7ccc7242f57badd1d5f0d712ffc967932260c31b92f0121123d32ef7acfe30d8eca43f70a38e9f7e4dd6d32b20014e971ef389b8aef8f16f5ef5a24243ef9fd5e3211922965ed5935c687d6b42fd5ca04d6f9adb6915d6d76bd6a125f0c15f178b86461a0883c31f8482ae754b15546edc81fa4ff3e1b505881167c3b178068c09186631c21fe67ca4d0df06a519ad78cfcf70a575231d2b373921d6826d49022c5741d9f5628d18e1ea9f8b7d60b28f0597ec3b3de27c5e30b2da554cd2cf0b853db2cbfd8c94ac2e1a52fd44a5b1bbda03bbd0d91a1800251f0de9f17233d9a31e4152c6947cec9922215b58d517da6499411dccfe4a57b95716eb12942b4e864cea6283d92772d6480cef08c7efdff10196734cfb397a9f29ae4a43fe2274a724140debbb3e0dea689ae09cbd6ca3437aef87911d90de053fb8c40a17d3cda25a7fcf0c2bf1de7f1cdbe525203752613ed038e6901ee8f6380c6a475839ea6a53d2bbd69558e425cf43e1d75be602b01e158e908c9b02e577ec77f1f2c0bbd66843960a22ca9740864ae35eede46477fdb462a1d28d8431e26fff328edcb13724ad3bb900325604a46644517bd58e2144859373380f5253554b7d9592060525328a11c40b3b74600382484dde999b71a20fd06791b9aae5e898b406f1a6272603426959cc42e258a6f7e93d66432d7166121cdd2b9f22158c0ca79e34689f161c449959705c43ecbc11046516fca5d5399c2e15e057260040858c109457d77eacdb48ae22ecfa84b948801fb4ddb62f9b71b2d0a25d9c020b4515df93a1501ea8f2ce9191bde5e118d0f886edf66a9b49832c35266d3eb41572ea0b192ebcc34efa1b18820a2b2e01ef03f04875dd497f4e1f49dbf1672e0695ce919c537d3812973e59690c006999a2aa7ad3a676778cd5e28e0579db7d7a53260d4ca5e27c5df59649974ac1ae6e825dcd06a28235d7aca4e4a4d0b80b7560b5c0ae0f4fca5f51db3ccc46891188ccb54cc9447542754c92caf8d8d28ae6ed19cc298b09be1fc636739c03cc67bacd66541a1a0e7712231aa92847a2992d13b441657250ebdc96f0e25cd3b466205518fe5cb0534f31259daf9a931991482d98abdd625eb01d0747e281af43905daf8a119a138aa3a1f08bc5c4cc280fef148c1a2deed82b085a1f8531780d96818e92f605b61c2bf0f8792933bf7b91cf8ba7bda1425c671c169747fb871711c97c63ce1c13cb508fedd3948f22027e06cb2e01895fd5b4ae685123620367e30927cd74c816144cb3fdded42d973668be778ce296cc5759259985791e86a1f369b68468a849333907a019fd8244bd37f3a3f6d9d23963382a1f1969b0e231978230e70e34df8d63ed69d6d7a5f56e945d2f71985788fcdd2b754107ced9cd4df4010359caa32610127835ce51fb2eeb7f0718ef5a139df5771be6d4e5a4bfd090b6717a2e2878483ef966a186536f4cc82bf8a839fdcdfe3c50cd328e61f37faa088be39ed91962802943b9ca8ea4eb2b0855333f43eb9fdec009e2ead02a816a9401901a526131b1081fc39bc952e7a7d8b36479b9b1941a46d00f52fa54582a5588c2bd07ed79c3a695d135986e6f62ff393262a12837ee5676dbc03e8248ccdc20632aced315b2e99706fb71e819b230d4e58b3a2de22c4650f209cfe5bd269ee9fb5466cd992e67cf69efc24658b83412205525901a90ef7eb7946537dd04fb53c26646df02f33a2a7c5f9b96220055f5eba38ea4b81d6ba5980820de4f116087d0c96a1e8876bd607a6ca73d55a8d3654fdf73a6c7ed1a576e07e716394fabee4a58d4baf7d18ffbe9dec46f29b8f7abc773247dec76526ce3eb72c04333faf68f812d519f01376d50cfd391a2d89f1e95b0467ea77088cfe687cabad4a7a5b4d670d64e1f588a4f5690bf72b8fd2d340e24bd6fae070775adef7f4690171a324a7a19d00e21fadf4ee54ed119be5e32d2d04ac4b7f70dd898ac6fdeb071c389afbde10c7f81d09f0e4264ac5c432f2cad23ac1a693640fe949fca643ccfa0fb7a3f8ab4c68b3d86b00c16c4d89d841dd09ca0ac64fd97c6b9e3659969a55d6d0df9a59b6fa367ff54ff048396bedf453a0335c90f57085bf5c9912972d7c35a134125b927abcf37eb2fd86aa9d3ac82ee4a316f13e135bb4f4aee1d9cf64c9abc4ba9bdaedb07acd4964a5d35ee52b7c0eebc2397796d7c25bea5ed9f2b8103d92242638ea9ca9502c93a1a02eb6fe9877e2b4c11b5cbc0f90b6a41401fd7a380c9f0d92c8209045fa8b275b7e62f39712b4976c6ee0cb0d44caf8407f6b1f8f3fcd6d7fb8de395da5f67103ff37f89c0cec106260e80559c594c9f8c7fbd5657267c07900f7ca7fb46f2aceb7232acebc477ee0b956346d84a94cfba2d004cad75e90a3a964ac7ae636806fb0dccb2f4a558e20cc54e56e26ad8e6130dd6cff47c04cfa3523ada3cccb0a509bbf992961c81027545bec8ca65e10139a16148d69a9ad466512c5a49793d850f3689f056b40cfe98e609f7efd9dc351d687ad08e8c48f4a2e8672aa77d07f61bf3a5d398e6cb9db3f6eb9ea3ebccd62aae03ada05e5d759a37053aeb37d705e271cb9b10aba447ff3b2fbb5656e7647078b4433bfb0058b73cb58fd8f9604438a01ad06de5751ce26169764708bd15328c58f153ca7b1aa78cf3795e52838de785eaf48ef951b1d22c4e0f8f2a40ee992283009bbd365e854ba8d4f20da36bbf6e6a2ed9576c87b23d896618d9805a4fa57c09717d6e9704eb7b77d68a16647714823f479976f1cc1d3d78afbeb0886c1bb7dba345139bdbbc1cd083a23bf024dc5282030c407c7ced2eafbaa391450be5e77b65b23a6df4b15f5594dd8a4bd0da4b38265ce7914763b7e87ebf9434fbe660132b3375c8e0dc8a5d1b58fa1d7d933c41a0d9f87a5219a1d4c11c4c77de99dcf15e9f81623e924a50852865c4f625a70d9346479d5a1618115737b2b8181431a79de87b6d22cb0cbf00628c8526067749999e3205b76c8a6272c9ee47e7fd53dbd53d5f8d4610e40a3dc8d556f2e77e03013ca42c397ae30270f19855801afe2289b08454b1af64f4ffa900374b0073263c747275c700975c42853e8b0c4f6cfbe1cb7b6f52f49c544a1fb7489926be9e22d15345b547996a37b690f9cbcd73c5dd9fbfd1df6d615f6a345fb4138e5b859cbf27a6bc4acd1f106e5c8d1b67340c127c21b3b485f90ba9d56ab94246473e31475890fa756fb723e08599e7bd018987e4902a5dd9b0f38f244dc7811089e7b87fa10f29dbe82d2f56894c1c0c32b4dedb4e30afa23960c3ea5d627c396db60d7e64344d281f71b1b8d57d9ad318c285b30c073373279a8cbe66d1afd33ba677e1962f73536522d7a089b24235d90b36ace55f68236bffce5a84086cef6d65cade2aae855d3ef729d7965e782ca43ace229783f32d16fafcc37f1ec4975076c6efe6e6af414ed2cada5a4821ce2bdcebfc491044678c59a815b6bbebda435ace6f1b91c699ad33ebc189a75681512809f5c1d2a9728064b1c142c5f9b0e3acd832a0e9b35439bc60a9cea004120093680c91594b9833849b4efe78c0728af3bf5556e4a530ab0481bb57e21b3d15d080461c2643cbcd33398f7fc62ee9b6638ee2af267208635c09a99cc2fde37bc772681446728fe990d5448ef44a987f727d5b36857c30f35d3d5bf422bf5786399cbc60d805d1e7230cb14ead64a3a5e56c012291da017e683658f4d9751400e92f3f529ecd16ae974baf9ddc86924c27e70dc9f339a29912de6e379b4ab65a433c34f845809f21f607eafffb56c28cf2c73f1449246134a76a437a7c2c96d0a421b26a1096e6b2ea87c0111c28844290b7c83ade9b4e809a1ae16b7b6f3a00d55f177828bfdee8b39b122d9e8cf98df25461eb80df9fcf7943fa323c614fdfd79726113e37548fb35358ee2c20540b8acb33aedb21c76ef81bc06638a471f043a0dbee0520dd3527378f0eb14957adb6e45b3357573aa12712033fe6e1bd0ca5e9c68f9ee045f3db9ee8315818d945bad91de93620232328e9a1c139f63cc666dfe9bb91c3ca35de6f482599d503091bc4e0762c9cc9803067e5129b09c482b55c9f9545df13eb4f36c8130a5fd10c0589a4d0e4523eeb29c8e6d868ff50869fc477aea865641fe2da97313bf58e607dc291944dfab02a653d6f1ca8ee21ebe5a4e488fd89a1ae344a88165dd95037bf5acfc61d28ffff2350ac5cbb21f3bdcfa9918b4d9323c884d8fcb01d6cedb33075ea81ea74bed925b87695174f31ecfa39e822b06cc8b90a304f129da0676e77cf3021b7455c830934f757b12441cd88fd8d0fa1ff1bfb7988999581b6433a418e87ccb166d6335668e9bdc1b3a24a38ef33dda2bef8fd606eda139b2761f5b659e7ad23ca517686d06c7128163852bcd14b8654e7e1d64d70f8ff344ba950cde8bd17e5358a01b4a8ff40f57cda1e9cacd96d448179eb1676aee3faecef2ee0634600fae22e28b4a17085d5c8ed98847425bf7b6d70bfb24e96161cba3bbe42ea2a81fc1e232f497f332ef9f984ac6e9e48c23a03458dacd914a22fdde41ba2eadb68647e148ab1ff5262420ad6b56f5ca23211fcaf77226ed26ad62f30321696dd03b5031d65d8348ce1353d02f3e462d5d42e8dea0a58dcb9d05528d970099d7d9556affd532d73ebbe8483ef0c44ff360e9a8b597f7ea3dd9b8b35ef5a648ae109932d9d9a07cfaef7e51084a1c3d7357d60cf8c6538bc469621d57cb4ee42b4af8449f1874dceddb844a85113cc9b08da8257122c2e644226a3b5a625cafe1b7804648779c2334fbfe69c258e8da287864bf340cc7684f98dc17bb1345171295d3c0cef2e21d1add1abe3198e0ba159fa1c5f81b858d3406d40102a83ddbadbb5031469bf2d2bba43260fabc31d0fd8950c79ba27a7ed2528e20bc5124d729ea8757f2fc6c7f3173d5937193eb52cab962e28dd32a8665de954b2856b140d787b582581e08c41eb4dfc41bea9bf4c8ed048dc1f862c0894445ec41baccf572704ad680ce4fc8c023d635c2c1a31b6cd86bec4b585a1fb22ec7182fb0fa6a3530035de933d0da021590edb2b57117297aba735a84d201b9b48530e545976c03172920779c13fc05fa44b2c7a5d5189f55537cce50440d5f705334adeac2b7c1fe80f38c4274bbab4a8e6a7f8a9d297428e620e27180c5f5acbf2e493ddc84817442ac25c526e151b8cf7e03060e4dfc3a9b2b190abaf8b68184cd4ec5e1815d1d59ad3f780ad2b1512def62f8bdaa221bd053967abb08e065234b1ba4cfc8fff04095d514b74a9f9b073fac8f5207328279c17e5287e52001c7e88739e7296e25fc7ae57d59f2b3ce88952ca25b5f622a980223aaa65083a0f172383226964187194a7e6b692fac287a45a11d8792e7c47a05053fc9063dd2713aa816e766aab99772b7399de2fd2e1e6402984a0cf2f69e90955d0ee0b6c6cf755b494f55db4be3533e6a3ab007895a125fe1a2e547b0d7aae6a5624337826f04989e482b02203b5dd7f9f781a2bf7c51b45db1915b328a7af84d70da45ae495b28c15f7afbf4ac1556299ea0ed907719fb289256aa676b9551268be23267a5b621f7e41fed955b2bbf5101e69df43bc518628079de5657e2eb9efc57f695dc79497c1586ed014a4b7aa91c82844963575c5c8bdae72f6c48620901429a5a297f38938f0037be7d0f0a28b765cf6e53489a24fec49c725c84d2956a09574a01b2f901c49617f3b34b10263f6dbe6e0ac0ff715c606004b3997b29200192e5ede528c8c2b37aee1b5d4e83bd0aa219d3cf40389e63ae58b9331f6b6af1cc8164e54c9222ab3d91a0839b4f693a724d71381756ee76c5941fba2c13a9a77e8a426e7f5482043766768e45af68f543faa548bb1dc755cc1af83793a66c1c8f28be0c49c16bf73a6801b864369b9a3449ec4e10ee664fa1541d17b3d89898cdb8d8c07ed6119b4a76ec7762cdb497f1e55013f28ca010f174c2d30ff43c14d7810205b1f2d1b3f649e0340e883690ebf79f3fc98625a9692eaec91a4c69ed5b95dc09c21a6c0dd5bbacff7702038305b007be17a34cfdd482f5e0e4609df09e75d28c45322ca750addb6453f3ca5dc8edd1287f71cc2b2bce602e6a8e7488b045faa671b05b89a87f9c2d07e9fa1ad97da750c3164e325c6dcd2d0548962b39287d38205cf4d63ff780fc6d71beb74d814e6954f361ee1d0de9cb26c9a607050234dc8fbd61a12568d650e381da5d6e1f7a5292873ae2b3b02d62ae61ff8ba3f40faa4bf9d54ccb708494347a3a610a4c87448d4eca8350534f44d59997685bee8a50fb280191854f4db63e1f045eec1e283ff01eb0a5e20acf7346057d4996dcd6b2b85172e6c6d00e83208a6db18c0cbbf02ec5ec7700b88d352ad237aade1613deb1afd67969288a0cc050af052b31fd1d7c5270bc96363d908469de976f68ebe1a5664cbcc3cf0cb7fb2d484cec14e463de83ebe555bbb0e29924c16c3328f9cb204022fa4c0a34d04fc2a444011a39ffdbfb71bf182e303c08b96bad12dade6d77ff8f4c4d73aba5b65af4fdc60288112b97f85ab821dc29630e6a2998496d5b93811435b7be21c7e22048de5928b875c4f6aac92730a089b5ed7b57d10fba194380c771dc0d31079e89ded4ff01ff66aa9274790ea182bac51859520651d11a195a69415d6376f711bbdfd5aa39ad83354f7999fdd6a9a94cccf4382b07d23ab7fc773f138ed631150a8bd7fa06e005d5fe41e1aa4f450be640c8efa6e8bf6d09f68f4c731e4a30d0e3d750aec85d7b08e3ce51986fd812f3b9cf3e33f60eb347812d29de5b7325448a9360a2923d62364b509f32ae2a26ef78ef5774338f07259ff6c4cf1b24c118ebbd9cf6ce10c7420125d5dcf8d292c69b02a0e474f78da2c954fc644b906bfe48d65fbc42a60ba62186c2b354067ec24fef4e48c921ca6f77d15e00d907e9d37dbcc361e025e7cfbd632d6bb1bd511a6bbf591dd09d4c9a1d7298552b39a5254e6ed9d19516ac8d6d241e5740287ae892600ab9699509f6cfe06e29a0bf8065d9517790e949bd752f67c6b40a5621bfb3cc0344f5923d4f31bc35f03cdf246ea0c20c4825388670193141561f32bc2e3c33a53b95c37f985a1a71dbc8eebf5135f77d257adebec6f5456e653c5f4d855dcc5083a1b726b4244d49e65148e8d697e8e5642718fa3fdb836fbd7f99cd26fdfffc1d99a04b9087cdd973d0b737cc1bdcddc834825a2d22fdef40c674a994e1e62f59e85c9cc8df952057aa59d5b8464a2f2d6619d5796e4fe7eae9f223490851fa9358b05d7a957a15955237239c0c49cafe9bdfe115148be9c7f3f496fb5c85bb9bdb271e59d4eeb13a0ed532e3a4f0d16bef0861468fc157cbb2bddf27365cf29623f080030af0ee64b076fbffc1cd3a219129b4a9470b377875a619141db2dcc01cc16a6dbb39182587750f58c6c0ed0d3cd4aac4e5d210dd7013bb364b3a6b3c3f5bdd5effe239cdb9c3d9b2a3bbf7e1f3319dbbea7452cb887ddd224c56e821fd534f5f4d1c78719d63316abca05bd3a21a6a1bc794eb8aa98412a4c5b22c3dfe8ffd295604f3d15cbf45cf644f980c8c4c9105257d6bfcfff7be89ef74e8c263145092c3b56ce097112b422ecf6fefca0f3f55672343e579a8f9267a6c214fe06d14d2dc8dddd6dd6e7e110d98ad77b668c5640c46d9854942f96cc38794ea6f57e43a18172de0c14d37d5bd54f9f931076b0703c1069974d6849451b14f59e542a16409e0a0418997993c71e3bb5e498783bbcb10e33b2cedd6058fe72b2596da09d60c830649ecd6c7fc6b23fe29708edc5246aa7bb4483ccb63cf8c96896356930e82ddac8b0f5c5aeea698495a4044acad7febcd60b4fc2310c54f1ae16dfe20218854a51f3681c5e9bbe5e8eaeb5060a47eda56b3dda66c614b533ce615936fdc0d6a48a716d45e908f2e5088d2ca3664d5c95cb0a0663649449744393712cadb0e630f7cbffa49125e0eea80b6cc452007a736362ad603731dc1470189988ec5bcd5064a7662385d197b3fe6c00b688ea7fdf303c895e0cc3f20e366b476910707758147995438461999df0e802c9e95998506025457a0ea3fecd71d9024a3986422e58c4e704d999b44967bb70662526447d5016f2c6214756acdd6f16ec6968f0e396fb3951f5232b117e256b3527632243dd2d55918f96e0b79e034ddb4c24b18eedea38d9228cdbfd7101d8f0003305b341e52427454d5286959734598761dcecb241e1b7628a5fc5ef5a7280c359ad47357e7b55427c4601e3040363f601fb85eddb16373ad13200380f6de2d9f924477035acdccb8819948f7e20336eeff006938bb82fac88ca876ae2de7787809b0d8ae1a522457e16acf141911f31c0cbf8f5b5398a816f3dae87aa4eeb527b8d6dc60f844031d51e9222a16cb3307a98474d7c8e12789e6adbb710134533f6a92f40e680da367d997b458fd770066c95a1f36bfce17be2ad596016f51c03d5351330f7cc0efc68ff86450be23375f6cfd2cf48502bff22bb744879aa738a1c1bf13758d2a816a9451ef0c0922ecbad55ded8670a8394f75cbcec285b600bf923ea14f4ed54ab764d30f0bf334a1c92d2f322106cfbde67169111c660f0f780e31b33b49cb6d17bad966f88aa58ed87104ca7ddae1efe19e5c0917240197f94f53f6094987ee3eb5379a8842a2c1f173757a043d1457e362248cb65364aba1a4a03d0ac8f9a06dbd0494ceeafc97f72e2df7658f57b59f5a96aee2b7459110f95e75b500b68bcc9234c9c9b4c192f5e0f5851b0ca96a95eae0a17e253ce21ba9bdcf0d75c30f9465743c30e67c7ebaea576f8e8ba8afe54d58fa678c1615ccd99b0ef55962ea0afb5159cb842a39f8e850798ce4fbaf85d78840bcda90b87782f31a2279cea26fad1adb146fbcf2e3a0b1e115dbf8e1852e139fa43035136d61a0982e474873e81473b214d0aa711994cc2db4bde177f655f5c7f77bf12e4e25024bc9fe97e000f85f09f788247566857c0fbd21c454f1306684d0b579e9365dc82c9c39b9c15cdef18fb428616b51e088ae6080ce309ff84cc542c7374ef3e149ccc26cde6af6894b680c7240d228c3102086a208bea52c83b2bce1925399731079fc8a0ffaf4fa12b4d6908b92c867d9d79285386b1cc27abb3f35a10564374ae62d638da4fdb5b99e1550731af5d2359967cca2d4c55c1c08086ccbbafd8ea29c9c4afc0de36046b527aab034a94971e393e7025f6a8728168f205a8062a34c29f404c3ad292f2e217e8a75dddb021b67eb163e3c057f2616297a9d7e1ffd5b4474d1bca0f337bc720849f39fcb7134248025c55ce13382c53a03517087f05776bd493ab39db60c652f002cb3b3d27f138ce83be0804c6f0f5be5223a3537b01141d5e3e7ec141a17fcabb6cd50bba076e3bcd69aeb7c3678fe2b260d2a3c031ce52a9ebc76eca605a2d3f3c75d2d432ec6c299e277079e0214a60a6c18ce98f78b740188312b35feeacd13cbfbe1f2dd9a64d4bccb6a2bf4db07b2a3188c9ef6ec51d36ca320aff60f50a96f9fbdad2d7aea24371c277414be5cb1f5c263655af7d685c3914d4d260440bfc99dd13ff0f6140742629d356329d6efde1094546ad9b213ce87fffec78b63ac5dd9745650b03ac5cb7a67772dacae04a2dc5c508acd01019f40204b5171614bc26fa96f6d85c2f0565b6f10c314c243a0c1542a012911051755c70a442445a8eba5c2689cb0de7a35f7b801c9fdbf34c5bce5d0abf53c9b52d3449b640bdc4bd9a657da0181a72bb2a6039fdca4dc1e75fc9663017310c7e4b070c7bff095333004414c4da21a4a50d8e54205a513b5a794cd06a4515f4c40d2eba6c53e6b175c69e122acff22103f752e67c436161a0ea6ec7908a44c4e48c7771d2457124ff7b2f27a9271482b50aae84af5866638eb99ee98f16bec5ad92f367da94a7379ae6bbf255d9671ffa0012050f7bdf189c862ca9d9794238ff7969b858f008fda95e58ceffc5124c46df597055367b2463d14cc747c3f7d45228e30ec61ec8cb2bd1cb46104f0c714b6d4f2a119dae3bb48f882f936cea1aebf1c0c817fc8645c149baee0bf7aef34bca8faf77180fedd7304abcec87796dbe50b777e48f473c4f9eee7b354db9914c6a5b5fccd9d7ef32c50b66dcd70f8041a4cbfa076a68c7771b867ff11a4b1cc7c8016b10739176decaa43f4b6345a4ea32605a09abdf7dfad0a7100790c3baaef7e8f91082f647595267a43a12c29323ff9e91e00c56db6c22ea8b0b12da8a99fc2422fc7958cf8f18fb31f8a36e13c789e7582072943a68a6bdbac192fb1bc5539d698f73c33759109badc0c4d891170b462b79df89d0a799bb38cca6edba137a8f0ff29f1022a7cd5c1634c7247b4c2b6faedf241f4b2ab185f631e7c9845cef880df4d6ff992cf41644facfc45c710b950c75f7891a6da1ff5905e704f9b710d5cfb7b683aa7aa69f56d08651fc48f20c39870ea9df0c14b5e86ca2ce976e4ea574759a26414b9a1e865409cb2239c579b0f32763db1a05e87188ad2827c3940db6920a8d92f5d9db6b11769b4c2d2fe83b258c161854bb4c06f78fb61ad7b09e5877cd2c3f7639f41ef90c7a2c14bf3ce557b3d85b4c9e2f97bf8ebf86103115b651a435de60b235c635f5558f0d2a3c61b75392f8738f777ca3327e2c3f9fe7c459ffd1a7b0aed9593f16a345888605462285a01e1c40541f3cf0e023fba2cf6a0fef32cc5afc38cb94c5e08763626858413c8b188473da79e4f3fd4b2eedcc24f77b5290c0db16fb44ec8ea6b3a1b9320b29d1c95be7a5778549166341d01a7d290f2e4b8f802edb63d2358df9fa98bfdc7993b7f8351f7d6686dffc96a369460867fba53f564e8e485b796288926c0fba228188796bbe35b66c3b54f76766c7e9341d34b012426cc08d03f5a68a2025b22aedc47a21656488eb349bb54f01c9983a9876f372a454a55bbc86ae840f244994442e5d7309be02010aaec0a9dfd6e40f327e30dbead1449a5d2223b0fd32295c9c2b577e234727d8574efa115e3b4c0a7c751106ada4b1048b9e97a7d26eb1ce743632d6894b614cf081b7fea0adf61bbc2885e0f9be3fc447d1020802c737028344c58f42b07c07331294e779576d06859a1f99fa1b7caa10e7cb84de6ac4b20da0d6ff6702cbf4c3bca9554458b4ed8a2609eb9492983fe695c700a105d71265a88d83e0b1562897ef0fb133af778c7f7ac02de3e59bff14653e5dfee13ec3229bdfebbe63b1731517e4926c40e975c9cd7a7f7693721f22c3bda3248c4439fc56f676c97dc8acfb7eb81677aa00b2ebce10cff73f49e6a74712e6c812d68a8e7c71c56ff74a604f167fe359e13f768e74c94f7f235ce20b912e2445baf438142f04582b63da9e973bba08e0c2a79551980aafd128ef55a470f2e7ffa6b2b705fa0c056ecc58418de851bc5b21ef3cbc8e06848b0e45fc4c3d67041d4daee88b18014425046b4fe4d7ebdc88a13dc6fe93c90017f7633676428b8161836f3ea60052c6c4a1219eb96b3a81827a6f18343540468077d533d75f5e854e4a37013f34438fec6b8dd02acbd9b8bb01bbfa84a5453d196f477dd3d3244ac35ff745daa82b2d855a3e82cea5f17b90b8b26bb698f765bc76a8cb704d50b20e61ffd925b973f31ac96e466cee6cac6bca7cb5ce4dbd1e974bb943d69638dabdfb8b599e657ba7cddb2ee54f9b4601a853be58caaa7ff51b66a019f8d021d9d9208fe14070c5b4dec92ed0c93e17d2170e705c49099a2e89939258d3f991947bb92be8380a6bf26b03e33c28cd8c4b59262c35eb38ac0d780c567684f74409d188c5359c5b8f88b1f1935084fa0d952bb9f7322c2e023d55c10708bc99cb095ec94a878e4b86eccf4d07eafafc303e98f83aa6761e1e816c3278ff58cbf7f0cceb2808fffe3b4038464f63172c08b2df1aa110095763403fc59587d6d39534682cbbad4bc44b1f250b9b6641fb146f9113b6a855e9340732ed0ac0374d02c8c22a462720ba564760829856e7da1ae5ba32eff661aa42de8b04a5036e49be4b61a279802e7df9317331232e79d55d5d400f666d4ea25d40e80b872d9a2126f18137983fd7f885e8e44cb520e1da5faa209188facfbd4a1a712d5f23b76828f58f6187b9ced054f45bc0e569cfe26a8258483ca42420149ada47654412421de74c3b5a2d18b8196ea9ddb953acc92a57959df196462764c42299daeb9f47a2e949bffce2dd4046941374eb12c8ccd189cf74a80252a9bdf4218e46df3656200224d52d94a5c53174c250b228d6f2d1c986f66fcad4bb1fb58cc60e0e8424480e380953b27588243173c2bb382cafef83597d19d1284fa5a3d527f5e2bc988a9cc7716cf754aa1de1b0da9aa64c6766cb789bb503e9f734c778bc05a04f77bc3f1ec9777495b548421392a8da5ea9f9401388c2165a1caeea7fce65167729021dc4c97d8a0237b91b4851e60802a2e6918ba3fdbcc25aeaad5f64111a7d7274fb3af3121a439012fdf67b74e7a0981c2a804014c733bc51067291d63e354e46f64fbe4ee12a722584cc2419c6590953fafb3a96918a48d6664843c861fea37472632d36a948e554509ae2985bdcc8be081a26dafe8fa2c42f456a85c46bdd6bf6fdbb7d4f4cc4cd593b3aa6b3d7c343fbac3b04629edddcab1de8202e68029b7d708345240b2dae47cbcc0067905ae4392b43b202e949f73b32b1712d72de1fdb578fecf0bd7e8993cb7baafac4b462019265d42ed377621c34d980527571337b6aa4214c9107f37428b4f5557422ea0494a717e28dc0082c8ae52ad342cb617943795ea733839f4e4fe76df91576458373660e6c55380f2a55ea68b53be031647be7982f230fbdfef6290f37bb6df92f776f0cd38c055985ce28a3ff5acbce0b4930b3040ed21fe06d43428d184c2b3dd8a761e72af6cc163fda59b134e4cf6b7261fe8633b1340ce3f78193018d66b387c1faebcf51b9422a287003104f75c543f213326e52e6e18eea602f4606977dd0aee592f25a322eb6215108f86165ea50fe16367fa76118753fad3248d53969c0ddc804a693c05dbc69ac04b13c6ae562d3aaceb097695c87831d88e110b7140baf92f0e192d269cb18ad294ab35fb960f036e33fd5723ce574e03ea836429eb18135f11ba1864fb888532aa80f2a0a6b7c255ec76121dc634ebf66573c85ff804feb4103b2a71f0ddb0cbf63d05f92260727252f23962e375e27f0c58a55be92925fd1e47dcbbd7b0555c000ded065293f087f9863b75e3dcb73246d30f286ad85aad1a412f0a24669b340ba3f9d51277d71c7dfbc2d2dc0b99be4c02dd84761dedf3f2b9856bdadb10b093ff41cc98c4d7a7e20c26c5fd381d540b89071f1942857f55aab419af4cc38838c98aa51fffb8c15913a408b3686e244b11bd2502bb4bce31172890095ffa9c9c29e1e2741f8ac86c2c1b2f80d6db25add11f85a7e2fe9660ca3b96a1e7cde43cce4a53a6f95979a98cfdadbfa113fd28f9d35d9ccbe434e67ce5594f1b5db3436e716e129d1cf50178974f2be530280e2f47dd8549957e18aa122485a4196b1921cfd4b9dfaf80a1a47dfe8cb292a4c52aa7bcde23cd2f3f4e93f48a4ce28858a61827f607777da35fa7e591dd6fd0a50d202c9165345e8d563b4564c35385021b1177fcd7d03280315d3b5cd95d189c51fd602899b89288194876dbcd5e03772aa5f5f44f7cd26757eeca88145e5a13a4fa1f4d9ad61038c91d2fd6a4b1063100b2d0b62eff0137b4cbee02858be92ad91b05b85ba5fe1d939eda299c";
+
+    public fun call(_args: vector<u8>) {}
 }
 }
\ No newline at end of file
@@ -43,6 +43,8 @@ const TX_NAMES: &[&str] = &[
     "store_native_withdraw_reg",
     "get_price_test",
     "missed_native_balance",
+    "store_u64_multi",
+    "store_native_deposit_overdraft",
 ];
 const TX_BYTECODE: &[&[u8]] = &[
     include_bytes!("../assets/user/target/transactions/store_u64.mvt"),
@@ -57,6 +59,8 @@ const TX_BYTECODE: &[&[u8]] = &[
     include_bytes!("../assets/user/target/transactions/store_native_withdraw_reg.mvt"),
     include_bytes!("../assets/user/target/transactions/get_price_test.mvt"),
     include_bytes!("../assets/user/target/transactions/missed_native_balance.mvt"),
+    include_bytes!("../assets/user/target/transactions/store_u64_multi.mvt"),
+    include_bytes!("../assets/user/target/transactions/store_native_deposit_overdraft.mvt"),
 ];
 
 pub trait BinAsset: Sized + Copy + Into<usize> {
@@ -128,6 +132,8 @@ pub enum UserTx {
     StoreNativeWithdrawReg = 9,
     GetPriceTest = 10,
     MissedNativeBalance = 11,
+    StoreU64Multi = 12,
+    StoreNativeDepositOverdraft = 13,
 }
 
 impl Into<usize> for StdMod {
@@ -201,6 +207,8 @@ impl BinAsset for UserTx {
             Self::StoreGetBalance,
             Self::GetPriceTest,
             Self::MissedNativeBalance,
+            Self::StoreU64Multi,
+            Self::StoreNativeDepositOverdraft,
         ]
     }
 }
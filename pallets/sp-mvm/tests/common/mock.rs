@@ -1,17 +1,24 @@
 #![allow(dead_code)]
 
 use sp_mvm::gas;
+use sp_mvm::fee::ExcludeGasFee;
 use sp_core::H256;
 use sp_std::convert::TryFrom;
+use std::cell::RefCell;
 use frame_system as system;
 use frame_support::{
     parameter_types,
-    weights::{Weight, constants::WEIGHT_PER_SECOND},
+    weights::{IdentityFee, Weight, constants::WEIGHT_PER_SECOND},
 };
-use frame_support::traits::{OnInitialize, OnFinalize};
+use frame_support::traits::{OnInitialize, OnFinalize, GenesisBuild, Get, EnsureOrigin};
 use sp_runtime::traits::{BlakeTwo256, IdentityLookup};
 use sp_runtime::{testing::Header};
 use move_vm::data::Oracle;
+use codec::{Decode, Encode};
+use frame_support::dispatch::DispatchResult;
+use sp_mvm::balance::{MultiCurrency, NativeCurrency, OrmlCurrencies, ConstTickers};
+use orml_traits::GetByKey;
+use serde::{Deserialize, Serialize};
 
 use super::addr::origin_ps_acc;
 use super::addr::root_ps_acc;
@@ -32,7 +39,9 @@ frame_support::construct_runtime!(
         System: frame_system::{Module, Call, Config, Storage, Event<T>},
         Timestamp: timestamp::{Module, Call, Storage, Inherent},
         Balances: balances::{Module, Call, Storage, Config<T>, Event<T>},
-        Mvm: sp_mvm::{Module, Call, Storage, Event<T>},
+        TransactionPayment: transaction_payment::{Module, Storage},
+        Tokens: orml_tokens::{Module, Storage, Event<T>},
+        Mvm: sp_mvm::{Module, Call, Config, Storage, Event<T>},
         // Sudo: pallet_sudo::{Module, Call, Config<T>, Storage, Event<T>},
     }
 );
@@ -92,6 +101,23 @@ impl gas::GasWeightMapping for MoveVMGasWeightMapping {
 }
 // ----------------- //
 
+// --- transaction payment --- //
+
+parameter_types! {
+    pub const TransactionByteFee: u128 = 0;
+}
+
+impl transaction_payment::Config for Test {
+    type OnChargeTransaction = ExcludeGasFee<
+        Test,
+        transaction_payment::CurrencyAdapter<Balances, ()>,
+        IdentityFee<u128>,
+    >;
+    type TransactionByteFee = TransactionByteFee;
+    type WeightToFee = IdentityFee<u128>;
+    type FeeMultiplierUpdate = ();
+}
+
 // --- timestamp --- //
 
 parameter_types! {
@@ -108,7 +134,6 @@ impl timestamp::Config for Test {
 // --- balances --- //
 
 parameter_types! {
-    pub const ExistentialDeposit: u128 = 1;
     pub const MaxLocks: u32 = 50;
 }
 
@@ -126,10 +151,207 @@ impl balances::Config for Test {
 
 // ----------------- //
 
+/// Currencies accessible from Move in tests
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Encode, Decode, Serialize, Deserialize,
+)]
+pub enum CurrencyId {
+    /// Native currency, `pallet_balances`
+    Pont,
+    /// Test currency, `orml_tokens`
+    Btc,
+}
+
+// --- tokens --- //
+
+pub struct ExistentialDeposits;
+
+impl GetByKey<CurrencyId, u128> for ExistentialDeposits {
+    fn get(_: &CurrencyId) -> u128 {
+        0
+    }
+}
+
+impl orml_tokens::Config for Test {
+    type Event = Event;
+    type Balance = u128;
+    type Amount = i128;
+    type CurrencyId = CurrencyId;
+    type WeightInfo = ();
+    type ExistentialDeposits = ExistentialDeposits;
+    type OnDust = ();
+}
+
+// ----------------- //
+
+/// Native currency and test `BTC` currency of `orml_tokens`
+pub struct MockCurrencies;
+
+impl MultiCurrency<<Test as system::Config>::AccountId> for MockCurrencies {
+    type CurrencyId = CurrencyId;
+    type Balance = u128;
+
+    fn free_balance(
+        currency_id: CurrencyId,
+        who: &<Test as system::Config>::AccountId,
+    ) -> Self::Balance {
+        match currency_id {
+            CurrencyId::Pont => NativeCurrency::<Test>::free_balance((), who),
+            CurrencyId::Btc => OrmlCurrencies::<Tokens>::free_balance(currency_id, who),
+        }
+    }
+
+    fn deposit(
+        currency_id: CurrencyId,
+        who: &<Test as system::Config>::AccountId,
+        amount: Self::Balance,
+    ) -> DispatchResult {
+        match currency_id {
+            CurrencyId::Pont => NativeCurrency::<Test>::deposit((), who, amount),
+            CurrencyId::Btc => OrmlCurrencies::<Tokens>::deposit(currency_id, who, amount),
+        }
+    }
+
+    fn withdraw(
+        currency_id: CurrencyId,
+        who: &<Test as system::Config>::AccountId,
+        amount: Self::Balance,
+    ) -> DispatchResult {
+        match currency_id {
+            CurrencyId::Pont => NativeCurrency::<Test>::withdraw((), who, amount),
+            CurrencyId::Btc => OrmlCurrencies::<Tokens>::withdraw(currency_id, who, amount),
+        }
+    }
+}
+
+parameter_types! {
+    pub const MoveTickers: &'static [(&'static str, CurrencyId)] =
+        &[("PONT", CurrencyId::Pont), ("BTC", CurrencyId::Btc)];
+}
+
+parameter_types! {
+    /// Oracle prices live 10 blocks.
+    pub const OraclePriceLifetime: u64 = 10 * TIME_BLOCK_MULTIPLIER;
+}
+
+parameter_types! {
+    /// Multi-signer script approvals live 10 blocks.
+    pub const ScriptApprovalLifetime: u64 = 10;
+    pub const ScriptApprovalDeposit: u128 = 1000;
+}
+
+thread_local! {
+    static EXISTENTIAL_DEPOSIT: RefCell<u128> = RefCell::new(1);
+    static STORAGE_DEPOSIT_PER_BYTE: RefCell<u128> = RefCell::new(0);
+    static STORAGE_QUOTA: RefCell<(u32, u64)> = RefCell::new((u32::MAX, u64::MAX));
+    static PUBLISH_ALLOWLIST: RefCell<Option<Vec<sp_core::sr25519::Public>>> = RefCell::new(None);
+}
+
+/// Existential deposit is 1 unless set by the test.
+pub struct ExistentialDeposit;
+
+impl ExistentialDeposit {
+    pub fn set(deposit: u128) {
+        EXISTENTIAL_DEPOSIT.with(|v| *v.borrow_mut() = deposit);
+    }
+}
+
+impl Get<u128> for ExistentialDeposit {
+    fn get() -> u128 {
+        EXISTENTIAL_DEPOSIT.with(|v| *v.borrow())
+    }
+}
+
+/// Storage deposit is free unless set by the test.
+pub struct StorageDepositPerByte;
+
+impl StorageDepositPerByte {
+    pub fn set(deposit: u128) {
+        STORAGE_DEPOSIT_PER_BYTE.with(|v| *v.borrow_mut() = deposit);
+    }
+}
+
+impl Get<u128> for StorageDepositPerByte {
+    fn get() -> u128 {
+        STORAGE_DEPOSIT_PER_BYTE.with(|v| *v.borrow())
+    }
+}
+
+/// Storage is unlimited unless the quota is set by the test.
+pub struct StorageQuota;
+
+impl StorageQuota {
+    pub fn set(keys: u32, bytes: u64) {
+        STORAGE_QUOTA.with(|v| *v.borrow_mut() = (keys, bytes));
+    }
+}
+
+pub struct StorageQuotaKeys;
+
+impl Get<u32> for StorageQuotaKeys {
+    fn get() -> u32 {
+        STORAGE_QUOTA.with(|v| v.borrow().0)
+    }
+}
+
+pub struct StorageQuotaBytes;
+
+impl Get<u64> for StorageQuotaBytes {
+    fn get() -> u64 {
+        STORAGE_QUOTA.with(|v| v.borrow().1)
+    }
+}
+
+/// Any signed account publishes modules unless the allowlist is set by the test.
+pub struct PublishAllowlist;
+
+impl PublishAllowlist {
+    pub fn set(accounts: Option<Vec<sp_core::sr25519::Public>>) {
+        PUBLISH_ALLOWLIST.with(|v| *v.borrow_mut() = accounts);
+    }
+}
+
+impl EnsureOrigin<Origin> for PublishAllowlist {
+    type Success = sp_core::sr25519::Public;
+
+    fn try_origin(o: Origin) -> Result<Self::Success, Origin> {
+        let who = system::EnsureSigned::try_origin(o)?;
+        let allowed = PUBLISH_ALLOWLIST.with(|v| {
+            v.borrow()
+                .as_ref()
+                .map_or(true, |accounts| accounts.contains(&who))
+        });
+        if allowed {
+            Ok(who)
+        } else {
+            Err(Origin::signed(who))
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn successful_origin() -> Origin {
+        Origin::signed(Default::default())
+    }
+}
+
 impl sp_mvm::Config for Test {
     // type Event = TestEvent;
     type Event = Event;
     type GasWeightMapping = MoveVMGasWeightMapping;
+    type WeightInfo = ();
+    type Currencies = MockCurrencies;
+    type Tickers = ConstTickers<MoveTickers>;
+    type Oracle = sp_mvm::oracle::StorageOracle<Test>;
+    type OraclePriceLifetime = OraclePriceLifetime;
+    type StorageDepositPerByte = StorageDepositPerByte;
+    type StorageQuotaKeys = StorageQuotaKeys;
+    type StorageQuotaBytes = StorageQuotaBytes;
+    type ScriptApprovalLifetime = ScriptApprovalLifetime;
+    type ScriptApprovalDeposit = ScriptApprovalDeposit;
+    type Currency = Balances;
+    type GasFees = ();
+    type StdPublishOrigin = system::EnsureRoot<sp_core::sr25519::Public>;
+    type ModulePublishOrigin = PublishAllowlist;
 }
 
 pub type Sys = system::Module<Test>;
@@ -148,6 +370,11 @@ impl Oracle for MockOracle {
 
 /// Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
+    new_test_ext_with_mvm(Default::default())
+}
+
+/// Build genesis storage according to the mock runtime with given initial Move state.
+pub fn new_test_ext_with_mvm(mvm: sp_mvm::GenesisConfig) -> sp_io::TestExternalities {
     let mut sys = system::GenesisConfig::default()
         .build_storage::<Test>()
         .expect("Frame system builds valid default genesis config");
@@ -174,6 +401,9 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
     .assimilate_storage(&mut sys)
     .expect("Pallet balances storage can be assimilated");
 
+    GenesisBuild::<Test>::assimilate_storage(&mvm, &mut sys)
+        .expect("Pallet mvm storage can be assimilated");
+
     sys.into()
 }
 
@@ -0,0 +1,147 @@
+use frame_support::{assert_noop, assert_ok};
+use move_vm::data::Storage;
+use sp_mvm::{Error, StorageDepositor, StorageDeposits};
+use sp_mvm::storage::{MoveVmStorage, ObservedStorage};
+
+mod common;
+use common::assets::*;
+use common::mock::*;
+use common::addr::*;
+use common::utils;
+
+const GAS_LIMIT: u64 = 1_000_000;
+
+fn reserved(account: &utils::AccountId) -> u128 {
+    balances::Pallet::<Test>::reserved_balance(account)
+}
+
+#[test]
+/// published module and stored resource are paid by the writer
+fn storage_deposit_reserved() {
+    new_test_ext().execute_with(|| {
+        StorageDepositPerByte::set(2);
+        let account = origin_ps_acc();
+
+        utils::publish_module(account, UserMod::Store);
+        let module_deposit = Mvm::storage_deposit(&account);
+        assert!(module_deposit > 2 * UserMod::Store.bc().len() as u128);
+        assert_eq!(reserved(&account), module_deposit);
+
+        assert_ok!(utils::execute_tx_unchecked(
+            Origin::signed(account),
+            UserTx::StoreU64,
+            GAS_LIMIT
+        ));
+        let total_deposit = Mvm::storage_deposit(&account);
+        assert!(total_deposit > module_deposit);
+        assert_eq!(reserved(&account), total_deposit);
+        assert_eq!(
+            balances::Pallet::<Test>::free_balance(&account),
+            INITIAL_BALANCE - total_deposit
+        );
+    });
+}
+
+#[test]
+/// deposit is released with the removed entry
+fn storage_deposit_released() {
+    new_test_ext().execute_with(|| {
+        StorageDepositPerByte::set(1);
+        let account = origin_ps_acc();
+        utils::publish_module(account, UserMod::Store);
+
+        let keys = StorageDeposits::<Test>::iter()
+            .map(|(key, _)| key)
+            .collect::<Vec<_>>();
+        assert_eq!(keys.len(), 1);
+
+        ObservedStorage::<_, Mvm>::new(Mvm::move_vm_storage()).remove(&keys[0]);
+        assert_eq!(Mvm::storage_deposit(&account), 0);
+        assert_eq!(reserved(&account), 0);
+        assert!(!StorageDeposits::<Test>::contains_key(&keys[0]));
+    });
+}
+
+#[test]
+/// growth of the existing entry is paid by the writer, shrinking releases the writer share first
+fn storage_deposit_paid_by_writer() {
+    new_test_ext().execute_with(|| {
+        StorageDepositPerByte::set(1);
+        let account = origin_ps_acc();
+        utils::publish_module(account, UserMod::Store);
+        let deposit = Mvm::storage_deposit(&account);
+
+        let (key, shares) = StorageDeposits::<Test>::iter().next().unwrap();
+        assert_eq!(shares, vec![(account, deposit)]);
+        let storage = ObservedStorage::<_, Mvm>::new(Mvm::move_vm_storage());
+        let value = storage.get(&key).unwrap();
+
+        // grown by another account:
+        StorageDepositor::<Test>::put(root_ps_acc());
+        storage.insert(&key, &[&value[..], &[0; 10]].concat());
+        assert_eq!(Mvm::storage_deposit(&account), deposit);
+        assert_eq!(reserved(&account), deposit);
+        assert_eq!(Mvm::storage_deposit(&root_ps_acc()), 10);
+        assert_eq!(reserved(&root_ps_acc()), 10);
+        assert_eq!(
+            StorageDeposits::<Test>::get(&key),
+            vec![(account, deposit), (root_ps_acc(), 10)]
+        );
+
+        // shrunk below the creator size:
+        storage.insert(&key, &value[..value.len() - 10]);
+        assert_eq!(Mvm::storage_deposit(&root_ps_acc()), 0);
+        assert_eq!(reserved(&root_ps_acc()), 0);
+        assert_eq!(Mvm::storage_deposit(&account), deposit - 10);
+        assert_eq!(reserved(&account), deposit - 10);
+        assert_eq!(
+            StorageDeposits::<Test>::get(&key),
+            vec![(account, deposit - 10)]
+        );
+
+        storage.remove(&key);
+        assert_eq!(Mvm::storage_deposit(&account), 0);
+        assert_eq!(reserved(&account), 0);
+        assert!(!StorageDeposits::<Test>::contains_key(&key));
+        StorageDepositor::<Test>::kill();
+    });
+}
+
+#[test]
+/// nothing is written when deposit can't be reserved
+fn insufficient_storage_deposit() {
+    new_test_ext().execute_with(|| {
+        StorageDepositPerByte::set(INITIAL_BALANCE);
+        let account = origin_ps_acc();
+
+        assert_noop!(
+            Mvm::publish_module(
+                Origin::signed(account),
+                UserMod::Store.bc().to_vec(),
+                GAS_LIMIT
+            ),
+            Error::<Test>::InsufficientStorageDeposit
+        );
+        assert_eq!(
+            Mvm::get_module(
+                origin_move_addr().as_ref(),
+                UserMod::Store.name().as_bytes()
+            ),
+            Ok(None)
+        );
+    });
+}
+
+#[test]
+/// std modules published by root are free
+fn std_storage_deposit() {
+    new_test_ext().execute_with(|| {
+        StorageDepositPerByte::set(1);
+        assert_ok!(Mvm::publish_std(
+            Origin::root(),
+            vec![StdMod::all()[0].bc().to_vec()],
+            GAS_LIMIT
+        ));
+        assert_eq!(StorageDeposits::<Test>::iter().count(), 0);
+    });
+}
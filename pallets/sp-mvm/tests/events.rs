@@ -0,0 +1,74 @@
+use frame_support::assert_ok;
+use move_core_types::identifier::Identifier;
+use move_core_types::language_storage::{StructTag, TypeTag};
+use sp_mvm::types::MoveValue;
+
+mod common;
+use common::assets::*;
+use common::mock::*;
+use common::addr::*;
+
+const GAS_LIMIT: u64 = 1_000_000;
+
+fn emit_events() {
+    common::utils::publish_module(root_ps_acc(), StdMod::Event);
+    common::utils::publish_module(origin_ps_acc(), UserMod::EventProxy);
+    roll_next_block();
+
+    assert_ok!(Mvm::execute(
+        Origin::signed(origin_ps_acc()),
+        UserTx::EmitEvent.bc().to_vec(),
+        GAS_LIMIT
+    ));
+}
+
+fn expected_value() -> MoveValue {
+    MoveValue::Struct(vec![(b"val".to_vec(), MoveValue::U64(42))])
+}
+
+#[test]
+/// events of the block are decoded by the layout of the event type
+fn block_events() {
+    new_test_ext().execute_with(|| {
+        emit_events();
+
+        let events = Mvm::block_events();
+        assert_eq!(events.len(), 2);
+        let ty_tag = TypeTag::Struct(StructTag {
+            address: origin_move_addr(),
+            module: Identifier::new(UserMod::EventProxy.name()).unwrap(),
+            name: Identifier::new("U64").unwrap(),
+            type_params: vec![],
+        })
+        .to_string();
+        for event in events {
+            assert_eq!(event.sender, origin_move_addr().as_ref().to_vec());
+            assert_eq!(String::from_utf8(event.ty_tag).unwrap(), ty_tag);
+            assert_eq!(event.message, 42u64.to_le_bytes().to_vec());
+            assert_eq!(event.value, Some(expected_value()));
+        }
+    });
+}
+
+#[test]
+/// event bytes are decoded by the given type tag
+fn decode_event() {
+    new_test_ext().execute_with(|| {
+        emit_events();
+        let bcs = 42u64.to_le_bytes();
+
+        let ty_tag = format!(
+            "0x{}::{}::U64",
+            origin_move_addr(),
+            UserMod::EventProxy.name()
+        );
+        assert_eq!(
+            Mvm::decode_event(ty_tag.as_bytes(), &bcs),
+            Ok(expected_value())
+        );
+        assert_eq!(Mvm::decode_event(b"u64", &bcs), Ok(MoveValue::U64(42)));
+
+        assert!(Mvm::decode_event(b"0x1::Missing::U64", &bcs).is_err());
+        assert!(Mvm::decode_event(b"u128", &bcs).is_err());
+    });
+}
@@ -0,0 +1,214 @@
+use frame_support::assert_ok;
+use frame_support::weights::{DispatchInfo, PostDispatchInfo, Pays};
+use sp_runtime::traits::SignedExtension;
+use sp_runtime::transaction_validity::InvalidTransaction;
+use sp_runtime::DispatchError;
+use sp_mvm::fee::{ChargeGasPrice, GAS_PRICE_TOO_LOW};
+use sp_mvm::gas::GasWeightMapping;
+use sp_mvm::MinGasPrice;
+use transaction_payment::OnChargeTransaction;
+
+mod common;
+use common::assets::*;
+use common::mock::*;
+use common::addr::*;
+use common::utils;
+
+const GAS_LIMIT: u64 = 1_000;
+
+fn execute_call() -> Call {
+    Call::Mvm(sp_mvm::Call::execute(
+        UserTx::StoreU64.bc().to_vec(),
+        GAS_LIMIT,
+    ))
+}
+
+fn info() -> DispatchInfo {
+    DispatchInfo {
+        weight: MoveVMGasWeightMapping::gas_to_weight(GAS_LIMIT),
+        ..Default::default()
+    }
+}
+
+fn free_balance(account: &utils::AccountId) -> u128 {
+    balances::Pallet::<Test>::free_balance(account)
+}
+
+#[test]
+/// gas price is the priority of the Move call
+fn gas_price_priority() {
+    new_test_ext().execute_with(|| {
+        let account = origin_ps_acc();
+        let validity = ChargeGasPrice::<Test>::from(7)
+            .validate(&account, &execute_call(), &info(), 0)
+            .unwrap();
+        assert_eq!(validity.priority, 7);
+
+        let transfer = Call::Balances(balances::Call::transfer(root_ps_acc(), 1));
+        let validity = ChargeGasPrice::<Test>::from(7)
+            .validate(&account, &transfer, &info(), 0)
+            .unwrap();
+        assert_eq!(validity.priority, 0);
+    });
+}
+
+#[test]
+/// Move calls below the min gas price are rejected, the others aren't affected
+fn min_gas_price() {
+    new_test_ext().execute_with(|| {
+        roll_next_block();
+        let account = origin_ps_acc();
+        assert_ok!(Mvm::set_min_gas_price(Origin::root(), 5));
+        assert_eq!(MinGasPrice::<Test>::get(), 5);
+        assert_eq!(last_event(), MoveEvent::MinGasPriceSet(5).into());
+
+        assert_eq!(
+            ChargeGasPrice::<Test>::from(4).validate(&account, &execute_call(), &info(), 0),
+            Err(InvalidTransaction::Custom(GAS_PRICE_TOO_LOW).into())
+        );
+        assert_ok!(ChargeGasPrice::<Test>::from(5).validate(
+            &account,
+            &execute_call(),
+            &info(),
+            0
+        ));
+
+        let transfer = Call::Balances(balances::Call::transfer(root_ps_acc(), 1));
+        assert_ok!(ChargeGasPrice::<Test>::from(0).validate(&account, &transfer, &info(), 0));
+
+        assert!(Mvm::set_min_gas_price(Origin::signed(account), 1).is_err());
+    });
+}
+
+#[test]
+/// gas limit can't be paid by the sender
+fn gas_fee_not_payable() {
+    new_test_ext().execute_with(|| {
+        let price = INITIAL_BALANCE as u64 / GAS_LIMIT + 1;
+        assert_eq!(
+            ChargeGasPrice::<Test>::from(price).pre_dispatch(
+                &origin_ps_acc(),
+                &execute_call(),
+                &info(),
+                0
+            ),
+            Err(InvalidTransaction::Payment.into())
+        );
+        assert_eq!(free_balance(&origin_ps_acc()), INITIAL_BALANCE);
+    });
+}
+
+#[test]
+/// gas limit is paid upfront, unused gas is refunded
+fn unused_gas_refunded() {
+    new_test_ext().execute_with(|| {
+        const GAS_PRICE: u64 = 10;
+        const GAS_USED: u64 = 400;
+        let account = origin_ps_acc();
+
+        let pre = ChargeGasPrice::<Test>::from(GAS_PRICE)
+            .pre_dispatch(&account, &execute_call(), &info(), 0)
+            .unwrap();
+        assert_eq!(
+            free_balance(&account),
+            INITIAL_BALANCE - (GAS_LIMIT * GAS_PRICE) as u128
+        );
+
+        let post_info = PostDispatchInfo {
+            actual_weight: Some(MoveVMGasWeightMapping::gas_to_weight(GAS_USED)),
+            pays_fee: Pays::Yes,
+        };
+        assert_ok!(ChargeGasPrice::<Test>::post_dispatch(
+            pre,
+            &info(),
+            &post_info,
+            0,
+            &Ok(())
+        ));
+        assert_eq!(
+            free_balance(&account),
+            INITIAL_BALANCE - (GAS_USED * GAS_PRICE) as u128
+        );
+    });
+}
+
+#[test]
+/// calls failed before the VM run use no gas, the whole gas fee is refunded
+fn gas_refunded_on_early_failure() {
+    new_test_ext().execute_with(|| {
+        const GAS_PRICE: u64 = 10;
+        let account = origin_ps_acc();
+
+        let pre = ChargeGasPrice::<Test>::from(GAS_PRICE)
+            .pre_dispatch(&account, &execute_call(), &info(), 0)
+            .unwrap();
+        assert_ok!(ChargeGasPrice::<Test>::post_dispatch(
+            pre,
+            &info(),
+            &PostDispatchInfo {
+                actual_weight: None,
+                pays_fee: Pays::Yes,
+            },
+            0,
+            &Err(DispatchError::BadOrigin)
+        ));
+        assert_eq!(free_balance(&account), INITIAL_BALANCE);
+    });
+}
+
+type Payment = <Test as transaction_payment::Config>::OnChargeTransaction;
+
+#[test]
+/// weight fee of the Move calls doesn't include the gas, it's paid by the gas price
+fn weight_fee_excludes_gas() {
+    new_test_ext().execute_with(|| {
+        const BASE_WEIGHT: u64 = 1_000;
+        const GAS_USED: u64 = 400;
+        let account = origin_ps_acc();
+        let info = DispatchInfo {
+            weight: BASE_WEIGHT + MoveVMGasWeightMapping::gas_to_weight(GAS_LIMIT),
+            ..Default::default()
+        };
+
+        // `IdentityFee`, the fee is the weight:
+        let paid =
+            Payment::withdraw_fee(&account, &execute_call(), &info, info.weight as u128, 0)
+                .unwrap();
+        assert_eq!(
+            free_balance(&account),
+            INITIAL_BALANCE - BASE_WEIGHT as u128
+        );
+
+        let actual_weight = BASE_WEIGHT + MoveVMGasWeightMapping::gas_to_weight(GAS_USED);
+        let post_info = PostDispatchInfo {
+            actual_weight: Some(actual_weight),
+            pays_fee: Pays::Yes,
+        };
+        assert_ok!(Payment::correct_and_deposit_fee(
+            &account,
+            &info,
+            &post_info,
+            actual_weight as u128,
+            0,
+            paid
+        ));
+        assert_eq!(
+            free_balance(&account),
+            INITIAL_BALANCE - BASE_WEIGHT as u128
+        );
+
+        // the other calls pay the whole weight fee:
+        let transfer = Call::Balances(balances::Call::transfer(root_ps_acc(), 1));
+        assert_ok!(Payment::withdraw_fee(
+            &account,
+            &transfer,
+            &info,
+            BASE_WEIGHT as u128,
+            0
+        ));
+        assert_eq!(
+            free_balance(&account),
+            INITIAL_BALANCE - 2 * BASE_WEIGHT as u128
+        );
+    });
+}
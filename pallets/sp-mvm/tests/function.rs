@@ -0,0 +1,147 @@
+use serde::Deserialize;
+use frame_support::{assert_ok, assert_err_ignore_postinfo};
+use frame_support::dispatch::DispatchResultWithPostInfo;
+use frame_support::weights::GetDispatchInfo;
+use move_core_types::identifier::Identifier;
+use move_core_types::language_storage::StructTag;
+use sp_mvm::Error;
+use sp_mvm::types::MoveModuleId;
+use sp_mvm::WeightInfo;
+
+mod common;
+use common::assets::*;
+use common::mock::*;
+use common::addr::*;
+use common::utils;
+
+const GAS_LIMIT: u64 = 1_000_000;
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct StoreU64 {
+    pub val: u64,
+}
+
+fn call_function(
+    module: UserMod,
+    function: &str,
+    args: Vec<Vec<u8>>,
+) -> DispatchResultWithPostInfo {
+    let result = Mvm::execute_function(
+        Origin::signed(origin_ps_acc()),
+        MoveModuleId {
+            owner: origin_ps_acc(),
+            module: module.name().as_bytes().to_vec(),
+        },
+        function.as_bytes().to_vec(),
+        vec![],
+        args,
+        GAS_LIMIT,
+    );
+    eprintln!("execute_function result: {:?}", result);
+    result
+}
+
+#[test]
+/// public function is called with the sender as signer
+fn execute_function() {
+    new_test_ext().execute_with(|| {
+        utils::publish_module(origin_ps_acc(), UserMod::Store);
+
+        assert_ok!(call_function(
+            UserMod::Store,
+            "store_u64",
+            vec![42u64.to_le_bytes().to_vec()]
+        ));
+
+        let tag = StructTag {
+            address: origin_move_addr(),
+            module: Identifier::new(UserMod::Store.name()).unwrap(),
+            name: Identifier::new("U64").unwrap(),
+            type_params: vec![],
+        };
+        utils::check_storage_res(origin_move_addr(), tag, StoreU64 { val: 42 });
+    });
+}
+
+#[test]
+fn execute_function_not_found() {
+    new_test_ext().execute_with(|| {
+        assert_err_ignore_postinfo!(
+            call_function(UserMod::Store, "store_u64", vec![]),
+            Error::<Test>::FunctionNotFound
+        );
+
+        utils::publish_module(origin_ps_acc(), UserMod::Store);
+        assert_err_ignore_postinfo!(
+            call_function(UserMod::Store, "missing", vec![]),
+            Error::<Test>::FunctionNotFound
+        );
+    });
+}
+
+#[test]
+fn execute_function_invalid_args() {
+    new_test_ext().execute_with(|| {
+        utils::publish_module(origin_ps_acc(), UserMod::Store);
+
+        // missing argument:
+        assert_err_ignore_postinfo!(
+            call_function(UserMod::Store, "store_u64", vec![]),
+            Error::<Test>::InvalidFunctionArguments
+        );
+        // u64 expected:
+        assert_err_ignore_postinfo!(
+            call_function(UserMod::Store, "store_u64", vec![vec![42]]),
+            Error::<Test>::InvalidFunctionArguments
+        );
+    });
+}
+
+#[test]
+/// struct can't be returned from the function call
+fn execute_function_unsupported_signature() {
+    new_test_ext().execute_with(|| {
+        utils::publish_std();
+        utils::publish_module(origin_ps_acc(), UserMod::EventProxy);
+
+        assert_err_ignore_postinfo!(
+            call_function(
+                UserMod::EventProxy,
+                "create_val",
+                vec![42u64.to_le_bytes().to_vec()]
+            ),
+            Error::<Test>::UnsupportedFunctionSignature
+        );
+    });
+}
+
+#[test]
+/// declared weight is for the largest module, the call reports the size of the called one
+fn execute_function_weight() {
+    new_test_ext().execute_with(|| {
+        utils::publish_module(origin_ps_acc(), UserMod::Store);
+
+        let args = vec![42u64.to_le_bytes().to_vec()];
+        let base_weight =
+            <() as WeightInfo>::execute_function(8, UserMod::Store.bc().len() as u32);
+        let weight = sp_mvm::Call::<Test>::execute_function(
+            MoveModuleId {
+                owner: origin_ps_acc(),
+                module: UserMod::Store.name().as_bytes().to_vec(),
+            },
+            b"store_u64".to_vec(),
+            vec![],
+            args.clone(),
+            GAS_LIMIT,
+        )
+        .get_dispatch_info()
+        .weight;
+
+        let actual_weight = call_function(UserMod::Store, "store_u64", args)
+            .unwrap()
+            .actual_weight
+            .unwrap();
+        assert!(actual_weight > base_weight);
+        assert!(actual_weight < weight);
+    });
+}
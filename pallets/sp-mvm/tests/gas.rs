@@ -1,10 +1,14 @@
 use frame_support::dispatch;
+use frame_support::weights::GetDispatchInfo;
 use sp_runtime::DispatchError;
+use sp_mvm::gas::GasWeightMapping;
+use sp_mvm::WeightInfo;
 
 mod common;
 use common::assets::*;
 use common::mock::*;
 use common::addr::*;
+use common::utils;
 
 /// Publish module then return result
 fn call_publish_module(
@@ -116,3 +120,94 @@ fn execute_gas_limit() {
         }
     });
 }
+
+#[test]
+/// every package module is estimated, nothing is published
+fn estimate_publish_package() {
+    new_test_ext().execute_with(|| {
+        common::utils::publish_std();
+
+        let package = UsrPackages::Assets;
+        let results =
+            Mvm::estimate_publish_package(&origin_ps_acc(), package.bc(), 1_000_000).unwrap();
+
+        let names = results.iter().map(|(name, _)| name.as_slice());
+        assert!(names.eq(package.modules().iter().map(|name| name.as_bytes())));
+        for (_, res) in results.iter() {
+            assert!(sp_mvm::result::is_ok(res));
+            assert!(res.gas_used > 0);
+        }
+
+        assert_eq!(Mvm::list_modules(origin_move_addr().as_ref()), Ok(vec![]));
+    });
+}
+
+#[test]
+/// estimation stops at the module out of gas
+fn estimate_publish_std_out_of_gas() {
+    new_test_ext().execute_with(|| {
+        let modules = StdMod::all()
+            .iter()
+            .map(|m| m.bc().to_vec())
+            .collect::<Vec<_>>();
+
+        let results = Mvm::estimate_publish_std(modules.clone(), 1_000_000).unwrap();
+        assert_eq!(results.len(), modules.len());
+        assert!(results.iter().all(|(_, res)| sp_mvm::result::is_ok(res)));
+
+        // enough for the first module only:
+        let gas_limit = results[0].1.gas_used + 1;
+        let results = Mvm::estimate_publish_std(modules, gas_limit).unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[1].0, StdMod::all()[1].name().as_bytes().to_vec());
+        assert!(!sp_mvm::result::is_ok(&results[1].1));
+    });
+}
+
+#[test]
+/// weight is the benchmarked base weight and the gas
+fn execute_weight() {
+    new_test_ext().execute_with(|| {
+        const GAS_LIMIT: u64 = 1_000_000;
+        let origin = origin_ps_acc();
+        utils::publish_module(origin, UserMod::Store);
+
+        let txbc = UserTx::StoreU64.bc().to_vec();
+        let base_weight = <() as WeightInfo>::execute(txbc.len() as u32);
+        let weight = sp_mvm::Call::<Test>::execute(txbc.clone(), GAS_LIMIT)
+            .get_dispatch_info()
+            .weight;
+        assert_eq!(
+            weight,
+            base_weight + MoveVMGasWeightMapping::gas_to_weight(GAS_LIMIT)
+        );
+
+        let actual_weight =
+            call_execute_script(Origin::signed(origin), UserTx::StoreU64, GAS_LIMIT)
+                .unwrap()
+                .actual_weight
+                .unwrap();
+        assert!(actual_weight > base_weight);
+        assert!(actual_weight < weight);
+    });
+}
+
+#[test]
+/// package weight has the module count component
+fn publish_package_weight() {
+    new_test_ext().execute_with(|| {
+        const GAS_LIMIT: u64 = 1_000_000;
+        let package = UsrPackages::Assets;
+        let bc = package.bc().to_vec();
+
+        let base_weight =
+            <() as WeightInfo>::publish_package(bc.len() as u32, package.modules().len() as u32);
+        let weight = sp_mvm::Call::<Test>::publish_package(bc, GAS_LIMIT)
+            .get_dispatch_info()
+            .weight;
+        assert_eq!(
+            weight,
+            base_weight + MoveVMGasWeightMapping::gas_to_weight(GAS_LIMIT)
+        );
+    });
+}
@@ -0,0 +1,78 @@
+use std::convert::TryFrom;
+use move_vm::types::ModulePackage;
+use sp_mvm::GenesisConfig;
+
+mod common;
+use common::assets::*;
+use common::mock::*;
+use common::addr::*;
+use common::utils;
+
+fn std_genesis() -> GenesisConfig {
+    GenesisConfig {
+        stdlib: StdMod::all().iter().map(|m| m.bc().to_vec()).collect(),
+        ..Default::default()
+    }
+}
+
+#[test]
+/// std modules are in storage right after genesis
+fn genesis_std() {
+    new_test_ext_with_mvm(std_genesis()).execute_with(|| {
+        let root = root_ps_acc();
+        for module in StdMod::all().iter() {
+            utils::check_storage_mod_raw(root, module.bc(), module.name());
+        }
+    });
+}
+
+#[test]
+/// packages are published after the std
+fn genesis_packages() {
+    let package = RootPackages::Assets;
+    let config = GenesisConfig {
+        packages: vec![package.bc().to_vec()],
+        ..std_genesis()
+    };
+
+    new_test_ext_with_mvm(config).execute_with(|| {
+        let (modules, _) = ModulePackage::try_from(package.bc())
+            .unwrap()
+            .into_tx(ROOT_ADDR)
+            .into_inner();
+
+        for (i, bc) in modules.iter().enumerate() {
+            utils::check_storage_mod_raw(root_ps_acc(), bc, package.modules()[i]);
+        }
+    });
+}
+
+#[test]
+/// raw storage entries are inserted as is
+fn genesis_raw_storage() {
+    let key = vec![1, 2, 3];
+    let value = vec![4, 5, 6];
+    let config = GenesisConfig {
+        storage: vec![(key.clone(), value.clone())],
+        ..Default::default()
+    };
+
+    new_test_ext_with_mvm(config).execute_with(|| {
+        // not an access path, so the whole key is the first one:
+        assert_eq!(
+            Some(value),
+            sp_mvm::VMStorage::<Test>::get(key, Vec::<u8>::new())
+        );
+    });
+}
+
+#[test]
+#[should_panic]
+/// genesis with broken module can't be built
+fn genesis_invalid_module() {
+    let config = GenesisConfig {
+        stdlib: vec![vec![0, 1, 2]],
+        ..Default::default()
+    };
+    new_test_ext_with_mvm(config);
+}
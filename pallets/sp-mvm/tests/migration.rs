@@ -0,0 +1,125 @@
+use codec::Encode;
+use frame_support::storage::{unhashed, StoragePrefixedMap};
+use frame_support::traits::OnRuntimeUpgrade;
+use sp_mvm::{StorageVersion, VMStorage};
+use sp_mvm::migration::{self, Releases};
+use sp_mvm::storage::{split_key, MoveVmStorage};
+use move_vm::data::Storage;
+
+mod common;
+use common::assets::*;
+use common::mock::*;
+use common::addr::*;
+use common::utils;
+
+/// Rewrite the entry in the flat `VMStorage` layout.
+fn into_flat_entry(key: &[u8], value: &[u8]) {
+    let (address, path) = split_key(key);
+    VMStorage::<Test>::remove(address, path);
+
+    let encoded = key.to_vec().encode();
+    let raw_key = [
+        &VMStorage::<Test>::final_prefix()[..],
+        &sp_io::hashing::blake2_128(&encoded)[..],
+        &encoded[..],
+    ]
+    .concat();
+    unhashed::put(&raw_key, &value.to_vec());
+}
+
+#[test]
+/// genesis is built with the latest layout
+fn genesis_storage_version() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(StorageVersion::<Test>::get(), Releases::LATEST);
+        assert_eq!(migration::pre_upgrade::<Test>(), Ok(0));
+        assert_eq!(migration::post_upgrade::<Test>(0), Ok(()));
+    });
+}
+
+#[test]
+/// flat entries are moved to the account layout
+fn migrate_flat_vm_storage() {
+    new_test_ext().execute_with(|| {
+        utils::publish_module(origin_ps_acc(), UserMod::Store);
+        let address = origin_move_addr();
+        let entries = Mvm::account_storage(address.as_ref()).unwrap();
+        assert_eq!(entries.len(), 1);
+
+        let raw = (vec![1, 2, 3], vec![4, 5, 6]);
+        for (key, value) in entries.iter().chain(Some(&raw)) {
+            into_flat_entry(key, value);
+        }
+        StorageVersion::<Test>::put(Releases::V1_0_0);
+        assert_eq!(Mvm::account_storage(address.as_ref()), Ok(vec![]));
+        assert_eq!(Mvm::move_vm_storage().get(&raw.0), None);
+
+        let count = migration::pre_upgrade::<Test>().unwrap();
+        assert_eq!(count, 2);
+        Mvm::on_runtime_upgrade();
+        assert_eq!(migration::post_upgrade::<Test>(count), Ok(()));
+
+        assert_eq!(StorageVersion::<Test>::get(), Releases::LATEST);
+        assert_eq!(Mvm::account_storage(address.as_ref()), Ok(entries));
+        assert_eq!(Mvm::move_vm_storage().get(&raw.0), Some(raw.1));
+        utils::check_storage_mod_raw(origin_ps_acc(), UserMod::Store.bc(), UserMod::Store.name());
+    });
+}
+
+#[test]
+/// steps up to the recorded version are skipped
+fn migration_skipped() {
+    new_test_ext().execute_with(|| {
+        utils::publish_module(origin_ps_acc(), UserMod::Store);
+        let entries = Mvm::account_storage(origin_move_addr().as_ref()).unwrap();
+        into_flat_entry(&entries[0].0, &entries[0].1);
+
+        let count = migration::pre_upgrade::<Test>().unwrap();
+        Mvm::on_runtime_upgrade();
+        assert_eq!(
+            migration::post_upgrade::<Test>(count),
+            Err("Flat VMStorage entries left")
+        );
+        assert_eq!(
+            Mvm::account_storage(origin_move_addr().as_ref()),
+            Ok(vec![])
+        );
+    });
+}
+
+#[test]
+/// lost entries fail the post-upgrade check
+fn post_upgrade_lost_entries() {
+    new_test_ext().execute_with(|| {
+        utils::publish_module(origin_ps_acc(), UserMod::Store);
+        let count = migration::pre_upgrade::<Test>().unwrap();
+        VMStorage::<Test>::remove_all();
+
+        assert_eq!(
+            migration::post_upgrade::<Test>(count),
+            Err("Number of VMStorage entries changed")
+        );
+    });
+}
+
+#[test]
+#[cfg(feature = "try-runtime")]
+/// pallet upgrade checks keep the entries number between them
+fn try_runtime_upgrade_checks() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            Mvm::post_upgrade(),
+            Err("Storage pre-upgrade check wasn't run")
+        );
+
+        utils::publish_module(origin_ps_acc(), UserMod::Store);
+        assert_eq!(Mvm::pre_upgrade(), Ok(()));
+        Mvm::on_runtime_upgrade();
+        assert_eq!(Mvm::pre_upgrade(), Ok(()));
+        VMStorage::<Test>::remove_all();
+        assert_eq!(
+            Mvm::post_upgrade(),
+            Err("Number of VMStorage entries changed")
+        );
+    });
+}
@@ -0,0 +1,119 @@
+use move_core_types::language_storage::CORE_CODE_ADDRESS;
+use sp_mvm::types::{FunctionAbi, StructAbi};
+
+mod common;
+use common::assets::*;
+use common::mock::*;
+use common::addr::*;
+use common::utils;
+
+fn names(modules: &[&str]) -> Vec<Vec<u8>> {
+    modules
+        .iter()
+        .map(|name| name.as_bytes().to_vec())
+        .collect()
+}
+
+#[test]
+/// published module is listed and readable by name
+fn get_and_list_module() {
+    new_test_ext().execute_with(|| {
+        let address = origin_move_addr();
+        assert_eq!(Mvm::list_modules(address.as_ref()), Ok(vec![]));
+
+        utils::publish_module(origin_ps_acc(), UserMod::Store);
+
+        assert_eq!(
+            Mvm::list_modules(address.as_ref()),
+            Ok(names(&[UserMod::Store.name()]))
+        );
+        assert_eq!(
+            Mvm::get_module(address.as_ref(), UserMod::Store.name().as_bytes()),
+            Ok(Some(UserMod::Store.bc().to_vec()))
+        );
+        assert_eq!(
+            Mvm::get_module(address.as_ref(), UserMod::EventProxy.name().as_bytes()),
+            Ok(None)
+        );
+    });
+}
+
+#[test]
+/// every module of the package is listed
+fn list_package_modules() {
+    new_test_ext().execute_with(|| {
+        utils::publish_std();
+        utils::publish_package(origin_ps_acc(), UsrPackages::Assets, 1_000_000);
+
+        assert_eq!(
+            Mvm::list_modules(origin_move_addr().as_ref()),
+            Ok(names(UsrPackages::Assets.modules()))
+        );
+    });
+}
+
+#[test]
+/// std modules are listed under `0x1`
+fn list_std_modules() {
+    new_test_ext().execute_with(|| {
+        utils::publish_std();
+
+        let std = StdMod::all().iter().map(|m| m.name()).collect::<Vec<_>>();
+        assert_eq!(
+            Mvm::list_modules(CORE_CODE_ADDRESS.as_ref()),
+            Ok(names(&std))
+        );
+    });
+}
+
+#[test]
+fn invalid_address() {
+    new_test_ext().execute_with(|| {
+        assert!(Mvm::list_modules(&[1, 2, 3]).is_err());
+        assert!(Mvm::get_module(&[1, 2, 3], b"Store").is_err());
+    });
+}
+
+#[test]
+/// ABI of the published module
+fn module_abi() {
+    new_test_ext().execute_with(|| {
+        utils::publish_module(origin_ps_acc(), UserMod::Store);
+
+        let address = origin_move_addr();
+        let abi = Mvm::get_module_abi(address.as_ref(), UserMod::Store.name().as_bytes())
+            .unwrap()
+            .unwrap();
+        assert_eq!(abi.address, address.as_ref().to_vec());
+        assert_eq!(abi.name, UserMod::Store.name().as_bytes().to_vec());
+
+        let store_u64 = abi
+            .functions
+            .iter()
+            .find(|f| f.name == b"store_u64")
+            .unwrap();
+        assert_eq!(
+            store_u64,
+            &FunctionAbi {
+                name: b"store_u64".to_vec(),
+                type_parameters: vec![],
+                parameters: vec![b"&signer".to_vec(), b"u64".to_vec()],
+                returns: vec![],
+                acquires: vec![],
+            }
+        );
+
+        let u64_struct = abi.structs.iter().find(|s| s.name == b"U64").unwrap();
+        assert_eq!(
+            u64_struct,
+            &StructAbi {
+                name: b"U64".to_vec(),
+                is_resource: true,
+                type_parameters: vec![],
+                fields: Some(vec![(b"val".to_vec(), b"u64".to_vec())]),
+            }
+        );
+
+        assert_eq!(Mvm::get_module_abi(address.as_ref(), b"Missing"), Ok(None));
+    });
+}
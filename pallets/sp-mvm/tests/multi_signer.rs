@@ -0,0 +1,274 @@
+use serde::Deserialize;
+use frame_support::{assert_noop, assert_ok, assert_err_ignore_postinfo};
+use sp_runtime::traits::Hash;
+use move_core_types::identifier::Identifier;
+use move_core_types::language_storage::StructTag;
+use sp_mvm::{Error, ScriptApprovalExpiries, ScriptApprovals};
+use sp_mvm::gas::GasWeightMapping;
+
+mod common;
+use common::assets::*;
+use common::mock::*;
+use common::addr::*;
+use common::utils;
+
+const GAS_LIMIT: u64 = 1_000_000;
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct StoreU64 {
+    pub val: u64,
+}
+
+fn store_u64_tag() -> StructTag {
+    StructTag {
+        address: origin_move_addr(),
+        module: Identifier::new(UserMod::Store.name()).unwrap(),
+        name: Identifier::new("U64").unwrap(),
+        type_params: vec![],
+    }
+}
+
+fn signers() -> Vec<utils::AccountId> {
+    vec![origin_ps_acc(), root_ps_acc()]
+}
+
+fn script_hash() -> <Test as frame_system::Config>::Hash {
+    <Test as frame_system::Config>::Hashing::hash_of(&(
+        &UserTx::StoreU64Multi.bc().to_vec(),
+        &signers(),
+    ))
+}
+
+fn reserved(account: &utils::AccountId) -> u128 {
+    balances::Pallet::<Test>::reserved_balance(account)
+}
+
+fn call_execute_multi_signer(
+    signer: utils::AccountId,
+) -> frame_support::dispatch::DispatchResultWithPostInfo {
+    call_execute_multi_signer_with_gas(signer, GAS_LIMIT)
+}
+
+fn call_execute_multi_signer_with_gas(
+    signer: utils::AccountId,
+    gas_limit: u64,
+) -> frame_support::dispatch::DispatchResultWithPostInfo {
+    let result = Mvm::execute_multi_signer(
+        Origin::signed(signer),
+        UserTx::StoreU64Multi.bc().to_vec(),
+        signers(),
+        gas_limit,
+    );
+    eprintln!("execute_multi_signer result: {:?}", result);
+    result
+}
+
+#[test]
+fn execute_multi_signer() {
+    new_test_ext().execute_with(|| {
+        utils::publish_std();
+        utils::publish_module(origin_ps_acc(), UserMod::Store);
+
+        // first approval only stores it with the deposit:
+        assert_ok!(call_execute_multi_signer(origin_ps_acc()));
+        assert_eq!(ScriptApprovals::<Test>::iter().count(), 1);
+        assert_eq!(reserved(&origin_ps_acc()), ScriptApprovalDeposit::get());
+
+        // last approval executes the script with both signers:
+        assert_ok!(call_execute_multi_signer(root_ps_acc()));
+        assert_eq!(ScriptApprovals::<Test>::iter().count(), 0);
+        assert_eq!(reserved(&origin_ps_acc()), 0);
+        assert_eq!(reserved(&root_ps_acc()), 0);
+
+        utils::check_storage_res(origin_move_addr(), store_u64_tag(), StoreU64 { val: 42 });
+        utils::check_storage_res(root_move_addr(), store_u64_tag(), StoreU64 { val: 42 });
+    });
+}
+
+#[test]
+/// approvals but the last one use no gas, so their gas fee is refunded
+fn execute_multi_signer_approval_weight() {
+    new_test_ext().execute_with(|| {
+        utils::publish_std();
+        utils::publish_module(origin_ps_acc(), UserMod::Store);
+        let gas_weight = MoveVMGasWeightMapping::gas_to_weight(GAS_LIMIT);
+
+        let approval = call_execute_multi_signer(origin_ps_acc()).unwrap();
+        let base_weight = approval.actual_weight.unwrap();
+        assert!(base_weight < gas_weight);
+
+        let execution = call_execute_multi_signer(root_ps_acc()).unwrap();
+        assert!(execution.actual_weight.unwrap() > base_weight);
+    });
+}
+
+#[test]
+fn execute_multi_signer_approved_twice() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(call_execute_multi_signer(origin_ps_acc()));
+        assert_err_ignore_postinfo!(
+            call_execute_multi_signer(origin_ps_acc()),
+            Error::<Test>::ScriptAlreadyApproved
+        );
+    });
+}
+
+#[test]
+fn execute_multi_signer_not_signer() {
+    new_test_ext().execute_with(|| {
+        let result = Mvm::execute_multi_signer(
+            Origin::signed(origin_ps_acc()),
+            UserTx::StoreU64Multi.bc().to_vec(),
+            vec![root_ps_acc(), root_ps_acc()],
+            GAS_LIMIT,
+        );
+        assert_err_ignore_postinfo!(result, Error::<Test>::NotScriptSigner);
+    });
+}
+
+#[test]
+fn execute_multi_signer_duplicate_signer() {
+    new_test_ext().execute_with(|| {
+        let result = Mvm::execute_multi_signer(
+            Origin::signed(origin_ps_acc()),
+            UserTx::StoreU64Multi.bc().to_vec(),
+            vec![origin_ps_acc(), origin_ps_acc()],
+            GAS_LIMIT,
+        );
+        assert_err_ignore_postinfo!(result, Error::<Test>::DuplicateScriptSigner);
+    });
+}
+
+#[test]
+fn execute_multi_signer_wrong_signers_num() {
+    new_test_ext().execute_with(|| {
+        // single signer for the script with two signers:
+        let result = Mvm::execute(
+            Origin::signed(origin_ps_acc()),
+            UserTx::StoreU64Multi.bc().to_vec(),
+            GAS_LIMIT,
+        );
+        assert_err_ignore_postinfo!(result, Error::<Test>::TransactionSignersNumError);
+
+        let result = Mvm::execute_multi_signer(
+            Origin::signed(origin_ps_acc()),
+            UserTx::StoreU64Multi.bc().to_vec(),
+            vec![origin_ps_acc()],
+            GAS_LIMIT,
+        );
+        assert_err_ignore_postinfo!(result, Error::<Test>::TransactionSignersNumError);
+    });
+}
+
+#[test]
+/// approvals with another gas limit are rejected
+fn execute_multi_signer_gas_limit_mismatch() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(call_execute_multi_signer(origin_ps_acc()));
+        assert_noop!(
+            call_execute_multi_signer_with_gas(root_ps_acc(), GAS_LIMIT * 2),
+            Error::<Test>::ScriptGasLimitMismatch
+        );
+    });
+}
+
+#[test]
+/// the last approver pays storage deposit of the script writes
+fn execute_multi_signer_storage_depositor() {
+    new_test_ext().execute_with(|| {
+        utils::publish_std();
+        utils::publish_module(origin_ps_acc(), UserMod::Store);
+        StorageDepositPerByte::set(1);
+
+        assert_ok!(call_execute_multi_signer(origin_ps_acc()));
+        assert_ok!(call_execute_multi_signer(root_ps_acc()));
+
+        assert_eq!(Mvm::storage_deposit(&origin_ps_acc()), 0);
+        assert!(Mvm::storage_deposit(&root_ps_acc()) > 0);
+        assert_eq!(
+            reserved(&root_ps_acc()),
+            Mvm::storage_deposit(&root_ps_acc())
+        );
+    });
+}
+
+#[test]
+/// approver withdraws its approval with the deposit
+fn cancel_script_approval() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(call_execute_multi_signer(origin_ps_acc()));
+        assert_noop!(
+            Mvm::cancel_script_approval(Origin::signed(root_ps_acc()), script_hash()),
+            Error::<Test>::ScriptApprovalNotFound
+        );
+
+        assert_ok!(Mvm::cancel_script_approval(
+            Origin::signed(origin_ps_acc()),
+            script_hash()
+        ));
+        assert_eq!(ScriptApprovals::<Test>::get(script_hash()), None);
+        assert_eq!(reserved(&origin_ps_acc()), 0);
+    });
+}
+
+#[test]
+/// expired approvals are dropped at the expiry block with their deposits released
+fn script_approval_expired() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(call_execute_multi_signer(origin_ps_acc()));
+        let pending = ScriptApprovals::<Test>::get(script_hash()).unwrap();
+        assert_eq!(
+            ScriptApprovalExpiries::<Test>::get(pending.expires_at),
+            vec![script_hash()]
+        );
+
+        roll_block_to(pending.expires_at - 1);
+        assert_eq!(reserved(&origin_ps_acc()), ScriptApprovalDeposit::get());
+
+        roll_block_to(pending.expires_at);
+        assert_eq!(ScriptApprovals::<Test>::get(script_hash()), None);
+        assert_eq!(
+            ScriptApprovalExpiries::<Test>::get(pending.expires_at),
+            vec![]
+        );
+        assert_eq!(reserved(&origin_ps_acc()), 0);
+        assert_eq!(
+            last_event(),
+            MoveEvent::ScriptApprovalsExpired(script_hash()).into()
+        );
+
+        // root approves anew and waits for the others:
+        assert_ok!(call_execute_multi_signer(root_ps_acc()));
+        let pending = ScriptApprovals::<Test>::get(script_hash()).unwrap();
+        assert_eq!(
+            pending.approvals,
+            vec![(root_ps_acc(), ScriptApprovalDeposit::get())]
+        );
+        assert_noop!(
+            Mvm::cancel_script_approval(Origin::signed(origin_ps_acc()), script_hash()),
+            Error::<Test>::ScriptApprovalNotFound
+        );
+    });
+}
+
+#[test]
+/// approvals cancelled and approved again aren't dropped by the stale expiry
+fn script_approval_expiry_after_reapproval() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(call_execute_multi_signer(origin_ps_acc()));
+        let first = ScriptApprovals::<Test>::get(script_hash()).unwrap();
+        assert_ok!(Mvm::cancel_script_approval(
+            Origin::signed(origin_ps_acc()),
+            script_hash()
+        ));
+
+        roll_next_block();
+        assert_ok!(call_execute_multi_signer(origin_ps_acc()));
+        let second = ScriptApprovals::<Test>::get(script_hash()).unwrap();
+        assert!(second.expires_at > first.expires_at);
+
+        roll_block_to(first.expires_at);
+        assert_eq!(ScriptApprovals::<Test>::get(script_hash()), Some(second));
+        assert_eq!(reserved(&origin_ps_acc()), ScriptApprovalDeposit::get());
+    });
+}
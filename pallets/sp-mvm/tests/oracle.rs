@@ -1,5 +1,9 @@
 use serde::Deserialize;
-use frame_support::assert_ok;
+use frame_support::{assert_ok, assert_err_ignore_postinfo};
+use move_vm::data::Oracle;
+use sp_core::sr25519::Public;
+use sp_mvm::Error;
+use sp_mvm::oracle::StorageOracle;
 use move_core_types::identifier::Identifier;
 use move_core_types::language_storage::StructTag;
 use move_core_types::account_address::AccountAddress;
@@ -11,12 +15,34 @@ use common::addr::*;
 use common::utils::*;
 
 const GAS_LIMIT: u64 = 1_000_000;
+/// Ticker requested by `Coins::get_price<Coins::BTC, PONT::T>`
+const BTC_PONT: &str = "BTC_PONT";
 
 #[derive(Deserialize, Debug, PartialEq)]
 struct StoreU128 {
     pub val: u128,
 }
 
+fn feeder(n: u8) -> Public {
+    Public([n; 32])
+}
+
+fn add_feeder(feeder: Public) {
+    assert_ok!(Mvm::add_oracle_feeder(Origin::root(), feeder));
+}
+
+fn feed_price(feeder: Public, price: u128) {
+    assert_ok!(Mvm::feed_price(
+        Origin::signed(feeder),
+        BTC_PONT.as_bytes().to_vec(),
+        price
+    ));
+}
+
+fn get_price() -> Option<u128> {
+    StorageOracle::<Test>::default().get_price(BTC_PONT)
+}
+
 fn check_storage_u128(address: AccountAddress, expected: u128) {
     let expected = StoreU128 { val: expected };
     let tag = StructTag {
@@ -33,6 +59,10 @@ fn oracle_get_price() {
     new_test_ext().execute_with(|| {
         let account = origin_ps_acc();
 
+        roll_next_block();
+        add_feeder(feeder(1));
+        feed_price(feeder(1), 42);
+
         // publish entire std lib:
         publish_std();
 
@@ -45,6 +75,77 @@ fn oracle_get_price() {
         assert_ok!(result);
 
         // check storage:
-        check_storage_u128(to_move_addr(account), 1);
+        check_storage_u128(to_move_addr(account), 42);
+    });
+}
+
+#[test]
+fn oracle_median_price() {
+    new_test_ext().execute_with(|| {
+        roll_next_block();
+        assert_eq!(get_price(), None);
+
+        for (n, price) in [(1, 10), (2, 30), (3, 20)].iter() {
+            add_feeder(feeder(*n));
+            feed_price(feeder(*n), *price);
+        }
+        assert_eq!(get_price(), Some(20));
+
+        add_feeder(feeder(4));
+        feed_price(feeder(4), 40);
+        assert_eq!(get_price(), Some(25));
+    });
+}
+
+#[test]
+fn oracle_stale_price() {
+    new_test_ext().execute_with(|| {
+        roll_next_block();
+        add_feeder(feeder(1));
+        add_feeder(feeder(2));
+        feed_price(feeder(1), 10);
+
+        roll_block_to(Sys::block_number() + 5);
+        feed_price(feeder(2), 20);
+        assert_eq!(get_price(), Some(15));
+
+        // price of the first feeder is outdated:
+        roll_block_to(Sys::block_number() + 6);
+        assert_eq!(get_price(), Some(20));
+
+        // all prices are outdated:
+        roll_block_to(Sys::block_number() + 5);
+        assert_eq!(get_price(), None);
+    });
+}
+
+#[test]
+fn oracle_feeders() {
+    new_test_ext().execute_with(|| {
+        roll_next_block();
+
+        // not a feeder:
+        assert_err_ignore_postinfo!(
+            Mvm::feed_price(Origin::signed(feeder(1)), BTC_PONT.as_bytes().to_vec(), 1),
+            Error::<Test>::OracleFeederNotFound
+        );
+
+        add_feeder(feeder(1));
+        assert_err_ignore_postinfo!(
+            Mvm::add_oracle_feeder(Origin::root(), feeder(1)),
+            Error::<Test>::OracleFeederAlreadyExists
+        );
+        assert!(Mvm::add_oracle_feeder(Origin::signed(feeder(1)), feeder(2)).is_err());
+
+        feed_price(feeder(1), 10);
+        assert_eq!(get_price(), Some(10));
+
+        // prices are removed with the feeder:
+        assert_ok!(Mvm::remove_oracle_feeder(Origin::root(), feeder(1)));
+        assert_eq!(get_price(), None);
+        assert_err_ignore_postinfo!(
+            Mvm::remove_oracle_feeder(Origin::root(), feeder(1)),
+            Error::<Test>::OracleFeederNotFound
+        );
     });
 }
@@ -0,0 +1,78 @@
+use frame_support::{assert_noop, assert_ok};
+use move_core_types::language_storage::CORE_CODE_ADDRESS;
+use sp_runtime::DispatchError;
+
+mod common;
+use common::assets::*;
+use common::mock::*;
+use common::addr::*;
+use common::utils;
+
+const GAS_LIMIT: u64 = 1_000_000;
+
+#[test]
+/// std is published by `StdPublishOrigin` only
+fn publish_std_bad_origin() {
+    new_test_ext().execute_with(|| {
+        let modules = StdMod::all()
+            .into_iter()
+            .map(|m| m.bc().to_vec())
+            .collect::<Vec<_>>();
+        assert_noop!(
+            Mvm::publish_std(Origin::signed(origin_ps_acc()), modules.clone(), GAS_LIMIT),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(Mvm::publish_std(Origin::root(), modules, GAS_LIMIT));
+    });
+}
+
+#[test]
+/// package of `StdPublishOrigin` is published under `0x1`
+fn publish_package_as_std_origin() {
+    new_test_ext().execute_with(|| {
+        utils::publish_std();
+
+        let package = RootPackages::Assets;
+        assert_ok!(Mvm::publish_package(
+            Origin::root(),
+            package.bc().to_vec(),
+            GAS_LIMIT
+        ));
+
+        let published = Mvm::list_modules(CORE_CODE_ADDRESS.as_ref()).unwrap();
+        for name in package.modules() {
+            assert!(published.contains(&name.as_bytes().to_vec()));
+        }
+    });
+}
+
+#[test]
+/// `ModulePublishOrigin` restricts publishing to the allowlist
+fn publish_module_allowlist() {
+    new_test_ext().execute_with(|| {
+        utils::publish_std();
+        PublishAllowlist::set(Some(vec![root_ps_acc()]));
+
+        let module = UserMod::Store.bc().to_vec();
+        assert_noop!(
+            Mvm::publish_module(Origin::signed(origin_ps_acc()), module.clone(), GAS_LIMIT),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Mvm::publish_package(
+                Origin::signed(origin_ps_acc()),
+                UsrPackages::Assets.bc().to_vec(),
+                GAS_LIMIT
+            ),
+            DispatchError::BadOrigin
+        );
+
+        PublishAllowlist::set(Some(vec![origin_ps_acc()]));
+        assert_ok!(Mvm::publish_module(
+            Origin::signed(origin_ps_acc()),
+            module.clone(),
+            GAS_LIMIT
+        ));
+        utils::check_storage_mod_raw(origin_ps_acc(), module, UserMod::Store.name());
+    });
+}
@@ -0,0 +1,50 @@
+use frame_support::assert_ok;
+use sp_mvm::types::MoveValue;
+
+mod common;
+use common::assets::*;
+use common::mock::*;
+use common::addr::*;
+use common::utils;
+
+fn store_u64_tag() -> String {
+    format!("0x{}::{}::U64", origin_move_addr(), UserMod::Store.name())
+}
+
+#[test]
+fn get_and_decode_resource() {
+    new_test_ext().execute_with(|| {
+        utils::publish_module(origin_ps_acc(), UserMod::Store);
+        assert_ok!(utils::execute_tx_unchecked(
+            Origin::signed(origin_ps_acc()),
+            UserTx::StoreU64,
+            1_000_000
+        ));
+
+        let address = origin_move_addr();
+        let tag = store_u64_tag();
+        let bcs = Mvm::get_resource(address.as_ref(), tag.as_bytes())
+            .unwrap()
+            .unwrap();
+        assert_eq!(bcs, 42u64.to_le_bytes().to_vec());
+
+        let value = Mvm::decode_resource(tag.as_bytes(), &bcs).unwrap();
+        assert_eq!(
+            value,
+            MoveValue::Struct(vec![(b"val".to_vec(), MoveValue::U64(42))])
+        );
+    });
+}
+
+#[test]
+fn get_missing_resource() {
+    new_test_ext().execute_with(|| {
+        let address = origin_move_addr();
+        let tag = store_u64_tag();
+        assert_eq!(
+            Mvm::get_resource(address.as_ref(), tag.as_bytes()),
+            Ok(None)
+        );
+        assert!(Mvm::get_resource(address.as_ref(), b"0x1::Store").is_err());
+    });
+}
@@ -0,0 +1,118 @@
+use sp_mvm::Simulation;
+use sp_mvm::types::NativeBalanceChange;
+
+mod common;
+use common::assets::*;
+use common::mock::*;
+use common::addr::*;
+use common::utils;
+
+const GAS_LIMIT: u64 = 1_000_000;
+
+fn store_u64_tag() -> String {
+    format!("0x{}::{}::U64", origin_move_addr(), UserMod::Store.name())
+}
+
+#[test]
+/// script writes are returned but not applied
+fn simulate_execute() {
+    new_test_ext().execute_with(|| {
+        utils::publish_module(origin_ps_acc(), UserMod::Store);
+
+        let (res, record) =
+            Mvm::simulate_execute(&origin_ps_acc(), UserTx::StoreU64.bc().to_vec(), GAS_LIMIT)
+                .unwrap();
+        assert!(sp_mvm::result::is_ok(&res));
+        assert!(res.gas_used > 0);
+
+        let stored = 42u64.to_le_bytes().to_vec();
+        assert_eq!(record.write_set.len(), 1);
+        assert_eq!(record.write_set[0].1, Some(stored));
+        assert!(record.events.is_empty());
+        assert!(record.balance_changes.is_empty());
+
+        let tag = store_u64_tag();
+        assert_eq!(
+            Mvm::get_resource(origin_move_addr().as_ref(), tag.as_bytes()),
+            Ok(None)
+        );
+        assert!(!Simulation::<Test>::exists());
+    });
+}
+
+#[test]
+/// emitted events are returned but not deposited
+fn simulate_execute_events() {
+    new_test_ext().execute_with(|| {
+        utils::publish_module(root_ps_acc(), StdMod::Event);
+        utils::publish_module(origin_ps_acc(), UserMod::EventProxy);
+        roll_next_block();
+
+        let (_, record) =
+            Mvm::simulate_execute(&origin_ps_acc(), UserTx::EmitEvent.bc().to_vec(), GAS_LIMIT)
+                .unwrap();
+
+        assert_eq!(record.events.len(), 2);
+        for event in record.events.iter() {
+            assert_eq!(event.sender, origin_move_addr().as_ref().to_vec());
+            assert_eq!(event.message, 42u64.to_le_bytes().to_vec());
+        }
+        assert!(Sys::events().is_empty());
+    });
+}
+
+#[test]
+/// native balance changes are returned but not applied
+fn simulate_execute_balance_changes() {
+    new_test_ext().execute_with(|| {
+        let account = origin_ps_acc();
+        utils::publish_std();
+        utils::publish_module(account, UserMod::Store);
+
+        let (res, record) = Mvm::simulate_execute(
+            &account,
+            UserTx::StoreNativeDepositReg.bc().to_vec(),
+            GAS_LIMIT,
+        )
+        .unwrap();
+        assert!(sp_mvm::result::is_ok(&res));
+
+        assert_eq!(
+            record.balance_changes,
+            vec![NativeBalanceChange::Withdraw {
+                address: origin_move_addr().as_ref().to_vec(),
+                ticker: b"PONT".to_vec(),
+                amount: INITIAL_BALANCE / 2,
+            }]
+        );
+        assert_eq!(
+            balances::Pallet::<Test>::free_balance(&account),
+            INITIAL_BALANCE
+        );
+    });
+}
+
+#[test]
+/// module isn't published by the simulation
+fn simulate_publish() {
+    new_test_ext().execute_with(|| {
+        let address = origin_move_addr();
+        let (res, record) =
+            Mvm::simulate_publish(&origin_ps_acc(), UserMod::Store.bc().to_vec(), GAS_LIMIT)
+                .unwrap();
+        assert!(sp_mvm::result::is_ok(&res));
+        assert_eq!(
+            record.write_set,
+            vec![(
+                record.write_set[0].0.clone(),
+                Some(UserMod::Store.bc().to_vec())
+            )]
+        );
+
+        assert_eq!(
+            Mvm::get_module(address.as_ref(), UserMod::Store.name().as_bytes()),
+            Ok(None)
+        );
+        assert_eq!(Mvm::list_modules(address.as_ref()), Ok(vec![]));
+    });
+}
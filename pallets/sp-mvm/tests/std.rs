@@ -1,11 +1,15 @@
 use std::convert::TryInto;
+use codec::{Decode, Encode};
 use frame_support::assert_ok;
 use move_core_types::identifier::Identifier;
 use move_core_types::language_storage::ModuleId;
+use move_core_types::language_storage::CORE_CODE_ADDRESS;
 
 use move_core_types::language_storage::StructTag;
 use move_core_types::language_storage::TypeTag;
 use sp_mvm::Event;
+use sp_mvm::types::{MoveModuleId, MoveStructTag, MoveTypeTag};
+use sp_runtime::traits::Hash;
 
 mod common;
 use common::assets::*;
@@ -54,6 +58,45 @@ fn publish_batch_std_as_root() {
     });
 }
 
+#[test]
+/// type parameters of the event type are kept with the nested ones
+fn event_type_tag_params() {
+    let struct_tag = |name: &str, type_params| StructTag {
+        address: to_move_addr(origin_ps_acc()),
+        module: Identifier::new(UserMod::EventProxy.name()).unwrap(),
+        name: Identifier::new(name).unwrap(),
+        type_params,
+    };
+    let tt = TypeTag::Struct(struct_tag(
+        "Pair",
+        vec![
+            TypeTag::Vector(Box::new(TypeTag::U8)),
+            TypeTag::Struct(struct_tag("U64", vec![TypeTag::Address])),
+        ],
+    ));
+
+    let move_tt: MoveTypeTag<utils::AccountId> = tt.try_into().unwrap();
+    let move_struct_tag = |name: &str, ty_params| {
+        MoveStructTag::new(
+            origin_ps_acc(),
+            Identifier::new(UserMod::EventProxy.name()).unwrap(),
+            Identifier::new(name).unwrap(),
+            ty_params,
+        )
+    };
+    let expected = MoveTypeTag::Struct(move_struct_tag(
+        "Pair",
+        vec![
+            MoveTypeTag::Vector(Box::new(MoveTypeTag::U8)),
+            MoveTypeTag::Struct(move_struct_tag("U64", vec![MoveTypeTag::Address])),
+        ],
+    ));
+    assert_eq!(move_tt, expected);
+
+    let encoded = move_tt.encode();
+    assert_eq!(MoveTypeTag::decode(&mut &encoded[..]).unwrap(), expected);
+}
+
 #[test]
 fn execute_script() {
     new_test_ext().execute_with(|| {
@@ -73,22 +116,22 @@ fn execute_script() {
         call_execute_script(Origin::signed(origin));
 
         // construct event: that should be emitted in the method call directly above
-        let tt = TypeTag::Struct(StructTag {
+        let tt: MoveTypeTag<_> = TypeTag::Struct(StructTag {
             address: to_move_addr(origin),
             module: Identifier::new(proxy.name()).unwrap(),
             name: Identifier::new("U64").unwrap(),
             type_params: Vec::with_capacity(0),
         })
-        .to_string();
-        let tt = tt.as_bytes();
+        .try_into()
+        .unwrap();
 
         let expected = vec![
             // one for user::Proxy -> std::Event (`Event::emit`)
-            Event::Event(origin, tt.to_vec(), 42u64.to_le_bytes().to_vec(), None).into(),
+            Event::Event(origin, tt.clone(), 42u64.to_le_bytes().to_vec(), None).into(),
             // and one for user::Proxy -> std::Event (`EventProxy::emit_event`)
             Event::Event(
                 origin,
-                tt.to_vec(),
+                tt.clone(),
                 42u64.to_le_bytes().to_vec(),
                 Some(
                     ModuleId::new(to_move_addr(origin), Identifier::new(proxy.name()).unwrap())
@@ -137,6 +180,79 @@ fn publish_package_as_origin() {
     });
 }
 
+#[test]
+/// package event lists all its modules
+fn publish_package_event() {
+    new_test_ext().execute_with(|| {
+        utils::publish_std();
+        roll_next_block();
+
+        let package = UsrPackages::Assets;
+        utils::publish_package(origin_ps_acc(), package, 1_000_000);
+
+        let modules = package
+            .modules()
+            .iter()
+            .map(|name| MoveModuleId {
+                owner: origin_ps_acc(),
+                module: name.as_bytes().to_vec(),
+            })
+            .collect();
+        assert_eq!(
+            last_event(),
+            MoveEvent::PackagePublished(
+                <Test as frame_system::Config>::Hashing::hash(package.bc()),
+                modules
+            )
+            .into()
+        );
+    });
+}
+
+#[test]
+/// failed std module rolls back the whole batch with its events
+fn publish_std_rolled_back() {
+    new_test_ext().execute_with(|| {
+        const GAS_LIMIT: u64 = 1_000_000;
+        roll_next_block();
+
+        let modules = vec![StdMod::all()[0].bc().to_vec(), vec![0xCA, 0xFE]];
+        let err = Mvm::publish_std(Origin::root(), modules, GAS_LIMIT).unwrap_err();
+
+        assert_eq!(Mvm::list_modules(CORE_CODE_ADDRESS.as_ref()), Ok(vec![]));
+        assert_eq!(
+            last_event(),
+            MoveEvent::BatchPublishFailed(1, err.error).into()
+        );
+        // events of the published module are rolled back too
+        assert_eq!(Sys::events().len(), 1);
+    });
+}
+
+#[test]
+/// package module out of gas rolls back the whole package
+fn publish_package_rolled_back() {
+    new_test_ext().execute_with(|| {
+        utils::publish_std();
+        roll_next_block();
+
+        let package = UsrPackages::Assets;
+        let origin = origin_ps_acc();
+        let results = Mvm::estimate_publish_package(&origin, package.bc(), 1_000_000).unwrap();
+        // enough for the first module only:
+        let gas_limit = results[0].1.gas_used + 1;
+
+        let err = Mvm::publish_package(Origin::signed(origin), package.bc().to_vec(), gas_limit)
+            .unwrap_err();
+
+        assert_eq!(Mvm::list_modules(origin_move_addr().as_ref()), Ok(vec![]));
+        assert_eq!(
+            last_event(),
+            MoveEvent::BatchPublishFailed(1, err.error).into()
+        );
+    });
+}
+
 // TODO: publish std modules as root
 // call `utils::publish_module_raw_with_origin_unchecked`
 // with `Origin::root()`
@@ -62,3 +62,23 @@ fn execute_script() {
         call_execute_script(signer);
     });
 }
+
+#[test]
+/// every entry owned by the address is listed with its VM key
+fn account_storage() {
+    new_test_ext().execute_with(|| {
+        let address = origin_move_addr();
+        assert_eq!(Mvm::account_storage(address.as_ref()), Ok(vec![]));
+
+        utils::publish_module(origin_ps_acc(), UserMod::Store);
+        call_execute_script(Origin::signed(origin_ps_acc()));
+        let entries = Mvm::account_storage(address.as_ref()).unwrap();
+        assert_eq!(entries.len(), 2);
+
+        let store = Mvm::move_vm_storage();
+        for (key, value) in entries {
+            assert_eq!(store.get(&key), Some(value));
+        }
+        assert!(Mvm::account_storage(&[1, 2, 3]).is_err());
+    });
+}
@@ -0,0 +1,296 @@
+use frame_support::{assert_noop, assert_ok};
+use frame_support::dispatch::DispatchResultWithPostInfo;
+use move_core_types::identifier::Identifier;
+use move_core_types::language_storage::CORE_CODE_ADDRESS;
+use move_vm::data::Storage;
+use sp_mvm::{AccountUpgradePolicy, Error, ModuleUpgradePolicy};
+use sp_mvm::types::{MoveModuleId, UpgradePolicy};
+use sp_runtime::traits::Hash;
+use vm::access::ModuleAccess;
+use vm::file_format::{CompiledModule, IdentifierIndex, StructFieldInformation};
+
+mod common;
+use common::assets::*;
+use common::mock::*;
+use common::addr::*;
+use common::utils;
+
+const GAS_LIMIT: u64 = 1_000_000;
+
+/// Module with the extra `value` identifier, and the first field of the `rename` struct
+/// renamed to it if passed.
+fn patch_module(bytecode: &[u8], rename: Option<&str>) -> Vec<u8> {
+    let mut module = CompiledModule::deserialize(bytecode).unwrap().into_inner();
+    let value = IdentifierIndex(module.identifiers.len() as u16);
+    module.identifiers.push(Identifier::new("value").unwrap());
+
+    if let Some(rename) = rename {
+        let def = module
+            .struct_defs
+            .iter()
+            .position(|def| {
+                let handle = &module.struct_handles[def.struct_handle.0 as usize];
+                module.identifiers[handle.name.0 as usize].as_str() == rename
+            })
+            .unwrap();
+        match &mut module.struct_defs[def].field_information {
+            StructFieldInformation::Declared(fields) => fields[0].name = value,
+            StructFieldInformation::Native => unreachable!(),
+        }
+    }
+
+    let module = module.freeze().unwrap();
+    let mut patched = Vec::new();
+    module.serialize(&mut patched).unwrap();
+    assert_ne!(bytecode, &patched[..]);
+    patched
+}
+
+/// `Store` module with `U64.val` field renamed to `U64.value`.
+fn incompatible_store() -> Vec<u8> {
+    patch_module(UserMod::Store.bc(), Some("U64"))
+}
+
+/// `Store` module with the same structs and functions.
+fn compatible_store() -> Vec<u8> {
+    patch_module(UserMod::Store.bc(), None)
+}
+
+/// Name of the first struct of the std module with declared fields.
+fn first_struct(bytecode: &[u8]) -> String {
+    let module = CompiledModule::deserialize(bytecode).unwrap();
+    module
+        .struct_defs()
+        .iter()
+        .find(|def| match &def.field_information {
+            StructFieldInformation::Declared(fields) => !fields.is_empty(),
+            StructFieldInformation::Native => false,
+        })
+        .map(|def| {
+            let handle = module.struct_handle_at(def.struct_handle);
+            module.identifier_at(handle.name).to_string()
+        })
+        .unwrap()
+}
+
+fn set_policy(module: Option<UserMod>, policy: UpgradePolicy) -> DispatchResultWithPostInfo {
+    Mvm::set_upgrade_policy(
+        Origin::signed(origin_ps_acc()),
+        module.map(|module| module.name().as_bytes().to_vec()),
+        policy,
+    )
+}
+
+#[test]
+/// module policy overrides account policy
+fn set_upgrade_policy() {
+    new_test_ext().execute_with(|| {
+        roll_next_block();
+        let address = origin_move_addr().as_ref().to_vec();
+        let store = UserMod::Store.name().as_bytes();
+        assert_eq!(
+            Mvm::upgrade_policy(&address, store),
+            UpgradePolicy::Compatible
+        );
+
+        assert_ok!(set_policy(None, UpgradePolicy::Arbitrary));
+        assert_eq!(
+            AccountUpgradePolicy::<Test>::get(&address),
+            UpgradePolicy::Arbitrary
+        );
+        assert_eq!(
+            Mvm::upgrade_policy(&address, store),
+            UpgradePolicy::Arbitrary
+        );
+
+        assert_ok!(set_policy(Some(UserMod::Store), UpgradePolicy::Immutable));
+        assert_eq!(
+            ModuleUpgradePolicy::<Test>::get(&address, store.to_vec()),
+            Some(UpgradePolicy::Immutable)
+        );
+        assert_eq!(
+            Mvm::upgrade_policy(&address, store),
+            UpgradePolicy::Immutable
+        );
+        assert_eq!(
+            last_event(),
+            MoveEvent::UpgradePolicySet(
+                origin_ps_acc(),
+                Some(store.to_vec()),
+                UpgradePolicy::Immutable
+            )
+            .into()
+        );
+    });
+}
+
+#[test]
+/// immutable policy can't be changed
+fn immutable_policy() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(set_policy(Some(UserMod::Store), UpgradePolicy::Immutable));
+        assert_noop!(
+            set_policy(Some(UserMod::Store), UpgradePolicy::Arbitrary),
+            Error::<Test>::ModuleUpgradeForbidden
+        );
+
+        assert_ok!(set_policy(None, UpgradePolicy::Immutable));
+        assert_noop!(
+            set_policy(None, UpgradePolicy::Compatible),
+            Error::<Test>::ModuleUpgradeForbidden
+        );
+        assert_noop!(
+            set_policy(Some(UserMod::EventProxy), UpgradePolicy::Arbitrary),
+            Error::<Test>::ModuleUpgradeForbidden
+        );
+    });
+}
+
+#[test]
+/// immutable module can't be replaced
+fn immutable_module_upgrade() {
+    new_test_ext().execute_with(|| {
+        utils::publish_module(origin_ps_acc(), UserMod::Store);
+        assert_ok!(set_policy(Some(UserMod::Store), UpgradePolicy::Immutable));
+
+        assert_noop!(
+            Mvm::publish_module(
+                Origin::signed(origin_ps_acc()),
+                incompatible_store(),
+                GAS_LIMIT
+            ),
+            Error::<Test>::ModuleUpgradeForbidden
+        );
+        utils::check_storage_mod_raw(origin_ps_acc(), UserMod::Store.bc(), UserMod::Store.name());
+    });
+}
+
+#[test]
+/// module replacing struct layout is rejected by default
+fn incompatible_module_upgrade() {
+    new_test_ext().execute_with(|| {
+        utils::publish_module(origin_ps_acc(), UserMod::Store);
+
+        assert_noop!(
+            Mvm::publish_module(
+                Origin::signed(origin_ps_acc()),
+                incompatible_store(),
+                GAS_LIMIT
+            ),
+            Error::<Test>::IncompatibleModuleUpgrade
+        );
+        utils::check_storage_mod_raw(origin_ps_acc(), UserMod::Store.bc(), UserMod::Store.name());
+    });
+}
+
+#[test]
+/// published module event tells the new module from the upgrade
+fn module_published_event() {
+    new_test_ext().execute_with(|| {
+        roll_next_block();
+        let id = MoveModuleId {
+            owner: origin_ps_acc(),
+            module: UserMod::Store.name().as_bytes().to_vec(),
+        };
+        let hash = |bc: &[u8]| <Test as frame_system::Config>::Hashing::hash(bc);
+
+        utils::publish_module(origin_ps_acc(), UserMod::Store);
+        assert_eq!(
+            last_event(),
+            MoveEvent::ModulePublished(id.clone(), hash(UserMod::Store.bc()), false).into()
+        );
+
+        assert_ok!(set_policy(None, UpgradePolicy::Arbitrary));
+        let upgrade = incompatible_store();
+        assert_ok!(Mvm::publish_module(
+            Origin::signed(origin_ps_acc()),
+            upgrade.clone(),
+            GAS_LIMIT
+        ));
+        let published: Event = MoveEvent::ModulePublished(id, hash(&upgrade), true).into();
+        assert!(Sys::events().iter().any(|record| record.event == published));
+    });
+}
+
+#[test]
+/// module keeping structs and public functions replaces the published one by default
+fn compatible_module_upgrade() {
+    new_test_ext().execute_with(|| {
+        roll_next_block();
+        utils::publish_module(origin_ps_acc(), UserMod::Store);
+
+        let upgrade = compatible_store();
+        assert_ok!(Mvm::publish_module(
+            Origin::signed(origin_ps_acc()),
+            upgrade.clone(),
+            GAS_LIMIT
+        ));
+        utils::check_storage_mod_raw(origin_ps_acc(), &upgrade, UserMod::Store.name());
+
+        let id = MoveModuleId {
+            owner: origin_ps_acc(),
+            module: UserMod::Store.name().as_bytes().to_vec(),
+        };
+        let hash = |bc: &[u8]| <Test as frame_system::Config>::Hashing::hash(bc);
+        let upgraded: Event =
+            MoveEvent::ModuleUpgraded(id, hash(UserMod::Store.bc()), hash(&upgrade)).into();
+        assert!(Sys::events().iter().any(|record| record.event == upgraded));
+    });
+}
+
+#[test]
+/// published module which can't be deserialized fails the compatibility check
+fn invalid_published_module_upgrade() {
+    new_test_ext().execute_with(|| {
+        utils::publish_module(origin_ps_acc(), UserMod::Store);
+        let entries = Mvm::account_storage(origin_move_addr().as_ref()).unwrap();
+        assert_eq!(entries.len(), 1);
+        Mvm::move_vm_storage().insert(&entries[0].0, &[0xde, 0xad]);
+
+        assert_noop!(
+            Mvm::publish_module(
+                Origin::signed(origin_ps_acc()),
+                compatible_store(),
+                GAS_LIMIT
+            ),
+            Error::<Test>::InvalidPublishedModule
+        );
+    });
+}
+
+#[test]
+/// std is upgraded by `StdPublishOrigin` with any bytecode unless its policy is set
+fn std_module_upgrade() {
+    new_test_ext().execute_with(|| {
+        utils::publish_std();
+        let address = CORE_CODE_ADDRESS.as_ref().to_vec();
+        let name = StdMod::Block.name().as_bytes();
+        assert_eq!(
+            Mvm::upgrade_policy(&address, name),
+            UpgradePolicy::Arbitrary
+        );
+
+        let block = StdMod::Block.bc();
+        let upgrade = patch_module(block, Some(&first_struct(block)));
+        assert_ok!(Mvm::publish_std(
+            Origin::root(),
+            vec![upgrade.clone()],
+            GAS_LIMIT
+        ));
+        utils::check_storage_mod_raw_with_addr(CORE_CODE_ADDRESS, &upgrade, StdMod::Block.name());
+
+        assert_ok!(Mvm::set_upgrade_policy(
+            Origin::root(),
+            None,
+            UpgradePolicy::Compatible
+        ));
+        assert_eq!(
+            Mvm::upgrade_policy(&address, name),
+            UpgradePolicy::Compatible
+        );
+        // the original layout is incompatible with the upgraded one:
+        let err = Mvm::publish_std(Origin::root(), vec![block.to_vec()], GAS_LIMIT).unwrap_err();
+        assert_eq!(err.error, Error::<Test>::IncompatibleModuleUpgrade.into());
+        utils::check_storage_mod_raw_with_addr(CORE_CODE_ADDRESS, &upgrade, StdMod::Block.name());
+    });
+}
@@ -0,0 +1,110 @@
+use frame_support::assert_err_ignore_postinfo;
+use frame_support::assert_ok;
+use move_core_types::language_storage::CORE_CODE_ADDRESS;
+use move_vm::data::Storage;
+use sp_mvm::{Error, StorageDepositor, StorageWriteFailure};
+use sp_mvm::storage::{join_key, MoveVmStorage, ObservedStorage, StorageWriteError};
+
+mod common;
+use common::assets::*;
+use common::mock::*;
+use common::addr::*;
+use common::utils;
+
+const GAS_LIMIT: u64 = 1_000_000;
+
+#[test]
+/// every written key is accounted to its owner
+fn storage_usage() {
+    new_test_ext().execute_with(|| {
+        let address = origin_move_addr();
+        assert_eq!(Mvm::storage_usage(address.as_ref()), Ok(Default::default()));
+
+        utils::publish_module(origin_ps_acc(), UserMod::Store);
+        let usage = Mvm::storage_usage(address.as_ref()).unwrap();
+        assert_eq!(usage.keys, 1);
+        assert!(usage.bytes > UserMod::Store.bc().len() as u64);
+
+        assert_ok!(utils::execute_tx_unchecked(
+            Origin::signed(origin_ps_acc()),
+            UserTx::StoreU64,
+            GAS_LIMIT
+        ));
+        let new_usage = Mvm::storage_usage(address.as_ref()).unwrap();
+        assert_eq!(new_usage.keys, 2);
+        assert!(new_usage.bytes > usage.bytes);
+    });
+}
+
+#[test]
+/// std modules are accounted to `0x1`
+fn std_storage_usage() {
+    new_test_ext().execute_with(|| {
+        utils::publish_std();
+
+        let usage = Mvm::storage_usage(CORE_CODE_ADDRESS.as_ref()).unwrap();
+        assert_eq!(usage.keys as usize, StdMod::all().len());
+    });
+}
+
+#[test]
+/// transaction exceeding the quota is aborted
+fn storage_quota_exceeded() {
+    new_test_ext().execute_with(|| {
+        StorageQuota::set(1, u64::MAX);
+        let address = origin_move_addr();
+        utils::publish_module(origin_ps_acc(), UserMod::Store);
+        let usage = Mvm::storage_usage(address.as_ref()).unwrap();
+
+        assert_err_ignore_postinfo!(
+            utils::execute_tx_unchecked(
+                Origin::signed(origin_ps_acc()),
+                UserTx::StoreU64,
+                GAS_LIMIT
+            ),
+            Error::<Test>::StorageQuotaExceeded
+        );
+        assert_eq!(Mvm::storage_usage(address.as_ref()), Ok(usage));
+    });
+}
+
+#[test]
+/// over-quota address rejects the writes of every depositor, writes without depositor pass
+fn storage_quota_applies_to_all_writers() {
+    new_test_ext().execute_with(|| {
+        StorageQuota::set(1, u64::MAX);
+        let address = origin_move_addr();
+        utils::publish_module(origin_ps_acc(), UserMod::Store);
+        let storage = ObservedStorage::<_, Mvm>::new(Mvm::move_vm_storage());
+        let keys = Mvm::storage_usage(address.as_ref()).unwrap().keys;
+
+        // without depositor:
+        storage.insert(&join_key(address.as_ref(), b"free"), &[1]);
+        assert_eq!(StorageWriteFailure::<Test>::get(), None);
+        assert_eq!(Mvm::storage_usage(address.as_ref()).unwrap().keys, keys + 1);
+
+        // by another account:
+        StorageDepositor::<Test>::put(root_ps_acc());
+        storage.insert(&join_key(address.as_ref(), b"incoming"), &[1]);
+        assert_eq!(
+            StorageWriteFailure::<Test>::take(),
+            Some(StorageWriteError::QuotaExceeded)
+        );
+
+        // by the owner itself:
+        StorageDepositor::<Test>::put(origin_ps_acc());
+        storage.insert(&join_key(address.as_ref(), b"own"), &[1]);
+        assert_eq!(
+            StorageWriteFailure::<Test>::take(),
+            Some(StorageWriteError::QuotaExceeded)
+        );
+        StorageDepositor::<Test>::kill();
+    });
+}
+
+#[test]
+fn invalid_address() {
+    new_test_ext().execute_with(|| {
+        assert!(Mvm::storage_usage(&[1, 2, 3]).is_err());
+    });
+}
@@ -10,6 +10,7 @@ use sp_std::prelude::*;
 use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 use sp_runtime::{
     ApplyExtrinsicResult, generic, create_runtime_str, impl_opaque_keys, MultiSignature,
+    ModuleId,
     transaction_validity::{TransactionValidity, TransactionSource},
 };
 use sp_runtime::traits::{
@@ -42,11 +43,20 @@ pub use frame_support::{
     },
 };
 use pallet_transaction_payment::CurrencyAdapter;
+use codec::{Encode, Decode};
+use sp_runtime::RuntimeDebug;
+#[cfg(feature = "std")]
+use serde::{Serialize, Deserialize};
+use orml_currencies::BasicCurrencyAdapter;
+use orml_traits::GetByKey;
 
 /// Import the Move-pallet.
 pub use sp_mvm;
 pub use sp_mvm::gas::{GasWeightMapping};
-pub use sp_mvm_rpc_runtime::types::MVMApiEstimation;
+pub use sp_mvm_rpc_runtime::types::{
+    MVMApiEstimation, MVMApiModuleEstimation, MVMApiResource, MVMApiSimulation, ModuleAbi,
+    MoveEventRecord, MoveValue, StorageUsage,
+};
 
 /// An index to a block.
 pub type BlockNumber = u32;
@@ -65,6 +75,19 @@ pub type AccountIndex = u32;
 /// Balance of an account.
 pub type Balance = u128;
 
+/// Signed balance change of `orml_currencies`.
+pub type Amount = i128;
+
+/// Currencies of the accounts, available to Move by their tickers.
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, PartialOrd, Ord)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum CurrencyId {
+    /// Native currency, `pallet_balances`
+    PONT,
+    /// Bridged KSM, `orml_tokens`
+    KSM,
+}
+
 /// Index of a transaction in the chain.
 pub type Index = u32;
 
@@ -109,10 +132,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     //   `spec_version`, and `authoring_version` are the same between Wasm and native.
     // This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
     //   the compatible custom types.
-    spec_version: 100,
+    spec_version: 102,
     impl_version: 1,
     apis: RUNTIME_API_VERSIONS,
-    transaction_version: 1,
+    transaction_version: 2,
 };
 
 /// This determines the average expected block time that we are targeting.
@@ -277,12 +300,39 @@ parameter_types! {
 }
 
 impl pallet_transaction_payment::Config for Runtime {
-    type OnChargeTransaction = CurrencyAdapter<Balances, ()>;
+    /// Gas of the Move calls is paid by `ChargeGasPrice`, not by the weight fee.
+    type OnChargeTransaction =
+        sp_mvm::fee::ExcludeGasFee<Runtime, CurrencyAdapter<Balances, ()>, IdentityFee<Balance>>;
     type TransactionByteFee = TransactionByteFee;
     type WeightToFee = IdentityFee<Balance>;
     type FeeMultiplierUpdate = ();
 }
 
+parameter_types! {
+    pub const TreasuryModuleId: ModuleId = ModuleId(*b"py/trsry");
+    pub const ProposalBond: Permill = Permill::from_percent(5);
+    pub const ProposalBondMinimum: Balance = PONT;
+    pub const SpendPeriod: BlockNumber = DAYS;
+    pub const Burn: Permill = Permill::zero();
+}
+
+/// Treasury receives the gas fees.
+impl pallet_treasury::Config for Runtime {
+    type ModuleId = TreasuryModuleId;
+    type Currency = Balances;
+    type ApproveOrigin = frame_system::EnsureRoot<AccountId>;
+    type RejectOrigin = frame_system::EnsureRoot<AccountId>;
+    type Event = Event;
+    type OnSlash = ();
+    type ProposalBond = ProposalBond;
+    type ProposalBondMinimum = ProposalBondMinimum;
+    type SpendPeriod = SpendPeriod;
+    type Burn = Burn;
+    type BurnDestination = ();
+    type SpendFunds = ();
+    type WeightInfo = pallet_treasury::weights::SubstrateWeight<Runtime>;
+}
+
 impl pallet_sudo::Config for Runtime {
     type Event = Event;
     type Call = Call;
@@ -311,10 +361,74 @@ impl GasWeightMapping for MoveVMGasWeightMapping {
     }
 }
 
+/// Tokens accounts are never reaped.
+pub struct ExistentialDeposits;
+
+impl GetByKey<CurrencyId, Balance> for ExistentialDeposits {
+    fn get(_: &CurrencyId) -> Balance {
+        0
+    }
+}
+
+impl orml_tokens::Config for Runtime {
+    type Event = Event;
+    type Balance = Balance;
+    type Amount = Amount;
+    type CurrencyId = CurrencyId;
+    type WeightInfo = ();
+    type ExistentialDeposits = ExistentialDeposits;
+    type OnDust = ();
+}
+
+parameter_types! {
+    pub const GetNativeCurrencyId: CurrencyId = CurrencyId::PONT;
+}
+
+impl orml_currencies::Config for Runtime {
+    type Event = Event;
+    type MultiCurrency = Tokens;
+    type NativeCurrency = BasicCurrencyAdapter<Runtime, Balances, Amount, BlockNumber>;
+    type GetNativeCurrencyId = GetNativeCurrencyId;
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    /// Move tickers of the currencies.
+    pub const MoveTickers: &'static [(&'static str, CurrencyId)] =
+        &[("PONT", CurrencyId::PONT), ("KSM", CurrencyId::KSM)];
+}
+
+parameter_types! {
+    /// Oracle prices older than 10 minutes are ignored.
+    pub const OraclePriceLifetime: u64 = 10 * 60 * 1000;
+    /// 0.01 PONT per kilobyte of Move state.
+    pub const StorageDepositPerByte: Balance = PONT / 100_000;
+    /// Every Move address owns up to 10 000 entries and 10 MB of Move state.
+    pub const StorageQuotaKeys: u32 = 10_000;
+    pub const StorageQuotaBytes: u64 = 10 * 1024 * 1024;
+    /// Multi-signer script approvals live a day and reserve 1 PONT each.
+    pub const ScriptApprovalLifetime: BlockNumber = DAYS;
+    pub const ScriptApprovalDeposit: Balance = PONT;
+}
+
 /// Configure the Move-pallet in pallets/sp-mvm.
 impl sp_mvm::Config for Runtime {
     type Event = Event;
     type GasWeightMapping = MoveVMGasWeightMapping;
+    type WeightInfo = sp_mvm::weights::SubstrateWeight<Runtime>;
+    type Currencies = sp_mvm::balance::OrmlCurrencies<Currencies>;
+    type Tickers = sp_mvm::balance::ConstTickers<MoveTickers>;
+    type Oracle = sp_mvm::oracle::StorageOracle<Runtime>;
+    type OraclePriceLifetime = OraclePriceLifetime;
+    type StorageDepositPerByte = StorageDepositPerByte;
+    type StorageQuotaKeys = StorageQuotaKeys;
+    type StorageQuotaBytes = StorageQuotaBytes;
+    type ScriptApprovalLifetime = ScriptApprovalLifetime;
+    type ScriptApprovalDeposit = ScriptApprovalDeposit;
+    type Currency = Balances;
+    type GasFees = Treasury;
+    type StdPublishOrigin = frame_system::EnsureRoot<AccountId>;
+    type ModulePublishOrigin = frame_system::EnsureSigned<AccountId>;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -332,7 +446,10 @@ construct_runtime!(
         Balances: pallet_balances::{Module, Call, Storage, Config<T>, Event<T>},
         TransactionPayment: pallet_transaction_payment::{Module, Storage},
         Sudo: pallet_sudo::{Module, Call, Config<T>, Storage, Event<T>},
-        Mvm: sp_mvm::{Module, Call, Storage, Event<T>},
+        Treasury: pallet_treasury::{Module, Call, Storage, Config, Event<T>},
+        Tokens: orml_tokens::{Module, Storage, Event<T>},
+        Currencies: orml_currencies::{Module, Call, Event<T>},
+        Mvm: sp_mvm::{Module, Call, Config, Storage, Event<T>},
         Vesting: pallet_vesting::{Module, Call, Storage, Config<T>, Event<T>},
     }
 );
@@ -356,6 +473,7 @@ pub type SignedExtra = (
     frame_system::CheckNonce<Runtime>,
     frame_system::CheckWeight<Runtime>,
     pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+    sp_mvm::fee::ChargeGasPrice<Runtime>,
 );
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<Address, Call, Signature, SignedExtra>;
@@ -531,6 +649,94 @@ impl_runtime_apis! {
                 status_code: vm_result.status_code as u64,
             })
         }
+
+        // Estimate gas for publish package.
+        fn estimate_gas_publish_package(account: AccountId, package: Vec<u8>, gas_limit: u64) -> Result<Vec<MVMApiModuleEstimation>, sp_runtime::DispatchError> {
+            let results = Mvm::estimate_publish_package(&account, &package, gas_limit).map_err(|_| sp_runtime::DispatchError::Other("error during VM execution"))?;
+
+            Ok(results.into_iter().map(|(module, vm_result)| MVMApiModuleEstimation {
+                module,
+                gas_used: vm_result.gas_used,
+                status_code: vm_result.status_code as u64,
+            }).collect())
+        }
+
+        // Estimate gas for publish std modules.
+        fn estimate_gas_publish_std(modules: Vec<Vec<u8>>, gas_limit: u64) -> Result<Vec<MVMApiModuleEstimation>, sp_runtime::DispatchError> {
+            let results = Mvm::estimate_publish_std(modules, gas_limit).map_err(|_| sp_runtime::DispatchError::Other("error during VM execution"))?;
+
+            Ok(results.into_iter().map(|(module, vm_result)| MVMApiModuleEstimation {
+                module,
+                gas_used: vm_result.gas_used,
+                status_code: vm_result.status_code as u64,
+            }).collect())
+        }
+
+        // Simulate script execution.
+        fn simulate_execute(account: AccountId, tx_bc: Vec<u8>, gas_limit: u64) -> Result<MVMApiSimulation, sp_runtime::DispatchError> {
+            let (vm_result, record) = Mvm::simulate_execute(&account, tx_bc, gas_limit).map_err(|_| sp_runtime::DispatchError::Other("error during VM execution"))?;
+
+            Ok(MVMApiSimulation {
+                gas_used: vm_result.gas_used,
+                status_code: vm_result.status_code as u64,
+                events: record.events,
+                write_set: record.write_set,
+                balance_changes: record.balance_changes,
+            })
+        }
+
+        // Simulate module publishing.
+        fn simulate_publish(account: AccountId, module_bc: Vec<u8>, gas_limit: u64) -> Result<MVMApiSimulation, sp_runtime::DispatchError> {
+            let (vm_result, record) = Mvm::simulate_publish(&account, module_bc, gas_limit).map_err(|_| sp_runtime::DispatchError::Other("error during VM execution"))?;
+
+            Ok(MVMApiSimulation {
+                gas_used: vm_result.gas_used,
+                status_code: vm_result.status_code as u64,
+                events: record.events,
+                write_set: record.write_set,
+                balance_changes: record.balance_changes,
+            })
+        }
+
+        // Get resource by Move address and struct tag.
+        fn get_resource(address: Vec<u8>, tag: Vec<u8>) -> Result<Option<MVMApiResource>, Vec<u8>> {
+            let bcs = Mvm::get_resource(&address, &tag)?;
+            Ok(bcs.map(|bcs| {
+                // Undecodable value is still returned as raw bytes.
+                let value = Mvm::decode_resource(&tag, &bcs).ok();
+                MVMApiResource { bcs, value }
+            }))
+        }
+
+        // Get module bytecode by Move address and module name.
+        fn get_module(address: Vec<u8>, name: Vec<u8>) -> Result<Option<Vec<u8>>, Vec<u8>> {
+            Mvm::get_module(&address, &name)
+        }
+
+        // Get ABI of the module by Move address and module name.
+        fn get_module_abi(address: Vec<u8>, name: Vec<u8>) -> Result<Option<ModuleAbi>, Vec<u8>> {
+            Mvm::get_module_abi(&address, &name)
+        }
+
+        // List names of all modules published under Move address.
+        fn list_modules(address: Vec<u8>) -> Result<Vec<Vec<u8>>, Vec<u8>> {
+            Mvm::list_modules(&address)
+        }
+
+        // Get number of storage keys and their total size owned by Move address.
+        fn get_storage_usage(address: Vec<u8>) -> Result<StorageUsage, Vec<u8>> {
+            Mvm::storage_usage(&address)
+        }
+
+        // Decode event by type tag.
+        fn decode_event(tag: Vec<u8>, message: Vec<u8>) -> Result<MoveValue, Vec<u8>> {
+            Mvm::decode_event(&tag, &message)
+        }
+
+        // Get Move events of the block with decoded payloads.
+        fn get_block_events() -> Vec<MoveEventRecord> {
+            Mvm::block_events()
+        }
     }
 
     #[cfg(feature = "runtime-benchmarks")]
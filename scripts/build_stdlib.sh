@@ -0,0 +1,35 @@
+#!/usr/bin/env bash
+
+# Builds Move standard library modules published under `0x1` at genesis of dev/local chains.
+# Output: `node/assets/target/stdlib/<index>_<module>.mv`, embedded into the node at build time.
+
+set -e
+
+STDLIB_REPO="https://github.com/pontem-network/move-stdlib.git"
+STDLIB_REV="0fb6bf83ca3c3a826f0df59ebbe62296515ca0ef"
+
+ROOT=$(cd "$(dirname "$0")/.." && pwd)
+SRC="$ROOT/target/move-stdlib"
+OUT="$ROOT/node/assets/target/stdlib"
+
+echo "*** Building Move stdlib modules ($STDLIB_REV)"
+
+if [ ! -d "$SRC" ] ; then
+   git clone "$STDLIB_REPO" "$SRC"
+fi
+git -C "$SRC" fetch origin
+git -C "$SRC" checkout "$STDLIB_REV"
+
+pushd "$SRC"
+dove clean
+dove build --tree
+popd
+
+if ! ls "$SRC"/target/modules/*.mv > /dev/null 2>&1 ; then
+   echo "Stdlib modules not built by dove" >&2
+   exit 1
+fi
+
+rm -rf "$OUT"
+mkdir -p "$OUT"
+cp "$SRC"/target/modules/*.mv "$OUT"/